@@ -1157,6 +1157,16 @@ def section_streaming_actors(outer_panels):
                                       "stream agg distinct cached keys count |table {{table_id}} actor {{actor_id}}"),
                     ],
                 ),
+                panels.timeseries_actor_ops(
+                    "Aggregation State Cleanup",
+                    "The number of watermark-driven state cleanups in each hash aggregation executor.",
+                    [
+                        panels.target(
+                            f"rate({metric('stream_agg_state_cleanup_count')}[$__rate_interval])",
+                            "state cleanup count | table {{table_id}} actor {{actor_id}}",
+                        ),
+                    ],
+                ),
                 panels.timeseries_count(
                     "TopN Cached Keys",
                     "The number of keys cached in each top_n executor's executor cache.",
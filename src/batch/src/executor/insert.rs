@@ -53,6 +53,9 @@ pub struct InsertExecutor {
     row_id_index: Option<usize>,
     returning: bool,
     txn_id: TxnId,
+    /// Number of DML channels to spread the written chunks over. Values greater than 1 split the
+    /// insert into that many independently committed transactions.
+    dml_parallelism: usize,
 }
 
 impl InsertExecutor {
@@ -68,6 +71,7 @@ impl InsertExecutor {
         sorted_default_columns: Vec<(usize, BoxedExpression)>,
         row_id_index: Option<usize>,
         returning: bool,
+        dml_parallelism: usize,
     ) -> Self {
         let table_schema = child.schema().clone();
         let txn_id = dml_manager.gen_txn_id();
@@ -90,6 +94,7 @@ impl InsertExecutor {
             row_id_index,
             returning,
             txn_id,
+            dml_parallelism: dml_parallelism.max(1),
         }
     }
 }
@@ -117,14 +122,24 @@ impl InsertExecutor {
         let table_dml_handle = self
             .dml_manager
             .table_dml_handle(self.table_id, self.table_version_id)?;
-        let mut write_handle = table_dml_handle.write_handle(self.txn_id)?;
+
+        // When `dml_parallelism` is greater than 1, spread the chunks over multiple write handles
+        // in a round-robin manner. Each handle owns an independent transaction on its own DML
+        // channel, so the insert is no longer atomic as a whole, which bulk loads opt in via the
+        // `BATCH_DML_PARALLELISM` session variable.
+        let mut write_handles = vec![table_dml_handle.write_handle(self.txn_id)?];
+        for _ in 1..self.dml_parallelism {
+            write_handles.push(table_dml_handle.write_handle(self.dml_manager.gen_txn_id())?);
+        }
 
         let mut notifiers = Vec::new();
 
-        notifiers.push(write_handle.begin()?);
+        for write_handle in &mut write_handles {
+            notifiers.push(write_handle.begin()?);
+        }
 
-        // Transform the data chunk to a stream chunk, then write to the source.
-        let write_txn_data = |chunk: DataChunk| async {
+        // Transform the data chunk to a stream chunk for writing to the source.
+        let build_txn_data = |chunk: DataChunk| async {
             let cap = chunk.capacity();
             let (mut columns, vis) = chunk.into_parts();
 
@@ -162,9 +177,10 @@ impl InsertExecutor {
             #[cfg(debug_assertions)]
             table_dml_handle.check_chunk_schema(&stream_chunk);
 
-            write_handle.write_chunk(stream_chunk).await
+            Ok::<_, RwError>(stream_chunk)
         };
 
+        let mut next_handle = 0;
         #[for_await]
         for data_chunk in self.child.execute() {
             let data_chunk = data_chunk?;
@@ -172,15 +188,25 @@ impl InsertExecutor {
                 yield data_chunk.clone();
             }
             for chunk in builder.append_chunk(data_chunk) {
-                notifiers.push(write_txn_data(chunk).await?);
+                let stream_chunk = build_txn_data(chunk).await?;
+                let write_handle = &write_handles[next_handle % write_handles.len()];
+                next_handle += 1;
+                notifiers.push(write_handle.write_chunk(stream_chunk).await?);
             }
         }
 
         if let Some(chunk) = builder.consume_all() {
-            notifiers.push(write_txn_data(chunk).await?);
+            let stream_chunk = build_txn_data(chunk).await?;
+            notifiers.push(
+                write_handles[next_handle % write_handles.len()]
+                    .write_chunk(stream_chunk)
+                    .await?,
+            );
         }
 
-        notifiers.push(write_handle.end()?);
+        for write_handle in write_handles {
+            notifiers.push(write_handle.end()?);
+        }
 
         // Wait for all chunks to be taken / written.
         let rows_inserted = try_join_all(notifiers)
@@ -251,6 +277,7 @@ impl BoxedExecutorBuilder for InsertExecutor {
             sorted_default_columns,
             insert_node.row_id_index.as_ref().map(|index| *index as _),
             insert_node.returning,
+            insert_node.dml_parallelism as usize,
         )))
     }
 }
@@ -346,6 +373,7 @@ mod tests {
             vec![],
             row_id_index,
             false,
+            1,
         ));
         let handle = tokio::spawn(async move {
             let mut stream = insert_executor.execute();
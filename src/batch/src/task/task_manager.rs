@@ -29,6 +29,7 @@ use risingwave_pb::common::BatchQueryEpoch;
 use risingwave_pb::task_service::task_info_response::TaskStatus;
 use risingwave_pb::task_service::{GetDataResponse, TaskInfoResponse};
 use tokio::sync::mpsc::Sender;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tonic::Status;
 
 use crate::monitor::BatchManagerMetrics;
@@ -59,6 +60,14 @@ pub struct BatchManager {
 
     /// Metrics for batch manager.
     metrics: BatchManagerMetrics,
+
+    /// Execution slots for admission control of batch tasks. `None` if the concurrent task
+    /// number is unlimited.
+    admission_semaphore: Option<Arc<Semaphore>>,
+
+    /// Admission permits held by running tasks. A permit is released when its task is removed,
+    /// so that a queued task can be admitted.
+    admission_permits: Arc<Mutex<HashMap<TaskId, OwnedSemaphorePermit>>>,
 }
 
 impl BatchManager {
@@ -76,6 +85,9 @@ impl BatchManager {
         };
 
         let mem_context = MemoryContext::root(metrics.batch_total_mem.clone());
+        let admission_semaphore = config
+            .max_concurrent_task_num
+            .map(|limit| Arc::new(Semaphore::new(limit)));
         BatchManager {
             tasks: Arc::new(Mutex::new(HashMap::new())),
             runtime: Arc::new(runtime.into()),
@@ -83,6 +95,8 @@ impl BatchManager {
             total_mem_val: TrAdder::new().into(),
             metrics,
             mem_context,
+            admission_semaphore,
+            admission_permits: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -100,6 +114,7 @@ impl BatchManager {
         tracing_context: TracingContext,
     ) -> Result<()> {
         trace!("Received task id: {:?}, plan: {:?}", tid, plan);
+        let permit = self.acquire_admission_permit().await?;
         let task = BatchTaskExecution::new(tid, plan, context, epoch, self.runtime())?;
         let task_id = task.get_task_id().clone();
         let task = Arc::new(task);
@@ -109,6 +124,11 @@ impl BatchManager {
         let ret = if let hash_map::Entry::Vacant(e) = self.tasks.lock().entry(task_id.clone()) {
             e.insert(task.clone());
             self.metrics.task_num.inc();
+            if let Some(permit) = permit {
+                self.admission_permits
+                    .lock()
+                    .insert(task_id.clone(), permit);
+            }
 
             let this = self.clone();
             let task_id = task_id.clone();
@@ -134,6 +154,39 @@ impl BatchManager {
         ret
     }
 
+    /// Admission control for batch tasks, so that ad-hoc batch queries cannot occupy all
+    /// resources of a compute node that also serves streaming jobs. Rejects the task immediately
+    /// if the total memory usage of batch tasks already exceeds the configured limit, and queues
+    /// it for a bounded time when the concurrent task number limit is reached.
+    async fn acquire_admission_permit(&self) -> Result<Option<OwnedSemaphorePermit>> {
+        if let Some(limit) = self.config.max_total_mem_usage_bytes {
+            let usage = self.total_mem_usage();
+            if usage > limit {
+                return Err(ErrorCode::InternalError(format!(
+                    "rejected by batch admission control: total memory usage of batch tasks {} \
+                     exceeds the limit {}",
+                    usage, limit,
+                ))
+                .into());
+            }
+        }
+        let semaphore = match &self.admission_semaphore {
+            Some(semaphore) => semaphore.clone(),
+            None => return Ok(None),
+        };
+        let timeout = core::time::Duration::from_millis(self.config.admission_queue_timeout_ms);
+        match tokio::time::timeout(timeout, semaphore.acquire_owned()).await {
+            Ok(permit) => Ok(Some(permit.expect("semaphore should never be closed"))),
+            Err(_) => Err(ErrorCode::InternalError(format!(
+                "rejected by batch admission control: {} batch tasks are already running and no \
+                 slot was freed within {}ms",
+                self.config.max_concurrent_task_num.unwrap(),
+                self.config.admission_queue_timeout_ms,
+            ))
+            .into()),
+        }
+    }
+
     #[cfg(test)]
     async fn fire_task_for_test(
         self: &Arc<Self>,
@@ -231,6 +284,8 @@ impl BatchManager {
                 // propagated to upstream.
                 task.cancel();
                 self.metrics.task_num.dec();
+                // Release the admission permit, if any, so that a queued task can proceed.
+                self.admission_permits.lock().remove(&sid);
                 if let Some(heartbeat_join_handle) = task.heartbeat_join_handle() {
                     heartbeat_join_handle.abort();
                 }
@@ -464,6 +519,57 @@ mod tests {
         assert!(!manager.tasks.lock().contains_key(&task_id));
     }
 
+    #[tokio::test]
+    async fn test_task_admission_control() {
+        let config = BatchConfig {
+            max_concurrent_task_num: Some(1),
+            admission_queue_timeout_ms: 100,
+            ..Default::default()
+        };
+        let manager = Arc::new(BatchManager::new(config, BatchManagerMetrics::for_test()));
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                node_body: Some(NodeBody::BlockExecutor(true)),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                distribution: None,
+            }),
+        };
+        let task_id = PbTaskId {
+            query_id: "".to_string(),
+            stage_id: 0,
+            task_id: 0,
+        };
+        manager
+            .fire_task_for_test(&task_id, plan.clone())
+            .await
+            .unwrap();
+
+        // The second task cannot get an execution slot and is rejected after queueing.
+        let next_task_id = PbTaskId {
+            query_id: "".to_string(),
+            stage_id: 0,
+            task_id: 1,
+        };
+        let err = manager
+            .fire_task_for_test(&next_task_id, plan.clone())
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("rejected by batch admission control"));
+
+        // Cancelling the first task releases its permit, so the second one can be admitted.
+        manager.cancel_task(&task_id);
+        manager
+            .fire_task_for_test(&next_task_id, plan)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_task_abort_for_busy_loop() {
         let manager = Arc::new(BatchManager::new(
@@ -121,7 +121,8 @@ where
             | Info::RelationGroup(_)
             | Info::User(_)
             | Info::Connection(_)
-            | Info::Function(_) => {
+            | Info::Function(_)
+            | Info::Secret(_) => {
                 notification.version > info.version.as_ref().unwrap().catalog_version
             }
             Info::ParallelUnitMapping(_) => {
@@ -270,6 +270,36 @@ impl StreamChunk {
             }
         }
     }
+
+    /// Split the chunk into multiple chunks with at most `max_chunk_size` rows each. An
+    /// `UpdateDelete` is never separated from its following `UpdateInsert`, so every output
+    /// chunk is a complete stream chunk on its own and the pieces can be consumed without
+    /// reassembly. Invisible rows are compacted away in the output.
+    pub fn split(&self, max_chunk_size: usize) -> Vec<StreamChunk> {
+        assert!(
+            max_chunk_size >= 2,
+            "max_chunk_size must be able to hold an update pair"
+        );
+        let rows = self
+            .rows()
+            .map(|(op, row)| (op, row.into_owned_row()))
+            .collect_vec();
+        let data_types = self.data.data_types();
+
+        let mut chunks = Vec::with_capacity(rows.len() / max_chunk_size + 1);
+        let mut start = 0;
+        while start < rows.len() {
+            let mut end = (start + max_chunk_size).min(rows.len());
+            // Move the split point one row earlier if it would leave an `UpdateDelete`
+            // dangling at the end of this chunk.
+            if end < rows.len() && end - start > 1 && rows[end - 1].0 == Op::UpdateDelete {
+                end -= 1;
+            }
+            chunks.push(StreamChunk::from_rows(&rows[start..end], &data_types));
+            start = end;
+        }
+        chunks
+    }
 }
 
 impl fmt::Debug for StreamChunk {
@@ -489,4 +519,44 @@ mod tests {
 +----+---+---+"
         );
     }
+
+    #[test]
+    fn test_split() {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+             + 1 6
+             - 2 .
+            U- 3 7
+            U+ 4 .
+             + 5 9",
+        );
+        // The natural split point after 3 rows falls between `U-` and `U+`, so the first
+        // chunk ends one row earlier and the pair stays together.
+        let chunks = chunk.split(3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks[0].to_pretty_string(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 6
+                - 2 ."
+            )
+            .to_pretty_string()
+        );
+        assert_eq!(
+            chunks[1].to_pretty_string(),
+            StreamChunk::from_pretty(
+                "  I I
+                U- 3 7
+                U+ 4 .
+                 + 5 9"
+            )
+            .to_pretty_string()
+        );
+
+        // A limit of at least the cardinality is a no-op split.
+        let chunks = chunk.split(5);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].to_pretty_string(), chunk.to_pretty_string());
+    }
 }
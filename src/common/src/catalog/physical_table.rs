@@ -58,6 +58,12 @@ pub struct TableDesc {
     ///
     /// See `version` field in `TableCatalog` for more details.
     pub versioned: bool,
+
+    /// The positions of the distribution key columns within the pk, as recorded in the catalog.
+    /// Internal state tables may distribute by an expression (e.g. a vnode column) rather than by
+    /// pk columns, in which case the positions cannot be derived from `distribution_key` and `pk`.
+    /// If empty, the positions are derived.
+    pub dist_key_in_pk: Vec<usize>,
 }
 
 impl TableDesc {
@@ -84,21 +90,25 @@ impl TableDesc {
             .iter()
             .map(|v| v.to_protobuf().column_index)
             .collect();
-        let dist_key_in_pk_indices = dist_key_indices
-            .iter()
-            .map(|&di| {
-                pk_indices
-                    .iter()
-                    .position(|&pi| di == pi)
-                    .unwrap_or_else(|| {
-                        panic!(
-                            "distribution key {:?} must be a subset of primary key {:?}",
-                            dist_key_indices, pk_indices
-                        )
-                    })
-            })
-            .map(|d| d as u32)
-            .collect_vec();
+        let dist_key_in_pk_indices = if !self.dist_key_in_pk.is_empty() {
+            self.dist_key_in_pk.iter().map(|&d| d as u32).collect_vec()
+        } else {
+            dist_key_indices
+                .iter()
+                .map(|&di| {
+                    pk_indices
+                        .iter()
+                        .position(|&pi| di == pi)
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "distribution key {:?} must be a subset of primary key {:?}",
+                                dist_key_indices, pk_indices
+                            )
+                        })
+                })
+                .map(|d| d as u32)
+                .collect_vec()
+        };
         StorageTableDesc {
             table_id: self.table_id.into(),
             columns: self.columns.iter().map(Into::into).collect(),
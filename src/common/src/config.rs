@@ -176,6 +176,26 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::vacuum_interval_sec")]
     pub vacuum_interval_sec: u64,
 
+    /// How long summaries of completed compaction tasks are retained, for
+    /// write-amplification trend analysis via `rw_compaction_history`.
+    #[serde(default = "default::meta::compaction_task_history_retention_sec")]
+    pub compaction_task_history_retention_sec: u64,
+
+    /// The secondary object store that newly committed SSTs and meta backups are
+    /// asynchronously mirrored to, for disaster recovery. Unset disables replication.
+    #[serde(default)]
+    pub dr_secondary_object_store: Option<String>,
+
+    /// Interval of mirroring newly committed SSTs and meta backups to the secondary
+    /// object store.
+    #[serde(default = "default::meta::dr_replication_interval_sec")]
+    pub dr_replication_interval_sec: u64,
+
+    /// Whether this cluster is a read-only disaster recovery standby that continuously
+    /// applies the replicated hummock version checkpoint, until it is promoted to primary.
+    #[serde(default)]
+    pub dr_standby: bool,
+
     /// Interval of hummock version checkpoint.
     #[serde(default = "default::meta::hummock_version_checkpoint_interval_sec")]
     pub hummock_version_checkpoint_interval_sec: u64,
@@ -193,6 +213,23 @@ pub struct MetaConfig {
     #[serde(default)]
     pub disable_recovery: bool,
 
+    /// Number of unexpected actor failures after which the owning streaming job is
+    /// automatically quarantined, i.e. its source actors are paused while the rest of the
+    /// cluster keeps running. Resume it with `risectl meta resume-quarantined-job`.
+    /// Zero disables automatic quarantine.
+    #[serde(default)]
+    pub quarantine_failure_threshold: u64,
+
+    /// Interval in seconds at which the meta node checks for parallelism imbalance (e.g. after
+    /// workers joined or left) and automatically applies reschedule plans. Zero disables
+    /// automatic rebalancing.
+    #[serde(default)]
+    pub auto_rebalance_interval_sec: u64,
+
+    /// Maximum number of fragments to reschedule per automatic rebalancing round.
+    #[serde(default = "default::meta::auto_rebalance_max_moves")]
+    pub auto_rebalance_max_moves: u64,
+
     #[serde(default = "default::meta::meta_leader_lease_secs")]
     pub meta_leader_lease_secs: u64,
 
@@ -234,6 +271,12 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::periodic_split_compact_group_interval_sec")]
     pub periodic_split_compact_group_interval_sec: u64,
 
+    /// Whether to automatically move high-write-throughput state tables to dedicated compaction
+    /// groups. Set to false to only split groups manually with
+    /// `risectl hummock split-compaction-group`.
+    #[serde(default = "default::meta::enable_automatic_split_compact_group")]
+    pub enable_automatic_split_compact_group: bool,
+
     /// Compute compactor_task_limit for machines with different hardware.Currently cpu is used as
     /// the main consideration,and is adjusted by max_compactor_task_multiplier, calculated as
     /// compactor_task_limit = core_num * max_compactor_task_multiplier;
@@ -366,6 +409,23 @@ pub struct BatchConfig {
     #[serde(default)]
     pub distributed_query_limit: Option<u64>,
 
+    /// The maximum number of batch tasks allowed to run concurrently in the compute node. A task
+    /// fired beyond the limit queues for an execution slot for `admission_queue_timeout_ms` and
+    /// is rejected if none is freed in time. The default value is unlimited.
+    #[serde(default)]
+    pub max_concurrent_task_num: Option<usize>,
+
+    /// The maximum total memory usage in bytes of batch tasks in the compute node, which is
+    /// mostly held by scanned data in flight. A task fired while the usage exceeds the limit is
+    /// rejected immediately. The default value is unlimited.
+    #[serde(default)]
+    pub max_total_mem_usage_bytes: Option<usize>,
+
+    /// How long a batch task may queue for an execution slot when `max_concurrent_task_num` is
+    /// reached before it is rejected.
+    #[serde(default = "default::batch::admission_queue_timeout_ms")]
+    pub admission_queue_timeout_ms: u64,
+
     #[serde(default = "default::batch::enable_barrier_read")]
     pub enable_barrier_read: bool,
 
@@ -473,6 +533,22 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::max_preload_wait_time_mill")]
     pub max_preload_wait_time_mill: u64,
 
+    /// Local directory for persisting an index of the block cache on graceful shutdown, from
+    /// which the cache is rehydrated on the next start to speed up restarts with a large
+    /// working set. Empty string disables the feature.
+    #[serde(default = "default::storage::cache_recovery_dir")]
+    pub cache_recovery_dir: String,
+
+    /// Key provider used for SST encryption at rest, e.g. "aws-kms". Encryption is only applied
+    /// to compaction groups that have a KMS key id configured. Empty string disables the
+    /// feature.
+    #[serde(default = "default::storage::data_key_provider")]
+    pub data_key_provider: String,
+
+    /// Retry behavior of object store requests.
+    #[serde(default)]
+    pub object_store_retry: RetryConfig,
+
     #[serde(default, flatten)]
     pub unrecognized: Unrecognized<Self>,
 }
@@ -504,6 +580,49 @@ pub struct FileCacheConfig {
     pub unrecognized: Unrecognized<Self>,
 }
 
+/// Tunables for a [`RetryPolicy`](crate::util::retry::RetryPolicy), the shared retry/backoff
+/// policy for calls to external services. The subsection `[storage.object_store_retry]` in
+/// `risingwave.toml` is an instance of it; clients without a config section of their own use
+/// the defaults.
+#[derive(Clone, Debug, Serialize, Deserialize, DefaultFromSerde)]
+pub struct RetryConfig {
+    /// The backoff interval before the first retry, doubled on every subsequent one.
+    #[serde(default = "default::retry::initial_interval_ms")]
+    pub initial_interval_ms: u64,
+
+    /// The maximum backoff interval between retries.
+    #[serde(default = "default::retry::max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// The maximum number of retries of a single call.
+    #[serde(default = "default::retry::max_attempts")]
+    pub max_attempts: usize,
+
+    /// The deadline for a single attempt, after which it is considered failed and may be
+    /// retried. `0` means no deadline.
+    #[serde(default = "default::retry::deadline_ms")]
+    pub deadline_ms: u64,
+
+    /// The capacity of the retry token bucket shared by all calls through the same policy.
+    /// Every retry consumes a token and every successful call deposits a fraction of one, so
+    /// that retries are skipped when the service is persistently failing. `0` disables the
+    /// budget.
+    #[serde(default = "default::retry::retry_budget")]
+    pub retry_budget: u32,
+
+    /// Open the circuit (fail calls fast without attempting them) after this number of
+    /// consecutive failures. `0` disables circuit breaking.
+    #[serde(default = "default::retry::circuit_break_threshold")]
+    pub circuit_break_threshold: u32,
+
+    /// How long the circuit stays open before a trial call is allowed again.
+    #[serde(default = "default::retry::circuit_break_cooldown_ms")]
+    pub circuit_break_cooldown_ms: u64,
+
+    #[serde(default, flatten)]
+    pub unrecognized: Unrecognized<Self>,
+}
+
 #[derive(Debug, Default, Clone, Copy, ValueEnum, Serialize, Deserialize)]
 pub enum AsyncStackTraceOption {
     /// Disabled.
@@ -544,6 +663,13 @@ pub struct StreamingDeveloperConfig {
     #[serde(default = "default::developer::unsafe_stream_extreme_cache_size")]
     pub unsafe_extreme_cache_size: usize,
 
+    /// Number of index bits (i.e. log2 of the register count) of the HyperLogLog sketch used by
+    /// `approx_count_distinct` on append-only streams. Larger values give a more accurate
+    /// estimate at the cost of a larger state. Only takes effect for newly created jobs, as the
+    /// persisted sketch records its own size.
+    #[serde(default = "default::developer::stream_approx_count_distinct_index_bits")]
+    pub approx_count_distinct_index_bits: u8,
+
     /// The maximum size of the chunk produced by executor at a time.
     #[serde(default = "default::developer::stream_chunk_size")]
     pub chunk_size: usize,
@@ -628,6 +754,36 @@ pub struct SystemConfig {
 
     #[serde(default = "default::system::telemetry_enabled")]
     pub telemetry_enabled: Option<bool>,
+
+    /// Label attached to outgoing IO requests (S3 user agent, Kafka client id) for cost and
+    /// quota attribution. Empty string disables the feature.
+    #[serde(default = "default::system::resource_label")]
+    pub resource_label: Option<String>,
+
+    /// Interval of automatic meta backups in seconds. Zero disables automatic backups.
+    #[serde(default = "default::system::backup_auto_interval_sec")]
+    pub backup_auto_interval_sec: Option<u64>,
+
+    /// Number of meta snapshots to retain. Zero disables count-based pruning.
+    #[serde(default = "default::system::backup_retention_count")]
+    pub backup_retention_count: Option<u64>,
+
+    /// Age in seconds beyond which meta snapshots are pruned. Zero disables age-based pruning.
+    #[serde(default = "default::system::backup_retention_sec")]
+    pub backup_retention_sec: Option<u64>,
+
+    /// Barrier latency in milliseconds above which the checkpoint frequency is adaptively
+    /// increased. Zero disables adaptive checkpointing.
+    #[serde(default = "default::system::adaptive_checkpoint_latency_threshold_ms")]
+    pub adaptive_checkpoint_latency_threshold_ms: Option<u64>,
+
+    /// Lower bound of the adaptively adjusted checkpoint frequency.
+    #[serde(default = "default::system::adaptive_checkpoint_frequency_min")]
+    pub adaptive_checkpoint_frequency_min: Option<u64>,
+
+    /// Upper bound of the adaptively adjusted checkpoint frequency.
+    #[serde(default = "default::system::adaptive_checkpoint_frequency_max")]
+    pub adaptive_checkpoint_frequency_max: Option<u64>,
 }
 
 impl SystemConfig {
@@ -643,6 +799,13 @@ impl SystemConfig {
             backup_storage_url: self.backup_storage_url,
             backup_storage_directory: self.backup_storage_directory,
             telemetry_enabled: self.telemetry_enabled,
+            resource_label: self.resource_label,
+            backup_auto_interval_sec: self.backup_auto_interval_sec,
+            backup_retention_count: self.backup_retention_count,
+            backup_retention_sec: self.backup_retention_sec,
+            adaptive_checkpoint_latency_threshold_ms: self.adaptive_checkpoint_latency_threshold_ms,
+            adaptive_checkpoint_frequency_min: self.adaptive_checkpoint_frequency_min,
+            adaptive_checkpoint_frequency_max: self.adaptive_checkpoint_frequency_max,
         }
     }
 }
@@ -663,10 +826,23 @@ mod default {
             60
         }
 
+        pub fn auto_rebalance_max_moves() -> u64 {
+            8
+        }
+
         pub fn vacuum_interval_sec() -> u64 {
             30
         }
 
+        pub fn compaction_task_history_retention_sec() -> u64 {
+            // 30 days
+            2592000
+        }
+
+        pub fn dr_replication_interval_sec() -> u64 {
+            10
+        }
+
         pub fn hummock_version_checkpoint_interval_sec() -> u64 {
             30
         }
@@ -707,6 +883,10 @@ mod default {
             180 // 3mi
         }
 
+        pub fn enable_automatic_split_compact_group() -> bool {
+            true
+        }
+
         pub fn max_compactor_task_multiplier() -> u32 {
             2
         }
@@ -825,6 +1005,14 @@ mod default {
         pub fn max_preload_wait_time_mill() -> u64 {
             10
         }
+
+        pub fn cache_recovery_dir() -> String {
+            "".to_string()
+        }
+
+        pub fn data_key_provider() -> String {
+            "".to_string()
+        }
     }
 
     pub mod streaming {
@@ -872,6 +1060,37 @@ mod default {
         }
     }
 
+    pub mod retry {
+
+        pub fn initial_interval_ms() -> u64 {
+            20
+        }
+
+        pub fn max_delay_ms() -> u64 {
+            10000
+        }
+
+        pub fn max_attempts() -> usize {
+            8
+        }
+
+        pub fn deadline_ms() -> u64 {
+            0
+        }
+
+        pub fn retry_budget() -> u32 {
+            10
+        }
+
+        pub fn circuit_break_threshold() -> u32 {
+            0
+        }
+
+        pub fn circuit_break_cooldown_ms() -> u64 {
+            10000
+        }
+    }
+
     pub mod developer {
 
         pub fn batch_output_channel_size() -> usize {
@@ -890,6 +1109,10 @@ mod default {
             10
         }
 
+        pub fn stream_approx_count_distinct_index_bits() -> u8 {
+            16
+        }
+
         pub fn stream_chunk_size() -> usize {
             256
         }
@@ -953,12 +1176,44 @@ mod default {
         pub fn telemetry_enabled() -> Option<bool> {
             system_param::default::telemetry_enabled()
         }
+
+        pub fn resource_label() -> Option<String> {
+            system_param::default::resource_label()
+        }
+
+        pub fn backup_auto_interval_sec() -> Option<u64> {
+            system_param::default::backup_auto_interval_sec()
+        }
+
+        pub fn backup_retention_count() -> Option<u64> {
+            system_param::default::backup_retention_count()
+        }
+
+        pub fn backup_retention_sec() -> Option<u64> {
+            system_param::default::backup_retention_sec()
+        }
+
+        pub fn adaptive_checkpoint_latency_threshold_ms() -> Option<u64> {
+            system_param::default::adaptive_checkpoint_latency_threshold_ms()
+        }
+
+        pub fn adaptive_checkpoint_frequency_min() -> Option<u64> {
+            system_param::default::adaptive_checkpoint_frequency_min()
+        }
+
+        pub fn adaptive_checkpoint_frequency_max() -> Option<u64> {
+            system_param::default::adaptive_checkpoint_frequency_max()
+        }
     }
 
     pub mod batch {
         pub fn enable_barrier_read() -> bool {
             true
         }
+
+        pub fn admission_queue_timeout_ms() -> u64 {
+            5000
+        }
     }
 }
 
@@ -34,4 +34,6 @@ pub mod hummock {
 
     pub const TABLE_OPTION_DUMMY_RETENTION_SECOND: u32 = 0;
     pub const PROPERTIES_RETENTION_SECOND_KEY: &str = "retention_seconds";
+    pub const PROPERTIES_COLD_STORAGE_CLASS_KEY: &str = "cold_storage_class";
+    pub const PROPERTIES_BLOOM_FILTER_MODE_KEY: &str = "bloom_filter_mode";
 }
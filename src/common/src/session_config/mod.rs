@@ -34,7 +34,7 @@ use crate::util::epoch::Epoch;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 25] = [
+const CONFIG_KEYS: [&str; 27] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -60,6 +60,8 @@ const CONFIG_KEYS: [&str; 25] = [
     "RW_ENABLE_JOIN_ORDERING",
     "SERVER_VERSION",
     "SERVER_VERSION_NUM",
+    "BATCH_DML_PARALLELISM",
+    "RW_STREAMING_ENABLE_KEY_SPREAD_AGG",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -89,6 +91,8 @@ const STREAMING_ENABLE_BUSHY_JOIN: usize = 21;
 const RW_ENABLE_JOIN_ORDERING: usize = 22;
 const SERVER_VERSION: usize = 23;
 const SERVER_VERSION_NUM: usize = 24;
+const BATCH_DML_PARALLELISM: usize = 25;
+const STREAMING_ENABLE_KEY_SPREAD_AGG: usize = 26;
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -284,7 +288,7 @@ type MaxSplitRangeGap = ConfigI32<MAX_SPLIT_RANGE_GAP, 8>;
 type QueryEpoch = ConfigU64<QUERY_EPOCH, 0>;
 type Timezone = ConfigString<TIMEZONE>;
 type StreamingParallelism = ConfigU64<STREAMING_PARALLELISM, 0>;
-type StreamingEnableDeltaJoin = ConfigBool<STREAMING_ENABLE_DELTA_JOIN, false>;
+type StreamingEnableDeltaJoin = ConfigBool<STREAMING_ENABLE_DELTA_JOIN, true>;
 type StreamingEnableBushyJoin = ConfigBool<STREAMING_ENABLE_BUSHY_JOIN, true>;
 type EnableTwoPhaseAgg = ConfigBool<ENABLE_TWO_PHASE_AGG, true>;
 type ForceTwoPhaseAgg = ConfigBool<FORCE_TWO_PHASE_AGG, false>;
@@ -294,6 +298,8 @@ type BatchParallelism = ConfigU64<BATCH_PARALLELISM, 0>;
 type EnableJoinOrdering = ConfigBool<RW_ENABLE_JOIN_ORDERING, true>;
 type ServerVersion = ConfigString<SERVER_VERSION>;
 type ServerVersionNum = ConfigI32<SERVER_VERSION_NUM, 80_300>;
+type BatchDmlParallelism = ConfigU64<BATCH_DML_PARALLELISM, 1>;
+type StreamingEnableKeySpreadAgg = ConfigBool<STREAMING_ENABLE_KEY_SPREAD_AGG, false>;
 
 /// Report status or notice to caller.
 pub trait ConfigReporter {
@@ -356,7 +362,8 @@ pub struct ConfigMap {
     /// streaming parallelism.
     streaming_parallelism: StreamingParallelism,
 
-    /// Enable delta join for streaming queries. Defaults to false.
+    /// Enable delta join for streaming queries. Defaults to true, in which case the optimizer
+    /// automatically uses a delta join whenever suitable indexes exist on both join sides.
     streaming_enable_delta_join: StreamingEnableDeltaJoin,
 
     /// Enable bushy join for streaming queries. Defaults to true.
@@ -384,6 +391,17 @@ pub struct ConfigMap {
 
     batch_parallelism: BatchParallelism,
 
+    /// Number of DML channels an `INSERT`/`DELETE`/`UPDATE` statement spreads its chunks over.
+    /// Values greater than 1 make bulk loads faster by writing to multiple source executors
+    /// concurrently, at the cost of splitting the statement into that many independently
+    /// committed transactions.
+    batch_dml_parallelism: BatchDmlParallelism,
+
+    /// Spread a streaming group-by key over salted partial aggregates that are merged
+    /// downstream, to remove the single-actor bottleneck on extremely hot keys. Defaults to
+    /// false, as the extra merge step adds a small amount of latency.
+    streaming_enable_key_spread_agg: StreamingEnableKeySpreadAgg,
+
     /// The version of PostgreSQL that Risingwave claims to be.
     #[educe(Default(expression = "ConfigString::<SERVER_VERSION>(String::from(\"8.3.0\"))"))]
     server_version: ServerVersion,
@@ -459,6 +477,10 @@ impl ConfigMap {
             self.interval_style = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(BatchParallelism::entry_name()) {
             self.batch_parallelism = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(BatchDmlParallelism::entry_name()) {
+            self.batch_dml_parallelism = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(StreamingEnableKeySpreadAgg::entry_name()) {
+            self.streaming_enable_key_spread_agg = val.as_slice().try_into()?;
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -513,6 +535,10 @@ impl ConfigMap {
             Ok(self.interval_style.to_string())
         } else if key.eq_ignore_ascii_case(BatchParallelism::entry_name()) {
             Ok(self.batch_parallelism.to_string())
+        } else if key.eq_ignore_ascii_case(BatchDmlParallelism::entry_name()) {
+            Ok(self.batch_dml_parallelism.to_string())
+        } else if key.eq_ignore_ascii_case(StreamingEnableKeySpreadAgg::entry_name()) {
+            Ok(self.streaming_enable_key_spread_agg.to_string())
         } else if key.eq_ignore_ascii_case(ServerVersion::entry_name()) {
             Ok(self.server_version.to_string())
         } else if key.eq_ignore_ascii_case(ServerVersionNum::entry_name()) {
@@ -599,7 +625,7 @@ impl ConfigMap {
             VariableInfo{
                 name : StreamingEnableDeltaJoin::entry_name().to_lowercase(),
                 setting : self.streaming_enable_delta_join.to_string(),
-                description: String::from("Enable delta join in streaming queries.")
+                description: String::from("Enable automatic selection of delta join in streaming queries when suitable indexes exist.")
             },
             VariableInfo{
                 name : StreamingEnableBushyJoin::entry_name().to_lowercase(),
@@ -636,6 +662,16 @@ impl ConfigMap {
                 setting : self.batch_parallelism.to_string(),
                 description: String::from("Sets the parallelism for batch. If 0, use default value.")
             },
+            VariableInfo{
+                name : BatchDmlParallelism::entry_name().to_lowercase(),
+                setting : self.batch_dml_parallelism.to_string(),
+                description: String::from("Sets the number of DML channels a DML statement spreads its chunks over. Values greater than 1 split the statement into that many independently committed transactions.")
+            },
+            VariableInfo{
+                name : StreamingEnableKeySpreadAgg::entry_name().to_lowercase(),
+                setting : self.streaming_enable_key_spread_agg.to_string(),
+                description: String::from("Spread a streaming group-by key over salted partial aggregates that are merged downstream, to remove the single-actor bottleneck on extremely hot keys.")
+            },
             VariableInfo{
                 name : ServerVersion::entry_name().to_lowercase(),
                 setting : self.server_version.to_string(),
@@ -749,4 +785,12 @@ impl ConfigMap {
         }
         None
     }
+
+    pub fn get_batch_dml_parallelism(&self) -> u64 {
+        self.batch_dml_parallelism.0.max(1)
+    }
+
+    pub fn get_streaming_enable_key_spread_agg(&self) -> bool {
+        *self.streaming_enable_key_spread_agg
+    }
 }
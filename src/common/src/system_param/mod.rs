@@ -46,6 +46,13 @@ macro_rules! for_all_undeprecated_params {
             { backup_storage_url, String, Some("memory".to_string()), false },
             { backup_storage_directory, String, Some("backup".to_string()), false },
             { telemetry_enabled, bool, Some(true), true },
+            { resource_label, String, Some("".to_string()), false },
+            { backup_auto_interval_sec, u64, Some(0_u64), true },
+            { backup_retention_count, u64, Some(0_u64), true },
+            { backup_retention_sec, u64, Some(0_u64), true },
+            { adaptive_checkpoint_latency_threshold_ms, u64, Some(0_u64), true },
+            { adaptive_checkpoint_frequency_min, u64, Some(10_u64), true },
+            { adaptive_checkpoint_frequency_max, u64, Some(100_u64), true },
             $({ $field, $type, $default },)*
         }
     };
@@ -264,6 +271,7 @@ macro_rules! impl_set_system_param {
                     ));
                 }
             };
+            validate_cross_params(params)?;
             Ok(())
         }
     };
@@ -314,6 +322,14 @@ impl ValidateOnSet for OverrideValidateOnSet {
         Self::expect_range(*v, 1..)
     }
 
+    fn adaptive_checkpoint_frequency_min(v: &u64) -> Result<()> {
+        Self::expect_range(*v, 1..)
+    }
+
+    fn adaptive_checkpoint_frequency_max(v: &u64) -> Result<()> {
+        Self::expect_range(*v, 1..)
+    }
+
     fn backup_storage_directory(_v: &String) -> Result<()> {
         // TODO
         Ok(())
@@ -325,6 +341,25 @@ impl ValidateOnSet for OverrideValidateOnSet {
     }
 }
 
+/// Check rules that span multiple fields, after one of them has been changed.
+fn validate_cross_params(params: &SystemParams) -> Result<()> {
+    if let (Some(min), Some(max)) = (
+        params.adaptive_checkpoint_frequency_min,
+        params.adaptive_checkpoint_frequency_max,
+    ) {
+        if min > max {
+            return Err(format!(
+                "{} ({}) must not exceed {} ({})",
+                key_of!(adaptive_checkpoint_frequency_min),
+                min,
+                key_of!(adaptive_checkpoint_frequency_max),
+                max
+            ));
+        }
+    }
+    Ok(())
+}
+
 for_all_undeprecated_params!(impl_default_from_other_params);
 
 struct OverrideFromParams;
@@ -348,6 +383,13 @@ mod tests {
             (BACKUP_STORAGE_URL_KEY, "a"),
             (BACKUP_STORAGE_DIRECTORY_KEY, "a"),
             (TELEMETRY_ENABLED_KEY, "false"),
+            (RESOURCE_LABEL_KEY, "a"),
+            (BACKUP_AUTO_INTERVAL_SEC_KEY, "0"),
+            (BACKUP_RETENTION_COUNT_KEY, "0"),
+            (BACKUP_RETENTION_SEC_KEY, "0"),
+            (ADAPTIVE_CHECKPOINT_LATENCY_THRESHOLD_MS_KEY, "0"),
+            (ADAPTIVE_CHECKPOINT_FREQUENCY_MIN_KEY, "10"),
+            (ADAPTIVE_CHECKPOINT_FREQUENCY_MAX_KEY, "100"),
         ];
 
         // To kv - missing field.
@@ -383,5 +425,25 @@ mod tests {
             set_system_param(&mut p, CHECKPOINT_FREQUENCY_KEY, Some("500".to_string())).is_ok()
         );
         assert_eq!(p.checkpoint_frequency, Some(500));
+        // The adaptive checkpoint frequency bounds must form a valid range.
+        assert!(set_system_param(
+            &mut p,
+            ADAPTIVE_CHECKPOINT_FREQUENCY_MIN_KEY,
+            Some("10".to_string())
+        )
+        .is_ok());
+        assert!(set_system_param(
+            &mut p,
+            ADAPTIVE_CHECKPOINT_FREQUENCY_MAX_KEY,
+            Some("5".to_string())
+        )
+        .is_err());
+        assert!(set_system_param(
+            &mut p,
+            ADAPTIVE_CHECKPOINT_FREQUENCY_MAX_KEY,
+            Some("20".to_string())
+        )
+        .is_ok());
+        assert_eq!(p.adaptive_checkpoint_frequency_max, Some(20));
     }
 }
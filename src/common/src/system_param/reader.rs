@@ -72,6 +72,48 @@ impl SystemParamsReader {
         self.prost.telemetry_enabled.unwrap()
     }
 
+    /// The label attached to outgoing IO requests (S3 user agent, Kafka client id) for cost and
+    /// quota attribution. An empty string disables the feature.
+    ///
+    /// The parameter may be missing when talking to a meta service of an older version, hence
+    /// the fallback instead of an unwrap.
+    pub fn resource_label(&self) -> &str {
+        self.prost.resource_label.as_deref().unwrap_or_default()
+    }
+
+    /// Interval of automatic meta backups in seconds. Zero disables automatic backups.
+    pub fn backup_auto_interval_sec(&self) -> u64 {
+        self.prost.backup_auto_interval_sec.unwrap_or_default()
+    }
+
+    /// Number of meta snapshots to retain. Zero disables count-based pruning.
+    pub fn backup_retention_count(&self) -> u64 {
+        self.prost.backup_retention_count.unwrap_or_default()
+    }
+
+    /// Age in seconds beyond which meta snapshots are pruned. Zero disables age-based pruning.
+    pub fn backup_retention_sec(&self) -> u64 {
+        self.prost.backup_retention_sec.unwrap_or_default()
+    }
+
+    /// Barrier latency above which the checkpoint frequency is adaptively increased, in
+    /// milliseconds. Zero disables adaptive checkpointing.
+    pub fn adaptive_checkpoint_latency_threshold_ms(&self) -> u64 {
+        self.prost
+            .adaptive_checkpoint_latency_threshold_ms
+            .unwrap_or_default()
+    }
+
+    /// Lower bound of the adaptively adjusted checkpoint frequency.
+    pub fn adaptive_checkpoint_frequency_min(&self) -> u64 {
+        self.prost.adaptive_checkpoint_frequency_min.unwrap_or(10)
+    }
+
+    /// Upper bound of the adaptively adjusted checkpoint frequency.
+    pub fn adaptive_checkpoint_frequency_max(&self) -> u64 {
+        self.prost.adaptive_checkpoint_frequency_max.unwrap_or(100)
+    }
+
     pub fn to_kv(&self) -> Vec<(String, String)> {
         system_params_to_kv(&self.prost).unwrap()
     }
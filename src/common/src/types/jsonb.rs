@@ -346,6 +346,49 @@ impl<'a> JsonbRef<'a> {
             _ => Err(format!("cannot deconstruct a jsonb {}", self.type_name())),
         }
     }
+
+    /// Returns whether this jsonb contains `other`, following the PostgreSQL `@>` semantics:
+    /// an object contains another object if it has all its key-value pairs (recursively), an
+    /// array contains another array if every element of the latter is contained in some
+    /// element of the former, and, as a special top-level exception, an array contains a
+    /// primitive value that equals one of its elements.
+    pub fn contains(&self, other: &JsonbRef<'_>) -> bool {
+        match (self.0, other.0) {
+            (Value::Array(left), right) if !right.is_array() && !right.is_object() => {
+                left.iter().any(|v| v == right)
+            }
+            _ => value_contains(self.0, other.0),
+        }
+    }
+
+    /// Returns whether the string exists as a top-level object key or array element within
+    /// this jsonb, or equals it if this is a string, following the PostgreSQL `?` semantics.
+    pub fn exists(&self, key: &str) -> bool {
+        match self.0 {
+            Value::Object(object) => object.contains_key(key),
+            Value::Array(array) => array.iter().any(|v| v.as_str() == Some(key)),
+            Value::String(string) => string == key,
+            _ => false,
+        }
+    }
+
+    /// Avoid this function (or `impl AsRef<Value>`) which is a leak of abstraction.
+    /// In most cases you would be using the dedicated accessors above.
+    pub fn as_serde(self) -> &'a Value {
+        self.0
+    }
+}
+
+fn value_contains(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => right
+            .iter()
+            .all(|(key, value)| left.get(key).map_or(false, |v| value_contains(v, value))),
+        (Value::Array(left), Value::Array(right)) => right
+            .iter()
+            .all(|value| left.iter().any(|v| value_contains(v, value))),
+        _ => left == right,
+    }
 }
 
 /// A custom implementation for [`serde_json::ser::Formatter`] to match PostgreSQL, which adds extra
@@ -0,0 +1,37 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature gates for behavior changes of streaming expression evaluation.
+//!
+//! When a fix to an expression changes the results it produces, the new behavior is guarded
+//! by a gate listed here instead of being applied unconditionally. The gates enabled at
+//! creation time are recorded in the stream environment of each streaming job, so that jobs
+//! created before an upgrade keep evaluating expressions the old way while newly created
+//! jobs pick up the fix. This avoids silently changing the contents of existing
+//! materialized views across versions.
+
+/// `proctime()` returns `timestamptz` instead of a naive `timestamp`.
+pub const PROCTIME_TIMESTAMPTZ: &str = "proctime_timestamptz";
+
+/// The expression feature gates enabled for newly created streaming jobs.
+///
+/// Gates are never removed from this list as long as the old behavior is still supported,
+/// since jobs restored from a meta snapshot may predate any of them.
+pub const CURRENT_EXPR_FEATURE_GATES: &[&str] = &[PROCTIME_TIMESTAMPTZ];
+
+/// Returns whether the given gate was enabled when a job was created, based on the gates
+/// recorded in its stream environment.
+pub fn is_enabled(recorded_gates: &[String], gate: &str) -> bool {
+    recorded_gates.iter().any(|recorded| recorded == gate)
+}
@@ -26,6 +26,7 @@ pub mod compress;
 pub mod deployment;
 pub mod env_var;
 pub mod epoch;
+pub mod expr_feature_gate;
 mod future_utils;
 pub mod hash_util;
 pub mod iter_util;
@@ -34,6 +35,7 @@ pub mod panic;
 pub mod pretty_bytes;
 pub mod prost;
 pub mod resource_util;
+pub mod retry;
 pub mod row_id;
 pub mod row_serde;
 pub mod runtime;
@@ -0,0 +1,298 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A shared retry/backoff policy for calls to external services (object store, connector node,
+//! schema registry, cloud APIs), replacing the ad-hoc retry loops of the individual clients so
+//! that retry behavior can be tuned consistently via [`RetryConfig`].
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use rand::Rng;
+use tokio::time::Instant;
+
+use crate::config::RetryConfig;
+
+/// The fraction of a retry token deposited back into the budget by a successful call.
+const BUDGET_DEPOSIT_PER_SUCCESS: f64 = 0.1;
+
+/// The error returned by [`RetryPolicy::retry`].
+#[derive(Debug, thiserror::Error)]
+pub enum RetryError<E> {
+    /// The call failed and the policy gave up retrying, either because the error is not
+    /// retryable, the attempts were exhausted, or the retry budget ran out.
+    #[error(transparent)]
+    Inner(E),
+    /// The circuit breaker is open due to recent consecutive failures, the call was not
+    /// attempted.
+    #[error("circuit breaker is open due to consecutive failures against the service")]
+    CircuitOpen,
+    /// The last attempt exceeded the per-attempt deadline.
+    #[error("deadline elapsed while calling the service")]
+    DeadlineElapsed,
+}
+
+/// A retry/backoff policy with jittered exponential backoff, an optional per-attempt deadline,
+/// a retry budget and a circuit breaker, built from a [`RetryConfig`].
+///
+/// The budget and the breaker are stateful and shared between all clones of a policy, so a
+/// client should create one policy per external service and reuse it for all calls.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    initial_interval: Duration,
+    max_delay: Duration,
+    max_attempts: usize,
+    deadline: Option<Duration>,
+    budget_capacity: f64,
+    circuit_break_threshold: u32,
+    circuit_break_cooldown: Duration,
+    state: Arc<Mutex<RetryState>>,
+}
+
+#[derive(Debug)]
+struct RetryState {
+    /// Remaining retry tokens. Each retry consumes one, each successful call deposits
+    /// [`BUDGET_DEPOSIT_PER_SUCCESS`].
+    budget: f64,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl RetryPolicy {
+    pub fn new(config: &RetryConfig) -> Self {
+        Self {
+            initial_interval: Duration::from_millis(config.initial_interval_ms),
+            max_delay: Duration::from_millis(config.max_delay_ms),
+            max_attempts: config.max_attempts,
+            deadline: (config.deadline_ms != 0).then(|| Duration::from_millis(config.deadline_ms)),
+            budget_capacity: config.retry_budget as f64,
+            circuit_break_threshold: config.circuit_break_threshold,
+            circuit_break_cooldown: Duration::from_millis(config.circuit_break_cooldown_ms),
+            state: Arc::new(Mutex::new(RetryState {
+                budget: config.retry_budget as f64,
+                consecutive_failures: 0,
+                open_until: None,
+            })),
+        }
+    }
+
+    /// The jittered exponential backoff intervals of this policy, one per retry. For call sites
+    /// that drive their own retry loop; [`Self::retry`] is preferred.
+    pub fn backoff(&self) -> impl Iterator<Item = Duration> {
+        let max_delay = self.max_delay;
+        let mut delay = self.initial_interval;
+        std::iter::repeat_with(move || {
+            let current = delay;
+            delay = (delay * 2).min(max_delay);
+            jitter(current)
+        })
+        .take(self.max_attempts)
+    }
+
+    /// Call `f`, retrying with backoff as long as `should_retry` returns `true` for the error
+    /// and the policy still allows a retry. An attempt exceeding the per-attempt deadline (if
+    /// configured) counts as a retryable failure.
+    pub async fn retry<F, Fut, T, E>(
+        &self,
+        mut f: F,
+        mut should_retry: impl FnMut(&E) -> bool,
+    ) -> Result<T, RetryError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if self.is_open() {
+            return Err(RetryError::CircuitOpen);
+        }
+
+        let mut backoff = self.backoff();
+        loop {
+            let result = match self.deadline {
+                Some(deadline) => match tokio::time::timeout(deadline, f()).await {
+                    Ok(result) => result.map_err(RetryError::Inner),
+                    Err(_elapsed) => Err(RetryError::DeadlineElapsed),
+                },
+                None => f().await.map_err(RetryError::Inner),
+            };
+
+            let error = match result {
+                Ok(v) => {
+                    self.on_success();
+                    return Ok(v);
+                }
+                Err(e) => e,
+            };
+            self.on_failure();
+
+            if let RetryError::Inner(e) = &error && !should_retry(e) {
+                return Err(error);
+            }
+            let Some(delay) = backoff.next() else {
+                return Err(error);
+            };
+            if !self.try_withdraw_retry_token() || self.is_open() {
+                return Err(error);
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Whether the circuit is currently open, i.e. calls would fail fast.
+    pub fn is_open(&self) -> bool {
+        let mut state = self.state.lock();
+        match state.open_until {
+            Some(open_until) if Instant::now() < open_until => true,
+            Some(_) => {
+                // Cooldown over: half-open, let the next call probe the service.
+                state.open_until = None;
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn on_success(&self) {
+        let mut state = self.state.lock();
+        state.consecutive_failures = 0;
+        state.open_until = None;
+        if self.budget_capacity != 0.0 {
+            state.budget = (state.budget + BUDGET_DEPOSIT_PER_SUCCESS).min(self.budget_capacity);
+        }
+    }
+
+    fn on_failure(&self) {
+        let mut state = self.state.lock();
+        state.consecutive_failures += 1;
+        if self.circuit_break_threshold != 0
+            && state.consecutive_failures >= self.circuit_break_threshold
+        {
+            state.open_until = Some(Instant::now() + self.circuit_break_cooldown);
+        }
+    }
+
+    fn try_withdraw_retry_token(&self) -> bool {
+        if self.budget_capacity == 0.0 {
+            return true;
+        }
+        let mut state = self.state.lock();
+        if state.budget >= 1.0 {
+            state.budget -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(&RetryConfig::default())
+    }
+}
+
+/// Equal jitter: keep half of the interval and randomize the other half, so that concurrent
+/// retries do not synchronize while the backoff still grows exponentially.
+fn jitter(duration: Duration) -> Duration {
+    duration / 2 + duration.mul_f64(rand::thread_rng().gen_range(0.0..0.5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RetryConfig;
+
+    fn test_config() -> RetryConfig {
+        RetryConfig {
+            initial_interval_ms: 1,
+            max_delay_ms: 2,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let policy = RetryPolicy::new(&test_config());
+        let mut attempts = 0;
+        let result = policy
+            .retry(
+                || {
+                    attempts += 1;
+                    let failed = attempts < 3;
+                    async move { if failed { Err("transient") } else { Ok(42) } }
+                },
+                |_| true,
+            )
+            .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_permanent_error() {
+        let policy = RetryPolicy::new(&test_config());
+        let mut attempts = 0;
+        let result: Result<(), _> = policy
+            .retry(
+                || {
+                    attempts += 1;
+                    async { Err("permanent") }
+                },
+                |_| false,
+            )
+            .await;
+        assert!(matches!(result, Err(RetryError::Inner("permanent"))));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_exhausted() {
+        let config = RetryConfig {
+            retry_budget: 2,
+            max_attempts: 100,
+            ..test_config()
+        };
+        let policy = RetryPolicy::new(&config);
+        let mut attempts = 0;
+        let result: Result<(), _> = policy
+            .retry(
+                || {
+                    attempts += 1;
+                    async { Err("transient") }
+                },
+                |_| true,
+            )
+            .await;
+        assert!(result.is_err());
+        // the initial attempt plus one retry per budget token
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_opens() {
+        let config = RetryConfig {
+            circuit_break_threshold: 2,
+            circuit_break_cooldown_ms: 60_000,
+            ..test_config()
+        };
+        let policy = RetryPolicy::new(&config);
+        let result: Result<(), _> = policy.retry(|| async { Err("down") }, |_| true).await;
+        assert!(result.is_err());
+        assert!(policy.is_open());
+
+        let result: Result<(), _> = policy.retry(|| async { Ok(()) }, |_| true).await;
+        assert!(matches!(result, Err(RetryError::CircuitOpen)));
+    }
+}
@@ -92,6 +92,10 @@ fn visit_stream_node_tables_inner<F>(
                 always!(node.left_table, "DynamicFilterLeft");
                 always!(node.right_table, "DynamicFilterRight");
             }
+            NodeBody::BandJoin(node) => {
+                always!(node.left_table, "BandJoinLeft");
+                always!(node.right_table, "BandJoinRight");
+            }
 
             // Aggregation
             NodeBody::HashAgg(node) => {
@@ -178,6 +182,11 @@ fn visit_stream_node_tables_inner<F>(
                 always!(node.state_table, "Sort");
             }
 
+            // Session window
+            NodeBody::SessionWindow(node) => {
+                always!(node.state_table, "SessionWindow");
+            }
+
             // Chain
             NodeBody::Chain(node) => {
                 optional!(node.state_table, "Chain")
@@ -20,6 +20,27 @@ use crate::hash::ParallelUnitId;
 
 pub type WorkerNodeId = u32;
 
+/// Restrict `workers` to the dedicated serving nodes, i.e. those serving batch queries but not
+/// running any streaming actors, if at least one is present. Otherwise return the workers
+/// unchanged, so that batch queries keep sharing the streaming nodes.
+///
+/// This way, deploying dedicated read replicas automatically isolates heavy ad-hoc scans from
+/// the latency-critical streaming nodes.
+pub fn prefer_dedicated_serving_nodes(workers: Vec<WorkerNode>) -> Vec<WorkerNode> {
+    let is_dedicated = |worker: &WorkerNode| {
+        worker
+            .property
+            .as_ref()
+            .map_or(false, |p| p.is_serving && !p.is_streaming)
+    };
+
+    if workers.iter().any(is_dedicated) {
+        workers.into_iter().filter(|w| is_dedicated(w)).collect()
+    } else {
+        workers
+    }
+}
+
 pub fn get_pu_to_worker_mapping(nodes: &[WorkerNode]) -> HashMap<ParallelUnitId, WorkerNode> {
     let mut pu_to_worker = HashMap::new();
 
@@ -196,6 +196,7 @@ mod tests {
             is_unschedulable: false,
             is_serving: true,
             is_streaming: false,
+            resource_group: Default::default(),
         };
         let mut gen_pus_for_worker =
             |worker_node_id: u32, number: u32, pu_to_worker: &mut HashMap<ParallelUnitId, u32>| {
@@ -14,7 +14,8 @@
 
 use risingwave_common::system_param::local_manager::LocalSystemParamsManagerRef;
 use risingwave_common_service::observer_manager::{ObserverState, SubscribeCompute};
-use risingwave_pb::meta::subscribe_response::Info;
+use risingwave_connector::secret;
+use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::SubscribeResponse;
 
 pub struct ComputeObserverNode {
@@ -31,13 +32,27 @@ impl ObserverState for ComputeObserverNode {
 
         match info.to_owned() {
             Info::SystemParams(p) => self.system_params_manager.try_set_params(p),
+            Info::Secret(s) => match resp.operation() {
+                // `Update` is credential rotation: overwrite the stored value so that connectors
+                // built from now on pick up the new credential.
+                Operation::Add | Operation::Update => secret::insert_secret(s.id, s.value),
+                Operation::Delete => secret::remove_secret(s.id),
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
             _ => {
                 panic!("error type notification");
             }
         }
     }
 
-    fn handle_initialization_notification(&mut self, _resp: SubscribeResponse) {}
+    fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
+        let Some(Info::Snapshot(snapshot)) = resp.info else {
+            unreachable!();
+        };
+        for s in snapshot.secrets {
+            secret::insert_secret(s.id, s.value);
+        }
+    }
 }
 
 impl ComputeObserverNode {
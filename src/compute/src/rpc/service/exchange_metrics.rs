@@ -18,6 +18,7 @@ use prometheus::{register_int_counter_vec_with_registry, Registry};
 pub struct ExchangeServiceMetrics {
     pub registry: Registry,
     pub stream_fragment_exchange_bytes: GenericCounterVec<AtomicU64>,
+    pub stream_fragment_exchange_split_count: GenericCounterVec<AtomicU64>,
     pub actor_sampled_serialize_duration_ns: GenericCounterVec<AtomicU64>,
 }
 
@@ -31,6 +32,14 @@ impl ExchangeServiceMetrics {
         )
         .unwrap();
 
+        let stream_fragment_exchange_split_count = register_int_counter_vec_with_registry!(
+            "stream_exchange_frag_split_count",
+            "Number of oversized chunks that have been split before sending to downstream Fragment",
+            &["up_fragment_id", "down_fragment_id"],
+            registry
+        )
+        .unwrap();
+
         let actor_sampled_serialize_duration_ns = register_int_counter_vec_with_registry!(
             "actor_sampled_serialize_duration_ns",
             "Duration (ns) of sampled chunk serialization",
@@ -42,6 +51,7 @@ impl ExchangeServiceMetrics {
         Self {
             registry,
             stream_fragment_exchange_bytes,
+            stream_fragment_exchange_split_count,
             actor_sampled_serialize_duration_ns,
         }
     }
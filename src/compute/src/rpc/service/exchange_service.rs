@@ -35,6 +35,11 @@ use crate::rpc::service::exchange_metrics::ExchangeServiceMetrics;
 /// Buffer size of the receiver of the remote channel.
 const BATCH_EXCHANGE_BUFFER_SIZE: usize = 1024;
 
+/// Maximum encoded size of a single `GetStreamResponse`. Chunks that serialize larger than this
+/// are split into row-aligned pieces below this size, keeping each response well within tonic's
+/// default 4 MiB message limit even with uneven row sizes.
+const STREAM_EXCHANGE_SPLIT_BYTES: usize = 1 << 21; // 2 MiB
+
 #[derive(Clone)]
 pub struct ExchangeServiceImpl {
     batch_mgr: Arc<BatchManager>,
@@ -180,19 +185,51 @@ impl ExchangeServiceImpl {
                     };
                     rr += 1;
 
-                    // forward the acquired permit to the downstream
-                    let response = GetStreamResponse {
-                        message: Some(proto),
-                        permits: Some(PbPermits { value: permits }),
+                    // Split an oversized chunk at row boundaries, so that wide-row workloads do
+                    // not exceed the gRPC message limit. Each piece is a complete chunk on its
+                    // own (update pairs are kept together), so the downstream consumes them
+                    // without reassembly.
+                    let encoded_len = Message::get_encoded_len(&proto);
+                    let protos = match &message {
+                        Message::Chunk(chunk)
+                            if encoded_len > STREAM_EXCHANGE_SPLIT_BYTES
+                                && chunk.cardinality() > 1 =>
+                        {
+                            let pieces = (encoded_len + STREAM_EXCHANGE_SPLIT_BYTES - 1)
+                                / STREAM_EXCHANGE_SPLIT_BYTES;
+                            let max_chunk_size =
+                                ((chunk.cardinality() + pieces - 1) / pieces).max(2);
+                            let split = chunk.split(max_chunk_size);
+                            metrics
+                                .stream_fragment_exchange_split_count
+                                .with_label_values(&[&up_fragment_id, &down_fragment_id])
+                                .inc();
+                            split
+                                .into_iter()
+                                .map(|chunk| Message::Chunk(chunk).to_protobuf())
+                                .collect()
+                        }
+                        _ => vec![proto],
                     };
-                    let bytes = Message::get_encoded_len(&response);
 
-                    yield response;
+                    // Forward the acquired permits to the downstream only with the last piece,
+                    // so that permit accounting is unaffected by splitting.
+                    let last = protos.len() - 1;
+                    for (idx, proto) in protos.into_iter().enumerate() {
+                        let value = if idx == last { permits.clone() } else { None };
+                        let response = GetStreamResponse {
+                            message: Some(proto),
+                            permits: Some(PbPermits { value }),
+                        };
+                        let bytes = Message::get_encoded_len(&response);
+
+                        yield response;
 
-                    metrics
-                        .stream_fragment_exchange_bytes
-                        .with_label_values(&[&up_fragment_id, &down_fragment_id])
-                        .inc_by(bytes as u64);
+                        metrics
+                            .stream_fragment_exchange_bytes
+                            .with_label_values(&[&up_fragment_id, &down_fragment_id])
+                            .inc_by(bytes as u64);
+                    }
                 }
             }
         }
@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    GetStreamStatsRequest, GetStreamStatsResponse, GetTableReadStatsRequest,
+    GetTableReadStatsResponse, HeapProfilingRequest, HeapProfilingResponse, ListFailpointsRequest,
+    ListFailpointsResponse, ProfilingRequest, ProfilingResponse, SetFailpointRequest,
+    SetFailpointResponse, StackTraceRequest, StackTraceResponse, StreamActorStats,
+    StreamExecutorStats, TableReadStats,
 };
+use risingwave_stream::executor::monitor::StreamingMetrics;
 use risingwave_stream::task::LocalStreamManager;
 use tonic::{Request, Response, Status};
 
@@ -26,16 +32,19 @@ use tonic::{Request, Response, Status};
 pub struct MonitorServiceImpl {
     stream_mgr: Arc<LocalStreamManager>,
     grpc_await_tree_reg: Option<AwaitTreeRegistryRef>,
+    streaming_metrics: Arc<StreamingMetrics>,
 }
 
 impl MonitorServiceImpl {
     pub fn new(
         stream_mgr: Arc<LocalStreamManager>,
         grpc_await_tree_reg: Option<AwaitTreeRegistryRef>,
+        streaming_metrics: Arc<StreamingMetrics>,
     ) -> Self {
         Self {
             stream_mgr,
             grpc_await_tree_reg,
+            streaming_metrics,
         }
     }
 }
@@ -104,6 +113,187 @@ impl MonitorService for MonitorServiceImpl {
             }
         }
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn heap_profiling(
+        &self,
+        request: Request<HeapProfilingRequest>,
+    ) -> Result<Response<HeapProfilingResponse>, Status> {
+        use std::ffi::CString;
+        use std::path::PathBuf;
+
+        let enabled = tikv_jemalloc_ctl::opt::prof::read()
+            .map_err(|e| Status::internal(format!("failed to read jemalloc opt.prof: {}", e)))?;
+        if !enabled {
+            return Err(Status::failed_precondition(
+                "heap profiling is not activated; start the node with `MALLOC_CONF=prof:true`",
+            ));
+        }
+
+        let dir = request.into_inner().dir;
+        let dir = if dir.is_empty() {
+            std::env::temp_dir()
+        } else {
+            PathBuf::from(dir)
+        };
+        let path = dir.join(format!(
+            "heap-profile-{}.dump",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        ));
+        let path_str = path.to_string_lossy().into_owned();
+        let path_c = CString::new(path_str.clone())
+            .map_err(|e| Status::invalid_argument(format!("invalid dump path: {}", e)))?;
+        // The pointer is read synchronously by the `prof.dump` mallctl, so `path_c` outliving
+        // the call is all that's required.
+        unsafe { tikv_jemalloc_ctl::raw::write(b"prof.dump\0", path_c.as_ptr()) }
+            .map_err(|e| Status::internal(format!("failed to dump heap profile: {}", e)))?;
+        Ok(Response::new(HeapProfilingResponse { path: path_str }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_stream_stats(
+        &self,
+        request: Request<GetStreamStatsRequest>,
+    ) -> Result<Response<GetStreamStatsResponse>, Status> {
+        let _req = request.into_inner();
+
+        let mut executor_stats = Vec::new();
+        let mut actor_stats = Vec::new();
+
+        // The per-executor and per-actor counters are maintained in the metrics registry, so we
+        // collect them by gathering the relevant metric families.
+        for family in self.streaming_metrics.registry.gather() {
+            match family.get_name() {
+                "stream_executor_row_count" => {
+                    for metric in family.get_metric() {
+                        let mut actor_id = 0;
+                        let mut executor_identity = String::new();
+                        for label in metric.get_label() {
+                            match label.get_name() {
+                                "actor_id" => actor_id = label.get_value().parse().unwrap_or(0),
+                                "executor_identity" => {
+                                    executor_identity = label.get_value().to_string()
+                                }
+                                _ => {}
+                            }
+                        }
+                        executor_stats.push(StreamExecutorStats {
+                            actor_id,
+                            executor_identity,
+                            output_row_count: metric.get_counter().get_value() as u64,
+                        });
+                    }
+                }
+                "stream_actor_actor_execution_time" => {
+                    for metric in family.get_metric() {
+                        let mut actor_id = 0;
+                        for label in metric.get_label() {
+                            if label.get_name() == "actor_id" {
+                                actor_id = label.get_value().parse().unwrap_or(0);
+                            }
+                        }
+                        actor_stats.push(StreamActorStats {
+                            actor_id,
+                            execution_time_s: metric.get_gauge().get_value(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Response::new(GetStreamStatsResponse {
+            executor_stats,
+            actor_stats,
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_table_read_stats(
+        &self,
+        request: Request<GetTableReadStatsRequest>,
+    ) -> Result<Response<GetTableReadStatsResponse>, Status> {
+        let _req = request.into_inner();
+
+        let mut stats: BTreeMap<u32, TableReadStats> = BTreeMap::new();
+
+        // The state store metrics are registered in the same registry as the streaming ones, so
+        // we can collect them by gathering the relevant metric families.
+        for family in self.streaming_metrics.registry.gather() {
+            match family.get_name() {
+                "state_store_sst_store_block_request_counts" => {
+                    for metric in family.get_metric() {
+                        let mut table_id = 0;
+                        let mut r#type = "";
+                        for label in metric.get_label() {
+                            match label.get_name() {
+                                "table_id" => table_id = label.get_value().parse().unwrap_or(0),
+                                "type" => r#type = label.get_value(),
+                                _ => {}
+                            }
+                        }
+                        let count = metric.get_counter().get_value() as u64;
+                        let entry = stats.entry(table_id).or_insert_with(|| TableReadStats {
+                            table_id,
+                            ..Default::default()
+                        });
+                        match r#type {
+                            "data_total" => entry.block_cache_total_count += count,
+                            "data_miss" => entry.block_cache_miss_count += count,
+                            _ => {}
+                        }
+                    }
+                }
+                "state_store_remote_read_time_per_task" => {
+                    for metric in family.get_metric() {
+                        let mut table_id = 0;
+                        for label in metric.get_label() {
+                            if label.get_name() == "table_id" {
+                                table_id = label.get_value().parse().unwrap_or(0);
+                            }
+                        }
+                        let entry = stats.entry(table_id).or_insert_with(|| TableReadStats {
+                            table_id,
+                            ..Default::default()
+                        });
+                        entry.remote_io_count += metric.get_histogram().get_sample_count();
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(Response::new(GetTableReadStatsResponse {
+            table_stats: stats.into_values().collect(),
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_failpoints(
+        &self,
+        request: Request<ListFailpointsRequest>,
+    ) -> Result<Response<ListFailpointsResponse>, Status> {
+        let _req = request.into_inner();
+        Ok(Response::new(ListFailpointsResponse {
+            failpoints: fail::list().into_iter().collect(),
+        }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn set_failpoint(
+        &self,
+        request: Request<SetFailpointRequest>,
+    ) -> Result<Response<SetFailpointResponse>, Status> {
+        let req = request.into_inner();
+        if req.actions.is_empty() {
+            fail::remove(&req.name);
+        } else {
+            fail::cfg(req.name, &req.actions).map_err(Status::invalid_argument)?;
+        }
+        Ok(Response::new(SetFailpointResponse::default()))
+    }
 }
 
 pub use grpc_middleware::*;
@@ -177,12 +177,29 @@ impl StreamService for StreamServiceImpl {
         request: Request<BarrierCompleteRequest>,
     ) -> Result<Response<BarrierCompleteResponse>, Status> {
         let req = request.into_inner();
-        let (collect_result, checkpoint) = self
+        let (collect_result, checkpoint) = match self
             .mgr
             .collect_barrier(req.prev_epoch)
             .instrument_await(format!("collect_barrier (epoch {})", req.prev_epoch))
             .await
-            .inspect_err(|err| tracing::error!("failed to collect barrier: {}", err))?;
+        {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("failed to collect barrier: {}", err);
+                let failed_actors = self.mgr.failed_actors();
+                if !failed_actors.is_empty() {
+                    // Report the failed actors in a structured way, so that the meta service
+                    // can attribute the failure to the owning streaming jobs.
+                    return Ok(Response::new(BarrierCompleteResponse {
+                        request_id: req.request_id,
+                        worker_id: self.env.worker_id(),
+                        failed_actors,
+                        ..Default::default()
+                    }));
+                }
+                return Err(err.into());
+            }
+        };
         // Must finish syncing data written in the epoch before respond back to ensure persistence
         // of the state.
         let synced_sstables = if checkpoint {
@@ -203,6 +220,7 @@ impl StreamService for StreamServiceImpl {
             request_id: req.request_id,
             status: None,
             create_mview_progress: collect_result.create_mview_progress,
+            actor_collect_latency: collect_result.actor_collect_latency,
             synced_sstables: synced_sstables
                 .into_iter()
                 .map(
@@ -218,6 +236,7 @@ impl StreamService for StreamServiceImpl {
                 )
                 .collect_vec(),
             worker_id: self.env.worker_id(),
+            failed_actors: vec![],
         }))
     }
 
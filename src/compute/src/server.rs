@@ -14,7 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use risingwave_batch::monitor::{BatchExecutorMetrics, BatchManagerMetrics, BatchTaskMetrics};
 use risingwave_batch::rpc::service::task_service::BatchServiceImpl;
@@ -44,10 +44,11 @@ use risingwave_pb::task_service::exchange_service_server::ExchangeServiceServer;
 use risingwave_pb::task_service::task_service_server::TaskServiceServer;
 use risingwave_rpc_client::{ComputeClientPool, ExtraInfoSourceRef, MetaClient};
 use risingwave_source::dml_manager::DmlManager;
+use risingwave_storage::hummock::cache_recovery::CacheRecoveryIndex;
 use risingwave_storage::hummock::compactor::{CompactionExecutor, Compactor, CompactorContext};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
-    HummockMemoryCollector, MemoryLimiter, TieredCacheMetricsBuilder,
+    HummockMemoryCollector, MemoryLimiter, SstableStoreRef, TieredCacheMetricsBuilder,
 };
 use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, HummockMetrics, HummockStateStoreMetrics,
@@ -207,6 +208,8 @@ pub async fn compute_node_serve(
     observer_manager.start().await;
 
     let mut extra_info_sources: Vec<ExtraInfoSourceRef> = vec![];
+    let cache_recovery_dir = config.storage.cache_recovery_dir.clone();
+    let mut cache_recovery_store: Option<SstableStoreRef> = None;
     if let Some(storage) = state_store.as_hummock_trait() {
         extra_info_sources.push(storage.sstable_object_id_manager().clone());
         if embedded_compactor_enabled {
@@ -246,6 +249,32 @@ pub async fn compute_node_serve(
                 .watch_config_change(system_params_mgr.watch_params())
                 .await;
         });
+
+        if !cache_recovery_dir.is_empty() {
+            // Rehydrate the caches from the index persisted by the last graceful shutdown, off
+            // the critical path of serving.
+            let sstable_store = storage.sstable_store();
+            cache_recovery_store = Some(sstable_store.clone());
+            let dir = cache_recovery_dir.clone();
+            tokio::spawn(async move {
+                match CacheRecoveryIndex::load(&dir).await {
+                    Ok(Some(index)) => {
+                        let block_count = index.block_count();
+                        let start = Instant::now();
+                        match index.rehydrate(&sstable_store).await {
+                            Ok(()) => tracing::info!(
+                                "Rehydrated {} cached blocks in {:?}",
+                                block_count,
+                                start.elapsed()
+                            ),
+                            Err(e) => tracing::warn!("Failed to rehydrate caches: {:?}", e),
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!("Failed to load cache recovery index: {:?}", e),
+                }
+            });
+        }
     }
 
     sub_tasks.push(MetaClient::start_heartbeat_loop(
@@ -358,7 +387,11 @@ pub async fn compute_node_serve(
     let exchange_srv =
         ExchangeServiceImpl::new(batch_mgr.clone(), stream_mgr.clone(), exchange_srv_metrics);
     let stream_srv = StreamServiceImpl::new(stream_mgr.clone(), stream_env.clone());
-    let monitor_srv = MonitorServiceImpl::new(stream_mgr.clone(), grpc_await_tree_reg.clone());
+    let monitor_srv = MonitorServiceImpl::new(
+        stream_mgr.clone(),
+        grpc_await_tree_reg.clone(),
+        streaming_metrics.clone(),
+    );
     let config_srv = ConfigServiceImpl::new(batch_mgr, stream_mgr);
     let health_srv = HealthServiceImpl::new();
 
@@ -407,6 +440,14 @@ pub async fn compute_node_serve(
                                 tracing::warn!("Failed to join shutdown: {:?}", err);
                             }
                         }
+                        // Dump the cache index for fast restart after all sub tasks have
+                        // stopped, so that the cache contents are final.
+                        if let Some(sstable_store) = cache_recovery_store {
+                            let index = CacheRecoveryIndex::snapshot(&sstable_store);
+                            if let Err(e) = index.dump(&cache_recovery_dir).await {
+                                tracing::warn!("Failed to dump cache recovery index: {:?}", e);
+                            }
+                        }
                     },
                 }
             })
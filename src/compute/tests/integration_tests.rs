@@ -179,6 +179,7 @@ async fn test_table_materialize() -> StreamResult<()> {
 
     // Create a `DmlExecutor` to accept data change from users.
     let dml_executor = DmlExecutor::new(
+        actor_ctx.clone(),
         Box::new(source_executor),
         all_schema.clone(),
         pk_indices.clone(),
@@ -239,6 +240,7 @@ async fn test_table_materialize() -> StreamResult<()> {
         vec![],
         Some(row_id_index),
         false,
+        1,
     ));
 
     tokio::spawn(async move {
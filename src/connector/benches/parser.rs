@@ -70,7 +70,7 @@ fn create_parser(
         SourceColumnDesc::simple("charlie", DataType::Int64, 2.into()),
         SourceColumnDesc::simple("delta", DataType::Int64, 3.into()),
     ];
-    let parser = JsonParser::new(desc.clone(), Default::default()).unwrap();
+    let parser = JsonParser::new(desc.clone(), Default::default(), Default::default()).unwrap();
     let input = gen_input(mode, chunk_size, chunk_num);
     (parser, desc, input)
 }
@@ -19,6 +19,8 @@ use aws_credential_types::provider::SharedCredentialsProvider;
 use aws_types::region::Region;
 use aws_types::SdkConfig;
 
+use crate::impl_redacted_debug;
+
 /// A flatten cofig map for aws auth.
 pub struct AwsAuthProps {
     pub region: Option<String>,
@@ -31,6 +33,12 @@ pub struct AwsAuthProps {
     pub profile: Option<String>,
 }
 
+impl_redacted_debug!(
+    AwsAuthProps,
+    fields = [region, endpoint, access_key, arn, external_id, profile],
+    secrets = [secret_key, session_token]
+);
+
 impl Default for AwsAuthProps {
     fn default() -> Self {
         Self {
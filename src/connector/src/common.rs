@@ -14,6 +14,7 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
 
 use aws_sdk_kinesis::Client as KinesisClient;
 use rdkafka::ClientConfig;
@@ -22,10 +23,37 @@ use serde_with::json::JsonString;
 use serde_with::serde_as;
 
 use crate::aws_auth::AwsAuthProps;
+use crate::impl_redacted_debug;
 
 // The file describes the common abstractions for each connector and can be used in both source and
 // sink.
 
+/// Placeholder printed in place of a secret value in `Debug` output.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Substrings that mark a free-form property key as carrying a credential.
+const SECRET_KEY_MARKERS: &[&str] = &["secret", "password", "token", "private.key", "private_key"];
+
+/// Debug adapter for a free-form property map that replaces the values of credential-carrying
+/// keys with [`REDACTED`]. Used by configs that keep user properties as an opaque map and thus
+/// cannot mark individual secret fields.
+pub struct RedactedProps<'a>(pub &'a HashMap<String, String>);
+
+impl fmt::Debug for RedactedProps<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(key, value)| {
+                let lower = key.to_lowercase();
+                if SECRET_KEY_MARKERS.iter().any(|m| lower.contains(m)) {
+                    (key, REDACTED)
+                } else {
+                    (key, value.as_str())
+                }
+            }))
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AwsPrivateLinkItem {
     pub az_id: Option<String>,
@@ -33,7 +61,7 @@ pub struct AwsPrivateLinkItem {
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct KafkaCommon {
     #[serde(rename = "properties.bootstrap.server", alias = "kafka.brokers")]
     pub brokers: String,
@@ -104,6 +132,27 @@ pub struct KafkaCommon {
     sasl_oathbearer_config: Option<String>,
 }
 
+impl_redacted_debug!(
+    KafkaCommon,
+    fields = [
+        brokers,
+        broker_rewrite_map,
+        topic,
+        security_protocol,
+        ssl_ca_location,
+        ssl_certificate_location,
+        ssl_key_location,
+        sasl_mechanism,
+        sasl_username,
+        sasl_kerberos_service_name,
+        sasl_kerberos_keytab,
+        sasl_kerberos_principal,
+        sasl_kerberos_kinit_cmd,
+        sasl_kerberos_min_time_before_relogin,
+    ],
+    secrets = [ssl_key_password, sasl_password, sasl_oathbearer_config]
+);
+
 impl KafkaCommon {
     pub(crate) fn set_security_properties(&self, config: &mut ClientConfig) {
         // Security protocol
@@ -169,7 +218,7 @@ impl KafkaCommon {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct KinesisCommon {
     #[serde(rename = "stream", alias = "kinesis.stream.name")]
     pub stream_name: String,
@@ -201,6 +250,19 @@ pub struct KinesisCommon {
     pub assume_role_external_id: Option<String>,
 }
 
+impl_redacted_debug!(
+    KinesisCommon,
+    fields = [
+        stream_name,
+        stream_region,
+        endpoint,
+        credentials_access_key,
+        assume_role_arn,
+        assume_role_external_id,
+    ],
+    secrets = [credentials_secret_access_key, session_token]
+);
+
 impl KinesisCommon {
     pub(crate) async fn build_client(&self) -> anyhow::Result<KinesisClient> {
         let config = AwsAuthProps {
@@ -229,3 +291,24 @@ pub struct UpsertMessage<'a> {
     #[serde(borrow)]
     pub record: Cow<'a, [u8]>,
 }
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashmap;
+
+    use super::*;
+
+    #[test]
+    fn test_redacted_props() {
+        let props = hashmap! {
+            "topic".to_string() => "test".to_string(),
+            "properties.sasl.password".to_string() => "s3cret".to_string(),
+            "aws.credentials.session_token".to_string() => "t0ken".to_string(),
+        };
+        let debug = format!("{:?}", RedactedProps(&props));
+        assert!(debug.contains("\"topic\": \"test\""));
+        assert!(!debug.contains("s3cret"));
+        assert!(!debug.contains("t0ken"));
+        assert!(debug.contains(REDACTED));
+    }
+}
@@ -41,8 +41,11 @@ pub mod error;
 mod macros;
 
 pub mod parser;
+pub mod registry;
+pub mod secret;
 pub mod sink;
 pub mod source;
+pub mod test_kit;
 
 pub mod common;
 
@@ -77,6 +80,21 @@ where
     })
 }
 
+pub(crate) fn deserialize_optional_usize_from_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<usize>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let s: String = de::Deserialize::deserialize(deserializer)?;
+    s.parse().map(Some).map_err(|_| {
+        de::Error::invalid_value(
+            de::Unexpected::Str(&s),
+            &"integer greater than or equal to 0",
+        )
+    })
+}
+
 pub(crate) fn deserialize_bool_from_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: de::Deserializer<'de>,
@@ -20,6 +20,11 @@ macro_rules! impl_split_enumerator {
              pub async fn create(properties: ConnectorProperties) -> Result<Self> {
                 match properties {
                     $( ConnectorProperties::$variant_name(props) => $split_enumerator_name::new(*props).await.map(Self::$variant_name), )*
+                    ConnectorProperties::Custom(props) => {
+                        let factory = $crate::registry::get_source_factory(&props.connector)
+                            .ok_or_else(|| anyhow!("source connector '{}' is not registered", props.connector))?;
+                        factory.create_enumerator(props.props).await.map(Self::Custom)
+                    }
                     other => Err(anyhow!(
                         "split enumerator type for config {:?} is not supported",
                         other
@@ -39,6 +44,11 @@ macro_rules! impl_split_enumerator {
                         })
                         .map_err(|e| ErrorCode::ConnectorError(e.into()).into()),
                     )*
+                    Self::Custom(inner) => inner
+                        .list_splits()
+                        .await
+                        .map(|ss| ss.into_iter().map(SplitImpl::Custom).collect_vec())
+                        .map_err(|e| ErrorCode::ConnectorError(e.into()).into()),
                 }
              }
         }
@@ -52,6 +62,7 @@ macro_rules! impl_split {
             fn from(split: &SplitImpl) -> Self {
                 match split {
                     $( SplitImpl::$variant_name(inner) => ConnectorSplit { split_type: String::from($connector_name), encoded_split: inner.encode_to_bytes().to_vec() }, )*
+                    SplitImpl::Custom(inner) => ConnectorSplit { split_type: inner.connector.clone(), encoded_split: inner.encode_to_bytes().to_vec() },
                 }
             }
         }
@@ -63,7 +74,11 @@ macro_rules! impl_split {
                 match split.split_type.to_lowercase().as_str() {
                     $( $connector_name => <$split>::restore_from_bytes(split.encoded_split.as_ref()).map(SplitImpl::$variant_name), )*
                         other => {
-                    Err(anyhow!("connector '{}' is not supported", other))
+                    if $crate::registry::get_source_factory(other).is_some() {
+                        <$crate::registry::CustomSplit>::restore_from_bytes(split.encoded_split.as_ref()).map(SplitImpl::Custom)
+                    } else {
+                        Err(anyhow!("connector '{}' is not supported", other))
+                    }
                     }
                 }
             }
@@ -73,6 +88,7 @@ macro_rules! impl_split {
             fn id(&self) -> SplitId {
                 match self {
                     $( Self::$variant_name(inner) => inner.id(), )*
+                    Self::Custom(inner) => inner.id(),
                 }
             }
 
@@ -95,6 +111,7 @@ macro_rules! impl_split {
              pub fn get_type(&self) -> String {
                 match self {
                     $( Self::$variant_name(_) => $connector_name, )*
+                    Self::Custom(inner) => return inner.connector.clone(),
                 }
                     .to_string()
             }
@@ -102,12 +119,14 @@ macro_rules! impl_split {
             pub fn update(&self, start_offset: String) -> Self {
                 match self {
                     $( Self::$variant_name(inner) => Self::$variant_name(inner.copy_with_offset(start_offset)), )*
+                    Self::Custom(inner) => Self::Custom(inner.copy_with_offset(start_offset)),
                 }
             }
 
             pub fn encode_to_json_inner(&self) -> JsonbVal {
                 match self {
                     $( Self::$variant_name(inner) => inner.encode_to_json(), )*
+                    Self::Custom(inner) => inner.encode_to_json(),
                 }
             }
 
@@ -115,7 +134,11 @@ macro_rules! impl_split {
                 match split_type.to_lowercase().as_str() {
                     $( $connector_name => <$split>::restore_from_json(value).map(SplitImpl::$variant_name), )*
                         other => {
-                    Err(anyhow!("connector '{}' is not supported", other))
+                    if $crate::registry::get_source_factory(other).is_some() {
+                        <$crate::registry::CustomSplit>::restore_from_json(value).map(SplitImpl::Custom)
+                    } else {
+                        Err(anyhow!("connector '{}' is not supported", other))
+                    }
                     }
                 }
             }
@@ -129,7 +152,9 @@ macro_rules! impl_split_reader {
         impl SplitReaderImpl {
             pub fn into_stream(self) -> BoxSourceWithStateStream {
                 match self {
-                    $( Self::$variant_name(inner) => inner.into_stream(), )*                 }
+                    $( Self::$variant_name(inner) => inner.into_stream(), )*
+                    Self::Custom(inner) => inner.into_stream(),
+                }
             }
 
             pub async fn create(
@@ -145,6 +170,11 @@ macro_rules! impl_split_reader {
                 let splits = state.unwrap();
                 let connector = match config {
                      $( ConnectorProperties::$variant_name(props) => Self::$variant_name(Box::new($split_reader_name::new(*props, splits, parser_config, source_ctx, columns).await?)), )*
+                     ConnectorProperties::Custom(props) => {
+                        let factory = $crate::registry::get_source_factory(&props.connector)
+                            .ok_or_else(|| anyhow!("source connector '{}' is not registered", props.connector))?;
+                        Self::Custom(factory.create_reader(props.props, splits, parser_config, source_ctx, columns).await?)
+                     }
                 };
 
                 Ok(connector)
@@ -160,6 +190,7 @@ macro_rules! impl_connector_properties {
             pub fn extract(mut props: HashMap<String, String>) -> Result<Self> {
                 const UPSTREAM_SOURCE_KEY: &str = "connector";
                 let connector = props.remove(UPSTREAM_SOURCE_KEY).ok_or_else(|| anyhow!("Must specify 'connector' in WITH clause"))?;
+                $crate::secret::resolve_secret_refs(&mut props)?;
                 if connector.ends_with("cdc") {
                     ConnectorProperties::new_cdc_properties(&connector, props)
                 } else {
@@ -171,7 +202,13 @@ macro_rules! impl_connector_properties {
                             },
                         )*
                         _ => {
-                            Err(anyhow!("connector '{}' is not supported", connector,))
+                            let connector = connector.to_lowercase();
+                            if $crate::registry::get_source_factory(&connector).is_some() {
+                                let props = serde_json::from_value(json_value).map_err(|e| anyhow!(e))?;
+                                Ok(Self::Custom(Box::new($crate::registry::CustomProperties { connector, props })))
+                            } else {
+                                Err(anyhow!("connector '{}' is not supported", connector,))
+                            }
                         }
                     }
                 }
@@ -224,3 +261,24 @@ macro_rules! impl_common_split_reader_logic {
         }
     };
 }
+
+/// Implement `Debug` for a connector config struct so that secret fields are printed as
+/// `Some("[REDACTED]")` instead of their values, making the config safe to log. All fields must
+/// be listed explicitly, and secret fields must be `Option`s (which holds for every
+/// credential-carrying config so far).
+#[macro_export]
+macro_rules! impl_redacted_debug {
+    ($config:ident, fields = [$($field:ident),* $(,)?], secrets = [$($secret:ident),* $(,)?]) => {
+        impl ::std::fmt::Debug for $config {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($config))
+                    $(.field(stringify!($field), &self.$field))*
+                    $(.field(
+                        stringify!($secret),
+                        &self.$secret.as_ref().map(|_| $crate::common::REDACTED),
+                    ))*
+                    .finish()
+            }
+        }
+    };
+}
@@ -91,7 +91,12 @@ impl AvroParserConfig {
         if use_schema_registry {
             let kafka_topic = get_kafka_topic(props)?;
             let client = Client::new(url, props)?;
-            let resolver = Arc::new(ConfluentSchemaResolver::new(client));
+            let resolver = Arc::new(ConfluentSchemaResolver::from_props(client, props)?);
+            let mut subjects = vec![format!("{}-value", kafka_topic)];
+            if enable_upsert {
+                subjects.push(format!("{}-key", kafka_topic));
+            }
+            resolver.spawn_prefetch(subjects);
 
             Ok(Self {
                 schema: resolver
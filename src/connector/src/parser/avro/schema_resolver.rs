@@ -14,11 +14,15 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use apache_avro::Schema;
 use moka::future::Cache;
-use risingwave_common::error::ErrorCode::{InternalError, InvalidConfigValue, ProtocolError};
+use risingwave_common::error::ErrorCode::{
+    InternalError, InvalidConfigValue, InvalidParameterValue, ProtocolError,
+};
 use risingwave_common::error::{Result, RwError};
 use url::Url;
 
@@ -92,10 +96,36 @@ pub enum SchemaResolver {
 }
 
 
+const SCHEMA_REGISTRY_MAX_CACHED_SCHEMAS: &str = "schema.registry.max.cached.schemas";
+const SCHEMA_REGISTRY_CACHE_TTL_MS: &str = "schema.registry.cache.ttl.ms";
+const SCHEMA_REGISTRY_PREFETCH_INTERVAL_MS: &str = "schema.registry.prefetch.interval.ms";
+
+const DEFAULT_MAX_CACHED_SCHEMAS: u64 = 1024;
+
+fn parse_u64_prop(props: &HashMap<String, String>, key: &str) -> Result<Option<u64>> {
+    props
+        .get(key)
+        .map(|v| {
+            v.parse::<u64>().map_err(|_| {
+                RwError::from(InvalidParameterValue(format!(
+                    "{} must be an unsigned integer, got {}",
+                    key, v
+                )))
+            })
+        })
+        .transpose()
+}
+
 #[derive(Debug)]
 pub struct ConfluentSchemaResolver {
     writer_schemas: Cache<i32, Arc<Schema>>,
     confluent_client: Client,
+    /// Re-fetch the latest version of the given subjects in the background at
+    /// this interval, so parsing rarely hits an uncached schema. `None`
+    /// disables prefetching.
+    prefetch_interval: Option<Duration>,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
 }
 
 impl ConfluentSchemaResolver {
@@ -109,14 +139,89 @@ impl ConfluentSchemaResolver {
         Ok(schema)
     }
 
-    /// Create a new `ConfluentSchemaResolver`
+    /// Create a new `ConfluentSchemaResolver` with the default cache options.
     pub fn new(client: Client) -> Self {
+        Self::with_options(client, DEFAULT_MAX_CACHED_SCHEMAS, None, None)
+    }
+
+    /// Create a new `ConfluentSchemaResolver` with the cache options taken from
+    /// the source WITH clause:
+    /// - `schema.registry.max.cached.schemas`: cache capacity (LRU, default 1024)
+    /// - `schema.registry.cache.ttl.ms`: expire cached schemas after this long
+    /// - `schema.registry.prefetch.interval.ms`: background prefetch interval
+    pub fn from_props(client: Client, props: &HashMap<String, String>) -> Result<Self> {
+        let max_cached_schemas = parse_u64_prop(props, SCHEMA_REGISTRY_MAX_CACHED_SCHEMAS)?
+            .unwrap_or(DEFAULT_MAX_CACHED_SCHEMAS);
+        let ttl = parse_u64_prop(props, SCHEMA_REGISTRY_CACHE_TTL_MS)?.map(Duration::from_millis);
+        let prefetch_interval =
+            parse_u64_prop(props, SCHEMA_REGISTRY_PREFETCH_INTERVAL_MS)?.map(Duration::from_millis);
+        Ok(Self::with_options(
+            client,
+            max_cached_schemas,
+            ttl,
+            prefetch_interval,
+        ))
+    }
+
+    fn with_options(
+        client: Client,
+        max_cached_schemas: u64,
+        ttl: Option<Duration>,
+        prefetch_interval: Option<Duration>,
+    ) -> Self {
+        let mut builder = Cache::builder().max_capacity(max_cached_schemas);
+        if let Some(ttl) = ttl {
+            builder = builder.time_to_live(ttl);
+        }
         ConfluentSchemaResolver {
-            writer_schemas: Cache::new(u64::MAX),
+            writer_schemas: builder.build(),
             confluent_client: client,
+            prefetch_interval,
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
         }
     }
 
+    /// The fraction of `get` calls served from the cache, or `None` before the
+    /// first lookup.
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let hits = self.cache_hits.load(Ordering::Relaxed);
+        let misses = self.cache_misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        (total != 0).then(|| hits as f64 / total as f64)
+    }
+
+    /// Spawn a background task refreshing the latest version of the given
+    /// subjects, if prefetching is enabled. The task exits once the resolver
+    /// is dropped.
+    pub fn spawn_prefetch(self: &Arc<Self>, subjects: Vec<String>) {
+        let Some(interval) = self.prefetch_interval else {
+            return;
+        };
+        let resolver = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                let Some(resolver) = resolver.upgrade() else {
+                    break;
+                };
+                for subject in &subjects {
+                    if let Err(e) = resolver.get_by_subject_name(subject).await {
+                        tracing::warn!("failed to prefetch schema of subject {}: {}", subject, e);
+                    }
+                }
+                if let Some(hit_rate) = resolver.cache_hit_rate() {
+                    tracing::debug!(
+                        "confluent schema cache hit rate: {:.2}%",
+                        hit_rate * 100.0
+                    );
+                }
+            }
+        });
+    }
+
     pub async fn get_by_subject_name(&self, subject_name: &str) -> Result<Arc<Schema>> {
         let raw_schema = self
             .confluent_client
@@ -128,8 +233,10 @@ impl ConfluentSchemaResolver {
     // get the writer schema by id
     pub async fn get(&self, schema_id: i32) -> Result<Arc<Schema>> {
         if let Some(schema) = self.writer_schemas.get(&schema_id) {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
             Ok(schema)
         } else {
+            self.cache_misses.fetch_add(1, Ordering::Relaxed);
             let raw_schema = self.confluent_client.get_schema_by_id(schema_id).await?;
             self.parse_and_cache_schema(raw_schema).await
         }
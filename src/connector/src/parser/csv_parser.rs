@@ -32,8 +32,15 @@ macro_rules! to_rust_type {
 }
 #[derive(Debug, Clone)]
 pub struct CsvParserConfig {
-    pub delimiter: u8,
+    /// The field delimiter, which may be longer than one byte.
+    pub delimiter: Vec<u8>,
     pub has_header: bool,
+    pub quote: u8,
+    /// The escape character used inside quoted fields. `None` means RFC-4180
+    /// style doubled quotes.
+    pub escape: Option<u8>,
+    /// The string that is decoded as `NULL`, besides the empty field.
+    pub null_literal: Option<String>,
 }
 
 /// Parser for CSV format
@@ -42,7 +49,10 @@ pub struct CsvParser {
     rw_columns: Vec<SourceColumnDesc>,
     source_ctx: SourceContextRef,
     headers: Option<Vec<String>>,
-    delimiter: u8,
+    delimiter: Vec<u8>,
+    quote: u8,
+    escape: Option<u8>,
+    null_literal: Option<String>,
 }
 
 impl CsvParser {
@@ -54,6 +64,9 @@ impl CsvParser {
         let CsvParserConfig {
             delimiter,
             has_header,
+            quote,
+            escape,
+            null_literal,
         } = parser_config;
 
         Ok(Self {
@@ -61,12 +74,22 @@ impl CsvParser {
             delimiter,
             headers: if has_header { Some(Vec::new()) } else { None },
             source_ctx,
+            quote,
+            escape,
+            null_literal,
         })
     }
 
     fn read_row(&self, buf: &[u8]) -> Result<Vec<String>> {
+        let &[delimiter] = self.delimiter.as_slice() else {
+            return self.read_row_multi_byte_delimiter(buf);
+        };
         let mut reader_builder = csv::ReaderBuilder::default();
-        reader_builder.delimiter(self.delimiter).has_headers(false);
+        reader_builder
+            .delimiter(delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .has_headers(false);
         let record = reader_builder
             .from_reader(buf)
             .records()
@@ -78,6 +101,64 @@ impl CsvParser {
             .unwrap_or_default())
     }
 
+    /// Splits one record on a delimiter longer than one byte, which the `csv`
+    /// crate does not support. Quoting and escaping follow the same rules as
+    /// the single-byte path.
+    fn read_row_multi_byte_delimiter(&self, buf: &[u8]) -> Result<Vec<String>> {
+        let line = buf.strip_suffix(b"\n").unwrap_or(buf);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut fields = Vec::new();
+        let mut field = Vec::new();
+        let mut in_quotes = false;
+        let mut i = 0;
+        while i < line.len() {
+            let b = line[i];
+            if in_quotes {
+                if Some(b) == self.escape && i + 1 < line.len() {
+                    field.push(line[i + 1]);
+                    i += 2;
+                } else if b == self.quote {
+                    if line.get(i + 1) == Some(&self.quote) {
+                        // An RFC-4180 style doubled quote.
+                        field.push(self.quote);
+                        i += 2;
+                    } else {
+                        in_quotes = false;
+                        i += 1;
+                    }
+                } else {
+                    field.push(b);
+                    i += 1;
+                }
+            } else if b == self.quote && field.is_empty() {
+                in_quotes = true;
+                i += 1;
+            } else if line[i..].starts_with(&self.delimiter) {
+                fields.push(std::mem::take(&mut field));
+                i += self.delimiter.len();
+            } else {
+                field.push(b);
+                i += 1;
+            }
+        }
+        if in_quotes {
+            return Err(RwError::from(ProtocolError(
+                "unterminated quoted field in CSV record".to_string(),
+            )));
+        }
+        fields.push(field);
+        fields
+            .into_iter()
+            .map(|field| {
+                String::from_utf8(field)
+                    .map_err(|err| RwError::from(ProtocolError(err.to_string())))
+            })
+            .collect()
+    }
+
     #[inline]
     fn parse_string(dtype: &DataType, v: String) -> Result<Datum> {
         let v = match dtype {
@@ -116,15 +197,21 @@ impl CsvParser {
         let mut fields = self.read_row(&payload)?;
         if let Some(headers) = &mut self.headers {
             if headers.is_empty() {
-                *headers = fields;
+                // Real-world exports often prepend a BOM and pad header names
+                // with spaces, so clean them up before matching columns.
+                *headers = fields
+                    .iter()
+                    .map(|field| field.trim_start_matches('\u{feff}').trim().to_string())
+                    .collect();
                 // Here we want a row, but got nothing. So it's an error for the `parse_inner` but
                 // has no bad impact on the system.
                 return  Err(RwError::from(ProtocolError("This message indicates a header, no row will be inserted. However, internal parser state was updated.".to_string())));
             }
+            let null_literal = self.null_literal.clone();
             writer.insert(|desc| {
                 if let Some(i) = headers.iter().position(|name| name == &desc.name) {
                     let value = fields.get_mut(i).map(std::mem::take).unwrap_or_default();
-                    if value.is_empty() {
+                    if value.is_empty() || null_literal.as_deref() == Some(value.as_str()) {
                         return Ok(None);
                     }
                     Self::parse_string(&desc.data_type, value)
@@ -134,9 +221,10 @@ impl CsvParser {
             })
         } else {
             fields.reverse();
+            let null_literal = self.null_literal.clone();
             writer.insert(|desc| {
                 if let Some(value) = fields.pop() {
-                    if value.is_empty() {
+                    if value.is_empty() || null_literal.as_deref() == Some(value.as_str()) {
                         return Ok(None);
                     }
                     Self::parse_string(&desc.data_type, value)
@@ -191,8 +279,11 @@ mod tests {
         let mut parser = CsvParser::new(
             Vec::new(),
             CsvParserConfig {
-                delimiter: b',',
+                delimiter: b",".to_vec(),
                 has_header: false,
+                quote: b'"',
+                escape: None,
+                null_literal: None,
             },
             Default::default(),
         )
@@ -281,6 +372,131 @@ mod tests {
             assert_eq!(row.datum_at(2), None);
         }
     }
+    #[tokio::test]
+    async fn test_csv_with_quote_escape_and_null() {
+        let data = vec![r#"1,'a,''b',2"#, r#"2,'a\'b',\N"#, r#"\N,c,3"#];
+        let descs = vec![
+            SourceColumnDesc::simple("a", DataType::Int32, 0.into()),
+            SourceColumnDesc::simple("b", DataType::Varchar, 1.into()),
+            SourceColumnDesc::simple("c", DataType::Int32, 2.into()),
+        ];
+        let mut parser = CsvParser::new(
+            Vec::new(),
+            CsvParserConfig {
+                delimiter: b",".to_vec(),
+                has_header: false,
+                quote: b'\'',
+                escape: Some(b'\\'),
+                null_literal: Some(r#"\N"#.into()),
+            },
+            Default::default(),
+        )
+        .unwrap();
+        let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 3);
+        for item in data {
+            parser
+                .parse_inner(item.as_bytes().to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+        }
+        let chunk = builder.finish();
+        let mut rows = chunk.rows();
+        {
+            let (op, row) = rows.next().unwrap();
+            assert_eq!(op, Op::Insert);
+            assert_eq!(
+                row.datum_at(0).to_owned_datum(),
+                (Some(ScalarImpl::Int32(1)))
+            );
+            assert_eq!(
+                row.datum_at(1).to_owned_datum(),
+                (Some(ScalarImpl::Utf8("a,'b".into())))
+            );
+            assert_eq!(
+                row.datum_at(2).to_owned_datum(),
+                (Some(ScalarImpl::Int32(2)))
+            );
+        }
+        {
+            let (op, row) = rows.next().unwrap();
+            assert_eq!(op, Op::Insert);
+            assert_eq!(
+                row.datum_at(1).to_owned_datum(),
+                (Some(ScalarImpl::Utf8("a'b".into())))
+            );
+            assert_eq!(row.datum_at(2), None);
+        }
+        {
+            let (op, row) = rows.next().unwrap();
+            assert_eq!(op, Op::Insert);
+            assert_eq!(row.datum_at(0), None);
+            assert_eq!(
+                row.datum_at(1).to_owned_datum(),
+                (Some(ScalarImpl::Utf8("c".into())))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_csv_with_multi_byte_delimiter() {
+        let data = vec![r#"1||a|b||2"#, r#"6||"3||4"||"#];
+        let descs = vec![
+            SourceColumnDesc::simple("a", DataType::Int32, 0.into()),
+            SourceColumnDesc::simple("b", DataType::Varchar, 1.into()),
+            SourceColumnDesc::simple("c", DataType::Int32, 2.into()),
+        ];
+        let mut parser = CsvParser::new(
+            Vec::new(),
+            CsvParserConfig {
+                delimiter: b"||".to_vec(),
+                has_header: false,
+                quote: b'"',
+                escape: None,
+                null_literal: None,
+            },
+            Default::default(),
+        )
+        .unwrap();
+        let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 2);
+        for item in data {
+            parser
+                .parse_inner(item.as_bytes().to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+        }
+        let chunk = builder.finish();
+        let mut rows = chunk.rows();
+        {
+            let (op, row) = rows.next().unwrap();
+            assert_eq!(op, Op::Insert);
+            assert_eq!(
+                row.datum_at(0).to_owned_datum(),
+                (Some(ScalarImpl::Int32(1)))
+            );
+            assert_eq!(
+                row.datum_at(1).to_owned_datum(),
+                (Some(ScalarImpl::Utf8("a|b".into())))
+            );
+            assert_eq!(
+                row.datum_at(2).to_owned_datum(),
+                (Some(ScalarImpl::Int32(2)))
+            );
+        }
+        {
+            let (op, row) = rows.next().unwrap();
+            assert_eq!(op, Op::Insert);
+            assert_eq!(
+                row.datum_at(0).to_owned_datum(),
+                (Some(ScalarImpl::Int32(6)))
+            );
+            assert_eq!(
+                row.datum_at(1).to_owned_datum(),
+                (Some(ScalarImpl::Utf8("3||4".into())))
+            );
+            assert_eq!(row.datum_at(2), None);
+        }
+    }
+
     #[tokio::test]
     async fn test_csv_with_headers() {
         let data = [
@@ -298,8 +514,11 @@ mod tests {
         let mut parser = CsvParser::new(
             Vec::new(),
             CsvParserConfig {
-                delimiter: b',',
+                delimiter: b",".to_vec(),
                 has_header: true,
+                quote: b'"',
+                escape: None,
+                null_literal: None,
             },
             Default::default(),
         )
@@ -69,14 +69,15 @@ impl DebeziumAvroParserConfig {
         let key_schema = Schema::parse_str(&raw_schema.content)
             .map_err(|e| RwError::from(ProtocolError(format!("Avro schema parse error {}", e))))?;
 
-        let resolver = ConfluentSchemaResolver::new(client);
+        let resolver = Arc::new(ConfluentSchemaResolver::from_props(client, props)?);
+        resolver.spawn_prefetch(vec![format!("{}-value", kafka_topic)]);
         let outer_schema = resolver
             .get_by_subject_name(&format!("{}-value", kafka_topic))
             .await?;
         Ok(Self {
             key_schema: Arc::new(key_schema),
             outer_schema,
-            schema_resolver: Arc::new(resolver),
+            schema_resolver: resolver,
         })
     }
 
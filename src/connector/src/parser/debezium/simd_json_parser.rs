@@ -16,8 +16,11 @@ use std::fmt::Debug;
 
 use risingwave_common::error::ErrorCode::ProtocolError;
 use risingwave_common::error::{Result, RwError};
-use simd_json::{BorrowedValue, Mutable};
+use simd_json::{BorrowedValue, Mutable, ValueAccess};
 
+use super::transaction::{
+    TransactionBuffer, DEBEZIUM_TRANSACTION_STATUS_BEGIN, DEBEZIUM_TRANSACTION_STATUS_END,
+};
 use crate::parser::unified::debezium::DebeziumChangeEvent;
 use crate::parser::unified::json::{JsonAccess, JsonParseOptions};
 use crate::parser::unified::util::apply_row_operation_on_stream_chunk_writer;
@@ -28,6 +31,9 @@ use crate::source::{SourceColumnDesc, SourceContext, SourceContextRef};
 pub struct DebeziumJsonParser {
     pub(crate) rw_columns: Vec<SourceColumnDesc>,
     source_ctx: SourceContextRef,
+    /// Buffers the data events of an in-flight upstream transaction, so it is
+    /// applied atomically when its `END` metadata event arrives.
+    txn_buffer: TransactionBuffer,
 }
 
 impl DebeziumJsonParser {
@@ -35,15 +41,23 @@ impl DebeziumJsonParser {
         Ok(Self {
             rw_columns,
             source_ctx,
+            txn_buffer: TransactionBuffer::default(),
         })
     }
 
-    #[allow(clippy::unused_async)]
     pub async fn parse_inner(
-        &self,
+        &mut self,
         mut payload: Vec<u8>,
         mut writer: SourceStreamChunkRowWriter<'_>,
     ) -> Result<WriteGuard> {
+        // Keep the raw bytes around in case the event must be buffered for an
+        // in-flight transaction.
+        let raw = if self.txn_buffer.is_active() {
+            Some(payload.clone())
+        } else {
+            None
+        };
+
         let mut event: BorrowedValue<'_> = simd_json::to_borrowed_value(&mut payload)
             .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
@@ -53,11 +67,71 @@ impl DebeziumJsonParser {
             event
         };
 
+        // Transaction metadata events (routed from the transaction topic) look like
+        // `{"status": "BEGIN", "id": "571", "event_count": null, ...}` and carry no `op`.
+        if payload.get("op").is_none()
+            && let Some(status) = payload.get("status").and_then(|v| v.as_str())
+            && let Some(txn_id) = payload.get("id").and_then(|v| v.as_str())
+        {
+            match status {
+                DEBEZIUM_TRANSACTION_STATUS_BEGIN => {
+                    let stale = self.txn_buffer.begin(txn_id.to_string());
+                    return self.write_events(stale, &mut writer);
+                }
+                DEBEZIUM_TRANSACTION_STATUS_END => {
+                    let buffered = self.txn_buffer.end(txn_id);
+                    return self.write_events(buffered, &mut writer);
+                }
+                other => {
+                    tracing::warn!("ignoring transaction metadata event with status {}", other);
+                    return Ok(WriteGuard::no_op());
+                }
+            }
+        }
+
+        // Buffer data events while a transaction is in flight. If the buffer is
+        // full, give up atomicity for this transaction and write through.
+        if let Some(raw) = raw {
+            if self.txn_buffer.push(raw) {
+                return Ok(WriteGuard::no_op());
+            }
+            tracing::warn!("transaction buffer is full, writing events through");
+            let buffered = self.txn_buffer.abort();
+            self.write_events(buffered, &mut writer)?;
+        }
+
+        Self::write_event(payload, &mut writer)
+    }
+
+    /// Parse and write a batch of buffered raw events to the writer in one go.
+    fn write_events(
+        &self,
+        events: Vec<Vec<u8>>,
+        writer: &mut SourceStreamChunkRowWriter<'_>,
+    ) -> Result<WriteGuard> {
+        let mut guard = WriteGuard::no_op();
+        for mut raw in events {
+            let mut event: BorrowedValue<'_> = simd_json::to_borrowed_value(&mut raw)
+                .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+            let payload = if let Some(payload) = event.get_mut("payload") {
+                std::mem::take(payload)
+            } else {
+                event
+            };
+            guard = Self::write_event(payload, writer)?;
+        }
+        Ok(guard)
+    }
+
+    fn write_event(
+        payload: BorrowedValue<'_>,
+        writer: &mut SourceStreamChunkRowWriter<'_>,
+    ) -> Result<WriteGuard> {
         let accessor = JsonAccess::new_with_options(payload, &JsonParseOptions::DEBEZIUM);
 
         let row_op = DebeziumChangeEvent::with_value(accessor);
 
-        apply_row_operation_on_stream_chunk_writer(row_op, &mut writer)
+        apply_row_operation_on_stream_chunk_writer(row_op, writer)
     }
 }
 
@@ -77,6 +151,10 @@ impl ByteStreamSourceParser for DebeziumJsonParser {
     ) -> Result<WriteGuard> {
         self.parse_inner(payload, writer).await
     }
+
+    fn is_buffering(&self) -> bool {
+        self.txn_buffer.is_active()
+    }
 }
 
 #[cfg(test)]
@@ -109,7 +187,7 @@ mod tests {
     }
 
     async fn parse_one(
-        parser: DebeziumJsonParser,
+        mut parser: DebeziumJsonParser,
         columns: Vec<SourceColumnDesc>,
         payload: Vec<u8>,
     ) -> Vec<(Op, OwnedRow)> {
@@ -256,17 +334,23 @@ mod tests {
 
             for data in input {
                 let parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
-                let [(op, row)]: [_; 1] = parse_one(parser, columns.clone(), data)
+                // An update with both images yields an `UpdateDelete`/`UpdateInsert` pair.
+                let [(op1, row1), (op2, row2)]: [_; 2] = parse_one(parser, columns.clone(), data)
                     .await
                     .try_into()
                     .unwrap();
 
-                assert_eq!(op, Op::Insert);
-
-                assert!(row[0].eq(&Some(ScalarImpl::Int32(102))));
-                assert!(row[1].eq(&Some(ScalarImpl::Utf8("car battery".into()))));
-                assert!(row[2].eq(&Some(ScalarImpl::Utf8("24V car battery".into()))));
-                assert!(row[3].eq(&Some(ScalarImpl::Float64(9.1.into()))));
+                assert_eq!(op1, Op::UpdateDelete);
+                assert!(row1[0].eq(&Some(ScalarImpl::Int32(102))));
+                assert!(row1[1].eq(&Some(ScalarImpl::Utf8("car battery".into()))));
+                assert!(row1[2].eq(&Some(ScalarImpl::Utf8("12V car battery".into()))));
+                assert!(row1[3].eq(&Some(ScalarImpl::Float64(8.1.into()))));
+
+                assert_eq!(op2, Op::UpdateInsert);
+                assert!(row2[0].eq(&Some(ScalarImpl::Int32(102))));
+                assert!(row2[1].eq(&Some(ScalarImpl::Utf8("car battery".into()))));
+                assert!(row2[2].eq(&Some(ScalarImpl::Utf8("24V car battery".into()))));
+                assert!(row2[3].eq(&Some(ScalarImpl::Float64(9.1.into()))));
             }
         }
     }
@@ -422,12 +506,17 @@ mod tests {
             let columns = get_test2_columns();
 
             let parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
-            let [(op, row)]: [_; 1] = parse_one(parser, columns, data.to_vec())
+            let [(op1, row1), (op, row)]: [_; 2] = parse_one(parser, columns, data.to_vec())
                 .await
                 .try_into()
                 .unwrap();
 
-            assert_eq!(op, Op::Insert);
+            assert_eq!(op1, Op::UpdateDelete);
+            assert!(row1[0].eq(&Some(ScalarImpl::Int64(111))));
+            assert!(row1[1].eq(&Some(ScalarImpl::Bool(true))));
+            assert!(row1[2].eq(&Some(ScalarImpl::Int16(-1))));
+
+            assert_eq!(op, Op::UpdateInsert);
 
             assert!(row[0].eq(&Some(ScalarImpl::Int64(111))));
             assert!(row[1].eq(&Some(ScalarImpl::Bool(false))));
@@ -462,7 +551,7 @@ mod tests {
                 SourceColumnDesc::simple("O_REAL", DataType::Float32, ColumnId::from(4)),
                 SourceColumnDesc::simple("O_DOUBLE", DataType::Float64, ColumnId::from(5)),
             ];
-            let parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
+            let mut parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
 
             let mut builder = SourceStreamChunkBuilder::with_capacity(columns, 2);
             // i64 overflow
@@ -527,7 +616,7 @@ mod tests {
                 DataType::Float64,
                 ColumnId::from(0),
             )];
-            let parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
+            let mut parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
             let mut builder = SourceStreamChunkBuilder::with_capacity(columns, 2);
             let data = br#"{"payload":{"before":null,"after":{"O_DOUBLE":1.797695E308},"source":{"version":"1.9.7.Final","connector":"mysql","name":"RW_CDC_test.orders","ts_ms":1678174483000,"snapshot":"false","db":"test","sequence":null,"table":"orders","server_id":223344,"gtid":null,"file":"mysql-bin.000003","pos":563,"row":0,"thread":3,"query":null},"op":"c","ts_ms":1678174483866,"transaction":null}}"#;
             if let Err(e) = parser
@@ -768,4 +857,57 @@ mod tests {
             assert!(row[12].eq(&Some(ScalarImpl::Utf8("hahaha".into()))));
         }
     }
+
+    mod test3_transaction {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_transaction_atomic_apply() {
+            let columns = vec![
+                SourceColumnDesc::simple("id", DataType::Int32, ColumnId::from(0)),
+                SourceColumnDesc::simple("name", DataType::Varchar, ColumnId::from(1)),
+            ];
+            let mut parser = DebeziumJsonParser::new(columns.clone(), Default::default()).unwrap();
+            let mut builder = SourceStreamChunkBuilder::with_capacity(columns, 4);
+
+            let begin = br#"{"status":"BEGIN","id":"571","event_count":null,"data_collections":null,"ts_ms":1486500577125}"#;
+            let event1 = br#"{"payload":{"before":null,"after":{"id":1,"name":"a"},"source":{"db":"test","table":"orders"},"op":"c","ts_ms":1486500577691,"transaction":{"id":"571","total_order":1,"data_collection_order":1}}}"#;
+            let event2 = br#"{"payload":{"before":null,"after":{"id":2,"name":"b"},"source":{"db":"test","table":"orders"},"op":"c","ts_ms":1486500577691,"transaction":{"id":"571","total_order":2,"data_collection_order":2}}}"#;
+            let end = br#"{"status":"END","id":"571","event_count":2,"data_collections":[{"data_collection":"test.orders","event_count":2}],"ts_ms":1486500577691}"#;
+
+            parser
+                .parse_inner(begin.to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+            assert_eq!(builder.op_num(), 0);
+
+            // Data events are buffered while the transaction is in flight.
+            parser
+                .parse_inner(event1.to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+            parser
+                .parse_inner(event2.to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+            assert_eq!(builder.op_num(), 0);
+
+            // All buffered events are flushed at END.
+            parser
+                .parse_inner(end.to_vec(), builder.row_writer())
+                .await
+                .unwrap();
+            assert_eq!(builder.op_num(), 2);
+
+            let chunk = builder.finish();
+            let rows = chunk
+                .rows()
+                .map(|(op, row_ref)| (op, row_ref.into_owned_row()))
+                .collect::<Vec<_>>();
+            assert_eq!(rows[0].0, Op::Insert);
+            assert_eq!(rows[0].1[0], Some(ScalarImpl::Int32(1)));
+            assert_eq!(rows[1].0, Op::Insert);
+            assert_eq!(rows[1].1[0], Some(ScalarImpl::Int32(2)));
+        }
+    }
 }
@@ -0,0 +1,129 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handling of Debezium transaction metadata events.
+//!
+//! When `provide.transaction.metadata` is enabled on the Debezium connector and the
+//! transaction topic is routed into the data topic, the stream carries `BEGIN`/`END`
+//! markers around the data events of each upstream transaction. We buffer the data
+//! events between the markers and flush them in one go when `END` arrives, so the
+//! whole transaction lands in a single stream chunk and becomes visible atomically
+//! within one barrier, instead of being torn across chunks.
+
+/// `status` of a transaction metadata event marking the start of a transaction.
+pub const DEBEZIUM_TRANSACTION_STATUS_BEGIN: &str = "BEGIN";
+/// `status` of a transaction metadata event marking the end of a transaction.
+pub const DEBEZIUM_TRANSACTION_STATUS_END: &str = "END";
+
+/// The maximum number of events buffered for one upstream transaction. If a
+/// transaction exceeds this, buffering is abandoned and the events are written
+/// through, trading atomicity for bounded memory usage.
+pub const MAX_TRANSACTION_BUFFER_EVENTS: usize = 4096;
+
+/// Buffers the raw payloads of data events that belong to an in-flight upstream
+/// transaction, keyed by the transaction id from the metadata events.
+#[derive(Debug, Default)]
+pub struct TransactionBuffer {
+    txn_id: Option<String>,
+    payloads: Vec<Vec<u8>>,
+}
+
+impl TransactionBuffer {
+    /// Whether we are currently inside a transaction.
+    pub fn is_active(&self) -> bool {
+        self.txn_id.is_some()
+    }
+
+    /// Start buffering for the given transaction. If a previous transaction is
+    /// still open (e.g. its `END` event was lost), its buffered events are
+    /// returned so the caller can write them through.
+    pub fn begin(&mut self, txn_id: String) -> Vec<Vec<u8>> {
+        let stale = if self.txn_id.is_some() {
+            tracing::warn!(
+                "transaction {:?} ended implicitly by BEGIN of {}, flushing {} buffered events",
+                self.txn_id,
+                txn_id,
+                self.payloads.len()
+            );
+            std::mem::take(&mut self.payloads)
+        } else {
+            Vec::new()
+        };
+        self.txn_id = Some(txn_id);
+        stale
+    }
+
+    /// Buffer one data event payload. Returns `false` if the buffer is full, in
+    /// which case the caller should fall back to writing through.
+    pub fn push(&mut self, payload: Vec<u8>) -> bool {
+        if self.payloads.len() >= MAX_TRANSACTION_BUFFER_EVENTS {
+            return false;
+        }
+        self.payloads.push(payload);
+        true
+    }
+
+    /// End the transaction and drain all buffered events. Events buffered for a
+    /// mismatched transaction id are drained as well, to avoid losing data.
+    pub fn end(&mut self, txn_id: &str) -> Vec<Vec<u8>> {
+        if self.txn_id.as_deref() != Some(txn_id) {
+            tracing::warn!(
+                "transaction END of {} does not match in-flight transaction {:?}",
+                txn_id,
+                self.txn_id
+            );
+        }
+        self.txn_id = None;
+        std::mem::take(&mut self.payloads)
+    }
+
+    /// Abandon buffering for the current transaction, draining all buffered
+    /// events so they can be written through.
+    pub fn abort(&mut self) -> Vec<Vec<u8>> {
+        self.txn_id = None;
+        std::mem::take(&mut self.payloads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_buffer_roundtrip() {
+        let mut buffer = TransactionBuffer::default();
+        assert!(!buffer.is_active());
+
+        assert!(buffer.begin("571".into()).is_empty());
+        assert!(buffer.is_active());
+        assert!(buffer.push(b"event-1".to_vec()));
+        assert!(buffer.push(b"event-2".to_vec()));
+
+        let events = buffer.end("571");
+        assert_eq!(events, vec![b"event-1".to_vec(), b"event-2".to_vec()]);
+        assert!(!buffer.is_active());
+    }
+
+    #[test]
+    fn test_transaction_buffer_implicit_end() {
+        let mut buffer = TransactionBuffer::default();
+        buffer.begin("1".into());
+        buffer.push(b"event".to_vec());
+        // A new BEGIN flushes the events of the lost transaction.
+        let stale = buffer.begin("2".into());
+        assert_eq!(stale, vec![b"event".to_vec()]);
+        assert!(buffer.is_active());
+        assert!(buffer.end("2").is_empty());
+    }
+}
@@ -12,31 +12,81 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::error::ErrorCode::{self, ProtocolError};
+use std::collections::HashMap;
+
+use risingwave_common::error::ErrorCode::{self, InvalidParameterValue, ProtocolError};
 use risingwave_common::error::{Result, RwError};
 
 use super::ByteStreamSourceParser;
 use crate::common::UpsertMessage;
-use crate::parser::unified::json::JsonAccess;
+use crate::parser::unified::json::{
+    JsonAccess, JsonParseOptions, NumericHandling, TimestamptzHandling,
+};
 use crate::parser::unified::upsert::UpsertChangeEvent;
 use crate::parser::unified::util::apply_row_operation_on_stream_chunk_writer;
 use crate::parser::{SourceStreamChunkRowWriter, WriteGuard};
 use crate::source::{SourceColumnDesc, SourceContext, SourceContextRef};
 
+/// The source-level option to decode numbers encoded as JSON strings, e.g.
+/// `json.numeric.as_string = 'true'` in the `WITH` clause.
+pub const JSON_NUMERIC_AS_STRING_KEY: &str = "json.numeric.as_string";
+
+#[derive(Debug, Clone, Default)]
+pub struct JsonParserConfig {
+    pub parse_options: JsonParseOptions,
+}
+
+impl JsonParserConfig {
+    pub fn from_props(props: &HashMap<String, String>) -> Result<Self> {
+        let mut parse_options = JsonParseOptions::default();
+        if let Some(value) = props.get(TimestamptzHandling::OPTION_KEY) {
+            parse_options.timestamptz_handling = TimestamptzHandling::from_option(value)
+                .ok_or_else(|| {
+                    RwError::from(InvalidParameterValue(format!(
+                        "unrecognized {} value {:?}, expect one of `guess_number_unit`, \
+                         `milliseconds`, `microseconds` or `rfc3339`",
+                        TimestamptzHandling::OPTION_KEY,
+                        value
+                    )))
+                })?;
+        }
+        if let Some(value) = props.get(JSON_NUMERIC_AS_STRING_KEY) {
+            let as_string = value.parse::<bool>().map_err(|_| {
+                RwError::from(InvalidParameterValue(format!(
+                    "invalid {} value {:?}, expect `true` or `false`",
+                    JSON_NUMERIC_AS_STRING_KEY, value
+                )))
+            })?;
+            if as_string {
+                parse_options.numeric_handling = NumericHandling::Relax {
+                    string_parsing: true,
+                };
+            }
+        }
+        Ok(Self { parse_options })
+    }
+}
+
 /// Parser for JSON format
 #[derive(Debug)]
 pub struct JsonParser {
     rw_columns: Vec<SourceColumnDesc>,
     source_ctx: SourceContextRef,
     enable_upsert: bool,
+    parse_options: JsonParseOptions,
 }
 
 impl JsonParser {
-    pub fn new(rw_columns: Vec<SourceColumnDesc>, source_ctx: SourceContextRef) -> Result<Self> {
+    pub fn new(
+        rw_columns: Vec<SourceColumnDesc>,
+        parser_config: JsonParserConfig,
+        source_ctx: SourceContextRef,
+    ) -> Result<Self> {
         Ok(Self {
             rw_columns,
             source_ctx,
             enable_upsert: false,
+            parse_options: parser_config.parse_options,
         })
     }
 
@@ -45,17 +95,20 @@ impl JsonParser {
             rw_columns,
             source_ctx: Default::default(),
             enable_upsert: false,
+            parse_options: JsonParseOptions::default(),
         })
     }
 
     pub fn new_with_upsert(
         rw_columns: Vec<SourceColumnDesc>,
+        parser_config: JsonParserConfig,
         source_ctx: SourceContextRef,
     ) -> Result<Self> {
         Ok(Self {
             rw_columns,
             source_ctx,
             enable_upsert: true,
+            parse_options: parser_config.parse_options,
         })
     }
 
@@ -83,9 +136,13 @@ impl JsonParser {
                 )
             };
 
-            let mut accessor = UpsertChangeEvent::default().with_key(JsonAccess::new(key_decoded));
+            let mut accessor = UpsertChangeEvent::default().with_key(JsonAccess::new_with_options(
+                key_decoded,
+                &self.parse_options,
+            ));
             if let Some(value) = value_decoded {
-                accessor = accessor.with_value(JsonAccess::new(value));
+                accessor =
+                    accessor.with_value(JsonAccess::new_with_options(value, &self.parse_options));
             }
             apply_row_operation_on_stream_chunk_writer(accessor, &mut writer)
         } else {
@@ -100,7 +157,8 @@ impl JsonParser {
             let mut guard = None;
             for value in values {
                 let accessor: UpsertChangeEvent<JsonAccess<'_, '_>, JsonAccess<'_, '_>> =
-                    UpsertChangeEvent::default().with_value(JsonAccess::new(value));
+                    UpsertChangeEvent::default()
+                        .with_value(JsonAccess::new_with_options(value, &self.parse_options));
 
                 match apply_row_operation_on_stream_chunk_writer(accessor, &mut writer) {
                     Ok(this_guard) => guard = Some(this_guard),
@@ -147,6 +205,7 @@ mod tests {
     use std::vec;
 
     use itertools::Itertools;
+    use maplit::{convert_args, hashmap};
     use risingwave_common::array::{Op, StructValue};
     use risingwave_common::cast::{str_to_date, str_to_timestamp};
     use risingwave_common::catalog::ColumnDesc;
@@ -155,7 +214,7 @@ mod tests {
     use risingwave_common::types::{DataType, Decimal, ScalarImpl, ToOwnedDatum};
 
     use crate::common::UpsertMessage;
-    use crate::parser::{JsonParser, SourceColumnDesc, SourceStreamChunkBuilder};
+    use crate::parser::{JsonParser, JsonParserConfig, SourceColumnDesc, SourceStreamChunkBuilder};
 
     fn get_payload() -> Vec<Vec<u8>> {
         vec![
@@ -184,7 +243,8 @@ mod tests {
             SourceColumnDesc::simple("decimal", DataType::Decimal, 10.into()),
         ];
 
-        let parser = JsonParser::new(descs.clone(), Default::default()).unwrap();
+        let parser =
+            JsonParser::new(descs.clone(), Default::default(), Default::default()).unwrap();
 
         let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 2);
 
@@ -283,7 +343,8 @@ mod tests {
             SourceColumnDesc::simple("v2", DataType::Int16, 1.into()),
             SourceColumnDesc::simple("v3", DataType::Varchar, 2.into()),
         ];
-        let parser = JsonParser::new(descs.clone(), Default::default()).unwrap();
+        let parser =
+            JsonParser::new(descs.clone(), Default::default(), Default::default()).unwrap();
         let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 3);
 
         // Parse a correct record.
@@ -314,6 +375,48 @@ mod tests {
         assert_eq!(chunk.cardinality(), 2);
     }
 
+    #[tokio::test]
+    async fn test_json_parse_options() {
+        let descs = vec![
+            SourceColumnDesc::simple("created_at", DataType::Timestamptz, 0.into()),
+            SourceColumnDesc::simple("count", DataType::Int64, 1.into()),
+        ];
+        let props = convert_args!(hashmap!(
+            "json.timestamptz.handling" => "milliseconds",
+            "json.numeric.as_string" => "true",
+        ));
+        let config = JsonParserConfig::from_props(&props).unwrap();
+        let parser = JsonParser::new(descs.clone(), config, Default::default()).unwrap();
+        let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 1);
+
+        {
+            let writer = builder.row_writer();
+            let payload = br#"{"created_at": 1620654819000, "count": "42"}"#.to_vec();
+            parser.parse_inner(payload, writer).await.unwrap();
+        }
+
+        let chunk = builder.finish();
+        let (op, row) = chunk.rows().next().unwrap();
+        assert_eq!(op, Op::Insert);
+        // The number is interpreted as milliseconds instead of guessing by magnitude.
+        assert_eq!(
+            row.datum_at(0).to_owned_datum(),
+            (Some(ScalarImpl::Int64(1620654819000000)))
+        );
+        assert_eq!(
+            row.datum_at(1).to_owned_datum(),
+            (Some(ScalarImpl::Int64(42)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_options_rejects_unknown_value() {
+        let props = convert_args!(hashmap!(
+            "json.timestamptz.handling" => "minutes",
+        ));
+        JsonParserConfig::from_props(&props).unwrap_err();
+    }
+
     #[tokio::test]
     async fn test_json_parse_struct() {
         let descs = vec![
@@ -344,7 +447,8 @@ mod tests {
         .map(SourceColumnDesc::from)
         .collect_vec();
 
-        let parser = JsonParser::new(descs.clone(), Default::default()).unwrap();
+        let parser =
+            JsonParser::new(descs.clone(), Default::default(), Default::default()).unwrap();
         let payload = br#"
         {
             "data": {
@@ -411,7 +515,9 @@ mod tests {
             SourceColumnDesc::simple("a", DataType::Int32, 0.into()),
             SourceColumnDesc::simple("b", DataType::Int32, 1.into()),
         ];
-        let parser = JsonParser::new_with_upsert(descs.clone(), Default::default()).unwrap();
+        let parser =
+            JsonParser::new_with_upsert(descs.clone(), Default::default(), Default::default())
+                .unwrap();
         let mut builder = SourceStreamChunkBuilder::with_capacity(descs, 4);
         for item in items {
             parser
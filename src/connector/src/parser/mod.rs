@@ -36,8 +36,8 @@ use self::bytes_parser::BytesParser;
 pub use self::csv_parser::CsvParserConfig;
 use crate::parser::maxwell::MaxwellParser;
 use crate::source::{
-    BoxSourceStream, SourceColumnDesc, SourceContext, SourceContextRef, SourceFormat, SourceMeta,
-    SourceWithStateStream, SplitId, StreamChunkWithState,
+    BoxSourceStream, ParseErrorPolicy, SourceColumnDesc, SourceContext, SourceContextRef,
+    SourceFormat, SourceMeta, SourceWithStateStream, SplitId, StreamChunkWithState,
 };
 
 mod avro;
@@ -110,6 +110,14 @@ pub struct SourceStreamChunkRowWriter<'a> {
 #[derive(Debug)]
 pub struct WriteGuard(());
 
+impl WriteGuard {
+    /// Create a write guard without writing any row, for control messages that do
+    /// not produce data rows, e.g. Debezium transaction metadata events.
+    pub(crate) fn no_op() -> Self {
+        Self(())
+    }
+}
+
 trait OpAction {
     type Output;
 
@@ -314,6 +322,14 @@ pub trait ByteStreamSourceParser: Send + Debug + Sized + 'static {
         writer: SourceStreamChunkRowWriter<'a>,
     ) -> impl Future<Output = Result<WriteGuard>> + Send + 'a;
 
+    /// Whether the parser is holding back already-consumed events in an internal buffer, e.g.
+    /// for an in-flight upstream transaction. While this returns `true`, the source offset must
+    /// not advance past the buffered events, so that a recovery replays them instead of
+    /// dropping the buffered part of the transaction.
+    fn is_buffering(&self) -> bool {
+        false
+    }
+
     /// Parse a data stream of one source split into a stream of [`StreamChunk`].
     ///
     /// # Arguments
@@ -339,14 +355,34 @@ async fn into_chunk_stream<P: ByteStreamSourceParser>(mut parser: P, data_stream
 
         for msg in batch {
             if let Some(content) = msg.payload {
-                split_offset_mapping.insert(msg.split_id, msg.offset);
+                let parse_error_policy = parser.source_ctx().parse_error_policy;
+                let (split_id, offset) = (msg.split_id, msg.offset);
+                // Keep the payload around to dead-letter the record before it
+                // is consumed by the parser.
+                let dead_letter = matches!(parse_error_policy, ParseErrorPolicy::DeadLetter)
+                    .then(|| content.clone());
 
                 let old_op_num = builder.op_num();
 
                 if let Err(e) = parser.parse_one(content, builder.row_writer()).await {
+                    if matches!(parse_error_policy, ParseErrorPolicy::Fail) {
+                        return Err(e);
+                    }
                     tracing::warn!("message parsing failed {}, skipping", e.to_string());
+                    if let Some(payload) = dead_letter {
+                        tracing::warn!(
+                            target: "parse_dead_letter",
+                            split_id = &*split_id,
+                            offset,
+                            error = %e,
+                            payload = %String::from_utf8_lossy(&payload),
+                            "malformed record"
+                        );
+                    }
                     // This will throw an error for batch
                     parser.source_ctx().report_user_source_error(e)?;
+                    // The malformed record is skipped for good, so advance past it.
+                    split_offset_mapping.insert(split_id, offset);
                     continue;
                 }
 
@@ -376,6 +412,13 @@ async fn into_chunk_stream<P: ByteStreamSourceParser>(mut parser: P, data_stream
                         builder.row_writer().fulfill_meta_column(f)?;
                     }
                 }
+
+                // While the parser buffers events of an in-flight transaction, hold this
+                // split's offset back, so that a recovery replays the buffered events
+                // instead of dropping the not-yet-flushed part of the transaction.
+                if !parser.is_buffering() {
+                    split_offset_mapping.insert(split_id, offset);
+                }
             }
         }
 
@@ -435,9 +478,11 @@ impl ByteStreamSourceParserImpl {
             SpecificParserConfig::Protobuf(config) => {
                 ProtobufParser::new(rw_columns, config, source_ctx).map(Self::Protobuf)
             }
-            SpecificParserConfig::Json => JsonParser::new(rw_columns, source_ctx).map(Self::Json),
-            SpecificParserConfig::UpsertJson => {
-                JsonParser::new_with_upsert(rw_columns, source_ctx).map(Self::Json)
+            SpecificParserConfig::Json(config) => {
+                JsonParser::new(rw_columns, config, source_ctx).map(Self::Json)
+            }
+            SpecificParserConfig::UpsertJson(config) => {
+                JsonParser::new_with_upsert(rw_columns, config, source_ctx).map(Self::Json)
             }
             SpecificParserConfig::CanalJson => {
                 CanalJsonParser::new(rw_columns, source_ctx).map(Self::CanalJson)
@@ -481,8 +526,8 @@ pub enum SpecificParserConfig {
     Avro(AvroParserConfig),
     UpsertAvro(AvroParserConfig),
     Protobuf(ProtobufParserConfig),
-    Json,
-    UpsertJson,
+    Json(JsonParserConfig),
+    UpsertJson(JsonParserConfig),
     DebeziumJson,
     DebeziumMongoJson,
     Maxwell,
@@ -500,8 +545,8 @@ impl SpecificParserConfig {
             SpecificParserConfig::UpsertAvro(_) => SourceFormat::UpsertAvro,
             SpecificParserConfig::Csv(_) => SourceFormat::Csv,
             SpecificParserConfig::Protobuf(_) => SourceFormat::Protobuf,
-            SpecificParserConfig::Json => SourceFormat::Json,
-            SpecificParserConfig::UpsertJson => SourceFormat::UpsertJson,
+            SpecificParserConfig::Json(_) => SourceFormat::Json,
+            SpecificParserConfig::UpsertJson(_) => SourceFormat::UpsertJson,
             SpecificParserConfig::DebeziumJson => SourceFormat::DebeziumJson,
             SpecificParserConfig::Maxwell => SourceFormat::Maxwell,
             SpecificParserConfig::CanalJson => SourceFormat::CanalJson,
@@ -515,7 +560,7 @@ impl SpecificParserConfig {
     pub fn is_upsert(&self) -> bool {
         matches!(
             self,
-            SpecificParserConfig::UpsertJson
+            SpecificParserConfig::UpsertJson(_)
                 | SpecificParserConfig::UpsertAvro(_)
                 | SpecificParserConfig::DebeziumAvro(_)
         )
@@ -528,8 +573,16 @@ impl SpecificParserConfig {
     ) -> Result<Self> {
         let conf = match format {
             SourceFormat::Csv => SpecificParserConfig::Csv(CsvParserConfig {
-                delimiter: info.csv_delimiter as u8,
+                delimiter: if info.csv_multi_char_delimiter.is_empty() {
+                    vec![info.csv_delimiter as u8]
+                } else {
+                    info.csv_multi_char_delimiter.clone().into_bytes()
+                },
                 has_header: info.csv_has_header,
+                quote: info.csv_quote.bytes().next().unwrap_or(b'"'),
+                escape: info.csv_escape.bytes().next(),
+                null_literal: (!info.csv_null_literal.is_empty())
+                    .then(|| info.csv_null_literal.clone()),
             }),
             SourceFormat::Avro => SpecificParserConfig::Avro(
                 AvroParserConfig::new(
@@ -566,8 +619,10 @@ impl SpecificParserConfig {
                 )
                 .await?,
             ),
-            SourceFormat::Json => SpecificParserConfig::Json,
-            SourceFormat::UpsertJson => SpecificParserConfig::UpsertJson,
+            SourceFormat::Json => SpecificParserConfig::Json(JsonParserConfig::from_props(props)?),
+            SourceFormat::UpsertJson => {
+                SpecificParserConfig::UpsertJson(JsonParserConfig::from_props(props)?)
+            }
             SourceFormat::DebeziumJson => SpecificParserConfig::DebeziumJson,
             SourceFormat::DebeziumMongoJson => SpecificParserConfig::DebeziumMongoJson,
             SourceFormat::Maxwell => SpecificParserConfig::Maxwell,
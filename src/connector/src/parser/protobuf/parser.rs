@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
 use itertools::Itertools;
 use prost_reflect::{
@@ -37,7 +38,9 @@ use crate::source::{SourceColumnDesc, SourceContext, SourceContextRef};
 #[derive(Debug, Clone)]
 pub struct ProtobufParser {
     message_descriptor: MessageDescriptor,
+    message_name: String,
     confluent_wire_type: bool,
+    schema_cache: Option<Arc<ConfluentPbSchemaCache>>,
     rw_columns: Vec<SourceColumnDesc>,
     source_ctx: SourceContextRef,
 }
@@ -46,6 +49,10 @@ pub struct ProtobufParser {
 pub struct ProtobufParserConfig {
     confluent_wire_type: bool,
     message_descriptor: MessageDescriptor,
+    message_name: String,
+    /// Resolves writer schemas by the id embedded in the payload, to follow
+    /// upstream schema evolution. `None` if not using the schema registry.
+    schema_cache: Option<Arc<ConfluentPbSchemaCache>>,
 }
 
 impl ProtobufParserConfig {
@@ -58,14 +65,17 @@ impl ProtobufParserConfig {
         let url = Url::parse(location)
             .map_err(|e| InternalError(format!("failed to parse url ({}): {}", location, e)))?;
 
+        let mut schema_cache = None;
         let schema_bytes = if use_schema_registry {
             let kafka_topic = get_kafka_topic(props)?;
             let client = Client::new(url, props)?;
-            compile_file_descriptor_from_schema_registry(
+            let schema_bytes = compile_file_descriptor_from_schema_registry(
                 format!("{}-value", kafka_topic).as_str(),
                 &client,
             )
-            .await?
+            .await?;
+            schema_cache = Some(Arc::new(ConfluentPbSchemaCache::new(client)));
+            schema_bytes
         } else {
             match url.scheme() {
                 // TODO(Tao): support local file only when it's compiled in debug mode.
@@ -104,7 +114,9 @@ impl ProtobufParserConfig {
         })?;
         Ok(Self {
             message_descriptor,
+            message_name: message_name.to_string(),
             confluent_wire_type: use_schema_registry,
+            schema_cache,
         })
     }
 
@@ -180,38 +192,50 @@ impl ProtobufParser {
         let ProtobufParserConfig {
             confluent_wire_type,
             message_descriptor,
+            message_name,
+            schema_cache,
         } = config;
         Ok(Self {
             message_descriptor,
+            message_name,
             confluent_wire_type,
+            schema_cache,
             rw_columns,
             source_ctx,
         })
     }
 
-    #[allow(clippy::unused_async)]
     pub async fn parse_inner(
         &self,
         payload: Vec<u8>,
         mut writer: SourceStreamChunkRowWriter<'_>,
     ) -> Result<WriteGuard> {
-        let payload = if self.confluent_wire_type {
-            resolve_pb_header(&payload)?
+        let (message_descriptor, payload) = if self.confluent_wire_type {
+            let (schema_id, payload) = resolve_pb_header(&payload)?;
+            // Resolve the writer schema by the embedded schema id, so messages
+            // encoded with an evolved schema version can still be parsed.
+            let message_descriptor = match &self.schema_cache {
+                Some(cache) => cache.get(schema_id, &self.message_name).await?,
+                None => self.message_descriptor.clone(),
+            };
+            (message_descriptor, payload)
         } else {
-            &payload
+            (self.message_descriptor.clone(), payload.as_slice())
         };
 
-        let message = DynamicMessage::decode(self.message_descriptor.clone(), payload)
+        let message = DynamicMessage::decode(message_descriptor, payload)
             .map_err(|e| ProtocolError(format!("parse message failed: {}", e)))?;
         writer.insert(|column_desc| {
-            let field_desc = message
-                .descriptor()
-                .get_field_by_name(&column_desc.name)
-                .ok_or_else(|| {
-                    let err_msg = format!("protobuf schema don't have field {}", column_desc.name);
-                    tracing::error!(err_msg);
-                    RwError::from(ProtocolError(err_msg))
-                })?;
+            let Some(field_desc) = message.descriptor().get_field_by_name(&column_desc.name) else {
+                // A column the writer schema does not (or no longer does) carry,
+                // e.g. the table was created against a newer schema version that
+                // added an optional field. Fill it with NULL instead of failing.
+                tracing::debug!(
+                    "protobuf schema don't have field {}, fill with NULL",
+                    column_desc.name
+                );
+                return Ok(None);
+            };
             let value = message.get_field(&field_desc);
             from_protobuf_value(&field_desc, &value).map_err(|e| {
                 tracing::error!(
@@ -362,13 +386,13 @@ fn protobuf_type_mapping(
     Ok(t)
 }
 
-pub(crate) fn resolve_pb_header(payload: &[u8]) -> Result<&[u8]> {
+pub(crate) fn resolve_pb_header(payload: &[u8]) -> Result<(i32, &[u8])> {
     // there's a message index array at the front of payload
     // if it is the first message in proto def, the array is just and `0`
     // TODO: support parsing more complex indec array
-    let (_, remained) = extract_schema_id(payload)?;
+    let (schema_id, remained) = extract_schema_id(payload)?;
     match remained.first() {
-        Some(0) => Ok(&remained[1..]),
+        Some(0) => Ok((schema_id, &remained[1..])),
         Some(i) => {
             Err(RwError::from(ProtocolError(format!("The payload message must be the first message in protobuf schema def, but the message index is {}", i))))
         }
@@ -15,13 +15,15 @@
 use std::iter;
 use std::path::Path;
 
+use moka::future::Cache;
+use prost_reflect::{DescriptorPool, MessageDescriptor};
 use protobuf_native::compiler::{SourceTreeDescriptorDatabase, VirtualSourceTree};
 use protobuf_native::MessageLite;
 use risingwave_common::error::ErrorCode::{InternalError, ProtocolError};
 use risingwave_common::error::{Result, RwError};
 use url::Url;
 
-use crate::parser::schema_registry::Client;
+use crate::parser::schema_registry::{Client, Subject};
 use crate::parser::util::download_from_http;
 
 const PB_SCHEMA_LOCATION_S3_REGION: &str = "region";
@@ -39,10 +41,17 @@ pub(super) async fn compile_file_descriptor_from_schema_registry(
 ) -> Result<Vec<u8>> {
     let (primary_subject, dependency_subjects) =
         client.get_subject_and_references(subject_name).await?;
+    compile_file_descriptor_from_subjects(&primary_subject, &dependency_subjects)
+}
 
+// Compile .proto files of the primary subject and all it's deps into one file descriptor
+fn compile_file_descriptor_from_subjects(
+    primary_subject: &Subject,
+    dependency_subjects: &[Subject],
+) -> Result<Vec<u8>> {
     // Compile .proto files into a file descriptor set.
     let mut source_tree = VirtualSourceTree::new();
-    for subject in iter::once(&primary_subject).chain(dependency_subjects.iter()) {
+    for subject in iter::once(primary_subject).chain(dependency_subjects.iter()) {
         source_tree.as_mut().add_file(
             Path::new(&subject.name),
             subject.schema.content.as_bytes().to_vec(),
@@ -61,3 +70,51 @@ pub(super) async fn compile_file_descriptor_from_schema_registry(
     fds.serialize()
         .map_err(|_| RwError::from(InternalError("serialize descriptor set failed".to_owned())))
 }
+
+/// Resolves the writer [`MessageDescriptor`] by the schema id embedded in a
+/// Confluent-framed payload, so the parser can follow upstream schema evolution
+/// without recreating the source. One compiled descriptor is cached per schema id.
+#[derive(Debug)]
+pub struct ConfluentPbSchemaCache {
+    writer_descriptors: Cache<i32, MessageDescriptor>,
+    confluent_client: Client,
+}
+
+impl ConfluentPbSchemaCache {
+    /// Create a new `ConfluentPbSchemaCache`
+    pub fn new(client: Client) -> Self {
+        ConfluentPbSchemaCache {
+            writer_descriptors: Cache::new(u64::MAX),
+            confluent_client: client,
+        }
+    }
+
+    // get the writer message descriptor by schema id
+    pub async fn get(&self, schema_id: i32, message_name: &str) -> Result<MessageDescriptor> {
+        if let Some(descriptor) = self.writer_descriptors.get(&schema_id) {
+            return Ok(descriptor);
+        }
+        let (primary_subject, dependency_subjects) = self
+            .confluent_client
+            .get_subject_and_references_by_id(schema_id)
+            .await?;
+        let schema_bytes =
+            compile_file_descriptor_from_subjects(&primary_subject, &dependency_subjects)?;
+        let pool = DescriptorPool::decode(schema_bytes.as_slice()).map_err(|e| {
+            ProtocolError(format!(
+                "cannot build descriptor pool from schema id {}, error: {}",
+                schema_id, e
+            ))
+        })?;
+        let descriptor = pool.get_message_by_name(message_name).ok_or_else(|| {
+            ProtocolError(format!(
+                "cannot find message {} in schema id {}",
+                message_name, schema_id
+            ))
+        })?;
+        self.writer_descriptors
+            .insert(schema_id, descriptor.clone())
+            .await;
+        Ok(descriptor)
+    }
+}
@@ -13,26 +13,98 @@
 // limitations under the License.
 
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
-use reqwest::{Method, Url};
-use risingwave_common::error::ErrorCode::ProtocolError;
+use reqwest::{Certificate, Identity, Method, Url};
+use risingwave_common::error::ErrorCode::{InvalidParameterValue, ProtocolError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::retry::{RetryError, RetryPolicy};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+/// How the client authenticates itself against the schema registry.
+enum SchemaRegistryAuth {
+    None,
+    /// HTTP basic auth with `schema.registry.username` / `schema.registry.password`.
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    /// OAuth2 client-credentials flow. A bearer token is fetched from the token
+    /// endpoint and attached to every registry request, refreshed before expiry.
+    OAuth {
+        token_url: Url,
+        client_id: String,
+        client_secret: String,
+        scope: Option<String>,
+    },
+}
+
+impl std::fmt::Debug for SchemaRegistryAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Passwords, client secrets and tokens are redacted so that the client (and thus any
+        // error context containing it) can be logged safely.
+        match self {
+            SchemaRegistryAuth::None => f.write_str("None"),
+            SchemaRegistryAuth::Basic { username, password } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &password.as_ref().map(|_| crate::common::REDACTED))
+                .finish(),
+            SchemaRegistryAuth::OAuth {
+                token_url,
+                client_id,
+                client_secret: _,
+                scope,
+            } => f
+                .debug_struct("OAuth")
+                .field("token_url", token_url)
+                .field("client_id", client_id)
+                .field("client_secret", &crate::common::REDACTED)
+                .field("scope", scope)
+                .finish(),
+        }
+    }
+}
+
+/// A bearer token fetched via the client-credentials flow, with its deadline.
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+impl std::fmt::Debug for CachedToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedToken")
+            .field("token", &crate::common::REDACTED)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
 /// An client for communication with schema registry
 #[derive(Debug)]
 pub struct Client {
     inner: reqwest::Client,
     url: Url,
-    username: Option<String>,
-    password: Option<String>,
+    auth: SchemaRegistryAuth,
+    token_cache: tokio::sync::Mutex<Option<CachedToken>>,
+    /// Retries requests that fail with a connection or timeout error.
+    retry_policy: RetryPolicy,
 }
 
 impl Client {
     pub(crate) fn new(url: Url, props: &HashMap<String, String>) -> Result<Self> {
         const SCHEMA_REGISTRY_USERNAME: &str = "schema.registry.username";
         const SCHEMA_REGISTRY_PASSWORD: &str = "schema.registry.password";
+        const SCHEMA_REGISTRY_OAUTH_TOKEN_URL: &str = "schema.registry.oauth.token.url";
+        const SCHEMA_REGISTRY_OAUTH_CLIENT_ID: &str = "schema.registry.oauth.client.id";
+        const SCHEMA_REGISTRY_OAUTH_CLIENT_SECRET: &str = "schema.registry.oauth.client.secret";
+        const SCHEMA_REGISTRY_OAUTH_SCOPE: &str = "schema.registry.oauth.scope";
+        const SCHEMA_REGISTRY_SSL_CA_LOCATION: &str = "schema.registry.ssl.ca.location";
+        const SCHEMA_REGISTRY_SSL_CERTIFICATE_LOCATION: &str =
+            "schema.registry.ssl.certificate.location";
+        const SCHEMA_REGISTRY_SSL_KEY_LOCATION: &str = "schema.registry.ssl.key.location";
 
         if url.cannot_be_a_base() {
             return Err(RwError::from(ProtocolError(format!(
@@ -41,21 +113,172 @@ impl Client {
             ))));
         }
 
-        let inner = reqwest::Client::builder().build().map_err(|e| {
+        let mut builder = reqwest::Client::builder();
+
+        // Custom CA for registries with self-signed or private-CA certificates.
+        if let Some(ca_location) = props.get(SCHEMA_REGISTRY_SSL_CA_LOCATION) {
+            let pem = std::fs::read(ca_location).map_err(|e| {
+                RwError::from(InvalidParameterValue(format!(
+                    "failed to read {}: {}",
+                    SCHEMA_REGISTRY_SSL_CA_LOCATION, e
+                )))
+            })?;
+            let cert = Certificate::from_pem(&pem).map_err(|e| {
+                RwError::from(InvalidParameterValue(format!("invalid ca certificate: {}", e)))
+            })?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        // Mutual TLS: present a client certificate to the registry.
+        match (
+            props.get(SCHEMA_REGISTRY_SSL_CERTIFICATE_LOCATION),
+            props.get(SCHEMA_REGISTRY_SSL_KEY_LOCATION),
+        ) {
+            (Some(cert_location), Some(key_location)) => {
+                let mut pem = std::fs::read(cert_location).map_err(|e| {
+                    RwError::from(InvalidParameterValue(format!(
+                        "failed to read {}: {}",
+                        SCHEMA_REGISTRY_SSL_CERTIFICATE_LOCATION, e
+                    )))
+                })?;
+                pem.extend(std::fs::read(key_location).map_err(|e| {
+                    RwError::from(InvalidParameterValue(format!(
+                        "failed to read {}: {}",
+                        SCHEMA_REGISTRY_SSL_KEY_LOCATION, e
+                    )))
+                })?);
+                let identity = Identity::from_pem(&pem).map_err(|e| {
+                    RwError::from(InvalidParameterValue(format!(
+                        "invalid client certificate or key: {}",
+                        e
+                    )))
+                })?;
+                builder = builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => {
+                return Err(RwError::from(InvalidParameterValue(format!(
+                    "{} and {} must be specified together",
+                    SCHEMA_REGISTRY_SSL_CERTIFICATE_LOCATION, SCHEMA_REGISTRY_SSL_KEY_LOCATION
+                ))));
+            }
+        }
+
+        let inner = builder.build().map_err(|e| {
             RwError::from(ProtocolError(format!("build reqwest client failed {}", e)))
         })?;
 
-        let username = props.get(SCHEMA_REGISTRY_USERNAME).cloned();
-        let password = props.get(SCHEMA_REGISTRY_PASSWORD).cloned();
+        let auth = match (
+            props.get(SCHEMA_REGISTRY_USERNAME),
+            props.get(SCHEMA_REGISTRY_OAUTH_TOKEN_URL),
+        ) {
+            (Some(_), Some(_)) => {
+                return Err(RwError::from(InvalidParameterValue(
+                    "basic auth and oauth cannot be enabled at the same time".into(),
+                )));
+            }
+            (Some(username), None) => SchemaRegistryAuth::Basic {
+                username: username.clone(),
+                password: props.get(SCHEMA_REGISTRY_PASSWORD).cloned(),
+            },
+            (None, Some(token_url)) => {
+                let token_url = Url::parse(token_url).map_err(|e| {
+                    RwError::from(InvalidParameterValue(format!(
+                        "invalid {}: {}",
+                        SCHEMA_REGISTRY_OAUTH_TOKEN_URL, e
+                    )))
+                })?;
+                let client_id = props
+                    .get(SCHEMA_REGISTRY_OAUTH_CLIENT_ID)
+                    .cloned()
+                    .ok_or_else(|| {
+                        RwError::from(InvalidParameterValue(format!(
+                            "{} is required for oauth",
+                            SCHEMA_REGISTRY_OAUTH_CLIENT_ID
+                        )))
+                    })?;
+                let client_secret = props
+                    .get(SCHEMA_REGISTRY_OAUTH_CLIENT_SECRET)
+                    .cloned()
+                    .ok_or_else(|| {
+                        RwError::from(InvalidParameterValue(format!(
+                            "{} is required for oauth",
+                            SCHEMA_REGISTRY_OAUTH_CLIENT_SECRET
+                        )))
+                    })?;
+                SchemaRegistryAuth::OAuth {
+                    token_url,
+                    client_id,
+                    client_secret,
+                    scope: props.get(SCHEMA_REGISTRY_OAUTH_SCOPE).cloned(),
+                }
+            }
+            (None, None) => SchemaRegistryAuth::None,
+        };
+
         Ok(Client {
             inner,
             url,
-            username,
-            password,
+            auth,
+            token_cache: tokio::sync::Mutex::new(None),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
-    fn build_request<P>(&self, method: Method, path: P) -> reqwest::RequestBuilder
+    /// Get a valid bearer token, fetching a new one via the client-credentials
+    /// flow if the cached token is absent or about to expire.
+    async fn bearer_token(
+        &self,
+        token_url: &Url,
+        client_id: &str,
+        client_secret: &str,
+        scope: Option<&str>,
+    ) -> Result<String> {
+        /// Refresh this much earlier than the reported expiry, to avoid
+        /// presenting a token that expires mid-flight.
+        const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+        let mut cache = self.token_cache.lock().await;
+        if let Some(cached) = cache.as_ref() && cached.expires_at > Instant::now() + EXPIRY_MARGIN {
+            return Ok(cached.token.clone());
+        }
+
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+        ];
+        if let Some(scope) = scope {
+            form.push(("scope", scope));
+        }
+        let res = self
+            .inner
+            .post(token_url.clone())
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| {
+                RwError::from(ProtocolError(format!("oauth token request error {}", e)))
+            })?;
+        if !res.status().is_success() {
+            return Err(RwError::from(ProtocolError(format!(
+                "oauth token request failed with status {}",
+                res.status()
+            ))));
+        }
+        let res: OAuthTokenResp = res.json().await.map_err(|e| {
+            RwError::from(ProtocolError(format!("oauth token parse resp error {}", e)))
+        })?;
+
+        let token = res.access_token;
+        *cache = Some(CachedToken {
+            token: token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(res.expires_in),
+        });
+        Ok(token)
+    }
+
+    async fn build_request<P>(&self, method: Method, path: P) -> Result<reqwest::RequestBuilder>
     where
         P: IntoIterator,
         P::Item: AsRef<str>,
@@ -68,17 +291,33 @@ impl Client {
 
         let mut request = self.inner.request(method, url);
 
-        if self.username.is_some() {
-            request = request.basic_auth(self.username.clone().unwrap(), self.password.clone())
+        match &self.auth {
+            SchemaRegistryAuth::None => {}
+            SchemaRegistryAuth::Basic { username, password } => {
+                request = request.basic_auth(username, password.as_ref());
+            }
+            SchemaRegistryAuth::OAuth {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+            } => {
+                let token = self
+                    .bearer_token(token_url, client_id, client_secret, scope.as_deref())
+                    .await?;
+                request = request.bearer_auth(token);
+            }
         }
 
-        request
+        Ok(request)
     }
 
     /// get schema by id
     pub async fn get_schema_by_id(&self, id: i32) -> Result<ConfluentSchema> {
-        let req = self.build_request(Method::GET, &["schemas", "ids", &id.to_string()]);
-        let res: GetByIdResp = request(req).await?;
+        let req = self
+            .build_request(Method::GET, &["schemas", "ids", &id.to_string()])
+            .await?;
+        let res: GetByIdResp = request(&self.retry_policy, req).await?;
         Ok(ConfluentSchema {
             id,
             content: res.schema,
@@ -92,8 +331,10 @@ impl Client {
 
     /// get the latest version of the subject
     pub async fn get_subject(&self, subject: &str) -> Result<Subject> {
-        let req = self.build_request(Method::GET, &["subjects", subject, "versions", "latest"]);
-        let res: GetBySubjectResp = request(req).await?;
+        let req = self
+            .build_request(Method::GET, &["subjects", subject, "versions", "latest"])
+            .await?;
+        let res: GetBySubjectResp = request(&self.retry_policy, req).await?;
         tracing::debug!("update schema: {:?}", res);
         Ok(Subject {
             schema: ConfluentSchema {
@@ -105,6 +346,61 @@ impl Client {
         })
     }
 
+    /// get the schema of the given id and all it's references(deps)
+    ///
+    /// This is used to resolve the writer schema by the id embedded in the payload,
+    /// so a source can keep up with upstream schema evolution without being recreated.
+    pub async fn get_subject_and_references_by_id(
+        &self,
+        id: i32,
+    ) -> Result<(Subject, Vec<Subject>)> {
+        let req = self
+            .build_request(Method::GET, &["schemas", "ids", &id.to_string()])
+            .await?;
+        let res: GetByIdResp = request(&self.retry_policy, req).await?;
+        let primary_subject = Subject {
+            schema: ConfluentSchema {
+                id,
+                content: res.schema,
+            },
+            // the by-id endpoint does not expose the subject version
+            version: 0,
+            name: format!("schema_{}", id),
+        };
+
+        let mut subjects = vec![];
+        let mut visited = HashSet::new();
+        let mut queue = res
+            .references
+            .into_iter()
+            .map(|r| (r.subject, r.version.to_string()))
+            .collect::<Vec<_>>();
+        // use bfs to get all references
+        while let Some((subject, version)) = queue.pop() {
+            let req = self
+                .build_request(Method::GET, &["subjects", &subject, "versions", &version])
+                .await?;
+            let res: GetBySubjectResp = request(&self.retry_policy, req).await?;
+            subjects.push(Subject {
+                schema: ConfluentSchema {
+                    id: res.id,
+                    content: res.schema,
+                },
+                version: res.version,
+                name: res.subject.clone(),
+            });
+            visited.insert(res.subject);
+            queue.extend(
+                res.references
+                    .into_iter()
+                    .filter(|r| !visited.contains(&r.subject))
+                    .map(|r| (r.subject, r.version.to_string())),
+            );
+        }
+
+        Ok((primary_subject, subjects))
+    }
+
     /// get the latest version of the subject and all it's references(deps)
     pub async fn get_subject_and_references(
         &self,
@@ -115,9 +411,10 @@ impl Client {
         let mut queue = vec![(subject.to_owned(), "latest".to_owned())];
         // use bfs to get all references
         while let Some((subject, version)) = queue.pop() {
-            let req =
-                self.build_request(Method::GET, &["subjects", &subject, "versions", &version]);
-            let res: GetBySubjectResp = request(req).await?;
+            let req = self
+                .build_request(Method::GET, &["subjects", &subject, "versions", &version])
+                .await?;
+            let res: GetBySubjectResp = request(&self.retry_policy, req).await?;
             let ref_subject = Subject {
                 schema: ConfluentSchema {
                     id: res.id,
@@ -141,11 +438,24 @@ impl Client {
     }
 }
 
-async fn request<T>(req: reqwest::RequestBuilder) -> Result<T>
+async fn request<T>(policy: &RetryPolicy, req: reqwest::RequestBuilder) -> Result<T>
 where
     T: DeserializeOwned,
 {
-    let res = req.send().await.map_err(|e| {
+    // Registry requests have no streaming body, so they are clonable and transient connection
+    // or timeout errors can be retried. Fall back to a single attempt just in case.
+    let res = match req.try_clone() {
+        Some(_) => {
+            policy
+                .retry(
+                    || req.try_clone().expect("checked clonable above").send(),
+                    |e: &reqwest::Error| e.is_connect() || e.is_timeout(),
+                )
+                .await
+        }
+        None => req.send().await.map_err(RetryError::Inner),
+    }
+    .map_err(|e| {
         RwError::from(ProtocolError(format!(
             "confluent registry send req error {}",
             e
@@ -208,6 +518,9 @@ pub struct SchemaReference {
 #[derive(Debug, Deserialize)]
 struct GetByIdResp {
     schema: String,
+    // default to empty/non-reference
+    #[serde(default)]
+    references: Vec<SchemaReference>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -221,6 +534,18 @@ struct GetBySubjectResp {
     references: Vec<SchemaReference>,
 }
 
+#[derive(Debug, Deserialize)]
+struct OAuthTokenResp {
+    access_token: String,
+    // default to one minute if the token endpoint does not report an expiry
+    #[serde(default = "default_expires_in")]
+    expires_in: u64,
+}
+
+fn default_expires_in() -> u64 {
+    60
+}
+
 #[derive(Debug, Deserialize)]
 struct ErrorResp {
     error_code: i32,
@@ -74,7 +74,7 @@ where
             }
 
             // value should not be None.
-            ChangeEventOperation::Upsert => self
+            ChangeEventOperation::Upsert | ChangeEventOperation::Update => self
                 .value_accessor
                 .as_ref()
                 .unwrap()
@@ -82,13 +82,29 @@ where
         }
     }
 
+    fn access_old_field(
+        &self,
+        name: &str,
+        type_expected: &risingwave_common::types::DataType,
+    ) -> super::AccessResult {
+        // value should not be None for updates.
+        self.value_accessor
+            .as_ref()
+            .unwrap()
+            .access(&[BEFORE, name], Some(type_expected))
+    }
+
     fn op(&self) -> std::result::Result<ChangeEventOperation, super::AccessError> {
         if let Some(accessor) = &self.value_accessor {
             if let Some(ScalarImpl::Utf8(op)) = accessor.access(&[OP], Some(&DataType::Varchar))? {
                 match op.as_ref() {
-                    DEBEZIUM_READ_OP | DEBEZIUM_CREATE_OP | DEBEZIUM_UPDATE_OP => {
+                    DEBEZIUM_READ_OP | DEBEZIUM_CREATE_OP => {
                         return Ok(ChangeEventOperation::Upsert)
                     }
+                    // Updates carry the before image as well (if the upstream is
+                    // configured to capture it), so they can be emitted as true
+                    // `UpdateDelete`/`UpdateInsert` pairs.
+                    DEBEZIUM_UPDATE_OP => return Ok(ChangeEventOperation::Update),
                     DEBEZIUM_DELETE_OP => return Ok(ChangeEventOperation::Delete),
                     _ => (),
                 }
@@ -54,6 +54,35 @@ pub enum NumericHandling {
         string_parsing: bool,
     },
 }
+#[derive(Clone, Debug)]
+pub enum TimestamptzHandling {
+    /// Guess the unit of an epoch number (seconds, milliseconds, microseconds
+    /// or nanoseconds) by its magnitude. The historical default.
+    GuessNumberUnit,
+    /// Numbers are epoch milliseconds.
+    Milli,
+    /// Numbers are epoch microseconds.
+    Micro,
+    /// Only RFC 3339 formatted strings are accepted.
+    Rfc3339,
+}
+
+impl TimestamptzHandling {
+    /// The source-level option to select a variant, e.g.
+    /// `json.timestamptz.handling = 'milliseconds'` in the `WITH` clause.
+    pub const OPTION_KEY: &'static str = "json.timestamptz.handling";
+
+    pub fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "guess_number_unit" => Some(Self::GuessNumberUnit),
+            "milliseconds" | "milli" => Some(Self::Milli),
+            "microseconds" | "micro" => Some(Self::Micro),
+            "rfc3339" => Some(Self::Rfc3339),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum BooleanHandling {
     Strict,
@@ -70,6 +99,7 @@ pub enum BooleanHandling {
 pub struct JsonParseOptions {
     pub bytea_handling: ByteaHandling,
     pub time_handling: TimeHandling,
+    pub timestamptz_handling: TimestamptzHandling,
     pub json_value_handling: JsonValueHandling,
     pub numeric_handling: NumericHandling,
     pub boolean_handing: BooleanHandling,
@@ -86,6 +116,7 @@ impl JsonParseOptions {
     pub const CANAL: JsonParseOptions = JsonParseOptions {
         bytea_handling: ByteaHandling::Standard,
         time_handling: TimeHandling::Micro,
+        timestamptz_handling: TimestamptzHandling::GuessNumberUnit,
         json_value_handling: JsonValueHandling::AsValue,
         numeric_handling: NumericHandling::Relax {
             string_parsing: true,
@@ -99,6 +130,7 @@ impl JsonParseOptions {
     pub const DEBEZIUM: JsonParseOptions = JsonParseOptions {
         bytea_handling: ByteaHandling::Base64,
         time_handling: TimeHandling::Micro,
+        timestamptz_handling: TimestamptzHandling::GuessNumberUnit,
         json_value_handling: JsonValueHandling::AsString,
         numeric_handling: NumericHandling::Relax {
             string_parsing: false,
@@ -112,6 +144,7 @@ impl JsonParseOptions {
     pub const DEFAULT: JsonParseOptions = JsonParseOptions {
         bytea_handling: ByteaHandling::Standard,
         time_handling: TimeHandling::Micro,
+        timestamptz_handling: TimestamptzHandling::GuessNumberUnit,
         json_value_handling: JsonValueHandling::AsValue,
         numeric_handling: NumericHandling::Relax {
             string_parsing: false,
@@ -350,9 +383,19 @@ impl JsonParseOptions {
             (
                 Some(DataType::Timestamptz),
                 ValueType::I64 | ValueType::I128 | ValueType::U64 | ValueType::U128,
-            ) => i64_to_timestamptz(value.as_i64().unwrap())
-                .map_err(|_| create_error())?
-                .into(),
+            ) => match self.timestamptz_handling {
+                TimestamptzHandling::GuessNumberUnit => {
+                    i64_to_timestamptz(value.as_i64().unwrap()).map_err(|_| create_error())?
+                }
+                TimestamptzHandling::Milli => value
+                    .as_i64()
+                    .unwrap()
+                    .checked_mul(1_000)
+                    .ok_or_else(create_error)?,
+                TimestamptzHandling::Micro => value.as_i64().unwrap(),
+                TimestamptzHandling::Rfc3339 => Err(create_error())?,
+            }
+            .into(),
             // ---- Interval -----
             (Some(DataType::Interval), ValueType::String) => {
                 Interval::from_iso_8601(value.as_str().unwrap())
@@ -36,6 +36,9 @@ pub trait Access {
 pub enum ChangeEventOperation {
     Upsert, // Insert or Update
     Delete,
+    /// Update carrying both the before and the after images, so it can be
+    /// emitted as an `UpdateDelete`/`UpdateInsert` pair.
+    Update,
 }
 
 /// Methods to access a CDC event.
@@ -44,6 +47,13 @@ pub trait ChangeEvent {
     fn op(&self) -> std::result::Result<ChangeEventOperation, AccessError>;
     /// Access the field after the operation.
     fn access_field(&self, name: &str, type_expected: &DataType) -> AccessResult;
+    /// Access the field before the operation, only valid for [`ChangeEventOperation::Update`].
+    fn access_old_field(&self, name: &str, _type_expected: &DataType) -> AccessResult {
+        Err(AccessError::Undefined {
+            name: name.into(),
+            path: "before".into(),
+        })
+    }
 }
 
 impl<A> ChangeEvent for (ChangeEventOperation, A)
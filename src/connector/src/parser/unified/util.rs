@@ -22,25 +22,35 @@ pub fn apply_row_operation_on_stream_chunk_writer(
     writer: &mut SourceStreamChunkRowWriter<'_>,
 ) -> std::result::Result<WriteGuard, RwError> {
     match row_op.op()? {
-        super::ChangeEventOperation::Upsert => writer.insert(|column| {
-            let res = match row_op.access_field(&column.name, &column.data_type) {
-                Ok(o) => Ok(o),
-                Err(AccessError::Undefined { name, .. }) if !column.is_pk && name == column.name => {
-                    // Fill in null value for non-pk column
-                    // TODO: figure out a way to fill in not-null default value if user specifies one
-                    Ok(None)
-                },
-                Err(e) => Err(e)
-            };
-            tracing::trace!(
-                "inserted {:?} {:?} is_pk:{:?} {:?} ",
-                &column.name,
-                &column.data_type,
-                &column.is_pk,
-                res
-            );
-            Ok(res?)
-        }),
+        super::ChangeEventOperation::Upsert => apply_upsert(&row_op, writer),
+        super::ChangeEventOperation::Update => {
+            // Try to emit a true `UpdateDelete`/`UpdateInsert` pair. If the event
+            // carries no usable before image (e.g. the upstream replica identity
+            // does not capture full rows), fall back to upsert semantics.
+            let update_res = writer.update(|column| {
+                let before = row_op.access_old_field(&column.name, &column.data_type)?;
+                let after = match row_op.access_field(&column.name, &column.data_type) {
+                    Ok(o) => o,
+                    Err(AccessError::Undefined { name, .. })
+                        if !column.is_pk && name == column.name =>
+                    {
+                        None
+                    }
+                    Err(e) => Err(e)?,
+                };
+                Ok((before, after))
+            });
+            match update_res {
+                Ok(guard) => Ok(guard),
+                Err(e) => {
+                    tracing::debug!(
+                        "update event has no usable before image, falling back to upsert: {}",
+                        e
+                    );
+                    apply_upsert(&row_op, writer)
+                }
+            }
+        }
         super::ChangeEventOperation::Delete => writer.delete(|column| {
             let res = row_op.access_field(&column.name, &column.data_type);
             match res {
@@ -59,6 +69,31 @@ pub fn apply_row_operation_on_stream_chunk_writer(
     }
 }
 
+fn apply_upsert(
+    row_op: &impl ChangeEvent,
+    writer: &mut SourceStreamChunkRowWriter<'_>,
+) -> std::result::Result<WriteGuard, RwError> {
+    writer.insert(|column| {
+        let res = match row_op.access_field(&column.name, &column.data_type) {
+            Ok(o) => Ok(o),
+            Err(AccessError::Undefined { name, .. }) if !column.is_pk && name == column.name => {
+                // Fill in null value for non-pk column
+                // TODO: figure out a way to fill in not-null default value if user specifies one
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        };
+        tracing::trace!(
+            "inserted {:?} {:?} is_pk:{:?} {:?} ",
+            &column.name,
+            &column.data_type,
+            &column.is_pk,
+            res
+        );
+        Ok(res?)
+    })
+}
+
 impl From<AccessError> for RwError {
     fn from(val: AccessError) -> Self {
         ErrorCode::InternalError(format!("AccessError: {:?}", val)).into()
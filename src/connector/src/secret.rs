@@ -0,0 +1,117 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Process-local store for the values of secrets created with `CREATE SECRET`.
+//!
+//! The frontend resolves a `secret my_secret` reference in a `WITH` clause against the catalog at
+//! bind time and lowers it to the marker property value `secret:<id>`, so the catalog and
+//! `SHOW CREATE` output never contain the credential itself, and renaming or recreating a secret
+//! cannot redirect existing references. Observers populate this store from catalog notifications,
+//! and connector property parsing resolves the markers back to the actual values right before a
+//! connector is built.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use anyhow::{anyhow, Result};
+use parking_lot::RwLock;
+
+/// The secret backend that stores the value in the meta store.
+pub const SECRET_BACKEND_META: &str = "meta";
+/// The secret backend that looks the value up in AWS Secrets Manager. Parsed but not supported
+/// yet.
+pub const SECRET_BACKEND_AWS_SECRETS_MANAGER: &str = "aws_secrets_manager";
+
+/// Prefix marking a property value as a reference to a secret.
+pub const SECRET_REF_PREFIX: &str = "secret:";
+
+/// The id of a secret in the catalog.
+pub type SecretId = u32;
+
+static SECRET_STORE: LazyLock<RwLock<HashMap<SecretId, Vec<u8>>>> = LazyLock::new(Default::default);
+
+/// Build the marker property value for a reference to the secret with the given id.
+pub fn secret_ref_property(id: SecretId) -> String {
+    format!("{}{}", SECRET_REF_PREFIX, id)
+}
+
+/// Insert or update the value of the secret with the given id.
+pub fn insert_secret(id: SecretId, value: Vec<u8>) {
+    SECRET_STORE.write().insert(id, value);
+}
+
+/// Remove the secret with the given id, if present.
+pub fn remove_secret(id: SecretId) {
+    SECRET_STORE.write().remove(&id);
+}
+
+/// Get the value of the secret with the given id.
+pub fn get_secret(id: SecretId) -> Option<Vec<u8>> {
+    SECRET_STORE.read().get(&id).cloned()
+}
+
+/// Replace all `secret:<id>` markers in the property values with the actual secret values.
+///
+/// Returns an error if a marker is malformed, if a referenced secret does not exist in the store,
+/// or if its value is not valid UTF-8 (properties are strings).
+pub fn resolve_secret_refs(props: &mut HashMap<String, String>) -> Result<()> {
+    for (key, value) in props.iter_mut() {
+        if let Some(marker) = value.strip_prefix(SECRET_REF_PREFIX) {
+            let id: SecretId = marker
+                .parse()
+                .map_err(|_| anyhow!("malformed secret reference '{}' in '{}'", value, key))?;
+            let secret = get_secret(id)
+                .ok_or_else(|| anyhow!("secret {} used by '{}' not found", id, key))?;
+            *value = String::from_utf8(secret)
+                .map_err(|_| anyhow!("value of secret {} is not valid UTF-8", id))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashmap;
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_secret_refs() {
+        insert_secret(101, b"s3cret".to_vec());
+
+        let mut props = hashmap! {
+            "properties.sasl.username".to_string() => "alice".to_string(),
+            "properties.sasl.password".to_string() => secret_ref_property(101),
+        };
+        resolve_secret_refs(&mut props).unwrap();
+        assert_eq!(props["properties.sasl.username"], "alice");
+        assert_eq!(props["properties.sasl.password"], "s3cret");
+
+        let mut props = hashmap! {
+            "properties.sasl.password".to_string() => secret_ref_property(102),
+        };
+        resolve_secret_refs(&mut props).unwrap_err();
+
+        let mut props = hashmap! {
+            "properties.sasl.password".to_string() => format!("{}not_an_id", SECRET_REF_PREFIX),
+        };
+        resolve_secret_refs(&mut props).unwrap_err();
+
+        remove_secret(101);
+        let mut props = hashmap! {
+            "properties.sasl.password".to_string() => secret_ref_property(101),
+        };
+        resolve_secret_refs(&mut props).unwrap_err();
+    }
+}
@@ -34,17 +34,30 @@ use super::{
 };
 use crate::common::KafkaCommon;
 use crate::sink::utils::{
-    gen_append_only_message_stream, gen_debezium_message_stream, gen_upsert_message_stream,
-    AppendOnlyAdapterOpts, DebeziumAdapterOpts, UpsertAdapterOpts,
+    apply_payload_size_policy, gen_append_only_message_stream, gen_debezium_message_stream,
+    gen_upsert_message_stream, AppendOnlyAdapterOpts, DebeziumAdapterOpts, OversizePolicy,
+    PayloadSizeOpts, PayloadSizeOutcome, UpsertAdapterOpts,
 };
 use crate::sink::Result;
 use crate::source::kafka::PrivateLinkProducerContext;
 use crate::{
-    deserialize_bool_from_string, deserialize_duration_from_string, deserialize_u32_from_string,
+    deserialize_bool_from_string, deserialize_duration_from_string,
+    deserialize_optional_usize_from_string, deserialize_u32_from_string,
 };
 
 pub const KAFKA_SINK: &str = "kafka";
 
+pub const DELIVERY_ORDERING_OPTION: &str = "delivery.ordering";
+pub const DELIVERY_ORDERING_NONE: &str = "none";
+pub const DELIVERY_ORDERING_PER_KEY: &str = "per_key";
+
+pub const OVERSIZE_POLICY_OPTION: &str = "oversize.policy";
+pub const OVERSIZE_POLICY_ERROR: &str = "error";
+pub const OVERSIZE_POLICY_TRUNCATE: &str = "truncate";
+pub const OVERSIZE_POLICY_DROP: &str = "drop";
+pub const OVERSIZE_POLICY_SPLIT: &str = "split";
+const OVERSIZE_TRUNCATE_COLUMNS_OPTION: &str = "oversize.truncate.columns";
+
 const fn _default_timeout() -> Duration {
     Duration::from_secs(5)
 }
@@ -65,6 +78,14 @@ const fn _default_force_append_only() -> bool {
     false
 }
 
+fn _default_delivery_ordering() -> String {
+    DELIVERY_ORDERING_NONE.to_string()
+}
+
+fn _default_oversize_policy() -> String {
+    OVERSIZE_POLICY_ERROR.to_string()
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct KafkaConfig {
@@ -117,6 +138,39 @@ pub struct KafkaConfig {
     /// the indices of the pk columns in the frontend, so we simply store the primary key here
     /// as a string.
     pub primary_key: Option<String>,
+
+    /// Delivery ordering guarantee, accept "none" or "per_key". With "per_key", all messages of
+    /// the same primary key are routed to a stable partition (messages are keyed by the primary
+    /// key, so the consistent partitioner already guarantees this), and the producer is configured
+    /// to be idempotent with a single in-flight request per connection, so that broker-side
+    /// retries cannot reorder messages within a partition.
+    #[serde(rename = "delivery.ordering", default = "_default_delivery_ordering")]
+    pub delivery_ordering: String,
+
+    /// Maximum serialized payload size in bytes for a single message. Payloads above the limit
+    /// are handled according to `oversize.policy`. Unset means no limit.
+    #[serde(
+        rename = "max.payload.bytes",
+        default,
+        deserialize_with = "deserialize_optional_usize_from_string"
+    )]
+    pub max_payload_bytes: Option<usize>,
+
+    /// What to do with a payload exceeding `max.payload.bytes`: "error" fails the sink,
+    /// "truncate" nulls out the columns listed in `oversize.truncate.columns` until the payload
+    /// fits, "drop" skips the message (forwarding it to `dead.letter.topic` if set), and "split"
+    /// spreads array columns across multiple messages.
+    #[serde(rename = "oversize.policy", default = "_default_oversize_policy")]
+    pub oversize_policy: String,
+
+    /// Comma-separated list of columns to null out, in order, under the "truncate" policy.
+    #[serde(rename = "oversize.truncate.columns")]
+    pub oversize_truncate_columns: Option<String>,
+
+    /// Topic receiving the payloads dropped by the "drop" policy. Unset means dropped payloads
+    /// are discarded.
+    #[serde(rename = "dead.letter.topic")]
+    pub dead_letter_topic: Option<String>,
 }
 
 impl KafkaConfig {
@@ -136,8 +190,68 @@ impl KafkaConfig {
                 SINK_TYPE_UPSERT
             )));
         }
+
+        if config.delivery_ordering != DELIVERY_ORDERING_NONE
+            && config.delivery_ordering != DELIVERY_ORDERING_PER_KEY
+        {
+            return Err(SinkError::Config(anyhow!(
+                "`{}` must be {} or {}",
+                DELIVERY_ORDERING_OPTION,
+                DELIVERY_ORDERING_NONE,
+                DELIVERY_ORDERING_PER_KEY
+            )));
+        }
+
+        match config.oversize_policy.as_str() {
+            OVERSIZE_POLICY_ERROR | OVERSIZE_POLICY_DROP | OVERSIZE_POLICY_SPLIT => {}
+            OVERSIZE_POLICY_TRUNCATE => {
+                if config
+                    .oversize_truncate_columns
+                    .as_ref()
+                    .map_or(true, |columns| columns.trim().is_empty())
+                {
+                    return Err(SinkError::Config(anyhow!(
+                        "`{}` must be set when `{}` is {}",
+                        OVERSIZE_TRUNCATE_COLUMNS_OPTION,
+                        OVERSIZE_POLICY_OPTION,
+                        OVERSIZE_POLICY_TRUNCATE
+                    )));
+                }
+            }
+            _ => {
+                return Err(SinkError::Config(anyhow!(
+                    "`{}` must be {}, {}, {}, or {}",
+                    OVERSIZE_POLICY_OPTION,
+                    OVERSIZE_POLICY_ERROR,
+                    OVERSIZE_POLICY_TRUNCATE,
+                    OVERSIZE_POLICY_DROP,
+                    OVERSIZE_POLICY_SPLIT
+                )));
+            }
+        }
         Ok(config)
     }
+
+    fn payload_size_opts(&self) -> PayloadSizeOpts {
+        let policy = match self.oversize_policy.as_str() {
+            OVERSIZE_POLICY_TRUNCATE => OversizePolicy::Truncate(
+                self.oversize_truncate_columns
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .filter(|column| !column.is_empty())
+                    .collect(),
+            ),
+            OVERSIZE_POLICY_DROP => OversizePolicy::Drop,
+            OVERSIZE_POLICY_SPLIT => OversizePolicy::SplitArrays,
+            _ => OversizePolicy::Error,
+        };
+        PayloadSizeOpts {
+            max_payload_bytes: self.max_payload_bytes,
+            policy,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, enum_as_inner::EnumAsInner)]
@@ -154,10 +268,12 @@ pub struct KafkaSink<const APPEND_ONLY: bool> {
     schema: Schema,
     pk_indices: Vec<usize>,
     in_transaction_epoch: Option<u64>,
+    payload_size_opts: PayloadSizeOpts,
 }
 
 impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
     pub async fn new(config: KafkaConfig, schema: Schema, pk_indices: Vec<usize>) -> Result<Self> {
+        let payload_size_opts = config.payload_size_opts();
         Ok(KafkaSink {
             config: config.clone(),
             conductor: KafkaTransactionConductor::new(config).await?,
@@ -165,6 +281,7 @@ impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
             state: KafkaSinkState::Init,
             schema,
             pk_indices,
+            payload_size_opts,
         })
     }
 
@@ -244,14 +361,33 @@ impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
         // here we assume the key part always exists and value part is optional.
         // if value is None, we will skip the payload part.
         let key_str = event_key_object.unwrap().to_string();
-        let mut record =
-            BaseRecord::<[u8], [u8]>::to(self.config.common.topic.as_str()).key(key_str.as_bytes());
-        let payload;
-        if let Some(value) = event_object {
-            payload = value.to_string();
-            record = record.payload(payload.as_bytes());
+        let Some(value) = event_object else {
+            let record = BaseRecord::<[u8], [u8]>::to(self.config.common.topic.as_str())
+                .key(key_str.as_bytes());
+            self.send(record).await?;
+            return Ok(());
+        };
+
+        match apply_payload_size_policy(value, &self.payload_size_opts)? {
+            PayloadSizeOutcome::Deliver(values) => {
+                for value in values {
+                    let payload = value.to_string();
+                    let record = BaseRecord::<[u8], [u8]>::to(self.config.common.topic.as_str())
+                        .key(key_str.as_bytes())
+                        .payload(payload.as_bytes());
+                    self.send(record).await?;
+                }
+            }
+            PayloadSizeOutcome::Dropped(value) => {
+                if let Some(topic) = &self.config.dead_letter_topic {
+                    let payload = value.to_string();
+                    let record = BaseRecord::<[u8], [u8]>::to(topic.as_str())
+                        .key(key_str.as_bytes())
+                        .payload(payload.as_bytes());
+                    self.send(record).await?;
+                }
+            }
         }
-        self.send(record).await?;
         Ok(())
     }
 
@@ -371,8 +507,16 @@ impl<const APPEND_ONLY: bool> Sink for KafkaSink<APPEND_ONLY> {
 }
 
 impl<const APPEND_ONLY: bool> Debug for KafkaSink<APPEND_ONLY> {
-    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
-        unimplemented!();
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The conductor holds a raw producer handle and is not `Debug`; the config (with
+        // secrets redacted by `KafkaCommon`) plus the transactional state is what error-path
+        // logging needs.
+        f.debug_struct("KafkaSink")
+            .field("config", &self.config)
+            .field("state", &self.state)
+            .field("pk_indices", &self.pk_indices)
+            .field("in_transaction_epoch", &self.in_transaction_epoch)
+            .finish_non_exhaustive()
     }
 }
 
@@ -389,6 +533,10 @@ impl KafkaTransactionConductor {
             config.common.set_security_properties(&mut c);
             c.set("bootstrap.servers", &config.common.brokers)
                 .set("message.timeout.ms", "5000");
+            if config.delivery_ordering == DELIVERY_ORDERING_PER_KEY {
+                c.set("enable.idempotence", "true")
+                    .set("max.in.flight.requests.per.connection", "1");
+            }
             config.use_transaction = false;
             if config.use_transaction {
                 c.set("transactional.id", &config.identifier); // required by kafka transaction
@@ -477,6 +625,10 @@ mod test {
             "properties.timeout".to_string() => "10s".to_string(),
             "properties.retry.max".to_string() => "20".to_string(),
             "properties.retry.interval".to_string() => "500ms".to_string(),
+            "delivery.ordering".to_string() => "per_key".to_string(),
+            "max.payload.bytes".to_string() => "1048576".to_string(),
+            "oversize.policy".to_string() => "truncate".to_string(),
+            "oversize.truncate.columns".to_string() => "blob, details".to_string(),
         };
         let config = KafkaConfig::from_hashmap(properties).unwrap();
         assert_eq!(config.common.brokers, "localhost:9092");
@@ -487,6 +639,12 @@ mod test {
         assert_eq!(config.timeout, Duration::from_secs(10));
         assert_eq!(config.max_retry_num, 20);
         assert_eq!(config.retry_interval, Duration::from_millis(500));
+        assert_eq!(config.delivery_ordering, DELIVERY_ORDERING_PER_KEY);
+        assert_eq!(config.max_payload_bytes, Some(1048576));
+        assert_eq!(
+            config.payload_size_opts().policy,
+            OversizePolicy::Truncate(vec!["blob".to_string(), "details".to_string()])
+        );
 
         // Optional fields eliminated.
         let properties: HashMap<String, String> = hashmap! {
@@ -502,6 +660,9 @@ mod test {
         assert_eq!(config.timeout, Duration::from_secs(5));
         assert_eq!(config.max_retry_num, 3);
         assert_eq!(config.retry_interval, Duration::from_millis(100));
+        assert_eq!(config.delivery_ordering, DELIVERY_ORDERING_NONE);
+        assert_eq!(config.max_payload_bytes, None);
+        assert_eq!(config.payload_size_opts().policy, OversizePolicy::Error);
 
         // Invalid u32 input.
         let properties: HashMap<String, String> = hashmap! {
@@ -535,6 +696,57 @@ mod test {
             "properties.retry.interval".to_string() => "500minutes".to_string(),  // error!
         };
         assert!(KafkaConfig::from_hashmap(properties).is_err());
+
+        // Invalid delivery ordering input.
+        let properties: HashMap<String, String> = hashmap! {
+            "connector".to_string() => "kafka".to_string(),
+            "properties.bootstrap.server".to_string() => "localhost:9092".to_string(),
+            "topic".to_string() => "test".to_string(),
+            "type".to_string() => "upsert".to_string(),
+            "identifier".to_string() => "test_sink_6".to_string(),
+            "delivery.ordering".to_string() => "per_partition".to_string(),  // error!
+        };
+        assert!(KafkaConfig::from_hashmap(properties).is_err());
+
+        // Invalid oversize policy input.
+        let properties: HashMap<String, String> = hashmap! {
+            "connector".to_string() => "kafka".to_string(),
+            "properties.bootstrap.server".to_string() => "localhost:9092".to_string(),
+            "topic".to_string() => "test".to_string(),
+            "type".to_string() => "upsert".to_string(),
+            "identifier".to_string() => "test_sink_7".to_string(),
+            "oversize.policy".to_string() => "ignore".to_string(),  // error!
+        };
+        assert!(KafkaConfig::from_hashmap(properties).is_err());
+
+        // Truncate policy without columns to truncate.
+        let properties: HashMap<String, String> = hashmap! {
+            "connector".to_string() => "kafka".to_string(),
+            "properties.bootstrap.server".to_string() => "localhost:9092".to_string(),
+            "topic".to_string() => "test".to_string(),
+            "type".to_string() => "upsert".to_string(),
+            "identifier".to_string() => "test_sink_8".to_string(),
+            "oversize.policy".to_string() => "truncate".to_string(),  // error!
+        };
+        assert!(KafkaConfig::from_hashmap(properties).is_err());
+    }
+
+    #[test]
+    fn debug_kafka_config_redacts_secrets() {
+        let properties: HashMap<String, String> = hashmap! {
+            "connector".to_string() => "kafka".to_string(),
+            "properties.bootstrap.server".to_string() => "localhost:9092".to_string(),
+            "topic".to_string() => "test".to_string(),
+            "type".to_string() => "append-only".to_string(),
+            "identifier".to_string() => "test_sink_1".to_string(),
+            "properties.sasl.username".to_string() => "alice".to_string(),
+            "properties.sasl.password".to_string() => "s3cret".to_string(),
+        };
+        let config = KafkaConfig::from_hashmap(properties).unwrap();
+        let debug = format!("{:?}", config);
+        assert!(debug.contains("alice"));
+        assert!(!debug.contains("s3cret"));
+        assert!(debug.contains(crate::common::REDACTED));
     }
 
     #[ignore]
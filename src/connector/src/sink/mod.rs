@@ -40,6 +40,7 @@ use thiserror::Error;
 pub use tracing;
 
 use self::catalog::{SinkCatalog, SinkType};
+use crate::registry::{BoxSink, CustomSinkConfig};
 use crate::sink::kafka::{KafkaConfig, KafkaSink, KAFKA_SINK};
 use crate::sink::kinesis::{KinesisSink, KinesisSinkConfig, KINESIS_SINK};
 use crate::sink::redis::{RedisConfig, RedisSink};
@@ -75,6 +76,7 @@ pub enum SinkConfig {
     Kafka(Box<KafkaConfig>),
     Remote(RemoteConfig),
     Kinesis(Box<KinesisSinkConfig>),
+    Custom(CustomSinkConfig),
     BlackHole,
 }
 
@@ -120,6 +122,8 @@ impl SinkConfig {
         properties.remove(PRIVATE_LINK_TARGET_KEY);
         properties.remove(CONNECTION_NAME_KEY);
 
+        crate::secret::resolve_secret_refs(&mut properties).map_err(SinkError::Config)?;
+
         let sink_type = properties
             .get(CONNECTOR_TYPE_KEY)
             .ok_or_else(|| SinkError::Config(anyhow!("missing config: {}", CONNECTOR_TYPE_KEY)))?;
@@ -131,15 +135,26 @@ impl SinkConfig {
                 KinesisSinkConfig::from_hashmap(properties)?,
             ))),
             BLACKHOLE_SINK => Ok(SinkConfig::BlackHole),
-            _ => Ok(SinkConfig::Remote(RemoteConfig::from_hashmap(properties)?)),
+            _ => {
+                let connector = sink_type.to_lowercase();
+                if crate::registry::get_sink_factory(&connector).is_some() {
+                    Ok(SinkConfig::Custom(CustomSinkConfig {
+                        connector,
+                        properties,
+                    }))
+                } else {
+                    Ok(SinkConfig::Remote(RemoteConfig::from_hashmap(properties)?))
+                }
+            }
         }
     }
 
-    pub fn get_connector(&self) -> &'static str {
+    pub fn get_connector(&self) -> &str {
         match self {
             SinkConfig::Kafka(_) => "kafka",
             SinkConfig::Redis(_) => "redis",
             SinkConfig::Remote(_) => "remote",
+            SinkConfig::Custom(cfg) => &cfg.connector,
             SinkConfig::BlackHole => "blackhole",
             SinkConfig::Kinesis(_) => "kinesis",
         }
@@ -156,6 +171,8 @@ pub enum SinkImpl {
     BlackHole(BlockHoleSink),
     Kinesis(KinesisSink<true>),
     UpsertKinesis(KinesisSink<false>),
+    /// A sink created by a registered [`crate::registry::SinkConnectorFactory`].
+    Custom(BoxSink),
 }
 
 #[macro_export]
@@ -172,6 +189,7 @@ macro_rules! dispatch_sink {
             SinkImpl::BlackHole($sink) => $body,
             SinkImpl::Kinesis($sink) => $body,
             SinkImpl::UpsertKinesis($sink) => $body,
+            SinkImpl::Custom($sink) => $body,
         }
     }};
 }
@@ -184,6 +202,27 @@ impl SinkImpl {
         connector_params: ConnectorParams,
         sink_type: SinkType,
         sink_id: u64,
+    ) -> Result<Self> {
+        let connector = cfg.get_connector().to_owned();
+        Self::build(
+            cfg,
+            schema,
+            pk_indices,
+            connector_params,
+            sink_type,
+            sink_id,
+        )
+        .await
+        .map_err(|e| e.with_sink_context(&connector, sink_id))
+    }
+
+    async fn build(
+        cfg: SinkConfig,
+        schema: Schema,
+        pk_indices: Vec<usize>,
+        connector_params: ConnectorParams,
+        sink_type: SinkType,
+        sink_id: u64,
     ) -> Result<Self> {
         Ok(match cfg {
             SinkConfig::Redis(cfg) => SinkImpl::Redis(RedisSink::new(cfg, schema)?),
@@ -228,6 +267,26 @@ impl SinkImpl {
                     )
                 }
             }
+            SinkConfig::Custom(cfg) => {
+                let factory =
+                    crate::registry::get_sink_factory(&cfg.connector).ok_or_else(|| {
+                        SinkError::Config(anyhow!(
+                            "sink connector '{}' is not registered",
+                            cfg.connector
+                        ))
+                    })?;
+                SinkImpl::Custom(
+                    factory
+                        .create_sink(
+                            cfg.properties,
+                            schema,
+                            pk_indices,
+                            sink_type.is_append_only(),
+                            sink_id,
+                        )
+                        .await?,
+                )
+            }
             SinkConfig::BlackHole => SinkImpl::BlackHole(BlockHoleSink),
         })
     }
@@ -236,6 +295,18 @@ impl SinkImpl {
         cfg: SinkConfig,
         sink_catalog: SinkCatalog,
         connector_rpc_endpoint: Option<String>,
+    ) -> Result<()> {
+        let connector = cfg.get_connector().to_owned();
+        let sink_id = sink_catalog.id.sink_id as u64;
+        Self::validate_inner(cfg, sink_catalog, connector_rpc_endpoint)
+            .await
+            .map_err(|e| e.with_sink_context(&connector, sink_id))
+    }
+
+    async fn validate_inner(
+        cfg: SinkConfig,
+        sink_catalog: SinkCatalog,
+        connector_rpc_endpoint: Option<String>,
     ) -> Result<()> {
         match cfg {
             SinkConfig::Redis(cfg) => {
@@ -262,6 +333,18 @@ impl SinkImpl {
                     RemoteSink::<false>::validate(cfg, sink_catalog, connector_rpc_endpoint).await
                 }
             }
+            SinkConfig::Custom(cfg) => {
+                let factory =
+                    crate::registry::get_sink_factory(&cfg.connector).ok_or_else(|| {
+                        SinkError::Config(anyhow!(
+                            "sink connector '{}' is not registered",
+                            cfg.connector
+                        ))
+                    })?;
+                factory
+                    .validate(cfg.properties, sink_catalog.sink_type.is_append_only())
+                    .await
+            }
             SinkConfig::BlackHole => Ok(()),
         }
     }
@@ -279,8 +362,33 @@ pub enum SinkError {
     Remote(String),
     #[error("Json parse error: {0}")]
     JsonParse(String),
+    #[error("Payload size error: {0}")]
+    PayloadSize(String),
     #[error("config error: {0}")]
     Config(#[from] anyhow::Error),
+    #[error("sink {sink_id} (connector '{connector}'): {error}")]
+    WithContext {
+        connector: String,
+        sink_id: u64,
+        error: Box<SinkError>,
+    },
+}
+
+impl SinkError {
+    /// Tag the error with the connector name and the id of the sink it came from, so that
+    /// errors surfaced from deep inside a connector client can be attributed to a specific
+    /// sink. Tagging is idempotent: an already-tagged error is returned unchanged, so retries
+    /// do not stack context.
+    pub fn with_sink_context(self, connector: &str, sink_id: u64) -> Self {
+        match self {
+            SinkError::WithContext { .. } => self,
+            error => SinkError::WithContext {
+                connector: connector.to_owned(),
+                sink_id,
+                error: Box::new(error),
+            },
+        }
+    }
 }
 
 impl From<RpcError> for SinkError {
@@ -262,6 +262,176 @@ pub fn chunk_to_json(chunk: StreamChunk, schema: &Schema) -> Result<Vec<String>>
     Ok(records)
 }
 
+/// What to do with a message whose serialized payload exceeds the configured maximum size.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum OversizePolicy {
+    /// Fail the sink. This is the default, as silently losing data is usually worse than
+    /// stopping the stream.
+    #[default]
+    Error,
+    /// Replace the values of the given columns with `null`, in order, until the payload fits.
+    /// Fails if the payload is still too large after all listed columns are truncated.
+    Truncate(Vec<String>),
+    /// Drop the message. The dropped payload is returned to the caller, which may forward it to
+    /// a dead-letter queue instead of discarding it.
+    Drop,
+    /// Repeatedly split the longest array-valued column in half across separate messages until
+    /// every message fits. Fails if a message is still too large when no array can be split
+    /// further.
+    SplitArrays,
+}
+
+/// Payload size guardrails applied to the messages produced by the `gen_*_message_stream`
+/// adapters before they are handed to the external system.
+#[derive(Debug, Clone, Default)]
+pub struct PayloadSizeOpts {
+    /// Maximum serialized payload size in bytes. `None` disables the check.
+    pub max_payload_bytes: Option<usize>,
+    pub policy: OversizePolicy,
+}
+
+/// Result of applying [`PayloadSizeOpts`] to a single message payload.
+#[derive(Debug, PartialEq)]
+pub enum PayloadSizeOutcome {
+    /// Payloads to deliver, in order. Contains more than one element only for
+    /// [`OversizePolicy::SplitArrays`].
+    Deliver(Vec<Value>),
+    /// The payload was dropped by [`OversizePolicy::Drop`]; the caller may forward it to a
+    /// dead-letter queue.
+    Dropped(Value),
+}
+
+/// Enforces the payload size limit of `opts` on a single message payload.
+pub fn apply_payload_size_policy(
+    value: Value,
+    opts: &PayloadSizeOpts,
+) -> Result<PayloadSizeOutcome> {
+    let Some(max_bytes) = opts.max_payload_bytes else {
+        return Ok(PayloadSizeOutcome::Deliver(vec![value]));
+    };
+    if payload_size(&value) <= max_bytes {
+        return Ok(PayloadSizeOutcome::Deliver(vec![value]));
+    }
+
+    match &opts.policy {
+        OversizePolicy::Error => Err(payload_too_large(&value, max_bytes)),
+        OversizePolicy::Truncate(columns) => {
+            let mut value = value;
+            for column in columns {
+                truncate_column(&mut value, column);
+                if payload_size(&value) <= max_bytes {
+                    return Ok(PayloadSizeOutcome::Deliver(vec![value]));
+                }
+            }
+            Err(payload_too_large(&value, max_bytes))
+        }
+        OversizePolicy::Drop => {
+            warn!(
+                "dropping sink payload of {} bytes exceeding the maximum of {} bytes",
+                payload_size(&value),
+                max_bytes
+            );
+            Ok(PayloadSizeOutcome::Dropped(value))
+        }
+        OversizePolicy::SplitArrays => {
+            split_arrays(value, max_bytes).map(PayloadSizeOutcome::Deliver)
+        }
+    }
+}
+
+fn payload_size(value: &Value) -> usize {
+    value.to_string().len()
+}
+
+fn payload_too_large(value: &Value, max_bytes: usize) -> SinkError {
+    SinkError::PayloadSize(format!(
+        "sink payload of {} bytes exceeds the configured maximum of {} bytes",
+        payload_size(value),
+        max_bytes
+    ))
+}
+
+/// Replaces the value of `column` with `null` wherever it appears, including in the nested
+/// `payload` / `before` / `after` objects of envelope formats like Debezium.
+fn truncate_column(value: &mut Value, column: &str) {
+    if let Value::Object(object) = value {
+        for (key, value) in object.iter_mut() {
+            if key == column {
+                *value = Value::Null;
+            } else if matches!(key.as_str(), "payload" | "before" | "after") {
+                truncate_column(value, column);
+            }
+        }
+    }
+}
+
+/// Recursively splits `value` across multiple messages until each of them fits in `max_bytes`,
+/// halving the longest array-valued column at every step.
+fn split_arrays(value: Value, max_bytes: usize) -> Result<Vec<Value>> {
+    if payload_size(&value) <= max_bytes {
+        return Ok(vec![value]);
+    }
+    let Some((left, right)) = split_longest_array(&value) else {
+        return Err(payload_too_large(&value, max_bytes));
+    };
+    let mut res = split_arrays(left, max_bytes)?;
+    res.extend(split_arrays(right, max_bytes)?);
+    Ok(res)
+}
+
+/// Splits the longest array-valued column of `value` in half, returning two copies of the
+/// message holding the first and the second half respectively. Returns `None` if there is no
+/// array with more than one element to split.
+fn split_longest_array(value: &Value) -> Option<(Value, Value)> {
+    fn find_longest(
+        value: &Value,
+        path: &mut Vec<String>,
+        longest: &mut Option<(Vec<String>, usize)>,
+    ) {
+        if let Value::Object(object) = value {
+            for (key, value) in object {
+                match value {
+                    Value::Array(elements) if elements.len() >= 2 => {
+                        if longest
+                            .as_ref()
+                            .map_or(true, |(_, len)| elements.len() > *len)
+                        {
+                            path.push(key.clone());
+                            *longest = Some((path.clone(), elements.len()));
+                            path.pop();
+                        }
+                    }
+                    _ if matches!(key.as_str(), "payload" | "before" | "after") => {
+                        path.push(key.clone());
+                        find_longest(value, path, longest);
+                        path.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn array_at_mut<'a>(value: &'a mut Value, path: &[String]) -> &'a mut Vec<Value> {
+        let mut current = value;
+        for key in path {
+            current = current.get_mut(key).unwrap();
+        }
+        current.as_array_mut().unwrap()
+    }
+
+    let mut longest = None;
+    find_longest(value, &mut Vec::new(), &mut longest);
+    let (path, _) = longest?;
+
+    let mut left = value.clone();
+    let mut right = value.clone();
+    let array = array_at_mut(&mut left, &path);
+    let tail = array.split_off(array.len() / 2);
+    *array_at_mut(&mut right, &path) = tail;
+    Some((left, right))
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct UpsertAdapterOpts {}
 
@@ -321,3 +491,97 @@ pub async fn gen_append_only_message_stream<'a>(
         yield (event_key_object, event_object);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(max_payload_bytes: usize, policy: OversizePolicy) -> PayloadSizeOpts {
+        PayloadSizeOpts {
+            max_payload_bytes: Some(max_payload_bytes),
+            policy,
+        }
+    }
+
+    #[test]
+    fn test_payload_within_limit() {
+        let value = json!({"id": 1, "v": "short"});
+        let outcome =
+            apply_payload_size_policy(value.clone(), &opts(1024, OversizePolicy::Error)).unwrap();
+        assert_eq!(outcome, PayloadSizeOutcome::Deliver(vec![value]));
+    }
+
+    #[test]
+    fn test_oversized_payload_error() {
+        let value = json!({"id": 1, "blob": "x".repeat(100)});
+        assert!(apply_payload_size_policy(value, &opts(32, OversizePolicy::Error)).is_err());
+    }
+
+    #[test]
+    fn test_oversized_payload_truncate() {
+        let value = json!({"id": 1, "blob": "x".repeat(100)});
+        let outcome = apply_payload_size_policy(
+            value,
+            &opts(32, OversizePolicy::Truncate(vec!["blob".to_string()])),
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            PayloadSizeOutcome::Deliver(vec![json!({"id": 1, "blob": Value::Null})])
+        );
+
+        // Truncation also reaches into the Debezium envelope.
+        let value = json!({"payload": {"after": {"id": 1, "blob": "x".repeat(100)}}});
+        let outcome = apply_payload_size_policy(
+            value,
+            &opts(48, OversizePolicy::Truncate(vec!["blob".to_string()])),
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            PayloadSizeOutcome::Deliver(vec![
+                json!({"payload": {"after": {"id": 1, "blob": Value::Null}}})
+            ])
+        );
+
+        // Still too large after truncating all listed columns.
+        let value = json!({"id": 1, "blob": "x".repeat(100), "other": "y".repeat(100)});
+        assert!(apply_payload_size_policy(
+            value,
+            &opts(32, OversizePolicy::Truncate(vec!["blob".to_string()])),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_oversized_payload_drop() {
+        let value = json!({"id": 1, "blob": "x".repeat(100)});
+        let outcome =
+            apply_payload_size_policy(value.clone(), &opts(32, OversizePolicy::Drop)).unwrap();
+        assert_eq!(outcome, PayloadSizeOutcome::Dropped(value));
+    }
+
+    #[test]
+    fn test_oversized_payload_split_arrays() {
+        let value = json!({"id": 1, "vs": (0..16).collect::<Vec<_>>()});
+        let max_bytes = 32;
+        let PayloadSizeOutcome::Deliver(values) =
+            apply_payload_size_policy(value, &opts(max_bytes, OversizePolicy::SplitArrays))
+                .unwrap()
+        else {
+            panic!("expected delivered payloads");
+        };
+        assert!(values.len() > 1);
+        let mut elements = Vec::new();
+        for value in &values {
+            assert!(value.to_string().len() <= max_bytes);
+            assert_eq!(value["id"], json!(1));
+            elements.extend(value["vs"].as_array().unwrap().clone());
+        }
+        assert_eq!(elements, (0..16).map(|v| json!(v)).collect::<Vec<_>>());
+
+        // A payload without splittable arrays fails instead.
+        let value = json!({"id": 1, "blob": "x".repeat(100)});
+        assert!(apply_payload_size_policy(value, &opts(32, OversizePolicy::SplitArrays)).is_err());
+    }
+}
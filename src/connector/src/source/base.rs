@@ -38,6 +38,7 @@ use super::kafka::KafkaMeta;
 use super::monitor::SourceMetrics;
 use super::nexmark::source::message::NexmarkMeta;
 use crate::parser::ParserConfig;
+use crate::registry::{BoxSplitEnumerator, BoxSplitReader, CustomProperties, CustomSplit};
 use crate::source::cdc::{
     CdcProperties, CdcSplitReader, DebeziumCdcSplit, DebeziumSplitEnumerator, CITUS_CDC_CONNECTOR,
     MYSQL_CDC_CONNECTOR, POSTGRES_CDC_CONNECTOR,
@@ -91,12 +92,72 @@ pub struct SourceCtrlOpts {
     // comes from developer::stream_chunk_size in stream scenario and developer::batch_chunk_size
     // in batch scenario
     pub chunk_size: usize,
+    /// Label attached to connector client ids for cost attribution, from the `resource_label`
+    /// system parameter. `None` if the parameter is unset.
+    pub resource_label: Option<String>,
 }
 
 impl Default for SourceCtrlOpts {
     fn default() -> Self {
         Self {
             chunk_size: MAX_CHUNK_SIZE,
+            resource_label: None,
+        }
+    }
+}
+
+/// What to do with a record that fails to parse, configured per source with the
+/// `parse.error.policy` option in the WITH clause.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseErrorPolicy {
+    /// Count the error in metrics and skip the record. This is the default.
+    #[default]
+    Skip,
+    /// Like `Skip`, but additionally route the raw payload together with the
+    /// error to the dedicated `parse_dead_letter` log target, so malformed
+    /// records can be collected and replayed.
+    DeadLetter,
+    /// Propagate the error and fail the source.
+    Fail,
+}
+
+pub const PARSE_ERROR_POLICY_KEY: &str = "parse.error.policy";
+
+/// The `rate_limit` option in the WITH clause: the maximum number of rows per second that a
+/// single source actor may emit, so that e.g. the backfill of a huge kafka topic does not
+/// starve other jobs. `0` (the default) means unlimited. The limit can be changed on a running
+/// source with `ALTER SOURCE ... SET (rate_limit = '...')`.
+pub const RATE_LIMIT_KEY: &str = "rate_limit";
+
+/// Parse the `rate_limit` option from the WITH clause properties. Returns `0` if unset.
+pub fn rate_limit_from_props(props: &HashMap<String, String>) -> Result<u32> {
+    match props.get(RATE_LIMIT_KEY) {
+        None => Ok(0),
+        Some(v) => v.parse().map_err(|_| {
+            anyhow!(
+                "invalid {}: {}, expected a non-negative integer number of rows per second",
+                RATE_LIMIT_KEY,
+                v
+            )
+        }),
+    }
+}
+
+impl ParseErrorPolicy {
+    pub fn from_props(props: &HashMap<String, String>) -> Result<Self> {
+        match props.get(PARSE_ERROR_POLICY_KEY) {
+            None => Ok(Self::default()),
+            Some(v) => match v.to_lowercase().as_str() {
+                "skip" => Ok(Self::Skip),
+                "dead_letter" => Ok(Self::DeadLetter),
+                "fail" => Ok(Self::Fail),
+                _ => Err(anyhow!(
+                    "invalid {}: {}, expected one of skip, dead_letter, fail",
+                    PARSE_ERROR_POLICY_KEY,
+                    v
+                )
+                .into()),
+            },
         }
     }
 }
@@ -106,6 +167,7 @@ pub struct SourceContext {
     pub source_info: SourceInfo,
     pub metrics: Arc<SourceMetrics>,
     pub source_ctrl_opts: SourceCtrlOpts,
+    pub parse_error_policy: ParseErrorPolicy,
     error_suppressor: Option<Arc<Mutex<ErrorSuppressor>>>,
 }
 impl SourceContext {
@@ -125,6 +187,7 @@ impl SourceContext {
             metrics,
             error_suppressor: None,
             source_ctrl_opts,
+            parse_error_policy: ParseErrorPolicy::default(),
         }
     }
 
@@ -132,6 +195,10 @@ impl SourceContext {
         self.error_suppressor = Some(error_suppressor)
     }
 
+    pub fn set_parse_error_policy(&mut self, policy: ParseErrorPolicy) {
+        self.parse_error_policy = policy;
+    }
+
     pub(crate) fn report_user_source_error(&self, e: RwError) -> RwResult<()> {
         // Repropagate the error if batch
         if self.source_info.fragment_id == u32::MAX {
@@ -244,6 +311,8 @@ pub enum ConnectorProperties {
     PostgresCdc(Box<CdcProperties>),
     CitusCdc(Box<CdcProperties>),
     GooglePubsub(Box<PubsubProperties>),
+    /// Properties of a connector registered in [`crate::registry`].
+    Custom(Box<CustomProperties>),
     Dummy(Box<()>),
 }
 
@@ -303,6 +372,8 @@ pub enum SplitImpl {
     PostgresCdc(DebeziumCdcSplit),
     CitusCdc(DebeziumCdcSplit),
     S3(FsSplit),
+    /// A split of a connector registered in [`crate::registry`].
+    Custom(CustomSplit),
 }
 
 // for the `FsSourceExecutor`
@@ -335,6 +406,7 @@ pub enum SplitReaderImpl {
     PostgresCdc(Box<CdcSplitReader>),
     CitusCdc(Box<CdcSplitReader>),
     GooglePubsub(Box<PubsubSplitReader>),
+    Custom(BoxSplitReader),
 }
 
 pub enum SplitEnumeratorImpl {
@@ -348,6 +420,7 @@ pub enum SplitEnumeratorImpl {
     CitusCdc(DebeziumSplitEnumerator),
     GooglePubsub(PubsubSplitEnumerator),
     S3(S3SplitEnumerator),
+    Custom(BoxSplitEnumerator),
 }
 
 impl_connector_properties! {
@@ -19,10 +19,11 @@ use serde::Deserialize;
 pub use source::S3FileReader;
 
 use crate::aws_auth::AwsAuthProps;
+use crate::impl_redacted_debug;
 
 pub const S3_CONNECTOR: &str = "s3";
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct S3Properties {
     #[serde(rename = "s3.region_name")]
     pub region_name: String,
@@ -38,6 +39,18 @@ pub struct S3Properties {
     endpoint_url: Option<String>,
 }
 
+impl_redacted_debug!(
+    S3Properties,
+    fields = [
+        region_name,
+        bucket_name,
+        match_pattern,
+        access,
+        endpoint_url
+    ],
+    secrets = [secret]
+);
+
 impl From<&S3Properties> for AwsAuthProps {
     fn from(props: &S3Properties) -> Self {
         Self {
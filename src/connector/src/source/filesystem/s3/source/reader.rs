@@ -258,8 +258,11 @@ mod tests {
         ];
 
         let csv_config = CsvParserConfig {
-            delimiter: b',',
+            delimiter: b",".to_vec(),
             has_header: true,
+            quote: b'"',
+            escape: None,
+            null_literal: None,
         };
 
         let config = ParserConfig {
@@ -124,6 +124,36 @@ impl SplitEnumerator for KafkaSplitEnumerator {
 }
 
 impl KafkaSplitEnumerator {
+    /// List splits whose start offsets are resolved from the given timestamp. Used to translate
+    /// the progress of a source onto a mirrored cluster, where the offsets of the original
+    /// cluster are meaningless but the (mirrored) timestamps are not.
+    pub async fn list_splits_start_from_timestamp(
+        &mut self,
+        timestamp_millis: i64,
+    ) -> anyhow::Result<Vec<KafkaSplit>> {
+        let topic_partitions = self.fetch_topic_partition().await.map_err(|e| {
+            anyhow!(format!(
+                "failed to fetch metadata from kafka ({}), error: {}",
+                self.broker_address, e
+            ))
+        })?;
+
+        let mut start_offsets = self
+            .fetch_offset_for_time(topic_partitions.as_ref(), timestamp_millis)
+            .await?;
+
+        Ok(topic_partitions
+            .into_iter()
+            .map(|partition| KafkaSplit {
+                topic: self.topic.clone(),
+                partition,
+                // the start offset of a split is the last consumed offset, hence the `- 1`
+                start_offset: start_offsets.remove(&partition).unwrap().map(|o| o - 1),
+                stop_offset: None,
+            })
+            .collect())
+    }
+
     pub async fn list_splits_batch(
         &mut self,
         expect_start_timestamp_millis: Option<i64>,
@@ -213,6 +243,27 @@ impl KafkaSplitEnumerator {
             .collect::<Vec<KafkaSplit>>())
     }
 
+    /// Fetch the latest available offset (high watermark) of each partition of the topic, for
+    /// reporting consumer lag.
+    pub async fn fetch_latest_offsets(&self) -> anyhow::Result<HashMap<i32, i64>> {
+        let topic_partitions = self.fetch_topic_partition().await.map_err(|e| {
+            anyhow!(format!(
+                "failed to fetch metadata from kafka ({}), error: {}",
+                self.broker_address, e
+            ))
+        })?;
+
+        let mut map = HashMap::new();
+        for partition in topic_partitions {
+            let (_, high_watermark) = self
+                .client
+                .fetch_watermarks(self.topic.as_str(), partition, self.sync_call_timeout)
+                .await?;
+            map.insert(partition, high_watermark);
+        }
+        Ok(map)
+    }
+
     async fn fetch_stop_offset(
         &self,
         partitions: &[i32],
@@ -31,6 +31,9 @@ use crate::deserialize_duration_from_string;
 pub const KAFKA_CONNECTOR: &str = "kafka";
 pub const KAFKA_PROPS_BROKER_KEY: &str = "properties.bootstrap.server";
 pub const KAFKA_PROPS_BROKER_KEY_ALIAS: &str = "kafka.brokers";
+pub const KAFKA_PROPS_CONSUMER_GROUP_KEY: &str = "properties.group.id";
+pub const KAFKA_PROPS_CONSUMER_GROUP_KEY_ALIAS: &str = "kafka.consumer.group";
+pub const KAFKA_PROPS_SYNC_CALL_TIMEOUT_KEY: &str = "properties.sync.call.timeout";
 pub const PRIVATELINK_CONNECTION: &str = "privatelink";
 
 #[derive(Clone, Debug, Deserialize)]
@@ -72,6 +72,23 @@ impl SplitReader for KafkaSplitReader {
 
         properties.common.set_security_properties(&mut config);
 
+        // Attach the resource label (if any) and the source/actor ids to the client id, so that
+        // broker-side quotas and logs can attribute traffic to the job.
+        if config.get("client.id").is_none() {
+            let source_info = &source_ctx.source_info;
+            let client_id = match &source_ctx.source_ctrl_opts.resource_label {
+                Some(label) => format!(
+                    "rw-consumer-{}-{}-{}",
+                    label, source_info.source_id.table_id, source_info.actor_id
+                ),
+                None => format!(
+                    "rw-consumer-{}-{}",
+                    source_info.source_id.table_id, source_info.actor_id
+                ),
+            };
+            config.set("client.id", client_id);
+        }
+
         if config.get("group.id").is_none() {
             config.set(
                 "group.id",
@@ -68,4 +68,8 @@ impl KafkaSplit {
     pub fn get_topic_and_partition(&self) -> (String, i32) {
         (self.topic.clone(), self.partition)
     }
+
+    pub fn get_start_offset(&self) -> Option<i64> {
+        self.start_offset
+    }
 }
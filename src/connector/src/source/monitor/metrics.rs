@@ -22,6 +22,8 @@ pub struct SourceMetrics {
     pub partition_input_bytes: GenericCounterVec<AtomicU64>,
     /// User error reporting
     pub user_source_error_count: GenericCounterVec<AtomicU64>,
+    /// Time spent throttled by the `rate_limit` source option.
+    pub source_throttled_duration_ms: GenericCounterVec<AtomicU64>,
 }
 
 impl SourceMetrics {
@@ -53,11 +55,19 @@ impl SourceMetrics {
             registry,
         )
         .unwrap();
+        let source_throttled_duration_ms = register_int_counter_vec_with_registry!(
+            "source_throttled_duration_ms",
+            "Total time in milliseconds the source reader has slept to respect `rate_limit`",
+            &["actor_id", "source_id"],
+            registry
+        )
+        .unwrap();
         SourceMetrics {
             registry,
             partition_input_count,
             partition_input_bytes,
             user_source_error_count,
+            source_throttled_duration_ms,
         }
     }
 
@@ -32,10 +32,12 @@ use tempfile::NamedTempFile;
 use url::Url;
 
 use crate::aws_utils::load_file_descriptor_from_s3;
+use crate::common::RedactedProps;
+use crate::impl_redacted_debug;
 
 pub const PULSAR_CONNECTOR: &str = "pulsar";
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct PulsarOauth {
     #[serde(rename = "oauth.issuer.url")]
     pub issuer_url: String,
@@ -54,7 +56,21 @@ pub struct PulsarOauth {
     pub s3_credentials: HashMap<String, String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl std::fmt::Debug for PulsarOauth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `s3_credentials` is a free-form map that may carry an AWS secret key, so it goes
+        // through the key-based redaction instead of the derived output.
+        f.debug_struct("PulsarOauth")
+            .field("issuer_url", &self.issuer_url)
+            .field("credentials_url", &self.credentials_url)
+            .field("audience", &self.audience)
+            .field("scope", &self.scope)
+            .field("s3_credentials", &RedactedProps(&self.s3_credentials))
+            .finish()
+    }
+}
+
+#[derive(Clone, Deserialize)]
 pub struct PulsarProperties {
     #[serde(rename = "topic", alias = "pulsar.topic")]
     pub topic: String,
@@ -75,6 +91,12 @@ pub struct PulsarProperties {
     pub oauth: Option<PulsarOauth>,
 }
 
+impl_redacted_debug!(
+    PulsarProperties,
+    fields = [topic, service_url, scan_startup_mode, time_offset, oauth],
+    secrets = [auth_token]
+);
+
 impl PulsarProperties {
     pub async fn build_pulsar_client(&self) -> Result<Pulsar<TokioExecutor>> {
         let mut pulsar_builder = Pulsar::builder(&self.service_url, TokioExecutor);
@@ -14,9 +14,11 @@
 
 pub mod bench;
 pub mod compute;
+pub mod debug;
 pub mod hummock;
 pub mod meta;
 pub mod profile;
 pub mod scale;
+pub mod source;
 pub mod table;
 pub mod trace;
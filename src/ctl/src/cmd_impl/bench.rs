@@ -12,15 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::Subcommand;
 use futures::future::try_join_all;
 use futures::{pin_mut, Future, StreamExt};
-use risingwave_common::util::epoch::EpochPair;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+use risingwave_common::array::{DataChunk, DataChunkTestExt, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::estimate_size::EstimateSize;
+use risingwave_common::field_generator::VarcharProperty;
+use risingwave_common::row::{OwnedRow, Row};
+use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::{Epoch, EpochPair};
+use risingwave_connector::sink::catalog::SinkType;
+use risingwave_connector::sink::{Sink, SinkConfig, SinkImpl};
+use risingwave_connector::{dispatch_sink, ConnectorParams};
 use risingwave_storage::store::PrefetchOptions;
 use size::Size;
 use tokio::task::JoinHandle;
@@ -40,6 +53,45 @@ pub enum BenchCommands {
         threads: usize,
         data_dir: Option<String>,
     },
+    /// benchmark a mixed read/write workload on a state table
+    Workload {
+        /// name of the materialized view to operate on
+        mv_name: String,
+        /// fraction of operations that are point gets; the rest insert generated rows
+        #[clap(long, default_value_t = 0.5)]
+        read_ratio: f64,
+        /// distribution of the keys picked by the operations: `uniform` or `zipfian`
+        #[clap(long, default_value = "uniform")]
+        distribution: String,
+        /// number of distinct generated rows the workload picks from
+        #[clap(long, default_value_t = 100000)]
+        key_space: usize,
+        /// number of concurrent workers
+        #[clap(long, default_value_t = 1)]
+        concurrency: usize,
+        /// number of operations each worker runs
+        #[clap(long, default_value_t = 100000)]
+        operations: usize,
+        data_dir: Option<String>,
+    },
+    /// benchmark writing generated stream chunks directly into a sink connector
+    Sink {
+        /// sink properties in `key=value` form, e.g. `-p connector=kafka -p topic=bench`
+        #[clap(long, short = 'p')]
+        properties: Vec<String>,
+        /// comma-separated types of the generated columns, e.g. `int,varchar`
+        #[clap(long, default_value = "int,varchar")]
+        schema: String,
+        /// number of rows per stream chunk
+        #[clap(long, default_value_t = 1024)]
+        chunk_size: usize,
+        /// number of chunks written in each epoch, i.e. between two commits
+        #[clap(long, default_value_t = 16)]
+        chunks_per_epoch: usize,
+        /// number of epochs to write before reporting
+        #[clap(long, default_value_t = 100)]
+        epochs: u64,
+    },
 }
 
 /// Spawn a tokio task with output of `anyhow::Result`, so that we can write dead loop in async
@@ -75,7 +127,6 @@ impl InterestedMetrics {
 }
 
 pub async fn do_bench(context: &CtlContext, cmd: BenchCommands) -> Result<()> {
-    let meta = context.meta_client().await?;
     let next_cnt = Arc::new(AtomicU64::new(0));
     let iter_cnt = Arc::new(AtomicU64::new(0));
     match cmd {
@@ -84,6 +135,7 @@ pub async fn do_bench(context: &CtlContext, cmd: BenchCommands) -> Result<()> {
             threads,
             data_dir,
         } => {
+            let meta = context.meta_client().await?;
             let (hummock, metrics) = context
                 .hummock_store_with_metrics(HummockServiceOpts::from_env(data_dir)?)
                 .await?;
@@ -139,7 +191,268 @@ pub async fn do_bench(context: &CtlContext, cmd: BenchCommands) -> Result<()> {
                 result?;
             }
         }
+        BenchCommands::Workload {
+            mv_name,
+            read_ratio,
+            distribution,
+            key_space,
+            concurrency,
+            operations,
+            data_dir,
+        } => {
+            let distribution = match distribution.as_str() {
+                "uniform" => KeyDistribution::Uniform,
+                "zipfian" => KeyDistribution::Zipfian,
+                d => {
+                    return Err(anyhow!(
+                        "invalid distribution `{d}`, expected `uniform` or `zipfian`"
+                    ))
+                }
+            };
+            bench_workload(
+                context,
+                mv_name,
+                read_ratio,
+                distribution,
+                key_space,
+                concurrency,
+                operations,
+                data_dir,
+            )
+            .await?
+        }
+        BenchCommands::Sink {
+            properties,
+            schema,
+            chunk_size,
+            chunks_per_epoch,
+            epochs,
+        } => bench_sink(properties, schema, chunk_size, chunks_per_epoch, epochs).await?,
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+enum KeyDistribution {
+    Uniform,
+    Zipfian,
+}
+
+impl KeyDistribution {
+    /// Picks a key index in `[0, key_space)`.
+    fn sample(&self, rng: &mut StdRng, key_space: usize) -> usize {
+        match self {
+            KeyDistribution::Uniform => rng.gen_range(0..key_space),
+            // A cheap zipfian-like skew: the power transform concentrates most samples on the
+            // first few keys while still occasionally touching the whole key space.
+            KeyDistribution::Zipfian => {
+                ((key_space as f64 * rng.gen::<f64>().powi(4)) as usize).min(key_space - 1)
+            }
+        }
+    }
+}
+
+/// Runs a mixed point-get/insert workload against a state table and reports throughput and
+/// latency percentiles, so storage tuning decisions can be evaluated before production rollout.
+#[allow(clippy::too_many_arguments)]
+async fn bench_workload(
+    context: &CtlContext,
+    mv_name: String,
+    read_ratio: f64,
+    distribution: KeyDistribution,
+    key_space: usize,
+    concurrency: usize,
+    operations: usize,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let meta = context.meta_client().await?;
+    let hummock = context
+        .hummock_store(HummockServiceOpts::from_env(data_dir)?)
+        .await?;
+    let table = get_table_catalog(meta, mv_name).await?;
+
+    // Pre-generate the rows the workload picks from, so that row generation does not show up
+    // in the measured latencies.
+    let data_types: Vec<DataType> = table
+        .columns()
+        .iter()
+        .map(|c| c.column_desc.data_type.clone())
+        .collect();
+    let chunk_size = 1024;
+    let rows: Arc<Vec<OwnedRow>> = Arc::new(
+        DataChunk::gen_data_chunks(
+            key_space / chunk_size + 1,
+            chunk_size,
+            &data_types,
+            &VarcharProperty::RandomVariableLength,
+        )
+        .iter()
+        .flat_map(|chunk| chunk.rows().map(|r| r.to_owned_row()))
+        .take(key_space)
+        .collect(),
+    );
+
+    let start = Instant::now();
+    let mut handles = vec![];
+    for i in 0..concurrency {
+        let table = table.clone();
+        let hummock = hummock.clone();
+        let rows = rows.clone();
+        handles.push(tokio::spawn(async move {
+            let mut state_table = make_state_table(hummock, &table).await;
+            let mut epoch = EpochPair::new_test_epoch(Epoch::now().0);
+            state_table.init_epoch(epoch);
+            let pk_indices = state_table.pk_indices().to_vec();
+
+            let mut rng = StdRng::seed_from_u64(i as u64);
+            let mut read_latencies = vec![];
+            let mut write_latencies = vec![];
+            // Keys inserted since the last commit. The mem-table rejects double inserts of the
+            // same key within one epoch, so such operations only sample the key.
+            let mut written = HashSet::new();
+            for _ in 0..operations {
+                let key = distribution.sample(&mut rng, rows.len());
+                let row = &rows[key];
+                if rng.gen::<f64>() < read_ratio {
+                    let op_start = Instant::now();
+                    let _ = state_table.get_row(row.project(&pk_indices)).await?;
+                    read_latencies.push(op_start.elapsed());
+                } else {
+                    let op_start = Instant::now();
+                    if written.insert(key) {
+                        state_table.insert(row.clone());
+                    }
+                    write_latencies.push(op_start.elapsed());
+                    if written.len() >= chunk_size {
+                        epoch.inc();
+                        state_table.commit(epoch).await?;
+                        written.clear();
+                    }
+                }
+            }
+            if !written.is_empty() {
+                epoch.inc();
+                state_table.commit(epoch).await?;
+            }
+            Ok::<_, anyhow::Error>((read_latencies, write_latencies))
+        }));
+    }
+
+    let mut read_latencies = vec![];
+    let mut write_latencies = vec![];
+    for handle in handles {
+        let (reads, writes) = handle.await??;
+        read_latencies.extend(reads);
+        write_latencies.extend(writes);
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    let total_ops = read_latencies.len() + write_latencies.len();
+
+    println!("ops/s: {:.0}", total_ops as f64 / elapsed);
+    fn report(name: &str, mut latencies: Vec<Duration>) {
+        if latencies.is_empty() {
+            return;
+        }
+        latencies.sort_unstable();
+        let percentile = |p: f64| latencies[((latencies.len() - 1) as f64 * p / 100.0) as usize];
+        println!(
+            "{name}: {} ops, p50: {:?}, p99: {:?}, p999: {:?}, max: {:?}",
+            latencies.len(),
+            percentile(50.0),
+            percentile(99.0),
+            percentile(99.9),
+            latencies.last().unwrap(),
+        );
     }
+    report("read", read_latencies);
+    report("write", write_latencies);
+
+    Ok(())
+}
+
+/// Writes generated stream chunks directly into a sink connector, so that connector bottlenecks
+/// can be told apart from query bottlenecks.
+async fn bench_sink(
+    properties: Vec<String>,
+    schema: String,
+    chunk_size: usize,
+    chunks_per_epoch: usize,
+    epochs: u64,
+) -> Result<()> {
+    let properties: HashMap<String, String> = properties
+        .into_iter()
+        .map(|kv| {
+            kv.split_once('=')
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .ok_or_else(|| anyhow!("invalid property `{kv}`, expected `key=value`"))
+        })
+        .collect::<Result<_>>()?;
+    let data_types = schema
+        .split(',')
+        .map(|t| {
+            t.trim()
+                .parse::<DataType>()
+                .map_err(|e| anyhow!("invalid data type `{t}`: {e}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Schema::new(
+        data_types
+            .iter()
+            .enumerate()
+            .map(|(i, data_type)| Field::with_name(data_type.clone(), format!("v{i}")))
+            .collect(),
+    );
+
+    let config = SinkConfig::from_hashmap(properties)?;
+    let sink_impl = SinkImpl::new(
+        config,
+        schema,
+        vec![],
+        ConnectorParams::default(),
+        SinkType::AppendOnly,
+        0,
+    )
+    .await?;
+
+    let chunks = StreamChunk::gen_stream_chunks(
+        chunks_per_epoch,
+        chunk_size,
+        &data_types,
+        &VarcharProperty::RandomVariableLength,
+    );
+    let bytes_per_epoch: usize = chunks.iter().map(|c| c.estimated_size()).sum();
+
+    dispatch_sink!(sink_impl, sink, {
+        let mut sink = sink;
+        let mut commit_latencies = Vec::with_capacity(epochs as usize);
+        let start = Instant::now();
+        for epoch in 1..=epochs {
+            sink.begin_epoch(epoch).await?;
+            for chunk in &chunks {
+                sink.write_batch(chunk.clone()).await?;
+            }
+            let commit_start = Instant::now();
+            sink.commit().await?;
+            commit_latencies.push(commit_start.elapsed());
+        }
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let total_rows = chunk_size * chunks_per_epoch * epochs as usize;
+        let total_bytes = bytes_per_epoch * epochs as usize;
+        commit_latencies.sort_unstable();
+        let percentile =
+            |p: f64| commit_latencies[((commit_latencies.len() - 1) as f64 * p / 100.0) as usize];
+        println!(
+            "rows/s: {:.0}\nbytes/s: {}/s\ncommit latency p50/p90/p99/max: {:?}/{:?}/{:?}/{:?}",
+            total_rows as f64 / elapsed,
+            Size::from_bytes(total_bytes as f64 / elapsed),
+            percentile(50.0),
+            percentile(90.0),
+            percentile(99.0),
+            commit_latencies.last().unwrap(),
+        );
+    });
 
     Ok(())
 }
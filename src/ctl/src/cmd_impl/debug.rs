@@ -0,0 +1,142 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use clap::Subcommand;
+use risingwave_common::util::addr::HostAddr;
+use risingwave_pb::common::WorkerType;
+use risingwave_rpc_client::{CompactorClient, ComputeClientPool};
+
+use crate::CtlContext;
+
+#[derive(Subcommand)]
+pub enum DebugCommands {
+    /// toggle failpoints on running compute and compactor nodes for fault injection.
+    /// The nodes must be built with the `failpoints` feature for them to take effect
+    #[clap(subcommand)]
+    Failpoint(FailpointCommands),
+}
+
+#[derive(Subcommand)]
+pub enum FailpointCommands {
+    /// set a failpoint on all compute and compactor nodes
+    Set {
+        /// name of the failpoint, e.g. `disable_block_cache`
+        name: String,
+        /// actions in the `fail` crate's syntax, e.g. `sleep(1000)`, `return` or `50%return`
+        actions: String,
+    },
+    /// clear a failpoint on all compute and compactor nodes
+    Clear {
+        /// name of the failpoint
+        name: String,
+    },
+    /// list the failpoints configured on each compute and compactor node
+    List,
+}
+
+pub async fn do_debug(context: &CtlContext, cmd: DebugCommands) -> Result<()> {
+    match cmd {
+        DebugCommands::Failpoint(cmd) => do_failpoint(context, cmd).await,
+    }
+}
+
+async fn do_failpoint(context: &CtlContext, cmd: FailpointCommands) -> Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let compute_nodes = meta_client
+        .get_cluster_info()
+        .await?
+        .worker_nodes
+        .into_iter()
+        .filter(|w| w.r#type() == WorkerType::ComputeNode)
+        .collect::<Vec<_>>();
+    let compactor_nodes = meta_client.list_worker_nodes(WorkerType::Compactor).await?;
+
+    let clients = ComputeClientPool::default();
+
+    // FIXME: the compute node may not be accessible directly from risectl, we may let the meta
+    // service forward the requests to all nodes in the future.
+    match cmd {
+        FailpointCommands::Set { name, actions } => {
+            for cn in &compute_nodes {
+                let client = clients.get(cn).await?;
+                client.set_failpoint(name.clone(), actions.clone()).await?;
+            }
+            for compactor in &compactor_nodes {
+                let addr: HostAddr = compactor.get_host().unwrap().into();
+                let client = CompactorClient::new(addr).await?;
+                client.set_failpoint(name.clone(), actions.clone()).await?;
+            }
+            println!(
+                "Set failpoint {name} to `{actions}` on {} compute and {} compactor node(s)",
+                compute_nodes.len(),
+                compactor_nodes.len()
+            );
+        }
+        FailpointCommands::Clear { name } => {
+            for cn in &compute_nodes {
+                let client = clients.get(cn).await?;
+                client.set_failpoint(name.clone(), String::new()).await?;
+            }
+            for compactor in &compactor_nodes {
+                let addr: HostAddr = compactor.get_host().unwrap().into();
+                let client = CompactorClient::new(addr).await?;
+                client.set_failpoint(name.clone(), String::new()).await?;
+            }
+            println!(
+                "Cleared failpoint {name} on {} compute and {} compactor node(s)",
+                compute_nodes.len(),
+                compactor_nodes.len()
+            );
+        }
+        FailpointCommands::List => {
+            let mut all_failpoints: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+            for cn in &compute_nodes {
+                let client = clients.get(cn).await?;
+                let node = HostAddr::from(cn.get_host().unwrap()).to_string();
+                for (name, actions) in client.list_failpoints().await?.failpoints {
+                    all_failpoints
+                        .entry(node.clone())
+                        .or_default()
+                        .insert(name, actions);
+                }
+            }
+            for compactor in &compactor_nodes {
+                let addr: HostAddr = compactor.get_host().unwrap().into();
+                let client = CompactorClient::new(addr.clone()).await?;
+                for (name, actions) in client.list_failpoints().await?.failpoints {
+                    all_failpoints
+                        .entry(addr.to_string())
+                        .or_default()
+                        .insert(name, actions);
+                }
+            }
+            if all_failpoints.is_empty() {
+                println!("No failpoints configured");
+            } else {
+                for (node, failpoints) in all_failpoints {
+                    println!("--- {node} ---");
+                    for (name, actions) in failpoints {
+                        println!("{name} = {actions}");
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -18,14 +18,20 @@ mod list_kv;
 pub use list_kv::*;
 mod sst_dump;
 pub use sst_dump::*;
+mod backup;
 mod compaction_group;
+mod list_orphans;
 mod list_version_deltas;
 mod pause_resume;
 mod trigger_full_gc;
 mod trigger_manual_compaction;
+mod verify;
 
+pub use backup::*;
 pub use compaction_group::*;
+pub use list_orphans::*;
 pub use list_version_deltas::*;
 pub use pause_resume::*;
 pub use trigger_full_gc::*;
 pub use trigger_manual_compaction::*;
+pub use verify::*;
@@ -0,0 +1,202 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use prost::Message;
+use risingwave_backup::storage::{MetaSnapshotStorage, ObjectStoreMetaSnapshotStorage};
+use risingwave_common::config::RetryConfig;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_hummock_sdk::{version_checkpoint_path, HummockSstableObjectId, OBJECT_SUFFIX};
+use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
+use risingwave_object_store::object::{parse_remote_object_store, ObjectStoreRef};
+use risingwave_pb::hummock::HummockVersionCheckpoint;
+use serde::{Deserialize, Serialize};
+
+use crate::common::HummockServiceOpts;
+use crate::CtlContext;
+
+/// Name of the manifest object of a hummock data backup, stored next to the backed up SST
+/// objects in the backup directory.
+const BACKUP_MANIFEST_NAME: &str = "hummock_backup_manifest.json";
+
+/// Manifest of a hummock data backup.
+///
+/// A data backup captures the hummock version recorded in a meta snapshot, together with all
+/// SST objects that version references. Restoring the data backup and then the meta snapshot
+/// it corresponds to yields a complete cluster.
+#[derive(Serialize, Deserialize)]
+pub struct HummockBackupManifest {
+    pub format_version: u32,
+    /// Id of the meta snapshot this data backup corresponds to.
+    pub meta_snapshot_id: u64,
+    pub hummock_version_id: u64,
+    pub max_committed_epoch: u64,
+    pub object_ids: Vec<HummockSstableObjectId>,
+}
+
+pub async fn backup_hummock(
+    context: &CtlContext,
+    meta_snapshot_id: Option<u64>,
+    target_url: String,
+    target_dir: String,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let system_params = meta_client.get_system_params().await?;
+
+    // The data backup is taken against the hummock version recorded in a meta snapshot,
+    // because the backup safe point of the snapshot prevents the version's SST objects from
+    // being GCed while they are copied.
+    let backup_store = create_object_store(system_params.backup_storage_url()).await;
+    let backup_storage =
+        ObjectStoreMetaSnapshotStorage::new(system_params.backup_storage_directory(), backup_store)
+            .await?;
+    let meta_snapshot_id = match meta_snapshot_id {
+        Some(id) => id,
+        None => backup_storage
+            .manifest()
+            .snapshot_metadata
+            .iter()
+            .map(|m| m.id)
+            .max()
+            .ok_or_else(|| {
+                anyhow!("no meta snapshot found, run `risectl meta backup-meta` first")
+            })?,
+    };
+    let meta_snapshot = backup_storage.get(meta_snapshot_id).await?;
+    let version = meta_snapshot.metadata.hummock_version;
+    println!(
+        "Backing up hummock version {} (committed epoch {}) from meta snapshot {}",
+        version.id, version.max_committed_epoch, meta_snapshot_id
+    );
+
+    let hummock_opts = HummockServiceOpts::from_env(None)?;
+    let sstable_store = hummock_opts.create_sstable_store().await?;
+    let source_store = sstable_store.store();
+    let target_store = create_object_store(&target_url).await;
+
+    let object_ids = version.get_object_ids();
+    println!("Copying {} SST objects...", object_ids.len());
+    for object_id in &object_ids {
+        let data = source_store
+            .read(&sstable_store.get_sst_data_path(*object_id), None)
+            .await?;
+        target_store
+            .upload(
+                &sst_object_path(&target_store, &target_dir, *object_id),
+                data,
+            )
+            .await?;
+    }
+
+    // Also back up a version checkpoint, so that the restored directory is directly usable
+    // as the data directory of a fresh cluster.
+    let checkpoint = HummockVersionCheckpoint {
+        version: Some(version.clone()),
+        stale_objects: Default::default(),
+    };
+    target_store
+        .upload(
+            &version_checkpoint_path(&target_dir),
+            checkpoint.encode_to_vec().into(),
+        )
+        .await?;
+
+    // The manifest is written last: its presence implies all objects it mentions exist.
+    let manifest = HummockBackupManifest {
+        format_version: 1,
+        meta_snapshot_id,
+        hummock_version_id: version.id,
+        max_committed_epoch: version.max_committed_epoch,
+        object_ids,
+    };
+    target_store
+        .upload(
+            &format!("{}/{}", target_dir, BACKUP_MANIFEST_NAME),
+            serde_json::to_vec(&manifest)?.into(),
+        )
+        .await?;
+    println!(
+        "Succeeded. Keep meta snapshot {} until this data backup is deleted.",
+        meta_snapshot_id
+    );
+    Ok(())
+}
+
+pub async fn restore_hummock(
+    source_url: String,
+    source_dir: String,
+    target_url: String,
+    target_dir: String,
+) -> anyhow::Result<()> {
+    let source_store = create_object_store(&source_url).await;
+    let target_store = create_object_store(&target_url).await;
+
+    let bytes = source_store
+        .read(&format!("{}/{}", source_dir, BACKUP_MANIFEST_NAME), None)
+        .await?;
+    let manifest: HummockBackupManifest = serde_json::from_slice(&bytes)?;
+    println!(
+        "Restoring hummock version {} (committed epoch {}, meta snapshot {})",
+        manifest.hummock_version_id, manifest.max_committed_epoch, manifest.meta_snapshot_id
+    );
+
+    println!("Copying {} SST objects...", manifest.object_ids.len());
+    for object_id in &manifest.object_ids {
+        let data = source_store
+            .read(
+                &sst_object_path(&source_store, &source_dir, *object_id),
+                None,
+            )
+            .await?;
+        target_store
+            .upload(
+                &sst_object_path(&target_store, &target_dir, *object_id),
+                data,
+            )
+            .await?;
+    }
+    let checkpoint = source_store
+        .read(&version_checkpoint_path(&source_dir), None)
+        .await?;
+    target_store
+        .upload(&version_checkpoint_path(&target_dir), checkpoint)
+        .await?;
+
+    println!(
+        "Succeeded. To restore metadata into an empty meta store, run:\n\
+        risectl meta restore-meta --meta-snapshot-id {} --hummock-storage-url {} --hummock-storage-dir {}",
+        manifest.meta_snapshot_id, target_url, target_dir
+    );
+    Ok(())
+}
+
+async fn create_object_store(url: &str) -> ObjectStoreRef {
+    Arc::new(
+        parse_remote_object_store(
+            url,
+            Arc::new(ObjectStoreMetrics::unused()),
+            "Hummock Backup",
+            None,
+            RetryConfig::default(),
+        )
+        .await,
+    )
+}
+
+fn sst_object_path(store: &ObjectStoreRef, dir: &str, object_id: HummockSstableObjectId) -> String {
+    let obj_prefix = store.get_object_prefix(object_id);
+    format!("{}/{}{}.{}", dir, obj_prefix, object_id, OBJECT_SUFFIX)
+}
@@ -13,12 +13,17 @@
 // limitations under the License.
 
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, UNIX_EPOCH};
 
+use anyhow::anyhow;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use comfy_table::{Row, Table};
 use itertools::Itertools;
 use risingwave_hummock_sdk::compaction_group::StateTableId;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockContextId};
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_config::MutableConfig;
+use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::CompressionAlgorithmLevel;
 
 use crate::CtlContext;
 
@@ -57,7 +62,13 @@ pub fn build_compaction_config_vec(
     max_sub_compaction: Option<u32>,
     level0_stop_write_threshold_sub_level_number: Option<u64>,
     level0_sub_level_compact_level_count: Option<u32>,
-) -> Vec<MutableConfig> {
+    sst_encryption_key_id: Option<String>,
+    compression_algorithm_level: Option<String>,
+    filter_algorithm: Option<String>,
+    filter_bits_per_key: Option<u32>,
+    compaction_priority: Option<u32>,
+    max_concurrent_task_number: Option<u32>,
+) -> anyhow::Result<Vec<MutableConfig>> {
     let mut configs = vec![];
     if let Some(c) = max_bytes_for_level_base {
         configs.push(MutableConfig::MaxBytesForLevelBase(c));
@@ -89,7 +100,50 @@ pub fn build_compaction_config_vec(
     if let Some(c) = level0_sub_level_compact_level_count {
         configs.push(MutableConfig::Level0SubLevelCompactLevelCount(c));
     }
-    configs
+    if let Some(c) = sst_encryption_key_id {
+        configs.push(MutableConfig::SstEncryptionKeyId(c));
+    }
+    if let Some(c) = compression_algorithm_level {
+        configs.push(MutableConfig::CompressionAlgorithmLevel(
+            parse_compression_algorithm_level(&c)?,
+        ));
+    }
+    if let Some(c) = filter_algorithm {
+        if !c.is_empty() && !matches!(c.as_str(), "Bloom" | "Ribbon") {
+            return Err(anyhow!(
+                "expect Bloom, Ribbon or an empty string, got {}",
+                c
+            ));
+        }
+        configs.push(MutableConfig::FilterAlgorithm(c));
+    }
+    if let Some(c) = filter_bits_per_key {
+        configs.push(MutableConfig::FilterBitsPerKey(c));
+    }
+    if let Some(c) = compaction_priority {
+        configs.push(MutableConfig::CompactionPriority(c));
+    }
+    if let Some(c) = max_concurrent_task_number {
+        configs.push(MutableConfig::MaxConcurrentTaskNumber(c));
+    }
+    Ok(configs)
+}
+
+/// Parses a `LEVEL:ALGORITHM` pair, e.g. `6:ZstdDict`.
+fn parse_compression_algorithm_level(s: &str) -> anyhow::Result<CompressionAlgorithmLevel> {
+    let (level, compression_algorithm) = s
+        .split_once(':')
+        .ok_or_else(|| anyhow!("expect LEVEL:ALGORITHM, got {}", s))?;
+    if !matches!(compression_algorithm, "None" | "Lz4" | "Zstd" | "ZstdDict") {
+        return Err(anyhow!(
+            "expect one of None, Lz4, Zstd, ZstdDict, got {}",
+            compression_algorithm
+        ));
+    }
+    Ok(CompressionAlgorithmLevel {
+        level: level.parse()?,
+        compression_algorithm: compression_algorithm.to_string(),
+    })
 }
 
 pub async fn split_compaction_group(
@@ -208,3 +262,57 @@ pub async fn list_compaction_status(context: &CtlContext, verbose: bool) -> anyh
     }
     Ok(())
 }
+
+pub async fn list_compact_task_history(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let task_summaries = meta_client.list_compact_task_history().await?;
+    let format_time_sec = |time_sec: u64| {
+        if time_sec == 0 {
+            return "unknown".to_string();
+        }
+        DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_secs(time_sec)).to_string()
+    };
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Task".into());
+        row.add_cell("Compaction Group".into());
+        row.add_cell("Type".into());
+        row.add_cell("Status".into());
+        row.add_cell("Target Level".into());
+        row.add_cell("Input Files".into());
+        row.add_cell("Input Size".into());
+        row.add_cell("Output Files".into());
+        row.add_cell("Output Size".into());
+        row.add_cell("Write Amplification".into());
+        row.add_cell("Start Time".into());
+        row.add_cell("End Time".into());
+        row
+    });
+    for s in task_summaries {
+        let write_amplification = if s.input_file_size == 0 {
+            "unknown".to_string()
+        } else {
+            format!(
+                "{:.2}",
+                s.output_file_size as f64 / s.input_file_size as f64
+            )
+        };
+        let mut row = Row::new();
+        row.add_cell(s.task_id.into());
+        row.add_cell(s.compaction_group_id.into());
+        row.add_cell(s.task_type().as_str_name().into());
+        row.add_cell(s.task_status().as_str_name().into());
+        row.add_cell(s.target_level.into());
+        row.add_cell(s.input_file_count.into());
+        row.add_cell(s.input_file_size.into());
+        row.add_cell(s.output_file_count.into());
+        row.add_cell(s.output_file_size.into());
+        row.add_cell(write_amplification.into());
+        row.add_cell(format_time_sec(s.start_time_sec).into());
+        row.add_cell(format_time_sec(s.end_time_sec).into());
+        table.add_row(row);
+    }
+    println!("{table}");
+    Ok(())
+}
@@ -0,0 +1,141 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::offset::Utc;
+use chrono::DateTime;
+use comfy_table::{Row, Table};
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_hummock_sdk::HummockSstableObjectId;
+use serde::Serialize;
+use size::Size;
+
+use crate::common::HummockServiceOpts;
+use crate::CtlContext;
+
+/// An object in the store that is not referenced by the current hummock version or any
+/// retained version delta.
+#[derive(Serialize)]
+struct OrphanObject {
+    object_id: HummockSstableObjectId,
+    path: String,
+    size: u64,
+    /// Seconds since unix epoch.
+    last_modified: u64,
+}
+
+/// Machine-readable report written by `risectl hummock list-orphans`.
+#[derive(Serialize)]
+struct OrphanReport {
+    hummock_version_id: u64,
+    sst_retention_time_sec: u64,
+    scanned_object_count: usize,
+    orphan_total_size: u64,
+    orphans: Vec<OrphanObject>,
+}
+
+/// Reports objects present in the object store but absent from the current version and all
+/// retained version deltas, i.e. what a subsequent `trigger-full-gc` would delete. Nothing is
+/// deleted.
+///
+/// This mirrors the filtering of the full-GC path: objects uploaded within the retention time
+/// are not reported, because they may belong to an ongoing version write.
+pub async fn list_orphans(
+    context: &CtlContext,
+    sst_retention_time_sec: u64,
+    output: Option<String>,
+    data_dir: Option<String>,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let version = meta_client.get_current_version().await?;
+    let deltas = meta_client
+        .list_version_deltas(0, u32::MAX, u64::MAX)
+        .await?
+        .version_deltas;
+    let mut tracked_object_ids: HashSet<HummockSstableObjectId> =
+        HashSet::from_iter(version.get_object_ids());
+    for delta in &deltas {
+        tracked_object_ids.extend(delta.get_gc_object_ids());
+    }
+
+    let hummock_opts = HummockServiceOpts::from_env(data_dir)?;
+    let sstable_store = hummock_opts.create_sstable_store().await?;
+    let objects = sstable_store.list_ssts_from_object_store().await?;
+    let scanned_object_count = objects.len();
+    let retention_watermark = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock set before UNIX epoch")
+        .as_secs()
+        .saturating_sub(sst_retention_time_sec);
+
+    let mut orphans = vec![];
+    for object in objects {
+        let object_id = sstable_store.get_object_id_from_path(&object.key);
+        let last_modified = object.last_modified as u64;
+        if tracked_object_ids.contains(&object_id) || last_modified >= retention_watermark {
+            continue;
+        }
+        orphans.push(OrphanObject {
+            object_id,
+            path: object.key,
+            size: object.total_size as u64,
+            last_modified,
+        });
+    }
+    orphans.sort_by_key(|orphan| orphan.object_id);
+    let orphan_total_size = orphans.iter().map(|orphan| orphan.size).sum();
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Object ID".into());
+        row.add_cell("Size".into());
+        row.add_cell("Last Modified".into());
+        row.add_cell("Path".into());
+        row
+    });
+    for orphan in &orphans {
+        let last_modified =
+            DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_secs(orphan.last_modified));
+        let mut row = Row::new();
+        row.add_cell(orphan.object_id.into());
+        row.add_cell(Size::from_bytes(orphan.size).to_string().into());
+        row.add_cell(last_modified.to_string().into());
+        row.add_cell(orphan.path.clone().into());
+        table.add_row(row);
+    }
+    println!("{table}");
+    println!(
+        "{} orphan objects ({}) among {} scanned, against hummock version {}",
+        orphans.len(),
+        Size::from_bytes(orphan_total_size),
+        scanned_object_count,
+        version.id
+    );
+
+    if let Some(output) = output {
+        let report = OrphanReport {
+            hummock_version_id: version.id,
+            sst_retention_time_sec,
+            scanned_object_count,
+            orphan_total_size,
+            orphans,
+        };
+        tokio::fs::write(&output, serde_json::to_vec_pretty(&report)?).await?;
+        println!("Report written to {}", output);
+    }
+    Ok(())
+}
@@ -268,6 +268,11 @@ async fn print_block(
     println!("\tBlock {}", block_idx);
     println!("\t-----------");
 
+    if !sst.meta.encrypted_data_key.is_empty() {
+        println!("\tBlock is encrypted at rest, skipping.");
+        return Ok(());
+    }
+
     let block_meta = &sst.meta.block_metas[block_idx];
     let smallest_key = FullKey::decode(&block_meta.smallest_key);
     let data_path = sstable_store.get_sst_data_path(sst.id);
@@ -295,6 +300,7 @@ async fn print_block(
             block_data,
             table_data,
             block_meta.uncompressed_size as usize,
+            (!sst.meta.zstd_dict.is_empty()).then_some(sst.meta.zstd_dict.as_slice()),
             args,
         )?;
     }
@@ -307,11 +313,13 @@ fn print_kv_pairs(
     block_data: Bytes,
     table_data: &TableData,
     uncompressed_capacity: usize,
+    zstd_dict: Option<&[u8]>,
     args: &SstDumpArgs,
 ) -> anyhow::Result<()> {
     println!("\tKV-Pairs:");
 
-    let block = Box::new(Block::decode(block_data, uncompressed_capacity).unwrap());
+    let block =
+        Box::new(Block::decode_with_dict(block_data, uncompressed_capacity, zstd_dict).unwrap());
     let holder = BlockHolder::from_owned_block(block);
     let mut block_iter = BlockIterator::new(holder);
     block_iter.seek_to_first();
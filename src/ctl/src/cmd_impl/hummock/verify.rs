@@ -0,0 +1,238 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+use bytes::Buf;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_hummock_sdk::key::FullKey;
+use risingwave_hummock_sdk::HummockSstableObjectId;
+use risingwave_object_store::object::ObjectStoreRef;
+use risingwave_storage::hummock::sstable::utils::xxhash64_checksum;
+use risingwave_storage::hummock::SstableMeta;
+
+use crate::common::HummockServiceOpts;
+use crate::CtlContext;
+
+/// Result of verifying a single SST object.
+enum ObjectCheck {
+    Ok,
+    /// Only the meta checksum is broken: the footer, the decoded meta and all data blocks
+    /// verify fine, so the meta block can be rewritten in place.
+    RepairableMeta(SstableMeta),
+    Corrupt(String),
+}
+
+/// Verifies the integrity of SST objects referenced by the current hummock version: block
+/// checksums, the meta block footer and checksum, and the monotonicity of block key ranges.
+pub async fn verify_objects(
+    context: &CtlContext,
+    object_id: Option<u64>,
+    repair_meta: bool,
+    quarantine_dir: Option<String>,
+    data_dir: Option<String>,
+) -> anyhow::Result<()> {
+    // Getting the version via the state store pins it, so verified objects cannot be GCed
+    // concurrently.
+    let hummock = context
+        .hummock_store(HummockServiceOpts::from_env(data_dir)?)
+        .await?;
+    let version = hummock.inner().get_pinned_version().version();
+    let sstable_store = hummock.sstable_store();
+    let store = sstable_store.store();
+
+    // Branched SSTs in different compaction groups share the same object; verify each object
+    // only once.
+    let mut objects = vec![];
+    let mut seen = HashSet::new();
+    for level in version.get_combined_levels() {
+        for sst in &level.table_infos {
+            if object_id.map_or(true, |id| id == sst.get_object_id())
+                && seen.insert(sst.get_object_id())
+            {
+                objects.push((sst.get_object_id(), sst.meta_offset));
+            }
+        }
+    }
+    if objects.is_empty() {
+        return Err(anyhow!(
+            "no matching SST object in hummock version {}",
+            version.id
+        ));
+    }
+
+    println!(
+        "Verifying {} SST objects of hummock version {}",
+        objects.len(),
+        version.id
+    );
+    let mut corrupt = vec![];
+    let mut repaired = 0;
+    for (object_id, meta_offset) in objects {
+        let path = sstable_store.get_sst_data_path(object_id);
+        match verify_object(&store, &path, meta_offset).await {
+            ObjectCheck::Ok => {}
+            ObjectCheck::RepairableMeta(meta) if repair_meta => {
+                let data = store.read(&path, None).await?;
+                let mut repaired_data = data[..meta_offset as usize].to_vec();
+                meta.encode_to(&mut repaired_data);
+                store.upload(&path, repaired_data.into()).await?;
+                println!("object {}: repaired broken meta checksum", object_id);
+                repaired += 1;
+            }
+            ObjectCheck::RepairableMeta(_) => {
+                println!(
+                    "object {}: meta checksum mismatch, repairable via --repair-meta",
+                    object_id
+                );
+                corrupt.push(object_id);
+            }
+            ObjectCheck::Corrupt(reason) => {
+                println!("object {}: {}", object_id, reason);
+                corrupt.push(object_id);
+            }
+        }
+    }
+
+    if corrupt.is_empty() {
+        println!("All objects verified, {} repaired.", repaired);
+        return Ok(());
+    }
+    if let Some(quarantine_dir) = &quarantine_dir {
+        for object_id in &corrupt {
+            quarantine_object(
+                &store,
+                sstable_store.get_sst_data_path(*object_id),
+                *object_id,
+                quarantine_dir,
+            )
+            .await?;
+        }
+        println!(
+            "Moved {} corrupt objects into {}. Reads of their key ranges will fail until the \
+            objects are restored, e.g. from a DR replica.",
+            corrupt.len(),
+            quarantine_dir
+        );
+    }
+    Err(anyhow!("{} corrupt SST objects found", corrupt.len()))
+}
+
+async fn verify_object(store: &ObjectStoreRef, path: &str, meta_offset: u64) -> ObjectCheck {
+    let data = match store.read(path, None).await {
+        Ok(data) => data,
+        Err(e) => return ObjectCheck::Corrupt(format!("failed to read object: {}", e)),
+    };
+    let meta_offset = meta_offset as usize;
+    if meta_offset >= data.len() {
+        return ObjectCheck::Corrupt(format!(
+            "object truncated: size {} not larger than meta offset {}",
+            data.len(),
+            meta_offset
+        ));
+    }
+    let (meta, meta_checksum_ok) = match SstableMeta::decode(&mut &data[meta_offset..]) {
+        Ok(meta) => (meta, true),
+        Err(e) => {
+            // The footer and meta body may still be intact if only the checksum bytes are
+            // corrupt. Parsing an arbitrarily corrupt body may panic, hence the guard.
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                SstableMeta::decode_unchecked(&mut &data[meta_offset..])
+            })) {
+                Ok(Ok(meta)) => (meta, false),
+                _ => return ObjectCheck::Corrupt(format!("failed to decode meta: {}", e)),
+            }
+        }
+    };
+
+    // Block checksums are computed before encryption at rest and cannot be verified without
+    // the data key; integrity of encrypted blocks is enforced by AES-GCM on read instead.
+    let encrypted = !meta.encrypted_data_key.is_empty();
+    let mut prev_smallest: Option<FullKey<&[u8]>> = None;
+    for (idx, block_meta) in meta.block_metas.iter().enumerate() {
+        let start = block_meta.offset as usize;
+        let end = start + block_meta.len as usize;
+        if end > meta_offset {
+            return ObjectCheck::Corrupt(format!(
+                "block {} out of bounds: [{}, {}) exceeds meta offset {}",
+                idx, start, end, meta_offset
+            ));
+        }
+        let block = &data[start..end];
+        if !encrypted {
+            if block.len() <= 9 {
+                return ObjectCheck::Corrupt(format!("block {} is too short", idx));
+            }
+            let checksum = (&block[block.len() - 8..]).get_u64_le();
+            let actual = xxhash64_checksum(&block[..block.len() - 8]);
+            if checksum != actual {
+                return ObjectCheck::Corrupt(format!(
+                    "block {}: checksum mismatch, expected {}, actual {}",
+                    idx, checksum, actual
+                ));
+            }
+        }
+        // An encoded `FullKey` is at least a table id (4B) plus an epoch (8B).
+        if block_meta.smallest_key.len() <= 12 {
+            return ObjectCheck::Corrupt(format!("block {} has an invalid smallest key", idx));
+        }
+        let smallest = FullKey::decode(&block_meta.smallest_key);
+        if let Some(prev) = prev_smallest.replace(smallest) {
+            if prev >= smallest {
+                return ObjectCheck::Corrupt(format!(
+                    "block {}: smallest key not larger than that of the previous block",
+                    idx
+                ));
+            }
+        }
+    }
+    if let (Some(first), Some(last)) = (meta.block_metas.first(), meta.block_metas.last()) {
+        if meta.smallest_key.len() <= 12 || meta.largest_key.len() <= 12 {
+            return ObjectCheck::Corrupt("meta key range is invalid".to_string());
+        }
+        if FullKey::decode(&meta.smallest_key) > FullKey::decode(&first.smallest_key) {
+            return ObjectCheck::Corrupt(
+                "meta smallest key is larger than the first block's smallest key".to_string(),
+            );
+        }
+        if FullKey::decode(&meta.largest_key) < FullKey::decode(&last.smallest_key) {
+            return ObjectCheck::Corrupt(
+                "meta largest key is smaller than the last block's smallest key".to_string(),
+            );
+        }
+    }
+    if meta_checksum_ok {
+        ObjectCheck::Ok
+    } else {
+        ObjectCheck::RepairableMeta(meta)
+    }
+}
+
+/// Moves a corrupt object into the quarantine directory within the same object store.
+async fn quarantine_object(
+    store: &ObjectStoreRef,
+    path: String,
+    object_id: HummockSstableObjectId,
+    quarantine_dir: &str,
+) -> anyhow::Result<()> {
+    let file_name = path.rsplit('/').next().unwrap().to_string();
+    let data = store.read(&path, None).await?;
+    store
+        .upload(&format!("{}/{}", quarantine_dir, file_name), data)
+        .await?;
+    store.delete(&path).await?;
+    println!("object {}: quarantined {}", object_id, path);
+    Ok(())
+}
@@ -12,16 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod apply;
 mod backup_meta;
+mod barrier_latency;
 mod cluster_info;
 mod connection;
+mod dr;
+mod graph;
 mod pause_resume;
+mod recovery;
 mod reschedule;
 mod serving;
 
+pub use apply::*;
 pub use backup_meta::*;
+pub use barrier_latency::*;
 pub use cluster_info::*;
 pub use connection::*;
+pub use dr::*;
+pub use graph::*;
 pub use pause_resume::*;
+pub use recovery::*;
 pub use reschedule::*;
 pub use serving::*;
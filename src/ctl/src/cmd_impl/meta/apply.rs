@@ -0,0 +1,289 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::process::exit;
+
+use anyhow::{anyhow, Context, Result};
+use inquire::Confirm;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use tokio_postgres::{NoTls, SimpleQueryMessage};
+
+/// The kind of a catalog object that can be managed declaratively.
+///
+/// The order of the variants is the creation order: objects are created from
+/// upstream to downstream and dropped in the reverse order, so that a single
+/// apply can both define a source and the materialized views on top of it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectKind {
+    User,
+    Source,
+    Table,
+    MaterializedView,
+    Sink,
+}
+
+impl ObjectKind {
+    /// The `rw_catalog` relation listing the live objects of this kind.
+    fn catalog_relation(self) -> &'static str {
+        match self {
+            ObjectKind::User => "rw_catalog.rw_users",
+            ObjectKind::Source => "rw_catalog.rw_sources",
+            ObjectKind::Table => "rw_catalog.rw_tables",
+            ObjectKind::MaterializedView => "rw_catalog.rw_materialized_views",
+            ObjectKind::Sink => "rw_catalog.rw_sinks",
+        }
+    }
+
+    /// The object kind keyword in `DROP <kind> <name>`.
+    fn sql_keyword(self) -> &'static str {
+        match self {
+            ObjectKind::User => "USER",
+            ObjectKind::Source => "SOURCE",
+            ObjectKind::Table => "TABLE",
+            ObjectKind::MaterializedView => "MATERIALIZED VIEW",
+            ObjectKind::Sink => "SINK",
+        }
+    }
+
+    /// Users have no stored definition and are shared infrastructure, so they
+    /// are only ever created, never dropped or recreated by an apply.
+    fn create_only(self) -> bool {
+        matches!(self, ObjectKind::User)
+    }
+}
+
+/// One desired object in the declarative catalog file.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DesiredObject {
+    pub kind: ObjectKind,
+    pub name: String,
+    /// The `CREATE ...` statement that defines the object.
+    pub sql: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    Drop,
+    Recreate,
+}
+
+#[derive(Debug)]
+struct PlanStep {
+    action: Action,
+    kind: ObjectKind,
+    name: String,
+    /// The statements to execute, in order.
+    statements: Vec<String>,
+}
+
+/// Normalizes a SQL definition for comparison, so that whitespace and a
+/// trailing semicolon do not cause a spurious recreate.
+fn normalize_sql(sql: &str) -> String {
+    sql.split_whitespace()
+        .join(" ")
+        .trim_end_matches(';')
+        .trim()
+        .to_string()
+}
+
+fn drop_statement(kind: ObjectKind, name: &str) -> String {
+    format!("DROP {} \"{}\"", kind.sql_keyword(), name)
+}
+
+/// Applies a declarative catalog file against the cluster by diffing it with
+/// the live catalog and running the DDL needed to converge, after showing the
+/// plan and asking for confirmation.
+pub async fn apply_catalog(
+    endpoint: String,
+    user: String,
+    database: String,
+    filename: String,
+    yes: bool,
+) -> Result<()> {
+    let file = std::fs::read_to_string(&filename)
+        .with_context(|| format!("failed to read from file {}", filename))?;
+    let desired: Vec<DesiredObject> = serde_yaml::from_str(&file)
+        .with_context(|| format!("failed to parse declarative catalog file {}", filename))?;
+
+    let mut desired_by_key: HashMap<(ObjectKind, String), &DesiredObject> = HashMap::new();
+    for object in &desired {
+        if desired_by_key
+            .insert((object.kind, object.name.clone()), object)
+            .is_some()
+        {
+            return Err(anyhow!(
+                "duplicate object {} {:?} in {}",
+                object.kind.sql_keyword(),
+                object.name,
+                filename
+            ));
+        }
+    }
+
+    let (host, port) = endpoint
+        .split_once(':')
+        .ok_or_else(|| anyhow!("invalid frontend endpoint {}, expect `host:port`", endpoint))?;
+    let (client, connection) = tokio_postgres::Config::new()
+        .host(host)
+        .port(port.parse().context("invalid frontend port")?)
+        .user(&user)
+        .dbname(&database)
+        .connect(NoTls)
+        .await
+        .context("failed to connect to the frontend")?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::error!("connection error: {}", e);
+        }
+    });
+
+    // Collect the live objects of each managed kind, with their definitions
+    // where available.
+    let kinds = [
+        ObjectKind::User,
+        ObjectKind::Source,
+        ObjectKind::Table,
+        ObjectKind::MaterializedView,
+        ObjectKind::Sink,
+    ];
+    let mut live: HashMap<ObjectKind, HashMap<String, String>> = HashMap::new();
+    for kind in kinds {
+        let query = if kind.create_only() {
+            format!("SELECT name, '' FROM {}", kind.catalog_relation())
+        } else {
+            format!("SELECT name, definition FROM {}", kind.catalog_relation())
+        };
+        let objects = client
+            .simple_query(&query)
+            .await
+            .with_context(|| format!("failed to query {}", kind.catalog_relation()))?
+            .into_iter()
+            .filter_map(|msg| match msg {
+                SimpleQueryMessage::Row(row) => Some((
+                    row.get(0).unwrap_or_default().to_string(),
+                    row.get(1).unwrap_or_default().to_string(),
+                )),
+                _ => None,
+            })
+            .collect();
+        live.insert(kind, objects);
+    }
+
+    // Compute the plan: create missing objects, recreate changed ones, and
+    // drop live ones that the file no longer mentions. Only kinds that appear
+    // in the file are diffed, so a file listing only sinks will never touch
+    // sources.
+    let managed_kinds: HashSet<ObjectKind> = desired.iter().map(|object| object.kind).collect();
+    let mut creates = vec![];
+    let mut drops = vec![];
+    for object in &desired {
+        match live[&object.kind].get(&object.name) {
+            None => creates.push(PlanStep {
+                action: Action::Create,
+                kind: object.kind,
+                name: object.name.clone(),
+                statements: vec![object.sql.clone()],
+            }),
+            Some(definition)
+                if !object.kind.create_only()
+                    && normalize_sql(definition) != normalize_sql(&object.sql) =>
+            {
+                creates.push(PlanStep {
+                    action: Action::Recreate,
+                    kind: object.kind,
+                    name: object.name.clone(),
+                    statements: vec![
+                        drop_statement(object.kind, &object.name),
+                        object.sql.clone(),
+                    ],
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for kind in kinds {
+        if !managed_kinds.contains(&kind) || kind.create_only() {
+            continue;
+        }
+        for name in live[&kind].keys().sorted() {
+            if !desired_by_key.contains_key(&(kind, name.clone())) {
+                drops.push(PlanStep {
+                    action: Action::Drop,
+                    kind,
+                    name: name.clone(),
+                    statements: vec![drop_statement(kind, name)],
+                });
+            }
+        }
+    }
+
+    // Drop downstream-first, then create upstream-first.
+    drops.sort_by_key(|step| std::cmp::Reverse(step.kind));
+    creates.sort_by_key(|step| step.kind);
+    let plan = drops.into_iter().chain(creates).collect_vec();
+
+    if plan.is_empty() {
+        println!("No changes, the catalog is up to date.");
+        return Ok(());
+    }
+
+    println!("Plan: {} change(s)\n", plan.len());
+    for step in &plan {
+        let action = match step.action {
+            Action::Create => "create",
+            Action::Drop => "drop",
+            Action::Recreate => "recreate",
+        };
+        println!("{} {} \"{}\"", action, step.kind.sql_keyword(), step.name);
+        for statement in &step.statements {
+            println!("    {}", statement);
+        }
+    }
+
+    if !yes {
+        match Confirm::new("Apply these changes to the cluster?")
+            .with_default(false)
+            .with_help_message("Use the --yes or -y option to skip this prompt")
+            .with_placeholder("no")
+            .prompt()
+        {
+            Ok(true) => println!("Applying..."),
+            Ok(false) => {
+                println!("Abort.");
+                exit(1);
+            }
+            Err(_) => {
+                println!("Error with questionnaire, try again later");
+                exit(-1);
+            }
+        }
+    }
+
+    for step in &plan {
+        for statement in &step.statements {
+            client
+                .simple_query(statement)
+                .await
+                .with_context(|| format!("failed to execute `{}`", statement))?;
+        }
+        println!("{} \"{}\": done", step.kind.sql_keyword(), step.name);
+    }
+    println!("Apply complete, {} change(s) made.", plan.len());
+
+    Ok(())
+}
@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::time::Duration;
+use std::process::exit;
+use std::time::{Duration, UNIX_EPOCH};
 
+use chrono::offset::Utc;
+use chrono::DateTime;
+use comfy_table::{Row, Table};
+use inquire::Confirm;
+use risingwave_common::util::epoch::Epoch;
+use risingwave_meta::backup_restore::RestoreOpts;
 use risingwave_pb::backup_service::BackupJobStatus;
+use size::Size;
 
 use crate::CtlContext;
 
@@ -50,3 +58,77 @@ pub async fn delete_meta_snapshots(
     tracing::info!("delete meta snapshots succeeded: {:?}", snapshot_ids);
     Ok(())
 }
+
+pub async fn list_meta_snapshots(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let manifest = meta_client.get_meta_snapshot_manifest().await?;
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Snapshot ID".into());
+        row.add_cell("Base".into());
+        row.add_cell("Hummock Version".into());
+        row.add_cell("Committed Epoch".into());
+        row.add_cell("Committed At".into());
+        row.add_cell("Size".into());
+        row.add_cell("Taken At".into());
+        row
+    });
+    for snapshot in manifest.snapshot_metadata {
+        let committed_at = DateTime::<Utc>::from(
+            UNIX_EPOCH
+                + Duration::from_millis(Epoch::from(snapshot.max_committed_epoch).as_unix_millis()),
+        );
+        // Snapshots taken before the timestamp was recorded have no `Taken At`.
+        let taken_at = match snapshot.timestamp {
+            Some(timestamp) => {
+                DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_secs(timestamp)).to_string()
+            }
+            None => "unknown".to_string(),
+        };
+        // A full snapshot has no base; an incremental one stores only the delta from its base.
+        let base = match snapshot.base_id {
+            Some(base_id) => base_id.to_string(),
+            None => "full".to_string(),
+        };
+        let mut row = Row::new();
+        row.add_cell(snapshot.id.into());
+        row.add_cell(base.into());
+        row.add_cell(snapshot.hummock_version_id.into());
+        row.add_cell(snapshot.max_committed_epoch.into());
+        row.add_cell(committed_at.to_string().into());
+        row.add_cell(Size::from_bytes(snapshot.size).to_string().into());
+        row.add_cell(taken_at.into());
+        table.add_row(row);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+pub async fn restore_meta(opts: RestoreOpts, yes: bool) -> anyhow::Result<()> {
+    if !yes {
+        match Confirm::new(&format!(
+            "Will restore meta snapshot {} into the meta store, are you sure?",
+            opts.meta_snapshot_id
+        ))
+        .with_default(false)
+        .with_help_message("Use the --yes or -y option to skip this prompt")
+        .with_placeholder("no")
+        .prompt()
+        {
+            Ok(true) => println!("Processing..."),
+            Ok(false) => {
+                println!("Abort.");
+                exit(1);
+            }
+            Err(_) => {
+                println!("Error with questionnaire, try again later");
+                exit(-1);
+            }
+        }
+    }
+    risingwave_meta::backup_restore::restore(opts).await?;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Local, TimeZone};
+use comfy_table::{Row, Table};
+use itertools::Itertools;
+use risingwave_common::util::epoch::Epoch;
+
+use crate::CtlContext;
+
+/// Number of slowest fragments to show per barrier.
+const SLOWEST_FRAGMENTS_PER_BARRIER: usize = 3;
+
+pub async fn barrier_latency(context: &CtlContext, limit: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let entries = meta_client.list_barrier_latency(limit).await?;
+
+    if entries.is_empty() {
+        println!("No barrier latency entries recorded yet");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("INJECT TIME".into());
+        row.add_cell("PREV EPOCH".into());
+        row.add_cell("CHECKPOINT".into());
+        row.add_cell("TOTAL".into());
+        row.add_cell("SLOWEST FRAGMENTS".into());
+        row
+    });
+
+    for entry in entries {
+        let inject_time = Local
+            .timestamp_millis_opt(Epoch::from(entry.curr_epoch).as_unix_millis() as i64)
+            .unwrap()
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let slowest = entry
+            .fragment_latency
+            .iter()
+            .sorted_by_key(|f| std::cmp::Reverse(f.max_collect_latency_ms))
+            .take(SLOWEST_FRAGMENTS_PER_BARRIER)
+            .map(|f| {
+                format!(
+                    "#{}: max {}ms, avg {}ms",
+                    f.fragment_id, f.max_collect_latency_ms, f.avg_collect_latency_ms
+                )
+            })
+            .join("; ");
+
+        let mut row = Row::new();
+        row.add_cell(inject_time.into());
+        row.add_cell(entry.prev_epoch.into());
+        row.add_cell(entry.checkpoint.into());
+        row.add_cell(format!("{}ms", entry.total_latency_ms).into());
+        row.add_cell(slowest.into());
+        table.add_row(row);
+    }
+
+    println!("{table}");
+    Ok(())
+}
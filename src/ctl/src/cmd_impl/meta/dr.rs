@@ -0,0 +1,59 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::util::epoch::Epoch;
+
+use crate::CtlContext;
+
+pub async fn dr_status(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let status = meta_client.get_dr_status().await?;
+    if !status.enabled {
+        println!(
+            "Disaster recovery replication is not configured. Set `dr_secondary_object_store` \
+             in the meta config to enable it."
+        );
+        return Ok(());
+    }
+    let replication_lag_ms = Epoch::from(status.committed_epoch)
+        .physical_time()
+        .saturating_sub(Epoch::from(status.replicated_epoch).physical_time());
+    println!("secondary location: {}", status.secondary_location);
+    println!("replicated epoch: {}", status.replicated_epoch);
+    println!("committed epoch: {}", status.committed_epoch);
+    println!("replication lag: {}ms", replication_lag_ms);
+    println!(
+        "replicated SST objects since meta start: {}",
+        status.replicated_object_count
+    );
+    println!(
+        "latest replicated meta snapshot: {}",
+        if status.replicated_meta_snapshot_id == 0 {
+            "none".to_string()
+        } else {
+            status.replicated_meta_snapshot_id.to_string()
+        }
+    );
+    Ok(())
+}
+
+pub async fn dr_promote(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let resp = meta_client.promote_dr_standby().await?;
+    println!(
+        "standby promoted to primary at hummock version {}",
+        resp.version_id
+    );
+    Ok(())
+}
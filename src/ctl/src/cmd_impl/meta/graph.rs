@@ -0,0 +1,144 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{anyhow, Result};
+use serde_json::json;
+
+use super::get_cluster_info;
+use crate::CtlContext;
+
+struct FragmentNode {
+    table_id: u32,
+    identity: String,
+    parallelism: usize,
+    distribution_type: String,
+}
+
+struct FragmentEdge {
+    channel: String,
+    dist_key_indices: Vec<u32>,
+}
+
+/// Exports the fragment graph of all streaming jobs as Graphviz DOT or JSON, with parallelism,
+/// distribution and channel information attached, so scheduling issues can be debugged visually.
+pub async fn graph(context: &CtlContext, format: String) -> Result<()> {
+    let info = get_cluster_info(context).await?;
+
+    let mut actor_to_fragment = HashMap::new();
+    for table_fragment in &info.table_fragments {
+        for fragment in table_fragment.fragments.values() {
+            for actor in &fragment.actors {
+                actor_to_fragment.insert(actor.actor_id, fragment.fragment_id);
+            }
+        }
+    }
+
+    let mut nodes: BTreeMap<u32, FragmentNode> = BTreeMap::new();
+    let mut edges: BTreeMap<(u32, u32), FragmentEdge> = BTreeMap::new();
+    for table_fragment in &info.table_fragments {
+        for fragment in table_fragment.fragments.values() {
+            let identity = fragment
+                .actors
+                .first()
+                .and_then(|actor| actor.nodes.as_ref())
+                .map(|node| node.identity.clone())
+                .unwrap_or_default();
+            nodes.insert(
+                fragment.fragment_id,
+                FragmentNode {
+                    table_id: table_fragment.table_id,
+                    identity,
+                    parallelism: fragment.actors.len(),
+                    distribution_type: fragment.distribution_type().as_str_name().to_string(),
+                },
+            );
+
+            for actor in &fragment.actors {
+                for dispatcher in &actor.dispatcher {
+                    for downstream in &dispatcher.downstream_actor_id {
+                        if let Some(&to) = actor_to_fragment.get(downstream) {
+                            edges.entry((fragment.fragment_id, to)).or_insert_with(|| {
+                                FragmentEdge {
+                                    channel: dispatcher.r#type().as_str_name().to_string(),
+                                    dist_key_indices: dispatcher.dist_key_indices.clone(),
+                                }
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    match format.as_str() {
+        "dot" => {
+            println!("digraph streaming_graph {{");
+            println!("  rankdir = LR;");
+            println!("  node [shape = box];");
+            for (fragment_id, node) in &nodes {
+                println!(
+                    "  {} [label=\"Fragment {} (table {})\\n{}\\n{} x {}\"];",
+                    fragment_id,
+                    fragment_id,
+                    node.table_id,
+                    node.identity.replace('"', "\\\""),
+                    node.distribution_type,
+                    node.parallelism,
+                );
+            }
+            for ((from, to), edge) in &edges {
+                let label = if edge.dist_key_indices.is_empty() {
+                    edge.channel.clone()
+                } else {
+                    format!("{} {:?}", edge.channel, edge.dist_key_indices)
+                };
+                println!("  {from} -> {to} [label=\"{label}\"];");
+            }
+            println!("}}");
+        }
+        "json" => {
+            let value = json!({
+                "fragments": nodes
+                    .iter()
+                    .map(|(fragment_id, node)| {
+                        json!({
+                            "fragment_id": fragment_id,
+                            "table_id": node.table_id,
+                            "identity": node.identity,
+                            "parallelism": node.parallelism,
+                            "distribution_type": node.distribution_type,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+                "edges": edges
+                    .iter()
+                    .map(|((from, to), edge)| {
+                        json!({
+                            "from_fragment_id": from,
+                            "to_fragment_id": to,
+                            "channel": edge.channel,
+                            "dist_key_indices": edge.dist_key_indices,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&value)?);
+        }
+        f => return Err(anyhow!("invalid format `{f}`, expected `dot` or `json`")),
+    }
+
+    Ok(())
+}
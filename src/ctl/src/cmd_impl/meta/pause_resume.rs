@@ -33,3 +33,33 @@ pub async fn resume(context: &CtlContext) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn pause_job(context: &CtlContext, table_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.pause_streaming_job(table_id).await?;
+
+    println!("Paused job #{}", table_id);
+
+    Ok(())
+}
+
+pub async fn resume_job(context: &CtlContext, table_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.resume_streaming_job(table_id).await?;
+
+    println!("Resumed job #{}", table_id);
+
+    Ok(())
+}
+
+pub async fn resume_quarantined_job(context: &CtlContext, table_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.resume_quarantined_job(table_id).await?;
+
+    println!("Resumed quarantined job #{}", table_id);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Local, TimeZone};
+use comfy_table::{Row, Table};
+use itertools::Itertools;
+
+use crate::CtlContext;
+
+pub async fn list_recoveries(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let recoveries = meta_client.list_recoveries().await?;
+
+    if recoveries.is_empty() {
+        println!("No recoveries recorded yet");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("STARTED AT".into());
+        row.add_cell("DURATION".into());
+        row.add_cell("NEW EPOCH".into());
+        row.add_cell("FAILED ACTORS".into());
+        row.add_cell("CAUSE".into());
+        row
+    });
+
+    for recovery in recoveries {
+        let started_at = Local
+            .timestamp_millis_opt(recovery.started_at_ms as i64)
+            .unwrap()
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let failed_actors = recovery
+            .failed_actors
+            .iter()
+            .map(|id| format!("#{}", id))
+            .join(", ");
+
+        let mut row = Row::new();
+        row.add_cell(started_at.into());
+        row.add_cell(format!("{}ms", recovery.duration_ms).into());
+        row.add_cell(recovery.new_epoch.into());
+        row.add_cell(failed_actors.into());
+        row.add_cell(recovery.cause.into());
+        table.add_row(row);
+    }
+
+    println!("{table}");
+    Ok(())
+}
@@ -80,6 +80,9 @@ impl From<Reschedule> for FragmentReschedulePlan {
     }
 }
 
+/// Assumed bandwidth at which state is migrated and rebuilt, used for the disruption estimate.
+const ESTIMATED_MIGRATION_BYTES_PER_SEC: u64 = 100 << 20;
+
 const RESCHEDULE_MATCH_REGEXP: &str =
     r"^(?P<fragment>\d+)(?:-\[(?P<removed>\d+(?:,\d+)*)])?(?:\+\[(?P<added>\d+(?:,\d+)*)])?$";
 const RESCHEDULE_FRAGMENT_KEY: &str = "fragment";
@@ -155,6 +158,10 @@ pub async fn reschedule(
         println!();
     }
 
+    if dry_run {
+        print_migration_estimates(context, &reschedules).await?;
+    }
+
     if !dry_run {
         println!("---------------------------");
         let (success, revision) = meta_client.reschedule(reschedules, revision).await?;
@@ -222,6 +229,86 @@ fn parse_plan(mut plan: String) -> Result<HashMap<u32, Reschedule>, Error> {
     Ok(reschedules)
 }
 
+/// Print the estimated amount of state to be migrated per fragment, derived from the hummock
+/// table statistics of the fragments' state tables, together with an estimated disruption time.
+pub async fn print_migration_estimates(
+    context: &CtlContext,
+    reschedules: &HashMap<u32, Reschedule>,
+) -> Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let GetClusterInfoResponse {
+        table_fragments, ..
+    } = meta_client.get_cluster_info().await?;
+    let version_stats = meta_client.get_hummock_version_stats().await?;
+
+    let fragment_index: HashMap<_, _> = table_fragments
+        .iter()
+        .flat_map(|table_fragments| table_fragments.fragments.iter())
+        .map(|(fragment_id, fragment)| (*fragment_id, fragment))
+        .collect();
+
+    println!("Estimated migration cost:");
+
+    let mut total_migrated_bytes = 0;
+    for (fragment_id, reschedule) in reschedules.iter().sorted_by_key(|(id, _)| **id) {
+        let Some(fragment) = fragment_index.get(fragment_id) else {
+            println!("\tFragment #{}: not found in the cluster", fragment_id);
+            continue;
+        };
+
+        let state_size: u64 = fragment
+            .state_table_ids
+            .iter()
+            .map(|table_id| {
+                version_stats
+                    .table_stats
+                    .get(table_id)
+                    .map(|stats| (stats.total_key_size + stats.total_value_size).max(0) as u64)
+                    .unwrap_or(0)
+            })
+            .sum();
+
+        // Roughly, each moved parallel unit migrates an even share of the fragment's state.
+        let parallelism = fragment.actors.len().max(1) as u64;
+        let moved = reschedule
+            .removed_parallel_units
+            .len()
+            .max(reschedule.added_parallel_units.len()) as u64;
+        let migrated_bytes = state_size * moved.min(parallelism) / parallelism;
+        total_migrated_bytes += migrated_bytes;
+
+        println!(
+            "\tFragment #{}: ~{} of state to migrate",
+            fragment_id,
+            format_bytes(migrated_bytes)
+        );
+    }
+
+    println!(
+        "\tTotal: ~{} of state, ≈{}s of disruption at {}/s",
+        format_bytes(total_migrated_bytes),
+        (total_migrated_bytes / ESTIMATED_MIGRATION_BYTES_PER_SEC).max(1),
+        format_bytes(ESTIMATED_MIGRATION_BYTES_PER_SEC)
+    );
+    println!();
+
+    Ok(())
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1}{}", value, UNITS[unit])
+}
+
 pub async fn get_reschedule_plan(
     context: &CtlContext,
     policy: PbPolicy,
@@ -15,7 +15,9 @@
 use std::path::PathBuf;
 
 use chrono::prelude::Local;
+use clap::Subcommand;
 use futures::future::try_join_all;
+use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::monitor_service::ProfilingResponse;
 use risingwave_rpc_client::ComputeClientPool;
@@ -24,7 +26,31 @@ use tokio::io::AsyncWriteExt;
 
 use crate::CtlContext;
 
-pub async fn profile(context: &CtlContext, sleep_s: u64) -> anyhow::Result<()> {
+#[derive(Subcommand)]
+pub enum ProfileCommands {
+    /// take a CPU flamegraph of all compute nodes
+    Cpu {
+        /// the number of seconds to sample
+        #[clap(short, long = "sleep")]
+        sleep: u64,
+    },
+    /// trigger a jemalloc heap profile dump on all compute nodes.
+    /// The nodes must be started with `MALLOC_CONF=prof:true` for the dump to succeed
+    Heap {
+        /// directory on the compute nodes to dump the profile to; defaults to their temp dir
+        #[clap(long, default_value = "")]
+        dir: String,
+    },
+}
+
+pub async fn do_profile(context: &CtlContext, cmd: ProfileCommands) -> anyhow::Result<()> {
+    match cmd {
+        ProfileCommands::Cpu { sleep } => cpu_profile(context, sleep).await,
+        ProfileCommands::Heap { dir } => heap_profile(context, dir).await,
+    }
+}
+
+pub async fn cpu_profile(context: &CtlContext, sleep_s: u64) -> anyhow::Result<()> {
     let meta_client = context.meta_client().await?;
 
     let workers = meta_client.get_cluster_info().await?.worker_nodes;
@@ -81,3 +107,40 @@ pub async fn profile(context: &CtlContext, sleep_s: u64) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn heap_profile(context: &CtlContext, dir: String) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let workers = meta_client.get_cluster_info().await?.worker_nodes;
+    let compute_nodes = workers
+        .into_iter()
+        .filter(|w| w.r#type() == WorkerType::ComputeNode);
+
+    let clients = ComputeClientPool::default();
+
+    // FIXME: the compute node may not be accessible directly from risectl, we may let the meta
+    // service collect the reports from all compute nodes in the future.
+    for cn in compute_nodes {
+        let client = clients.get(&cn).await?;
+        let host_addr = HostAddr::from(cn.get_host().expect("Should have host address"));
+        match client.heap_profile(dir.clone()).await {
+            Ok(response) => {
+                println!("Dumped heap profile of {} to {}", host_addr, response.path);
+            }
+            Err(err) => {
+                tracing::error!(
+                    "Failed to dump heap profile of {} with error {}",
+                    host_addr,
+                    err.to_string()
+                );
+            }
+        }
+    }
+
+    println!(
+        "Symbolize the dumps on the corresponding node with e.g. \
+         `jeprof --collapsed <compute-node-binary> <dump-file>`"
+    );
+
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod drain;
 mod resize;
 
+pub use drain::*;
 pub use resize::*;
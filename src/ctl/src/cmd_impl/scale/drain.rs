@@ -0,0 +1,260 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::process::exit;
+use std::time::Duration;
+
+use inquire::Confirm;
+use itertools::Itertools;
+use risingwave_pb::common::WorkerNode;
+use risingwave_pb::meta::get_reschedule_plan_request::{
+    PbPolicy, StableResizePolicy, WorkerChanges,
+};
+use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
+use risingwave_pb::meta::{GetClusterInfoResponse, GetReschedulePlanResponse, PbTableFragments};
+use risingwave_rpc_client::MetaClient;
+use risingwave_stream::task::FragmentId;
+
+use crate::common::CtlContext;
+
+/// Interval between two checks while waiting for the drained workers to become empty.
+const DRAIN_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum number of checks while waiting for the drained workers to become empty.
+const DRAIN_CHECK_ATTEMPTS: usize = 30;
+
+/// Gracefully take the given workers out of the cluster: cordon them, move their actors to the
+/// remaining workers, wait until they are empty and finally unregister them.
+pub async fn drain(context: &CtlContext, workers: Vec<String>, yes: bool) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let GetClusterInfoResponse {
+        worker_nodes,
+        table_fragments,
+        revision,
+        ..
+    } = match meta_client.get_cluster_info().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            println!("Failed to fetch cluster info: {}", e);
+            exit(1);
+        }
+    };
+
+    let streaming_workers_index_by_id = worker_nodes
+        .into_iter()
+        .filter(|worker| {
+            worker
+                .property
+                .as_ref()
+                .map(|property| property.is_streaming)
+                .unwrap_or(false)
+        })
+        .map(|worker| (worker.id, worker))
+        .collect::<HashMap<_, _>>();
+
+    let streaming_workers_index_by_host = streaming_workers_index_by_id
+        .values()
+        .map(|worker| {
+            let host = worker.get_host().expect("worker host must be set");
+            (format!("{}:{}", host.host, host.port), worker.clone())
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut drained_worker_ids = HashSet::new();
+
+    for worker in workers {
+        let worker_id = worker.parse::<u32>().ok().or_else(|| {
+            streaming_workers_index_by_host
+                .get(&worker)
+                .map(|worker| worker.id)
+        });
+
+        if let Some(worker_id) = worker_id
+            && streaming_workers_index_by_id.contains_key(&worker_id)
+        {
+            if !drained_worker_ids.insert(worker_id) {
+                println!("warn: {} and {} are the same worker", worker, worker_id);
+            }
+        } else {
+            println!("Invalid worker input: {}", worker);
+            exit(1);
+        }
+    }
+
+    if drained_worker_ids.len() >= streaming_workers_index_by_id.len() {
+        println!("Cannot drain all streaming workers of the cluster");
+        exit(1);
+    }
+
+    let drained_workers = drained_worker_ids
+        .iter()
+        .map(|worker_id| streaming_workers_index_by_id[worker_id].clone())
+        .collect_vec();
+
+    if !yes {
+        match Confirm::new("Will drain and unregister the given workers, are you sure?")
+            .with_default(false)
+            .with_help_message("Use the --yes or -y option to skip this prompt")
+            .with_placeholder("no")
+            .prompt()
+        {
+            Ok(true) => println!("Processing..."),
+            Ok(false) => {
+                println!("Abort.");
+                exit(1);
+            }
+            Err(_) => {
+                println!("Error with questionnaire, try again later");
+                exit(-1);
+            }
+        }
+    }
+
+    // Cordon the workers first, so that concurrent scheduling does not place new actors on them
+    // while the plan is generated and applied.
+    let drained_worker_id_vec = drained_worker_ids.iter().copied().collect_vec();
+    meta_client
+        .update_schedulability(&drained_worker_id_vec, Schedulability::Unschedulable)
+        .await?;
+    println!("Cordoned workers: {:?}", drained_worker_id_vec);
+
+    let all_fragment_ids: HashSet<_> = table_fragments
+        .iter()
+        .flat_map(|table_fragments| table_fragments.fragments.keys().cloned())
+        .collect();
+
+    let worker_changes = WorkerChanges {
+        include_worker_ids: vec![],
+        exclude_worker_ids: drained_worker_id_vec.clone(),
+    };
+
+    let policy = PbPolicy::StableResizePolicy(StableResizePolicy {
+        fragment_worker_changes: all_fragment_ids
+            .iter()
+            .map(|id| (*id as FragmentId, worker_changes.clone()))
+            .collect(),
+    });
+
+    let GetReschedulePlanResponse {
+        revision,
+        reschedules,
+        success,
+    } = match meta_client.get_reschedule_plan(policy, revision).await {
+        Ok(response) => response,
+        Err(e) => {
+            println!("Failed to generate plan: {:?}", e);
+            exit(1);
+        }
+    };
+
+    if !success {
+        println!("Failed to generate plan, current revision is {}", revision);
+        exit(1);
+    }
+
+    if reschedules.is_empty() {
+        println!("Workers are already empty, no reschedule required");
+    } else {
+        println!(
+            "Successfully generated plan for {} fragments, current revision is {}",
+            reschedules.len(),
+            revision
+        );
+
+        let (success, next_revision) = match meta_client.reschedule(reschedules, revision).await {
+            Ok(response) => response,
+            Err(e) => {
+                println!("Failed to execute plan: {:?}", e);
+                exit(1);
+            }
+        };
+
+        if !success {
+            println!("Failed to execute plan, current revision is {}", revision);
+            exit(1);
+        }
+
+        println!(
+            "Successfully executed plan, current revision is {}",
+            next_revision
+        );
+    }
+
+    // Wait until no actor is placed on the drained workers anymore, e.g. when a recovery that
+    // was triggered concurrently has settled.
+    let mut remaining = usize::MAX;
+    for _ in 0..DRAIN_CHECK_ATTEMPTS {
+        let GetClusterInfoResponse {
+            table_fragments, ..
+        } = meta_client.get_cluster_info().await?;
+
+        remaining = remaining_actors(&table_fragments, &drained_worker_ids);
+        if remaining == 0 {
+            break;
+        }
+
+        println!(
+            "Waiting for {} actors to leave the drained workers...",
+            remaining
+        );
+        tokio::time::sleep(DRAIN_CHECK_INTERVAL).await;
+    }
+
+    if remaining != 0 {
+        println!(
+            "Timed out waiting for the workers to become empty, {} actors remaining. The workers \
+             are left cordoned, re-run the command to retry.",
+            remaining
+        );
+        exit(1);
+    }
+
+    for worker in drained_workers {
+        unregister_worker(&meta_client, worker).await?;
+    }
+
+    println!("Done");
+
+    Ok(())
+}
+
+/// Number of actors still placed on the given workers.
+fn remaining_actors(
+    table_fragments: &[PbTableFragments],
+    drained_worker_ids: &HashSet<u32>,
+) -> usize {
+    table_fragments
+        .iter()
+        .flat_map(|table_fragments| table_fragments.actor_status.values())
+        .filter(|status| {
+            status
+                .parallel_unit
+                .as_ref()
+                .map(|parallel_unit| drained_worker_ids.contains(&parallel_unit.worker_node_id))
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+async fn unregister_worker(meta_client: &MetaClient, worker: WorkerNode) -> anyhow::Result<()> {
+    let host = worker.get_host().expect("worker host must be set").clone();
+    meta_client.delete_worker_node(host.clone()).await?;
+    println!(
+        "Unregistered worker {} ({}:{})",
+        worker.id, host.host, host.port
+    );
+    Ok(())
+}
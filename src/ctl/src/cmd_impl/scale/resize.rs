@@ -25,7 +25,7 @@ use risingwave_pb::meta::{GetClusterInfoResponse, GetReschedulePlanResponse};
 use risingwave_stream::task::FragmentId;
 use serde_yaml;
 
-use crate::cmd_impl::meta::ReschedulePayload;
+use crate::cmd_impl::meta::{print_migration_estimates, ReschedulePayload};
 use crate::common::CtlContext;
 use crate::ScaleResizeCommands;
 
@@ -224,6 +224,8 @@ pub async fn resize(context: &CtlContext, resize: ScaleResizeCommands) -> anyhow
     );
 
     if generate {
+        print_migration_estimates(context, &reschedules).await?;
+
         let payload = ReschedulePayload {
             reschedule_revision: revision,
             reschedule_plan: reschedules
@@ -337,3 +339,55 @@ pub async fn update_schedulability(
 
     Ok(())
 }
+
+pub async fn update_resource_group(
+    context: &CtlContext,
+    workers: Vec<String>,
+    resource_group: String,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let GetClusterInfoResponse { worker_nodes, .. } = match meta_client.get_cluster_info().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            println!("Failed to get cluster info: {:?}", e);
+            exit(1);
+        }
+    };
+
+    let worker_ids: HashSet<_> = worker_nodes.iter().map(|worker| worker.id).collect();
+
+    let worker_index_by_host: HashMap<_, _> = worker_nodes
+        .iter()
+        .map(|worker| {
+            let host = worker.get_host().expect("worker host must be set");
+            (format!("{}:{}", host.host, host.port), worker.id)
+        })
+        .collect();
+
+    let mut target_worker_ids = HashSet::new();
+
+    for worker in workers {
+        let worker_id = worker
+            .parse::<u32>()
+            .ok()
+            .or_else(|| worker_index_by_host.get(&worker).cloned());
+
+        if let Some(worker_id) = worker_id && worker_ids.contains(&worker_id){
+            if !target_worker_ids.insert(worker_id) {
+                println!("Warn: {} and {} are the same worker", worker, worker_id);
+            }
+        } else {
+            println!("Invalid worker id: {}", worker);
+            exit(1);
+        }
+    }
+
+    let target_worker_ids = target_worker_ids.into_iter().collect_vec();
+
+    meta_client
+        .update_resource_group(&target_worker_ids, resource_group)
+        .await?;
+
+    Ok(())
+}
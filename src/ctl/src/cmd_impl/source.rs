@@ -0,0 +1,96 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use comfy_table::{Row, Table};
+
+use crate::CtlContext;
+
+pub async fn list_splits(context: &CtlContext, source_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let assignments = meta_client.list_source_splits(source_id).await?;
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Fragment".into());
+        row.add_cell("Actor".into());
+        row.add_cell("Splits".into());
+        row
+    });
+    for assignment in assignments {
+        let mut row = Row::new();
+        row.add_cell(assignment.fragment_id.into());
+        row.add_cell(assignment.actor_id.into());
+        row.add_cell(assignment.split_ids.join(", ").into());
+        table.add_row(row);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+pub async fn show_lag(context: &CtlContext, source_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let split_lags = meta_client.get_source_lag(source_id).await?;
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Split".into());
+        row.add_cell("Assigned Offset".into());
+        row.add_cell("Latest Offset".into());
+        row.add_cell("Lag".into());
+        row
+    });
+    for split_lag in split_lags {
+        // The assigned offset only tracks split discovery, so the lag is an upper bound on the
+        // actual consumer lag.
+        let lag = match (
+            split_lag.assigned_offset.parse::<i64>(),
+            split_lag.latest_offset.parse::<i64>(),
+        ) {
+            (Ok(assigned), Ok(latest)) => (latest - assigned).to_string(),
+            _ => "-".to_string(),
+        };
+        let mut row = Row::new();
+        row.add_cell(split_lag.split_id.into());
+        row.add_cell(split_lag.assigned_offset.into());
+        row.add_cell(split_lag.latest_offset.into());
+        row.add_cell(lag.into());
+        table.add_row(row);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+pub async fn pause(context: &CtlContext, source_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.pause_source(source_id).await?;
+
+    println!("Paused source #{}", source_id);
+
+    Ok(())
+}
+
+pub async fn resume(context: &CtlContext, source_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.resume_source(source_id).await?;
+
+    println!("Resumed source #{}", source_id);
+
+    Ok(())
+}
@@ -17,3 +17,6 @@ pub use scan::*;
 
 mod list;
 pub use list::*;
+
+mod stats;
+pub use stats::*;
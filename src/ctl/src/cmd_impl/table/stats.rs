@@ -0,0 +1,106 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use comfy_table::{Row, Table};
+use risingwave_pb::common::WorkerType;
+use risingwave_pb::monitor_service::TableReadStats;
+use risingwave_rpc_client::ComputeClientPool;
+
+use crate::CtlContext;
+
+/// Aggregates per-table read stats from all compute nodes and storage stats from meta, so
+/// capacity planning doesn't require joining Prometheus metrics by table id manually.
+pub async fn stats(context: &CtlContext) -> Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let mvs = meta_client.risectl_list_state_tables().await?;
+    let version_stats = meta_client.get_hummock_version_stats().await?;
+
+    let workers = meta_client.get_cluster_info().await?.worker_nodes;
+    let compute_nodes = workers
+        .into_iter()
+        .filter(|w| w.r#type() == WorkerType::ComputeNode);
+
+    let clients = ComputeClientPool::default();
+
+    // FIXME: the compute node may not be accessible directly from risectl, we may let the meta
+    // service collect the reports from all compute nodes in the future.
+    let mut read_stats: BTreeMap<u32, TableReadStats> = BTreeMap::new();
+    for cn in compute_nodes {
+        let client = clients.get(&cn).await?;
+        for stats in client.get_table_read_stats().await?.table_stats {
+            let entry = read_stats
+                .entry(stats.table_id)
+                .or_insert_with(|| TableReadStats {
+                    table_id: stats.table_id,
+                    ..Default::default()
+                });
+            entry.block_cache_total_count += stats.block_cache_total_count;
+            entry.block_cache_miss_count += stats.block_cache_miss_count;
+            entry.remote_io_count += stats.remote_io_count;
+        }
+    }
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Table ID".into());
+        row.add_cell("Name".into());
+        row.add_cell("Cache Hit Ratio".into());
+        row.add_cell("Remote IO Count".into());
+        row.add_cell("Key Count".into());
+        row.add_cell("Storage Size".into());
+        row
+    });
+
+    for mv in mvs {
+        let (hit_ratio, remote_io_count) = match read_stats.get(&mv.id) {
+            Some(stats) if stats.block_cache_total_count > 0 => (
+                format!(
+                    "{:.2}%",
+                    (stats.block_cache_total_count - stats.block_cache_miss_count) as f64 * 100.0
+                        / stats.block_cache_total_count as f64
+                ),
+                stats.remote_io_count.to_string(),
+            ),
+            Some(stats) => ("-".to_string(), stats.remote_io_count.to_string()),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        let (key_count, storage_size) = match version_stats.table_stats.get(&mv.id) {
+            Some(stats) => (
+                stats.total_key_count.to_string(),
+                format!(
+                    "{} KiB",
+                    (stats.total_key_size + stats.total_value_size) >> 10
+                ),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        let mut row = Row::new();
+        row.add_cell(mv.id.into());
+        row.add_cell(mv.name.into());
+        row.add_cell(hit_ratio.into());
+        row.add_cell(remote_io_count.into());
+        row.add_cell(key_count.into());
+        row.add_cell(storage_size.into());
+        table.add_row(row);
+    }
+
+    println!("{table}");
+    Ok(())
+}
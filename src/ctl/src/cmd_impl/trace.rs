@@ -12,18 +12,70 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::monitor_service::StackTraceResponse;
-use risingwave_rpc_client::{CompactorClient, ComputeClientPool};
+use risingwave_rpc_client::{CompactorClient, ComputeClientPool, MetaClient};
 
 use crate::CtlContext;
 
-pub async fn trace(context: &CtlContext) -> anyhow::Result<()> {
+pub async fn trace(
+    context: &CtlContext,
+    actor_ids: Vec<u32>,
+    fragment_ids: Vec<u32>,
+    watch: Option<u64>,
+    output_dir: String,
+) -> anyhow::Result<()> {
     let meta_client = context.meta_client().await?;
 
+    // Resolve the fragment filter to actor ids once upfront, so that watch mode does not query
+    // meta on every capture.
+    let actor_filter: Option<HashSet<u32>> = if actor_ids.is_empty() && fragment_ids.is_empty() {
+        None
+    } else {
+        let mut actors: HashSet<u32> = actor_ids.into_iter().collect();
+        if !fragment_ids.is_empty() {
+            let fragment_ids: HashSet<u32> = fragment_ids.into_iter().collect();
+            for table_fragment in meta_client.get_cluster_info().await?.table_fragments {
+                for fragment in table_fragment.fragments.values() {
+                    if fragment_ids.contains(&fragment.fragment_id) {
+                        actors.extend(fragment.actors.iter().map(|actor| actor.actor_id));
+                    }
+                }
+            }
+        }
+        Some(actors)
+    };
+
+    match watch {
+        None => {
+            let dump = capture_dump(&meta_client, actor_filter.as_ref()).await?;
+            print!("{dump}");
+        }
+        Some(interval_sec) => loop {
+            let dump = capture_dump(&meta_client, actor_filter.as_ref()).await?;
+            let path = PathBuf::from(&output_dir).join(format!(
+                "await-tree-{}.txt",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ));
+            tokio::fs::write(&path, dump).await?;
+            println!("Dumped await-tree to {}", path.display());
+            tokio::time::sleep(Duration::from_secs(interval_sec)).await;
+        },
+    }
+
+    Ok(())
+}
+
+async fn capture_dump(
+    meta_client: &MetaClient,
+    actor_filter: Option<&HashSet<u32>>,
+) -> anyhow::Result<String> {
     let workers = meta_client.get_cluster_info().await?.worker_nodes;
     let compute_nodes = workers
         .into_iter()
@@ -52,37 +104,47 @@ pub async fn trace(context: &CtlContext) -> anyhow::Result<()> {
             )
         }));
     }
+    if let Some(actor_filter) = actor_filter {
+        all_actor_traces.retain(|actor_id, _| actor_filter.contains(actor_id));
+    }
 
+    let mut dump = String::new();
     if all_actor_traces.is_empty() && all_rpc_traces.is_empty() {
-        println!("No traces found. No actors are running, or `--async-stack-trace` not set?");
+        writeln!(
+            dump,
+            "No traces found. No actors are running, or `--async-stack-trace` not set?"
+        )?;
     } else {
-        println!("--- Actor Traces ---");
+        writeln!(dump, "--- Actor Traces ---")?;
         for (key, trace) in all_actor_traces {
-            println!(">> Actor {key}\n{trace}");
+            writeln!(dump, ">> Actor {key}\n{trace}")?;
         }
-        println!("--- RPC Traces ---");
+        writeln!(dump, "--- RPC Traces ---")?;
         for (key, trace) in all_rpc_traces {
-            println!(">> RPC {key}\n{trace}");
+            writeln!(dump, ">> RPC {key}\n{trace}")?;
         }
     }
 
-    let compactor_nodes = meta_client.list_worker_nodes(WorkerType::Compactor).await?;
-    let mut all_compaction_task_traces = BTreeMap::new();
-    for compactor in compactor_nodes {
-        let addr: HostAddr = compactor.get_host().unwrap().into();
-        let client = CompactorClient::new(addr).await?;
-        let StackTraceResponse {
-            compaction_task_traces,
-            ..
-        } = client.stack_trace().await?;
-        all_compaction_task_traces.extend(compaction_task_traces);
-    }
-    if !all_compaction_task_traces.is_empty() {
-        println!("--- Compactor Traces ---");
-        for (key, trace) in all_compaction_task_traces {
-            println!(">> Compaction Task {key}\n{trace}");
+    // Actor filters don't apply to compaction tasks, so skip compactors when filtering.
+    if actor_filter.is_none() {
+        let compactor_nodes = meta_client.list_worker_nodes(WorkerType::Compactor).await?;
+        let mut all_compaction_task_traces = BTreeMap::new();
+        for compactor in compactor_nodes {
+            let addr: HostAddr = compactor.get_host().unwrap().into();
+            let client = CompactorClient::new(addr).await?;
+            let StackTraceResponse {
+                compaction_task_traces,
+                ..
+            } = client.stack_trace().await?;
+            all_compaction_task_traces.extend(compaction_task_traces);
+        }
+        if !all_compaction_task_traces.is_empty() {
+            writeln!(dump, "--- Compactor Traces ---")?;
+            for (key, trace) in all_compaction_task_traces {
+                writeln!(dump, ">> Compaction Task {key}\n{trace}")?;
+            }
         }
     }
 
-    Ok(())
+    Ok(dump)
 }
@@ -15,5 +15,7 @@
 mod context;
 mod hummock_service;
 mod meta_service;
+mod profile;
 pub use context::*;
 pub use hummock_service::HummockServiceOpts;
+pub use profile::{Profile, RisectlConfig};
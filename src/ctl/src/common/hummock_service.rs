@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{anyhow, bail, Result};
+use risingwave_common::config::RetryConfig;
 use risingwave_object_store::object::parse_remote_object_store;
 use risingwave_rpc_client::MetaClient;
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
@@ -159,6 +160,8 @@ For `./risedev apply-compose-deploy` users,
             self.hummock_url.strip_prefix("hummock+").unwrap(),
             Arc::new(ObjectStoreMetrics::unused()),
             "Hummock",
+            None,
+            RetryConfig::default(),
         )
         .await;
 
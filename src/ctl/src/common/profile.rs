@@ -0,0 +1,233 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Named connection profiles for risectl, so operators working with multiple clusters don't have
+//! to re-export `RW_META_ADDR`-style env variables per cluster.
+//!
+//! Profiles are stored in `~/.risectl/config.yaml` (overridable via `RISECTL_CONFIG`):
+//!
+//! ```yaml
+//! current-profile: staging
+//! profiles:
+//!   staging:
+//!     meta-addr: http://staging-meta:5690
+//!     hummock-url: hummock+s3://staging-bucket
+//!   prod:
+//!     meta-addr: https://prod-meta:5690
+//!     hummock-url: hummock+s3://prod-bucket
+//!     env:
+//!       RW_S3_ENDPOINT: https://s3.internal
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A named set of connection options for one cluster.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Profile {
+    /// Meta service address, e.g. `http://127.0.0.1:5690`. Use an `https` scheme for TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta_addr: Option<String>,
+    /// Hummock state store URL, e.g. `hummock+minio://...`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hummock_url: Option<String>,
+    /// Extra env variables to export when this profile is active, e.g. object store credentials
+    /// or auth tokens.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+/// The on-disk risectl configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RisectlConfig {
+    /// The profile used when `--profile` is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl RisectlConfig {
+    /// Path of the config file: `RISECTL_CONFIG` if set, otherwise `~/.risectl/config.yaml`.
+    pub fn path() -> Result<PathBuf> {
+        if let Ok(path) = env::var("RISECTL_CONFIG") {
+            return Ok(PathBuf::from(path));
+        }
+        let home = env::var("HOME").context("neither `RISECTL_CONFIG` nor `HOME` is set")?;
+        Ok(PathBuf::from(home).join(".risectl").join("config.yaml"))
+    }
+
+    /// Load the config file, or the default (empty) config if it does not exist.
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).context(format!("failed to read {}", path.display())),
+        };
+        serde_yaml::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Persist the config file, creating the parent directory if needed.
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        let content = serde_yaml::to_string(self)?;
+        std::fs::write(&path, content)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Export the env variables of the selected profile, so that the existing
+    /// `RW_META_ADDR`/`RW_HUMMOCK_URL` based service options pick them up.
+    ///
+    /// An explicitly selected profile (`--profile`) overrides env variables already present in
+    /// the environment, while the `current-profile` of the config file only fills in env
+    /// variables that are not set, so ad-hoc `RW_META_ADDR=... risectl ...` invocations keep
+    /// working.
+    pub fn apply_profile(&self, selected: Option<&str>) -> Result<()> {
+        let (name, explicit) = match selected {
+            Some(name) => (name, true),
+            None => match &self.current_profile {
+                Some(name) => (name.as_str(), false),
+                None => return Ok(()),
+            },
+        };
+        let profile = self.profiles.get(name).ok_or_else(|| {
+            anyhow!(
+                "profile `{}` not found in {}",
+                name,
+                Self::path().map_or_else(
+                    |_| "the config file".to_string(),
+                    |p| p.display().to_string()
+                )
+            )
+        })?;
+
+        let set_var = |key: &str, value: &str| {
+            if explicit || env::var(key).is_err() {
+                env::set_var(key, value);
+            }
+        };
+        if let Some(meta_addr) = &profile.meta_addr {
+            set_var("RW_META_ADDR", meta_addr);
+        }
+        if let Some(hummock_url) = &profile.hummock_url {
+            set_var("RW_HUMMOCK_URL", hummock_url);
+        }
+        for (key, value) in &profile.env {
+            set_var(key, value);
+        }
+        tracing::info!("using risectl profile `{}`", name);
+        Ok(())
+    }
+
+    /// Switch `current-profile` to the given profile and persist the config.
+    pub fn use_profile(name: String) -> Result<()> {
+        let mut config = Self::load()?;
+        if !config.profiles.contains_key(&name) {
+            bail!(
+                "profile `{}` not found, available profiles: [{}]",
+                name,
+                config
+                    .profiles
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        config.current_profile = Some(name.clone());
+        config.save()?;
+        println!("switched to profile `{}`", name);
+        Ok(())
+    }
+
+    /// Print all profiles, marking the current one.
+    pub fn list_profiles() -> Result<()> {
+        let config = Self::load()?;
+        for (name, profile) in &config.profiles {
+            let current = if config.current_profile.as_deref() == Some(name) {
+                "*"
+            } else {
+                " "
+            };
+            println!(
+                "{} {}\tmeta-addr: {}\thummock-url: {}",
+                current,
+                name,
+                profile.meta_addr.as_deref().unwrap_or("<unset>"),
+                profile.hummock_url.as_deref().unwrap_or("<unset>"),
+            );
+        }
+        Ok(())
+    }
+
+    /// Print the name of the current profile.
+    pub fn current_profile() -> Result<()> {
+        let config = Self::load()?;
+        match config.current_profile {
+            Some(name) => println!("{}", name),
+            None => println!("<none>"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_roundtrip() {
+        let yaml = r#"
+current-profile: staging
+profiles:
+  staging:
+    meta-addr: http://staging-meta:5690
+    hummock-url: hummock+s3://staging-bucket
+    env:
+      RW_S3_ENDPOINT: https://s3.internal
+  prod: {}
+"#;
+        let config: RisectlConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.current_profile.as_deref(), Some("staging"));
+        let staging = &config.profiles["staging"];
+        assert_eq!(
+            staging.meta_addr.as_deref(),
+            Some("http://staging-meta:5690")
+        );
+        assert_eq!(staging.env["RW_S3_ENDPOINT"], "https://s3.internal");
+        assert!(config.profiles["prod"].meta_addr.is_none());
+
+        let reparsed: RisectlConfig =
+            serde_yaml::from_str(&serde_yaml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(reparsed.profiles.len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_field_rejected() {
+        let yaml = "profiles:\n  a:\n    meta-address: http://x\n";
+        assert!(serde_yaml::from_str::<RisectlConfig>(yaml).is_err());
+    }
+}
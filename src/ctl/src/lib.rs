@@ -18,7 +18,10 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use cmd_impl::bench::BenchCommands;
+use cmd_impl::debug::DebugCommands;
 use cmd_impl::hummock::SstDumpArgs;
+use cmd_impl::profile::ProfileCommands;
+use risingwave_meta::backup_restore::RestoreOpts;
 use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
 
 use crate::cmd_impl::hummock::{
@@ -39,6 +42,12 @@ pub mod common;
 #[clap(propagate_version = true)]
 #[clap(infer_subcommands = true)]
 pub struct CliOpts {
+    /// The connection profile from `~/.risectl/config.yaml` to use. Defaults to the
+    /// `current-profile` of the config file; explicitly selecting a profile overrides the
+    /// `RW_META_ADDR`/`RW_HUMMOCK_URL` env variables.
+    #[clap(long, global = true)]
+    profile: Option<String>,
+
     #[clap(subcommand)]
     command: Commands,
 }
@@ -58,20 +67,50 @@ enum Commands {
     /// Commands for Meta
     #[clap(subcommand)]
     Meta(MetaCommands),
+    /// Commands for Sources
+    #[clap(subcommand)]
+    Source(SourceCommands),
     /// Commands for Scaling
     #[clap(subcommand)]
     Scale(ScaleCommands),
     /// Commands for Benchmarks
     #[clap(subcommand)]
     Bench(BenchCommands),
+    /// Commands for debugging, e.g. fault injection
+    #[clap(subcommand)]
+    Debug(DebugCommands),
     /// Commands for tracing the compute nodes
-    Trace,
+    Trace {
+        /// only show traces of these actor ids
+        #[clap(long, value_delimiter = ',')]
+        actor_ids: Vec<u32>,
+        /// only show traces of the actors of these fragment ids
+        #[clap(long, value_delimiter = ',')]
+        fragment_ids: Vec<u32>,
+        /// capture a dump every `watch` seconds and write it to files instead of stdout
+        #[clap(long)]
+        watch: Option<u64>,
+        /// directory the watch-mode dumps are written to
+        #[clap(long, default_value = ".")]
+        output_dir: String,
+    },
     // TODO(yuhao): profile other nodes
     /// Commands for profilng the compute nodes
-    Profile {
-        #[clap(short, long = "sleep")]
-        sleep: u64,
-    },
+    #[clap(subcommand)]
+    Profile(ProfileCommands),
+    /// Commands for managing connection profiles
+    #[clap(subcommand)]
+    Config(ConfigCommands),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Switch the current profile in the config file
+    UseContext { name: String },
+    /// Show the name of the current profile
+    CurrentContext,
+    /// List all profiles in the config file
+    GetContexts,
 }
 
 #[derive(Subcommand)]
@@ -157,6 +196,23 @@ enum HummockCommands {
         level0_stop_write_threshold_sub_level_number: Option<u64>,
         #[clap(long)]
         level0_sub_level_compact_level_count: Option<u32>,
+        #[clap(long)]
+        sst_encryption_key_id: Option<String>,
+        /// `LEVEL:ALGORITHM`, e.g. `6:ZstdDict`. Algorithm is one of `None`, `Lz4`, `Zstd` or
+        /// `ZstdDict`.
+        #[clap(long)]
+        compression_algorithm_level: Option<String>,
+        /// `Bloom` or `Ribbon`. An empty string resets the group to the default xor filters.
+        #[clap(long)]
+        filter_algorithm: Option<String>,
+        #[clap(long)]
+        filter_bits_per_key: Option<u32>,
+        /// Groups with a higher priority are offered idle compactors first.
+        #[clap(long)]
+        compaction_priority: Option<u32>,
+        /// Max number of concurrent compaction tasks for the group. 0 means unlimited.
+        #[clap(long)]
+        max_concurrent_task_number: Option<u32>,
     },
     /// Split given compaction group into two. Moves the given tables to the new group.
     SplitCompactionGroup {
@@ -176,6 +232,66 @@ enum HummockCommands {
         #[clap(short, long = "verbose", default_value_t = false)]
         verbose: bool,
     },
+    /// List summaries of completed compaction tasks
+    TaskHistory,
+    /// Back up the hummock version of a meta snapshot and all SST objects it references
+    /// to another object store, for disaster recovery. Run `risectl meta backup-meta` first.
+    Backup {
+        /// Id of the meta snapshot to back up the hummock version of. Default: the latest one.
+        #[clap(long)]
+        meta_snapshot_id: Option<u64>,
+        /// Url of the object store to back up to.
+        #[clap(long)]
+        target_url: String,
+        /// Directory in the target object store to back up to.
+        #[clap(long)]
+        target_dir: String,
+    },
+    /// Restore a hummock data backup into a fresh object store prefix. Restore metadata
+    /// afterwards via `risectl meta restore-meta`.
+    Restore {
+        /// Url of the object store holding the data backup.
+        #[clap(long)]
+        source_url: String,
+        /// Directory in the source object store holding the data backup.
+        #[clap(long)]
+        source_dir: String,
+        /// Url of the object store to restore to.
+        #[clap(long)]
+        target_url: String,
+        /// Directory in the target object store to restore to. Must be a fresh directory.
+        #[clap(long)]
+        target_dir: String,
+    },
+    /// Verify the integrity of SST objects referenced by the current version: block
+    /// checksums, meta block footer and checksum, and key-range monotonicity.
+    Verify {
+        /// Id of a single SST object to verify. Default: all objects of the current version.
+        #[clap(short, long = "object-id")]
+        object_id: Option<u64>,
+        /// Rewrite the meta block of objects whose meta checksum is broken but whose
+        /// content verifies fine otherwise.
+        #[clap(long)]
+        repair_meta: bool,
+        /// Move corrupt objects into this directory within the object store.
+        #[clap(long)]
+        quarantine_dir: Option<String>,
+        // data directory for hummock state store. None: use default
+        data_dir: Option<String>,
+    },
+    /// Report objects in the store that no version references, i.e. what a subsequent
+    /// trigger-full-gc would delete. Nothing is deleted.
+    ListOrphans {
+        /// Objects uploaded within this time are not reported, because they may belong to an
+        /// ongoing version write.
+        #[clap(short, long = "sst_retention_time_sec", default_value_t = 259200)]
+        sst_retention_time_sec: u64,
+        /// Write a machine-readable JSON report to this local file.
+        #[clap(long)]
+        output: Option<String>,
+        // data directory for hummock state store. None: use default
+        data_dir: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -196,6 +312,8 @@ enum TableCommands {
     },
     /// list all state tables
     List,
+    /// show per-table read and storage stats aggregated from compute nodes and meta
+    Stats,
 }
 
 #[derive(clap::Args, Debug)]
@@ -255,6 +373,22 @@ enum ScaleCommands {
         )]
         workers: Vec<String>,
     },
+    /// gracefully remove compute nodes from the cluster: cordon them, move their actors to the
+    /// remaining nodes, wait until they are empty and unregister them
+    Drain {
+        /// Workers that need to be drained, both id and host are supported.
+        #[clap(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "id or host,..."
+        )]
+        workers: Vec<String>,
+
+        /// Automatic yes to prompts
+        #[clap(short = 'y', long, default_value_t = false)]
+        yes: bool,
+    },
     /// mark a compute node as schedulable. Nodes are schedulable unless they are cordoned
     Uncordon {
         /// Workers that need to be uncordoned, both id and host are supported.
@@ -266,6 +400,48 @@ enum ScaleCommands {
         )]
         workers: Vec<String>,
     },
+    /// label compute nodes with a resource group. Streaming jobs created with a matching
+    /// `resource_group` option are only scheduled to them
+    SetResourceGroup {
+        /// Workers that need to be labeled, both id and host are supported.
+        #[clap(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "id or host,..."
+        )]
+        workers: Vec<String>,
+
+        /// The resource group to label the workers with. An empty string moves them back to
+        /// the default (unlabeled) group.
+        #[clap(long, default_value = "")]
+        resource_group: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum SourceCommands {
+    /// List the split assignment of a source, per fragment and actor
+    ListSplits {
+        /// Id of the source to operate on
+        source_id: u32,
+    },
+    /// Show the lag of a source, comparing the assigned offsets with the latest offsets
+    /// available upstream. Only supported for kafka sources
+    ShowLag {
+        /// Id of the source to operate on
+        source_id: u32,
+    },
+    /// Pause a single source without pausing the rest of the stream graph
+    Pause {
+        /// Id of the source to operate on
+        source_id: u32,
+    },
+    /// Resume a source paused by `source pause`
+    Resume {
+        /// Id of the source to operate on
+        source_id: u32,
+    },
 }
 
 #[derive(Subcommand)]
@@ -274,8 +450,37 @@ enum MetaCommands {
     Pause,
     /// resume the stream graph
     Resume,
+    /// pause a single streaming job (table/MV/sink) without pausing the stream graph
+    PauseJob {
+        /// Id of the table backing the streaming job
+        table_id: u32,
+    },
+    /// resume a streaming job paused by `meta pause-job`
+    ResumeJob {
+        /// Id of the table backing the streaming job
+        table_id: u32,
+    },
+    /// resume a streaming job quarantined after repeated actor failures
+    ResumeQuarantinedJob {
+        /// Id of the table backing the streaming job
+        table_id: u32,
+    },
     /// get cluster info
     ClusterInfo,
+    /// export the streaming fragment graph
+    Graph {
+        /// output format: `dot` or `json`
+        #[clap(long, default_value = "dot")]
+        format: String,
+    },
+    /// show the per-fragment latency breakdown of recent barriers
+    BarrierLatency {
+        /// maximum number of barriers to show, latest first. 0 means all recorded
+        #[clap(long, default_value_t = 20)]
+        limit: u32,
+    },
+    /// list the recoveries recorded in the in-memory recovery log on the meta node
+    ListRecoveries,
     /// get source split info
     SourceSplitInfo,
     /// Reschedule the parallel unit in the stream graph
@@ -308,8 +513,57 @@ enum MetaCommands {
         #[clap(long, default_value = "false")]
         dry_run: bool,
     },
+    /// Apply a declarative catalog file (YAML) by diffing it against the live
+    /// catalog and running the DDL needed to converge
+    ///
+    /// The file is a list of objects, each with a `kind` (user, source, table,
+    /// materialized_view or sink), a `name` and the `sql` statement that
+    /// creates it. Objects of a kind that appears in the file but are absent
+    /// from it are dropped; objects whose definition changed are recreated.
+    #[clap(verbatim_doc_comment)]
+    Apply {
+        /// The declarative catalog file
+        #[clap(long, value_hint = clap::ValueHint::AnyPath)]
+        file: String,
+
+        /// The frontend endpoint to execute DDL against
+        #[clap(long, default_value = "localhost:4566")]
+        endpoint: String,
+
+        /// The database user
+        #[clap(long, default_value = "root")]
+        user: String,
+
+        /// The database name
+        #[clap(long, default_value = "dev")]
+        database: String,
+
+        /// Automatic yes to prompts
+        #[clap(short = 'y', long, default_value_t = false)]
+        yes: bool,
+    },
+
     /// backup meta by taking a meta snapshot
     BackupMeta,
+    /// list meta snapshots in the backup storage with epoch, timestamp and size
+    ListMetaSnapshots,
+    /// restore meta from a meta snapshot
+    ///
+    /// This connects to the meta store and backup storage directly, bypassing
+    /// any running meta node. Only run it against a stopped cluster whose meta
+    /// store is empty.
+    #[clap(verbatim_doc_comment)]
+    RestoreMeta {
+        #[clap(flatten)]
+        opts: RestoreOpts,
+        /// Automatic yes to prompts
+        #[clap(short = 'y', long, default_value_t = false)]
+        yes: bool,
+    },
+    /// show the disaster recovery replication status
+    DrStatus,
+    /// promote a disaster recovery standby cluster to primary
+    DrPromote,
     /// delete meta snapshots
     DeleteMetaSnapshots { snapshot_ids: Vec<u64> },
 
@@ -341,6 +595,7 @@ enum MetaCommands {
 }
 
 pub async fn start(opts: CliOpts) -> Result<()> {
+    common::RisectlConfig::load()?.apply_profile(opts.profile.as_deref())?;
     let context = CtlContext::default();
     let result = start_impl(opts, &context).await;
     context.try_close().await;
@@ -411,6 +666,12 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             max_sub_compaction,
             level0_stop_write_threshold_sub_level_number,
             level0_sub_level_compact_level_count,
+            sst_encryption_key_id,
+            compression_algorithm_level,
+            filter_algorithm,
+            filter_bits_per_key,
+            compaction_priority,
+            max_concurrent_task_number,
         }) => {
             cmd_impl::hummock::update_compaction_config(
                 context,
@@ -426,7 +687,13 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
                     max_sub_compaction,
                     level0_stop_write_threshold_sub_level_number,
                     level0_sub_level_compact_level_count,
-                ),
+                    sst_encryption_key_id,
+                    compression_algorithm_level,
+                    filter_algorithm,
+                    filter_bits_per_key,
+                    compaction_priority,
+                    max_concurrent_task_number,
+                )?,
             )
             .await?
         }
@@ -449,6 +716,49 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Hummock(HummockCommands::ListCompactionStatus { verbose }) => {
             cmd_impl::hummock::list_compaction_status(context, verbose).await?;
         }
+        Commands::Hummock(HummockCommands::TaskHistory) => {
+            cmd_impl::hummock::list_compact_task_history(context).await?;
+        }
+        Commands::Hummock(HummockCommands::Backup {
+            meta_snapshot_id,
+            target_url,
+            target_dir,
+        }) => {
+            cmd_impl::hummock::backup_hummock(context, meta_snapshot_id, target_url, target_dir)
+                .await?;
+        }
+        Commands::Hummock(HummockCommands::Restore {
+            source_url,
+            source_dir,
+            target_url,
+            target_dir,
+        }) => {
+            cmd_impl::hummock::restore_hummock(source_url, source_dir, target_url, target_dir)
+                .await?;
+        }
+        Commands::Hummock(HummockCommands::Verify {
+            object_id,
+            repair_meta,
+            quarantine_dir,
+            data_dir,
+        }) => {
+            cmd_impl::hummock::verify_objects(
+                context,
+                object_id,
+                repair_meta,
+                quarantine_dir,
+                data_dir,
+            )
+            .await?;
+        }
+        Commands::Hummock(HummockCommands::ListOrphans {
+            sst_retention_time_sec,
+            output,
+            data_dir,
+        }) => {
+            cmd_impl::hummock::list_orphans(context, sst_retention_time_sec, output, data_dir)
+                .await?;
+        }
         Commands::Table(TableCommands::Scan { mv_name, data_dir }) => {
             cmd_impl::table::scan(context, mv_name, data_dir).await?
         }
@@ -456,10 +766,30 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::table::scan_id(context, table_id, data_dir).await?
         }
         Commands::Table(TableCommands::List) => cmd_impl::table::list(context).await?,
+        Commands::Table(TableCommands::Stats) => cmd_impl::table::stats(context).await?,
         Commands::Bench(cmd) => cmd_impl::bench::do_bench(context, cmd).await?,
+        Commands::Debug(cmd) => cmd_impl::debug::do_debug(context, cmd).await?,
         Commands::Meta(MetaCommands::Pause) => cmd_impl::meta::pause(context).await?,
         Commands::Meta(MetaCommands::Resume) => cmd_impl::meta::resume(context).await?,
+        Commands::Meta(MetaCommands::PauseJob { table_id }) => {
+            cmd_impl::meta::pause_job(context, table_id).await?
+        }
+        Commands::Meta(MetaCommands::ResumeJob { table_id }) => {
+            cmd_impl::meta::resume_job(context, table_id).await?
+        }
+        Commands::Meta(MetaCommands::ResumeQuarantinedJob { table_id }) => {
+            cmd_impl::meta::resume_quarantined_job(context, table_id).await?
+        }
         Commands::Meta(MetaCommands::ClusterInfo) => cmd_impl::meta::cluster_info(context).await?,
+        Commands::Meta(MetaCommands::Graph { format }) => {
+            cmd_impl::meta::graph(context, format).await?
+        }
+        Commands::Meta(MetaCommands::BarrierLatency { limit }) => {
+            cmd_impl::meta::barrier_latency(context, limit).await?
+        }
+        Commands::Meta(MetaCommands::ListRecoveries) => {
+            cmd_impl::meta::list_recoveries(context).await?
+        }
         Commands::Meta(MetaCommands::SourceSplitInfo) => {
             cmd_impl::meta::source_split_info(context).await?
         }
@@ -469,7 +799,22 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             plan,
             revision,
         }) => cmd_impl::meta::reschedule(context, plan, revision, from, dry_run).await?,
+        Commands::Meta(MetaCommands::Apply {
+            file,
+            endpoint,
+            user,
+            database,
+            yes,
+        }) => cmd_impl::meta::apply_catalog(endpoint, user, database, file, yes).await?,
         Commands::Meta(MetaCommands::BackupMeta) => cmd_impl::meta::backup_meta(context).await?,
+        Commands::Meta(MetaCommands::ListMetaSnapshots) => {
+            cmd_impl::meta::list_meta_snapshots(context).await?
+        }
+        Commands::Meta(MetaCommands::RestoreMeta { opts, yes }) => {
+            cmd_impl::meta::restore_meta(opts, yes).await?
+        }
+        Commands::Meta(MetaCommands::DrStatus) => cmd_impl::meta::dr_status(context).await?,
+        Commands::Meta(MetaCommands::DrPromote) => cmd_impl::meta::dr_promote(context).await?,
         Commands::Meta(MetaCommands::DeleteMetaSnapshots { snapshot_ids }) => {
             cmd_impl::meta::delete_meta_snapshots(context, &snapshot_ids).await?
         }
@@ -484,8 +829,32 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             yes,
             ignore_not_found,
         }) => cmd_impl::meta::unregister_workers(context, workers, yes, ignore_not_found).await?,
-        Commands::Trace => cmd_impl::trace::trace(context).await?,
-        Commands::Profile { sleep } => cmd_impl::profile::profile(context, sleep).await?,
+        Commands::Trace {
+            actor_ids,
+            fragment_ids,
+            watch,
+            output_dir,
+        } => cmd_impl::trace::trace(context, actor_ids, fragment_ids, watch, output_dir).await?,
+        Commands::Profile(cmd) => cmd_impl::profile::do_profile(context, cmd).await?,
+        Commands::Config(ConfigCommands::UseContext { name }) => {
+            common::RisectlConfig::use_profile(name)?
+        }
+        Commands::Config(ConfigCommands::CurrentContext) => {
+            common::RisectlConfig::current_profile()?
+        }
+        Commands::Config(ConfigCommands::GetContexts) => common::RisectlConfig::list_profiles()?,
+        Commands::Source(SourceCommands::ListSplits { source_id }) => {
+            cmd_impl::source::list_splits(context, source_id).await?
+        }
+        Commands::Source(SourceCommands::ShowLag { source_id }) => {
+            cmd_impl::source::show_lag(context, source_id).await?
+        }
+        Commands::Source(SourceCommands::Pause { source_id }) => {
+            cmd_impl::source::pause(context, source_id).await?
+        }
+        Commands::Source(SourceCommands::Resume { source_id }) => {
+            cmd_impl::source::resume(context, source_id).await?
+        }
         Commands::Scale(ScaleCommands::Resize(resize)) => {
             cmd_impl::scale::resize(context, resize).await?
         }
@@ -493,10 +862,17 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::scale::update_schedulability(context, workers, Schedulability::Unschedulable)
                 .await?
         }
+        Commands::Scale(ScaleCommands::Drain { workers, yes }) => {
+            cmd_impl::scale::drain(context, workers, yes).await?
+        }
         Commands::Scale(ScaleCommands::Uncordon { workers }) => {
             cmd_impl::scale::update_schedulability(context, workers, Schedulability::Schedulable)
                 .await?
         }
+        Commands::Scale(ScaleCommands::SetResourceGroup {
+            workers,
+            resource_group,
+        }) => cmd_impl::scale::update_resource_group(context, workers, resource_group).await?,
     }
     Ok(())
 }
@@ -0,0 +1,311 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::*;
+use risingwave_common::estimate_size::{EstimateSize, ZeroHeapSize};
+use risingwave_common::types::*;
+use risingwave_expr_macro::build_aggregate;
+
+use super::Aggregator;
+use crate::agg::AggCall;
+use crate::Result;
+
+/// Default compression factor of the t-digest. A larger factor keeps more centroids and thus
+/// gives a more accurate estimate, at the cost of more memory and state.
+const DEFAULT_COMPRESSION: f64 = 100.0;
+
+/// A centroid of the t-digest, summarizing `weight` input values around `mean`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Centroid {
+    pub mean: f64,
+    pub weight: f64,
+}
+
+impl ZeroHeapSize for Centroid {}
+
+/// A [t-digest](https://arxiv.org/abs/1902.04023) sketch for estimating quantiles over a data
+/// stream. Values are kept as singleton centroids and merged into larger clusters once the
+/// number of centroids exceeds the compression budget, with clusters near the tails kept
+/// smaller to preserve accuracy of extreme percentiles.
+#[derive(Clone, Debug, EstimateSize)]
+pub struct TDigest {
+    compression: f64,
+    /// Centroids sorted by mean.
+    centroids: Vec<Centroid>,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: Vec::new(),
+        }
+    }
+
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    pub fn centroids(&self) -> &[Centroid] {
+        &self.centroids
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum()
+    }
+
+    /// Rebuild the digest from centroids that are already sorted by mean.
+    pub fn from_sorted_centroids(compression: f64, centroids: Vec<Centroid>) -> Self {
+        debug_assert!(centroids.windows(2).all(|w| w[0].mean <= w[1].mean));
+        Self {
+            compression,
+            centroids,
+        }
+    }
+
+    /// Insert a value into the digest as a singleton centroid.
+    pub fn insert(&mut self, value: f64) {
+        let idx = self.centroids.partition_point(|c| c.mean < value);
+        self.centroids.insert(
+            idx,
+            Centroid {
+                mean: value,
+                weight: 1.0,
+            },
+        );
+        if self.centroids.len() > (6.0 * self.compression) as usize {
+            self.compress();
+        }
+    }
+
+    /// Remove a value from the digest by decrementing the weight of the centroid whose mean is
+    /// closest to it. This is approximate: the removed value may have been merged into a
+    /// neighboring cluster. Removing from an empty digest is a no-op.
+    pub fn delete(&mut self, value: f64) {
+        if self.centroids.is_empty() {
+            return;
+        }
+        let idx = self.centroids.partition_point(|c| c.mean < value);
+        // The closest centroid is either the first one not less than `value` or its predecessor.
+        let idx = if idx == self.centroids.len() {
+            idx - 1
+        } else if idx > 0
+            && (value - self.centroids[idx - 1].mean) < (self.centroids[idx].mean - value)
+        {
+            idx - 1
+        } else {
+            idx
+        };
+        self.centroids[idx].weight -= 1.0;
+        if self.centroids[idx].weight <= 0.0 {
+            self.centroids.remove(idx);
+        }
+    }
+
+    /// Merge adjacent centroids so that each cluster respects the size bound
+    /// `4 * n * q * (1 - q) / compression`, where `q` is the quantile at the cluster midpoint.
+    pub fn compress(&mut self) {
+        if self.centroids.len() <= 1 {
+            return;
+        }
+        let total = self.total_weight();
+        let mut compressed = Vec::with_capacity(self.centroids.len());
+        let mut iter = self.centroids.drain(..);
+        let mut current = iter.next().unwrap();
+        let mut cum = 0.0;
+        for c in iter {
+            let proposed = current.weight + c.weight;
+            let q = (cum + proposed / 2.0) / total;
+            let limit = 4.0 * total * q * (1.0 - q) / self.compression;
+            if proposed <= limit {
+                current.mean = (current.mean * current.weight + c.mean * c.weight) / proposed;
+                current.weight = proposed;
+            } else {
+                cum += current.weight;
+                compressed.push(current);
+                current = c;
+            }
+        }
+        compressed.push(current);
+        self.centroids = compressed;
+    }
+
+    /// Estimate the value at the given quantile (in `0..=1`), interpolating linearly between
+    /// the midpoints of adjacent centroids. Returns `None` if the digest is empty.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.centroids.is_empty() {
+            return None;
+        }
+        let total = self.total_weight();
+        let target = q * total;
+        let mut cum = 0.0;
+        let mut prev: Option<(f64, f64)> = None; // (midpoint, mean)
+        for c in &self.centroids {
+            let mid = cum + c.weight / 2.0;
+            if target <= mid {
+                return Some(match prev {
+                    Some((prev_mid, prev_mean)) if mid > prev_mid => {
+                        prev_mean + (c.mean - prev_mean) * (target - prev_mid) / (mid - prev_mid)
+                    }
+                    _ => c.mean,
+                });
+            }
+            cum += c.weight;
+            prev = Some((mid, c.mean));
+        }
+        Some(self.centroids.last().unwrap().mean)
+    }
+}
+
+/// Computes an approximate percentile using a t-digest sketch. Unlike `percentile_cont`, the
+/// fraction is given as the second argument and no `WITHIN GROUP` clause is needed, so it can
+/// also be used in streaming queries.
+///
+/// ```slt
+/// statement ok
+/// create table t(x int);
+///
+/// statement ok
+/// insert into t values(1),(2),(3);
+///
+/// query R
+/// select approx_percentile(x, 0.5) from t;
+/// ----
+/// 2
+///
+/// query R
+/// select approx_percentile(x, 0.99) from t;
+/// ----
+/// 3
+///
+/// query R
+/// select approx_percentile(x, NULL) from t;
+/// ----
+/// NULL
+///
+/// statement ok
+/// drop table t;
+/// ```
+#[build_aggregate("approx_percentile(float64) -> float64")]
+fn build(agg: AggCall) -> Result<Box<dyn Aggregator>> {
+    let fraction: Option<f64> = agg.direct_args[0]
+        .literal()
+        .map(|x| (*x.as_float64()).into());
+    Ok(Box::new(ApproxPercentile::new(fraction)))
+}
+
+#[derive(Clone, EstimateSize)]
+pub struct ApproxPercentile {
+    fraction: Option<f64>,
+    digest: TDigest,
+}
+
+impl ApproxPercentile {
+    pub fn new(fraction: Option<f64>) -> Self {
+        Self {
+            fraction,
+            digest: TDigest::default(),
+        }
+    }
+
+    fn add_datum(&mut self, datum_ref: DatumRef<'_>) {
+        if let Some(datum) = datum_ref.to_owned_datum() {
+            self.digest.insert((*datum.as_float64()).into());
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Aggregator for ApproxPercentile {
+    fn return_type(&self) -> DataType {
+        DataType::Float64
+    }
+
+    async fn update_multi(
+        &mut self,
+        input: &DataChunk,
+        start_row_id: usize,
+        end_row_id: usize,
+    ) -> Result<()> {
+        let array = input.column_at(0);
+        for row_id in start_row_id..end_row_id {
+            self.add_datum(array.value_at(row_id));
+        }
+        Ok(())
+    }
+
+    fn output(&mut self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        let result = self
+            .fraction
+            .and_then(|fraction| self.digest.quantile(fraction));
+        builder.append(result.map(|x| ScalarImpl::Float64(x.into())));
+        Ok(())
+    }
+
+    fn estimated_size(&self) -> usize {
+        EstimateSize::estimated_size(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantile_small() {
+        let mut digest = TDigest::default();
+        for v in 1..=5 {
+            digest.insert(v as f64);
+        }
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+        assert_eq!(TDigest::default().quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_compression_accuracy() {
+        let mut digest = TDigest::default();
+        for v in 0..10000 {
+            digest.insert(v as f64);
+        }
+        assert!((6.0 * DEFAULT_COMPRESSION) as usize >= digest.centroids().len());
+        for (q, expected) in [(0.01, 100.0), (0.5, 5000.0), (0.99, 9900.0)] {
+            let estimate = digest.quantile(q).unwrap();
+            assert!(
+                (estimate - expected).abs() / 10000.0 < 0.01,
+                "quantile {q}: estimated {estimate}, expected around {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut digest = TDigest::default();
+        for v in 1..=3 {
+            digest.insert(v as f64);
+        }
+        digest.delete(3.0);
+        assert_eq!(digest.quantile(1.0), Some(2.0));
+        digest.delete(2.0);
+        digest.delete(1.0);
+        assert_eq!(digest.quantile(0.5), None);
+    }
+}
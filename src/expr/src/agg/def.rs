@@ -224,6 +224,7 @@ pub enum AggKind {
     Avg,
     StringAgg,
     ApproxCountDistinct,
+    ApproxPercentile,
     ArrayAgg,
     JsonbAgg,
     JsonbObjectAgg,
@@ -253,6 +254,7 @@ impl AggKind {
             PbType::Count => Ok(AggKind::Count),
             PbType::StringAgg => Ok(AggKind::StringAgg),
             PbType::ApproxCountDistinct => Ok(AggKind::ApproxCountDistinct),
+            PbType::ApproxPercentile => Ok(AggKind::ApproxPercentile),
             PbType::ArrayAgg => Ok(AggKind::ArrayAgg),
             PbType::JsonbAgg => Ok(AggKind::JsonbAgg),
             PbType::JsonbObjectAgg => Ok(AggKind::JsonbObjectAgg),
@@ -283,6 +285,7 @@ impl AggKind {
             Self::Count => PbType::Count,
             Self::StringAgg => PbType::StringAgg,
             Self::ApproxCountDistinct => PbType::ApproxCountDistinct,
+            Self::ApproxPercentile => PbType::ApproxPercentile,
             Self::ArrayAgg => PbType::ArrayAgg,
             Self::JsonbAgg => PbType::JsonbAgg,
             Self::JsonbObjectAgg => PbType::JsonbObjectAgg,
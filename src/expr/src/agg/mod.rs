@@ -24,6 +24,7 @@ mod def;
 
 // concrete aggregators
 mod approx_count_distinct;
+mod approx_percentile;
 mod array_agg;
 mod count_star;
 mod general;
@@ -40,6 +41,7 @@ mod filter;
 mod orderby;
 mod projection;
 
+pub use self::approx_percentile::{Centroid, TDigest};
 pub use self::def::*;
 use self::distinct::Distinct;
 use self::filter::*;
@@ -25,8 +25,9 @@ use super::expr_coalesce::CoalesceExpression;
 use super::expr_concat_ws::ConcatWsExpression;
 use super::expr_field::FieldExpression;
 use super::expr_in::InExpression;
+use super::expr_jsonb_build::JsonbBuildExpression;
 use super::expr_nested_construct::NestedConstructExpression;
-use super::expr_regexp::RegexpMatchExpression;
+use super::expr_regexp::{RegexpCountExpression, RegexpMatchExpression, RegexpReplaceExpression};
 use super::expr_some_all::SomeAllExpression;
 use super::expr_udf::UdfExpression;
 use super::expr_vnode::VnodeExpression;
@@ -62,7 +63,12 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         E::Field => FieldExpression::try_from_boxed(prost),
         E::Array => NestedConstructExpression::try_from_boxed(prost),
         E::Row => NestedConstructExpression::try_from_boxed(prost),
+        E::ToJsonb | E::JsonbBuildArray | E::JsonbBuildObject => {
+            JsonbBuildExpression::try_from_boxed(prost)
+        }
         E::RegexpMatch => RegexpMatchExpression::try_from_boxed(prost),
+        E::RegexpReplace => RegexpReplaceExpression::try_from_boxed(prost),
+        E::RegexpCount => RegexpCountExpression::try_from_boxed(prost),
         E::ArrayCat | E::ArrayAppend | E::ArrayPrepend => {
             // Now we implement these three functions as a single expression for the
             // sake of simplicity. If performance matters at some time, we can split
@@ -0,0 +1,338 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayBuilder, ArrayRef, DataChunk, JsonbArrayBuilder};
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::{DataType, Datum, DatumRef, JsonbVal, Scalar, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+use serde_json::{Map, Number, Value};
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::{bail, ensure, ExprError, Result};
+
+/// Constructs `jsonb` values from arbitrary typed arguments, following PostgreSQL semantics:
+/// * `to_jsonb(anyelement)` converts a single value, including nested structs and lists.
+/// * `jsonb_build_array(variadic any)` builds a jsonb array out of the arguments.
+/// * `jsonb_build_object(variadic any)` builds a jsonb object out of alternating key/value
+///   arguments.
+///
+/// These cannot be expressed as fixed-signature functions because they accept any number of
+/// arguments of any type, hence the dedicated expression.
+#[derive(Debug)]
+pub struct JsonbBuildExpression {
+    func_type: Type,
+    children: Vec<BoxedExpression>,
+}
+
+#[async_trait::async_trait]
+impl Expression for JsonbBuildExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Jsonb
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut columns = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            columns.push(child.eval_checked(input).await?);
+        }
+        let types = self
+            .children
+            .iter()
+            .map(|child| child.return_type())
+            .collect::<Vec<_>>();
+
+        let row_len = input.capacity();
+        let vis = input.vis();
+        let mut builder = JsonbArrayBuilder::new(row_len);
+        let mut datums = Vec::with_capacity(self.children.len());
+        for row_idx in 0..row_len {
+            if !vis.is_set(row_idx) {
+                builder.append(None);
+                continue;
+            }
+            datums.clear();
+            datums.extend(columns.iter().map(|column| column.value_at(row_idx)));
+            let value = JsonbVal::from(self.build_value(&datums, &types)?);
+            builder.append(Some(value.as_scalar_ref()));
+        }
+        Ok(Arc::new(builder.finish().into()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let mut owned_datums = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            owned_datums.push(child.eval_row(input).await?);
+        }
+        let datums = owned_datums
+            .iter()
+            .map(|d| d.as_ref().map(|s| s.as_scalar_ref_impl()))
+            .collect::<Vec<_>>();
+        let types = self
+            .children
+            .iter()
+            .map(|child| child.return_type())
+            .collect::<Vec<_>>();
+        let value = JsonbVal::from(self.build_value(&datums, &types)?);
+        Ok(Some(value.to_scalar_value()))
+    }
+}
+
+impl JsonbBuildExpression {
+    pub fn new(func_type: Type, children: Vec<BoxedExpression>) -> Self {
+        JsonbBuildExpression {
+            func_type,
+            children,
+        }
+    }
+
+    /// Builds the jsonb value of one row out of the evaluated arguments.
+    fn build_value(&self, datums: &[DatumRef<'_>], types: &[DataType]) -> Result<Value> {
+        match self.func_type {
+            Type::ToJsonb => datum_to_jsonb(datums[0], &types[0]),
+            Type::JsonbBuildArray => Ok(Value::Array(
+                datums
+                    .iter()
+                    .zip_eq_fast(types)
+                    .map(|(datum, ty)| datum_to_jsonb(*datum, ty))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            Type::JsonbBuildObject => {
+                let mut map = Map::with_capacity(datums.len() / 2);
+                for (i, (pair, types)) in datums.chunks(2).zip_eq_fast(types.chunks(2)).enumerate()
+                {
+                    let Some(key) = pair[0] else {
+                        return Err(ExprError::InvalidParam {
+                            name: "jsonb_build_object",
+                            reason: format!("argument {}: key must not be null", 2 * i + 1),
+                        });
+                    };
+                    map.insert(
+                        jsonb_object_key(key, &types[0])?,
+                        datum_to_jsonb(pair[1], &types[1])?,
+                    );
+                }
+                Ok(Value::Object(map))
+            }
+            _ => unreachable!("checked in `try_from`"),
+        }
+    }
+}
+
+/// Converts one key argument of `jsonb_build_object` into an object key, using the text output
+/// of its type like PostgreSQL does.
+fn jsonb_object_key(scalar: ScalarRefImpl<'_>, ty: &DataType) -> Result<String> {
+    match ty {
+        DataType::Struct(_) | DataType::List(_) | DataType::Jsonb => Err(ExprError::InvalidParam {
+            name: "jsonb_build_object",
+            reason: "key value must be scalar, not array, composite, or json".into(),
+        }),
+        DataType::Varchar => Ok(scalar.into_utf8().to_string()),
+        _ => Ok(scalar.to_text_with_type(ty)),
+    }
+}
+
+/// Converts a datum into a [`Value`], following the PostgreSQL conversion of `to_jsonb`:
+/// booleans and numbers map to the corresponding json types, structs map to objects, lists map
+/// to arrays, `jsonb` is taken as is, and everything else uses its text output.
+pub(super) fn datum_to_jsonb(datum: DatumRef<'_>, ty: &DataType) -> Result<Value> {
+    let Some(scalar) = datum else {
+        return Ok(Value::Null);
+    };
+    Ok(match (ty, scalar) {
+        (DataType::Boolean, ScalarRefImpl::Bool(v)) => Value::Bool(v),
+        (DataType::Int16, ScalarRefImpl::Int16(v)) => Value::from(v),
+        (DataType::Int32, ScalarRefImpl::Int32(v)) => Value::from(v),
+        (DataType::Int64, ScalarRefImpl::Int64(v)) => Value::from(v),
+        (DataType::Serial, ScalarRefImpl::Serial(v)) => Value::from(v.into_inner()),
+        (DataType::Float32, ScalarRefImpl::Float32(v)) => float_to_jsonb(v.0 as f64),
+        (DataType::Float64, ScalarRefImpl::Float64(v)) => float_to_jsonb(v.0),
+        // `serde_json` numbers are backed by `f64`, so non-finite or overly large values fall
+        // back to their text form, like the float cases above.
+        (DataType::Decimal | DataType::Int256, scalar) => {
+            let text = scalar.to_text_with_type(ty);
+            match text.parse::<Value>() {
+                Ok(number @ Value::Number(_)) => number,
+                _ => Value::String(text),
+            }
+        }
+        (DataType::Varchar, ScalarRefImpl::Utf8(v)) => Value::String(v.to_string()),
+        (DataType::Jsonb, ScalarRefImpl::Jsonb(v)) => v.to_owned_scalar().take(),
+        (DataType::List(elem_type), ScalarRefImpl::List(v)) => Value::Array(
+            v.iter()
+                .map(|datum| datum_to_jsonb(datum, elem_type))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        (DataType::Struct(struct_type), ScalarRefImpl::Struct(v)) => {
+            let field_names = struct_type.names().collect::<Vec<_>>();
+            let mut map = Map::with_capacity(struct_type.len());
+            for (i, (datum, field_type)) in v
+                .iter_fields_ref()
+                .zip_eq_fast(struct_type.types())
+                .enumerate()
+            {
+                // An unnamed struct (e.g. `ROW(1, 2)`) gets `f1`, `f2`, ... as keys, following
+                // PostgreSQL's treatment of anonymous record fields.
+                let key = match field_names.get(i) {
+                    Some(name) => name.to_string(),
+                    None => format!("f{}", i + 1),
+                };
+                map.insert(key, datum_to_jsonb(datum, field_type)?);
+            }
+            Value::Object(map)
+        }
+        // Date, time, timestamp, interval, bytea, etc. use their text output. PostgreSQL also
+        // quotes these types as json strings.
+        (ty, scalar) => Value::String(scalar.to_text_with_type(ty)),
+    })
+}
+
+fn float_to_jsonb(v: f64) -> Value {
+    match Number::from_f64(v) {
+        Some(number) => Value::Number(number),
+        // `NaN` and `Infinity` are not valid json numbers, so they become strings like in
+        // PostgreSQL.
+        None => Value::String(ScalarRefImpl::Float64(v.into()).to_text()),
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for JsonbBuildExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let func_type = prost.get_function_type().unwrap();
+        ensure!(
+            [Type::ToJsonb, Type::JsonbBuildArray, Type::JsonbBuildObject].contains(&func_type)
+        );
+
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let children = func_call_node
+            .children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        if func_type == Type::ToJsonb {
+            ensure!(children.len() == 1);
+        }
+        if func_type == Type::JsonbBuildObject && children.len() % 2 != 0 {
+            return Err(ExprError::InvalidParam {
+                name: "jsonb_build_object",
+                reason: "argument list must have even number of elements".into(),
+            });
+        }
+        Ok(JsonbBuildExpression::new(func_type, children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunk, DataChunkTestExt};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_pb::expr::expr_node::Type;
+
+    use super::JsonbBuildExpression;
+    use crate::expr::{Expression, InputRefExpression, LiteralExpression};
+
+    #[tokio::test]
+    async fn test_to_jsonb() {
+        let expr = JsonbBuildExpression::new(
+            Type::ToJsonb,
+            vec![Box::new(InputRefExpression::new(DataType::Int32, 0))],
+        );
+        let chunk = DataChunk::from_pretty(
+            "i
+             1
+             .
+             42",
+        );
+        let output = expr.eval(&chunk).await.unwrap();
+        let expected = ["1", "null", "42"];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(
+                output.value_at(i).unwrap().into_jsonb().to_string(),
+                *expected
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jsonb_build_array() {
+        let expr = JsonbBuildExpression::new(
+            Type::JsonbBuildArray,
+            vec![
+                Box::new(LiteralExpression::new(
+                    DataType::Int32,
+                    Some(ScalarImpl::Int32(1)),
+                )),
+                Box::new(LiteralExpression::new(
+                    DataType::Varchar,
+                    Some(ScalarImpl::Utf8("a".into())),
+                )),
+                Box::new(LiteralExpression::new(DataType::Boolean, None)),
+            ],
+        );
+        let result = expr
+            .eval_row(&risingwave_common::row::OwnedRow::new(vec![]))
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().as_jsonb().to_string(), r#"[1, "a", null]"#);
+    }
+
+    #[tokio::test]
+    async fn test_jsonb_build_object() {
+        let expr = JsonbBuildExpression::new(
+            Type::JsonbBuildObject,
+            vec![
+                Box::new(LiteralExpression::new(
+                    DataType::Varchar,
+                    Some(ScalarImpl::Utf8("count".into())),
+                )),
+                Box::new(LiteralExpression::new(
+                    DataType::Int64,
+                    Some(ScalarImpl::Int64(5)),
+                )),
+            ],
+        );
+        let result = expr
+            .eval_row(&risingwave_common::row::OwnedRow::new(vec![]))
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().as_jsonb().to_string(), r#"{"count": 5}"#);
+    }
+
+    #[tokio::test]
+    async fn test_jsonb_build_object_null_key() {
+        let expr = JsonbBuildExpression::new(
+            Type::JsonbBuildObject,
+            vec![
+                Box::new(LiteralExpression::new(DataType::Varchar, None)),
+                Box::new(LiteralExpression::new(
+                    DataType::Int64,
+                    Some(ScalarImpl::Int64(5)),
+                )),
+            ],
+        );
+        let result = expr
+            .eval_row(&risingwave_common::row::OwnedRow::new(vec![]))
+            .await;
+        assert!(result.is_err());
+    }
+}
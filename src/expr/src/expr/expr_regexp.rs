@@ -18,7 +18,8 @@ use std::sync::Arc;
 use itertools::Itertools;
 use regex::{Regex, RegexBuilder};
 use risingwave_common::array::{
-    Array, ArrayBuilder, ArrayRef, DataChunk, ListArrayBuilder, ListRef, ListValue, Utf8Array,
+    Array, ArrayBuilder, ArrayRef, DataChunk, I32Array, I32ArrayBuilder, ListArrayBuilder, ListRef,
+    ListValue, Utf8Array, Utf8ArrayBuilder,
 };
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::{DataType, Datum, ScalarImpl};
@@ -31,7 +32,7 @@ use super::{build_from_prost as expr_build_from_prost, Expression};
 use crate::{bail, ensure, ExprError, Result};
 
 #[derive(Debug)]
-pub struct RegexpContext(pub Regex);
+pub struct RegexpContext(pub Regex, pub RegexpOptions);
 
 impl RegexpContext {
     pub fn new(pattern: &str, flags: &str) -> Result<Self> {
@@ -40,6 +41,7 @@ impl RegexpContext {
             RegexBuilder::new(pattern)
                 .case_insensitive(options.case_insensitive)
                 .build()?,
+            options,
         ))
     }
 
@@ -68,9 +70,12 @@ impl RegexpContext {
 }
 
 /// <https://www.postgresql.org/docs/current/functions-matching.html#POSIX-EMBEDDED-OPTIONS-TABLE>
-struct RegexpOptions {
+#[derive(Debug)]
+pub struct RegexpOptions {
     /// `c` and `i`
-    case_insensitive: bool,
+    pub case_insensitive: bool,
+    /// `g`, only meaningful for `regexp_replace`
+    pub global: bool,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -78,6 +83,7 @@ impl Default for RegexpOptions {
     fn default() -> Self {
         Self {
             case_insensitive: false,
+            global: false,
         }
     }
 }
@@ -91,7 +97,7 @@ impl FromStr for RegexpOptions {
             match c {
                 'c' => opts.case_insensitive = false,
                 'i' => opts.case_insensitive = true,
-                'g' => {}
+                'g' => opts.global = true,
                 _ => {
                     bail!("invalid regular expression option: \"{c}\"");
                 }
@@ -101,6 +107,29 @@ impl FromStr for RegexpOptions {
     }
 }
 
+/// Extracts the constant string argument (pattern or flags) of a regexp function from the given
+/// expression node. Returns `None` for a `NULL` constant.
+fn get_const_str_arg(node: &ExprNode, what: &str, func: &str) -> Result<Option<String>> {
+    match &node.get_rex_node()? {
+        RexNode::Constant(value) => {
+            let datum = deserialize_datum(
+                value.get_body().as_slice(),
+                &DataType::from(node.get_return_type().unwrap()),
+            )
+            .map_err(|e| ExprError::Internal(e.into()))?;
+
+            match datum {
+                Some(ScalarImpl::Utf8(s)) => Ok(Some(s.to_string())),
+                None => Ok(None),
+                _ => bail!("Expected {what} to be an String"),
+            }
+        }
+        _ => Err(ExprError::UnsupportedFunction(format!(
+            "non-constant {what} in {func}"
+        ))),
+    }
+}
+
 #[derive(Debug)]
 pub struct RegexpMatchExpression {
     pub child: Box<dyn Expression>,
@@ -126,51 +155,17 @@ impl<'a> TryFrom<&'a ExprNode> for RegexpMatchExpression {
         let Some(pattern_node) = children.next() else {
             bail!("Expected argument pattern");
         };
-        let mut pattern = match &pattern_node.get_rex_node()? {
-            RexNode::Constant(pattern_value) => {
-                let pattern_datum = deserialize_datum(
-                    pattern_value.get_body().as_slice(),
-                    &DataType::from(pattern_node.get_return_type().unwrap()),
-                )
-                .map_err(|e| ExprError::Internal(e.into()))?;
-
-                match pattern_datum {
-                    Some(ScalarImpl::Utf8(pattern)) => pattern.to_string(),
-                    // NULL pattern
-                    None => NULL_PATTERN.to_string(),
-                    _ => bail!("Expected pattern to be an String"),
-                }
-            }
-            _ => {
-                return Err(ExprError::UnsupportedFunction(
-                    "non-constant pattern in regexp_match".to_string(),
-                ))
-            }
-        };
+        let mut pattern = get_const_str_arg(pattern_node, "pattern", "regexp_match")?
+            // NULL pattern
+            .unwrap_or_else(|| NULL_PATTERN.to_string());
 
         let flags = if let Some(flags_node) = children.next() {
-            match &flags_node.get_rex_node()? {
-                RexNode::Constant(flags_value) => {
-                    let flags_datum = deserialize_datum(
-                        flags_value.get_body().as_slice(),
-                        &DataType::from(flags_node.get_return_type().unwrap()),
-                    )
-                    .map_err(|e| ExprError::Internal(e.into()))?;
-
-                    match flags_datum {
-                        Some(ScalarImpl::Utf8(flags)) => flags.to_string(),
-                        // NULL flag
-                        None => {
-                            pattern = NULL_PATTERN.to_string();
-                            "".to_string()
-                        }
-                        _ => bail!("Expected flags to be an String"),
-                    }
-                }
-                _ => {
-                    return Err(ExprError::UnsupportedFunction(
-                        "non-constant flags in regexp_match".to_string(),
-                    ))
+            match get_const_str_arg(flags_node, "flags", "regexp_match")? {
+                Some(flags) => flags,
+                // NULL flag
+                None => {
+                    pattern = NULL_PATTERN.to_string();
+                    "".to_string()
                 }
             }
         } else {
@@ -248,3 +243,265 @@ impl Expression for RegexpMatchExpression {
         })
     }
 }
+
+#[derive(Debug)]
+pub struct RegexpReplaceExpression {
+    pub source: Box<dyn Expression>,
+    pub replacement: Box<dyn Expression>,
+    pub ctx: RegexpContext,
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpReplaceExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_function_type().unwrap() == Type::RegexpReplace);
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let mut children = func_call_node.children.iter();
+        let Some(source_node) = children.next() else {
+            bail!("Expected argument source");
+        };
+        let source = expr_build_from_prost(source_node)?;
+        let Some(pattern_node) = children.next() else {
+            bail!("Expected argument pattern");
+        };
+        let mut pattern = get_const_str_arg(pattern_node, "pattern", "regexp_replace")?
+            // NULL pattern
+            .unwrap_or_else(|| NULL_PATTERN.to_string());
+        let Some(replacement_node) = children.next() else {
+            bail!("Expected argument replacement");
+        };
+        let replacement = expr_build_from_prost(replacement_node)?;
+
+        let flags = if let Some(flags_node) = children.next() {
+            match get_const_str_arg(flags_node, "flags", "regexp_replace")? {
+                Some(flags) => flags,
+                // NULL flag
+                None => {
+                    pattern = NULL_PATTERN.to_string();
+                    "".to_string()
+                }
+            }
+        } else {
+            "".to_string()
+        };
+
+        let ctx = RegexpContext::new(&pattern, &flags)?;
+        Ok(Self {
+            source,
+            replacement,
+            ctx,
+        })
+    }
+}
+
+impl RegexpReplaceExpression {
+    /// Converts a PostgreSQL replacement string (`\1`..`\9` for capture groups, `\&` for the
+    /// whole match, `\\` for a literal backslash) into the `$`-based syntax expected by the
+    /// `regex` crate.
+    fn convert_replacement(replacement: &str) -> String {
+        let mut converted = String::with_capacity(replacement.len());
+        let mut chars = replacement.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                // A literal `$` would otherwise start a capture group reference.
+                '$' => converted.push_str("$$"),
+                '\\' => match chars.next() {
+                    Some('&') => converted.push_str("${0}"),
+                    Some(d @ '1'..='9') => {
+                        converted.push_str("${");
+                        converted.push(d);
+                        converted.push('}');
+                    }
+                    Some(e) => converted.push(e),
+                    None => converted.push('\\'),
+                },
+                _ => converted.push(c),
+            }
+        }
+        converted
+    }
+
+    /// Replace one row and return the result.
+    fn replace_one(&self, source: Option<&str>, replacement: Option<&str>) -> Option<String> {
+        let (Some(source), Some(replacement)) = (source, replacement) else {
+            return None;
+        };
+        let replacement = Self::convert_replacement(replacement);
+        let replaced = if self.ctx.1.global {
+            self.ctx.0.replace_all(source, replacement.as_str())
+        } else {
+            self.ctx.0.replace(source, replacement.as_str())
+        };
+        Some(replaced.into_owned())
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for RegexpReplaceExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let source_arr = self.source.eval_checked(input).await?;
+        let source_arr: &Utf8Array = source_arr.as_ref().into();
+        let replacement_arr = self.replacement.eval_checked(input).await?;
+        let replacement_arr: &Utf8Array = replacement_arr.as_ref().into();
+        let mut output = Utf8ArrayBuilder::new(input.capacity());
+
+        for ((source, replacement), vis) in source_arr
+            .iter()
+            .zip_eq_fast(replacement_arr.iter())
+            .zip_eq_fast(input.vis().iter())
+        {
+            if !vis {
+                output.append_null();
+            } else {
+                output.append(self.replace_one(source, replacement).as_deref());
+            }
+        }
+
+        Ok(Arc::new(output.finish().into()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let source = self.source.eval_row(input).await?;
+        let replacement = self.replacement.eval_row(input).await?;
+        Ok(match (&source, &replacement) {
+            (Some(ScalarImpl::Utf8(source)), Some(ScalarImpl::Utf8(replacement))) => {
+                self.replace_one(Some(source), Some(replacement))
+            }
+            _ => None,
+        }
+        .map(|s| ScalarImpl::Utf8(s.into())))
+    }
+}
+
+#[derive(Debug)]
+pub struct RegexpCountExpression {
+    pub source: Box<dyn Expression>,
+    /// The optional 1-based character position to start searching from.
+    pub start: Option<Box<dyn Expression>>,
+    pub ctx: RegexpContext,
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpCountExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_function_type().unwrap() == Type::RegexpCount);
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let mut children = func_call_node.children.iter();
+        let Some(source_node) = children.next() else {
+            bail!("Expected argument source");
+        };
+        let source = expr_build_from_prost(source_node)?;
+        let Some(pattern_node) = children.next() else {
+            bail!("Expected argument pattern");
+        };
+        let mut pattern = get_const_str_arg(pattern_node, "pattern", "regexp_count")?
+            // NULL pattern
+            .unwrap_or_else(|| NULL_PATTERN.to_string());
+
+        let start = children.next().map(expr_build_from_prost).transpose()?;
+
+        let flags = if let Some(flags_node) = children.next() {
+            match get_const_str_arg(flags_node, "flags", "regexp_count")? {
+                Some(flags) => flags,
+                // NULL flag
+                None => {
+                    pattern = NULL_PATTERN.to_string();
+                    "".to_string()
+                }
+            }
+        } else {
+            "".to_string()
+        };
+
+        let ctx = RegexpContext::new(&pattern, &flags)?;
+        Ok(Self { source, start, ctx })
+    }
+}
+
+impl RegexpCountExpression {
+    /// Count the matches in one row.
+    fn count_one(&self, source: &str, start: i32) -> Result<i32> {
+        if start < 1 {
+            return Err(ExprError::InvalidParam {
+                name: "start",
+                reason: format!("invalid value for parameter \"start\": {start}"),
+            });
+        }
+        // `start` is a 1-based character position.
+        let byte_offset = if start == 1 {
+            Some(0)
+        } else {
+            source
+                .char_indices()
+                .nth(start as usize - 1)
+                .map(|(offset, _)| offset)
+        };
+        Ok(match byte_offset {
+            Some(offset) => self.ctx.0.find_iter(&source[offset..]).count() as i32,
+            // Start beyond the end of the source, nothing to match.
+            None => 0,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Expression for RegexpCountExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Int32
+    }
+
+    async fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let source_arr = self.source.eval_checked(input).await?;
+        let source_arr: &Utf8Array = source_arr.as_ref().into();
+        let start_arr = match &self.start {
+            Some(start) => Some(start.eval_checked(input).await?),
+            None => None,
+        };
+        let start_arr: Option<&I32Array> = start_arr.as_ref().map(|arr| arr.as_ref().into());
+        let mut output = I32ArrayBuilder::new(input.capacity());
+
+        for (row, (source, vis)) in source_arr
+            .iter()
+            .zip_eq_fast(input.vis().iter())
+            .enumerate()
+        {
+            let start = match start_arr {
+                Some(arr) => arr.value_at(row),
+                None => Some(1),
+            };
+            match (vis, source, start) {
+                (true, Some(source), Some(start)) => {
+                    output.append(Some(self.count_one(source, start)?));
+                }
+                _ => output.append_null(),
+            }
+        }
+
+        Ok(Arc::new(output.finish().into()))
+    }
+
+    async fn eval_row(&self, input: &OwnedRow) -> Result<Datum> {
+        let source = self.source.eval_row(input).await?;
+        let Some(ScalarImpl::Utf8(source)) = source else {
+            return Ok(None);
+        };
+        let start = match &self.start {
+            Some(start) => match start.eval_row(input).await? {
+                Some(ScalarImpl::Int32(start)) => start,
+                _ => return Ok(None),
+            },
+            None => 1,
+        };
+        Ok(Some(ScalarImpl::Int32(self.count_one(&source, start)?)))
+    }
+}
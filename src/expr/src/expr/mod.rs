@@ -43,6 +43,7 @@ mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
 mod expr_jsonb_access;
+mod expr_jsonb_build;
 mod expr_literal;
 mod expr_nested_construct;
 mod expr_proctime;
@@ -0,0 +1,245 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A SQL/JSON path engine for `jsonb_path_query`.
+//!
+//! This implements the commonly used subset of the PostgreSQL jsonpath language in lax mode:
+//! the `$` root, member accessors (`.key`, `."quoted key"` and `.*`) and array accessors
+//! (`[0]`, `[*]`). Lax mode implicitly unwraps arrays when a member accessor is applied to
+//! them, like PostgreSQL does. Filter expressions, arithmetic and methods are not supported
+//! yet.
+
+use risingwave_common::types::{JsonbRef, JsonbVal};
+use risingwave_expr_macro::function;
+use serde_json::Value;
+
+use crate::{ExprError, Result};
+
+/// Returns all JSON items returned by the JSON path for the specified JSON value.
+#[function("jsonb_path_query(jsonb, varchar) -> setof jsonb")]
+fn jsonb_path_query(input: JsonbRef<'_>, path: &str) -> Result<impl Iterator<Item = JsonbVal>> {
+    let path = JsonPath::parse(path).map_err(|reason| ExprError::InvalidParam {
+        name: "jsonb_path_query",
+        reason,
+    })?;
+    let matched = path
+        .query(input.as_serde())
+        .into_iter()
+        .map(|v| JsonbVal::from(v.clone()))
+        .collect::<Vec<_>>();
+    Ok(matched.into_iter())
+}
+
+/// One accessor step of a JSON path.
+#[derive(Debug, PartialEq, Eq)]
+enum PathStep {
+    /// `.key` or `."quoted key"`
+    Member(String),
+    /// `.*`
+    AnyMember,
+    /// `[n]`
+    Index(usize),
+    /// `[*]`
+    AnyElement,
+}
+
+/// A parsed JSON path, e.g. `$.events[*].type`.
+#[derive(Debug, PartialEq, Eq)]
+struct JsonPath {
+    steps: Vec<PathStep>,
+}
+
+impl JsonPath {
+    /// Parses a JSON path, returning a human-readable reason on failure.
+    fn parse(path: &str) -> std::result::Result<Self, String> {
+        // The optional mode keyword. Strict mode is not supported as it differs in error
+        // handling and array unwrapping.
+        let rest = path.trim();
+        let rest = rest.strip_prefix("lax ").unwrap_or(rest).trim_start();
+        if rest.starts_with("strict ") {
+            return Err("strict jsonpath mode is not supported yet".into());
+        }
+        let mut chars = rest.chars().peekable();
+        if chars.next() != Some('$') {
+            return Err("jsonpath must start with `$`".into());
+        }
+        let mut steps = Vec::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => match chars.peek() {
+                    Some('*') => {
+                        chars.next();
+                        steps.push(PathStep::AnyMember);
+                    }
+                    Some('"') => {
+                        chars.next();
+                        let mut key = String::new();
+                        loop {
+                            match chars.next() {
+                                Some('"') => break,
+                                Some('\\') => match chars.next() {
+                                    Some(c) => key.push(c),
+                                    None => return Err("unterminated quoted key".into()),
+                                },
+                                Some(c) => key.push(c),
+                                None => return Err("unterminated quoted key".into()),
+                            }
+                        }
+                        steps.push(PathStep::Member(key));
+                    }
+                    _ => {
+                        let mut key = String::new();
+                        while let Some(c) = chars.peek()
+                            && (c.is_alphanumeric() || *c == '_')
+                        {
+                            key.push(*c);
+                            chars.next();
+                        }
+                        if key.is_empty() {
+                            return Err("expected a key after `.`".into());
+                        }
+                        steps.push(PathStep::Member(key));
+                    }
+                },
+                '[' => {
+                    let mut index = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) if !c.is_whitespace() => index.push(c),
+                            Some(_) => {}
+                            None => return Err("unterminated array accessor".into()),
+                        }
+                    }
+                    if index == "*" {
+                        steps.push(PathStep::AnyElement);
+                    } else {
+                        let index = index
+                            .parse::<usize>()
+                            .map_err(|_| format!("invalid array index `{index}`"))?;
+                        steps.push(PathStep::Index(index));
+                    }
+                }
+                c if c.is_whitespace() => {}
+                c => return Err(format!("unexpected character `{c}` in jsonpath")),
+            }
+        }
+        Ok(Self { steps })
+    }
+
+    /// Evaluates the path against a JSON value, returning all matched items.
+    fn query<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![root];
+        for step in &self.steps {
+            let mut next = Vec::new();
+            for value in current {
+                match step {
+                    PathStep::Member(key) => match value {
+                        Value::Object(object) => next.extend(object.get(key)),
+                        // Lax mode unwraps arrays when a member accessor is applied.
+                        Value::Array(array) => {
+                            for element in array {
+                                if let Value::Object(object) = element {
+                                    next.extend(object.get(key));
+                                }
+                            }
+                        }
+                        _ => {}
+                    },
+                    PathStep::AnyMember => {
+                        if let Value::Object(object) = value {
+                            next.extend(object.values());
+                        }
+                    }
+                    PathStep::Index(index) => {
+                        if let Value::Array(array) = value {
+                            next.extend(array.get(*index));
+                        }
+                    }
+                    PathStep::AnyElement => match value {
+                        Value::Array(array) => next.extend(array.iter()),
+                        // Lax mode treats a non-array as a singleton array.
+                        _ => next.push(value),
+                    },
+                }
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::JsonPath;
+
+    fn query(path: &str, value: Value) -> Vec<Value> {
+        JsonPath::parse(path)
+            .unwrap()
+            .query(&value)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(JsonPath::parse("a.b").is_err());
+        assert!(JsonPath::parse("$.").is_err());
+        assert!(JsonPath::parse("$[1").is_err());
+        assert!(JsonPath::parse("$[x]").is_err());
+        assert!(JsonPath::parse("strict $.a").is_err());
+    }
+
+    #[test]
+    fn test_member_access() {
+        let value = json!({"a": {"b": 1}, "c": 2});
+        assert_eq!(query("$.a.b", value.clone()), vec![json!(1)]);
+        assert_eq!(query("$.\"a\".b", value.clone()), vec![json!(1)]);
+        assert_eq!(query("lax $.c", value.clone()), vec![json!(2)]);
+        assert!(query("$.d", value).is_empty());
+    }
+
+    #[test]
+    fn test_array_access() {
+        let value = json!({"a": [1, 2, 3]});
+        assert_eq!(query("$.a[0]", value.clone()), vec![json!(1)]);
+        assert_eq!(
+            query("$.a[*]", value.clone()),
+            vec![json!(1), json!(2), json!(3)]
+        );
+        assert!(query("$.a[5]", value).is_empty());
+    }
+
+    #[test]
+    fn test_lax_array_unwrapping() {
+        let value = json!({"events": [{"type": "click"}, {"type": "view"}]});
+        assert_eq!(
+            query("$.events.type", value.clone()),
+            vec![json!("click"), json!("view")]
+        );
+        assert_eq!(
+            query("$.events[*].type", value),
+            vec![json!("click"), json!("view")]
+        );
+    }
+
+    #[test]
+    fn test_any_member() {
+        let value = json!({"a": 1, "b": 2});
+        assert_eq!(query("$.*", value), vec![json!(1), json!(2)]);
+    }
+}
@@ -29,6 +29,7 @@ use crate::sig::FuncSigDebug;
 
 mod generate_series;
 mod jsonb;
+mod jsonb_path;
 mod regexp_matches;
 mod repeat;
 mod unnest;
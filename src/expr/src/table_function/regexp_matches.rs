@@ -43,3 +43,37 @@ fn regexp_matches<'a>(
         ListValue::new(list)
     })
 }
+
+#[function(
+    "regexp_split_to_table(varchar, varchar) -> setof varchar",
+    prebuild = "RegexpContext::from_pattern($1)?"
+)]
+#[function(
+    "regexp_split_to_table(varchar, varchar, varchar) -> setof varchar",
+    prebuild = "RegexpContext::from_pattern_flags($1, $2)?"
+)]
+fn regexp_split_to_table<'a>(
+    text: &'a str,
+    regex: &'a RegexpContext,
+) -> impl Iterator<Item = Box<str>> + 'a {
+    let mut start = 0;
+    let mut done = false;
+    let mut matches = regex.0.find_iter(text);
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        for mat in matches.by_ref() {
+            // As in PostgreSQL, zero-length matches at the start of the string, at the end, or
+            // immediately after a previous match do not split off an (empty) field.
+            if mat.start() == mat.end() && (mat.start() == start || mat.end() == text.len()) {
+                continue;
+            }
+            let field = &text[start..mat.start()];
+            start = mat.end();
+            return Some(field.into());
+        }
+        done = true;
+        Some(text[start..].into())
+    })
+}
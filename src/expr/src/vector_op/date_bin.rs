@@ -0,0 +1,123 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Interval, Timestamp};
+use risingwave_expr_macro::function;
+
+use crate::{ExprError, Result};
+
+/// Bins the source timestamp into the specified interval (stride) aligned with the origin,
+/// following PostgreSQL's `date_bin`.
+#[function("date_bin(interval, timestamp, timestamp) -> timestamp")]
+pub fn date_bin_timestamp(
+    stride: Interval,
+    source: Timestamp,
+    origin: Timestamp,
+) -> Result<Timestamp> {
+    let source_usecs = source.0.timestamp_micros();
+    let origin_usecs = origin.0.timestamp_micros();
+    let binned_usecs = date_bin_usecs(stride, source_usecs, origin_usecs)?;
+    Ok(Timestamp::from_timestamp_uncheck(
+        binned_usecs.div_euclid(1_000_000),
+        (binned_usecs.rem_euclid(1_000_000) * 1000) as u32,
+    ))
+}
+
+/// For `timestamptz` the binning is done on the absolute time, so no timezone is involved,
+/// exactly as in PostgreSQL.
+#[function("date_bin(interval, timestamptz, timestamptz) -> timestamptz")]
+pub fn date_bin_timestamptz(stride: Interval, source: i64, origin: i64) -> Result<i64> {
+    date_bin_usecs(stride, source, origin)
+}
+
+fn date_bin_usecs(stride: Interval, source_usecs: i64, origin_usecs: i64) -> Result<i64> {
+    // As in PostgreSQL, a stride with a month component is rejected because months have no fixed
+    // length in microseconds, so the bins would be ambiguous.
+    if stride.months() != 0 {
+        return Err(ExprError::InvalidParam {
+            name: "stride",
+            reason: "timestamps cannot be binned into intervals containing months or years"
+                .to_string(),
+        });
+    }
+    let stride_usecs = (stride.days() as i64)
+        .checked_mul(Interval::USECS_PER_DAY)
+        .and_then(|usecs| usecs.checked_add(stride.usecs()))
+        .ok_or(ExprError::NumericOutOfRange)?;
+    if stride_usecs <= 0 {
+        return Err(ExprError::InvalidParam {
+            name: "stride",
+            reason: "stride must be greater than zero".to_string(),
+        });
+    }
+
+    let delta_usecs = source_usecs
+        .checked_sub(origin_usecs)
+        .ok_or(ExprError::NumericOutOfRange)?;
+    // `div_euclid` rounds towards negative infinity, so sources before the origin fall into the
+    // correct (earlier) bin as well.
+    let binned_usecs = delta_usecs
+        .div_euclid(stride_usecs)
+        .checked_mul(stride_usecs)
+        .and_then(|usecs| usecs.checked_add(origin_usecs))
+        .ok_or(ExprError::NumericOutOfRange)?;
+    Ok(binned_usecs)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::test_utils::IntervalTestExt;
+
+    use super::*;
+
+    fn ts(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_date_bin_timestamp() {
+        let origin = ts("2001-01-01 00:00:00");
+        // PostgreSQL doc example: 15 minute bins
+        assert_eq!(
+            date_bin_timestamp(
+                Interval::from_minutes(15),
+                ts("2020-02-11 15:44:17"),
+                origin
+            )
+            .unwrap(),
+            ts("2020-02-11 15:30:00")
+        );
+        // sources before the origin fall into the bin starting before them
+        assert_eq!(
+            date_bin_timestamp(
+                Interval::from_minutes(15),
+                ts("2000-12-31 23:59:00"),
+                origin
+            )
+            .unwrap(),
+            ts("2000-12-31 23:45:00")
+        );
+    }
+
+    #[test]
+    fn test_date_bin_invalid_stride() {
+        let origin = ts("2001-01-01 00:00:00");
+        let source = ts("2020-02-11 15:44:17");
+        // stride with months is ambiguous
+        assert!(date_bin_timestamp(Interval::from_month(1), source, origin).is_err());
+        // stride must be positive
+        assert!(date_bin_timestamp(Interval::from_minutes(0), source, origin).is_err());
+        assert!(date_bin_timestamp(Interval::from_minutes(-15), source, origin).is_err());
+    }
+}
@@ -38,9 +38,12 @@ pub fn date_trunc_timestamp(field: &str, ts: Timestamp) -> Result<Timestamp> {
     })
 }
 
-// #[function("date_trunc(varchar, timestamptz) -> timestamptz")]
-pub fn date_trunc_timestamptz(_field: &str, _ts: i64) -> Result<i64> {
-    todo!("date_trunc_timestamptz")
+/// The frontend rewrites the two-argument form on `timestamptz` to the three-argument form with
+/// the session timezone appended, so this is only a UTC fallback for paths that do not go
+/// through that rewrite.
+#[function("date_trunc(varchar, timestamptz) -> timestamptz")]
+pub fn date_trunc_timestamptz(field: &str, ts: i64) -> Result<i64> {
+    date_trunc_timestamptz_at_timezone(field, ts, "UTC")
 }
 
 #[function("date_trunc(varchar, timestamptz, varchar) -> timestamptz")]
@@ -0,0 +1,82 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::JsonbRef;
+use risingwave_expr_macro::function;
+
+/// Does the first jsonb value contain the second, i.e. the `@>` operator?
+///
+/// # Examples
+///
+/// ```slt
+/// query B
+/// select '{"type": "click", "user": {"id": 1}}'::jsonb @> '{"type": "click"}';
+/// ----
+/// t
+///
+/// query B
+/// select '[1, 2, 3]'::jsonb @> '[3, 1]';
+/// ----
+/// t
+///
+/// query B
+/// select '[1, 2, 3]'::jsonb @> '4';
+/// ----
+/// f
+/// ```
+#[function("jsonb_contains(jsonb, jsonb) -> boolean")]
+pub fn jsonb_contains(left: JsonbRef<'_>, right: JsonbRef<'_>) -> bool {
+    left.contains(&right)
+}
+
+/// Is the first jsonb value contained in the second, i.e. the `<@` operator?
+///
+/// # Examples
+///
+/// ```slt
+/// query B
+/// select '{"type": "click"}'::jsonb <@ '{"type": "click", "user": {"id": 1}}';
+/// ----
+/// t
+/// ```
+#[function("jsonb_contained(jsonb, jsonb) -> boolean")]
+pub fn jsonb_contained(left: JsonbRef<'_>, right: JsonbRef<'_>) -> bool {
+    right.contains(&left)
+}
+
+/// Does the string exist as a top-level key or array element within the jsonb value, i.e. the
+/// `?` operator?
+///
+/// # Examples
+///
+/// ```slt
+/// query B
+/// select '{"type": "click", "user": {"id": 1}}'::jsonb ? 'type';
+/// ----
+/// t
+///
+/// query B
+/// select '["a", "b"]'::jsonb ? 'b';
+/// ----
+/// t
+///
+/// query B
+/// select '{"user": {"id": 1}}'::jsonb ? 'id';
+/// ----
+/// f
+/// ```
+#[function("jsonb_exists(jsonb, varchar) -> boolean")]
+pub fn jsonb_exists(v: JsonbRef<'_>, key: &str) -> bool {
+    v.exists(key)
+}
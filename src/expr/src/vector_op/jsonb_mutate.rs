@@ -0,0 +1,463 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::ListRef;
+use risingwave_common::types::{JsonbRef, JsonbVal, ScalarRefImpl};
+use risingwave_expr_macro::function;
+use serde_json::Value;
+
+use crate::{ExprError, Result};
+
+/// Concatenates the two jsonb values, i.e. the `||` operator.
+///
+/// Concatenating two objects merges them, with the right operand winning on duplicate keys.
+/// Concatenating two arrays appends them. In all other cases a non-array operand is first
+/// wrapped into a single-element array, following PostgreSQL.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select '{"a": 1, "b": 2}'::jsonb || '{"b": 3, "c": 4}';
+/// ----
+/// {"a": 1, "b": 3, "c": 4}
+///
+/// query T
+/// select '[1, 2]'::jsonb || '[3]';
+/// ----
+/// [1, 2, 3]
+///
+/// query T
+/// select '{"a": 1}'::jsonb || '2';
+/// ----
+/// [{"a": 1}, 2]
+/// ```
+#[function("jsonb_cat(jsonb, jsonb) -> jsonb")]
+pub fn jsonb_cat(left: JsonbRef<'_>, right: JsonbRef<'_>) -> JsonbVal {
+    let left = left.to_owned_scalar().take();
+    let right = right.to_owned_scalar().take();
+    match (left, right) {
+        (Value::Object(mut left), Value::Object(right)) => {
+            left.extend(right);
+            Value::Object(left)
+        }
+        (left, right) => {
+            let mut left = match left {
+                Value::Array(elems) => elems,
+                value => vec![value],
+            };
+            match right {
+                Value::Array(elems) => left.extend(elems),
+                value => left.push(value),
+            }
+            Value::Array(left)
+        }
+    }
+    .into()
+}
+
+/// Replaces the item at the given path with the new value, i.e. `jsonb_set` with
+/// `create_if_missing` defaulting to `true`.
+#[function("jsonb_set(jsonb, list, jsonb) -> jsonb")]
+pub fn jsonb_set3(
+    target: JsonbRef<'_>,
+    path: ListRef<'_>,
+    new_value: JsonbRef<'_>,
+) -> Result<JsonbVal> {
+    jsonb_set4(target, path, new_value, true)
+}
+
+/// Replaces the item at the given path with the new value.
+///
+/// If the item does not exist and `create_if_missing` is `true`, it is added: as the new key of
+/// an object, or at the start or end of an array depending on the sign of the index. All earlier
+/// path steps must exist, otherwise the target is returned unchanged.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select jsonb_set('{"a": {"b": 1}}', '{a,b}', '2');
+/// ----
+/// {"a": {"b": 2}}
+///
+/// query T
+/// select jsonb_set('{"a": [0, 1]}', '{a,1}', 'null');
+/// ----
+/// {"a": [0, null]}
+///
+/// query T
+/// select jsonb_set('{"a": 1}', '{b}', '2', false);
+/// ----
+/// {"a": 1}
+/// ```
+#[function("jsonb_set(jsonb, list, jsonb, boolean) -> jsonb")]
+pub fn jsonb_set4(
+    target: JsonbRef<'_>,
+    path: ListRef<'_>,
+    new_value: JsonbRef<'_>,
+    create_if_missing: bool,
+) -> Result<JsonbVal> {
+    let mut root = target.to_owned_scalar().take();
+    let path = collect_path(path)?;
+    if !path.is_empty() {
+        let new_value = new_value.to_owned_scalar().take();
+        mutate_path(
+            &mut root,
+            &path,
+            0,
+            new_value,
+            Op::Set { create_if_missing },
+        )?;
+    }
+    Ok(root.into())
+}
+
+/// Inserts the new value at the given path, i.e. `jsonb_insert` with `insert_after` defaulting
+/// to `false`.
+#[function("jsonb_insert(jsonb, list, jsonb) -> jsonb")]
+pub fn jsonb_insert3(
+    target: JsonbRef<'_>,
+    path: ListRef<'_>,
+    new_value: JsonbRef<'_>,
+) -> Result<JsonbVal> {
+    jsonb_insert4(target, path, new_value, false)
+}
+
+/// Inserts the new value at the given path.
+///
+/// When the last path step addresses an array element, the value is inserted before it, or
+/// after it if `insert_after` is `true`. When it addresses an object key, the key must not
+/// exist yet: unlike `jsonb_set`, `jsonb_insert` refuses to replace an existing value.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select jsonb_insert('{"a": [0, 1]}', '{a,1}', '9');
+/// ----
+/// {"a": [0, 9, 1]}
+///
+/// query T
+/// select jsonb_insert('{"a": [0, 1]}', '{a,1}', '9', true);
+/// ----
+/// {"a": [0, 1, 9]}
+/// ```
+#[function("jsonb_insert(jsonb, list, jsonb, boolean) -> jsonb")]
+pub fn jsonb_insert4(
+    target: JsonbRef<'_>,
+    path: ListRef<'_>,
+    new_value: JsonbRef<'_>,
+    insert_after: bool,
+) -> Result<JsonbVal> {
+    let mut root = target.to_owned_scalar().take();
+    let path = collect_path(path)?;
+    if !path.is_empty() {
+        let new_value = new_value.to_owned_scalar().take();
+        mutate_path(&mut root, &path, 0, new_value, Op::Insert { insert_after })?;
+    }
+    Ok(root.into())
+}
+
+/// Deletes a key from an object or a string element from an array, i.e. the `jsonb - text`
+/// operator.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select '{"a": 1, "b": 2}'::jsonb - 'a';
+/// ----
+/// {"b": 2}
+///
+/// query T
+/// select '["a", "b", "a"]'::jsonb - 'a';
+/// ----
+/// ["b"]
+/// ```
+#[function("jsonb_delete(jsonb, varchar) -> jsonb")]
+pub fn jsonb_delete(target: JsonbRef<'_>, key: &str) -> Result<JsonbVal> {
+    let mut root = target.to_owned_scalar().take();
+    match &mut root {
+        Value::Object(object) => {
+            object.remove(key);
+        }
+        Value::Array(elems) => elems.retain(|elem| elem.as_str() != Some(key)),
+        _ => {
+            return Err(ExprError::InvalidParam {
+                name: "jsonb_delete",
+                reason: "cannot delete from scalar".into(),
+            })
+        }
+    }
+    Ok(root.into())
+}
+
+/// Deletes the array element at the given index, i.e. the `jsonb - int` operator.
+///
+/// A negative index counts from the end. An index out of range leaves the array unchanged.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select '["a", "b", "c"]'::jsonb - 1;
+/// ----
+/// ["a", "c"]
+///
+/// query T
+/// select '["a", "b", "c"]'::jsonb - -1;
+/// ----
+/// ["a", "b"]
+/// ```
+#[function("jsonb_delete(jsonb, int32) -> jsonb")]
+pub fn jsonb_delete_index(target: JsonbRef<'_>, index: i32) -> Result<JsonbVal> {
+    let mut root = target.to_owned_scalar().take();
+    match &mut root {
+        Value::Array(elems) => {
+            let index = index as i64;
+            let index = if index < 0 {
+                index + elems.len() as i64
+            } else {
+                index
+            };
+            if (0..elems.len() as i64).contains(&index) {
+                elems.remove(index as usize);
+            }
+        }
+        Value::Object(_) => {
+            return Err(ExprError::InvalidParam {
+                name: "jsonb_delete",
+                reason: "cannot delete from object using integer index".into(),
+            })
+        }
+        _ => {
+            return Err(ExprError::InvalidParam {
+                name: "jsonb_delete",
+                reason: "cannot delete from scalar".into(),
+            })
+        }
+    }
+    Ok(root.into())
+}
+
+enum Op {
+    Set { create_if_missing: bool },
+    Insert { insert_after: bool },
+}
+
+/// Collects the elements of the path array, which must all be non-null strings.
+fn collect_path(path: ListRef<'_>) -> Result<Vec<&str>> {
+    path.iter()
+        .enumerate()
+        .map(|(i, datum)| match datum {
+            Some(ScalarRefImpl::Utf8(step)) => Ok(step),
+            _ => Err(ExprError::InvalidParam {
+                name: "path",
+                reason: format!("path element at position {} is null or not a string", i + 1),
+            }),
+        })
+        .collect()
+}
+
+/// Applies `op` to the item of `cur` addressed by `path[pos..]`, following the semantics of
+/// `setPath` in PostgreSQL: a missing intermediate step leaves the target unchanged, while
+/// traversing into a scalar is an error.
+fn mutate_path(cur: &mut Value, path: &[&str], pos: usize, new: Value, op: Op) -> Result<()> {
+    let step = path[pos];
+    let last = pos == path.len() - 1;
+    match cur {
+        Value::Object(object) => {
+            if !last {
+                if let Some(child) = object.get_mut(step) {
+                    mutate_path(child, path, pos + 1, new, op)?;
+                }
+                return Ok(());
+            }
+            match op {
+                Op::Set { create_if_missing } => {
+                    if create_if_missing || object.contains_key(step) {
+                        object.insert(step.to_string(), new);
+                    }
+                }
+                Op::Insert { .. } => {
+                    if object.contains_key(step) {
+                        return Err(ExprError::InvalidParam {
+                            name: "jsonb_insert",
+                            reason: "cannot replace existing key".into(),
+                        });
+                    }
+                    object.insert(step.to_string(), new);
+                }
+            }
+        }
+        Value::Array(elems) => {
+            let index: i64 = step.parse().map_err(|_| ExprError::InvalidParam {
+                name: "path",
+                reason: format!(
+                    "path element at position {} is not an integer: \"{}\"",
+                    pos + 1,
+                    step
+                ),
+            })?;
+            let len = elems.len() as i64;
+            let index = if index < 0 { index + len } else { index };
+            if !last {
+                if (0..len).contains(&index) {
+                    mutate_path(&mut elems[index as usize], path, pos + 1, new, op)?;
+                }
+                return Ok(());
+            }
+            match op {
+                Op::Set { create_if_missing } => {
+                    if (0..len).contains(&index) {
+                        elems[index as usize] = new;
+                    } else if create_if_missing && index < 0 {
+                        elems.insert(0, new);
+                    } else if create_if_missing {
+                        elems.push(new);
+                    }
+                }
+                Op::Insert { insert_after } => {
+                    let index = if insert_after { index + 1 } else { index };
+                    elems.insert(index.clamp(0, len) as usize, new);
+                }
+            }
+        }
+        _ => {
+            return Err(ExprError::InvalidParam {
+                name: "path",
+                reason: "cannot set path in scalar".into(),
+            })
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    fn path(steps: &[&str]) -> risingwave_common::array::ListValue {
+        risingwave_common::array::ListValue::new(
+            steps
+                .iter()
+                .map(|s| Some(risingwave_common::types::ScalarImpl::Utf8((*s).into())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_jsonb_cat() {
+        let assert_cat = |left: &str, right: &str, expected: &str| {
+            let left = jsonb(left);
+            let right = jsonb(right);
+            assert_eq!(
+                jsonb_cat(left.as_scalar_ref(), right.as_scalar_ref()),
+                jsonb(expected)
+            );
+        };
+        assert_cat(r#"{"a": 1}"#, r#"{"a": 2, "b": 3}"#, r#"{"a": 2, "b": 3}"#);
+        assert_cat("[1, 2]", "[3]", "[1, 2, 3]");
+        assert_cat(r#"{"a": 1}"#, "[2]", r#"[{"a": 1}, 2]"#);
+        assert_cat("1", "2", "[1, 2]");
+    }
+
+    #[test]
+    fn test_jsonb_set() {
+        let target = jsonb(r#"{"a": {"b": 1}, "c": [0, 1]}"#);
+        let new = jsonb("9");
+        let set = |steps: &[&str], create| {
+            let path = path(steps);
+            jsonb_set4(
+                target.as_scalar_ref(),
+                risingwave_common::array::ListRef::ValueRef { val: &path },
+                new.as_scalar_ref(),
+                create,
+            )
+        };
+        assert_eq!(
+            set(&["a", "b"], true).unwrap(),
+            jsonb(r#"{"a": {"b": 9}, "c": [0, 1]}"#)
+        );
+        assert_eq!(
+            set(&["c", "-1"], true).unwrap(),
+            jsonb(r#"{"a": {"b": 1}, "c": [0, 9]}"#)
+        );
+        assert_eq!(set(&["d"], false).unwrap(), target);
+        assert_eq!(
+            set(&["d"], true).unwrap(),
+            jsonb(r#"{"a": {"b": 1}, "c": [0, 1], "d": 9}"#)
+        );
+        assert!(set(&["a", "b", "c"], true).is_err());
+    }
+
+    #[test]
+    fn test_jsonb_insert() {
+        let target = jsonb(r#"{"a": [0, 1]}"#);
+        let new = jsonb("9");
+        let insert = |steps: &[&str], after| {
+            let path = path(steps);
+            jsonb_insert4(
+                target.as_scalar_ref(),
+                risingwave_common::array::ListRef::ValueRef { val: &path },
+                new.as_scalar_ref(),
+                after,
+            )
+        };
+        assert_eq!(
+            insert(&["a", "1"], false).unwrap(),
+            jsonb(r#"{"a": [0, 9, 1]}"#)
+        );
+        assert_eq!(
+            insert(&["a", "1"], true).unwrap(),
+            jsonb(r#"{"a": [0, 1, 9]}"#)
+        );
+        assert_eq!(
+            insert(&["b"], false).unwrap(),
+            jsonb(r#"{"a": [0, 1], "b": 9}"#)
+        );
+        assert!(insert(&["a"], false).is_err());
+    }
+
+    #[test]
+    fn test_jsonb_delete() {
+        let assert_delete = |target: &str, key: &str, expected: &str| {
+            assert_eq!(
+                jsonb_delete(jsonb(target).as_scalar_ref(), key).unwrap(),
+                jsonb(expected)
+            );
+        };
+        assert_delete(r#"{"a": 1, "b": 2}"#, "a", r#"{"b": 2}"#);
+        assert_delete(r#"["a", "b", "a"]"#, "a", r#"["b"]"#);
+        assert!(jsonb_delete(jsonb("1").as_scalar_ref(), "a").is_err());
+
+        let target = jsonb(r#"["a", "b", "c"]"#);
+        assert_eq!(
+            jsonb_delete_index(target.as_scalar_ref(), -1).unwrap(),
+            jsonb(r#"["a", "b"]"#)
+        );
+        assert_eq!(
+            jsonb_delete_index(target.as_scalar_ref(), 3).unwrap(),
+            target
+        );
+        assert!(jsonb_delete_index(jsonb(r#"{"a": 1}"#).as_scalar_ref(), 0).is_err());
+    }
+}
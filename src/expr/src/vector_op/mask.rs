@@ -0,0 +1,151 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use risingwave_expr_macro::function;
+use sha2::{Digest, Sha256};
+
+/// Masks the local part of an email address, keeping its first character and the domain,
+/// so that masked values remain joinable by domain.
+///
+/// If the input does not look like an email address (no `@`), everything but the first
+/// character is masked.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select mask_email('john.doe@example.com');
+/// ----
+/// j*******@example.com
+/// ```
+#[function("mask_email(varchar) -> varchar")]
+pub fn mask_email(email: &str, writer: &mut dyn Write) {
+    let (local, domain) = match email.split_once('@') {
+        Some((local, domain)) => (local, Some(domain)),
+        None => (email, None),
+    };
+    let mut chars = local.chars();
+    if let Some(first) = chars.next() {
+        writer.write_char(first).unwrap();
+    }
+    for _ in chars {
+        writer.write_char('*').unwrap();
+    }
+    if let Some(domain) = domain {
+        writer.write_char('@').unwrap();
+        writer.write_str(domain).unwrap();
+    }
+}
+
+/// Masks all but the last four digits of a social security number, keeping any separators,
+/// so that masked values can still be eyeballed against the original format.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select mask_ssn('123-45-6789');
+/// ----
+/// ***-**-6789
+/// ```
+#[function("mask_ssn(varchar) -> varchar")]
+pub fn mask_ssn(ssn: &str, writer: &mut dyn Write) {
+    let digits = ssn.chars().filter(|c| c.is_ascii_digit()).count();
+    let mut to_mask = digits.saturating_sub(4);
+    for c in ssn.chars() {
+        if c.is_ascii_digit() && to_mask > 0 {
+            to_mask -= 1;
+            writer.write_char('*').unwrap();
+        } else {
+            writer.write_char(c).unwrap();
+        }
+    }
+}
+
+/// Hashes a PII value with a salt into a hex-encoded SHA-256 digest, as a one-way
+/// pseudonymization that is still stable for joins and group-bys.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select hash_pii('alice', 'pepper');
+/// ----
+/// b1b68da447843a6519d8dd7a9c13c90aa1148805cbe55810f86712e6c294ff36
+/// ```
+#[function("hash_pii(varchar, varchar) -> varchar")]
+pub fn hash_pii(value: &str, salt: &str, writer: &mut dyn Write) {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(value.as_bytes());
+    write!(writer, "{}", hex::encode(hasher.finalize())).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_email() {
+        let cases = [
+            ("john.doe@example.com", "j*******@example.com"),
+            ("a@example.com", "a@example.com"),
+            ("@example.com", "@example.com"),
+            ("not-an-email", "n***********"),
+            ("", ""),
+        ];
+
+        for (input, expected) in cases {
+            let mut writer = String::new();
+            mask_email(input, &mut writer);
+            assert_eq!(writer, expected);
+        }
+    }
+
+    #[test]
+    fn test_mask_ssn() {
+        let cases = [
+            ("123-45-6789", "***-**-6789"),
+            ("123456789", "*****6789"),
+            ("6789", "6789"),
+            ("12-3", "12-3"),
+            ("", ""),
+        ];
+
+        for (input, expected) in cases {
+            let mut writer = String::new();
+            mask_ssn(input, &mut writer);
+            assert_eq!(writer, expected);
+        }
+    }
+
+    #[test]
+    fn test_hash_pii() {
+        let mut first = String::new();
+        hash_pii("alice", "pepper", &mut first);
+        assert_eq!(first.len(), 64);
+
+        // The hash is deterministic for the same value and salt.
+        let mut second = String::new();
+        hash_pii("alice", "pepper", &mut second);
+        assert_eq!(first, second);
+
+        // A different salt yields a different digest.
+        let mut other = String::new();
+        hash_pii("alice", "salt", &mut other);
+        assert_ne!(first, other);
+    }
+}
@@ -27,16 +27,20 @@ pub mod cast;
 pub mod cmp;
 pub mod concat_op;
 pub mod conjunction;
+pub mod date_bin;
 pub mod date_trunc;
 pub mod encdec;
 pub mod exp;
 pub mod extract;
 pub mod format_type;
 pub mod int256;
+pub mod jsonb_contains;
 pub mod jsonb_info;
+pub mod jsonb_mutate;
 pub mod length;
 pub mod like;
 pub mod lower;
+pub mod mask;
 pub mod md5;
 pub mod overlay;
 pub mod position;
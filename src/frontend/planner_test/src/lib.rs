@@ -759,6 +759,7 @@ impl TestCase {
                     format!("CREATE SINK {sink_name} AS {}", stmt),
                     options,
                     false,
+                    false,
                 ) {
                     Ok(sink_plan) => {
                         ret.sink_plan = Some(explain_plan(&sink_plan.into()));
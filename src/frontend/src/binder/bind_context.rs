@@ -67,6 +67,9 @@ pub struct LateralBindContext {
 pub struct BindContext {
     // Columns of all tables.
     pub columns: Vec<ColumnBinding>,
+    // Indices in `columns` that the current user has no `SELECT` privilege on, because the
+    // privilege on the relation is restricted to specific columns.
+    pub disallowed_select_columns: HashSet<usize>,
     // Mapping column name to indices in `columns`.
     pub indices_of: HashMap<String, Vec<usize>>,
     // Mapping table name to [begin, end) of its columns.
@@ -286,6 +289,19 @@ impl BindContext {
         }
     }
 
+    /// Errors if the current user has no `SELECT` privilege on the column at `index`.
+    pub fn check_column_selectable(&self, index: usize) -> Result<()> {
+        if self.disallowed_select_columns.contains(&index) {
+            let column = &self.columns[index];
+            return Err(ErrorCode::PermissionDenied(format!(
+                "permission denied for column \"{}\" of relation \"{}\"",
+                column.field.name, column.table_name
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     /// Merges two `BindContext`s which are adjacent. For instance, the `BindContext` of two
     /// adjacent cross-joined tables.
     pub fn merge_context(&mut self, other: Self) -> Result<()> {
@@ -294,6 +310,12 @@ impl BindContext {
             c.index += begin;
             c
         }));
+        self.disallowed_select_columns.extend(
+            other
+                .disallowed_select_columns
+                .into_iter()
+                .map(|x| x + begin),
+        );
         for (k, v) in other.indices_of {
             let entry = self.indices_of.entry(k).or_insert_with(Vec::new);
             entry.extend(v.into_iter().map(|x| x + begin));
@@ -61,7 +61,13 @@ impl Binder {
         let mut func_types = vec![];
         let final_type = match op {
             BinaryOperator::Plus => ExprType::Add,
-            BinaryOperator::Minus => ExprType::Subtract,
+            BinaryOperator::Minus => {
+                if !bound_left.is_untyped() && bound_left.return_type() == DataType::Jsonb {
+                    ExprType::JsonbDelete
+                } else {
+                    ExprType::Subtract
+                }
+            }
             BinaryOperator::Multiply => ExprType::Multiply,
             BinaryOperator::Divide => ExprType::Divide,
             BinaryOperator::Modulo => ExprType::Modulus,
@@ -86,6 +92,9 @@ impl Binder {
             BinaryOperator::PGBitwiseShiftRight => ExprType::BitwiseShiftRight,
             BinaryOperator::Arrow => ExprType::JsonbAccessInner,
             BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::Contains => ExprType::JsonbContains,
+            BinaryOperator::Contained => ExprType::JsonbContained,
+            BinaryOperator::Exists => ExprType::JsonbExists,
             BinaryOperator::Prefix => ExprType::StartsWith,
             BinaryOperator::Concat => {
                 let left_type = (!bound_left.is_untyped()).then(|| bound_left.return_type());
@@ -103,11 +112,13 @@ impl Binder {
                         ExprType::ConcatOp
                     }
 
-                    // jsonb, bytea (and varbit, tsvector, tsquery)
-                    (Some(t @ DataType::Jsonb), Some(DataType::Jsonb))
-                    | (Some(t @ DataType::Jsonb), None)
-                    | (None, Some(t @ DataType::Jsonb))
-                    | (Some(t @ DataType::Bytea), Some(DataType::Bytea))
+                    // jsonb concatenation
+                    (Some(DataType::Jsonb), Some(DataType::Jsonb))
+                    | (Some(DataType::Jsonb), None)
+                    | (None, Some(DataType::Jsonb)) => ExprType::JsonbCat,
+
+                    // bytea (and varbit, tsvector, tsquery)
+                    (Some(t @ DataType::Bytea), Some(DataType::Bytea))
                     | (Some(t @ DataType::Bytea), None)
                     | (None, Some(t @ DataType::Bytea)) => {
                         return Err(ErrorCode::BindError(format!(
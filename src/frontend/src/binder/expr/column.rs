@@ -41,6 +41,11 @@ impl Binder {
             .get_column_binding_indices(&table_name, &column_name)
         {
             Ok(mut indices) => {
+                if self.select_privilege_exempt == 0 {
+                    for index in &indices {
+                        self.context.check_column_selectable(*index)?;
+                    }
+                }
                 match indices.len() {
                     0 => unreachable!(),
                     1 => {
@@ -79,6 +84,9 @@ impl Binder {
             let depth = i + 1;
             match context.get_column_binding_index(&table_name, &column_name) {
                 Ok(index) => {
+                    if self.select_privilege_exempt == 0 {
+                        context.check_column_selectable(index)?;
+                    }
                     let column = &context.columns[index];
                     return Ok(CorrelatedInputRef::new(
                         column.index,
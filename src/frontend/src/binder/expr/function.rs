@@ -188,6 +188,17 @@ impl Binder {
                 .unwrap()
                 .clone()
                 .cast_implicit(DataType::Float64)?;
+        } else if kind == AggKind::ApproxPercentile {
+            if inputs.len() != 2 {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "two args are expected in {}",
+                    kind
+                ))
+                .into());
+            }
+            // Only the value argument is aggregated; the fraction goes to `direct_args` below.
+            let value = inputs[0].clone().cast_implicit(DataType::Float64)?;
+            inputs = vec![value];
         }
 
         if f.distinct {
@@ -282,11 +293,20 @@ impl Binder {
                     .try_collect()?,
             )
         };
-        let direct_args = if matches!(kind, AggKind::PercentileCont | AggKind::PercentileDisc) {
-            let args =
-                self.bind_function_arg(f.args.into_iter().exactly_one().map_err(|_| {
+        let direct_args = if matches!(
+            kind,
+            AggKind::PercentileCont | AggKind::PercentileDisc | AggKind::ApproxPercentile
+        ) {
+            let fraction_arg = if kind == AggKind::ApproxPercentile {
+                // The first argument is the aggregated value and has been bound above; the
+                // second one is the constant fraction. Its presence is checked there as well.
+                f.args.into_iter().nth(1).unwrap()
+            } else {
+                f.args.into_iter().exactly_one().map_err(|_| {
                     ErrorCode::InvalidInputSyntax(format!("only one arg is expected in {}", kind))
-                })?)?;
+                })?
+            };
+            let args = self.bind_function_arg(fraction_arg)?;
             if args.len() != 1 || args[0].clone().as_literal().is_none() {
                 Err(
                     ErrorCode::InvalidInputSyntax(format!("arg in {} must be constant", kind))
@@ -581,6 +601,7 @@ impl Binder {
                     ]),
                 ),
                 ("date_trunc", raw_call(ExprType::DateTrunc)),
+                ("date_bin", raw_call(ExprType::DateBin)),
                 ("date_part", raw_call(ExprType::DatePart)),
                 // string
                 ("substr", raw_call(ExprType::Substr)),
@@ -609,6 +630,8 @@ impl Binder {
                 ("octet_length", raw_call(ExprType::OctetLength)),
                 ("bit_length", raw_call(ExprType::BitLength)),
                 ("regexp_match", raw_call(ExprType::RegexpMatch)),
+                ("regexp_replace", raw_call(ExprType::RegexpReplace)),
+                ("regexp_count", raw_call(ExprType::RegexpCount)),
                 ("chr", raw_call(ExprType::Chr)),
                 ("starts_with", raw_call(ExprType::StartsWith)),
                 ("initcap", raw_call(ExprType::Initcap)),
@@ -627,6 +650,9 @@ impl Binder {
                 ("sha256", raw_call(ExprType::Sha256)),
                 ("sha384", raw_call(ExprType::Sha384)),
                 ("sha512", raw_call(ExprType::Sha512)),
+                ("mask_email", raw_call(ExprType::MaskEmail)),
+                ("mask_ssn", raw_call(ExprType::MaskSsn)),
+                ("hash_pii", raw_call(ExprType::HashPii)),
                 // array
                 ("array_cat", raw_call(ExprType::ArrayCat)),
                 ("array_append", raw_call(ExprType::ArrayAppend)),
@@ -685,6 +711,15 @@ impl Binder {
                 ("jsonb_array_element_text", raw_call(ExprType::JsonbAccessStr)),
                 ("jsonb_typeof", raw_call(ExprType::JsonbTypeof)),
                 ("jsonb_array_length", raw_call(ExprType::JsonbArrayLength)),
+                ("jsonb_contains", raw_call(ExprType::JsonbContains)),
+                ("jsonb_contained", raw_call(ExprType::JsonbContained)),
+                ("jsonb_exists", raw_call(ExprType::JsonbExists)),
+                ("to_jsonb", raw_call(ExprType::ToJsonb)),
+                ("jsonb_build_array", raw_call(ExprType::JsonbBuildArray)),
+                ("jsonb_build_object", raw_call(ExprType::JsonbBuildObject)),
+                ("jsonb_set", raw_call(ExprType::JsonbSet)),
+                ("jsonb_insert", raw_call(ExprType::JsonbInsert)),
+                ("jsonb_delete", raw_call(ExprType::JsonbDelete)),
                 // Functions that return a constant value
                 ("pi", pi()),
                 // System information operations.
@@ -59,6 +59,7 @@ use crate::catalog::catalog_service::CatalogReadGuard;
 use crate::catalog::schema_catalog::SchemaCatalog;
 use crate::catalog::{CatalogResult, TableId, ViewId};
 use crate::session::{AuthContext, SessionImpl};
+use crate::user::user_service::UserInfoReadGuard;
 
 pub type ShareId = usize;
 
@@ -78,6 +79,7 @@ enum BindFor {
 pub struct Binder {
     // TODO: maybe we can only lock the database, but not the whole catalog.
     catalog: CatalogReadGuard,
+    user: UserInfoReadGuard,
     db_name: String,
     session_id: SessionId,
     context: BindContext,
@@ -113,6 +115,12 @@ pub struct Binder {
     /// The included relations while binding a query.
     included_relations: HashSet<TableId>,
 
+    /// Nonzero while binding parts of a statement where the column-level privileges of the
+    /// current user must not be enforced, i.e. the definition of a view (which is bound on
+    /// behalf of the view's owner) and a `* EXCEPT` column list (which excludes the columns
+    /// rather than reading them).
+    select_privilege_exempt: usize,
+
     param_types: ParameterTypes,
 }
 
@@ -124,8 +132,8 @@ pub struct Binder {
 /// 3. When the binder encounters a cast on parameter, if it's a unknown type, the cast function
 /// will record the target type as infer type for that parameter(call `record_infer_type`). If the
 /// parameter has been inferred, the cast function will act as a normal cast.
-/// 4. After bind finished:
-///     (a) parameter not in `ParameterTypes` means that the user didn't specify it and it didn't
+/// 4. After bind finished: (a) parameter not in `ParameterTypes` means that the user didn't specify
+///    it and it didn't
 /// occur in the query. `export` will return error if there is a kind of
 /// parameter. This rule is compatible with PostgreSQL
 ///     (b) parameter is None means that it's a unknown type. The user didn't specify it
@@ -202,6 +210,7 @@ impl Binder {
     fn new_inner(session: &SessionImpl, bind_for: BindFor, param_types: Vec<DataType>) -> Binder {
         Binder {
             catalog: session.env().catalog_reader().read_guard(),
+            user: session.env().user_info_reader().read_guard(),
             db_name: session.database().to_string(),
             session_id: session.id(),
             context: BindContext::new(),
@@ -221,6 +230,7 @@ impl Binder {
             bind_for,
             shared_views: HashMap::new(),
             included_relations: HashSet::new(),
+            select_privilege_exempt: 0,
             param_types: ParameterTypes::new(param_types),
         }
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -19,6 +20,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::{is_system_schema, Field};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
+use risingwave_pb::user::grant_privilege::{PbAction, PbObject};
 use risingwave_sqlparser::ast::{Statement, TableAlias};
 use risingwave_sqlparser::parser::Parser;
 
@@ -31,6 +33,7 @@ use crate::catalog::system_catalog::SystemCatalog;
 use crate::catalog::table_catalog::{TableCatalog, TableType};
 use crate::catalog::view_catalog::ViewCatalog;
 use crate::catalog::{CatalogError, IndexCatalog, TableId};
+use crate::user::UserId;
 
 #[derive(Debug, Clone)]
 pub struct BoundBaseTable {
@@ -183,10 +186,78 @@ impl Binder {
             }
         };
 
+        // Column-level SELECT grants are enforced lazily when a column is actually
+        // referenced, so record the positions the current user may not read here.
+        let denied_columns = self.denied_select_positions(&ret, &columns);
+        let begin = self.context.columns.len();
         self.bind_table_to_context(columns, table_name.to_string(), alias)?;
+        self.context
+            .disallowed_select_columns
+            .extend(denied_columns.into_iter().map(|pos| begin + pos));
         Ok(ret)
     }
 
+    /// Returns the positions in `columns` that the current user has no `SELECT` privilege
+    /// on, if the access to the relation is restricted to specific columns.
+    fn denied_select_positions(
+        &self,
+        relation: &Relation,
+        columns: &[(bool, Field)],
+    ) -> Vec<usize> {
+        if self.select_privilege_exempt > 0 {
+            return vec![];
+        }
+        let granted = match relation {
+            Relation::BaseTable(table) => self.granted_select_columns(
+                table.table_catalog.owner,
+                PbObject::TableId(table.table_id.table_id),
+            ),
+            Relation::Source(source) => self.granted_select_columns(
+                source.catalog.owner,
+                PbObject::SourceId(source.catalog.id),
+            ),
+            _ => None,
+        };
+        let Some(granted) = granted else {
+            return vec![];
+        };
+        columns
+            .iter()
+            .positions(|(_, field)| !granted.contains(&field.name))
+            .collect()
+    }
+
+    /// Returns the set of column names the current user's `SELECT` privilege on `object` is
+    /// restricted to, or `None` if the access is not restricted to specific columns. Whether
+    /// the user may access the relation at all is checked separately per statement.
+    pub(crate) fn granted_select_columns(
+        &self,
+        owner: UserId,
+        object: PbObject,
+    ) -> Option<HashSet<String>> {
+        let user = self.user.get_user_by_name(&self.auth_context.user_name)?;
+        if user.is_super || user.id == owner {
+            return None;
+        }
+        let mut columns = HashSet::new();
+        for privilege in user
+            .grant_privileges
+            .iter()
+            .filter(|privilege| privilege.object.as_ref() == Some(&object))
+        {
+            for ao in &privilege.action_with_opts {
+                if ao.action == PbAction::Select as i32 {
+                    if ao.column_names.is_empty() {
+                        // A grant on the whole relation.
+                        return None;
+                    }
+                    columns.extend(ao.column_names.iter().cloned());
+                }
+            }
+        }
+        (!columns.is_empty()).then_some(columns)
+    }
+
     fn resolve_table_relation(
         &mut self,
         table_catalog: &TableCatalog,
@@ -241,7 +312,12 @@ impl Binder {
         else {
             unreachable!("a view should contain a query statement");
         };
-        let query = self.bind_query(*query).map_err(|e| {
+        // A view's definition is bound on behalf of the view's owner rather than the
+        // current user, so column-level privileges do not apply inside it.
+        self.select_privilege_exempt += 1;
+        let query = self.bind_query(*query);
+        self.select_privilege_exempt -= 1;
+        let query = query.map_err(|e| {
             ErrorCode::BindError(format!(
                 "failed to bind view {}, sql: {}\nerror: {}",
                 view_catalog.name, view_catalog.sql, e
@@ -296,6 +372,7 @@ impl Binder {
 
         let columns = table_catalog.columns.clone();
 
+        let begin = self.context.columns.len();
         self.bind_table_to_context(
             columns
                 .iter()
@@ -303,6 +380,18 @@ impl Binder {
             table_name.to_string(),
             alias,
         )?;
+        // Reading columns of the target table, e.g. in a `WHERE` clause, also requires the
+        // `SELECT` privilege on them.
+        if let Some(granted) =
+            self.granted_select_columns(table_catalog.owner, PbObject::TableId(table_id.table_id))
+        {
+            self.context.disallowed_select_columns.extend(
+                columns
+                    .iter()
+                    .positions(|column| !granted.contains(column.name()))
+                    .map(|pos| begin + pos),
+            );
+        }
 
         Ok(BoundBaseTable {
             table_id,
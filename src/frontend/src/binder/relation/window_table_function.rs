@@ -28,6 +28,7 @@ use crate::expr::{ExprImpl, InputRef};
 pub enum WindowTableFunctionKind {
     Tumble,
     Hop,
+    Session,
 }
 
 impl FromStr for WindowTableFunctionKind {
@@ -38,6 +39,8 @@ impl FromStr for WindowTableFunctionKind {
             Ok(WindowTableFunctionKind::Tumble)
         } else if s.eq_ignore_ascii_case("hop") {
             Ok(WindowTableFunctionKind::Hop)
+        } else if s.eq_ignore_ascii_case("session") {
+            Ok(WindowTableFunctionKind::Session)
         } else {
             Err(())
         }
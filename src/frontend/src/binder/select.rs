@@ -246,6 +246,12 @@ impl Binder {
                     let (begin, end) = self.context.range_of.get(table_name).ok_or_else(|| {
                         ErrorCode::ItemNotFound(format!("relation \"{}\"", table_name))
                     })?;
+                    for column in self.context.columns[*begin..*end]
+                        .iter()
+                        .filter(|c| !c.is_hidden)
+                    {
+                        self.context.check_column_selectable(column.index)?;
+                    }
                     let (exprs, names) = Self::iter_bound_columns(
                         self.context.columns[*begin..*end]
                             .iter()
@@ -277,8 +283,12 @@ impl Binder {
                     let mut except_indices: HashSet<usize> = HashSet::new();
                     if let Some(exprs) = w {
                         for expr in exprs {
-                            let bound = self.bind_expr(expr)?;
-                            if let ExprImpl::InputRef(inner) = bound {
+                            // The excepted columns are not read, so binding them is exempt
+                            // from column-level privilege checks.
+                            self.select_privilege_exempt += 1;
+                            let bound = self.bind_expr(expr);
+                            self.select_privilege_exempt -= 1;
+                            if let ExprImpl::InputRef(inner) = bound? {
                                 except_indices.insert(inner.index);
                             } else {
                                 unreachable!();
@@ -286,6 +296,15 @@ impl Binder {
                         }
                     }
 
+                    for column in self
+                        .context
+                        .columns
+                        .iter()
+                        .filter(|c| !c.is_hidden && !except_indices.contains(&c.index))
+                    {
+                        self.context.check_column_selectable(column.index)?;
+                    }
+
                     // Bind columns that are not in groups
                     let (exprs, names) =
                         Self::iter_bound_columns(self.context.columns[..].iter().filter(|c| {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use parking_lot::lock_api::ArcRwLockReadGuard;
@@ -21,9 +22,10 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::catalog::{
-    PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
+    PbDatabase, PbFunction, PbIndex, PbSchema, PbSecret, PbSink, PbSource, PbTable, PbView,
 };
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
+use risingwave_pb::ddl_service::alter_relation_owner_request::Relation as OwnerRelation;
 use risingwave_pb::ddl_service::create_connection_request;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_rpc_client::MetaClient;
@@ -64,7 +66,7 @@ pub trait CatalogWriter: Send + Sync {
         owner: UserId,
     ) -> Result<()>;
 
-    async fn create_view(&self, view: PbView) -> Result<()>;
+    async fn create_view(&self, view: PbView, or_replace: bool) -> Result<()>;
 
     async fn create_materialized_view(
         &self,
@@ -81,6 +83,7 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn replace_table(
         &self,
+        source: Option<PbSource>,
         table: PbTable,
         graph: StreamFragmentGraph,
         mapping: ColIndexMapping,
@@ -97,7 +100,7 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn create_sink(&self, sink: PbSink, graph: StreamFragmentGraph) -> Result<()>;
 
-    async fn create_function(&self, function: PbFunction) -> Result<()>;
+    async fn create_function(&self, function: PbFunction, or_replace: bool) -> Result<()>;
 
     async fn create_connection(
         &self,
@@ -108,6 +111,10 @@ pub trait CatalogWriter: Send + Sync {
         connection: create_connection_request::Payload,
     ) -> Result<()>;
 
+    async fn create_secret(&self, secret: PbSecret) -> Result<()>;
+
+    async fn alter_secret(&self, secret: PbSecret) -> Result<()>;
+
     async fn drop_table(&self, source_id: Option<u32>, table_id: TableId) -> Result<()>;
 
     async fn drop_materialized_view(&self, table_id: TableId) -> Result<()>;
@@ -128,6 +135,12 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn drop_connection(&self, connection_id: u32) -> Result<()>;
 
+    async fn drop_secret(&self, secret_id: u32) -> Result<()>;
+
+    async fn drop_owned(&self, user_ids: Vec<UserId>) -> Result<()>;
+
+    async fn reassign_owned(&self, user_ids: Vec<UserId>, new_owner: UserId) -> Result<()>;
+
     async fn alter_table_name(&self, table_id: u32, table_name: &str) -> Result<()>;
 
     async fn alter_view_name(&self, view_id: u32, view_name: &str) -> Result<()>;
@@ -137,6 +150,17 @@ pub trait CatalogWriter: Send + Sync {
     async fn alter_sink_name(&self, sink_id: u32, sink_name: &str) -> Result<()>;
 
     async fn alter_source_name(&self, source_id: u32, source_name: &str) -> Result<()>;
+
+    async fn alter_table_owner(&self, table_id: u32, owner_id: u32) -> Result<()>;
+
+    async fn alter_view_owner(&self, view_id: u32, owner_id: u32) -> Result<()>;
+
+    async fn alter_source_props(
+        &self,
+        source_id: u32,
+        changed_props: HashMap<String, String>,
+        migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -190,8 +214,8 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
-    async fn create_view(&self, view: PbView) -> Result<()> {
-        let (_, version) = self.meta_client.create_view(view).await?;
+    async fn create_view(&self, view: PbView, or_replace: bool) -> Result<()> {
+        let (_, version) = self.meta_client.create_view(view, or_replace).await?;
         self.wait_version(version).await
     }
 
@@ -217,13 +241,14 @@ impl CatalogWriter for CatalogWriterImpl {
 
     async fn replace_table(
         &self,
+        source: Option<PbSource>,
         table: PbTable,
         graph: StreamFragmentGraph,
         mapping: ColIndexMapping,
     ) -> Result<()> {
         let version = self
             .meta_client
-            .replace_table(table, graph, mapping)
+            .replace_table(source, table, graph, mapping)
             .await?;
         self.wait_version(version).await
     }
@@ -238,8 +263,11 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
-    async fn create_function(&self, function: PbFunction) -> Result<()> {
-        let (_, version) = self.meta_client.create_function(function).await?;
+    async fn create_function(&self, function: PbFunction, or_replace: bool) -> Result<()> {
+        let (_, version) = self
+            .meta_client
+            .create_function(function, or_replace)
+            .await?;
         self.wait_version(version).await
     }
 
@@ -264,6 +292,16 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn create_secret(&self, secret: PbSecret) -> Result<()> {
+        let (_, version) = self.meta_client.create_secret(secret).await?;
+        self.wait_version(version).await
+    }
+
+    async fn alter_secret(&self, secret: PbSecret) -> Result<()> {
+        let version = self.meta_client.alter_secret(secret).await?;
+        self.wait_version(version).await
+    }
+
     async fn drop_table(&self, source_id: Option<u32>, table_id: TableId) -> Result<()> {
         let version = self.meta_client.drop_table(source_id, table_id).await?;
         self.wait_version(version).await
@@ -314,6 +352,21 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn drop_secret(&self, secret_id: u32) -> Result<()> {
+        let version = self.meta_client.drop_secret(secret_id).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_owned(&self, user_ids: Vec<UserId>) -> Result<()> {
+        let version = self.meta_client.drop_owned(user_ids).await?;
+        self.wait_version(version).await
+    }
+
+    async fn reassign_owned(&self, user_ids: Vec<UserId>, new_owner: UserId) -> Result<()> {
+        let version = self.meta_client.reassign_owned(user_ids, new_owner).await?;
+        self.wait_version(version).await
+    }
+
     async fn alter_table_name(&self, table_id: u32, table_name: &str) -> Result<()> {
         let version = self
             .meta_client
@@ -353,6 +406,35 @@ impl CatalogWriter for CatalogWriterImpl {
             .await?;
         self.wait_version(version).await
     }
+
+    async fn alter_table_owner(&self, table_id: u32, owner_id: u32) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_relation_owner(OwnerRelation::TableId(table_id), owner_id)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn alter_view_owner(&self, view_id: u32, owner_id: u32) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_relation_owner(OwnerRelation::ViewId(view_id), owner_id)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn alter_source_props(
+        &self,
+        source_id: u32,
+        changed_props: HashMap<String, String>,
+        migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_source_props(source_id, changed_props, migrate_offsets_timestamp_millis)
+            .await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -30,6 +30,7 @@ pub(crate) mod function_catalog;
 pub(crate) mod index_catalog;
 pub(crate) mod root_catalog;
 pub(crate) mod schema_catalog;
+pub(crate) mod secret_catalog;
 pub(crate) mod source_catalog;
 pub(crate) mod system_catalog;
 pub(crate) mod table_catalog;
@@ -41,6 +42,7 @@ pub use table_catalog::TableCatalog;
 use crate::user::UserId;
 
 pub(crate) type ConnectionId = u32;
+pub(crate) type SecretId = u32;
 pub(crate) type SourceId = u32;
 pub(crate) type SinkId = u32;
 pub(crate) type ViewId = u32;
@@ -0,0 +1,45 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::catalog::PbSecret;
+
+use crate::catalog::{OwnedByUserCatalog, SecretId};
+use crate::user::UserId;
+
+/// The catalog entry of a secret created with `CREATE SECRET`. The value of the secret is never
+/// sent to the frontend; it is only resolved on compute nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretCatalog {
+    pub id: SecretId,
+    pub name: String,
+    pub owner: UserId,
+    pub backend: String,
+}
+
+impl From<&PbSecret> for SecretCatalog {
+    fn from(prost: &PbSecret) -> Self {
+        Self {
+            id: prost.id,
+            name: prost.name.clone(),
+            owner: prost.owner,
+            backend: prost.backend.clone(),
+        }
+    }
+}
+
+impl OwnedByUserCatalog for SecretCatalog {
+    fn owner(&self) -> UserId {
+        self.owner
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+/// The view `column_privileges` identifies all privileges granted on columns, with one row
+/// per (grantee, relation, column, privilege type). A privilege granted on the whole
+/// relation is listed for each of its columns.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-column-privileges.html`]
+pub const COLUMN_PRIVILEGES_TABLE_NAME: &str = "column_privileges";
+pub const COLUMN_PRIVILEGES_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Varchar, "grantor"),
+    (DataType::Varchar, "grantee"),
+    (DataType::Varchar, "table_catalog"),
+    (DataType::Varchar, "table_schema"),
+    (DataType::Varchar, "table_name"),
+    (DataType::Varchar, "column_name"),
+    (DataType::Varchar, "privilege_type"),
+    (DataType::Varchar, "is_grantable"),
+];
@@ -12,19 +12,117 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod column_privileges;
 pub mod columns;
 pub mod tables;
 
+use std::collections::HashMap;
+
+pub use column_privileges::*;
 pub use columns::*;
 use itertools::Itertools;
 use risingwave_common::error::Result;
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::ScalarImpl;
+use risingwave_pb::user::grant_privilege::{Action, Object};
 pub use tables::*;
 
 use super::SysCatalogReaderImpl;
 
 impl SysCatalogReaderImpl {
+    pub(super) fn read_column_privileges(&self) -> Result<Vec<OwnedRow>> {
+        let catalog_reader = self.catalog_reader.read_guard();
+        let schemas = catalog_reader.iter_schemas(&self.auth_context.database)?;
+        let user_reader = self.user_info_reader.read_guard();
+        let users = user_reader.get_all_users();
+        let username_map = user_reader.get_user_name_map();
+
+        // Map relation ids to the schema name, relation name and column names of the
+        // relation, to expand privileges on the whole relation to one row per column.
+        let mut tables = HashMap::new();
+        let mut sources = HashMap::new();
+        for schema in schemas {
+            for table in schema.iter_table().chain(schema.iter_mv()) {
+                tables.insert(
+                    table.id().table_id,
+                    (
+                        schema.name(),
+                        table.name().to_string(),
+                        table
+                            .columns()
+                            .iter()
+                            .filter(|column| !column.is_hidden)
+                            .map(|column| column.name().to_string())
+                            .collect_vec(),
+                    ),
+                );
+            }
+            for source in schema.iter_source() {
+                sources.insert(
+                    source.id,
+                    (
+                        schema.name(),
+                        source.name.clone(),
+                        source
+                            .columns
+                            .iter()
+                            .filter(|column| !column.is_hidden)
+                            .map(|column| column.name().to_string())
+                            .collect_vec(),
+                    ),
+                );
+            }
+        }
+
+        let mut rows = vec![];
+        for user in &users {
+            for privilege in &user.grant_privileges {
+                let relation = match &privilege.object {
+                    Some(Object::TableId(id)) => tables.get(id),
+                    Some(Object::SourceId(id)) => sources.get(id),
+                    _ => None,
+                };
+                let Some((schema_name, relation_name, relation_columns)) = relation else {
+                    continue;
+                };
+                for ao in &privilege.action_with_opts {
+                    let privilege_type = match ao.action() {
+                        Action::Select => "SELECT",
+                        Action::Insert => "INSERT",
+                        Action::Update => "UPDATE",
+                        _ => continue,
+                    };
+                    let column_names = if ao.column_names.is_empty() {
+                        relation_columns
+                    } else {
+                        &ao.column_names
+                    };
+                    for column_name in column_names {
+                        rows.push(OwnedRow::new(vec![
+                            Some(ScalarImpl::Utf8(
+                                username_map
+                                    .get(&ao.granted_by)
+                                    .cloned()
+                                    .unwrap_or_default()
+                                    .into(),
+                            )),
+                            Some(ScalarImpl::Utf8(user.name.clone().into())),
+                            Some(ScalarImpl::Utf8(self.auth_context.database.clone().into())),
+                            Some(ScalarImpl::Utf8(schema_name.clone().into())),
+                            Some(ScalarImpl::Utf8(relation_name.clone().into())),
+                            Some(ScalarImpl::Utf8(column_name.clone().into())),
+                            Some(ScalarImpl::Utf8(privilege_type.into())),
+                            Some(ScalarImpl::Utf8(
+                                if ao.with_grant_option { "YES" } else { "NO" }.into(),
+                            )),
+                        ]));
+                    }
+                }
+            }
+        }
+        Ok(rows)
+    }
+
     pub(super) fn read_columns_info(&self) -> Result<Vec<OwnedRow>> {
         let reader = self.catalog_reader.read_guard();
         let schemas = reader.iter_schemas(&self.auth_context.database)?;
@@ -297,4 +297,12 @@ prepare_sys_catalog! {
     { RW_CATALOG, RW_META_SNAPSHOT, vec![], read_meta_snapshot await },
     { RW_CATALOG, RW_DDL_PROGRESS, vec![], read_ddl_progress await },
     { RW_CATALOG, RW_RELATION_INFO, vec![], read_relation_info await },
+    { RW_CATALOG, RW_TABLE_DISTRIBUTION, vec![0], read_table_distribution },
+    { RW_CATALOG, RW_COMPACTION_HISTORY, vec![0], read_compaction_history await },
+    { RW_CATALOG, RW_EXPR_FEATURE_GATES, vec![], read_expr_feature_gates await },
+    { INFORMATION_SCHEMA, COLUMN_PRIVILEGES, vec![], read_column_privileges },
+    { RW_CATALOG, RW_BARRIER_LATENCY, vec![], read_barrier_latency await },
+    { RW_CATALOG, RW_RECOVERY_EVENTS, vec![], read_recovery_events await },
+    { RW_CATALOG, RW_RECOVERY_LOG, vec![], read_recovery_log await },
+    { RW_CATALOG, RW_BACKFILL_PROGRESS, vec![], read_backfill_progress await },
 }
@@ -12,17 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod rw_backfill_progress;
+mod rw_barrier_latency;
+mod rw_compaction_history;
 mod rw_connections;
 mod rw_databases;
 mod rw_ddl_progress;
+mod rw_expr_feature_gates;
 mod rw_functions;
 mod rw_indexes;
 mod rw_materialized_views;
 mod rw_meta_snapshot;
+mod rw_recovery_events;
+mod rw_recovery_log;
 mod rw_relation_info;
 mod rw_schemas;
 mod rw_sinks;
 mod rw_sources;
+mod rw_table_distribution;
 mod rw_tables;
 mod rw_users;
 mod rw_views;
@@ -32,18 +39,26 @@ use risingwave_common::error::Result;
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::{ScalarImpl, Timestamp};
 use risingwave_common::util::epoch::Epoch;
+use risingwave_pb::meta::recovery_event::Kind as RecoveryEventKind;
 use risingwave_pb::user::grant_privilege::Object;
+pub use rw_backfill_progress::*;
+pub use rw_barrier_latency::*;
+pub use rw_compaction_history::*;
 pub use rw_connections::*;
 pub use rw_databases::*;
 pub use rw_ddl_progress::*;
+pub use rw_expr_feature_gates::*;
 pub use rw_functions::*;
 pub use rw_indexes::*;
 pub use rw_materialized_views::*;
 pub use rw_meta_snapshot::*;
+pub use rw_recovery_events::*;
+pub use rw_recovery_log::*;
 pub use rw_relation_info::*;
 pub use rw_schemas::*;
 pub use rw_sinks::*;
 pub use rw_sources::*;
+pub use rw_table_distribution::*;
 pub use rw_tables::*;
 pub use rw_users::*;
 pub use rw_views::*;
@@ -86,6 +101,103 @@ impl SysCatalogReaderImpl {
         Ok(meta_snapshots)
     }
 
+    pub(super) async fn read_barrier_latency(&self) -> Result<Vec<OwnedRow>> {
+        let try_get_date_time = |epoch: u64| {
+            if epoch == 0 {
+                return None;
+            }
+            let time_millis = Epoch::from(epoch).as_unix_millis();
+            Timestamp::with_secs_nsecs(
+                (time_millis / 1000) as i64,
+                (time_millis % 1000 * 1_000_000) as u32,
+            )
+            .map(ScalarImpl::Timestamp)
+            .ok()
+        };
+        let rows = self
+            .meta_client
+            .list_barrier_latency()
+            .await?
+            .into_iter()
+            .flat_map(|e| {
+                let inject_time = try_get_date_time(e.curr_epoch);
+                e.fragment_latency
+                    .into_iter()
+                    .map(|f| {
+                        OwnedRow::new(vec![
+                            Some(ScalarImpl::Int64(e.prev_epoch as i64)),
+                            Some(ScalarImpl::Int64(e.curr_epoch as i64)),
+                            inject_time.clone(),
+                            Some(ScalarImpl::Bool(e.checkpoint)),
+                            Some(ScalarImpl::Int64(e.total_latency_ms as i64)),
+                            Some(ScalarImpl::Int32(f.fragment_id as i32)),
+                            Some(ScalarImpl::Int64(f.max_collect_latency_ms as i64)),
+                            Some(ScalarImpl::Int64(f.avg_collect_latency_ms as i64)),
+                        ])
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        Ok(rows)
+    }
+
+    pub(super) async fn read_recovery_events(&self) -> Result<Vec<OwnedRow>> {
+        let rows = self
+            .meta_client
+            .list_recovery_events()
+            .await?
+            .into_iter()
+            .map(|e| {
+                let kind = match e.kind() {
+                    RecoveryEventKind::Unspecified => "UNSPECIFIED",
+                    RecoveryEventKind::Recovery => "RECOVERY",
+                    RecoveryEventKind::Quarantine => "QUARANTINE",
+                    RecoveryEventKind::Resume => "RESUME",
+                };
+                let event_time = Timestamp::with_secs_nsecs(
+                    (e.at_ms / 1000) as i64,
+                    (e.at_ms % 1000 * 1_000_000) as u32,
+                )
+                .map(ScalarImpl::Timestamp)
+                .ok();
+                OwnedRow::new(vec![
+                    event_time,
+                    Some(ScalarImpl::Utf8(kind.into())),
+                    Some(ScalarImpl::Int32(e.table_id as i32)),
+                    Some(ScalarImpl::Int64(e.failure_count as i64)),
+                    Some(ScalarImpl::Utf8(e.message.into())),
+                ])
+            })
+            .collect_vec();
+        Ok(rows)
+    }
+
+    pub(super) async fn read_recovery_log(&self) -> Result<Vec<OwnedRow>> {
+        let rows = self
+            .meta_client
+            .list_recoveries()
+            .await?
+            .into_iter()
+            .map(|r| {
+                let started_at = Timestamp::with_secs_nsecs(
+                    (r.started_at_ms / 1000) as i64,
+                    (r.started_at_ms % 1000 * 1_000_000) as u32,
+                )
+                .map(ScalarImpl::Timestamp)
+                .ok();
+                let failed_actors = r.failed_actors.iter().map(|id| id.to_string()).join(", ");
+                OwnedRow::new(vec![
+                    started_at,
+                    Some(ScalarImpl::Int64(r.duration_ms as i64)),
+                    Some(ScalarImpl::Int64(r.new_epoch as i64)),
+                    Some(ScalarImpl::Utf8(r.cause.into())),
+                    Some(ScalarImpl::Utf8(failed_actors.into())),
+                ])
+            })
+            .collect_vec();
+        Ok(rows)
+    }
+
     pub(super) async fn read_ddl_progress(&self) -> Result<Vec<OwnedRow>> {
         let ddl_grogress = self
             .meta_client
@@ -103,6 +215,114 @@ impl SysCatalogReaderImpl {
         Ok(ddl_grogress)
     }
 
+    pub(super) async fn read_backfill_progress(&self) -> Result<Vec<OwnedRow>> {
+        let backfill_progress = self
+            .meta_client
+            .list_backfill_progress()
+            .await?
+            .into_iter()
+            .map(|p| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int64(p.job_id as i64)),
+                    Some(ScalarImpl::Int32(p.fragment_id as i32)),
+                    Some(ScalarImpl::Int64(p.consumed_rows as i64)),
+                    Some(ScalarImpl::Int64(p.min_consumed_epoch as i64)),
+                    Some(ScalarImpl::Int64(p.upstream_total_key_count as i64)),
+                    Some(ScalarImpl::Int32(p.done_actors as i32)),
+                    Some(ScalarImpl::Int32(p.total_actors as i32)),
+                ])
+            })
+            .collect_vec();
+        Ok(backfill_progress)
+    }
+
+    pub(super) async fn read_compaction_history(&self) -> Result<Vec<OwnedRow>> {
+        let try_get_date_time = |time_sec: u64| {
+            if time_sec == 0 {
+                return None;
+            }
+            Timestamp::with_secs_nsecs(time_sec as i64, 0)
+                .map(ScalarImpl::Timestamp)
+                .ok()
+        };
+        let task_summaries = self
+            .meta_client
+            .list_compact_task_history()
+            .await?
+            .into_iter()
+            .map(|s| {
+                let write_amplification = if s.input_file_size == 0 {
+                    None
+                } else {
+                    Some(ScalarImpl::Float64(
+                        (s.output_file_size as f64 / s.input_file_size as f64).into(),
+                    ))
+                };
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int64(s.task_id as i64)),
+                    Some(ScalarImpl::Int64(s.compaction_group_id as i64)),
+                    Some(ScalarImpl::Utf8(s.task_type().as_str_name().into())),
+                    Some(ScalarImpl::Utf8(s.task_status().as_str_name().into())),
+                    Some(ScalarImpl::Int32(s.target_level as i32)),
+                    Some(ScalarImpl::Int64(s.input_file_count as i64)),
+                    Some(ScalarImpl::Int64(s.input_file_size as i64)),
+                    Some(ScalarImpl::Int64(s.output_file_count as i64)),
+                    Some(ScalarImpl::Int64(s.output_file_size as i64)),
+                    write_amplification,
+                    try_get_date_time(s.start_time_sec),
+                    try_get_date_time(s.end_time_sec),
+                ])
+            })
+            .collect_vec();
+        Ok(task_summaries)
+    }
+
+    pub(super) async fn read_expr_feature_gates(&self) -> Result<Vec<OwnedRow>> {
+        let mut relations = Vec::new();
+        {
+            let reader = self.catalog_reader.read_guard();
+            let schemas = reader.get_all_schema_names(&self.auth_context.database)?;
+            for schema in &schemas {
+                let schema_catalog =
+                    reader.get_schema_by_name(&self.auth_context.database, schema)?;
+
+                schema_catalog.iter_mv().for_each(|t| {
+                    relations.push((schema.clone(), t.name.clone(), t.id.table_id));
+                });
+
+                schema_catalog.iter_table().for_each(|t| {
+                    relations.push((schema.clone(), t.name.clone(), t.id.table_id));
+                });
+
+                schema_catalog.iter_sink().for_each(|t| {
+                    relations.push((schema.clone(), t.name.clone(), t.id.sink_id));
+                });
+
+                schema_catalog.iter_index().for_each(|t| {
+                    relations.push((schema.clone(), t.name.clone(), t.index_table.id.table_id));
+                });
+            }
+        }
+
+        let table_ids = relations.iter().map(|(_, _, id)| *id).collect_vec();
+        let table_fragments = self.meta_client.list_table_fragments(&table_ids).await?;
+        let mut rows = Vec::new();
+        for (schema, name, relation_id) in relations {
+            if let Some(fragments) = table_fragments.get(&relation_id) {
+                for gate in &fragments.get_env().unwrap().expr_feature_gates {
+                    rows.push(OwnedRow::new(vec![
+                        Some(ScalarImpl::Utf8(schema.clone().into())),
+                        Some(ScalarImpl::Utf8(name.clone().into())),
+                        Some(ScalarImpl::Int32(relation_id as i32)),
+                        Some(ScalarImpl::Utf8(gate.clone().into())),
+                    ]));
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
     pub(super) async fn read_relation_info(&self) -> Result<Vec<OwnedRow>> {
         let mut table_ids = Vec::new();
         {
@@ -230,6 +450,37 @@ impl SysCatalogReaderImpl {
         Ok(rows)
     }
 
+    pub(super) fn read_table_distribution(&self) -> Result<Vec<OwnedRow>> {
+        let reader = self.catalog_reader.read_guard();
+        let schemas = reader.iter_schemas(&self.auth_context.database)?;
+
+        let mut rows = Vec::new();
+        for schema in schemas {
+            for table in schema.iter_valid_table() {
+                let distribution_keys = table
+                    .distribution_key()
+                    .iter()
+                    .map(|&idx| table.columns[idx].name().to_owned())
+                    .join(", ");
+                // The mapping may be absent transiently, e.g. right after the
+                // table is created or while the cluster is rescheduling.
+                let vnode_mapping = self
+                    .worker_node_manager
+                    .get_streaming_fragment_mapping(&table.fragment_id)
+                    .ok()
+                    .map(|mapping| json!(mapping.to_expanded()).to_string());
+                rows.push(OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(table.id.table_id as i32)),
+                    Some(ScalarImpl::Utf8(table.name().into())),
+                    Some(ScalarImpl::Int32(table.fragment_id as i32)),
+                    Some(ScalarImpl::Utf8(distribution_keys.into())),
+                    vnode_mapping.map(|m| ScalarImpl::Utf8(m.into())),
+                ]));
+            }
+        }
+        Ok(rows)
+    }
+
     pub(super) fn read_rw_database_info(&self) -> Result<Vec<OwnedRow>> {
         let reader = self.catalog_reader.read_guard();
         let user_reader = self.user_info_reader.read_guard();
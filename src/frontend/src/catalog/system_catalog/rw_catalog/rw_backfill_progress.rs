@@ -0,0 +1,29 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_BACKFILL_PROGRESS_TABLE_NAME: &str = "rw_backfill_progress";
+
+pub const RW_BACKFILL_PROGRESS_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int64, "job_id"),
+    (DataType::Int32, "fragment_id"),
+    (DataType::Int64, "consumed_rows"),
+    (DataType::Int64, "min_consumed_epoch"),
+    (DataType::Int64, "upstream_total_key_count"),
+    (DataType::Int32, "done_actors"),
+    (DataType::Int32, "total_actors"),
+];
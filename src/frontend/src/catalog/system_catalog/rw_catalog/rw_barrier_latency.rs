@@ -0,0 +1,32 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_BARRIER_LATENCY_TABLE_NAME: &str = "rw_barrier_latency";
+
+/// One row per (barrier, fragment), for the barriers still kept in the in-memory latency trace
+/// on the meta node.
+pub const RW_BARRIER_LATENCY_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int64, "prev_epoch"),
+    (DataType::Int64, "curr_epoch"),
+    (DataType::Timestamp, "inject_time"),
+    (DataType::Boolean, "checkpoint"),
+    (DataType::Int64, "total_latency_ms"),
+    (DataType::Int32, "fragment_id"),
+    (DataType::Int64, "max_collect_latency_ms"),
+    (DataType::Int64, "avg_collect_latency_ms"),
+];
@@ -0,0 +1,37 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_COMPACTION_HISTORY_TABLE_NAME: &str = "rw_compaction_history";
+
+pub const RW_COMPACTION_HISTORY_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int64, "task_id"),
+    (DataType::Int64, "compaction_group_id"),
+    (DataType::Varchar, "task_type"),
+    (DataType::Varchar, "task_status"),
+    (DataType::Int32, "target_level"),
+    (DataType::Int64, "input_file_count"),
+    (DataType::Int64, "input_file_size"),
+    (DataType::Int64, "output_file_count"),
+    (DataType::Int64, "output_file_size"),
+    // output size over input size; NULL if the input size is 0
+    (DataType::Float64, "write_amplification"),
+    // human-readable timestamp of the task start; NULL if unknown
+    (DataType::Timestamp, "start_time"),
+    // human-readable timestamp of the task result report
+    (DataType::Timestamp, "end_time"),
+];
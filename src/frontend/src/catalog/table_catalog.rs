@@ -312,6 +312,7 @@ impl TableCatalog {
             read_prefix_len_hint: self.read_prefix_len_hint,
             watermark_columns: self.watermark_columns.clone(),
             versioned: self.version.is_some(),
+            dist_key_in_pk: self.dist_key_in_pk.clone(),
         }
     }
 
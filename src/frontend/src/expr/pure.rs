@@ -63,6 +63,7 @@ impl ExprVisitor<bool> for ImpureAnalyzer {
             | expr_node::Type::ToTimestamp
             | expr_node::Type::AtTimeZone
             | expr_node::Type::DateTrunc
+            | expr_node::Type::DateBin
             | expr_node::Type::ToTimestamp1
             | expr_node::Type::CastWithTimeZone
             | expr_node::Type::AddWithTimeZone
@@ -100,6 +101,8 @@ impl ExprVisitor<bool> for ImpureAnalyzer {
             | expr_node::Type::BitLength
             | expr_node::Type::Overlay
             | expr_node::Type::RegexpMatch
+            | expr_node::Type::RegexpReplace
+            | expr_node::Type::RegexpCount
             | expr_node::Type::Pow
             | expr_node::Type::Exp
             | expr_node::Type::Ln
@@ -158,6 +161,16 @@ impl ExprVisitor<bool> for ImpureAnalyzer {
             | expr_node::Type::JsonbAccessStr
             | expr_node::Type::JsonbTypeof
             | expr_node::Type::JsonbArrayLength
+            | expr_node::Type::JsonbContains
+            | expr_node::Type::JsonbContained
+            | expr_node::Type::JsonbExists
+            | expr_node::Type::ToJsonb
+            | expr_node::Type::JsonbBuildArray
+            | expr_node::Type::JsonbBuildObject
+            | expr_node::Type::JsonbCat
+            | expr_node::Type::JsonbSet
+            | expr_node::Type::JsonbInsert
+            | expr_node::Type::JsonbDelete
             | expr_node::Type::Sind
             | expr_node::Type::Cosd
             | expr_node::Type::Cotd
@@ -176,6 +189,9 @@ impl ExprVisitor<bool> for ImpureAnalyzer {
             | expr_node::Type::Sha256
             | expr_node::Type::Sha384
             | expr_node::Type::Sha512
+            | expr_node::Type::MaskEmail
+            | expr_node::Type::MaskSsn
+            | expr_node::Type::HashPii
             | expr_node::Type::Tand
             | expr_node::Type::ArrayPositions
             | expr_node::Type::StringToArray =>
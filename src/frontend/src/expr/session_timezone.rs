@@ -194,6 +194,17 @@ impl SessionTimezone {
                 }
                 None
             }
+            // `date_trunc(field, timestamptz)`
+            // => `date_trunc(field, timestamptz, zone_string)`
+            ExprType::DateTrunc => {
+                if !(inputs.len() == 2 && matches!(inputs[1].return_type(), DataType::Timestamptz))
+                {
+                    return None;
+                }
+                let mut inputs = inputs.clone();
+                inputs.push(ExprImpl::literal_varchar(self.timezone()));
+                Some(FunctionCall::new(func_type, inputs).unwrap().into())
+            }
             _ => None,
         }
     }
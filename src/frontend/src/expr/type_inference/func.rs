@@ -334,6 +334,25 @@ fn infer_type_for_special(
                 .try_collect()?;
             Ok(Some(DataType::Varchar))
         }
+        ExprType::ToJsonb => {
+            ensure_arity!("to_jsonb", | inputs | == 1);
+            cast_untyped_to_varchar(inputs)?;
+            Ok(Some(DataType::Jsonb))
+        }
+        ExprType::JsonbBuildArray => {
+            cast_untyped_to_varchar(inputs)?;
+            Ok(Some(DataType::Jsonb))
+        }
+        ExprType::JsonbBuildObject => {
+            if inputs.len().is_odd() {
+                return Err(ErrorCode::BindError(
+                    "argument list must have even number of elements".into(),
+                )
+                .into());
+            }
+            cast_untyped_to_varchar(inputs)?;
+            Ok(Some(DataType::Jsonb))
+        }
         ExprType::ConcatOp => {
             let inputs_owned = std::mem::take(inputs);
             *inputs = inputs_owned
@@ -461,6 +480,28 @@ fn infer_type_for_special(
             }
             Ok(Some(DataType::List(Box::new(DataType::Varchar))))
         }
+        ExprType::RegexpReplace => {
+            ensure_arity!("regexp_replace", 3 <= | inputs | <= 4);
+            if inputs.len() == 4 {
+                check_regexp_flag(&inputs[3], "regexp_replace", true)?;
+            }
+            Ok(Some(DataType::Varchar))
+        }
+        ExprType::RegexpCount => {
+            ensure_arity!("regexp_count", 2 <= | inputs | <= 4);
+            if inputs.len() >= 3 {
+                inputs[2].cast_implicit_mut(DataType::Int32).map_err(|_| {
+                    ErrorCode::BindError(format!(
+                        "start in regexp_count must be castable to integer, but got {}",
+                        inputs[2].return_type()
+                    ))
+                })?;
+            }
+            if inputs.len() == 4 {
+                check_regexp_flag(&inputs[3], "regexp_count", false)?;
+            }
+            Ok(Some(DataType::Int32))
+        }
         ExprType::ArrayCat => {
             ensure_arity!("array_cat", | inputs | == 2);
             let left_type = (!inputs[0].is_untyped()).then(|| inputs[0].return_type());
@@ -641,6 +682,56 @@ fn infer_type_for_special(
     }
 }
 
+/// Casts all untyped inputs to `varchar`, for functions accepting arguments of any type. An
+/// untyped literal passed to such a function is treated as text, as `unknown` would be in
+/// PostgreSQL when no better type is available.
+fn cast_untyped_to_varchar(inputs: &mut [ExprImpl]) -> Result<()> {
+    for input in inputs {
+        if input.is_untyped() {
+            input.cast_implicit_mut(DataType::Varchar)?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the `flags` argument of a regexp function is a literal string consisting of valid
+/// option characters, since the backend only compiles constant patterns and flags.
+fn check_regexp_flag(flag: &ExprImpl, func: &str, allow_global: bool) -> Result<()> {
+    let ExprImpl::Literal(flag) = flag else {
+        return Err(
+            ErrorCode::BindError(format!("flags in {func} must be a literal string")).into(),
+        );
+    };
+    match flag.get_data() {
+        Some(ScalarImpl::Utf8(flag)) => {
+            for c in flag.chars() {
+                if c == 'g' && !allow_global {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "{func}() does not support the \"global\" option."
+                    ))
+                    .into());
+                }
+                if !"icg".contains(c) {
+                    return Err(ErrorCode::NotImplemented(
+                        format!("invalid regular expression option: \"{c}\""),
+                        None.into(),
+                    )
+                    .into());
+                }
+            }
+        }
+        Some(_) => {
+            return Err(
+                ErrorCode::BindError(format!("flags in {func} must be a literal string")).into(),
+            );
+        }
+        None => {
+            // flag is NULL. Will return NULL.
+        }
+    }
+    Ok(())
+}
+
 /// From all available functions in `sig_map`, find and return the best matching `FuncSign` for the
 /// provided `func_type` and `inputs`. This not only support exact function signature match, but can
 /// also match `substr(varchar, smallint)` or even `substr(varchar, unknown)` to `substr(varchar,
@@ -879,7 +970,9 @@ fn narrow_category<'a>(
                 .all(|(formal, category)| {
                     // category.is_none() means the actual argument is non-null and skipped category
                     // selection.
-                    let Some(selected) = category else { return true };
+                    let Some(selected) = category else {
+                        return true;
+                    };
                     *formal == *selected
                         || !is_preferred(*selected) && implicit_ok(*formal, *selected, false)
                 })
@@ -0,0 +1,639 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An Arrow Flight SQL endpoint on the frontend.
+//!
+//! Analytics clients (e.g. Python via ADBC) can fetch large batch query results
+//! in columnar form instead of going through the row-oriented pgwire protocol,
+//! and bulk-load data into tables by streaming Arrow record batches through
+//! `do_put`. Authentication reuses the frontend user catalog: the Flight
+//! handshake takes the same user name and password as pgwire, and statements
+//! run through the regular binder so all privilege checks still apply.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest, CommandGetCatalogs,
+    CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys, CommandGetImportedKeys,
+    CommandGetPrimaryKeys, CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
+    CommandStatementUpdate, ProstMessageExt, SqlInfo, TicketStatementQuery,
+};
+use arrow_flight::utils::{flight_data_from_arrow_batch, flight_data_to_arrow_batch};
+use arrow_flight::{
+    Action, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, IpcMessage, SchemaAsIpc, Ticket,
+};
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::{Field as ArrowField, Schema as ArrowSchema};
+use futures::{Stream, StreamExt, TryStreamExt};
+use parking_lot::Mutex;
+use pgwire::pg_server::{Session, SessionManager, UserAuthenticator};
+use pgwire::scram::ScramSecret;
+use pgwire::types::Format as PgFormat;
+use prost::Message;
+use rand::Rng;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::Schema;
+use risingwave_common::row::Row;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::ScalarRefImpl;
+use risingwave_sqlparser::ast::{
+    Expr, Ident, ObjectName, Query, SetExpr, Statement, Value, Values,
+};
+use risingwave_sqlparser::parser::Parser;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::handler::flush::do_flush;
+use crate::handler::query::{
+    execute_chunk_stream, gen_batch_plan_by_statement, gen_batch_plan_fragmenter,
+    BatchPlanFragmenterResult,
+};
+use crate::handler::HandlerArgs;
+use crate::optimizer::OptimizerContext;
+use crate::session::{SessionImpl, SessionManagerImpl};
+use crate::user::user_authentication::{md5_hash, md5_hash_with_salt};
+
+/// How long a bearer token stays valid without being used. Flight connections have no explicit
+/// close we could hook into, so idle sessions are dropped after this interval and the client has
+/// to handshake again.
+const SESSION_TTL: Duration = Duration::from_secs(3600);
+
+/// The Arrow Flight SQL service on the frontend. Queries run over `do_get`,
+/// bulk ingest into tables over `do_put`; other DML and DDL are rejected.
+pub struct FlightSqlServiceImpl {
+    session_mgr: Arc<SessionManagerImpl>,
+    /// Bearer token issued at handshake -> authenticated session and when it was last used.
+    sessions: Mutex<HashMap<String, (Arc<SessionImpl>, Instant)>>,
+}
+
+impl FlightSqlServiceImpl {
+    pub fn new(session_mgr: Arc<SessionManagerImpl>) -> Self {
+        Self {
+            session_mgr,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn into_server(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Get the session bound to the bearer token carried in the request metadata, refreshing
+    /// its expiry. Expired tokens are rejected and evicted.
+    fn session(&self, metadata: &MetadataMap) -> Result<Arc<SessionImpl>, Status> {
+        let token = bearer_token(metadata)?;
+        let mut sessions = self.sessions.lock();
+        match sessions.get_mut(&token) {
+            Some((session, last_used)) if last_used.elapsed() < SESSION_TTL => {
+                *last_used = Instant::now();
+                Ok(session.clone())
+            }
+            Some(_) => {
+                sessions.remove(&token);
+                Err(Status::unauthenticated("invalid or expired token"))
+            }
+            None => Err(Status::unauthenticated("invalid or expired token")),
+        }
+    }
+
+    /// Plan the query with the session, so binding performs the same privilege
+    /// checks as pgwire. Returns the result schema and the plan fragmenter result.
+    fn plan_query(
+        session: &Arc<SessionImpl>,
+        sql: &str,
+    ) -> Result<(Schema, BatchPlanFragmenterResult), Status> {
+        let stmts = Parser::parse_sql(sql)
+            .map_err(|e| Status::invalid_argument(format!("failed to parse query: {}", e)))?;
+        let [stmt]: [_; 1] = stmts
+            .try_into()
+            .map_err(|_| Status::invalid_argument("expected exactly one statement"))?;
+        // `gen_batch_plan_by_statement` also plans DML, but this endpoint only advertises
+        // queries: writes must go through `do_put` (or pgwire).
+        if !matches!(stmt, Statement::Query(_)) {
+            return Err(Status::invalid_argument(
+                "only queries are supported here, use `do_put` or pgwire for DML",
+            ));
+        }
+        let handler_args = HandlerArgs::new(session.clone(), &stmt, sql)
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let plan_result = gen_batch_plan_by_statement(session, context.into(), stmt)
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let plan_fragmenter_result = gen_batch_plan_fragmenter(session, plan_result)
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let schema = plan_fragmenter_result.schema.clone();
+        Ok((schema, plan_fragmenter_result))
+    }
+}
+
+/// Verify the plaintext password of the handshake against the stored credential.
+///
+/// Unlike pgwire, the Flight SQL handshake hands us the raw password, so we cannot go through
+/// [`UserAuthenticator::authenticate`], which expects the response to the salted-MD5 or SCRAM
+/// challenge sent by the pgwire protocol handler. Instead, apply the stored encryption to the
+/// raw password and compare the results.
+fn verify_password(session: &SessionImpl, user_name: &str, password: &str) -> bool {
+    match session.user_authenticator() {
+        UserAuthenticator::None => true,
+        UserAuthenticator::ClearText(text) => password.as_bytes() == text.as_slice(),
+        UserAuthenticator::Md5WithSalt {
+            encrypted_password,
+            salt,
+        } => &md5_hash_with_salt(&md5_hash(user_name, password), salt) == encrypted_password,
+        UserAuthenticator::ScramSha256(stored_secret) => ScramSecret::parse(stored_secret)
+            .map_or(false, |secret| secret.verify_password(password)),
+    }
+}
+
+/// Extract the bearer token from the `authorization` metadata.
+fn bearer_token(metadata: &MetadataMap) -> Result<String, Status> {
+    let authorization = metadata
+        .get("authorization")
+        .ok_or_else(|| Status::unauthenticated("no authorization header"))?
+        .to_str()
+        .map_err(|_| Status::unauthenticated("invalid authorization header"))?;
+    authorization
+        .strip_prefix("Bearer ")
+        .map(|t| t.to_string())
+        .ok_or_else(|| Status::unauthenticated("expected bearer authorization"))
+}
+
+/// Convert the RisingWave result schema to an Arrow schema, keeping field names.
+fn to_arrow_schema(schema: &Schema) -> ArrowSchema {
+    ArrowSchema::new(
+        schema
+            .fields()
+            .iter()
+            .map(|f| ArrowField::new(f.name.clone(), (&f.data_type).into(), true))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Convert a [`DataChunk`] to a record batch carrying the proper field names.
+fn chunk_to_record_batch(
+    chunk: DataChunk,
+    schema: &Arc<ArrowSchema>,
+) -> Result<arrow_array::RecordBatch, Status> {
+    let batch = arrow_array::RecordBatch::from(&chunk.compact());
+    arrow_array::RecordBatch::try_new(schema.clone(), batch.columns().to_vec())
+        .map_err(|e| Status::internal(format!("convert chunk to record batch: {}", e)))
+}
+
+/// Build an `INSERT INTO t (cols) VALUES ...` statement carrying all rows of
+/// the chunk as literals, to be bound and executed by the regular DML path.
+fn build_insert_statement(
+    table_name: ObjectName,
+    columns: Vec<Ident>,
+    chunk: &DataChunk,
+) -> Statement {
+    let rows = (0..chunk.capacity())
+        .filter_map(|i| {
+            let (row, visible) = chunk.row_at(i);
+            visible.then(|| row.iter().map(datum_to_literal).collect())
+        })
+        .collect();
+    Statement::Insert {
+        table_name,
+        columns,
+        source: Box::new(Query {
+            with: None,
+            body: SetExpr::Values(Values(rows)),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+            fetch: None,
+        }),
+        returning: vec![],
+    }
+}
+
+fn datum_to_literal(datum: Option<ScalarRefImpl<'_>>) -> Expr {
+    let value = match datum {
+        None => Value::Null,
+        Some(ScalarRefImpl::Bool(b)) => Value::Boolean(b),
+        Some(
+            s @ (ScalarRefImpl::Int16(_)
+            | ScalarRefImpl::Int32(_)
+            | ScalarRefImpl::Int64(_)
+            | ScalarRefImpl::Float32(_)
+            | ScalarRefImpl::Float64(_)
+            | ScalarRefImpl::Decimal(_)),
+        ) => Value::Number(s.to_text()),
+        Some(ScalarRefImpl::Utf8(s)) => Value::SingleQuotedString(s.to_string()),
+        // Other types (dates, times, intervals, ...) round-trip through their
+        // text form and are cast back to the column type by the insert binder.
+        Some(s) => Value::SingleQuotedString(s.to_text()),
+    };
+    Expr::Value(value)
+}
+
+#[async_trait::async_trait]
+impl FlightSqlService for FlightSqlServiceImpl {
+    type FlightService = Self;
+
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        // Flight SQL clients send `authorization: Basic base64(user:password)`.
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .ok_or_else(|| Status::unauthenticated("no authorization header"))?
+            .to_str()
+            .map_err(|_| Status::unauthenticated("invalid authorization header"))?;
+        let basic = authorization
+            .strip_prefix("Basic ")
+            .ok_or_else(|| Status::unauthenticated("expected basic authorization"))?;
+        let bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, basic)
+            .map_err(|_| Status::unauthenticated("invalid base64 in authorization"))?;
+        let credential = String::from_utf8(bytes)
+            .map_err(|_| Status::unauthenticated("invalid utf8 in authorization"))?;
+        let (user_name, password) = credential
+            .split_once(':')
+            .ok_or_else(|| Status::unauthenticated("expected user:password"))?;
+        // The database is not part of the handshake; default to `dev` like psql.
+        let database = "dev";
+
+        let session = self
+            .session_mgr
+            .connect(database, user_name)
+            .map_err(|e| Status::unauthenticated(e.to_string()))?;
+        if !verify_password(&session, user_name, password) {
+            return Err(Status::unauthenticated("password authentication failed"));
+        }
+
+        let token = format!("{:x}", rand::thread_rng().gen::<u128>());
+        {
+            let mut sessions = self.sessions.lock();
+            // The handshake is the only place sessions are inserted, so sweeping expired ones
+            // here is enough to keep the map from growing unboundedly.
+            sessions.retain(|_, (_, last_used)| last_used.elapsed() < SESSION_TTL);
+            sessions.insert(token.clone(), (session, Instant::now()));
+        }
+
+        let result = HandshakeResponse {
+            protocol_version: 0,
+            payload: token.clone().into_bytes().into(),
+        };
+        let output = futures::stream::iter(vec![Ok(result)]);
+        let mut response = Response::new(Box::pin(output) as _);
+        response.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let session = self.session(request.metadata())?;
+        let (schema, _) = Self::plan_query(&session, &query.query)?;
+
+        let arrow_schema = to_arrow_schema(&schema);
+        let IpcMessage(schema_bytes) = SchemaAsIpc::new(&arrow_schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e: arrow_schema::ArrowError| Status::internal(e.to_string()))?;
+
+        // The ticket carries the SQL itself; the session is identified by the
+        // bearer token when the client comes back with `do_get`.
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.clone().into_bytes().into(),
+        };
+        let endpoint = FlightEndpoint {
+            ticket: Some(Ticket {
+                ticket: ticket.as_any().encode_to_vec().into(),
+            }),
+            // No location: fetch from the same connection.
+            location: vec![],
+        };
+        let info = FlightInfo {
+            schema: schema_bytes,
+            flight_descriptor: Some(request.into_inner()),
+            endpoint: vec![endpoint],
+            total_records: -1,
+            total_bytes: -1,
+        };
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(request.metadata())?;
+        let sql = String::from_utf8(ticket.statement_handle.to_vec())
+            .map_err(|_| Status::invalid_argument("invalid statement handle"))?;
+        let (schema, plan_fragmenter_result) = Self::plan_query(&session, &sql)?;
+        let (_, chunk_stream) = execute_chunk_stream(session, plan_fragmenter_result)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let arrow_schema = Arc::new(to_arrow_schema(&schema));
+        let options = IpcWriteOptions::default();
+        let schema_flight_data: FlightData = SchemaAsIpc::new(&arrow_schema, &options).into();
+
+        let batch_stream = chunk_stream
+            .map_err(|e| Status::internal(e.to_string()))
+            .map(move |chunk| {
+                let (_dicts, batch) = flight_data_from_arrow_batch(
+                    &chunk_to_record_batch(chunk?, &arrow_schema)?,
+                    &options,
+                );
+                Ok(batch)
+            });
+        let output = futures::stream::once(async { Ok(schema_flight_data) }).chain(batch_stream);
+        Ok(Response::new(Box::pin(output)))
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("prepared statements not supported"))
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("catalog metadata not supported"))
+    }
+
+    async fn get_flight_info_schemas(
+        &self,
+        _query: CommandGetDbSchemas,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("schema metadata not supported"))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        _query: CommandGetTables,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("table metadata not supported"))
+    }
+
+    async fn get_flight_info_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("table type metadata not supported"))
+    }
+
+    async fn get_flight_info_sql_info(
+        &self,
+        _query: CommandGetSqlInfo,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("sql info not supported"))
+    }
+
+    async fn get_flight_info_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("primary key metadata not supported"))
+    }
+
+    async fn get_flight_info_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("exported key metadata not supported"))
+    }
+
+    async fn get_flight_info_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("imported key metadata not supported"))
+    }
+
+    async fn get_flight_info_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented(
+            "cross reference metadata not supported",
+        ))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("prepared statements not supported"))
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("catalog metadata not supported"))
+    }
+
+    async fn do_get_schemas(
+        &self,
+        _query: CommandGetDbSchemas,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("schema metadata not supported"))
+    }
+
+    async fn do_get_tables(
+        &self,
+        _query: CommandGetTables,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("table metadata not supported"))
+    }
+
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("table type metadata not supported"))
+    }
+
+    async fn do_get_sql_info(
+        &self,
+        _query: CommandGetSqlInfo,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("sql info not supported"))
+    }
+
+    async fn do_get_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("primary key metadata not supported"))
+    }
+
+    async fn do_get_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("exported key metadata not supported"))
+    }
+
+    async fn do_get_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("imported key metadata not supported"))
+    }
+
+    async fn do_get_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented(
+            "cross reference metadata not supported",
+        ))
+    }
+
+    /// Bulk ingest: the command is a bare `INSERT INTO t (cols)` statement and the
+    /// rows come from the Arrow IPC stream. Each record batch is applied as one
+    /// DML statement and flushed before the next batch is pulled, so gRPC flow
+    /// control provides end-to-end backpressure and every consumed batch has been
+    /// committed in an epoch.
+    async fn do_put_statement_update(
+        &self,
+        ticket: CommandStatementUpdate,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<i64, Status> {
+        let session = self.session(request.metadata())?;
+
+        let stmts = Parser::parse_sql(&ticket.query)
+            .map_err(|e| Status::invalid_argument(format!("failed to parse statement: {}", e)))?;
+        let [stmt]: [_; 1] = stmts
+            .try_into()
+            .map_err(|_| Status::invalid_argument("expected exactly one statement"))?;
+        let Statement::Insert {
+            table_name,
+            columns,
+            ..
+        } = stmt
+        else {
+            return Err(Status::invalid_argument(
+                "only `INSERT INTO t (cols)` is supported for bulk ingest",
+            ));
+        };
+
+        let mut stream = request.into_inner();
+        // The first message of a flight data stream carries the schema.
+        let flight_data = stream
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("empty flight data stream"))?;
+        let schema = Arc::new(
+            ArrowSchema::try_from(&flight_data)
+                .map_err(|e| Status::invalid_argument(format!("invalid schema message: {}", e)))?,
+        );
+
+        let dictionaries_by_id = HashMap::new();
+        let mut total_rows: i64 = 0;
+        while let Some(flight_data) = stream.message().await? {
+            let batch =
+                flight_data_to_arrow_batch(&flight_data, schema.clone(), &dictionaries_by_id)
+                    .map_err(|e| {
+                        Status::invalid_argument(format!("invalid record batch: {}", e))
+                    })?;
+            let chunk = DataChunk::try_from(&batch)
+                .map_err(|e| Status::invalid_argument(format!("unsupported batch: {}", e)))?;
+            let num_rows = chunk.cardinality();
+
+            let insert = build_insert_statement(table_name.clone(), columns.clone(), &chunk);
+            session
+                .clone()
+                .run_one_query(insert, PgFormat::Text)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            // Make the batch durable before pulling the next one, giving
+            // per-batch epoch acknowledgment.
+            do_flush(&session)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            total_rows += num_rows as i64;
+        }
+        Ok(total_rows)
+    }
+
+    async fn do_put_prepared_statement_query(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        Err(Status::unimplemented("prepared statements not supported"))
+    }
+
+    async fn do_put_prepared_statement_update(
+        &self,
+        _query: CommandPreparedStatementUpdate,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<i64, Status> {
+        Err(Status::unimplemented(
+            "the flight sql endpoint is read-only, use pgwire for DML",
+        ))
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        _query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<arrow_flight::sql::ActionCreatePreparedStatementResult, Status> {
+        Err(Status::unimplemented("prepared statements not supported"))
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        _query: ActionClosePreparedStatementRequest,
+        _request: Request<Action>,
+    ) {
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
@@ -0,0 +1,65 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::ObjectName;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::Binder;
+
+/// Handle `ALTER MATERIALIZED VIEW <name> SUSPEND/RESUME` by pausing or resuming the streaming
+/// job of the materialized view, leaving the rest of the streaming graph running.
+pub async fn handle_suspend_mv(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    suspend: bool,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        if table.table_type != TableType::MaterializedView {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{table_name}\" is not a materialized view",
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id
+    };
+
+    let meta_client = session.env().meta_client();
+    if suspend {
+        meta_client.pause_streaming_job(table_id.table_id).await?;
+    } else {
+        meta_client.resume_streaming_job(table_id.table_id).await?;
+    }
+
+    Ok(PgResponse::empty_result(
+        StatementType::ALTER_MATERIALIZED_VIEW,
+    ))
+}
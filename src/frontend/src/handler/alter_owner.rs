@@ -0,0 +1,146 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{Ident, ObjectName};
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::catalog::CatalogError;
+use crate::session::SessionImpl;
+use crate::Binder;
+
+/// Resolve the id of the new owner by its name.
+fn get_new_owner_id(session: &SessionImpl, new_owner_name: &Ident) -> Result<u32> {
+    let new_owner_name = new_owner_name.real_value();
+    let user_reader = session.env().user_info_reader().read_guard();
+    user_reader
+        .get_user_by_name(&new_owner_name)
+        .map(|u| u.id)
+        .ok_or_else(|| CatalogError::NotFound("user", new_owner_name).into())
+}
+
+pub async fn handle_alter_table_owner(
+    handler_args: HandlerArgs,
+    table_type: TableType,
+    table_name: ObjectName,
+    new_owner_name: Ident,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+    let new_owner_id = get_new_owner_id(&session, &new_owner_name)?;
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        if table_type != table.table_type {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{table_name}\" is not a {}",
+                table_type.to_prost().as_str_name()
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .alter_table_owner(table_id.table_id, new_owner_id)
+        .await?;
+
+    let stmt_type = match table_type {
+        TableType::Table => StatementType::ALTER_TABLE,
+        TableType::MaterializedView => StatementType::ALTER_MATERIALIZED_VIEW,
+        _ => unreachable!(),
+    };
+    Ok(PgResponse::empty_result(stmt_type))
+}
+
+pub async fn handle_alter_view_owner(
+    handler_args: HandlerArgs,
+    view_name: ObjectName,
+    new_owner_name: Ident,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_view_name) =
+        Binder::resolve_schema_qualified_name(db_name, view_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+    let new_owner_id = get_new_owner_id(&session, &new_owner_name)?;
+
+    let view_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (view, schema_name) = reader.get_view_by_name(db_name, schema_path, &real_view_name)?;
+        session.check_privilege_for_drop_alter(schema_name, &**view)?;
+        view.id
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .alter_view_owner(view_id, new_owner_id)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_VIEW))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use crate::catalog::root_catalog::SchemaPath;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_alter_table_owner_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+
+        frontend.run_sql("create table t (i int);").await.unwrap();
+        frontend
+            .run_sql("create user u with nocreatedb password 'p';")
+            .await
+            .unwrap();
+
+        let new_owner_id = {
+            let user_reader = session.env().user_info_reader().read_guard();
+            user_reader.get_user_by_name("u").unwrap().id
+        };
+
+        frontend.run_sql("alter table t owner to u;").await.unwrap();
+
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let altered_owner = catalog_reader
+            .get_table_by_name(DEFAULT_DATABASE_NAME, schema_path, "t")
+            .unwrap()
+            .0
+            .owner;
+        assert_eq!(altered_owner, new_owner_id);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::ObjectName;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::Binder;
+
+/// Handle `ALTER TABLE/MATERIALIZED VIEW <name> SET PARALLELISM <n>` by rescheduling the
+/// streaming job of the relation to the given parallelism.
+pub async fn handle_alter_table_parallelism(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    table_type: TableType,
+    parallelism: u64,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        if table_type != table.table_type {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{table_name}\" is not a {}",
+                table_type.to_prost().as_str_name()
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id
+    };
+
+    let meta_client = session.env().meta_client();
+    meta_client
+        .alter_parallelism(table_id.table_id, validate_parallelism(parallelism)?)
+        .await?;
+
+    let stmt_type = match table_type {
+        TableType::Table => StatementType::ALTER_TABLE,
+        TableType::MaterializedView => StatementType::ALTER_MATERIALIZED_VIEW,
+        _ => unreachable!(),
+    };
+    Ok(PgResponse::empty_result(stmt_type))
+}
+
+/// Handle `ALTER SINK <name> SET PARALLELISM <n>`.
+pub async fn handle_alter_sink_parallelism(
+    handler_args: HandlerArgs,
+    sink_name: ObjectName,
+    parallelism: u64,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_sink_name) =
+        Binder::resolve_schema_qualified_name(db_name, sink_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let sink_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (sink, schema_name) = reader.get_sink_by_name(db_name, schema_path, &real_sink_name)?;
+        session.check_privilege_for_drop_alter(schema_name, &**sink)?;
+        sink.id
+    };
+
+    let meta_client = session.env().meta_client();
+    meta_client
+        .alter_parallelism(sink_id.sink_id, validate_parallelism(parallelism)?)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_SINK))
+}
+
+fn validate_parallelism(parallelism: u64) -> Result<u32> {
+    if parallelism == 0 || parallelism > u32::MAX as u64 {
+        return Err(
+            ErrorCode::InvalidInputSyntax(format!("invalid parallelism: {}", parallelism)).into(),
+        );
+    }
+    Ok(parallelism as u32)
+}
@@ -0,0 +1,97 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::ErrorCode::{NotImplemented, ProtocolError};
+use risingwave_common::error::{Result, RwError};
+use risingwave_connector::secret::{SECRET_BACKEND_AWS_SECRETS_MANAGER, SECRET_BACKEND_META};
+use risingwave_pb::catalog::PbSecret;
+use risingwave_sqlparser::ast::{AlterSecretStatement, Value};
+
+use super::create_secret::SECRET_BACKEND_PROP;
+use super::RwPgResponse;
+use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+use crate::handler::HandlerArgs;
+
+/// Rotates the value of an existing secret. The new value is propagated to compute nodes through
+/// the notification manager and used the next time a connector is built, so the sources and sinks
+/// referencing the secret keep running and do not need to be recreated.
+pub async fn handle_alter_secret(
+    handler_args: HandlerArgs,
+    stmt: AlterSecretStatement,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let db_name = session.database();
+    let (schema_name, secret_name) =
+        Binder::resolve_schema_qualified_name(db_name, stmt.secret_name)?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let (secret_id, old_backend) = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (secret, schema_name) =
+            reader.get_secret_by_name(db_name, schema_path, secret_name.as_str())?;
+        session.check_privilege_for_drop_alter(schema_name, &**secret)?;
+
+        (secret.id, secret.backend.clone())
+    };
+
+    let backend = handler_args
+        .with_options
+        .get(SECRET_BACKEND_PROP)
+        .map(|s| s.to_lowercase())
+        .unwrap_or(old_backend);
+    match backend.as_str() {
+        SECRET_BACKEND_META => {}
+        SECRET_BACKEND_AWS_SECRETS_MANAGER => {
+            return Err(NotImplemented(
+                format!("secret backend \"{}\"", SECRET_BACKEND_AWS_SECRETS_MANAGER),
+                None.into(),
+            )
+            .into());
+        }
+        _ => {
+            return Err(RwError::from(ProtocolError(format!(
+                "Secret backend \"{}\" is not supported",
+                backend
+            ))));
+        }
+    }
+
+    let value = match stmt.credential {
+        Value::SingleQuotedString(s) => s.into_bytes(),
+        _ => {
+            return Err(RwError::from(ProtocolError(
+                "Secret value should be a single quoted string".to_string(),
+            )));
+        }
+    };
+
+    // Only the id, backend and value are used by the meta node; the remaining fields are kept
+    // from the stored secret.
+    let secret = PbSecret {
+        id: secret_id,
+        backend,
+        value,
+        ..Default::default()
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.alter_secret(secret).await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_SECRET))
+}
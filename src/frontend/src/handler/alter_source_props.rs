@@ -0,0 +1,152 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_connector::source::kafka::{
+    KAFKA_PROPS_BROKER_KEY, KAFKA_PROPS_BROKER_KEY_ALIAS, KAFKA_PROPS_CONSUMER_GROUP_KEY,
+    KAFKA_PROPS_CONSUMER_GROUP_KEY_ALIAS, KAFKA_PROPS_SYNC_CALL_TIMEOUT_KEY,
+};
+use risingwave_connector::source::{KAFKA_CONNECTOR, RATE_LIMIT_KEY};
+use risingwave_sqlparser::ast::{ObjectName, SqlOption};
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::handler::create_source::UPSTREAM_SOURCE_KEY;
+use crate::utils::WithOptions;
+use crate::Binder;
+
+/// The cut-over timestamp (in milliseconds) used to re-resolve the offsets of the assigned
+/// splits on the new cluster. It is consumed by the meta node and not persisted.
+const MIGRATE_TIMESTAMP_OPTION: &str = "migration.timestamp.millis";
+
+/// The connector properties that may be changed on a running source. Anything affecting the
+/// data itself (topic, startup mode, format options, ...) still requires a drop and recreate.
+const ALTERABLE_PROPS: [&str; 6] = [
+    KAFKA_PROPS_BROKER_KEY,
+    KAFKA_PROPS_BROKER_KEY_ALIAS,
+    KAFKA_PROPS_CONSUMER_GROUP_KEY,
+    KAFKA_PROPS_CONSUMER_GROUP_KEY_ALIAS,
+    KAFKA_PROPS_SYNC_CALL_TIMEOUT_KEY,
+    RATE_LIMIT_KEY,
+];
+
+/// Handle `ALTER SOURCE <name> SET (...)`, which changes selected safe connector properties of a
+/// running source without dropping it: the kafka broker addresses (e.g. to migrate to a mirrored
+/// upstream cluster), the consumer group, the sync call timeout and the `rate_limit`. A broker
+/// change may be combined with `migration.timestamp.millis` to translate the progress of the
+/// source onto the new cluster by timestamp. `rate_limit` applies to any connector and takes
+/// effect on the running source executors via a throttle barrier mutation.
+pub async fn handle_alter_source_props(
+    handler_args: HandlerArgs,
+    source_name: ObjectName,
+    changed_props: Vec<SqlOption>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_source_name) =
+        Binder::resolve_schema_qualified_name(db_name, source_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let mut changed_props = WithOptions::try_from(changed_props.as_slice())?.into_inner();
+    let migrate_offsets_timestamp_millis = changed_props
+        .remove(MIGRATE_TIMESTAMP_OPTION)
+        .map(|ts| {
+            ts.parse::<i64>().map_err(|_| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "`{}` must be an integer timestamp in milliseconds",
+                    MIGRATE_TIMESTAMP_OPTION
+                ))
+            })
+        })
+        .transpose()?;
+
+    // Only connection-level properties may be changed on a running source, the rest would
+    // require a drop and recreate.
+    if let Some(prop) = changed_props
+        .keys()
+        .find(|k| !ALTERABLE_PROPS.contains(&k.as_str()))
+    {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "`{}` cannot be altered, only `{}` are supported",
+            prop,
+            ALTERABLE_PROPS.join("`, `")
+        ))
+        .into());
+    }
+    if changed_props.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "`ALTER SOURCE ... SET` requires at least one property to be set".to_owned(),
+        )
+        .into());
+    }
+    if let Some(rate_limit) = changed_props.get(RATE_LIMIT_KEY) {
+        rate_limit.parse::<u32>().map_err(|_| {
+            ErrorCode::InvalidInputSyntax(format!(
+                "`{}` must be a non-negative integer number of rows per second",
+                RATE_LIMIT_KEY
+            ))
+        })?;
+    }
+    // `rate_limit` is connector-agnostic, the rest of the alterable properties are
+    // kafka-specific.
+    let kafka_props_changed = changed_props.keys().any(|k| k != RATE_LIMIT_KEY)
+        || migrate_offsets_timestamp_millis.is_some();
+
+    let source_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (source, schema_name) =
+            reader.get_source_by_name(db_name, schema_path, &real_source_name)?;
+
+        // For `CREATE TABLE WITH (connector = '...')`, users should call `ALTER TABLE` instead.
+        if source.associated_table_id.is_some() {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "Use `ALTER TABLE` to alter a table with connector.".to_owned(),
+            )
+            .into());
+        }
+
+        if kafka_props_changed
+            && source
+                .properties
+                .get(UPSTREAM_SOURCE_KEY)
+                .map(|s| s.as_str())
+                != Some(KAFKA_CONNECTOR)
+        {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "`ALTER SOURCE ... SET` is only supported for kafka sources, except for the \
+                 `rate_limit` property"
+                    .to_owned(),
+            )
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**source)?;
+        source.id
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .alter_source_props(
+            source_id,
+            changed_props.into_iter().collect(),
+            migrate_offsets_timestamp_millis,
+        )
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_SOURCE))
+}
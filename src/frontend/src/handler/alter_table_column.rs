@@ -17,13 +17,17 @@ use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
+use risingwave_pb::catalog::source::OptionalAssociatedTableId;
+use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::Table;
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_sqlparser::ast::{AlterTableOperation, ColumnOption, ObjectName, Statement};
 use risingwave_sqlparser::parser::Parser;
 
-use super::create_table::{gen_create_table_plan, ColumnIdGenerator};
+use super::create_table::{
+    gen_create_table_plan, gen_create_table_plan_with_source, ColumnIdGenerator,
+};
 use super::{HandlerArgs, RwPgResponse};
 use crate::catalog::root_catalog::SchemaPath;
 use crate::catalog::table_catalog::TableType;
@@ -51,13 +55,6 @@ pub async fn handle_alter_table_column(
             reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
 
         match table.table_type() {
-            // Do not allow altering a table with a connector. It should be done passively according
-            // to the messages from the connector.
-            TableType::Table if table.has_associated_source() => {
-                Err(ErrorCode::InvalidInputSyntax(format!(
-                    "cannot alter table \"{table_name}\" because it has a connector"
-                )))?
-            }
             TableType::Table => {}
 
             _ => Err(ErrorCode::InvalidInputSyntax(format!(
@@ -82,10 +79,25 @@ pub async fn handle_alter_table_column(
         .context("unable to parse original table definition")?
         .try_into()
         .unwrap();
-    let Statement::CreateTable { columns, .. } = &mut definition else {
+    let Statement::CreateTable {
+        columns,
+        source_schema,
+        ..
+    } = &mut definition
+    else {
         panic!("unexpected statement: {:?}", definition);
     };
 
+    // A table with a connector cannot be altered yet: the replacement would rebuild the source
+    // executors with fresh state tables and a fresh split assignment, losing the offsets consumed
+    // so far and re-ingesting from the connector's startup offset.
+    if source_schema.is_some() {
+        Err(ErrorCode::NotImplemented(
+            "alter a table with a connector".to_string(),
+            None.into(),
+        ))?
+    }
+
     match operation {
         AlterTableOperation::AddColumn {
             column_def: new_column,
@@ -162,6 +174,7 @@ pub async fn handle_alter_table_column(
     let Statement::CreateTable {
         columns,
         constraints,
+        source_schema,
         source_watermarks,
         append_only,
         ..
@@ -170,20 +183,32 @@ pub async fn handle_alter_table_column(
         panic!("unexpected statement type: {:?}", definition);
     };
 
-    let (graph, table) = {
+    let (graph, table, source) = {
         let context = OptimizerContext::from_handler_args(handler_args);
-        let (plan, source, table) = gen_create_table_plan(
-            context,
-            table_name,
-            columns,
-            constraints,
-            col_id_gen,
-            source_watermarks,
-            append_only,
-        )?;
-
-        // We should already have rejected the case where the table has a connector.
-        assert!(source.is_none());
+        let (plan, source, table) = match source_schema {
+            Some(source_schema) => {
+                gen_create_table_plan_with_source(
+                    context,
+                    table_name,
+                    columns,
+                    constraints,
+                    source_schema,
+                    source_watermarks,
+                    col_id_gen,
+                    append_only,
+                )
+                .await?
+            }
+            None => gen_create_table_plan(
+                context,
+                table_name,
+                columns,
+                constraints,
+                col_id_gen,
+                source_watermarks,
+                append_only,
+            )?,
+        };
 
         // TODO: avoid this backward conversion.
         if TableCatalog::from(&table).pk_column_ids() != original_catalog.pk_column_ids() {
@@ -200,13 +225,26 @@ pub async fn handle_alter_table_column(
             ..build_graph(plan)
         };
 
-        // Fill the original table ID.
-        let table = Table {
+        // Fill the original table ID and the associated source ID, which are generated by the
+        // meta service on creation and not filled by the plan generation above.
+        let mut table = Table {
             id: original_catalog.id().table_id(),
             ..table
         };
-
-        (graph, table)
+        let source = source.map(|mut source| {
+            let source_id = original_catalog
+                .associated_source_id()
+                .expect("altered table must have an associated source")
+                .table_id();
+            source.id = source_id;
+            source.optional_associated_table_id =
+                Some(OptionalAssociatedTableId::AssociatedTableId(table.id));
+            table.optional_associated_source_id =
+                Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id));
+            source
+        });
+
+        (graph, table, source)
     };
 
     // Calculate the mapping from the original columns to the new columns.
@@ -225,7 +263,7 @@ pub async fn handle_alter_table_column(
     let catalog_writer = session.env().catalog_writer();
 
     catalog_writer
-        .replace_table(table, graph, col_index_mapping)
+        .replace_table(source, table, graph, col_index_mapping)
         .await?;
 
     Ok(PgResponse::empty_result(StatementType::ALTER_TABLE))
@@ -37,13 +37,6 @@ pub async fn handle_create_function(
     returns: Option<CreateFunctionReturns>,
     params: CreateFunctionBody,
 ) -> Result<RwPgResponse> {
-    if or_replace {
-        return Err(ErrorCode::NotImplemented(
-            "CREATE OR REPLACE FUNCTION".to_string(),
-            None.into(),
-        )
-        .into());
-    }
     if temporary {
         return Err(ErrorCode::NotImplemented(
             "CREATE TEMPORARY FUNCTION".to_string(),
@@ -104,11 +97,13 @@ pub async fn handle_create_function(
     let (schema_name, function_name) = Binder::resolve_schema_qualified_name(db_name, name)?;
     let (database_id, schema_id) = session.get_database_and_schema_id_for_create(schema_name)?;
 
-    // check if function exists
-    if (session.env().catalog_reader().read_guard())
-        .get_schema_by_id(&database_id, &schema_id)?
-        .get_function_by_name_args(&function_name, &arg_types)
-        .is_some()
+    // Check if the function exists. When `OR REPLACE` is specified, the check is performed
+    // atomically on the meta side instead.
+    if !or_replace
+        && (session.env().catalog_reader().read_guard())
+            .get_schema_by_id(&database_id, &schema_id)?
+            .get_function_by_name_args(&function_name, &arg_types)
+            .is_some()
     {
         let name = format!(
             "{function_name}({})",
@@ -117,6 +112,23 @@ pub async fn handle_create_function(
         return Err(CatalogError::Duplicated("function", name).into());
     }
 
+    // Replacing an existing function is subject to the same ownership rules as dropping it.
+    if or_replace {
+        let (schema_name, function) = {
+            let reader = session.env().catalog_reader().read_guard();
+            let schema = reader.get_schema_by_id(&database_id, &schema_id)?;
+            (
+                schema.name(),
+                schema
+                    .get_function_by_name_args(&function_name, &arg_types)
+                    .cloned(),
+            )
+        };
+        if let Some(function) = function {
+            session.check_privilege_for_drop_alter(&schema_name, &*function)?;
+        }
+    }
+
     // check the service
     let client = ArrowFlightUdfClient::connect(&link)
         .await
@@ -154,7 +166,7 @@ pub async fn handle_create_function(
     };
 
     let catalog_writer = session.env().catalog_writer();
-    catalog_writer.create_function(function).await?;
+    catalog_writer.create_function(function, or_replace).await?;
 
     Ok(PgResponse::empty_result(StatementType::CREATE_FUNCTION))
 }
@@ -201,15 +201,23 @@ pub(crate) fn gen_create_index_plan(
     let index_table = materialize.table();
     let mut index_table_prost = index_table.to_prost(index_schema_id, index_database_id);
     {
-        use risingwave_common::constants::hummock::PROPERTIES_RETENTION_SECOND_KEY;
-        let retention_second_string_key = PROPERTIES_RETENTION_SECOND_KEY.to_string();
+        use risingwave_common::constants::hummock::{
+            PROPERTIES_BLOOM_FILTER_MODE_KEY, PROPERTIES_COLD_STORAGE_CLASS_KEY,
+            PROPERTIES_RETENTION_SECOND_KEY,
+        };
 
         // Inherit table properties
-        table.properties.get(&retention_second_string_key).map(|v| {
-            index_table_prost
-                .properties
-                .insert(retention_second_string_key, v.clone())
-        });
+        for key in [
+            PROPERTIES_RETENTION_SECOND_KEY,
+            PROPERTIES_COLD_STORAGE_CLASS_KEY,
+            PROPERTIES_BLOOM_FILTER_MODE_KEY,
+        ] {
+            table.properties.get(key).map(|v| {
+                index_table_prost
+                    .properties
+                    .insert(key.to_string(), v.clone())
+            });
+        }
     }
 
     index_table_prost.owner = session.user_id();
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::num::NonZeroU64;
+
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result};
@@ -174,10 +176,17 @@ It only indicates the physical clustering of the data, which may improve the per
             gen_create_mv_plan(&session, context.into(), query, name, columns, emit_mode)?;
         let context = plan.plan_base().ctx.clone();
         let mut graph = build_graph(plan);
-        graph.parallelism = session
-            .config()
-            .get_streaming_parallelism()
+        graph.parallelism = context
+            .with_options()
+            .parallelism()
+            .map(NonZeroU64::get)
+            .or_else(|| session.config().get_streaming_parallelism())
             .map(|parallelism| Parallelism { parallelism });
+        graph.resource_group = context
+            .with_options()
+            .resource_group()
+            .unwrap_or_default()
+            .to_owned();
         // Set the timezone for the stream environment
         let env = graph.env.as_mut().unwrap();
         env.timezone = context.get_session_timezone();
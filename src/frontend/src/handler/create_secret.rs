@@ -0,0 +1,98 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::ErrorCode::{NotImplemented, ProtocolError};
+use risingwave_common::error::{Result, RwError};
+use risingwave_connector::secret::{SECRET_BACKEND_AWS_SECRETS_MANAGER, SECRET_BACKEND_META};
+use risingwave_pb::catalog::PbSecret;
+use risingwave_sqlparser::ast::{CreateSecretStatement, Value};
+
+use super::RwPgResponse;
+use crate::binder::Binder;
+use crate::handler::HandlerArgs;
+
+pub(crate) const SECRET_BACKEND_PROP: &str = "backend";
+
+pub async fn handle_create_secret(
+    handler_args: HandlerArgs,
+    stmt: CreateSecretStatement,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let db_name = session.database();
+    let (schema_name, secret_name) =
+        Binder::resolve_schema_qualified_name(db_name, stmt.secret_name.clone())?;
+
+    if let Err(e) = session.check_secret_name_duplicated(stmt.secret_name) {
+        return if stmt.if_not_exists {
+            Ok(PgResponse::builder(StatementType::CREATE_SECRET)
+                .notice(format!("secret \"{}\" exists, skipping", secret_name))
+                .into())
+        } else {
+            Err(e)
+        };
+    }
+    let (database_id, schema_id) = session.get_database_and_schema_id_for_create(schema_name)?;
+
+    let backend = handler_args
+        .with_options
+        .get(SECRET_BACKEND_PROP)
+        .map(|s| s.to_lowercase())
+        .ok_or_else(|| {
+            RwError::from(ProtocolError(format!(
+                "Required property \"{}\" is not provided",
+                SECRET_BACKEND_PROP
+            )))
+        })?;
+    match backend.as_str() {
+        SECRET_BACKEND_META => {}
+        SECRET_BACKEND_AWS_SECRETS_MANAGER => {
+            return Err(NotImplemented(
+                format!("secret backend \"{}\"", SECRET_BACKEND_AWS_SECRETS_MANAGER),
+                None.into(),
+            )
+            .into());
+        }
+        _ => {
+            return Err(RwError::from(ProtocolError(format!(
+                "Secret backend \"{}\" is not supported",
+                backend
+            ))));
+        }
+    }
+
+    let value = match stmt.credential {
+        Value::SingleQuotedString(s) => s.into_bytes(),
+        _ => {
+            return Err(RwError::from(ProtocolError(
+                "Secret value should be a single quoted string".to_string(),
+            )));
+        }
+    };
+
+    let secret = PbSecret {
+        id: 0,
+        schema_id,
+        database_id,
+        name: secret_name,
+        owner: session.user_id(),
+        backend,
+        value,
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_secret(secret).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_SECRET))
+}
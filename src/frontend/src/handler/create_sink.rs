@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::num::NonZeroU64;
 use std::rc::Rc;
 
 use itertools::Itertools;
@@ -73,6 +74,10 @@ pub fn gen_sink_plan(
     let (sink_schema_name, sink_table_name) =
         Binder::resolve_schema_qualified_name(db_name, stmt.sink_name.clone())?;
 
+    // For `FROM mv` sinks the primary key can be derived from the stream key of the
+    // materialized view, while `AS SELECT` sinks must spell it out in the `primary_key`
+    // option.
+    let sink_from_mv = matches!(stmt.sink_from, CreateSink::From(_));
     let query = match stmt.sink_from {
         CreateSink::From(from_name) => Box::new(gen_sink_query_from_name(from_name)?),
         CreateSink::AsQuery(query) => query,
@@ -117,6 +122,7 @@ pub fn gen_sink_plan(
         definition,
         with_options,
         emit_on_window_close,
+        sink_from_mv,
     )?;
     let sink_desc = sink_plan.sink_desc().clone();
     let sink_plan: PlanRef = sink_plan.into();
@@ -161,10 +167,17 @@ pub async fn handle_create_sink(
             );
         }
         let mut graph = build_graph(plan);
-        graph.parallelism = session
-            .config()
-            .get_streaming_parallelism()
+        graph.parallelism = context
+            .with_options()
+            .parallelism()
+            .map(NonZeroU64::get)
+            .or_else(|| session.config().get_streaming_parallelism())
             .map(|parallelism| Parallelism { parallelism });
+        graph.resource_group = context
+            .with_options()
+            .resource_group()
+            .unwrap_or_default()
+            .to_owned();
         (sink, graph)
     };
 
@@ -394,13 +394,29 @@ pub(crate) async fn try_bind_columns_from_source(
                         .to_owned(),
                 )));
             }
+            for (option, c) in [("QUOTE", csv_info.quote), ("ESCAPE", csv_info.escape)] {
+                if let Some(c) = c && !c.is_ascii() {
+                    return Err(RwError::from(ProtocolError(format!(
+                        "CSV {} must be an ASCII character, but got {:?}",
+                        option, c
+                    ))));
+                }
+            }
             (
                 None,
                 sql_defined_pk_names,
                 StreamSourceInfo {
                     row_format: RowFormatType::Csv as i32,
-                    csv_delimiter: csv_info.delimiter as i32,
+                    csv_delimiter: *csv_info.delimiter.as_bytes().first().unwrap() as i32,
+                    csv_multi_char_delimiter: if csv_info.delimiter.len() > 1 {
+                        csv_info.delimiter.clone()
+                    } else {
+                        String::new()
+                    },
                     csv_has_header: csv_info.has_header,
+                    csv_null_literal: csv_info.null_literal.clone().unwrap_or_default(),
+                    csv_quote: csv_info.quote.map(String::from).unwrap_or_default(),
+                    csv_escape: csv_info.escape.map(String::from).unwrap_or_default(),
                     ..Default::default()
                 },
             )
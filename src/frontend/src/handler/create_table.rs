@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::num::NonZeroU64;
 use std::rc::Rc;
 
 use fixedbitset::FixedBitSet;
@@ -699,11 +700,19 @@ pub async fn handle_create_table(
             )?,
         };
 
+        let context = plan.ctx();
         let mut graph = build_graph(plan);
-        graph.parallelism = session
-            .config()
-            .get_streaming_parallelism()
+        graph.parallelism = context
+            .with_options()
+            .parallelism()
+            .map(NonZeroU64::get)
+            .or_else(|| session.config().get_streaming_parallelism())
             .map(|parallelism| Parallelism { parallelism });
+        graph.resource_group = context
+            .with_options()
+            .resource_group()
+            .unwrap_or_default()
+            .to_owned();
         (graph, source, table)
     };
 
@@ -862,7 +871,8 @@ mod tests {
                 columns: column_defs,
                 constraints,
                 ..
-            } = ast.remove(0) else {
+            } = ast.remove(0)
+            else {
                 panic!("test case should be create table")
             };
             let actual: Result<_> = (|| {
@@ -54,6 +54,7 @@ fn make_prost_user_info(
             action: Action::Connect as i32,
             with_grant_option: true,
             granted_by: session_user.id,
+            column_names: vec![],
         }],
         object: Some(Object::DatabaseId(database_id)),
     }];
@@ -30,6 +30,7 @@ use crate::session::CheckRelationError;
 
 pub async fn handle_create_view(
     handler_args: HandlerArgs,
+    or_replace: bool,
     if_not_exists: bool,
     name: ObjectName,
     columns: Vec<Ident>,
@@ -43,15 +44,31 @@ pub async fn handle_create_view(
 
     let properties = handler_args.with_options.clone();
 
-    match session.check_relation_name_duplicated(name.clone()) {
-        Err(CheckRelationError::Catalog(CatalogError::Duplicated(_, name))) if if_not_exists => {
-            return Ok(PgResponse::builder(StatementType::CREATE_VIEW)
-                .notice(format!("relation \"{}\" already exists, skipping", name))
-                .into());
+    // When `OR REPLACE` is specified, the check for an existing view is performed atomically
+    // on the meta side.
+    if !or_replace {
+        match session.check_relation_name_duplicated(name.clone()) {
+            Err(CheckRelationError::Catalog(CatalogError::Duplicated(_, name)))
+                if if_not_exists =>
+            {
+                return Ok(PgResponse::builder(StatementType::CREATE_VIEW)
+                    .notice(format!("relation \"{}\" already exists, skipping", name))
+                    .into());
+            }
+            Err(e) => return Err(e.into()),
+            Ok(_) => {}
+        };
+    } else {
+        // Replacing an existing view is subject to the same ownership rules as dropping it.
+        let (schema_name, view) = {
+            let reader = session.env().catalog_reader().read_guard();
+            let schema = reader.get_schema_by_id(&database_id, &schema_id)?;
+            (schema.name(), schema.get_view_by_name(&view_name).cloned())
+        };
+        if let Some(view) = view {
+            session.check_privilege_for_drop_alter(&schema_name, &*view)?;
         }
-        Err(e) => return Err(e.into()),
-        Ok(_) => {}
-    };
+    }
 
     // plan the query to validate it and resolve dependencies
     let (dependent_relations, schema) = {
@@ -106,7 +123,7 @@ pub async fn handle_create_view(
     };
 
     let catalog_writer = session.env().catalog_writer();
-    catalog_writer.create_view(view).await?;
+    catalog_writer.create_view(view, or_replace).await?;
 
     Ok(PgResponse::empty_result(StatementType::CREATE_VIEW))
 }
@@ -26,6 +26,7 @@ use risingwave_sqlparser::ast::{display_comma_separated, ObjectName};
 use super::RwPgResponse;
 use crate::binder::{Binder, Relation};
 use crate::catalog::{CatalogError, IndexCatalog};
+use crate::handler::create_source::UPSTREAM_SOURCE_KEY;
 use crate::handler::util::col_descs_to_rows;
 use crate::handler::HandlerArgs;
 
@@ -34,59 +35,122 @@ pub fn handle_describe(handler_args: HandlerArgs, table_name: ObjectName) -> Res
     let mut binder = Binder::new_for_system(&session);
     let relation = binder.bind_relation_by_name(table_name.clone(), None, false)?;
     // For Source, it doesn't have table catalog so use get source to get column descs.
-    let (columns, pk_columns, indices): (Vec<ColumnDesc>, Vec<ColumnDesc>, Vec<Arc<IndexCatalog>>) = {
-        let (column_catalogs, pk_column_catalogs, indices) = match relation {
-            Relation::Source(s) => {
-                let pk_column_catalogs = s
-                    .catalog
-                    .pk_col_ids
-                    .iter()
-                    .map(|&column_id| {
-                        s.catalog
-                            .columns
-                            .iter()
-                            .filter(|x| x.column_id() == column_id)
-                            .exactly_one()
-                            .unwrap()
-                            .clone()
-                    })
-                    .collect_vec();
-                (s.catalog.columns, pk_column_catalogs, vec![])
-            }
-            Relation::BaseTable(t) => {
-                let pk_column_catalogs = t
-                    .table_catalog
-                    .pk()
-                    .iter()
-                    .map(|x| t.table_catalog.columns[x.column_index].clone())
-                    .collect_vec();
-                (t.table_catalog.columns, pk_column_catalogs, t.table_indexes)
-            }
-            Relation::SystemTable(t) => {
-                let pk_column_catalogs = t
-                    .sys_table_catalog
-                    .pk
-                    .iter()
-                    .map(|idx| t.sys_table_catalog.columns[*idx].clone())
-                    .collect_vec();
-                (t.sys_table_catalog.columns, pk_column_catalogs, vec![])
-            }
-            _ => {
-                return Err(
-                    CatalogError::NotFound("table or source", table_name.to_string()).into(),
-                );
-            }
-        };
+    let (columns, hidden_columns, pk_columns, dist_columns, watermark_columns, connector_info, indices): (
+        Vec<ColumnDesc>,
+        Vec<ColumnDesc>,
+        Vec<ColumnDesc>,
+        Vec<String>,
+        Vec<String>,
+        Option<(Option<String>, String)>,
+        Vec<Arc<IndexCatalog>>,
+    ) = {
+        let (column_catalogs, pk_column_catalogs, dist_columns, watermark_columns, connector_info, indices) =
+            match relation {
+                Relation::Source(s) => {
+                    let pk_column_catalogs = s
+                        .catalog
+                        .pk_col_ids
+                        .iter()
+                        .map(|&column_id| {
+                            s.catalog
+                                .columns
+                                .iter()
+                                .filter(|x| x.column_id() == column_id)
+                                .exactly_one()
+                                .unwrap()
+                                .clone()
+                        })
+                        .collect_vec();
+                    let watermark_columns = s
+                        .catalog
+                        .watermark_descs
+                        .iter()
+                        .map(|desc| s.catalog.columns[desc.watermark_idx as usize].name().to_owned())
+                        .collect_vec();
+                    let connector = s.catalog.properties.get(UPSTREAM_SOURCE_KEY).cloned();
+                    let row_format = s
+                        .catalog
+                        .info
+                        .get_row_format()
+                        .map(|f| f.as_str_name().to_owned())
+                        .unwrap_or_else(|_| "ROW_UNSPECIFIED".to_owned());
+                    (
+                        s.catalog.columns,
+                        pk_column_catalogs,
+                        vec![],
+                        watermark_columns,
+                        Some((connector, row_format)),
+                        vec![],
+                    )
+                }
+                Relation::BaseTable(t) => {
+                    let pk_column_catalogs = t
+                        .table_catalog
+                        .pk()
+                        .iter()
+                        .map(|x| t.table_catalog.columns[x.column_index].clone())
+                        .collect_vec();
+                    let dist_columns = t
+                        .table_catalog
+                        .distribution_key()
+                        .iter()
+                        .map(|&idx| t.table_catalog.columns[idx].name().to_owned())
+                        .collect_vec();
+                    let watermark_columns = t
+                        .table_catalog
+                        .watermark_columns
+                        .ones()
+                        .map(|idx| t.table_catalog.columns[idx].name().to_owned())
+                        .collect_vec();
+                    (
+                        t.table_catalog.columns,
+                        pk_column_catalogs,
+                        dist_columns,
+                        watermark_columns,
+                        None,
+                        t.table_indexes,
+                    )
+                }
+                Relation::SystemTable(t) => {
+                    let pk_column_catalogs = t
+                        .sys_table_catalog
+                        .pk
+                        .iter()
+                        .map(|idx| t.sys_table_catalog.columns[*idx].clone())
+                        .collect_vec();
+                    (
+                        t.sys_table_catalog.columns,
+                        pk_column_catalogs,
+                        vec![],
+                        vec![],
+                        None,
+                        vec![],
+                    )
+                }
+                _ => {
+                    return Err(
+                        CatalogError::NotFound("table or source", table_name.to_string()).into(),
+                    );
+                }
+            };
         (
             column_catalogs
                 .iter()
                 .filter(|c| !c.is_hidden)
                 .map(|c| c.column_desc.clone())
                 .collect(),
+            column_catalogs
+                .iter()
+                .filter(|c| c.is_hidden)
+                .map(|c| c.column_desc.clone())
+                .collect(),
             pk_column_catalogs
                 .iter()
                 .map(|c| c.column_desc.clone())
                 .collect(),
+            dist_columns,
+            watermark_columns,
+            connector_info,
             indices,
         )
     };
@@ -94,6 +158,14 @@ pub fn handle_describe(handler_args: HandlerArgs, table_name: ObjectName) -> Res
     // Convert all column descs to rows
     let mut rows = col_descs_to_rows(columns);
 
+    // Hidden columns (e.g. `_row_id`) are still shown, marked as hidden.
+    rows.extend(hidden_columns.into_iter().map(|col| {
+        Row::new(vec![
+            Some(col.name.into()),
+            Some(format!("{} (hidden)", col.data_type).into()),
+        ])
+    }));
+
     // Convert primary key to rows
     if !pk_columns.is_empty() {
         rows.push(Row::new(vec![
@@ -108,6 +180,36 @@ pub fn handle_describe(handler_args: HandlerArgs, table_name: ObjectName) -> Res
         ]));
     }
 
+    // Convert distribution key to rows
+    if !dist_columns.is_empty() {
+        rows.push(Row::new(vec![
+            Some("distribution key".into()),
+            Some(format!("{}", display_comma_separated(&dist_columns)).into()),
+        ]));
+    }
+
+    // Convert watermark columns to rows
+    if !watermark_columns.is_empty() {
+        rows.push(Row::new(vec![
+            Some("watermark".into()),
+            Some(format!("{}", display_comma_separated(&watermark_columns)).into()),
+        ]));
+    }
+
+    // Convert connector and row format of sources to rows
+    if let Some((connector, row_format)) = connector_info {
+        if let Some(connector) = connector {
+            rows.push(Row::new(vec![
+                Some("connector".into()),
+                Some(connector.into()),
+            ]));
+        }
+        rows.push(Row::new(vec![
+            Some("row format".into()),
+            Some(row_format.into()),
+        ]));
+    }
+
     // Convert all indexes to rows
     rows.extend(indices.iter().map(|index| {
         let index_display = index.display();
@@ -204,6 +306,7 @@ mod tests {
             "v3".into() => "integer".into(),
             "v4".into() => "integer".into(),
             "primary key".into() => "v3".into(),
+            "distribution key".into() => "v3".into(),
             "idx1".into() => "index(v1 DESC, v2 ASC, v3 ASC) include(v4) distributed by(v1, v2)".into(),
         };
 
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
 use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{ExplainOptions, ExplainType, Statement};
 
@@ -29,6 +32,9 @@ use super::create_table::{
 };
 use super::query::gen_batch_plan_by_statement;
 use super::RwPgResponse;
+use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::{Convention, Explain};
 use crate::optimizer::OptimizerContext;
@@ -227,19 +233,162 @@ async fn do_handle_explain(
     Ok(())
 }
 
+/// The duration between the two samples of the streaming metrics, used to derive the output
+/// rate of each executor.
+const ANALYZE_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Handle `EXPLAIN ANALYZE` on a streaming job by reporting the per-executor runtime statistics
+/// collected from the compute nodes. The job must have been created and running, so that the
+/// statistics reflect its actual execution.
+async fn do_handle_explain_analyze(
+    handler_args: HandlerArgs,
+    stmt: Statement,
+    blocks: &mut Vec<String>,
+) -> Result<()> {
+    let Statement::CreateView {
+        materialized: true,
+        name,
+        ..
+    } = stmt
+    else {
+        return Err(ErrorCode::NotImplemented(
+            "explain analyze is only supported on materialized views".to_string(),
+            4856.into(),
+        )
+        .into());
+    };
+
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, table_name) = Binder::resolve_schema_qualified_name(db_name, name)?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, _) = reader.get_table_by_name(db_name, schema_path, &table_name)?;
+        if table.table_type() != TableType::MaterializedView {
+            return Err(ErrorCode::NotImplemented(
+                "explain analyze is only supported on materialized views".to_string(),
+                4856.into(),
+            )
+            .into());
+        }
+        table.id()
+    };
+
+    let mut table_fragments = session
+        .env()
+        .meta_client()
+        .list_table_fragments(&[table_id.table_id])
+        .await?;
+    let fragment_info = table_fragments.remove(&table_id.table_id).ok_or_else(|| {
+        ErrorCode::InternalError(format!(
+            "fragments of materialized view {} not found",
+            table_name
+        ))
+    })?;
+
+    let mut actor_to_fragment = HashMap::new();
+    for fragment in &fragment_info.fragments {
+        for actor in &fragment.actors {
+            actor_to_fragment.insert(actor.id, fragment.id);
+        }
+    }
+
+    // Collect two samples of the streaming metrics from all compute nodes, so that the output
+    // rate of each executor can be derived from the difference.
+    let collect = || async {
+        let mut executor_rows: HashMap<(u32, String), u64> = HashMap::new();
+        let mut actor_busy_time: HashMap<u32, f64> = HashMap::new();
+        for worker in session.env().worker_node_manager().list_worker_nodes() {
+            let client = session.env().client_pool().get(&worker).await?;
+            let stats = client.get_stream_stats().await?;
+            for executor in stats.executor_stats {
+                executor_rows.insert(
+                    (executor.actor_id, executor.executor_identity),
+                    executor.output_row_count,
+                );
+            }
+            for actor in stats.actor_stats {
+                actor_busy_time.insert(actor.actor_id, actor.execution_time_s);
+            }
+        }
+        Ok::<_, RwError>((executor_rows, actor_busy_time))
+    };
+
+    let (first_executor_rows, _) = collect().await?;
+    tokio::time::sleep(ANALYZE_SAMPLE_INTERVAL).await;
+    let (executor_rows, actor_busy_time) = collect().await?;
+
+    // Aggregate the stats of all actors of a fragment, by the name of the executor.
+    let mut fragment_executor_stats: BTreeMap<u32, BTreeMap<String, (u64, u64)>> = BTreeMap::new();
+    for ((actor_id, identity), row_count) in &executor_rows {
+        let Some(&fragment_id) = actor_to_fragment.get(actor_id) else {
+            // The executor belongs to another streaming job.
+            continue;
+        };
+        // The identity is in the form of `HashAggExecutor 27000000003`, strip the per-actor
+        // executor id to aggregate over all parallelisms of the fragment.
+        let executor_name = identity.split_whitespace().next().unwrap_or(identity.as_str());
+        let delta = row_count.saturating_sub(
+            first_executor_rows
+                .get(&(*actor_id, identity.clone()))
+                .copied()
+                .unwrap_or(0),
+        );
+        let entry = fragment_executor_stats
+            .entry(fragment_id)
+            .or_default()
+            .entry(executor_name.to_string())
+            .or_default();
+        entry.0 += row_count;
+        entry.1 += delta;
+    }
+
+    for fragment in &fragment_info.fragments {
+        let actor_ids = fragment.actors.iter().map(|actor| actor.id).collect_vec();
+        blocks.push(format!(
+            "Fragment {} (actor count: {})",
+            fragment.id,
+            actor_ids.len()
+        ));
+        if let Some(executor_stats) = fragment_executor_stats.get(&fragment.id) {
+            for (executor_name, (row_count, delta)) in executor_stats {
+                blocks.push(format!(
+                    "  {}: {} rows processed, {:.1} rows/s",
+                    executor_name,
+                    row_count,
+                    *delta as f64 / ANALYZE_SAMPLE_INTERVAL.as_secs_f64()
+                ));
+            }
+        }
+        let busy_time: f64 = actor_ids
+            .iter()
+            .map(|actor_id| actor_busy_time.get(actor_id).copied().unwrap_or(0.0))
+            .sum();
+        blocks.push(format!("  busy time: {:.3}s", busy_time));
+    }
+
+    Ok(())
+}
+
 pub async fn handle_explain(
     handler_args: HandlerArgs,
     stmt: Statement,
     options: ExplainOptions,
     analyze: bool,
 ) -> Result<RwPgResponse> {
+    let mut blocks = Vec::new();
+
     if analyze {
-        return Err(ErrorCode::NotImplemented("explain analyze".to_string(), 4856.into()).into());
+        do_handle_explain_analyze(handler_args, stmt, &mut blocks).await?;
+        return Ok(explain_response(blocks));
     }
 
     let context = OptimizerContext::new(handler_args.clone(), options.clone());
 
-    let mut blocks = Vec::new();
     let result = do_handle_explain(context, stmt, &mut blocks).await;
 
     if let Err(e) = result {
@@ -256,13 +405,17 @@ pub async fn handle_explain(
         }
     }
 
+    Ok(explain_response(blocks))
+}
+
+fn explain_response(blocks: Vec<String>) -> RwPgResponse {
     let rows = blocks
         .iter()
         .flat_map(|b| b.lines().map(|l| l.to_owned()))
         .map(|l| Row::new(vec![Some(l.into())]))
         .collect_vec();
 
-    Ok(PgResponse::builder(StatementType::EXPLAIN)
+    PgResponse::builder(StatementType::EXPLAIN)
         .values(
             rows.into(),
             vec![PgFieldDescriptor::new(
@@ -271,5 +424,5 @@ pub async fn handle_explain(
                 DataType::Varchar.type_len(),
             )],
         )
-        .into())
+        .into()
 }
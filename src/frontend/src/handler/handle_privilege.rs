@@ -16,7 +16,7 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, PbObject};
 use risingwave_pb::user::PbGrantPrivilege;
-use risingwave_sqlparser::ast::{GrantObjects, Privileges, Statement};
+use risingwave_sqlparser::ast::{Action, GrantObjects, Privileges, Statement};
 
 use super::RwPgResponse;
 use crate::binder::Binder;
@@ -27,6 +27,49 @@ use crate::user::user_privilege::{
     available_privilege_actions, check_privilege_type, get_prost_action,
 };
 
+/// Resolves the column list of a column-level action against the target relation, returning
+/// the validated column names to record in the privilege. `relation` is `None` for objects
+/// that have no columns, on which column-level actions are rejected.
+fn resolve_action_columns(
+    action: &Action,
+    relation: Option<&(String, Vec<String>)>,
+) -> Result<Vec<String>> {
+    let columns = match action {
+        Action::Select {
+            columns: Some(columns),
+        } => columns,
+        Action::Insert { columns: Some(_) } | Action::Update { columns: Some(_) } => {
+            return Err(ErrorCode::NotImplemented(
+                "column-level privileges are only supported for SELECT".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        _ => return Ok(vec![]),
+    };
+    let Some((relation_name, relation_columns)) = relation else {
+        return Err(ErrorCode::BindError(
+            "column privileges are only valid for relations".to_string(),
+        )
+        .into());
+    };
+    columns
+        .iter()
+        .map(|column| {
+            let name = column.real_value();
+            if relation_columns.contains(&name) {
+                Ok(name)
+            } else {
+                Err(ErrorCode::BindError(format!(
+                    "column \"{}\" of relation \"{}\" does not exist",
+                    name, relation_name
+                ))
+                .into())
+            }
+        })
+        .collect()
+}
+
 fn make_prost_privilege(
     session: &SessionImpl,
     privileges: Privileges,
@@ -40,23 +83,25 @@ fn make_prost_privilege(
         Privileges::All { .. } => available_privilege_actions(&objects)?,
         Privileges::Actions(actions) => actions,
     };
-    let mut grant_objs = vec![];
+    // The objects to grant on, each with the relation's name and column names to validate
+    // column-level actions against, or `None` for objects that have no columns.
+    let mut grant_objs: Vec<(PbObject, Option<(String, Vec<String>)>)> = vec![];
     match objects {
         GrantObjects::Databases(databases) => {
             for db in databases {
                 let database_name = Binder::resolve_database_name(db)?;
                 let database = reader.get_database_by_name(&database_name)?;
-                grant_objs.push(PbObject::DatabaseId(database.id()));
+                grant_objs.push((PbObject::DatabaseId(database.id()), None));
             }
         }
         GrantObjects::Schemas(schemas) => {
             for schema in schemas {
                 let schema_name = Binder::resolve_schema_name(schema)?;
                 let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
-                grant_objs.push(PbObject::SchemaId(schema.id()));
+                grant_objs.push((PbObject::SchemaId(schema.id()), None));
             }
         }
-        GrantObjects::Mviews(tables) => {
+        GrantObjects::Tables(tables) | GrantObjects::Mviews(tables) => {
             let db_name = session.database();
             let search_path = session.config().get_search_path();
             let user_name = &session.auth_context().user_name;
@@ -67,7 +112,16 @@ fn make_prost_privilege(
                 let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
 
                 let (table, _) = reader.get_table_by_name(db_name, schema_path, &table_name)?;
-                grant_objs.push(PbObject::TableId(table.id().table_id));
+                let columns = table
+                    .columns()
+                    .iter()
+                    .filter(|column| !column.is_hidden)
+                    .map(|column| column.name().to_string())
+                    .collect();
+                grant_objs.push((
+                    PbObject::TableId(table.id().table_id),
+                    Some((table_name, columns)),
+                ));
             }
         }
         GrantObjects::Sources(sources) => {
@@ -81,21 +135,27 @@ fn make_prost_privilege(
                 let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
 
                 let (source, _) = reader.get_source_by_name(db_name, schema_path, &source_name)?;
-                grant_objs.push(PbObject::SourceId(source.id));
+                let columns = source
+                    .columns
+                    .iter()
+                    .filter(|column| !column.is_hidden)
+                    .map(|column| column.name().to_string())
+                    .collect();
+                grant_objs.push((PbObject::SourceId(source.id), Some((source_name, columns))));
             }
         }
         GrantObjects::AllSourcesInSchema { schemas } => {
             for schema in schemas {
                 let schema_name = Binder::resolve_schema_name(schema)?;
                 let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
-                grant_objs.push(PbObject::AllSourcesSchemaId(schema.id()));
+                grant_objs.push((PbObject::AllSourcesSchemaId(schema.id()), None));
             }
         }
         GrantObjects::AllMviewsInSchema { schemas } => {
             for schema in schemas {
                 let schema_name = Binder::resolve_schema_name(schema)?;
                 let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
-                grant_objs.push(PbObject::AllTablesSchemaId(schema.id()));
+                grant_objs.push((PbObject::AllTablesSchemaId(schema.id()), None));
             }
         }
         o => {
@@ -106,23 +166,24 @@ fn make_prost_privilege(
             .into());
         }
     };
-    let action_with_opts = actions
-        .iter()
-        .map(|action| {
-            let prost_action = get_prost_action(action);
-            ActionWithGrantOption {
-                action: prost_action as i32,
-                granted_by: session.user_id(),
-                ..Default::default()
-            }
-        })
-        .collect::<Vec<_>>();
 
     let mut prost_privileges = vec![];
-    for objs in grant_objs {
+    for (object, relation) in grant_objs {
+        let action_with_opts = actions
+            .iter()
+            .map(|action| {
+                let column_names = resolve_action_columns(action, relation.as_ref())?;
+                Ok(ActionWithGrantOption {
+                    action: get_prost_action(action) as i32,
+                    granted_by: session.user_id(),
+                    column_names,
+                    ..Default::default()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
         prost_privileges.push(PbGrantPrivilege {
-            action_with_opts: action_with_opts.clone(),
-            object: Some(objs),
+            action_with_opts,
+            object: Some(object),
         });
     }
     Ok(prost_privileges)
@@ -224,8 +285,9 @@ pub async fn handle_revoke_privilege(
 
 #[cfg(test)]
 mod tests {
-    use risingwave_common::catalog::DEFAULT_SUPER_USER_ID;
-    use risingwave_pb::user::grant_privilege::Action;
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SUPER_USER_ID};
+    use risingwave_pb::user::grant_privilege::Action as PbAction;
+    use risingwave_sqlparser::parser::Parser;
 
     use super::*;
     use crate::test_utils::LocalFrontend;
@@ -269,23 +331,26 @@ mod tests {
                 vec![
                     PbGrantPrivilege {
                         action_with_opts: vec![ActionWithGrantOption {
-                            action: Action::Connect as i32,
+                            action: PbAction::Connect as i32,
                             with_grant_option: true,
                             granted_by: session.user_id(),
+                            column_names: vec![],
                         }],
                         object: Some(PbObject::DatabaseId(session_database_id)),
                     },
                     PbGrantPrivilege {
                         action_with_opts: vec![
                             ActionWithGrantOption {
-                                action: Action::Connect as i32,
+                                action: PbAction::Connect as i32,
                                 with_grant_option: true,
                                 granted_by: DEFAULT_SUPER_USER_ID,
+                                column_names: vec![],
                             },
                             ActionWithGrantOption {
-                                action: Action::Create as i32,
+                                action: PbAction::Create as i32,
                                 with_grant_option: true,
                                 granted_by: DEFAULT_SUPER_USER_ID,
+                                column_names: vec![],
                             }
                         ],
                         object: Some(PbObject::DatabaseId(database_id)),
@@ -321,9 +386,10 @@ mod tests {
                 user_info.grant_privileges,
                 vec![PbGrantPrivilege {
                     action_with_opts: vec![ActionWithGrantOption {
-                        action: Action::Connect as i32,
+                        action: PbAction::Connect as i32,
                         with_grant_option: true,
                         granted_by: session.user_id(),
+                        column_names: vec![],
                     }],
                     object: Some(PbObject::DatabaseId(session_database_id)),
                 }]
@@ -331,4 +397,57 @@ mod tests {
         }
         frontend.run_sql("DROP USER user1").await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_grant_column_privilege() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        frontend
+            .run_sql("CREATE TABLE t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("CREATE USER user1 WITH NOSUPERUSER PASSWORD 'password1'")
+            .await
+            .unwrap();
+
+        // The column list must refer to existing columns.
+        assert!(frontend
+            .run_sql("GRANT SELECT(v3) ON t TO user1")
+            .await
+            .is_err());
+        frontend
+            .run_sql("GRANT SELECT(v1) ON t TO user1")
+            .await
+            .unwrap();
+
+        let user_id = {
+            let user_reader = session.env().user_info_reader();
+            let reader = user_reader.read_guard();
+            let user_info = reader.get_user_by_name("user1").unwrap();
+            assert!(user_info.grant_privileges.iter().any(|privilege| {
+                privilege
+                    .action_with_opts
+                    .iter()
+                    .any(|ao| ao.action == PbAction::Select as i32 && ao.column_names == vec!["v1"])
+            }));
+            user_info.id
+        };
+
+        // The binder only allows `user1` to read the granted column.
+        let session = frontend.session_user_ref(
+            DEFAULT_DATABASE_NAME.to_string(),
+            "user1".to_string(),
+            user_id,
+        );
+        let bind = |sql: &str| {
+            let stmt = Parser::parse_sql(sql).unwrap().into_iter().next().unwrap();
+            Binder::new(&session).bind(stmt)
+        };
+        assert!(bind("SELECT v1 FROM t").is_ok());
+        assert!(bind("SELECT v2 FROM t").is_err());
+        assert!(bind("SELECT * FROM t").is_err());
+        assert!(bind("SELECT * EXCEPT (v2) FROM t").is_ok());
+        assert!(bind("SELECT count(*) FROM t WHERE v2 > 0").is_err());
+    }
 }
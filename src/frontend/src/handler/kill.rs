@@ -0,0 +1,45 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+
+use super::RwPgResponse;
+use crate::handler::HandlerArgs;
+
+/// Handle `KILL <process_id>` by cancelling the batch query currently running in the session
+/// with the given process id, as listed by `SHOW PROCESSLIST`.
+pub(super) fn handle_kill(handler_args: HandlerArgs, process_id: i32) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let target = {
+        let sessions_map = session.env().sessions_map().lock().unwrap();
+        sessions_map
+            .iter()
+            .find(|((pid, _), _)| *pid == process_id)
+            .map(|(_, session)| session.clone())
+    };
+    let Some(target) = target else {
+        return Err(ErrorCode::ItemNotFound(format!("process {}", process_id)).into());
+    };
+    // Like Postgres, only superusers can signal the sessions of other users.
+    if target.user_name() != session.user_name() && !session.is_super_user() {
+        return Err(ErrorCode::PermissionDenied(
+            "Only the session user and superuser can kill a session.".to_string(),
+        )
+        .into());
+    }
+    tracing::info!("cancelling query in session {} on user request", process_id);
+    target.cancel_current_query();
+    Ok(PgResponse::empty_result(StatementType::KILL))
+}
@@ -32,18 +32,25 @@ use self::variable::handle_set_time_zone;
 use crate::catalog::table_catalog::TableType;
 use crate::scheduler::{DistributedQueryStream, LocalQueryStream};
 use crate::session::SessionImpl;
-use crate::utils::WithOptions;
+use crate::utils::{resolve_secret_refs_in_with_options, WithOptions};
 
+mod alter_mv_suspend;
+mod alter_owner;
+mod alter_parallelism;
 mod alter_relation_rename;
+mod alter_secret;
+mod alter_source_props;
 mod alter_system;
 mod alter_table_column;
 pub mod alter_user;
+mod cancel_job;
 pub mod create_connection;
 mod create_database;
 pub mod create_function;
 pub mod create_index;
 pub mod create_mv;
 pub mod create_schema;
+pub mod create_secret;
 pub mod create_sink;
 pub mod create_source;
 pub mod create_table;
@@ -56,7 +63,9 @@ mod drop_database;
 pub mod drop_function;
 mod drop_index;
 pub mod drop_mv;
+mod drop_owned;
 mod drop_schema;
+mod drop_secret;
 pub mod drop_sink;
 pub mod drop_source;
 pub mod drop_table;
@@ -64,10 +73,12 @@ pub mod drop_user;
 mod drop_view;
 pub mod explain;
 pub mod extended_handle;
-mod flush;
+pub(crate) mod flush;
 pub mod handle_privilege;
+mod kill;
 pub mod privilege;
 pub mod query;
+mod reassign_owned;
 mod show;
 pub mod util;
 pub mod variable;
@@ -112,10 +123,13 @@ pub struct HandlerArgs {
 
 impl HandlerArgs {
     pub fn new(session: Arc<SessionImpl>, stmt: &Statement, sql: &str) -> Result<Self> {
+        let mut with_options = WithOptions::try_from(stmt)?;
+        resolve_secret_refs_in_with_options(&mut with_options, &session)?;
+
         Ok(Self {
             session,
             sql: sql.into(),
-            with_options: WithOptions::try_from(stmt)?,
+            with_options,
             normalized_sql: Self::normalize_sql(stmt),
         })
     }
@@ -128,7 +142,8 @@ impl HandlerArgs {
     fn normalize_sql(stmt: &Statement) -> String {
         let mut stmt = stmt.clone();
         match &mut stmt {
-            Statement::CreateView { or_replace, .. } => {
+            Statement::CreateView { or_replace, .. }
+            | Statement::CreateFunction { or_replace, .. } => {
                 *or_replace = false;
             }
             Statement::CreateTable {
@@ -158,6 +173,11 @@ impl HandlerArgs {
             } => {
                 *if_not_exists = false;
             }
+            Statement::CreateSecret {
+                stmt: CreateSecretStatement { if_not_exists, .. },
+            } => {
+                *if_not_exists = false;
+            }
             _ => {}
         }
         stmt.to_string()
@@ -187,6 +207,9 @@ pub async fn handle(
         Statement::CreateConnection { stmt } => {
             create_connection::handle_create_connection(handler_args, stmt).await
         }
+        Statement::CreateSecret { stmt } => {
+            create_secret::handle_create_secret(handler_args, stmt).await
+        }
         Statement::CreateFunction {
             or_replace,
             temporary,
@@ -273,8 +296,17 @@ pub async fn handle(
         Statement::Revoke { .. } => {
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
+        Statement::DropOwned { owned_by, option } => {
+            drop_owned::handle_drop_owned(handler_args, owned_by, option).await
+        }
+        Statement::ReassignOwned {
+            owned_by,
+            new_owner,
+        } => reassign_owned::handle_reassign_owned(handler_args, owned_by, new_owner).await,
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
-        Statement::ShowObjects(show_object) => show::handle_show_object(handler_args, show_object),
+        Statement::ShowObjects(show_object) => {
+            show::handle_show_object(handler_args, show_object).await
+        }
         Statement::ShowCreateObject { create_type, name } => {
             show::handle_show_create_object(handler_args, create_type, name)
         }
@@ -339,6 +371,9 @@ pub async fn handle(
                     drop_connection::handle_drop_connection(handler_args, object_name, if_exists)
                         .await
                 }
+                ObjectType::Secret => {
+                    drop_secret::handle_drop_secret(handler_args, object_name, if_exists).await
+                }
             }
         }
         // XXX: should we reuse Statement::Drop for DROP FUNCTION?
@@ -358,17 +393,17 @@ pub async fn handle(
             columns,
             query,
             with_options: _, // It is put in OptimizerContext
-            or_replace,      // not supported
+            or_replace,
             emit_mode,
         } => {
-            if or_replace {
-                return Err(ErrorCode::NotImplemented(
-                    "CREATE OR REPLACE VIEW".to_string(),
-                    None.into(),
-                )
-                .into());
-            }
             if materialized {
+                if or_replace {
+                    return Err(ErrorCode::NotImplemented(
+                        "CREATE OR REPLACE MATERIALIZED VIEW".to_string(),
+                        None.into(),
+                    )
+                    .into());
+                }
                 create_mv::handle_create_mv(
                     handler_args,
                     if_not_exists,
@@ -379,11 +414,20 @@ pub async fn handle(
                 )
                 .await
             } else {
-                create_view::handle_create_view(handler_args, if_not_exists, name, columns, *query)
-                    .await
+                create_view::handle_create_view(
+                    handler_args,
+                    or_replace,
+                    if_not_exists,
+                    name,
+                    columns,
+                    *query,
+                )
+                .await
             }
         }
         Statement::Flush => flush::handle_flush(handler_args).await,
+        Statement::Kill(process_id) => kill::handle_kill(handler_args, process_id),
+        Statement::CancelJobs(jobs) => cancel_job::handle_cancel(handler_args, jobs).await,
         Statement::SetVariable {
             local: _,
             variable,
@@ -423,6 +467,18 @@ pub async fn handle(
                 operation @ (AlterTableOperation::AddColumn { .. }
                 | AlterTableOperation::DropColumn { .. }),
         } => alter_table_column::handle_alter_table_column(handler_args, name, operation).await,
+        Statement::AlterTable {
+            name,
+            operation: AlterTableOperation::ChangeOwner { new_owner_name },
+        } => {
+            alter_owner::handle_alter_table_owner(
+                handler_args,
+                TableType::Table,
+                name,
+                new_owner_name,
+            )
+            .await
+        }
         Statement::AlterTable {
             name,
             operation: AlterTableOperation::RenameTable { table_name },
@@ -435,6 +491,18 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterTable {
+            name,
+            operation: AlterTableOperation::SetParallelism { parallelism },
+        } => {
+            alter_parallelism::handle_alter_table_parallelism(
+                handler_args,
+                name,
+                TableType::Table,
+                parallelism,
+            )
+            .await
+        }
         Statement::AlterIndex {
             name,
             operation: AlterIndexOperation::RenameIndex { index_name },
@@ -456,14 +524,77 @@ pub async fn handle(
                 alter_relation_rename::handle_rename_view(handler_args, name, view_name).await
             }
         }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::ChangeOwner { new_owner_name },
+        } => {
+            if materialized {
+                alter_owner::handle_alter_table_owner(
+                    handler_args,
+                    TableType::MaterializedView,
+                    name,
+                    new_owner_name,
+                )
+                .await
+            } else {
+                alter_owner::handle_alter_view_owner(handler_args, name, new_owner_name).await
+            }
+        }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: operation @ (AlterViewOperation::Suspend | AlterViewOperation::Resume),
+        } => {
+            if !materialized {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "SUSPEND/RESUME is only supported for materialized views".to_string(),
+                )
+                .into());
+            }
+            let suspend = matches!(operation, AlterViewOperation::Suspend);
+            alter_mv_suspend::handle_suspend_mv(handler_args, name, suspend).await
+        }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::SetParallelism { parallelism },
+        } => {
+            if !materialized {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "SET PARALLELISM is only supported for materialized views".to_string(),
+                )
+                .into());
+            }
+            alter_parallelism::handle_alter_table_parallelism(
+                handler_args,
+                name,
+                TableType::MaterializedView,
+                parallelism,
+            )
+            .await
+        }
         Statement::AlterSink {
             name,
             operation: AlterSinkOperation::RenameSink { sink_name },
         } => alter_relation_rename::handle_rename_sink(handler_args, name, sink_name).await,
+        Statement::AlterSink {
+            name,
+            operation: AlterSinkOperation::SetParallelism { parallelism },
+        } => {
+            alter_parallelism::handle_alter_sink_parallelism(handler_args, name, parallelism).await
+        }
         Statement::AlterSource {
             name,
             operation: AlterSourceOperation::RenameSource { source_name },
         } => alter_relation_rename::handle_rename_source(handler_args, name, source_name).await,
+        Statement::AlterSource {
+            name,
+            operation: AlterSourceOperation::SetSourceProps { changed_props },
+        } => alter_source_props::handle_alter_source_props(handler_args, name, changed_props).await,
+        Statement::AlterSecret { stmt } => {
+            alter_secret::handle_alter_secret(handler_args, stmt).await
+        }
         Statement::AlterSystem { param, value } => {
             alter_system::handle_alter_system(handler_args, param, value).await
         }
@@ -162,7 +162,7 @@ impl SessionImpl {
     }
 
     /// Returns `true` if the user of the current session is a super user.
-    fn is_super_user(&self) -> bool {
+    pub(crate) fn is_super_user(&self) -> bool {
         let reader = self.env().user_info_reader().read_guard();
 
         if let Some(info) = reader.get_user_by_name(self.user_name()) {
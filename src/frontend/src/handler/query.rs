@@ -16,12 +16,14 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::pg_server::BoxedError;
 use pgwire::types::Format;
 use postgres_types::FromSql;
+use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::QueryMode;
@@ -253,7 +255,7 @@ fn determine_query_mode(batch_plan: PlanRef) -> QueryMode {
     }
 }
 
-struct BatchPlanFragmenterResult {
+pub(crate) struct BatchPlanFragmenterResult {
     pub(crate) plan_fragmenter: BatchPlanFragmenter,
     pub(crate) query_mode: QueryMode,
     pub(crate) schema: Schema,
@@ -261,7 +263,7 @@ struct BatchPlanFragmenterResult {
     pub(crate) _dependent_relations: Vec<TableId>,
 }
 
-fn gen_batch_plan_fragmenter(
+pub(crate) fn gen_batch_plan_fragmenter(
     session: &SessionImpl,
     plan_result: BatchQueryPlanResult,
 ) -> Result<BatchPlanFragmenterResult> {
@@ -298,6 +300,76 @@ fn gen_batch_plan_fragmenter(
     })
 }
 
+/// A stream of raw [`DataChunk`]s from either the local or the distributed query
+/// execution, before they are encoded into pgwire rows. Used by consumers that
+/// serve columnar results, e.g. the Arrow Flight SQL service.
+pub(crate) enum ChunkStream {
+    Local(LocalQueryStream),
+    Distributed(DistributedQueryStream),
+}
+
+impl Stream for ChunkStream {
+    type Item = std::result::Result<DataChunk, BoxedError>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        match self.get_mut() {
+            ChunkStream::Local(stream) => stream.poll_next_unpin(cx),
+            ChunkStream::Distributed(stream) => stream.poll_next_unpin(cx),
+        }
+    }
+}
+
+/// Execute a read-only batch query and return the raw [`DataChunk`] stream,
+/// bypassing the pgwire row encoding.
+pub(crate) async fn execute_chunk_stream(
+    session: Arc<SessionImpl>,
+    plan_fragmenter_result: BatchPlanFragmenterResult,
+) -> Result<(Schema, ChunkStream)> {
+    let BatchPlanFragmenterResult {
+        plan_fragmenter,
+        query_mode,
+        schema,
+        stmt_type,
+        ..
+    } = plan_fragmenter_result;
+
+    if stmt_type != StatementType::SELECT {
+        return Err(ErrorCode::NotSupported(
+            "only read-only queries can be executed as a chunk stream".to_string(),
+            "use the pgwire protocol for DML and DDL statements".to_string(),
+        )
+        .into());
+    }
+
+    let is_barrier_read = session.is_barrier_read();
+    let query = plan_fragmenter.generate_complete_query().await?;
+    tracing::trace!("Generated query after plan fragmenter: {:?}", &query);
+
+    let query_epoch = session.config().get_query_epoch();
+    let query_snapshot = if let Some(query_epoch) = query_epoch {
+        PinnedHummockSnapshot::Other(query_epoch)
+    } else {
+        let hummock_snapshot_manager = session.env().hummock_snapshot_manager();
+        let query_id = query.query_id().clone();
+        let pinned_snapshot = hummock_snapshot_manager.acquire(&query_id).await?;
+        PinnedHummockSnapshot::FrontendPinned(pinned_snapshot, is_barrier_read)
+    };
+    let chunk_stream = match query_mode {
+        QueryMode::Auto => unreachable!(),
+        QueryMode::Local => {
+            ChunkStream::Local(local_execute(session.clone(), query, query_snapshot).await?)
+        }
+        QueryMode::Distributed => ChunkStream::Distributed(
+            distribute_execute(session.clone(), query, query_snapshot).await?,
+        ),
+    };
+
+    Ok((schema, chunk_stream))
+}
+
 async fn execute(
     session: Arc<SessionImpl>,
     plan_fragmenter_result: BatchPlanFragmenterResult,
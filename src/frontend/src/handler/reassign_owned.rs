@@ -0,0 +1,61 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::ErrorCode::PermissionDenied;
+use risingwave_common::error::Result;
+use risingwave_sqlparser::ast::Ident;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::CatalogError;
+
+pub async fn handle_reassign_owned(
+    handler_args: HandlerArgs,
+    owned_by: Vec<Ident>,
+    new_owner: Ident,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+
+    let (user_ids, new_owner_id) = {
+        let user_reader = session.env().user_info_reader().read_guard();
+        let session_user = user_reader
+            .get_user_by_name(session.user_name())
+            .ok_or_else(|| CatalogError::NotFound("user", session.user_name().to_string()))?;
+        if !session_user.is_super {
+            return Err(
+                PermissionDenied("permission denied to reassign objects".to_string()).into(),
+            );
+        }
+        let mut user_ids = Vec::with_capacity(owned_by.len());
+        for user_name in &owned_by {
+            let user_name = user_name.real_value();
+            let user = user_reader
+                .get_user_by_name(&user_name)
+                .ok_or(CatalogError::NotFound("user", user_name))?;
+            user_ids.push(user.id);
+        }
+        let new_owner_name = new_owner.real_value();
+        let new_owner = user_reader
+            .get_user_by_name(&new_owner_name)
+            .ok_or(CatalogError::NotFound("user", new_owner_name))?;
+        (user_ids, new_owner.id)
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .reassign_owned(user_ids, new_owner_id)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::REASSIGN_OWNED))
+}
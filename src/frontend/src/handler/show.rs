@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use itertools::Itertools;
@@ -23,6 +24,7 @@ use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_connector::source::kafka::PRIVATELINK_CONNECTION;
 use risingwave_pb::catalog::connection;
+use risingwave_pb::ddl_service::BackfillProgress;
 use risingwave_sqlparser::ast::{Ident, ObjectName, ShowCreateType, ShowObject};
 use serde_json;
 
@@ -77,8 +79,18 @@ fn schema_or_default(schema: &Option<Ident>) -> String {
         .map_or_else(|| DEFAULT_SCHEMA_NAME.to_string(), |s| s.real_value())
 }
 
-pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Result<RwPgResponse> {
+pub async fn handle_show_object(
+    handler_args: HandlerArgs,
+    command: ShowObject,
+) -> Result<RwPgResponse> {
     let session = handler_args.session;
+
+    // Handled separately, as it calls meta and must not hold the catalog read guard across await
+    // points.
+    if let ShowObject::Jobs = command {
+        return handle_show_jobs(&session).await;
+    }
+
     let catalog_reader = session.env().catalog_reader().read_guard();
 
     let names = match command {
@@ -138,6 +150,72 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
                 )
                 .into());
         }
+        ShowObject::ProcessList => {
+            // Like Postgres, non-superusers only see the sessions of their own user.
+            let is_super_user = session.is_super_user();
+            let rows = {
+                let sessions_map = session.env().sessions_map().lock().unwrap();
+                sessions_map
+                    .values()
+                    .filter(|s| is_super_user || s.user_name() == session.user_name())
+                    .map(|s| {
+                        let (info, time, state) = match s.running_sql() {
+                            Some((sql, elapsed)) => (
+                                Some(sql),
+                                Some(format!("{}ms", elapsed.as_millis())),
+                                "running",
+                            ),
+                            None => (None, None, "idle"),
+                        };
+                        Row::new(vec![
+                            Some(s.session_id().0.to_string().into()),
+                            Some(s.user_name().to_string().into()),
+                            Some(s.database().to_string().into()),
+                            Some(state.into()),
+                            time.map(Into::into),
+                            info.map(Into::into),
+                        ])
+                    })
+                    .collect_vec()
+            };
+            return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+                .values(
+                    rows.into(),
+                    vec![
+                        PgFieldDescriptor::new(
+                            "Id".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                        PgFieldDescriptor::new(
+                            "User".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                        PgFieldDescriptor::new(
+                            "Database".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                        PgFieldDescriptor::new(
+                            "State".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                        PgFieldDescriptor::new(
+                            "Time".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                        PgFieldDescriptor::new(
+                            "Info".to_owned(),
+                            DataType::Varchar.to_oid(),
+                            DataType::Varchar.type_len(),
+                        ),
+                    ],
+                )
+                .into());
+        }
         ShowObject::Indexes { table } => {
             let indexes = get_indexes_from_table(&session, table)?;
             let rows = indexes_to_rows(indexes);
@@ -289,6 +367,7 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
                 )
                 .into());
         }
+        ShowObject::Jobs => unreachable!("handled above"),
     };
 
     let rows = names
@@ -308,6 +387,83 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
         .into())
 }
 
+/// Show all creating streaming jobs, with one row per backfilling fragment so that users can tell
+/// how far each part of a long-running `CREATE MATERIALIZED VIEW` has proceeded.
+async fn handle_show_jobs(session: &SessionImpl) -> Result<RwPgResponse> {
+    let mut backfill_by_job: HashMap<u64, Vec<BackfillProgress>> = HashMap::new();
+    for progress in session.env().meta_client().list_backfill_progress().await? {
+        backfill_by_job
+            .entry(progress.job_id as u64)
+            .or_default()
+            .push(progress);
+    }
+
+    let mut rows = vec![];
+    for job in session.env().meta_client().list_ddl_progress().await? {
+        match backfill_by_job.get(&job.id) {
+            Some(fragments) => {
+                for fragment in fragments {
+                    rows.push(Row::new(vec![
+                        Some(job.id.to_string().into()),
+                        Some(job.statement.clone().into()),
+                        Some(job.progress.clone().into()),
+                        Some(fragment.fragment_id.to_string().into()),
+                        Some(fragment.consumed_rows.to_string().into()),
+                        Some(fragment.upstream_total_key_count.to_string().into()),
+                    ]));
+                }
+            }
+            // The job is tracked but none of its actors has reported yet.
+            None => rows.push(Row::new(vec![
+                Some(job.id.to_string().into()),
+                Some(job.statement.into()),
+                Some(job.progress.into()),
+                None,
+                None,
+                None,
+            ])),
+        }
+    }
+
+    Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+        .values(
+            rows.into(),
+            vec![
+                PgFieldDescriptor::new(
+                    "Id".to_owned(),
+                    DataType::Int64.to_oid(),
+                    DataType::Int64.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Statement".to_owned(),
+                    DataType::Varchar.to_oid(),
+                    DataType::Varchar.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Progress".to_owned(),
+                    DataType::Varchar.to_oid(),
+                    DataType::Varchar.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Fragment Id".to_owned(),
+                    DataType::Int32.to_oid(),
+                    DataType::Int32.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Consumed Rows".to_owned(),
+                    DataType::Int64.to_oid(),
+                    DataType::Int64.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Total Rows".to_owned(),
+                    DataType::Int64.to_oid(),
+                    DataType::Int64.type_len(),
+                ),
+            ],
+        )
+        .into())
+}
+
 pub fn handle_show_create_object(
     handle_args: HandlerArgs,
     show_create_type: ShowCreateType,
@@ -60,6 +60,7 @@ mod meta_client;
 pub mod test_utils;
 mod user;
 
+pub mod flight_service;
 pub mod health_service;
 mod monitor;
 
@@ -115,6 +116,11 @@ pub struct FrontendOpts {
     )]
     pub health_check_listener_addr: String,
 
+    /// The address the Arrow Flight SQL service listens to, serving read-only
+    /// batch query results in columnar form. Disabled if not specified.
+    #[clap(long, env = "RW_FLIGHT_SQL_LISTEN_ADDR")]
+    pub flight_sql_listen_addr: Option<String>,
+
     /// The path of `risingwave.toml` configuration file.
     ///
     /// If empty, default configuration values will be used.
@@ -160,7 +166,21 @@ pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
     // slow compile in release mode.
     Box::pin(async move {
         let listen_addr = opts.listen_addr.clone();
+        let flight_sql_listen_addr = opts.flight_sql_listen_addr.clone();
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
+        if let Some(flight_sql_listen_addr) = flight_sql_listen_addr {
+            let flight_sql_addr = flight_sql_listen_addr.parse().unwrap();
+            let flight_service =
+                flight_service::FlightSqlServiceImpl::new(session_mgr.clone()).into_server();
+            tokio::spawn(async move {
+                tracing::info!("Flight SQL listener is set up on {}", flight_sql_addr);
+                tonic::transport::Server::builder()
+                    .add_service(flight_service)
+                    .serve(flight_sql_addr)
+                    .await
+                    .unwrap();
+            });
+        }
         pg_serve(&listen_addr, session_mgr, Some(TlsConfig::new_default()))
             .await
             .unwrap();
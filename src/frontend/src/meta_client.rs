@@ -16,10 +16,11 @@ use std::collections::HashMap;
 
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
-use risingwave_pb::ddl_service::DdlProgress;
-use risingwave_pb::hummock::HummockSnapshot;
+use risingwave_pb::ddl_service::{BackfillProgress, DdlProgress};
+use risingwave_pb::hummock::{CompactTaskSummary, HummockSnapshot};
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
-use risingwave_pb::meta::CreatingJobInfo;
+use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::meta::{BarrierLatencyEntry, RecoveryEvent, RecoveryRecord};
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 
@@ -36,7 +37,13 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn flush(&self, checkpoint: bool) -> Result<HummockSnapshot>;
 
-    async fn cancel_creating_jobs(&self, infos: Vec<CreatingJobInfo>) -> Result<()>;
+    async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<()>;
+
+    async fn pause_streaming_job(&self, table_id: u32) -> Result<()>;
+
+    async fn resume_streaming_job(&self, table_id: u32) -> Result<()>;
+
+    async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()>;
 
     async fn list_table_fragments(
         &self,
@@ -54,6 +61,16 @@ pub trait FrontendMetaClient: Send + Sync {
     async fn set_system_param(&self, param: String, value: Option<String>) -> Result<()>;
 
     async fn list_ddl_progress(&self) -> Result<Vec<DdlProgress>>;
+
+    async fn list_backfill_progress(&self) -> Result<Vec<BackfillProgress>>;
+
+    async fn list_compact_task_history(&self) -> Result<Vec<CompactTaskSummary>>;
+
+    async fn list_barrier_latency(&self) -> Result<Vec<BarrierLatencyEntry>>;
+
+    async fn list_recovery_events(&self) -> Result<Vec<RecoveryEvent>>;
+
+    async fn list_recoveries(&self) -> Result<Vec<RecoveryRecord>>;
 }
 
 pub struct FrontendMetaClientImpl(pub MetaClient);
@@ -72,8 +89,20 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.flush(checkpoint).await
     }
 
-    async fn cancel_creating_jobs(&self, infos: Vec<CreatingJobInfo>) -> Result<()> {
-        self.0.cancel_creating_jobs(infos).await
+    async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<()> {
+        self.0.cancel_creating_jobs(jobs).await
+    }
+
+    async fn pause_streaming_job(&self, table_id: u32) -> Result<()> {
+        self.0.pause_streaming_job(table_id).await
+    }
+
+    async fn resume_streaming_job(&self, table_id: u32) -> Result<()> {
+        self.0.resume_streaming_job(table_id).await
+    }
+
+    async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()> {
+        self.0.alter_parallelism(table_id, parallelism).await
     }
 
     async fn list_table_fragments(
@@ -108,4 +137,25 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         let ddl_progress = self.0.get_ddl_progress().await?;
         Ok(ddl_progress)
     }
+
+    async fn list_backfill_progress(&self) -> Result<Vec<BackfillProgress>> {
+        let backfill_progress = self.0.get_backfill_progress().await?;
+        Ok(backfill_progress)
+    }
+
+    async fn list_compact_task_history(&self) -> Result<Vec<CompactTaskSummary>> {
+        self.0.list_compact_task_history().await
+    }
+
+    async fn list_barrier_latency(&self) -> Result<Vec<BarrierLatencyEntry>> {
+        self.0.list_barrier_latency(0).await
+    }
+
+    async fn list_recovery_events(&self) -> Result<Vec<RecoveryEvent>> {
+        self.0.list_recovery_events().await
+    }
+
+    async fn list_recoveries(&self) -> Result<Vec<RecoveryRecord>> {
+        self.0.list_recoveries().await
+    }
 }
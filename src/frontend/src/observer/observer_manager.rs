@@ -58,7 +58,8 @@ impl ObserverState for FrontendObserverNode {
             | Info::Schema(_)
             | Info::RelationGroup(_)
             | Info::Function(_)
-            | Info::Connection(_) => {
+            | Info::Connection(_)
+            | Info::Secret(_) => {
                 self.handle_catalog_notification(resp);
             }
             Info::Node(node) => {
@@ -114,6 +115,7 @@ impl ObserverState for FrontendObserverNode {
             views,
             functions,
             connections,
+            secrets,
             users,
             parallel_unit_mappings,
             serving_parallel_unit_mappings,
@@ -152,6 +154,9 @@ impl ObserverState for FrontendObserverNode {
         for connection in connections {
             catalog_guard.create_connection(&connection)
         }
+        for secret in secrets {
+            catalog_guard.create_secret(&secret)
+        }
         for user in users {
             user_guard.create_user(user)
         }
@@ -287,6 +292,7 @@ impl FrontendObserverNode {
                     function.schema_id,
                     function.id.into(),
                 ),
+                Operation::Update => catalog_guard.update_function(function),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             Info::Connection(connection) => match resp.operation() {
@@ -298,6 +304,14 @@ impl FrontendObserverNode {
                 ),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
+            Info::Secret(secret) => match resp.operation() {
+                Operation::Add => catalog_guard.create_secret(secret),
+                Operation::Delete => {
+                    catalog_guard.drop_secret(secret.database_id, secret.schema_id, secret.id)
+                }
+                Operation::Update => catalog_guard.update_secret(secret),
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
             _ => unreachable!(),
         }
         assert!(
@@ -436,6 +436,10 @@ impl LogicalOptimizer {
         // If share plan is disable, we need to remove all the share operator generated by the
         // binder, e.g. CTE and View. However, we still need to share source to ensure self
         // source join can return correct result.
+        //
+        // In both modes, duplicate scans of the same source (e.g. the branches of a `UNION ALL`)
+        // end up under a single share operator, so only one source executor is instantiated and
+        // all downstream branches merge its changes with aligned barriers.
         let enable_share_plan = ctx.session_ctx().config().get_enable_share_plan();
         if enable_share_plan {
             // Common sub-plan sharing.
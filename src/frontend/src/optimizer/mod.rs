@@ -284,9 +284,11 @@ impl PlanRoot {
             ApplyOrder::BottomUp,
         ));
 
+        // Rewrite joins with index to delta join. The rule validates that suitable indexes
+        // exist on both join sides and falls back to hash join otherwise, so it is enabled by
+        // default and can be opted out via the session config.
         if ctx.session_ctx().config().get_streaming_enable_delta_join() {
             // TODO: make it a logical optimization.
-            // Rewrite joins with index to delta join
             plan = plan.optimize_by_rules(&OptimizationStage::new(
                 "To IndexDeltaJoin",
                 vec![IndexDeltaJoinRule::create()],
@@ -522,6 +524,7 @@ impl PlanRoot {
         definition: String,
         properties: WithOptions,
         emit_on_window_close: bool,
+        from_mv: bool,
     ) -> Result<StreamSink> {
         let stream_plan = self.gen_optimized_stream_plan(emit_on_window_close)?;
 
@@ -534,6 +537,7 @@ impl PlanRoot {
             self.out_names.clone(),
             definition,
             properties,
+            from_mv,
         )
     }
 
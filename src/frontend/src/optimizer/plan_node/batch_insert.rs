@@ -113,6 +113,12 @@ impl ToBatchPb for BatchInsert {
             },
             row_id_index: self.logical.row_id_index.map(|index| index as _),
             returning: self.logical.returning,
+            dml_parallelism: self
+                .base
+                .ctx
+                .session_ctx()
+                .config()
+                .get_batch_dml_parallelism() as u32,
         })
     }
 }
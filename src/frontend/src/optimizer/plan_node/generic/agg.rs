@@ -93,6 +93,18 @@ impl<PlanRef: GenericPlanRef> Agg<PlanRef> {
         self.two_phase_agg_forced() && self.can_two_phase_agg()
     }
 
+    /// Whether to spread each group-by key over salted partial aggregates merged downstream.
+    /// This removes the single-actor bottleneck on an extremely hot key, at the cost of an
+    /// extra merge step. Only applies to group-by aggs whose calls support two phase agg.
+    pub(crate) fn must_try_key_spread_agg(&self) -> bool {
+        self.ctx()
+            .session_ctx()
+            .config()
+            .get_streaming_enable_key_spread_agg()
+            && self.group_key.count_ones(..) != 0
+            && self.can_two_phase_agg()
+    }
+
     fn two_phase_agg_forced(&self) -> bool {
         self.ctx().session_ctx().config().get_force_two_phase_agg()
     }
@@ -410,6 +422,15 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                         type_name: String::default(),
                     });
                 }
+                AggKind::ApproxPercentile => {
+                    // Add centroids column.
+                    table_builder.add_column(&Field {
+                        data_type: DataType::List(Box::new(DataType::Float64)),
+                        name: String::from("centroids"),
+                        sub_fields: vec![],
+                        type_name: String::default(),
+                    });
+                }
                 _ => panic!("state of agg kind `{agg_kind}` is not supposed to be `TableState`"),
             }
 
@@ -490,6 +511,16 @@ impl<PlanRef: stream::StreamPlanRef> Agg<PlanRef> {
                         AggCallState::Table(Box::new(state))
                     }
                 }
+                AggKind::ApproxPercentile => {
+                    if !in_append_only {
+                        // FIXME: now the approx percentile on a non-append-only stream does not
+                        // really has state and can handle failover or scale-out correctly
+                        AggCallState::ResultValue
+                    } else {
+                        let state = gen_table_state(agg_call.agg_kind);
+                        AggCallState::Table(Box::new(state))
+                    }
+                }
                 // TODO: is its state a Table?
                 AggKind::BitAnd
                 | AggKind::BitOr
@@ -756,6 +787,7 @@ impl PlanAggCall {
             | AggKind::Max
             | AggKind::StringAgg
             | AggKind::FirstValue
+            | AggKind::ApproxPercentile
             | AggKind::PercentileCont
             | AggKind::PercentileDisc
             | AggKind::Mode => self.agg_kind,
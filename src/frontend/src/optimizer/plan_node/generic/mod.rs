@@ -50,6 +50,8 @@ mod share;
 pub use share::*;
 mod dedup;
 pub use dedup::*;
+mod session_window;
+pub use session_window::*;
 mod intersect;
 pub use intersect::*;
 mod over_window;
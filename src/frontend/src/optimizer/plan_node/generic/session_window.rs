@@ -0,0 +1,130 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use pretty_xmlish::{Pretty, Str, XmlNode};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Interval, IntervalDisplay};
+use risingwave_common::util::column_index_mapping::ColIndexMapping;
+
+use super::{DistillUnit, GenericPlanNode, GenericPlanRef};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, InputRefDisplay, Literal};
+use crate::optimizer::plan_node::utils::childless_record;
+use crate::optimizer::property::FunctionalDependencySet;
+use crate::utils::ColIndexMappingRewriteExt;
+use crate::OptimizerContextRef;
+
+/// [`SessionWindow`] implements the session time-window table function. Each input row is
+/// assigned to a session window, where consecutive rows whose time values are within `gap` of
+/// each other belong to the same window. `window_start` and `window_end` columns are appended
+/// at the end of the input schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionWindow<PlanRef> {
+    pub input: PlanRef,
+    pub time_col: InputRef,
+    pub gap: Interval,
+}
+
+impl<PlanRef: GenericPlanRef> SessionWindow<PlanRef> {
+    pub fn window_start_col_idx(&self) -> usize {
+        self.input.schema().len()
+    }
+
+    pub fn window_end_col_idx(&self) -> usize {
+        self.input.schema().len() + 1
+    }
+
+    /// Mapping from the input columns to the output columns, which appends the two window
+    /// columns at the end.
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        ColIndexMapping::identity_or_none(self.input.schema().len(), self.input.schema().len() + 2)
+    }
+
+    /// Derive the expression evaluating the window end candidate (`time_col + gap`) of each
+    /// input row. The window end of a session is the maximum of the candidates of its rows.
+    pub fn derive_window_end_expr(&self) -> Result<ExprImpl> {
+        let gap_expr = Literal::new(Some(self.gap.into()), DataType::Interval).into();
+        Ok(FunctionCall::new(
+            ExprType::Add,
+            vec![
+                ExprImpl::InputRef(Box::new(self.time_col.clone())),
+                gap_expr,
+            ],
+        )?
+        .into())
+    }
+
+    pub fn fmt_with_name(&self, f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+        let mut builder = f.debug_struct(name);
+        builder.field(
+            "time_col",
+            &InputRefDisplay {
+                input_ref: &self.time_col,
+                input_schema: self.input.schema(),
+            },
+        );
+        builder.field("gap", &IntervalDisplay { core: &self.gap });
+        builder.finish()
+    }
+}
+
+impl<PlanRef: GenericPlanRef> DistillUnit for SessionWindow<PlanRef> {
+    fn distill_with_name<'a>(&self, name: impl Into<Str<'a>>) -> XmlNode<'a> {
+        childless_record(
+            name,
+            vec![
+                (
+                    "time_col",
+                    Pretty::display(&InputRefDisplay {
+                        input_ref: &self.time_col,
+                        input_schema: self.input.schema(),
+                    }),
+                ),
+                ("gap", Pretty::debug(&self.gap)),
+            ],
+        )
+    }
+}
+
+impl<PlanRef: GenericPlanRef> GenericPlanNode for SessionWindow<PlanRef> {
+    fn schema(&self) -> Schema {
+        let window_type = self.time_col.data_type.clone();
+        self.input
+            .schema()
+            .clone()
+            .into_fields()
+            .into_iter()
+            .chain([
+                Field::with_name(window_type.clone(), "window_start"),
+                Field::with_name(window_type, "window_end"),
+            ])
+            .collect()
+    }
+
+    fn logical_pk(&self) -> Option<Vec<usize>> {
+        // Each input row appears in exactly one session window, so the input pk is preserved.
+        Some(self.input.logical_pk().to_vec())
+    }
+
+    fn ctx(&self) -> OptimizerContextRef {
+        self.input.ctx()
+    }
+
+    fn functional_dependency(&self) -> FunctionalDependencySet {
+        self.i2o_col_mapping()
+            .rewrite_functional_dependency_set(self.input.functional_dependency().clone())
+    }
+}
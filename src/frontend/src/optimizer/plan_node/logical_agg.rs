@@ -195,7 +195,10 @@ impl LogicalAgg {
         // Shuffle agg
         // If we have group key, and we won't try two phase agg optimization at all,
         // we will always choose shuffle agg over single agg.
-        if !self.group_key().is_empty() && !self.core.must_try_two_phase_agg() {
+        if !self.group_key().is_empty()
+            && !self.core.must_try_two_phase_agg()
+            && !self.core.must_try_key_spread_agg()
+        {
             return self.gen_shuffle_plan(stream_input);
         }
 
@@ -206,7 +209,7 @@ impl LogicalAgg {
         }
 
         debug_assert!(if !self.group_key().is_empty() {
-            self.core.must_try_two_phase_agg()
+            self.core.must_try_two_phase_agg() || self.core.must_try_key_spread_agg()
         } else {
             self.core.can_two_phase_agg()
         });
@@ -225,13 +228,21 @@ impl LogicalAgg {
         // The only remaining strategy is Vnode-based 2-phase agg.
         // We shall first distribute it by PK,
         // so it obeys consistent hash strategy via [`Distribution::HashShard`].
-        let stream_input =
-            if *input_dist == Distribution::SomeShard && self.core.must_try_two_phase_agg() {
-                RequiredDist::shard_by_key(stream_input.schema().len(), stream_input.logical_pk())
-                    .enforce_if_not_satisfies(stream_input, &Order::any())?
-            } else {
-                stream_input
-            };
+        //
+        // For key-spread agg we also redistribute an input that is already distributed by the
+        // group key: the vnode of the stream key then acts as the salt that spreads a hot
+        // group-by key over multiple partial aggregates.
+        let stream_input = if (*input_dist == Distribution::SomeShard
+            && self.core.must_try_two_phase_agg())
+            || (self.core.must_try_key_spread_agg()
+                && (*input_dist == Distribution::SomeShard
+                    || self.core.hash_agg_dist_satisfied_by_input_dist(input_dist)))
+        {
+            RequiredDist::shard_by_key(stream_input.schema().len(), stream_input.logical_pk())
+                .enforce_if_not_satisfies(stream_input, &Order::any())?
+        } else {
+            stream_input
+        };
         let input_dist = stream_input.distribution();
 
         // Vnode-based 2-phase agg
@@ -36,9 +36,10 @@ use crate::optimizer::plan_node::generic::DynamicFilter;
 use crate::optimizer::plan_node::stream::StreamPlanRef;
 use crate::optimizer::plan_node::utils::IndicesDisplay;
 use crate::optimizer::plan_node::{
-    BatchHashJoin, BatchLookupJoin, BatchNestedLoopJoin, ColumnPruningContext, EqJoinPredicate,
-    LogicalFilter, LogicalScan, PredicatePushdownContext, RewriteStreamContext,
-    StreamDynamicFilter, StreamFilter, StreamTableScan, StreamTemporalJoin, ToStreamContext,
+    BandConditionDesc, BatchHashJoin, BatchLookupJoin, BatchNestedLoopJoin, ColumnPruningContext,
+    EqJoinPredicate, LogicalFilter, LogicalScan, PredicatePushdownContext, RewriteStreamContext,
+    StreamBandJoin, StreamDynamicFilter, StreamFilter, StreamTableScan, StreamTemporalJoin,
+    ToStreamContext,
 };
 use crate::optimizer::plan_visitor::LogicalCardinalityExt;
 use crate::optimizer::property::{Distribution, Order, RequiredDist};
@@ -675,8 +676,8 @@ impl ExprRewritable for LogicalJoin {
 ///    then we proceed. Else abort.
 /// 2. Then, we collect `InputRef`s in the conjunction.
 /// 3. If they are all columns in the given side of join eq condition, then we proceed. Else abort.
-/// 4. We then rewrite the `ExprImpl`, by replacing `InputRef` column indices with
-///    the equivalent in the other side.
+/// 4. We then rewrite the `ExprImpl`, by replacing `InputRef` column indices with the equivalent in
+///    the other side.
 ///
 /// # Arguments
 ///
@@ -1201,6 +1202,89 @@ impl LogicalJoin {
         }
     }
 
+    fn to_stream_band_join(&self, ctx: &mut ToStreamContext) -> Result<Option<PlanRef>> {
+        // If every predicate is a comparison (<, <=, >, >=) between the two inputs and some
+        // left column is bounded from both sides by right columns, the join is a band join
+        // and can be converted into a `StreamBandJoin`.
+
+        // Check if `Inner`
+        if self.join_type() != JoinType::Inner {
+            return Ok(None);
+        }
+
+        let left_len = self.left().schema().len();
+
+        // Classify each conjunction as a lower bound, an upper bound, or a residual
+        // condition. `as_comparison_cond` orders the input refs by index, so the left input
+        // always comes first in the returned tuple.
+        let mut lower_bounds = vec![];
+        let mut upper_bounds = vec![];
+        for (i, conjunction) in self.on().conjunctions.iter().enumerate() {
+            let Some((left_ref, comparator, right_ref)) = conjunction.as_comparison_cond() else {
+                continue;
+            };
+            if left_ref.index >= left_len || right_ref.index < left_len {
+                continue;
+            }
+            // We align input types on all join predicates with cmp operator
+            if left_ref.return_type() != right_ref.return_type() {
+                continue;
+            }
+            match comparator {
+                ExprType::GreaterThan | ExprType::GreaterThanOrEqual => {
+                    lower_bounds.push((i, left_ref, right_ref, comparator));
+                }
+                ExprType::LessThan | ExprType::LessThanOrEqual => {
+                    upper_bounds.push((i, left_ref, right_ref, comparator));
+                }
+                _ => continue,
+            }
+        }
+
+        // Pick the first left column bounded from both sides as the band column.
+        let Some(((lower_i, _, lower_ref, lower_cmp), (upper_i, left_ref, upper_ref, upper_cmp))) =
+            lower_bounds.iter().find_map(|lower| {
+                upper_bounds
+                    .iter()
+                    .find(|upper| upper.1.index == lower.1.index)
+                    .map(|upper| (lower.clone(), upper.clone()))
+            })
+        else {
+            return Ok(None);
+        };
+
+        let residual = Condition {
+            conjunctions: self
+                .on()
+                .conjunctions
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != lower_i && *i != upper_i)
+                .map(|(_, expr)| expr.clone())
+                .collect(),
+        };
+
+        let left = self.left().to_stream(ctx)?;
+        let right = self.right().to_stream_with_dist_required(
+            &RequiredDist::PhysicalDist(Distribution::Broadcast),
+            ctx,
+        )?;
+
+        let mut core = self.core.clone();
+        core.left = left;
+        core.right = right;
+
+        let band = BandConditionDesc {
+            left_index: left_ref.index,
+            right_lower_index: lower_ref.index - left_len,
+            right_upper_index: upper_ref.index - left_len,
+            lower_inclusive: lower_cmp == ExprType::GreaterThanOrEqual,
+            upper_inclusive: upper_cmp == ExprType::LessThanOrEqual,
+            residual,
+        };
+        Ok(Some(StreamBandJoin::new(core, band).into()))
+    }
+
     pub fn index_lookup_join_to_batch_lookup_join(&self) -> Result<PlanRef> {
         let predicate = EqJoinPredicate::create(
             self.left().schema().len(),
@@ -1283,6 +1367,8 @@ impl ToStream for LogicalJoin {
             self.to_stream_dynamic_filter(self.on().clone(), ctx)?
         {
             Ok(dynamic_filter)
+        } else if let Some(band_join) = self.to_stream_band_join(ctx)? {
+            Ok(band_join)
         } else {
             Err(RwError::from(ErrorCode::NotSupported(
                 "streaming nested-loop join".to_string(),
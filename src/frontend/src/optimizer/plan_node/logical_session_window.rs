@@ -0,0 +1,219 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::Interval;
+
+use super::utils::impl_distill_by_unit;
+use super::{
+    gen_filter_and_pushdown, generic, ColPrunable, ColumnPruningContext, ExprRewritable,
+    LogicalFilter, LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary, PredicatePushdown,
+    PredicatePushdownContext, RewriteStreamContext, StreamSessionWindow, StreamSort, ToBatch,
+    ToStream, ToStreamContext,
+};
+use crate::expr::{ExprType, FunctionCall, InputRef};
+use crate::optimizer::property::{Order, RequiredDist};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalSessionWindow` implements the session time-window table function, assigning each
+/// input row to a session window. Consecutive rows whose time values are within `gap` of each
+/// other belong to the same window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogicalSessionWindow {
+    pub base: PlanBase,
+    core: generic::SessionWindow<PlanRef>,
+}
+
+impl LogicalSessionWindow {
+    fn new(input: PlanRef, time_col: InputRef, gap: Interval) -> Self {
+        let core = generic::SessionWindow {
+            input,
+            time_col,
+            gap,
+        };
+        let base = PlanBase::new_logical_with_core(&core);
+        LogicalSessionWindow { base, core }
+    }
+
+    /// Used for binder and planner. The function will add a filter operator to ignore records
+    /// with NULL time value, just like `LogicalHopWindow::create`.
+    pub fn create(input: PlanRef, time_col: InputRef, gap: Interval) -> PlanRef {
+        let input = LogicalFilter::create_with_expr(
+            input,
+            FunctionCall::new(ExprType::IsNotNull, vec![time_col.clone().into()])
+                .unwrap()
+                .into(),
+        );
+        Self::new(input, time_col, gap).into()
+    }
+
+    pub fn time_col(&self) -> &InputRef {
+        &self.core.time_col
+    }
+
+    pub fn gap(&self) -> Interval {
+        self.core.gap
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalSessionWindow {
+    fn input(&self) -> PlanRef {
+        self.core.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.core.time_col.clone(), self.core.gap)
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let mut time_col = self.core.time_col.clone();
+        time_col.index = input_col_change.map(time_col.index);
+        let new_input_len = input.schema().len();
+        // Map the input columns with `input_col_change` and keep the two window columns at the
+        // end of the schema.
+        let mut map = (0..self.core.window_start_col_idx())
+            .map(|idx| input_col_change.try_map(idx))
+            .collect_vec();
+        map.push(Some(new_input_len));
+        map.push(Some(new_input_len + 1));
+        (
+            Self::new(input, time_col, self.core.gap),
+            ColIndexMapping::with_target_size(map, new_input_len + 2),
+        )
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalSessionWindow}
+impl_distill_by_unit!(LogicalSessionWindow, core, "LogicalSessionWindow");
+
+impl ColPrunable for LogicalSessionWindow {
+    fn prune_col(&self, required_cols: &[usize], ctx: &mut ColumnPruningContext) -> PlanRef {
+        let input_len = self.input().schema().len();
+        let input_required_cols = {
+            let mut tmp = FixedBitSet::with_capacity(input_len);
+            for &idx in required_cols {
+                if idx < input_len {
+                    tmp.insert(idx);
+                }
+            }
+            // The time column is always required for sessionization.
+            tmp.insert(self.core.time_col.index());
+            tmp.ones().collect_vec()
+        };
+        let input_change = ColIndexMapping::with_remaining_columns(&input_required_cols, input_len);
+        let new_input = self.input().prune_col(&input_required_cols, ctx);
+        let (new_session, out_col_change) = self.rewrite_with_input(new_input, input_change);
+        let new_session: PlanRef = new_session.into();
+
+        let output_required_cols = required_cols
+            .iter()
+            .map(|&idx| out_col_change.map(idx))
+            .collect_vec();
+        if output_required_cols
+            .iter()
+            .copied()
+            .eq(0..new_session.schema().len())
+        {
+            new_session
+        } else {
+            let src_size = new_session.schema().len();
+            LogicalProject::with_mapping(
+                new_session,
+                ColIndexMapping::with_remaining_columns(&output_required_cols, src_size),
+            )
+            .into()
+        }
+    }
+}
+
+impl ExprRewritable for LogicalSessionWindow {}
+
+impl PredicatePushdown for LogicalSessionWindow {
+    /// Keep predicates on the window columns (`window_start`, `window_end`), the rest may be
+    /// pushed down.
+    fn predicate_pushdown(
+        &self,
+        predicate: Condition,
+        ctx: &mut PredicatePushdownContext,
+    ) -> PlanRef {
+        let mut window_columns = FixedBitSet::with_capacity(self.schema().len());
+        window_columns.insert(self.core.window_start_col_idx());
+        window_columns.insert(self.core.window_end_col_idx());
+        let (window_pred, pushed_predicate) = predicate.split_disjoint(&window_columns);
+        let mut mapping = self
+            .core
+            .i2o_col_mapping()
+            .inverse()
+            .expect("must be invertible");
+        let pushed_predicate = pushed_predicate.rewrite_expr(&mut mapping);
+        gen_filter_and_pushdown(self, window_pred, pushed_predicate, ctx)
+    }
+}
+
+impl ToBatch for LogicalSessionWindow {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Err(ErrorCode::NotImplemented(
+            "Session window in batch queries is not supported yet".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+}
+
+impl ToStream for LogicalSessionWindow {
+    fn to_stream(&self, ctx: &mut ToStreamContext) -> Result<PlanRef> {
+        let input = self.input().to_stream(ctx)?;
+        if !input.append_only() {
+            return Err(ErrorCode::NotImplemented(
+                "Session window on a non-append-only input is not supported yet".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        if !input
+            .watermark_columns()
+            .contains(self.core.time_col.index())
+        {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "The time column of session window must be a watermark column".to_string(),
+            )
+            .into());
+        }
+        // Sessions are derived from globally consecutive rows, so a single-parallelism sort by
+        // the time column is enforced below the session window.
+        let input = RequiredDist::single().enforce_if_not_satisfies(input, &Order::any())?;
+        let sort = StreamSort::new(input, self.core.time_col.index());
+
+        let mut core = self.core.clone();
+        core.input = sort.into();
+        let window_end_expr = core.derive_window_end_expr()?;
+        Ok(StreamSessionWindow::new(core, window_end_expr).into())
+    }
+
+    fn logical_rewrite_for_stream(
+        &self,
+        ctx: &mut RewriteStreamContext,
+    ) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input().logical_rewrite_for_stream(ctx)?;
+        let (session, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((session.into(), out_col_change))
+    }
+}
@@ -46,6 +46,10 @@ use crate::utils::{ColIndexMapping, Condition, IndexRewriter};
 /// can limit the timestamp range when querying it directly with batch query. The column type is
 /// [`DataType::Timestamptz`]. For more details, please refer to
 /// [this rfc](https://github.com/risingwavelabs/rfcs/pull/20).
+///
+/// As the column is populated from the broker message timestamp in the source executor, a
+/// watermark can also be declared on it to derive event time when the payload itself carries no
+/// timestamp.
 pub const KAFKA_TIMESTAMP_COLUMN_NAME: &str = "_rw_kafka_timestamp";
 
 /// `LogicalSource` returns contents of a table or other equivalent object
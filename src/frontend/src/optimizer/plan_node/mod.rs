@@ -634,6 +634,7 @@ mod logical_over_window;
 mod logical_project;
 mod logical_project_set;
 mod logical_scan;
+mod logical_session_window;
 mod logical_share;
 mod logical_source;
 mod logical_table_function;
@@ -641,6 +642,7 @@ mod logical_topn;
 mod logical_union;
 mod logical_update;
 mod logical_values;
+mod stream_band_join;
 mod stream_dedup;
 mod stream_delta_join;
 mod stream_dml;
@@ -658,6 +660,7 @@ mod stream_now;
 mod stream_project;
 mod stream_project_set;
 mod stream_row_id_gen;
+mod stream_session_window;
 mod stream_simple_agg;
 mod stream_sink;
 mod stream_sort;
@@ -716,6 +719,7 @@ pub use logical_over_window::LogicalOverWindow;
 pub use logical_project::LogicalProject;
 pub use logical_project_set::LogicalProjectSet;
 pub use logical_scan::LogicalScan;
+pub use logical_session_window::LogicalSessionWindow;
 pub use logical_share::LogicalShare;
 pub use logical_source::LogicalSource;
 pub use logical_table_function::LogicalTableFunction;
@@ -723,6 +727,7 @@ pub use logical_topn::LogicalTopN;
 pub use logical_union::LogicalUnion;
 pub use logical_update::LogicalUpdate;
 pub use logical_values::LogicalValues;
+pub use stream_band_join::{BandConditionDesc, StreamBandJoin};
 pub use stream_dedup::StreamDedup;
 pub use stream_delta_join::StreamDeltaJoin;
 pub use stream_dml::StreamDml;
@@ -740,6 +745,7 @@ pub use stream_now::StreamNow;
 pub use stream_project::StreamProject;
 pub use stream_project_set::StreamProjectSet;
 pub use stream_row_id_gen::StreamRowIdGen;
+pub use stream_session_window::StreamSessionWindow;
 pub use stream_share::StreamShare;
 pub use stream_simple_agg::StreamSimpleAgg;
 pub use stream_sink::StreamSink;
@@ -798,6 +804,7 @@ macro_rules! for_all_plan_nodes {
             , { Logical, Share }
             , { Logical, Now }
             , { Logical, Dedup }
+            , { Logical, SessionWindow }
             , { Logical, Intersect }
             , { Logical, Except }
             , { Batch, SimpleAgg }
@@ -853,6 +860,8 @@ macro_rules! for_all_plan_nodes {
             , { Stream, Dedup }
             , { Stream, EowcOverWindow }
             , { Stream, Sort }
+            , { Stream, SessionWindow }
+            , { Stream, BandJoin }
         }
     };
 }
@@ -885,6 +894,7 @@ macro_rules! for_logical_plan_nodes {
             , { Logical, Share }
             , { Logical, Now }
             , { Logical, Dedup }
+            , { Logical, SessionWindow }
             , { Logical, Intersect }
             , { Logical, Except }
         }
@@ -958,6 +968,8 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, Dedup }
             , { Stream, EowcOverWindow }
             , { Stream, Sort }
+            , { Stream, SessionWindow }
+            , { Stream, BandJoin }
         }
     };
 }
@@ -0,0 +1,218 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use pretty_xmlish::{Pretty, XmlNode};
+use risingwave_pb::plan_common::JoinType;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::BandJoinNode;
+
+use super::utils::{childless_record, formatter_debug_plan_node, Distill};
+use super::{generic, ExprRewritable, PlanBase, PlanRef, PlanTreeNodeBinary, StreamNode};
+use crate::expr::{Expr, ExprRewriter};
+use crate::optimizer::plan_node::generic::GenericPlanRef;
+use crate::optimizer::plan_node::stream::StreamPlanRef;
+use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::utils::{ColIndexMappingRewriteExt, Condition, ConditionDisplay};
+
+/// The band predicate of a [`StreamBandJoin`]: the left column must fall between the two right
+/// columns, with possibly strict bounds. All indices are over the concatenated join schema,
+/// with the right-side ones kept relative to the right input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BandConditionDesc {
+    /// Index of the probe column in the left input.
+    pub left_index: usize,
+    /// Index of the lower bound column in the right input.
+    pub right_lower_index: usize,
+    /// Index of the upper bound column in the right input.
+    pub right_upper_index: usize,
+    pub lower_inclusive: bool,
+    pub upper_inclusive: bool,
+    /// The conjunctions of the join condition other than the band predicate, over the
+    /// concatenated join schema.
+    pub residual: Condition,
+}
+
+/// [`StreamBandJoin`] implements an inner join whose join condition consists of range
+/// predicates only. The left side keeps its distribution and persists its rows keyed by the
+/// band column; the right side is broadcast so that every parallel unit can maintain a full
+/// interval index of the bands.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StreamBandJoin {
+    pub base: PlanBase,
+    logical: generic::Join<PlanRef>,
+    band: BandConditionDesc,
+}
+
+impl StreamBandJoin {
+    pub fn new(logical: generic::Join<PlanRef>, band: BandConditionDesc) -> Self {
+        assert_eq!(logical.join_type, JoinType::Inner);
+
+        let l2o = logical
+            .l2i_col_mapping()
+            .composite(&logical.i2o_col_mapping());
+        let dist = l2o.rewrite_provided_distribution(logical.left.distribution());
+
+        // TODO: derive watermark columns from the band predicate.
+        let watermark_columns = FixedBitSet::with_capacity(logical.internal_column_num());
+        let base = PlanBase::new_stream_with_logical(
+            &logical,
+            dist,
+            false,
+            false, // TODO(rc): decide EOWC property
+            logical.i2o_col_mapping().rewrite_bitset(&watermark_columns),
+        );
+
+        Self {
+            base,
+            logical,
+            band,
+        }
+    }
+
+    /// Get join type
+    pub fn join_type(&self) -> JoinType {
+        self.logical.join_type
+    }
+
+    pub fn band_condition(&self) -> &BandConditionDesc {
+        &self.band
+    }
+
+    fn band_pretty(&self) -> String {
+        let schema = self.logical.concat_schema();
+        let left_len = self.logical.left.schema().len();
+        format!(
+            "{lo} {lo_cmp} {probe} AND {probe} {hi_cmp} {hi}",
+            probe = schema.fields[self.band.left_index].name,
+            lo = schema.fields[left_len + self.band.right_lower_index].name,
+            hi = schema.fields[left_len + self.band.right_upper_index].name,
+            lo_cmp = if self.band.lower_inclusive { "<=" } else { "<" },
+            hi_cmp = if self.band.upper_inclusive { "<=" } else { "<" },
+        )
+    }
+}
+
+impl Distill for StreamBandJoin {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        let mut vec = Vec::with_capacity(3);
+        vec.push(("type", Pretty::debug(&self.logical.join_type)));
+        vec.push(("band", Pretty::from(self.band_pretty())));
+        if !self.band.residual.always_true() {
+            let concat_schema = self.logical.concat_schema();
+            vec.push((
+                "condition",
+                Pretty::display(&ConditionDisplay {
+                    condition: &self.band.residual,
+                    input_schema: &concat_schema,
+                }),
+            ));
+        }
+        childless_record("StreamBandJoin", vec)
+    }
+}
+
+impl fmt::Display for StreamBandJoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = formatter_debug_plan_node!(f, "StreamBandJoin");
+        builder.field("type", &self.logical.join_type);
+        builder.field("band", &format_args!("{}", self.band_pretty()));
+        if !self.band.residual.always_true() {
+            let concat_schema = self.logical.concat_schema();
+            builder.field(
+                "condition",
+                &ConditionDisplay {
+                    condition: &self.band.residual,
+                    input_schema: &concat_schema,
+                },
+            );
+        }
+        builder.finish()
+    }
+}
+
+impl PlanTreeNodeBinary for StreamBandJoin {
+    fn left(&self) -> PlanRef {
+        self.logical.left.clone()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right.clone()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        let mut logical = self.logical.clone();
+        logical.left = left;
+        logical.right = right;
+        Self::new(logical, self.band.clone())
+    }
+}
+
+impl_plan_tree_node_for_binary! { StreamBandJoin }
+
+impl StreamNode for StreamBandJoin {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> NodeBody {
+        use generic::dynamic_filter::infer_left_internal_table_catalog;
+
+        // The left table is keyed by the band column so that a band update can find the
+        // affected left rows with a range scan; the right table is replicated and keyed by
+        // the lower bound, recovering the interval index on startup.
+        let left_table =
+            infer_left_internal_table_catalog(self.left().plan_base(), self.band.left_index)
+                .with_id(state.gen_table_id_wrapped());
+        let right_table = infer_left_internal_table_catalog(
+            self.right().plan_base(),
+            self.band.right_lower_index,
+        )
+        .with_id(state.gen_table_id_wrapped());
+
+        NodeBody::BandJoin(BandJoinNode {
+            join_type: self.logical.join_type as i32,
+            left_key: self.band.left_index as u32,
+            right_lower_key: self.band.right_lower_index as u32,
+            right_upper_key: self.band.right_upper_index as u32,
+            lower_inclusive: self.band.lower_inclusive,
+            upper_inclusive: self.band.upper_inclusive,
+            condition: self
+                .band
+                .residual
+                .as_expr_unless_true()
+                .map(|x| x.to_expr_proto()),
+            left_table: Some(left_table.to_internal_table_prost()),
+            right_table: Some(right_table.to_internal_table_prost()),
+            output_indices: self
+                .logical
+                .output_indices
+                .iter()
+                .map(|&x| x as u32)
+                .collect(),
+        })
+    }
+}
+
+impl ExprRewritable for StreamBandJoin {
+    fn has_rewritable_expr(&self) -> bool {
+        true
+    }
+
+    fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
+        let mut logical = self.logical.clone();
+        logical.rewrite_exprs(r);
+        let mut band = self.band.clone();
+        band.residual = band.residual.rewrite_expr(r);
+        Self::new(logical, band).into()
+    }
+}
@@ -91,6 +91,14 @@ impl StreamHashAgg {
                     watermark_columns.insert(mapping.map(idx));
                 }
             }
+            // A watermark on a group key column (typically an event-time bucket) closes its
+            // groups for good once it passes, so order the state tables by that column to
+            // let the executor clean up the state of closed groups by watermark, instead of
+            // retaining all historical groups forever.
+            window_col_idx = logical
+                .watermark_group_key(input.watermark_columns())
+                .first()
+                .copied();
         }
 
         // Hash agg executor might change the append-only behavior of the stream.
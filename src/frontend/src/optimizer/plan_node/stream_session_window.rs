@@ -0,0 +1,152 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use pretty_xmlish::XmlNode;
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_pb::stream_plan::stream_node::PbNodeBody;
+
+use super::utils::{formatter_debug_plan_node, Distill, TableCatalogBuilder};
+use super::{generic, ExprRewritable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamNode};
+use crate::expr::{Expr, ExprImpl, ExprRewriter};
+use crate::optimizer::plan_node::generic::DistillUnit;
+use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::TableCatalog;
+
+/// [`StreamSessionWindow`] represents a session window table function. It buffers the rows of
+/// the currently open session and emits them with the window columns appended once the session
+/// is closed, i.e. when a row or a watermark beyond the window end is received.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StreamSessionWindow {
+    pub base: PlanBase,
+    logical: generic::SessionWindow<PlanRef>,
+    window_end_expr: ExprImpl,
+}
+
+impl StreamSessionWindow {
+    pub fn new(logical: generic::SessionWindow<PlanRef>, window_end_expr: ExprImpl) -> Self {
+        let input = logical.input.clone();
+        assert!(input.append_only());
+        assert!(input.watermark_columns().contains(logical.time_col.index()));
+
+        let dist = input.distribution().clone();
+        // Rows of an open session are emitted only after a watermark beyond the session end has
+        // been received, so no watermark can be propagated on any output column.
+        let watermark_columns = FixedBitSet::with_capacity(input.schema().len() + 2);
+
+        let base = PlanBase::new_stream_with_logical(&logical, dist, true, true, watermark_columns);
+        Self {
+            base,
+            logical,
+            window_end_expr,
+        }
+    }
+
+    fn infer_state_table(&self) -> TableCatalog {
+        // The buffer table has the same schema as the input, holding the rows of the currently
+        // open session ordered by the time column.
+
+        let in_fields = self.logical.input.schema().fields();
+        let mut tbl_builder =
+            TableCatalogBuilder::new(self.ctx().with_options().internal_table_subset());
+        for field in in_fields {
+            tbl_builder.add_column(field);
+        }
+
+        let mut order_cols = HashSet::new();
+        tbl_builder.add_order_column(self.logical.time_col.index(), OrderType::ascending());
+        order_cols.insert(self.logical.time_col.index());
+
+        let dist_key = self.base.dist.dist_column_indices().to_vec();
+        for idx in &dist_key {
+            if !order_cols.contains(idx) {
+                tbl_builder.add_order_column(*idx, OrderType::ascending());
+                order_cols.insert(*idx);
+            }
+        }
+
+        for idx in self.logical.input.logical_pk() {
+            if !order_cols.contains(idx) {
+                tbl_builder.add_order_column(*idx, OrderType::ascending());
+                order_cols.insert(*idx);
+            }
+        }
+
+        let read_prefix_len_hint = 0;
+        tbl_builder.build(dist_key, read_prefix_len_hint)
+    }
+}
+
+impl fmt::Display for StreamSessionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = formatter_debug_plan_node!(f, "StreamSessionWindow");
+        builder
+            .field("time_col", &self.logical.time_col)
+            .field("gap", &self.logical.gap)
+            .finish()
+    }
+}
+
+impl Distill for StreamSessionWindow {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        self.logical.distill_with_name("StreamSessionWindow")
+    }
+}
+
+impl PlanTreeNodeUnary for StreamSessionWindow {
+    fn input(&self) -> PlanRef {
+        self.logical.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        let mut logical = self.logical.clone();
+        logical.input = input;
+        Self::new(logical, self.window_end_expr.clone())
+    }
+}
+
+impl_plan_tree_node_for_unary! {StreamSessionWindow}
+
+impl StreamNode for StreamSessionWindow {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> PbNodeBody {
+        use risingwave_pb::stream_plan::*;
+        PbNodeBody::SessionWindow(SessionWindowNode {
+            time_col: self.logical.time_col.index() as _,
+            gap: Some(self.logical.gap.into()),
+            window_end_expr: Some(self.window_end_expr.to_expr_proto()),
+            state_table: Some(
+                self.infer_state_table()
+                    .with_id(state.gen_table_id_wrapped())
+                    .to_internal_table_prost(),
+            ),
+        })
+    }
+}
+
+impl ExprRewritable for StreamSessionWindow {
+    fn has_rewritable_expr(&self) -> bool {
+        true
+    }
+
+    fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
+        Self::new(
+            self.logical.clone(),
+            r.rewrite_expr(self.window_end_expr.clone()),
+        )
+        .into()
+    }
+}
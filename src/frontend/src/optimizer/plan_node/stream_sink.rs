@@ -71,6 +71,7 @@ impl StreamSink {
         out_names: Vec<String>,
         definition: String,
         properties: WithOptions,
+        from_mv: bool,
     ) -> Result<Self> {
         let required_dist = match input.distribution() {
             Distribution::Single => RequiredDist::single(),
@@ -101,11 +102,13 @@ impl StreamSink {
             columns,
             definition,
             properties,
+            from_mv,
         )?;
 
         Ok(Self::new(input, sink))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn derive_sink_desc(
         input: PlanRef,
         name: String,
@@ -113,6 +116,7 @@ impl StreamSink {
         columns: Vec<ColumnCatalog>,
         definition: String,
         properties: WithOptions,
+        from_mv: bool,
     ) -> Result<SinkDesc> {
         const DOWNSTREAM_PK_KEY: &str = "primary_key";
 
@@ -120,7 +124,15 @@ impl StreamSink {
         let sink_type = Self::derive_sink_type(input.append_only(), &properties)?;
         let (pk, _) = derive_pk(input, user_order_by, &columns);
 
-        let downstream_pk = Self::parse_downstream_pk(&columns, properties.get(DOWNSTREAM_PK_KEY))?;
+        let mut downstream_pk =
+            Self::parse_downstream_pk(&columns, properties.get(DOWNSTREAM_PK_KEY))?;
+        if downstream_pk.is_empty() && from_mv && sink_type == SinkType::Upsert {
+            // For `CREATE SINK ... FROM mv`, the primary key of the sink is unambiguously the
+            // stream key of the materialized view, so derive it instead of requiring the user
+            // to duplicate it in the `primary_key` option. `AS SELECT` sinks may not have a
+            // meaningful stream key on the query output, so the option stays mandatory there.
+            downstream_pk = pk.iter().map(|k| k.column_index).collect();
+        }
 
         Ok(SinkDesc {
             id: SinkId::placeholder(),
@@ -66,7 +66,8 @@ impl TableCatalogBuilder {
 
         self.columns.push(ColumnCatalog {
             column_desc: column_desc.clone(),
-            // All columns in internal table are invisible to batch query.
+            // All columns in internal tables are visible to batch queries, so that the executor
+            // state (keys, agg intermediates, ...) can be inspected with `SELECT` for debugging.
             is_hidden: false,
         });
         column_idx
@@ -305,6 +306,7 @@ macro_rules! formatter_debug_plan_node {
         }
     };
 }
-pub(crate) use {formatter_debug_plan_node, plan_node_name};
+pub(crate) use formatter_debug_plan_node;
+pub(crate) use plan_node_name;
 
 use super::generic::{self, GenericPlanRef};
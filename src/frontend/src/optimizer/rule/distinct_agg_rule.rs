@@ -295,6 +295,7 @@ impl DistinctAggRule {
                     | AggKind::StddevSamp
                     | AggKind::VarPop
                     | AggKind::VarSamp
+                    | AggKind::ApproxPercentile
                     | AggKind::PercentileCont
                     | AggKind::PercentileDisc
                     | AggKind::Mode => (),
@@ -24,8 +24,12 @@ pub struct IndexDeltaJoinRule {}
 
 impl Rule for IndexDeltaJoinRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let ctx = plan.ctx();
         let join = plan.as_stream_hash_join()?;
         if join.eq_join_predicate().has_non_eq() || join.join_type() != JoinType::Inner {
+            if ctx.is_explain_trace() {
+                ctx.trace("Not using delta join: only inner equi-joins are supported.");
+            }
             return Some(plan);
         }
 
@@ -142,6 +146,11 @@ impl Rule for IndexDeltaJoinRule {
         if let Some(left) = match_indexes(&left_indices, input_left, ChainType::Backfill) {
             if let Some(right) = match_indexes(&right_indices, input_right, ChainType::UpstreamOnly)
             {
+                if ctx.is_explain_trace() {
+                    ctx.trace(
+                        "Using delta join: found indexes matching the join key distribution on both join sides.",
+                    );
+                }
                 // We already ensured that index and join use the same distribution, so we directly
                 // replace the children with stream index scan without inserting any exchanges.
                 Some(
@@ -151,9 +160,19 @@ impl Rule for IndexDeltaJoinRule {
                         .into(),
                 )
             } else {
+                if ctx.is_explain_trace() {
+                    ctx.trace(
+                        "Not using delta join: no index matching the join key distribution on the right join side, fall back to hash join.",
+                    );
+                }
                 Some(plan)
             }
         } else {
+            if ctx.is_explain_trace() {
+                ctx.trace(
+                    "Not using delta join: no index matching the join key distribution on the left join side, fall back to hash join.",
+                );
+            }
             Some(plan)
         }
     }
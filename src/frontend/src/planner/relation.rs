@@ -25,13 +25,14 @@ use crate::binder::{
 };
 use crate::expr::{Expr, ExprImpl, ExprType, FunctionCall, InputRef};
 use crate::optimizer::plan_node::{
-    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalShare, LogicalSource,
-    LogicalTableFunction, LogicalValues, PlanRef,
+    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSessionWindow,
+    LogicalShare, LogicalSource, LogicalTableFunction, LogicalValues, PlanRef,
 };
 use crate::planner::Planner;
 
 const ERROR_WINDOW_SIZE_ARG: &str =
     "The size arg of window table function should be an interval literal.";
+const ERROR_GAP_ARG: &str = "The gap arg of session window function should be an interval literal.";
 
 impl Planner {
     pub fn plan_relation(&mut self, relation: Relation) -> Result<PlanRef> {
@@ -113,6 +114,11 @@ impl Planner {
                 table_function.time_col,
                 table_function.args,
             ),
+            Session => self.plan_session_window(
+                table_function.input,
+                table_function.time_col,
+                table_function.args,
+            ),
         }
     }
 
@@ -295,4 +301,39 @@ impl Planner {
             window_offset,
         ))
     }
+
+    fn plan_session_window(
+        &mut self,
+        input: Relation,
+        time_col: InputRef,
+        args: Vec<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let input = self.plan_relation(input)?;
+        let mut args = args.into_iter();
+        let gap = match (args.next(), args.next()) {
+            (Some(ExprImpl::Literal(gap)), None) => match *gap.get_data() {
+                Some(ScalarImpl::Interval(gap)) => gap,
+                _ => return Err(ErrorCode::BindError(ERROR_GAP_ARG.to_string()).into()),
+            },
+            _ => return Err(ErrorCode::BindError(ERROR_GAP_ARG.to_string()).into()),
+        };
+
+        if !gap.is_positive() {
+            return Err(
+                ErrorCode::BindError(format!("gap {} must be positive", gap)).into(),
+            );
+        }
+
+        // The window columns of a session window take the raw time values of the input rows,
+        // so a date column cannot produce the timestamp windows promised by the binder.
+        if time_col.data_type == DataType::Date {
+            return Err(ErrorCode::NotImplemented(
+                "session window on a column of type date".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+
+        Ok(LogicalSessionWindow::create(input, time_col, gap))
+    }
 }
@@ -528,6 +528,7 @@ pub(crate) mod tests {
                 read_prefix_len_hint: 0,
                 watermark_columns: FixedBitSet::with_capacity(3),
                 versioned: false,
+                dist_key_in_pk: vec![],
             }),
             vec![],
             ctx,
@@ -608,6 +609,7 @@ pub(crate) mod tests {
                 is_unschedulable: false,
                 is_serving: true,
                 is_streaming: true,
+                resource_group: Default::default(),
             }),
         };
         let worker2 = WorkerNode {
@@ -623,6 +625,7 @@ pub(crate) mod tests {
                 is_unschedulable: false,
                 is_serving: true,
                 is_streaming: true,
+                resource_group: Default::default(),
             }),
         };
         let worker3 = WorkerNode {
@@ -638,6 +641,7 @@ pub(crate) mod tests {
                 is_unschedulable: false,
                 is_serving: true,
                 is_streaming: true,
+                resource_group: Default::default(),
             }),
         };
         let workers = vec![worker1, worker2, worker3];
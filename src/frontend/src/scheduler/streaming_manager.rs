@@ -19,6 +19,7 @@ use std::sync::Arc;
 use itertools::Itertools;
 use parking_lot::RwLock;
 use pgwire::pg_server::SessionId;
+use risingwave_pb::meta::cancel_creating_jobs_request::{CreatingJobInfos, PbJobs};
 use risingwave_pb::meta::CreatingJobInfo;
 use uuid::Uuid;
 
@@ -126,7 +127,9 @@ impl StreamingJobTracker {
         let client = self.meta_client.clone();
         tokio::spawn(async move {
             client
-                .cancel_creating_jobs(jobs.into_iter().map(|job| job.info).collect_vec())
+                .cancel_creating_jobs(PbJobs::Infos(CreatingJobInfos {
+                    infos: jobs.into_iter().map(|job| job.info).collect_vec(),
+                }))
                 .await
         });
     }
@@ -19,7 +19,9 @@ use std::time::Duration;
 use rand::seq::SliceRandom;
 use risingwave_common::bail;
 use risingwave_common::hash::{ParallelUnitId, ParallelUnitMapping};
-use risingwave_common::util::worker_util::get_pu_to_worker_mapping;
+use risingwave_common::util::worker_util::{
+    get_pu_to_worker_mapping, prefer_dedicated_serving_nodes,
+};
 use risingwave_common::vnode_mapping::vnode_placement::place_vnode;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 
@@ -305,8 +307,7 @@ impl WorkerNodeSelector {
         if self.enable_barrier_read {
             self.manager.list_streaming_worker_nodes().len()
         } else {
-            self.apply_worker_node_mask(self.manager.list_serving_worker_nodes())
-                .len()
+            self.serving_worker_nodes().len()
         }
     }
 
@@ -314,7 +315,7 @@ impl WorkerNodeSelector {
         let worker_nodes = if self.enable_barrier_read {
             self.manager.list_streaming_worker_nodes()
         } else {
-            self.apply_worker_node_mask(self.manager.list_serving_worker_nodes())
+            self.serving_worker_nodes()
         };
         worker_nodes
             .iter()
@@ -333,7 +334,7 @@ impl WorkerNodeSelector {
             if self.manager.worker_node_mask().is_empty() {
                 return Ok(origin);
             }
-            let new_workers = self.apply_worker_node_mask(self.manager.list_serving_worker_nodes());
+            let new_workers = self.serving_worker_nodes();
             let masked_mapping =
                 place_vnode(Some(&origin), &new_workers, origin.iter_unique().count());
             masked_mapping.ok_or_else(|| SchedulerError::EmptyWorkerNodes)
@@ -344,7 +345,7 @@ impl WorkerNodeSelector {
         let worker_nodes = if self.enable_barrier_read {
             self.manager.list_streaming_worker_nodes()
         } else {
-            self.apply_worker_node_mask(self.manager.list_serving_worker_nodes())
+            self.serving_worker_nodes()
         };
         worker_nodes
             .choose(&mut rand::thread_rng())
@@ -352,6 +353,14 @@ impl WorkerNodeSelector {
             .map(|w| (*w).clone())
     }
 
+    /// Serving worker nodes for batch queries: restricted to the dedicated read replicas once
+    /// any are deployed, with the temporary worker mask applied.
+    fn serving_worker_nodes(&self) -> Vec<WorkerNode> {
+        self.apply_worker_node_mask(prefer_dedicated_serving_nodes(
+            self.manager.list_serving_worker_nodes(),
+        ))
+    }
+
     fn apply_worker_node_mask(&self, origin: Vec<WorkerNode>) -> Vec<WorkerNode> {
         if origin.len() <= 1 {
             // If there is at most one worker, don't apply mask.
@@ -392,6 +401,7 @@ mod tests {
                     is_unschedulable: false,
                     is_serving: true,
                     is_streaming: true,
+                    resource_group: Default::default(),
                 }),
             },
             WorkerNode {
@@ -404,6 +414,7 @@ mod tests {
                     is_unschedulable: false,
                     is_serving: true,
                     is_streaming: false,
+                    resource_group: Default::default(),
                 }),
             },
         ];
@@ -16,7 +16,7 @@ use std::collections::HashMap;
 use std::io::{Error, ErrorKind};
 use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use bytes::Bytes;
 use parking_lot::{RwLock, RwLockReadGuard};
@@ -119,7 +119,7 @@ pub struct FrontendEnv {
     creating_streaming_job_tracker: StreamingJobTrackerRef,
 }
 
-type SessionMapRef = Arc<Mutex<HashMap<(i32, i32), Arc<SessionImpl>>>>;
+pub(crate) type SessionMapRef = Arc<Mutex<HashMap<(i32, i32), Arc<SessionImpl>>>>;
 
 impl FrontendEnv {
     pub fn mock() -> Self {
@@ -404,6 +404,10 @@ impl FrontendEnv {
     pub fn creating_streaming_job_tracker(&self) -> &StreamingJobTrackerRef {
         &self.creating_streaming_job_tracker
     }
+
+    pub(crate) fn sessions_map(&self) -> &SessionMapRef {
+        &self.sessions_map
+    }
 }
 
 pub struct AuthContext {
@@ -439,6 +443,9 @@ pub struct SessionImpl {
     /// This flag is set only when current query is executed in local mode, and used to cancel
     /// local query.
     current_query_cancel_flag: Mutex<Option<Trigger>>,
+
+    /// The SQL statement currently being executed and when it started, for `SHOW PROCESSLIST`.
+    running_sql: Mutex<Option<(String, Instant)>>,
 }
 
 #[derive(Error, Debug)]
@@ -473,6 +480,7 @@ impl SessionImpl {
             id,
             current_query_cancel_flag: Mutex::new(None),
             notices: Default::default(),
+            running_sql: Mutex::new(None),
         }
     }
 
@@ -491,6 +499,7 @@ impl SessionImpl {
             id: (0, 0),
             current_query_cancel_flag: Mutex::new(None),
             notices: Default::default(),
+            running_sql: Mutex::new(None),
         }
     }
 
@@ -586,6 +595,26 @@ impl SessionImpl {
             .map_err(RwError::from)
     }
 
+    pub fn check_secret_name_duplicated(&self, name: ObjectName) -> Result<()> {
+        let db_name = self.database();
+        let catalog_reader = self.env().catalog_reader().read_guard();
+        let (schema_name, secret_name) = {
+            let (schema_name, secret_name) = Binder::resolve_schema_qualified_name(db_name, name)?;
+            let search_path = self.config().get_search_path();
+            let user_name = &self.auth_context().user_name;
+            let schema_name = match schema_name {
+                Some(schema_name) => schema_name,
+                None => catalog_reader
+                    .first_valid_schema(db_name, &search_path, user_name)?
+                    .name(),
+            };
+            (schema_name, secret_name)
+        };
+        catalog_reader
+            .check_secret_name_duplicated(db_name, &schema_name, &secret_name)
+            .map_err(RwError::from)
+    }
+
     /// Also check if the user has the privilege to create in the schema.
     pub fn get_database_and_schema_id_for_create(
         &self,
@@ -639,6 +668,23 @@ impl SessionImpl {
         Ok(connection.clone())
     }
 
+    fn set_running_sql(&self, sql: &str) {
+        *self.running_sql.lock().unwrap() = Some((sql.to_string(), Instant::now()));
+    }
+
+    fn clear_running_sql(&self) {
+        *self.running_sql.lock().unwrap() = None;
+    }
+
+    /// Returns the SQL statement currently being executed and its elapsed time, if any.
+    pub fn running_sql(&self) -> Option<(String, Duration)> {
+        self.running_sql
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|(sql, started_at)| (sql.clone(), started_at.elapsed()))
+    }
+
     pub fn clear_cancel_query_flag(&self) {
         let mut flag = self.current_query_cancel_flag.lock().unwrap();
         *flag = None;
@@ -697,8 +743,9 @@ impl SessionImpl {
             );
         }
         let stmt = stmts.swap_remove(0);
+        self.set_running_sql(sql);
         let rsp = {
-            let mut handle_fut = Box::pin(handle(self, stmt, sql, formats));
+            let mut handle_fut = Box::pin(handle(self.clone(), stmt, sql, formats));
             if cfg!(debug_assertions) {
                 // Report the SQL in the log periodically if the query is slow.
                 const SLOW_QUERY_LOG_PERIOD: Duration = Duration::from_secs(60);
@@ -717,8 +764,9 @@ impl SessionImpl {
                 handle_fut.await
             }
         }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))?;
-        Ok(rsp)
+        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e));
+        self.clear_running_sql();
+        Ok(rsp?)
     }
 
     pub fn notice_to_user(&self, str: impl Into<String>) {
@@ -800,6 +848,15 @@ impl SessionManager for SessionManagerImpl {
                             ),
                             salt,
                         }
+                    } else if auth_info.encryption_type == EncryptionType::ScramSha256 as i32 {
+                        let stored_secret = String::from_utf8(auth_info.encrypted_value.clone())
+                            .map_err(|_| {
+                                Error::new(
+                                    ErrorKind::InvalidInput,
+                                    "malformed SCRAM-SHA-256 secret",
+                                )
+                            })?;
+                        UserAuthenticator::ScramSha256(stored_secret)
                     } else {
                         return Err(Box::new(Error::new(
                             ErrorKind::Unsupported,
@@ -894,8 +951,9 @@ impl Session for SessionImpl {
         format: Format,
     ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
         let sql_str = stmt.to_string();
+        self.set_running_sql(&sql_str);
         let rsp = {
-            let mut handle_fut = Box::pin(handle(self, stmt, &sql_str, vec![format]));
+            let mut handle_fut = Box::pin(handle(self.clone(), stmt, &sql_str, vec![format]));
             if cfg!(debug_assertions) {
                 // Report the SQL in the log periodically if the query is slow.
                 const SLOW_QUERY_LOG_PERIOD: Duration = Duration::from_secs(60);
@@ -912,8 +970,9 @@ impl Session for SessionImpl {
                 handle_fut.await
             }
         }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql_str, e))?;
-        Ok(rsp)
+        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql_str, e));
+        self.clear_running_sql();
+        Ok(rsp?)
     }
 
     fn user_authenticator(&self) -> &UserAuthenticator {
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use risingwave_common::util::expr_feature_gate::CURRENT_EXPR_FEATURE_GATES;
 use risingwave_pb::stream_plan::stream_fragment_graph::{
     StreamFragment as StreamFragmentProto, StreamFragmentEdge as StreamFragmentEdgeProto,
 };
@@ -105,7 +106,15 @@ impl StreamFragmentGraph {
                 .map(|(k, v)| (*k, v.to_protobuf()))
                 .collect(),
             edges: self.edges.values().cloned().collect(),
-            env: Some(self.env.clone()),
+            // Record the current expression feature gates so that the job keeps its behavior
+            // after upgrades.
+            env: Some(StreamEnvironment {
+                expr_feature_gates: CURRENT_EXPR_FEATURE_GATES
+                    .iter()
+                    .map(|gate| gate.to_string())
+                    .collect(),
+                ..self.env.clone()
+            }),
             // To be filled later
             dependent_table_ids: vec![],
             table_ids_cnt: 0,
@@ -34,10 +34,11 @@ use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView,
 };
-use risingwave_pb::ddl_service::{create_connection_request, DdlProgress};
-use risingwave_pb::hummock::HummockSnapshot;
+use risingwave_pb::ddl_service::{create_connection_request, BackfillProgress, DdlProgress};
+use risingwave_pb::hummock::{CompactTaskSummary, HummockSnapshot};
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
-use risingwave_pb::meta::{CreatingJobInfo, SystemParams};
+use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::meta::{BarrierLatencyEntry, RecoveryEvent, RecoveryRecord, SystemParams};
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::{GrantPrivilege, UserInfo};
@@ -235,10 +236,28 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
-    async fn create_view(&self, mut view: PbView) -> Result<()> {
-        view.id = self.gen_id();
-        self.catalog.write().create_view(&view);
-        self.add_table_or_source_id(view.id, view.schema_id, view.database_id);
+    async fn create_view(&self, mut view: PbView, or_replace: bool) -> Result<()> {
+        let replaced_view_id = or_replace
+            .then(|| {
+                self.catalog
+                    .read()
+                    .get_schema_by_id(&view.database_id, &view.schema_id)
+                    .unwrap()
+                    .get_view_by_name(&view.name)
+                    .map(|v| v.id)
+            })
+            .flatten();
+        match replaced_view_id {
+            Some(view_id) => {
+                view.id = view_id;
+                self.catalog.write().update_view(&view);
+            }
+            None => {
+                view.id = self.gen_id();
+                self.catalog.write().create_view(&view);
+                self.add_table_or_source_id(view.id, view.schema_id, view.database_id);
+            }
+        }
         Ok(())
     }
 
@@ -259,10 +278,14 @@ impl CatalogWriter for MockCatalogWriter {
 
     async fn replace_table(
         &self,
+        source: Option<PbSource>,
         table: PbTable,
         _graph: StreamFragmentGraph,
         _mapping: ColIndexMapping,
     ) -> Result<()> {
+        if let Some(source) = source {
+            self.catalog.write().update_source(&source);
+        }
         self.catalog.write().update_table(&table);
         Ok(())
     }
@@ -295,7 +318,7 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
-    async fn create_function(&self, _function: PbFunction) -> Result<()> {
+    async fn create_function(&self, _function: PbFunction, _or_replace: bool) -> Result<()> {
         unreachable!()
     }
 
@@ -403,6 +426,14 @@ impl CatalogWriter for MockCatalogWriter {
         unreachable!()
     }
 
+    async fn drop_owned(&self, _user_ids: Vec<UserId>) -> Result<()> {
+        unreachable!()
+    }
+
+    async fn reassign_owned(&self, _user_ids: Vec<UserId>, _new_owner: UserId) -> Result<()> {
+        unreachable!()
+    }
+
     async fn drop_database(&self, database_id: u32) -> Result<()> {
         self.catalog.write().drop_database(database_id);
         Ok(())
@@ -436,6 +467,26 @@ impl CatalogWriter for MockCatalogWriter {
     async fn alter_source_name(&self, _source_id: u32, _source_name: &str) -> Result<()> {
         unreachable!()
     }
+
+    async fn alter_table_owner(&self, table_id: u32, owner_id: u32) -> Result<()> {
+        self.catalog
+            .write()
+            .alter_table_owner_by_id(&table_id.into(), owner_id);
+        Ok(())
+    }
+
+    async fn alter_view_owner(&self, _view_id: u32, _owner_id: u32) -> Result<()> {
+        unreachable!()
+    }
+
+    async fn alter_source_props(
+        &self,
+        _source_id: u32,
+        _changed_props: HashMap<String, String>,
+        _migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> Result<()> {
+        unreachable!()
+    }
 }
 
 impl MockCatalogWriter {
@@ -720,7 +771,19 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         })
     }
 
-    async fn cancel_creating_jobs(&self, _infos: Vec<CreatingJobInfo>) -> RpcResult<()> {
+    async fn cancel_creating_jobs(&self, _jobs: PbJobs) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn pause_streaming_job(&self, _table_id: u32) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn resume_streaming_job(&self, _table_id: u32) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn alter_parallelism(&self, _table_id: u32, _parallelism: u32) -> RpcResult<()> {
         Ok(())
     }
 
@@ -754,6 +817,26 @@ impl FrontendMetaClient for MockFrontendMetaClient {
     async fn list_ddl_progress(&self) -> RpcResult<Vec<DdlProgress>> {
         Ok(vec![])
     }
+
+    async fn list_backfill_progress(&self) -> RpcResult<Vec<BackfillProgress>> {
+        Ok(vec![])
+    }
+
+    async fn list_compact_task_history(&self) -> RpcResult<Vec<CompactTaskSummary>> {
+        Ok(vec![])
+    }
+
+    async fn list_barrier_latency(&self) -> RpcResult<Vec<BarrierLatencyEntry>> {
+        Ok(vec![])
+    }
+
+    async fn list_recovery_events(&self) -> RpcResult<Vec<RecoveryEvent>> {
+        Ok(vec![])
+    }
+
+    async fn list_recoveries(&self) -> RpcResult<Vec<RecoveryRecord>> {
+        Ok(vec![])
+    }
 }
 
 #[cfg(test)]
@@ -12,12 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use pgwire::scram::{ScramSecret, DEFAULT_ITERATIONS};
 use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::AuthInfo;
 use sha2::{Digest, Sha256};
 
-// SHA-256 is not supported in PostgreSQL protocol. We need to implement SCRAM-SHA-256 instead
-// if necessary.
+// SHA-256 is not supported in PostgreSQL protocol. SCRAM-SHA-256 is the supported alternative.
 const SHA256_ENCRYPTED_PREFIX: &str = "SHA-256:";
 const MD5_ENCRYPTED_PREFIX: &str = "md5";
 
@@ -26,10 +26,10 @@ const VALID_MD5_ENCRYPTED_LEN: usize = MD5_ENCRYPTED_PREFIX.len() + 32;
 
 /// Try to extract the encryption password from given password. The password is always stored
 /// encrypted in the system catalogs. The ENCRYPTED keyword has no effect, but is accepted for
-/// backwards compatibility. The method of encryption is by default SHA-256-encrypted. If the
-/// presented password string is already in MD5-encrypted or SHA-256-encrypted format, then it is
-/// stored as-is regardless of `password_encryption` (since the system cannot decrypt the specified
-/// encrypted password string, to encrypt it in a different format).
+/// backwards compatibility. The method of encryption is by default SCRAM-SHA-256. If the
+/// presented password string is already in MD5-encrypted, SHA-256-encrypted or SCRAM-SHA-256
+/// format, then it is stored as-is regardless of `password_encryption` (since the system cannot
+/// decrypt the specified encrypted password string, to encrypt it in a different format).
 ///
 /// For an MD5 encrypted password, rolpassword column will begin with the string md5 followed by a
 /// 32-character hexadecimal MD5 hash. The MD5 hash will be of the user's password concatenated to
@@ -41,7 +41,11 @@ const VALID_MD5_ENCRYPTED_LEN: usize = MD5_ENCRYPTED_PREFIX.len() + 32;
 /// password concatenated to their user name. The SHA-256 will be the default hash algorithm for
 /// Risingwave.
 ///
-/// A password that does not follow either of those formats is assumed to be unencrypted.
+/// For a SCRAM-SHA-256 encrypted password, rolpassword column will begin with the string
+/// SCRAM-SHA-256$ followed by the iteration count, the salt and the salted verifier keys, so
+/// the raw password cannot be recovered from the catalog. This is the default storage format.
+///
+/// A password that does not follow any of those formats is assumed to be unencrypted.
 #[inline(always)]
 pub fn encrypted_password(name: &str, password: &str) -> Option<AuthInfo> {
     // Specifying an empty string will also set the auth info to null.
@@ -49,7 +53,12 @@ pub fn encrypted_password(name: &str, password: &str) -> Option<AuthInfo> {
         return None;
     }
 
-    if valid_sha256_password(password) {
+    if valid_scram_sha_256_password(password) {
+        Some(AuthInfo {
+            encryption_type: EncryptionType::ScramSha256 as i32,
+            encrypted_value: password.as_bytes().to_vec(),
+        })
+    } else if valid_sha256_password(password) {
         Some(AuthInfo {
             encryption_type: EncryptionType::Sha256 as i32,
             encrypted_value: password.trim_start_matches(SHA256_ENCRYPTED_PREFIX).into(),
@@ -64,12 +73,13 @@ pub fn encrypted_password(name: &str, password: &str) -> Option<AuthInfo> {
     }
 }
 
-/// Encrypt the password with MD5 as default.
+/// Encrypt the password as a salted SCRAM-SHA-256 verifier by default, so the stored value
+/// cannot be replayed as a password.
 #[inline(always)]
-fn encrypt_default(name: &str, password: &str) -> AuthInfo {
+fn encrypt_default(_name: &str, password: &str) -> AuthInfo {
     AuthInfo {
-        encryption_type: EncryptionType::Md5 as i32,
-        encrypted_value: md5_hash(name, password),
+        encryption_type: EncryptionType::ScramSha256 as i32,
+        encrypted_value: ScramSecret::build(password, DEFAULT_ITERATIONS).into_bytes(),
     }
 }
 
@@ -82,6 +92,11 @@ pub fn md5_hash_with_salt(encrypted_value: &[u8], salt: &[u8; 4]) -> Vec<u8> {
     format!("md5{:x}", ctx.compute()).into_bytes()
 }
 
+#[inline(always)]
+fn valid_scram_sha_256_password(password: &str) -> bool {
+    ScramSecret::parse(password).is_some()
+}
+
 #[inline(always)]
 fn valid_sha256_password(password: &str) -> bool {
     password.starts_with(SHA256_ENCRYPTED_PREFIX) && password.len() == VALID_SHA256_ENCRYPTED_LEN
@@ -135,16 +150,11 @@ mod tests {
         );
 
         let input_passwords = vec![
-            "bar",
             "",
             "md596948aad3fcae80c08a35c9b5958cd89",
             "SHA-256:88ecde925da3c6f8ec3d140683da9d2a422f26c1ae1d9212da1e5a53416dcc88",
         ];
         let expected_output_passwords = vec![
-            Some(AuthInfo {
-                encryption_type: EncryptionType::Md5 as i32,
-                encrypted_value: md5_hash(user_name, password),
-            }),
             None,
             Some(AuthInfo {
                 encryption_type: EncryptionType::Md5 as i32,
@@ -160,5 +170,22 @@ mod tests {
             .map(|&p| encrypted_password(user_name, p))
             .collect::<Vec<_>>();
         assert_eq!(output_passwords, expected_output_passwords);
+
+        // An unencrypted password is stored as a salted SCRAM-SHA-256 verifier by default, and
+        // a pre-built verifier is stored as-is.
+        let auth_info = encrypted_password(user_name, password).unwrap();
+        assert_eq!(
+            auth_info.encryption_type,
+            EncryptionType::ScramSha256 as i32
+        );
+        let stored = String::from_utf8(auth_info.encrypted_value).unwrap();
+        assert!(ScramSecret::parse(&stored).is_some());
+        assert_eq!(
+            encrypted_password(user_name, &stored),
+            Some(AuthInfo {
+                encryption_type: EncryptionType::ScramSha256 as i32,
+                encrypted_value: stored.clone().into_bytes(),
+            })
+        );
     }
 }
@@ -29,11 +29,23 @@ static AVAILABLE_ACTION_ON_SOURCE: &[Action] = &[
     Action::Insert { columns: None },
     Action::Delete,
 ];
+static AVAILABLE_ACTION_ON_TABLE: &[Action] = AVAILABLE_ACTION_ON_SOURCE;
 static AVAILABLE_ACTION_ON_MVIEW: &[Action] = &[Action::Select { columns: None }];
 static AVAILABLE_ACTION_ON_VIEW: &[Action] = AVAILABLE_ACTION_ON_MVIEW;
 static AVAILABLE_ACTION_ON_SINK: &[Action] = &[];
 static AVAILABLE_ACTION_ON_FUNCTION: &[Action] = &[];
 
+/// Strips the column list of an action so that column-level actions can be matched against
+/// the relation-level availability lists above.
+fn to_relation_action(action: &Action) -> Action {
+    match action {
+        Action::Select { .. } => Action::Select { columns: None },
+        Action::Insert { .. } => Action::Insert { columns: None },
+        Action::Update { .. } => Action::Update { columns: None },
+        _ => action.clone(),
+    }
+}
+
 pub fn check_privilege_type(privilege: &Privileges, objects: &GrantObjects) -> Result<()> {
     match privilege {
         Privileges::All { .. } => Ok(()),
@@ -47,17 +59,17 @@ pub fn check_privilege_type(privilege: &Privileges, objects: &GrantObjects) -> R
                     .all(|action| AVAILABLE_ACTION_ON_SCHEMA.contains(action)),
                 GrantObjects::Sources(_) | GrantObjects::AllSourcesInSchema { .. } => actions
                     .iter()
-                    .all(|action| AVAILABLE_ACTION_ON_SOURCE.contains(action)),
+                    .all(|action| AVAILABLE_ACTION_ON_SOURCE.contains(&to_relation_action(action))),
                 GrantObjects::Mviews(_) | GrantObjects::AllMviewsInSchema { .. } => actions
                     .iter()
-                    .all(|action| AVAILABLE_ACTION_ON_MVIEW.contains(action)),
+                    .all(|action| AVAILABLE_ACTION_ON_MVIEW.contains(&to_relation_action(action))),
                 GrantObjects::Sinks(_) => actions
                     .iter()
                     .all(|action| AVAILABLE_ACTION_ON_SINK.contains(action)),
-                GrantObjects::Sequences(_)
-                | GrantObjects::AllSequencesInSchema { .. }
-                | GrantObjects::Tables(_)
-                | GrantObjects::AllTablesInSchema { .. } => true,
+                GrantObjects::Tables(_) | GrantObjects::AllTablesInSchema { .. } => actions
+                    .iter()
+                    .all(|action| AVAILABLE_ACTION_ON_TABLE.contains(&to_relation_action(action))),
+                GrantObjects::Sequences(_) | GrantObjects::AllSequencesInSchema { .. } => true,
             };
             if !valid {
                 return Err(ErrorCode::BindError(
@@ -81,6 +93,7 @@ pub fn available_privilege_actions(objects: &GrantObjects) -> Result<Vec<Action>
         GrantObjects::Mviews(_) | GrantObjects::AllMviewsInSchema { .. } => {
             Ok(AVAILABLE_ACTION_ON_MVIEW.to_vec())
         }
+        GrantObjects::Tables(_) => Ok(AVAILABLE_ACTION_ON_TABLE.to_vec()),
         _ => Err(
             ErrorCode::BindError("Invalid privilege type for the given object.".to_string()).into(),
         ),
@@ -120,6 +133,7 @@ pub fn available_prost_privilege(object: PbObject) -> PbGrantPrivilege {
             action: get_prost_action(action) as i32,
             with_grant_option: false,
             granted_by: DEFAULT_SUPER_USER_ID,
+            column_names: vec![],
         })
         .collect_vec();
     PbGrantPrivilege {
@@ -14,32 +14,54 @@
 
 use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
-use std::num::NonZeroU32;
+use std::num::{NonZeroU32, NonZeroU64};
 
-use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result as RwResult, RwError};
+use risingwave_common::types::Interval;
+use risingwave_connector::secret::secret_ref_property;
 use risingwave_connector::source::KAFKA_CONNECTOR;
 use risingwave_sqlparser::ast::{
-    CreateConnectionStatement, CreateSinkStatement, CreateSourceStatement, SqlOption, Statement,
-    Value,
+    AlterSecretStatement, CreateConnectionStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSourceStatement, SqlOption, Statement, Value,
 };
 
 use crate::catalog::connection_catalog::resolve_private_link_connection;
+use crate::catalog::root_catalog::SchemaPath;
 use crate::catalog::ConnectionId;
 use crate::handler::create_source::UPSTREAM_SOURCE_KEY;
 use crate::handler::util::get_connection_name;
 use crate::session::SessionImpl;
 
 mod options {
-    use risingwave_common::catalog::hummock::PROPERTIES_RETENTION_SECOND_KEY;
+    use risingwave_common::catalog::hummock::{
+        PROPERTIES_BLOOM_FILTER_MODE_KEY, PROPERTIES_COLD_STORAGE_CLASS_KEY,
+        PROPERTIES_RETENTION_SECOND_KEY,
+    };
 
+    pub const BLOOM_FILTER_MODE: &str = PROPERTIES_BLOOM_FILTER_MODE_KEY;
+    pub const COLD_STORAGE_CLASS: &str = PROPERTIES_COLD_STORAGE_CLASS_KEY;
+    /// A human-friendly alias of [`RETENTION_SECONDS`] taking an interval string like `7 days`.
+    /// It is normalized to `retention_seconds` before reaching the catalog.
+    pub const RETENTION: &str = "retention";
     pub const RETENTION_SECONDS: &str = PROPERTIES_RETENTION_SECOND_KEY;
+    /// The parallelism of the streaming job, overriding the `streaming_parallelism` session
+    /// config. It is extracted into [`WithOptions::parallelism`](super::WithOptions::parallelism)
+    /// instead of being kept as a property.
+    pub const PARALLELISM: &str = "parallelism";
+    /// The resource group of the streaming job. The job is only scheduled to compute nodes
+    /// labeled with the same group, see `risectl scale set-resource-group`.
+    pub const RESOURCE_GROUP: &str = "resource_group";
 }
 
 /// Options or properties extracted from the `WITH` clause of DDLs.
 #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WithOptions {
     inner: BTreeMap<String, String>,
+    parallelism: Option<NonZeroU64>,
+    resource_group: Option<String>,
+    /// `secret <name>` references by property key, pending resolution against the catalog. See
+    /// [`resolve_secret_refs_in_with_options`].
+    secret_refs: BTreeMap<String, String>,
 }
 
 impl std::ops::Deref for WithOptions {
@@ -55,6 +77,9 @@ impl WithOptions {
     pub fn new(inner: HashMap<String, String>) -> Self {
         Self {
             inner: inner.into_iter().collect(),
+            parallelism: None,
+            resource_group: None,
+            secret_refs: BTreeMap::new(),
         }
     }
 
@@ -79,6 +104,16 @@ impl WithOptions {
             .and_then(|s| s.parse().ok())
     }
 
+    /// Get the parallelism declared via `WITH (parallelism = ...)`, if any.
+    pub fn parallelism(&self) -> Option<NonZeroU64> {
+        self.parallelism
+    }
+
+    /// Get the resource group declared via `WITH (resource_group = ...)`, if any.
+    pub fn resource_group(&self) -> Option<&str> {
+        self.resource_group.as_deref()
+    }
+
     /// Get a subset of the options from the given keys.
     pub fn subset(&self, keys: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         let inner = keys
@@ -90,14 +125,93 @@ impl WithOptions {
             })
             .collect();
 
-        Self { inner }
+        Self {
+            inner,
+            parallelism: None,
+            resource_group: None,
+            secret_refs: BTreeMap::new(),
+        }
     }
 
     /// Get the subset of the options for internal table catalogs.
     ///
-    /// Currently only `retention_seconds` is included.
+    /// Currently `retention_seconds`, `cold_storage_class` and `bloom_filter_mode` are included.
     pub fn internal_table_subset(&self) -> Self {
-        self.subset([options::RETENTION_SECONDS])
+        self.subset([
+            options::RETENTION_SECONDS,
+            options::COLD_STORAGE_CLASS,
+            options::BLOOM_FILTER_MODE,
+        ])
+    }
+
+    /// Rewrite the `retention` interval option into the `retention_seconds` property understood
+    /// by the compaction-filter TTL machinery.
+    fn normalize_retention(&mut self) -> RwResult<()> {
+        let Some(retention) = self.inner.remove(options::RETENTION) else {
+            return Ok(());
+        };
+        if self.inner.contains_key(options::RETENTION_SECONDS) {
+            return Err(ErrorCode::InvalidParameterValue(format!(
+                "`{}` and `{}` cannot be specified together",
+                options::RETENTION,
+                options::RETENTION_SECONDS
+            ))
+            .into());
+        }
+        let interval: Interval = retention.parse().map_err(|_| {
+            ErrorCode::InvalidParameterValue(format!(
+                "`{}` expects an interval like '7 days', got '{}'",
+                options::RETENTION,
+                retention
+            ))
+        })?;
+        let retention_seconds = interval.epoch_in_micros() / 1_000_000;
+        if retention_seconds <= 0 || retention_seconds > u32::MAX as i128 {
+            return Err(ErrorCode::InvalidParameterValue(format!(
+                "`{}` out of range: '{}'",
+                options::RETENTION,
+                retention
+            ))
+            .into());
+        }
+        self.inner.insert(
+            options::RETENTION_SECONDS.to_string(),
+            retention_seconds.to_string(),
+        );
+        Ok(())
+    }
+
+    /// Extract the `parallelism` option into [`Self::parallelism`], so that it never shows up in
+    /// the properties passed to connectors or persisted in the catalog.
+    fn normalize_parallelism(&mut self) -> RwResult<()> {
+        let Some(parallelism) = self.inner.remove(options::PARALLELISM) else {
+            return Ok(());
+        };
+        self.parallelism = Some(parallelism.parse().map_err(|_| {
+            ErrorCode::InvalidParameterValue(format!(
+                "`{}` expects a positive integer, got '{}'",
+                options::PARALLELISM,
+                parallelism
+            ))
+        })?);
+        Ok(())
+    }
+
+    /// Extract the `resource_group` option into [`Self::resource_group`], so that it never
+    /// shows up in the properties passed to connectors or persisted in the catalog.
+    fn normalize_resource_group(&mut self) -> RwResult<()> {
+        let Some(resource_group) = self.inner.remove(options::RESOURCE_GROUP) else {
+            return Ok(());
+        };
+        if resource_group.is_empty() {
+            return Err(ErrorCode::InvalidParameterValue(format!(
+                "`{}` cannot be empty",
+                options::RESOURCE_GROUP
+            ))
+            .into());
+        }
+        self.resource_group = Some(resource_group);
+        Ok(())
     }
 
     pub fn value_eq_ignore_case(&self, key: &str, val: &str) -> bool {
@@ -112,7 +226,11 @@ impl WithOptions {
 
 #[inline(always)]
 fn is_kafka_connector(with_options: &WithOptions) -> bool {
-    let Some(connector) = with_options.inner().get(UPSTREAM_SOURCE_KEY).map(|s| s.to_lowercase()) else {
+    let Some(connector) = with_options
+        .inner()
+        .get(UPSTREAM_SOURCE_KEY)
+        .map(|s| s.to_lowercase())
+    else {
         return false;
     };
     connector == KAFKA_CONNECTOR
@@ -143,25 +261,72 @@ pub(crate) fn resolve_connection_in_with_option(
     Ok(connection_id)
 }
 
+/// Resolve the `secret <name>` references in the `WITH` clause against the catalog.
+///
+/// Each referenced secret must exist, and referencing one is subject to the same ownership rules
+/// as dropping it. The reference is then lowered to a `secret:<id>` marker in the properties, so
+/// that the actual value is resolved on compute nodes and never shows up in the catalog or
+/// `SHOW CREATE` output.
+pub(crate) fn resolve_secret_refs_in_with_options(
+    with_options: &mut WithOptions,
+    session: &SessionImpl,
+) -> RwResult<()> {
+    if with_options.secret_refs.is_empty() {
+        return Ok(());
+    }
+
+    let db_name = session.database();
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    for (key, secret_name) in std::mem::take(&mut with_options.secret_refs) {
+        let (schema_name, secret) = {
+            let reader = session.env().catalog_reader().read_guard();
+            let schema_path = SchemaPath::new(None, &search_path, user_name);
+            let (secret, schema_name) =
+                reader.get_secret_by_name(db_name, schema_path, &secret_name)?;
+            (schema_name.to_string(), secret.clone())
+        };
+        session.check_privilege_for_drop_alter(&schema_name, &*secret)?;
+        with_options
+            .inner
+            .insert(key, secret_ref_property(secret.id));
+    }
+    Ok(())
+}
+
 impl TryFrom<&[SqlOption]> for WithOptions {
     type Error = RwError;
 
     fn try_from(options: &[SqlOption]) -> Result<Self, Self::Error> {
-        let inner = options
-            .iter()
-            .cloned()
-            .map(|x| match x.value {
-                Value::SingleQuotedString(s) => Ok((x.name.real_value(), s)),
-                Value::Number(n) => Ok((x.name.real_value(), n)),
-                Value::Boolean(b) => Ok((x.name.real_value(), b.to_string())),
-                _ => Err(ErrorCode::InvalidParameterValue(
-                    "`with options` or `with properties` only support single quoted string value"
-                        .to_owned(),
-                )),
-            })
-            .try_collect()?;
+        let mut inner = BTreeMap::new();
+        let mut secret_refs = BTreeMap::new();
+        for x in options.iter().cloned() {
+            let key = x.name.real_value();
+            match x.value {
+                Value::SingleQuotedString(s) => inner.insert(key, s),
+                Value::Number(n) => inner.insert(key, n),
+                Value::Boolean(b) => inner.insert(key, b.to_string()),
+                // The reference is resolved against the catalog once the session is available,
+                // see `resolve_secret_refs_in_with_options`.
+                Value::SecretRef(name) => secret_refs.insert(key, name),
+                _ => {
+                    return Err(ErrorCode::InvalidParameterValue(
+                        "`with options` or `with properties` only support single quoted string \
+                         value"
+                            .to_owned(),
+                    )
+                    .into())
+                }
+            };
+        }
 
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            parallelism: None,
+            resource_group: None,
+            secret_refs,
+        })
     }
 }
 
@@ -176,9 +341,28 @@ impl TryFrom<&Statement> for WithOptions {
 
             // Table & View
             Statement::CreateTable { with_options, .. }
-            | Statement::CreateView { with_options, .. } => Self::try_from(with_options.as_slice()),
+            | Statement::CreateView { with_options, .. } => {
+                let mut options = Self::try_from(with_options.as_slice())?;
+                options.normalize_retention()?;
+                options.normalize_parallelism()?;
+                options.normalize_resource_group()?;
+                Ok(options)
+            }
 
-            // Source & Sink
+            // Sink
+            Statement::CreateSink {
+                stmt:
+                    CreateSinkStatement {
+                        with_properties, ..
+                    },
+            } => {
+                let mut options = Self::try_from(with_properties.0.as_slice())?;
+                options.normalize_parallelism()?;
+                options.normalize_resource_group()?;
+                Ok(options)
+            }
+
+            // Source
             Statement::CreateSource {
                 stmt:
                     CreateSourceStatement {
@@ -186,15 +370,21 @@ impl TryFrom<&Statement> for WithOptions {
                     },
                 ..
             }
-            | Statement::CreateSink {
+            | Statement::CreateConnection {
                 stmt:
-                    CreateSinkStatement {
+                    CreateConnectionStatement {
                         with_properties, ..
                     },
             }
-            | Statement::CreateConnection {
+            | Statement::CreateSecret {
                 stmt:
-                    CreateConnectionStatement {
+                    CreateSecretStatement {
+                        with_properties, ..
+                    },
+            }
+            | Statement::AlterSecret {
+                stmt:
+                    AlterSecretStatement {
                         with_properties, ..
                     },
             } => Self::try_from(with_properties.0.as_slice()),
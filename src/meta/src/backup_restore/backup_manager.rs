@@ -12,20 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use arc_swap::ArcSwap;
 use itertools::Itertools;
 use risingwave_backup::error::BackupError;
+use risingwave_backup::meta_snapshot::{ClusterMetadataDelta, MetaSnapshotDelta};
 use risingwave_backup::storage::{BoxedMetaSnapshotStorage, ObjectStoreMetaSnapshotStorage};
-use risingwave_backup::{MetaBackupJobId, MetaSnapshotId, MetaSnapshotManifest};
+use risingwave_backup::{
+    snapshot_base_chain, MetaBackupJobId, MetaSnapshotId, MetaSnapshotManifest,
+};
 use risingwave_common::bail;
+use risingwave_common::config::RetryConfig;
 use risingwave_hummock_sdk::HummockSstableObjectId;
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_object_store::object::parse_remote_object_store;
 use risingwave_pb::backup_service::{BackupJobStatus, MetaBackupManifestId};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 
 use crate::backup_restore::meta_snapshot_builder::MetaSnapshotBuilder;
@@ -294,7 +300,25 @@ impl<S: MetaStore> BackupManager<S> {
     }
 
     /// Deletes existent backups from backup storage.
+    ///
+    /// A snapshot that a retained incremental snapshot depends on for reconstruction cannot be
+    /// deleted on its own.
     pub async fn delete_backups(&self, ids: &[MetaSnapshotId]) -> MetaResult<()> {
+        let to_delete: HashSet<MetaSnapshotId> = HashSet::from_iter(ids.iter().cloned());
+        let manifest = self.manifest();
+        for metadata in &manifest.snapshot_metadata {
+            if to_delete.contains(&metadata.id) {
+                continue;
+            }
+            for dep in snapshot_base_chain(&manifest, metadata.id) {
+                if to_delete.contains(&dep) {
+                    bail!(format!(
+                        "meta snapshot {} is the base of snapshot {}, delete the latter first",
+                        dep, metadata.id
+                    ));
+                }
+            }
+        }
         self.backup_store.load().0.delete(ids).await?;
         self.env
             .notification_manager()
@@ -323,6 +347,126 @@ impl<S: MetaStore> BackupManager<S> {
     pub fn manifest(&self) -> Arc<MetaSnapshotManifest> {
         self.backup_store.load().0.manifest()
     }
+
+    /// Takes an automatic meta snapshot if one is due and prunes snapshots beyond the retention
+    /// policy. No-op when `backup_auto_interval_sec` is zero.
+    async fn run_auto_backup(self: &Arc<Self>) -> MetaResult<()> {
+        let params = self.env.system_params_manager().get_params().await;
+        let interval_sec = params.backup_auto_interval_sec();
+        if interval_sec == 0 {
+            return Ok(());
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        // Snapshots without a timestamp are from older versions and don't count towards the
+        // schedule.
+        let latest_timestamp = self
+            .manifest()
+            .snapshot_metadata
+            .iter()
+            .filter_map(|m| m.timestamp)
+            .max();
+        if latest_timestamp.map_or(true, |t| now >= t + interval_sec) {
+            self.metrics.auto_job_count.inc();
+            if let Err(e) = self.start_backup_job().await {
+                // The job may be rejected by a concurrent manual one. It's retried on the next
+                // tick.
+                self.metrics.auto_job_failure_count.inc();
+                tracing::error!("automatic meta backup failed to start: {:#?}", e);
+            }
+        }
+        self.prune_snapshots(
+            now,
+            params.backup_retention_count(),
+            params.backup_retention_sec(),
+        )
+        .await
+    }
+
+    /// Prunes meta snapshots beyond the retention policy. The newest snapshot is never pruned.
+    async fn prune_snapshots(
+        &self,
+        now: u64,
+        retention_count: u64,
+        retention_sec: u64,
+    ) -> MetaResult<()> {
+        if retention_count == 0 && retention_sec == 0 {
+            return Ok(());
+        }
+        let mut snapshots = self.manifest().snapshot_metadata.clone();
+        snapshots.sort_unstable_by_key(|m| m.id);
+        let newest_id = match snapshots.last() {
+            Some(newest) => newest.id,
+            None => return Ok(()),
+        };
+        let mut to_delete: HashSet<MetaSnapshotId> = HashSet::new();
+        if retention_count > 0 && snapshots.len() as u64 > retention_count {
+            let stale_count = snapshots.len() - retention_count as usize;
+            to_delete.extend(snapshots[..stale_count].iter().map(|m| m.id));
+        }
+        if retention_sec > 0 {
+            to_delete.extend(
+                snapshots
+                    .iter()
+                    .filter(|m| m.timestamp.map_or(false, |t| t + retention_sec < now))
+                    .map(|m| m.id),
+            );
+        }
+        to_delete.remove(&newest_id);
+        // Never prune a snapshot that a retained one still needs for reconstruction.
+        let manifest = self.manifest();
+        for metadata in &manifest.snapshot_metadata {
+            if !to_delete.contains(&metadata.id) {
+                for dep in snapshot_base_chain(&manifest, metadata.id) {
+                    to_delete.remove(&dep);
+                }
+            }
+        }
+        if to_delete.is_empty() {
+            return Ok(());
+        }
+        let to_delete = to_delete.into_iter().sorted().collect_vec();
+        tracing::info!(
+            "pruning meta snapshots beyond retention policy: {:?}",
+            to_delete
+        );
+        self.delete_backups(&to_delete).await?;
+        self.metrics
+            .pruned_snapshot_count
+            .inc_by(to_delete.len() as u64);
+        Ok(())
+    }
+}
+
+/// Starts a task that drives automatic meta backups and retention-based pruning, controlled by
+/// the `backup_auto_interval_sec`, `backup_retention_count` and `backup_retention_sec` system
+/// parameters.
+pub fn start_auto_backup_scheduler<S: MetaStore>(
+    backup_manager: BackupManagerRef<S>,
+    check_interval: Duration,
+) -> (JoinHandle<()>, Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(check_interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_trigger_interval.tick() => {},
+                // Shutdown scheduler
+                _ = &mut shutdown_rx => {
+                    tracing::info!("auto backup scheduler is stopped");
+                    return;
+                }
+            }
+            if let Err(err) = backup_manager.run_auto_backup().await {
+                tracing::error!("automatic meta backup error {:#?}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
 }
 
 /// `BackupWorker` creates a database snapshot.
@@ -330,6 +474,11 @@ struct BackupWorker<S: MetaStore> {
     backup_manager: BackupManagerRef<S>,
 }
 
+/// Maximum number of snapshots an incremental snapshot may transitively depend on. A full
+/// snapshot is taken when the delta chain would otherwise grow beyond this length, bounding
+/// reconstruction cost at restore time.
+const MAX_DELTA_CHAIN_LEN: usize = 16;
+
 impl<S: MetaStore> BackupWorker<S> {
     fn new(backup_manager: BackupManagerRef<S>) -> Self {
         Self { backup_manager }
@@ -348,12 +497,38 @@ impl<S: MetaStore> BackupWorker<S> {
                 })
                 .await?;
             let snapshot = snapshot_builder.finish()?;
-            backup_manager_clone
-                .backup_store
-                .load()
-                .0
-                .create(&snapshot)
-                .await?;
+            let backup_store = backup_manager_clone.backup_store.load_full();
+            // Store an incremental snapshot when a usable base exists, to keep backup time and
+            // object-store usage bounded. Fall back to a full one otherwise.
+            let base_id = {
+                let manifest = backup_store.0.manifest();
+                manifest
+                    .snapshot_metadata
+                    .iter()
+                    .map(|m| m.id)
+                    .max()
+                    .filter(|latest| {
+                        snapshot_base_chain(&manifest, *latest).len() + 1 < MAX_DELTA_CHAIN_LEN
+                    })
+            };
+            match base_id {
+                Some(base_id) => {
+                    let base_snapshot = backup_store.0.get(base_id).await?;
+                    let delta = MetaSnapshotDelta {
+                        format_version: snapshot.format_version,
+                        id: snapshot.id,
+                        base_id,
+                        metadata_delta: ClusterMetadataDelta::diff(
+                            &base_snapshot.metadata,
+                            &snapshot.metadata,
+                        ),
+                    };
+                    backup_store.0.create_delta(&delta).await?;
+                }
+                None => {
+                    backup_store.0.create(&snapshot).await?;
+                }
+            }
             Ok(BackupJobResult::Succeeded)
         };
         tokio::spawn(async move {
@@ -369,7 +544,16 @@ async fn create_snapshot_store(
     config: &StoreConfig,
     metric: Arc<ObjectStoreMetrics>,
 ) -> MetaResult<BoxedMetaSnapshotStorage> {
-    let object_store = Arc::new(parse_remote_object_store(&config.0, metric, "Meta Backup").await);
+    let object_store = Arc::new(
+        parse_remote_object_store(
+            &config.0,
+            metric,
+            "Meta Backup",
+            None,
+            RetryConfig::default(),
+        )
+        .await,
+    );
     let store = ObjectStoreMetaSnapshotStorage::new(&config.1, object_store).await?;
     Ok(Box::new(store))
 }
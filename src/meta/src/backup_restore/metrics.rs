@@ -21,6 +21,9 @@ pub struct BackupManagerMetrics {
     pub job_count: IntCounter,
     pub job_latency_success: Histogram,
     pub job_latency_failure: Histogram,
+    pub auto_job_count: IntCounter,
+    pub auto_job_failure_count: IntCounter,
+    pub pruned_snapshot_count: IntCounter,
 }
 
 impl BackupManagerMetrics {
@@ -44,10 +47,31 @@ impl BackupManagerMetrics {
         let job_latency_failure = job_latency
             .get_metric_with_label_values(&["failure"])
             .unwrap();
+        let auto_job_count = register_int_counter_with_registry!(
+            "backup_auto_job_count",
+            "total automatic backup job count since meta node is started",
+            registry,
+        )
+        .unwrap();
+        let auto_job_failure_count = register_int_counter_with_registry!(
+            "backup_auto_job_failure_count",
+            "total failed automatic backup job count since meta node is started",
+            registry,
+        )
+        .unwrap();
+        let pruned_snapshot_count = register_int_counter_with_registry!(
+            "backup_pruned_snapshot_count",
+            "total meta snapshot count pruned by the retention policy since meta node is started",
+            registry,
+        )
+        .unwrap();
         Self {
             job_count,
             job_latency_success,
             job_latency_failure,
+            auto_job_count,
+            auto_job_failure_count,
+            pruned_snapshot_count,
         }
     }
 }
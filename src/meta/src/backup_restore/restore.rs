@@ -19,7 +19,7 @@ use itertools::Itertools;
 use risingwave_backup::error::{BackupError, BackupResult};
 use risingwave_backup::meta_snapshot::MetaSnapshot;
 use risingwave_backup::storage::MetaSnapshotStorageRef;
-use risingwave_common::config::MetaBackend;
+use risingwave_common::config::{MetaBackend, RetryConfig};
 use risingwave_hummock_sdk::version_checkpoint_path;
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_object_store::object::parse_remote_object_store;
@@ -81,6 +81,8 @@ async fn restore_hummock_version(
             hummock_storage_url,
             Arc::new(ObjectStoreMetrics::unused()),
             "Version Checkpoint",
+            None,
+            RetryConfig::default(),
         )
         .await,
     );
@@ -328,6 +330,10 @@ mod tests {
             backup_storage_url: Some("backup_storage_url".to_string()),
             backup_storage_directory: Some("backup_storage_directory".to_string()),
             telemetry_enabled: Some(false),
+            resource_label: Some("".to_string()),
+            backup_auto_interval_sec: Some(0),
+            backup_retention_count: Some(0),
+            backup_retention_sec: Some(0),
         }
     }
 
@@ -130,6 +130,7 @@ pub enum Command {
         old_table_fragments: TableFragments,
         new_table_fragments: TableFragments,
         merge_updates: Vec<MergeUpdate>,
+        init_split_assignment: SplitAssignment,
     },
 
     /// `SourceSplitAssignment` generates Plain(Mutation::Splits) for pushing initialized splits or
@@ -310,13 +311,20 @@ where
             Command::ReplaceTable {
                 old_table_fragments,
                 merge_updates,
+                init_split_assignment,
                 ..
             } => {
                 let dropped_actors = old_table_fragments.actor_ids();
 
+                let actor_splits = init_split_assignment
+                    .values()
+                    .flat_map(build_actor_connector_splits)
+                    .collect();
+
                 Some(Mutation::Update(UpdateMutation {
                     merge_update: merge_updates.clone(),
                     dropped_actors,
+                    actor_splits,
                     ..Default::default()
                 }))
             }
@@ -670,6 +678,7 @@ where
                 old_table_fragments,
                 new_table_fragments,
                 merge_updates,
+                init_split_assignment,
             } => {
                 let table_ids = HashSet::from_iter(std::iter::once(old_table_fragments.table_id()));
 
@@ -685,6 +694,19 @@ where
                         merge_updates,
                     )
                     .await?;
+
+                // Update the source fragments and the split assignments for a table with a
+                // connector.
+                self.source_manager
+                    .drop_source_change(std::slice::from_ref(old_table_fragments))
+                    .await;
+                self.source_manager
+                    .apply_source_change(
+                        Some(new_table_fragments.stream_source_fragments()),
+                        Some(init_split_assignment.clone()),
+                        None,
+                    )
+                    .await;
             }
         }
 
@@ -0,0 +1,94 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+
+use itertools::Itertools;
+use parking_lot::Mutex;
+use risingwave_pb::meta::barrier_latency_entry::FragmentLatency;
+use risingwave_pb::meta::BarrierLatencyEntry;
+use risingwave_pb::stream_service::BarrierCompleteResponse;
+
+use crate::model::{ActorId, FragmentId};
+
+/// Maximum number of barriers to keep latency breakdowns for. At the default barrier interval
+/// of 1s this covers roughly the last 17 minutes.
+const BARRIER_LATENCY_TRACE_CAPACITY: usize = 1024;
+
+/// A bounded in-memory trace of per-fragment barrier latencies, fed by
+/// [`crate::barrier::GlobalBarrierManager`] on each barrier completion. It is not persisted in
+/// the meta store and starts empty after a meta node restart.
+#[derive(Default)]
+pub struct BarrierLatencyTrace {
+    entries: Mutex<VecDeque<BarrierLatencyEntry>>,
+}
+
+impl BarrierLatencyTrace {
+    /// Record the latency breakdown of a completed barrier, evicting the oldest entry if the
+    /// trace is full.
+    pub fn record(&self, entry: BarrierLatencyEntry) {
+        let mut entries = self.entries.lock();
+        if entries.len() >= BARRIER_LATENCY_TRACE_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// List the latest `limit` entries, latest first. A `limit` of 0 means no limit.
+    pub fn list(&self, limit: usize) -> Vec<BarrierLatencyEntry> {
+        let entries = self.entries.lock();
+        let limit = if limit == 0 { entries.len() } else { limit };
+        entries.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+/// Build a trace entry by aggregating the per-actor collect latencies reported by the compute
+/// nodes into per-fragment max and average. Actors that are no longer found in the fragment
+/// manager (e.g. just dropped) are skipped.
+pub(super) fn build_latency_entry(
+    prev_epoch: u64,
+    curr_epoch: u64,
+    checkpoint: bool,
+    total_latency_ms: u64,
+    resps: &[BarrierCompleteResponse],
+    actor_to_fragment: &HashMap<ActorId, FragmentId>,
+) -> BarrierLatencyEntry {
+    let mut latencies: HashMap<FragmentId, Vec<u64>> = HashMap::new();
+    for latency in resps.iter().flat_map(|r| &r.actor_collect_latency) {
+        if let Some(fragment_id) = actor_to_fragment.get(&latency.actor_id) {
+            latencies
+                .entry(*fragment_id)
+                .or_default()
+                .push(latency.collect_latency_ms);
+        }
+    }
+
+    let fragment_latency = latencies
+        .into_iter()
+        .map(|(fragment_id, latencies)| FragmentLatency {
+            fragment_id,
+            max_collect_latency_ms: latencies.iter().copied().max().unwrap_or(0),
+            avg_collect_latency_ms: latencies.iter().sum::<u64>() / latencies.len() as u64,
+        })
+        .sorted_by_key(|f| f.fragment_id)
+        .collect();
+
+    BarrierLatencyEntry {
+        prev_epoch,
+        curr_epoch,
+        checkpoint,
+        total_latency_ms,
+        fragment_latency,
+    }
+}
@@ -16,7 +16,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::mem::take;
 use std::ops::Deref;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use fail::fail_point;
 use futures::future::try_join_all;
@@ -24,13 +24,15 @@ use itertools::Itertools;
 use prometheus::HistogramTimer;
 use risingwave_common::bail;
 use risingwave_common::catalog::TableId;
-use risingwave_common::util::epoch::INVALID_EPOCH;
+use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_common::util::tracing::TracingContext;
 use risingwave_hummock_sdk::{ExtendedSstableInfo, HummockSstableObjectId};
-use risingwave_pb::ddl_service::DdlProgress;
+use risingwave_pb::ddl_service::{BackfillProgress, DdlProgress};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::table_fragments::actor_status::ActorState;
-use risingwave_pb::stream_plan::Barrier;
+use risingwave_pb::meta::{BarrierLatencyEntry, RecoveryEvent, RecoveryRecord};
+use risingwave_pb::stream_plan::barrier::Mutation;
+use risingwave_pb::stream_plan::{Barrier, SourcePauseMutation};
 use risingwave_pb::stream_service::{
     BarrierCompleteRequest, BarrierCompleteResponse, InjectBarrierRequest,
 };
@@ -43,8 +45,11 @@ use uuid::Uuid;
 
 use self::command::CommandContext;
 use self::info::BarrierActorInfo;
+use self::latency::BarrierLatencyTrace;
 use self::notifier::Notifier;
 use self::progress::TrackingCommand;
+use self::quarantine::{unix_millis, StreamingJobQuarantine};
+use self::recovery_log::RecoveryLog;
 use crate::barrier::progress::CreateMviewProgressTracker;
 use crate::barrier::BarrierEpochState::{Completed, InFlight};
 use crate::hummock::HummockManagerRef;
@@ -60,9 +65,12 @@ use crate::{MetaError, MetaResult};
 
 mod command;
 mod info;
+mod latency;
 mod notifier;
 mod progress;
+mod quarantine;
 mod recovery;
+mod recovery_log;
 mod schedule;
 mod trace;
 
@@ -143,6 +151,15 @@ pub struct GlobalBarrierManager<S: MetaStore> {
 
     metrics: Arc<MetaMetrics>,
 
+    /// In-memory trace of per-fragment barrier latency breakdowns.
+    latency_trace: BarrierLatencyTrace,
+
+    /// Per-job failure tracking and the recovery event log.
+    quarantine: StreamingJobQuarantine,
+
+    /// Bounded in-memory log of past recoveries.
+    recovery_log: RecoveryLog,
+
     pub(crate) env: MetaSrvEnv<S>,
 
     tracker: Mutex<CreateMviewProgressTracker<S>>,
@@ -494,6 +511,7 @@ where
     ) -> Self {
         let enable_recovery = env.opts.enable_recovery;
         let in_flight_barrier_nums = env.opts.in_flight_barrier_nums;
+        let quarantine = StreamingJobQuarantine::new(env.opts.quarantine_failure_threshold);
 
         let tracker = CreateMviewProgressTracker::new();
         Self {
@@ -507,6 +525,9 @@ where
             hummock_manager,
             source_manager,
             metrics,
+            latency_trace: BarrierLatencyTrace::default(),
+            quarantine,
+            recovery_log: RecoveryLog::default(),
             env,
             tracker: Mutex::new(tracker),
         }
@@ -819,6 +840,14 @@ where
             return;
         }
 
+        // Actors that exited unexpectedly are reported as a structured part of the responses
+        // rather than an RPC error, so that they can be attributed to the owning streaming jobs
+        // before the failure triggers recovery.
+        let result = match result {
+            Ok(resps) => self.apply_quarantine_policy(resps).await,
+            Err(err) => Err(err),
+        };
+
         if let Err(err) = result {
             // FIXME: If it is a connector source error occurred in the init barrier, we should pass
             // back to frontend
@@ -873,6 +902,9 @@ where
         if self.enable_recovery {
             // If failed, enter recovery mode.
             self.set_status(BarrierManagerStatus::Recovering).await;
+            self.quarantine.record_recovery(&err.to_string());
+            let started_at_ms = unix_millis();
+            let recovery_timer = Instant::now();
             let mut tracker = self.tracker.lock().await;
             *tracker = CreateMviewProgressTracker::new();
             let new_epoch = self.recovery(state.in_flight_prev_epoch()).await;
@@ -880,12 +912,89 @@ where
                 .update_inflight_prev_epoch(self.env.meta_store(), new_epoch)
                 .await
                 .unwrap();
+            self.recovery_log.record(
+                started_at_ms,
+                recovery_timer.elapsed().as_millis() as u64,
+                new_epoch.0,
+                &err.to_string(),
+            );
+            self.reapply_quarantine().await;
             self.set_status(BarrierManagerStatus::Running).await;
         } else {
             panic!("failed to execute barrier: {:?}", err);
         }
     }
 
+    /// Check the responses for actors that exited unexpectedly. If there are any, feed them to
+    /// the quarantine policy and convert them into an error to trigger recovery.
+    async fn apply_quarantine_policy(
+        &self,
+        resps: Vec<BarrierCompleteResponse>,
+    ) -> MetaResult<Vec<BarrierCompleteResponse>> {
+        let failed_actors = resps.iter().flat_map(|r| &r.failed_actors).collect_vec();
+        if failed_actors.is_empty() {
+            return Ok(resps);
+        }
+
+        // Count at most one failure per streaming job per failed barrier, so that the failure
+        // of a job with high parallelism is not counted once per actor.
+        let actor_to_table = self.fragment_manager.get_actor_to_table_mapping().await;
+        let mut failed_jobs: HashMap<TableId, &str> = HashMap::new();
+        for failed in &failed_actors {
+            if let Some(table_id) = actor_to_table.get(&failed.actor_id) {
+                failed_jobs
+                    .entry(*table_id)
+                    .or_insert(failed.message.as_str());
+            }
+        }
+        for (table_id, message) in failed_jobs {
+            if self.quarantine.on_actor_failure(table_id, message) {
+                tracing::warn!(
+                    "streaming job {} is quarantined after repeated actor failures, resume it \
+                     with `risectl meta resume-quarantined-job` once the cause is resolved",
+                    table_id
+                );
+            }
+        }
+
+        self.recovery_log
+            .stage_failed_actors(failed_actors.iter().map(|f| f.actor_id).collect());
+
+        let failed = failed_actors.first().unwrap();
+        Err(anyhow::anyhow!(
+            "actor {} exit unexpectedly: {}",
+            failed.actor_id,
+            failed.message
+        )
+        .into())
+    }
+
+    /// Re-schedule the pause on all quarantined jobs. Invoked after a recovery, since rebuilt
+    /// source actors start unpaused.
+    async fn reapply_quarantine(&self) {
+        for table_id in self.quarantine.quarantined_jobs() {
+            let Ok(table_fragments) = self
+                .fragment_manager
+                .select_table_fragments_by_table_id(&table_id)
+                .await
+            else {
+                // The job has been dropped in the meantime.
+                self.quarantine.forget(table_id);
+                continue;
+            };
+            let actor_pause = table_fragments
+                .barrier_inject_actor_ids()
+                .into_iter()
+                .map(|actor_id| (actor_id, true))
+                .collect();
+            self.scheduled_barriers
+                .push_command(Command::Plain(Some(Mutation::PauseSource(
+                    SourcePauseMutation { actor_pause },
+                ))))
+                .await;
+        }
+    }
+
     /// Try to commit this node. If err, returns
     async fn complete_barrier(
         &self,
@@ -900,6 +1009,25 @@ where
                 // the L0 layer files are generated.
                 // See https://github.com/risingwave-labs/risingwave/issues/1251
                 let checkpoint = node.command_ctx.checkpoint;
+
+                // Record the per-fragment latency breakdown of this barrier in the in-memory
+                // trace.
+                let curr_epoch = node.command_ctx.curr_epoch.value();
+                let total_latency_ms =
+                    Epoch::physical_now().saturating_sub(curr_epoch.physical_time());
+                let actor_to_fragment = self.fragment_manager.get_actor_to_fragment_mapping().await;
+                self.latency_trace.record(latency::build_latency_entry(
+                    prev_epoch,
+                    curr_epoch.0,
+                    checkpoint,
+                    total_latency_ms,
+                    resps,
+                    &actor_to_fragment,
+                ));
+                if checkpoint {
+                    self.adapt_checkpoint_frequency(total_latency_ms).await;
+                }
+
                 let (sst_to_worker, synced_ssts) = collect_synced_ssts(resps);
                 // hummock_manager commit epoch.
                 let mut new_snapshot = None;
@@ -1033,6 +1161,53 @@ where
         self.tracker.lock().await.gen_ddl_progress()
     }
 
+    pub async fn get_backfill_progress(&self) -> Vec<BackfillProgress> {
+        self.tracker.lock().await.gen_backfill_progress()
+    }
+
+    /// List the latest `limit` barrier latency breakdowns, latest first. A `limit` of 0 means no
+    /// limit.
+    pub fn list_barrier_latency(&self, limit: usize) -> Vec<BarrierLatencyEntry> {
+        self.latency_trace.list(limit)
+    }
+
+    /// List the recorded recovery events, latest first.
+    pub fn list_recovery_events(&self) -> Vec<RecoveryEvent> {
+        self.quarantine.list_events()
+    }
+
+    /// List the recorded recoveries, latest first.
+    pub fn list_recoveries(&self) -> Vec<RecoveryRecord> {
+        self.recovery_log.list()
+    }
+
+    /// Take a streaming job out of the quarantine. The caller should resume the job's source
+    /// actors afterwards.
+    pub fn resume_quarantined_job(&self, table_id: TableId) -> MetaResult<()> {
+        if !self.quarantine.resume(table_id) {
+            bail!("streaming job {} is not quarantined", table_id);
+        }
+        tracing::info!("streaming job {} is taken out of quarantine", table_id);
+        Ok(())
+    }
+
+    /// Adjust the checkpoint frequency according to the latency of the last completed checkpoint
+    /// barrier, if adaptive checkpointing is enabled. Checkpointing backs off when barriers take
+    /// longer than the configured threshold and recovers once they are healthy again, bounded by
+    /// the min/max frequency system params.
+    async fn adapt_checkpoint_frequency(&self, total_latency_ms: u64) {
+        let params = self.env.system_params_manager().get_params().await;
+        let threshold = params.adaptive_checkpoint_latency_threshold_ms();
+        if threshold == 0 {
+            return;
+        }
+        self.scheduled_barriers.adapt_checkpoint_frequency(
+            total_latency_ms <= threshold,
+            params.adaptive_checkpoint_frequency_min() as usize,
+            params.adaptive_checkpoint_frequency_max() as usize,
+        );
+    }
+
     /// Only handle `SystemParamsChange`.
     fn handle_local_notification(&self, notification: LocalNotification) {
         if let LocalNotification::SystemParamsChange(p) = notification {
@@ -13,20 +13,20 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::Epoch;
-use risingwave_pb::ddl_service::DdlProgress;
+use risingwave_pb::ddl_service::{BackfillProgress, DdlProgress};
 use risingwave_pb::hummock::HummockVersionStats;
 use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
 
 use super::command::CommandContext;
 use super::notifier::Notifier;
 use crate::barrier::Command;
-use crate::model::ActorId;
+use crate::model::{ActorId, FragmentId};
 use crate::storage::MetaStore;
 
 type CreateMviewEpoch = Epoch;
@@ -46,6 +46,10 @@ struct Progress {
 
     done_count: usize,
 
+    /// Fragment of each actor containing the chain node, for per-fragment backfill progress
+    /// reporting.
+    actor_fragments: HashMap<ActorId, FragmentId>,
+
     /// Creating mv id.
     creating_mv_id: TableId,
 
@@ -64,6 +68,7 @@ impl Progress {
     fn new(
         actors: impl IntoIterator<Item = ActorId>,
         creating_mv_id: TableId,
+        actor_fragments: HashMap<ActorId, FragmentId>,
         upstream_mv_count: HashMap<TableId, usize>,
         upstream_total_key_count: u64,
         definition: String,
@@ -77,6 +82,7 @@ impl Progress {
         Self {
             states,
             done_count: 0,
+            actor_fragments,
             creating_mv_id,
             upstream_mv_count,
             upstream_total_key_count,
@@ -135,6 +141,36 @@ impl Progress {
         }
         progress
     }
+
+    /// Aggregate the per-actor states into per-fragment backfill progress.
+    fn gen_backfill_progress(&self) -> Vec<BackfillProgress> {
+        let mut per_fragment: BTreeMap<FragmentId, BackfillProgress> = BTreeMap::new();
+        for (actor, state) in &self.states {
+            let fragment_id = self.actor_fragments[actor];
+            let entry = per_fragment
+                .entry(fragment_id)
+                .or_insert_with(|| BackfillProgress {
+                    job_id: self.creating_mv_id.table_id,
+                    fragment_id,
+                    upstream_total_key_count: self.upstream_total_key_count,
+                    ..Default::default()
+                });
+            entry.total_actors += 1;
+            match state {
+                ChainState::Init => {}
+                ChainState::ConsumingUpstream(epoch, consumed_rows) => {
+                    entry.consumed_rows += consumed_rows;
+                    entry.min_consumed_epoch = if entry.min_consumed_epoch == 0 {
+                        epoch.0
+                    } else {
+                        entry.min_consumed_epoch.min(epoch.0)
+                    };
+                }
+                ChainState::Done => entry.done_actors += 1,
+            }
+        }
+        per_fragment.into_values().collect()
+    }
 }
 
 /// The command tracking by the [`CreateMviewProgressTracker`].
@@ -175,6 +211,13 @@ impl<S: MetaStore> CreateMviewProgressTracker<S> {
             .collect()
     }
 
+    pub fn gen_backfill_progress(&self) -> Vec<BackfillProgress> {
+        self.progress_map
+            .values()
+            .flat_map(|(x, _)| x.gen_backfill_progress())
+            .collect()
+    }
+
     /// Try to find the target create-streaming-job command from track.
     ///
     /// Return the target command as it should be cancelled based on the input actors.
@@ -215,50 +258,57 @@ impl<S: MetaStore> CreateMviewProgressTracker<S> {
             self.actor_map.insert(actor, ddl_epoch);
         }
 
-        let (creating_mv_id, upstream_mv_count, upstream_total_key_count, definition) =
-            if let Command::CreateStreamingJob {
-                table_fragments,
-                dispatchers,
-                upstream_mview_actors,
-                definition,
-                ..
-            } = &command.context.command
-            {
-                // Keep track of how many times each upstream MV appears.
-                let mut upstream_mv_count = HashMap::new();
-                for (table_id, actors) in upstream_mview_actors {
-                    assert!(!actors.is_empty());
-                    let dispatch_count: usize = dispatchers
-                        .iter()
-                        .filter(|(upstream_actor_id, _)| actors.contains(upstream_actor_id))
-                        .map(|(_, v)| v.len())
-                        .sum();
-                    upstream_mv_count.insert(*table_id, dispatch_count / actors.len());
-                }
-
-                let upstream_total_key_count: u64 = upstream_mv_count
+        let (
+            creating_mv_id,
+            actor_fragments,
+            upstream_mv_count,
+            upstream_total_key_count,
+            definition,
+        ) = if let Command::CreateStreamingJob {
+            table_fragments,
+            dispatchers,
+            upstream_mview_actors,
+            definition,
+            ..
+        } = &command.context.command
+        {
+            // Keep track of how many times each upstream MV appears.
+            let mut upstream_mv_count = HashMap::new();
+            for (table_id, actors) in upstream_mview_actors {
+                assert!(!actors.is_empty());
+                let dispatch_count: usize = dispatchers
                     .iter()
-                    .map(|(upstream_mv, count)| {
-                        *count as u64
-                            * version_stats
-                                .table_stats
-                                .get(&upstream_mv.table_id)
-                                .map_or(0, |stat| stat.total_key_count as u64)
-                    })
+                    .filter(|(upstream_actor_id, _)| actors.contains(upstream_actor_id))
+                    .map(|(_, v)| v.len())
                     .sum();
-                (
-                    table_fragments.table_id(),
-                    upstream_mv_count,
-                    upstream_total_key_count,
-                    definition.to_string(),
-                )
-            } else {
-                unreachable!("Must be CreateStreamingJob.");
-            };
+                upstream_mv_count.insert(*table_id, dispatch_count / actors.len());
+            }
+
+            let upstream_total_key_count: u64 = upstream_mv_count
+                .iter()
+                .map(|(upstream_mv, count)| {
+                    *count as u64
+                        * version_stats
+                            .table_stats
+                            .get(&upstream_mv.table_id)
+                            .map_or(0, |stat| stat.total_key_count as u64)
+                })
+                .sum();
+            (
+                table_fragments.table_id(),
+                table_fragments.actor_fragment_mapping(),
+                upstream_mv_count,
+                upstream_total_key_count,
+                definition.to_string(),
+            )
+        } else {
+            unreachable!("Must be CreateStreamingJob.");
+        };
 
         let progress = Progress::new(
             actors,
             creating_mv_id,
+            actor_fragments,
             upstream_mv_count,
             upstream_total_key_count,
             definition,
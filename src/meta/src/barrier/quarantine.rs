@@ -0,0 +1,127 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_common::catalog::TableId;
+use risingwave_pb::meta::recovery_event::Kind;
+use risingwave_pb::meta::RecoveryEvent;
+
+/// Maximum number of recovery events to keep in the log.
+const RECOVERY_EVENT_LOG_CAPACITY: usize = 256;
+
+/// Tracks unexpected actor failures per streaming job and quarantines jobs that keep failing,
+/// i.e. pauses their source actors while the rest of the cluster keeps running. Also keeps a
+/// bounded in-memory log of recovery events, exposed through `rw_catalog.rw_recovery_events`.
+/// Neither the quarantine set nor the event log is persisted in the meta store.
+pub struct StreamingJobQuarantine {
+    /// Number of failures after which a job is quarantined. Zero disables quarantine.
+    threshold: u64,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// Accumulated number of unexpected actor failures per streaming job.
+    failure_counts: HashMap<TableId, u64>,
+    /// Streaming jobs that are currently quarantined.
+    quarantined: HashSet<TableId>,
+    events: VecDeque<RecoveryEvent>,
+}
+
+impl Inner {
+    fn record_event(&mut self, kind: Kind, table_id: u32, failure_count: u64, message: &str) {
+        if self.events.len() >= RECOVERY_EVENT_LOG_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(RecoveryEvent {
+            kind: kind as i32,
+            at_ms: unix_millis(),
+            table_id,
+            failure_count,
+            message: message.to_owned(),
+        });
+    }
+}
+
+pub(super) fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+impl StreamingJobQuarantine {
+    pub fn new(threshold: u64) -> Self {
+        Self {
+            threshold,
+            inner: Mutex::new(Inner::default()),
+        }
+    }
+
+    /// Record that a recovery was triggered by the given error.
+    pub fn record_recovery(&self, message: &str) {
+        self.inner
+            .lock()
+            .record_event(Kind::Recovery, 0, 0, message);
+    }
+
+    /// Record an unexpected failure of an actor of the given streaming job. Returns `true` if
+    /// this failure newly quarantines the job.
+    pub fn on_actor_failure(&self, table_id: TableId, message: &str) -> bool {
+        let mut inner = self.inner.lock();
+        let count = {
+            let count = inner.failure_counts.entry(table_id).or_default();
+            *count += 1;
+            *count
+        };
+        if self.threshold == 0 || count < self.threshold || !inner.quarantined.insert(table_id) {
+            return false;
+        }
+        inner.record_event(Kind::Quarantine, table_id.table_id, count, message);
+        true
+    }
+
+    /// Streaming jobs that are currently quarantined.
+    pub fn quarantined_jobs(&self) -> Vec<TableId> {
+        self.inner.lock().quarantined.iter().copied().collect()
+    }
+
+    /// Remove a job from the quarantine without recording an event, e.g. when it has been
+    /// dropped.
+    pub fn forget(&self, table_id: TableId) {
+        let mut inner = self.inner.lock();
+        inner.quarantined.remove(&table_id);
+        inner.failure_counts.remove(&table_id);
+    }
+
+    /// Resume a quarantined job and reset its failure count. Returns `false` if the job is not
+    /// quarantined.
+    pub fn resume(&self, table_id: TableId) -> bool {
+        let mut inner = self.inner.lock();
+        if !inner.quarantined.remove(&table_id) {
+            return false;
+        }
+        let count = inner.failure_counts.remove(&table_id).unwrap_or(0);
+        inner.record_event(Kind::Resume, table_id.table_id, count, "");
+        true
+    }
+
+    /// List the recorded recovery events, latest first.
+    pub fn list_events(&self) -> Vec<RecoveryEvent> {
+        self.inner.lock().events.iter().rev().cloned().collect()
+    }
+}
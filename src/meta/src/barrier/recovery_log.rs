@@ -0,0 +1,66 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use parking_lot::Mutex;
+use risingwave_pb::meta::RecoveryRecord;
+
+/// Maximum number of recovery records to keep in the log.
+const RECOVERY_LOG_CAPACITY: usize = 256;
+
+/// Bounded in-memory log of past recoveries, with the cause, the failed actors, the duration and
+/// the epoch the cluster resumed at. Exposed through `rw_catalog.rw_recovery_log` and
+/// `risectl meta list-recoveries` for post-incident reviews. Not persisted in the meta store.
+#[derive(Default)]
+pub struct RecoveryLog {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    records: VecDeque<RecoveryRecord>,
+    /// Actors whose unexpected exit is about to trigger a recovery, staged until the recovery
+    /// completes and is recorded.
+    pending_failed_actors: Vec<u32>,
+}
+
+impl RecoveryLog {
+    /// Stage the actors whose unexpected exit is about to trigger a recovery, to be attached to
+    /// the next recorded recovery.
+    pub fn stage_failed_actors(&self, actor_ids: Vec<u32>) {
+        self.inner.lock().pending_failed_actors = actor_ids;
+    }
+
+    /// Record a completed recovery.
+    pub fn record(&self, started_at_ms: u64, duration_ms: u64, new_epoch: u64, cause: &str) {
+        let mut inner = self.inner.lock();
+        let failed_actors = std::mem::take(&mut inner.pending_failed_actors);
+        if inner.records.len() >= RECOVERY_LOG_CAPACITY {
+            inner.records.pop_front();
+        }
+        inner.records.push_back(RecoveryRecord {
+            started_at_ms,
+            duration_ms,
+            new_epoch,
+            cause: cause.to_owned(),
+            failed_actors,
+        });
+    }
+
+    /// List the recorded recoveries, latest first.
+    pub fn list(&self) -> Vec<RecoveryRecord> {
+        self.inner.lock().records.iter().rev().cloned().collect()
+    }
+}
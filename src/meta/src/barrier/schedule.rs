@@ -287,6 +287,21 @@ impl ScheduledBarriers {
         rx.changed().await.unwrap();
     }
 
+    /// Push a command into the queue without waiting for its completion. Used by the barrier
+    /// manager itself, e.g. to re-apply the pause on quarantined jobs after a recovery.
+    pub(super) async fn push_command(&self, command: Command) {
+        let mut queue = self.inner.queue.write().await;
+        queue.push_back(Scheduled {
+            command,
+            notifiers: Default::default(),
+            send_latency_timer: self.inner.metrics.barrier_send_latency.start_timer(),
+            checkpoint: false,
+        });
+        if queue.len() == 1 {
+            self.inner.changed_tx.send(()).ok();
+        }
+    }
+
     /// Clear all queued scheduled barriers, and notify their subscribers with failed as aborted.
     pub(super) async fn abort(&self) {
         let mut queue = self.inner.queue.write().await;
@@ -318,6 +333,30 @@ impl ScheduledBarriers {
             .store(frequency, Ordering::Relaxed);
     }
 
+    /// Adaptively adjust the `checkpoint_frequency` within `[min, max]`: double it when barriers
+    /// are unhealthy to lengthen the checkpoint interval, and decrement it when healthy to shrink
+    /// the interval back.
+    pub(super) fn adapt_checkpoint_frequency(&self, healthy: bool, min: usize, max: usize) {
+        let current = self.inner.checkpoint_frequency.load(Ordering::Relaxed);
+        // The bounds are validated to satisfy `min <= max` when the system params are set, but
+        // `clamp` panics on an inverted range, so fence it defensively anyway.
+        let min = min.min(max);
+        let new = if healthy {
+            current.saturating_sub(1)
+        } else {
+            current.saturating_mul(2)
+        }
+        .clamp(min, max);
+        if new != current {
+            tracing::info!(
+                "adaptive checkpointing: checkpoint frequency adjusted from {} to {}",
+                current,
+                new
+            );
+            self.inner.checkpoint_frequency.store(new, Ordering::Relaxed);
+        }
+    }
+
     /// Update the `num_uncheckpointed_barrier`
     fn update_num_uncheckpointed_barrier(&self, checkpoint: bool) {
         if checkpoint {
@@ -77,6 +77,11 @@ impl CompactionConfigBuilder {
                 level0_sub_level_compact_level_count: DEFAULT_MIN_SUB_LEVEL_COMPACT_LEVEL_COUNT,
                 level0_overlapping_sub_level_compact_level_count:
                     DEFAULT_MIN_OVERLAPPING_SUB_LEVEL_COMPACT_LEVEL_COUNT,
+                sst_encryption_key_id: None,
+                filter_algorithm: None,
+                filter_bits_per_key: 0,
+                compaction_priority: 0,
+                max_concurrent_task_number: 0,
             },
         }
     }
@@ -142,4 +147,9 @@ builder_field! {
     level0_max_compact_file_number: u64,
     level0_sub_level_compact_level_count: u32,
     level0_overlapping_sub_level_compact_level_count: u32,
+    sst_encryption_key_id: Option<String>,
+    filter_algorithm: Option<String>,
+    filter_bits_per_key: u32,
+    compaction_priority: u32,
+    max_concurrent_task_number: u32,
 }
@@ -126,6 +126,7 @@ impl CompactStatus {
         let compression_algorithm = match ret.compression_algorithm.as_str() {
             "Lz4" => 1,
             "Zstd" => 2,
+            "ZstdDict" => 3,
             _ => 0,
         };
 
@@ -152,6 +153,14 @@ impl CompactStatus {
             task_type: ret.compaction_task_type as i32,
             split_by_state_table: group.compaction_config.split_by_state_table,
             split_weight_by_vnode: group.compaction_config.split_weight_by_vnode,
+            encryption_key_id: group.compaction_config.sst_encryption_key_id.clone(),
+            cold_storage_class: String::default(),
+            filter_algorithm: group
+                .compaction_config
+                .filter_algorithm
+                .clone()
+                .unwrap_or_default(),
+            filter_bits_per_key: group.compaction_config.filter_bits_per_key,
         };
         Some(compact_task)
     }
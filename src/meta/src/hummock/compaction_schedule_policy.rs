@@ -480,6 +480,10 @@ mod tests {
             task_type: compact_task::TaskType::Dynamic as i32,
             split_by_state_table: false,
             split_weight_by_vnode: 0,
+            encryption_key_id: None,
+            cold_storage_class: String::default(),
+            filter_algorithm: String::default(),
+            filter_bits_per_key: 0,
         }
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
@@ -421,7 +422,19 @@ where
         sched_channel: Arc<CompactionRequestChannel>,
         task_type: compact_task::TaskType,
     ) {
-        for cg_id in self.hummock_manager.compaction_group_ids().await {
+        // Enqueue higher-priority groups first, so that they are offered idle compactors
+        // first when compactor slots are scarce.
+        let group_configs = self.hummock_manager.get_compaction_group_map().await;
+        let mut cg_ids = self.hummock_manager.compaction_group_ids().await;
+        cg_ids.sort_by_key(|cg_id| {
+            Reverse(
+                group_configs
+                    .get(cg_id)
+                    .map(|group| group.compaction_config.compaction_priority)
+                    .unwrap_or(0),
+            )
+        });
+        for cg_id in cg_ids {
             if let Err(e) = sched_channel.try_sched_compaction(cg_id, task_type) {
                 tracing::warn!(
                     "Failed to schedule {:?} compaction for compaction group {}. {}",
@@ -440,6 +453,25 @@ where
         compaction_selectors: &mut HashMap<compact_task::TaskType, Box<dyn LevelSelector>>,
         sched_channel: Arc<CompactionRequestChannel>,
     ) -> bool {
+        // Enforce the group's concurrency quota, so that one backfilling group cannot
+        // monopolize all compactor slots.
+        let max_concurrent_task_number = self
+            .hummock_manager
+            .get_compaction_group_map()
+            .await
+            .get(&compaction_group)
+            .map(|group| group.compaction_config.max_concurrent_task_number)
+            .unwrap_or(0);
+        if max_concurrent_task_number > 0
+            && self
+                .hummock_manager
+                .get_group_assigned_task_num(compaction_group)
+                .await
+                >= max_concurrent_task_number as u64
+        {
+            return false;
+        }
+
         // Wait for a compactor to become available.
         let compactor = match self.hummock_manager.get_idle_compactor().await {
             Some(compactor) => compactor,
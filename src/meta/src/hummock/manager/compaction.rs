@@ -12,25 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use function_name::named;
 use itertools::Itertools;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockCompactionTaskId, HummockContextId};
-use risingwave_pb::hummock::{CompactStatus as PbCompactStatus, CompactTaskAssignment};
+use risingwave_pb::hummock::{
+    CompactStatus as PbCompactStatus, CompactTaskAssignment, CompactTaskSummary, CompactorScaleInfo,
+};
 
-use crate::hummock::compaction::CompactStatus;
+use crate::hummock::compaction::{CompactStatus, DynamicLevelSelectorCore};
 use crate::hummock::manager::read_lock;
 use crate::hummock::HummockManager;
 use crate::model::BTreeMapTransaction;
 use crate::storage::MetaStore;
 
+/// The pending compaction bytes one compactor CPU core is expected to keep up with. Exceeding
+/// it means the compactor deployment should scale out.
+const SCALE_OUT_PENDING_BYTES_PER_CORE: u64 = 4 * 1024 * 1024 * 1024;
+
 #[derive(Default)]
 pub struct Compaction {
     /// Compaction task that is already assigned to a compactor
     pub compact_task_assignment: BTreeMap<HummockCompactionTaskId, CompactTaskAssignment>,
     /// `CompactStatus` of each compaction group
     pub compaction_statuses: BTreeMap<CompactionGroupId, CompactStatus>,
+    /// Summaries of finished compaction tasks, with bounded retention controlled by
+    /// `MetaOpts::compaction_task_history_retention_sec`.
+    pub completed_task_history: BTreeMap<HummockCompactionTaskId, CompactTaskSummary>,
+    /// In-memory start times (Unix seconds) of the tasks in progress. Not persisted, so the
+    /// start times of tasks that span a meta node restart are unknown.
+    pub task_start_times: HashMap<HummockCompactionTaskId, u64>,
 
     pub deterministic_mode: bool,
 }
@@ -99,6 +111,21 @@ where
             .len() as u64
     }
 
+    #[named]
+    pub async fn get_group_assigned_task_num(&self, compaction_group: CompactionGroupId) -> u64 {
+        read_lock!(self, compaction)
+            .await
+            .compact_task_assignment
+            .values()
+            .filter(|s| {
+                s.compact_task
+                    .as_ref()
+                    .map(|t| t.compaction_group_id == compaction_group)
+                    .unwrap_or(false)
+            })
+            .count() as u64
+    }
+
     #[named]
     pub async fn get_assigned_tasks_number(&self, context_id: HummockContextId) -> u64 {
         read_lock!(self, compaction)
@@ -109,6 +136,16 @@ where
             .count() as u64
     }
 
+    #[named]
+    pub async fn list_compact_task_history(&self) -> Vec<CompactTaskSummary> {
+        read_lock!(self, compaction)
+            .await
+            .completed_task_history
+            .values()
+            .cloned()
+            .collect_vec()
+    }
+
     #[named]
     pub async fn list_all_tasks_ids(&self) -> Vec<HummockCompactionTaskId> {
         let compaction = read_lock!(self, compaction).await;
@@ -137,6 +174,45 @@ where
                 .collect(),
         )
     }
+
+    /// Computes a scale hint for the compactor deployment from the pending compaction bytes of
+    /// all compaction groups. External autoscalers can poll it via
+    /// `HummockManagerService::GetCompactorScaleInfo` instead of deriving the same numbers from
+    /// Prometheus metrics, and scale the compactor deployment towards `suggested_cpu_cores`.
+    pub async fn get_compactor_scale_info(&self) -> CompactorScaleInfo {
+        let current_version = self.get_current_version().await;
+        let id_to_config = self.get_compaction_group_map().await;
+
+        let mut pending_compaction_bytes = 0;
+        for (group_id, levels) in &current_version.levels {
+            if let Some(group) = id_to_config.get(group_id) {
+                let dynamic_level_core = DynamicLevelSelectorCore::new(group.compaction_config());
+                pending_compaction_bytes += dynamic_level_core.compact_pending_bytes_needed(levels);
+            }
+        }
+
+        // Always suggest at least one core, so that a scaled-to-zero deployment is brought back
+        // before the first compaction task of a new write workload is scheduled.
+        let suggested_cpu_cores = ((pending_compaction_bytes + SCALE_OUT_PENDING_BYTES_PER_CORE
+            - 1)
+            / SCALE_OUT_PENDING_BYTES_PER_CORE)
+            .max(1);
+
+        let info = CompactorScaleInfo {
+            node_count: self.compactor_manager.compactor_num() as u64,
+            cpu_cores: self.compactor_manager.total_cpu_core_num() as u64,
+            pending_compaction_bytes,
+            suggested_cpu_cores,
+        };
+
+        // Refresh the gauge whenever the hint is recomputed, no matter whether by the periodic
+        // report timer or by an RPC.
+        self.metrics
+            .compactor_suggested_cores
+            .set(info.suggested_cpu_cores as _);
+
+        info
+    }
 }
 
 #[cfg(test)]
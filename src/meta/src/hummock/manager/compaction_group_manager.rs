@@ -906,6 +906,32 @@ fn update_compaction_config(target: &mut CompactionConfig, items: &[MutableConfi
             MutableConfig::Level0OverlappingSubLevelCompactLevelCount(c) => {
                 target.level0_overlapping_sub_level_compact_level_count = *c;
             }
+            MutableConfig::SstEncryptionKeyId(c) => {
+                target.sst_encryption_key_id = (!c.is_empty()).then(|| c.clone());
+            }
+            MutableConfig::CompressionAlgorithmLevel(c) => {
+                let level = c.level as usize;
+                if level < target.compression_algorithm.len() {
+                    target.compression_algorithm[level] = c.compression_algorithm.clone();
+                } else {
+                    tracing::warn!(
+                        "ignore compression algorithm update for out-of-range level {}",
+                        level
+                    );
+                }
+            }
+            MutableConfig::FilterAlgorithm(c) => {
+                target.filter_algorithm = (!c.is_empty()).then(|| c.clone());
+            }
+            MutableConfig::FilterBitsPerKey(c) => {
+                target.filter_bits_per_key = *c;
+            }
+            MutableConfig::CompactionPriority(c) => {
+                target.compaction_priority = *c;
+            }
+            MutableConfig::MaxConcurrentTaskNumber(c) => {
+                target.max_concurrent_task_number = *c;
+            }
         }
     }
 }
@@ -17,7 +17,7 @@ use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, LazyLock};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use arc_swap::ArcSwap;
 use bytes::Bytes;
@@ -26,6 +26,7 @@ use function_name::named;
 use futures::future::Either;
 use futures::stream::BoxStream;
 use itertools::Itertools;
+use risingwave_common::config::RetryConfig;
 use risingwave_common::monitor::rwlock::MonitoredRwLock;
 use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_common::util::select_all;
@@ -44,7 +45,8 @@ use risingwave_pb::hummock::compact_task::{self, TaskStatus};
 use risingwave_pb::hummock::group_delta::DeltaType;
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
-    version_update_payload, CompactTask, CompactTaskAssignment, CompactionConfig, GroupDelta,
+    version_update_payload, CompactTask, CompactTaskAssignment, CompactTaskSummary,
+    CompactionConfig, GroupDelta,
     HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot, HummockVersion,
     HummockVersionCheckpoint, HummockVersionDelta, HummockVersionDeltas, HummockVersionStats,
     IntraLevelDelta, TableOption,
@@ -300,6 +302,8 @@ where
                 state_store_url.strip_prefix("hummock+").unwrap_or("memory"),
                 metrics.object_store_metric.clone(),
                 "Version Checkpoint",
+                None,
+                RetryConfig::default(),
             )
             .await,
         );
@@ -391,6 +395,11 @@ where
                 .into_iter()
                 .map(|assigned| (assigned.key().unwrap(), assigned))
                 .collect();
+        compaction_guard.completed_task_history = CompactTaskSummary::list(self.env.meta_store())
+            .await?
+            .into_iter()
+            .map(|summary| (summary.task_id, summary))
+            .collect();
 
         let hummock_version_deltas: BTreeMap<_, _> =
             HummockVersionDelta::list(self.env.meta_store())
@@ -745,6 +754,10 @@ where
         // lock in compaction_guard, take out all table_options in advance there may be a
         // waste of resources here, need to add a more efficient filter in catalog_manager
         let all_table_id_to_option = self.catalog_manager.get_all_table_options().await;
+        let all_table_cold_storage_classes = self
+            .catalog_manager
+            .get_all_table_cold_storage_classes()
+            .await;
 
         let mut compaction_guard = write_lock!(self, compaction).await;
         let compaction = compaction_guard.deref_mut();
@@ -835,6 +848,15 @@ where
             .clone();
         let is_trivial_reclaim = CompactStatus::is_trivial_reclaim(&compact_task);
         let is_trivial_move = CompactStatus::is_trivial_move_task(&compact_task);
+        // Record the start time of the task for its history entry. The entry is consumed when
+        // the task result is reported.
+        compaction.task_start_times.insert(
+            compact_task.task_id,
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        );
 
         if is_trivial_reclaim {
             compact_task.set_task_status(TaskStatus::Success);
@@ -878,6 +900,20 @@ where
             compact_task.current_epoch_time = Epoch::now().0;
             compact_task.compaction_filter_mask =
                 group_config.compaction_config.compaction_filter_mask;
+            // Output of the bottommost level holds the coldest data. Spill it to the configured
+            // storage class, but only if every table in the group agrees on one: output SSTs may
+            // mix data of all member tables.
+            if compact_task.target_level as u64 == group_config.compaction_config.max_level {
+                let mut classes = compact_task
+                    .existing_table_ids
+                    .iter()
+                    .map(|table_id| all_table_cold_storage_classes.get(table_id));
+                if let Some(Some(class)) = classes.next() {
+                    if classes.all(|c| c == Some(class)) {
+                        compact_task.cold_storage_class = class.clone();
+                    }
+                }
+            }
             commit_multi_var!(self, None, Transaction::default(), compact_status)?;
 
             // this task has been finished.
@@ -1171,9 +1207,14 @@ where
         let compaction = compaction_guard.deref_mut();
         let start_time = Instant::now();
         let original_keys = compaction.compaction_statuses.keys().cloned().collect_vec();
+        let task_start_time_sec = compaction
+            .task_start_times
+            .get(&compact_task.task_id)
+            .copied();
         let mut compact_statuses = BTreeMapTransaction::new(&mut compaction.compaction_statuses);
         let mut compact_task_assignment =
             BTreeMapTransaction::new(&mut compaction.compact_task_assignment);
+        let mut task_history = BTreeMapTransaction::new(&mut compaction.completed_task_history);
         let assignee_context_id = compact_task_assignment
             .remove(compact_task.task_id)
             .map(|assignment| assignment.context_id);
@@ -1262,6 +1303,55 @@ where
             } else {
                 false
             };
+
+            // Record a bounded-retention summary of the finished task, mainly for
+            // write-amplification trend analysis via `rw_compaction_history`.
+            let end_time_sec = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let expired_task_ids = task_history
+                .tree_ref()
+                .iter()
+                .filter(|(_, summary)| {
+                    summary.end_time_sec + self.env.opts.compaction_task_history_retention_sec
+                        < end_time_sec
+                })
+                .map(|(task_id, _)| *task_id)
+                .collect_vec();
+            for task_id in expired_task_ids {
+                task_history.remove(task_id);
+            }
+            task_history.insert(
+                compact_task.task_id,
+                CompactTaskSummary {
+                    task_id: compact_task.task_id,
+                    compaction_group_id: compact_task.compaction_group_id,
+                    task_type: compact_task.task_type,
+                    task_status: compact_task.task_status,
+                    target_level: compact_task.target_level,
+                    input_file_count: compact_task
+                        .input_ssts
+                        .iter()
+                        .map(|level| level.table_infos.len() as u64)
+                        .sum(),
+                    input_file_size: compact_task
+                        .input_ssts
+                        .iter()
+                        .flat_map(|level| level.table_infos.iter())
+                        .map(|sst| sst.file_size)
+                        .sum(),
+                    output_file_count: compact_task.sorted_output_ssts.len() as u64,
+                    output_file_size: compact_task
+                        .sorted_output_ssts
+                        .iter()
+                        .map(|sst| sst.file_size)
+                        .sum(),
+                    start_time_sec: task_start_time_sec.unwrap_or(0),
+                    end_time_sec,
+                },
+            );
+
             if is_success {
                 let mut hummock_version_deltas =
                     BTreeMapTransaction::new(&mut versioning.hummock_version_deltas);
@@ -1285,6 +1375,7 @@ where
                     Transaction::default(),
                     compact_statuses,
                     compact_task_assignment,
+                    task_history,
                     hummock_version_deltas,
                     version_stats
                 )?;
@@ -1304,10 +1395,12 @@ where
                     context_id,
                     Transaction::default(),
                     compact_statuses,
-                    compact_task_assignment
+                    compact_task_assignment,
+                    task_history
                 )?;
             }
         }
+        compaction.task_start_times.remove(&compact_task.task_id);
 
         let task_status = compact_task.task_status();
         let task_status_label = task_status.as_str_name();
@@ -1740,6 +1833,13 @@ where
         read_lock!(self, versioning).await.branched_ssts.clone()
     }
 
+    /// Gets the per-table stats of the current version.
+    /// Should not be called inside [`HummockManager`], because it requests locks internally.
+    #[named]
+    pub async fn get_version_stats(&self) -> HummockVersionStats {
+        read_lock!(self, versioning).await.version_stats.clone()
+    }
+
     /// Get version deltas from meta store
     #[cfg_attr(coverage, no_coverage)]
     pub async fn list_version_deltas(
@@ -1846,6 +1946,29 @@ where
         versioning_guard.current_version.clone()
     }
 
+    #[named]
+    pub async fn enable_commit_epoch(&self) {
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        versioning_guard.disable_commit_epochs = false;
+    }
+
+    /// Overwrites the current hummock version with a newer one read from a replicated version
+    /// checkpoint, on a read-only standby cluster. The new version is kept in memory only: a
+    /// standby meta node re-reads the replicated checkpoint on restart.
+    /// Returns the new version, or `None` if `version` is not newer than the current one.
+    #[named]
+    pub async fn apply_replicated_version(
+        &self,
+        version: HummockVersion,
+    ) -> Option<HummockVersion> {
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        if version.id <= versioning_guard.current_version.id {
+            return None;
+        }
+        versioning_guard.current_version = version.clone();
+        Some(version)
+    }
+
     /// Triggers compacitons to specified compaction groups.
     /// Don't wait for compaction finish
     pub async fn trigger_compaction_deterministic(
@@ -2107,7 +2230,10 @@ where
                 .opts
                 .periodic_split_compact_group_interval_sec;
 
-            if periodic_check_split_group_interval_sec > 0 {
+            let enable_automatic_split_compact_group =
+                hummock_manager.env.opts.enable_automatic_split_compact_group;
+
+            if enable_automatic_split_compact_group && periodic_check_split_group_interval_sec > 0 {
                 let mut split_group_trigger_interval = tokio::time::interval(Duration::from_secs(
                     periodic_check_split_group_interval_sec,
                 ));
@@ -2194,6 +2320,11 @@ where
                                             compaction_group_config.group_id(),
                                         )
                                     }
+
+                                    // Refreshes the compactor scale hint gauge as a
+                                    // side effect, so it stays fresh even if nobody
+                                    // calls the corresponding RPC.
+                                    hummock_manager.get_compactor_scale_info().await;
                                 }
 
                                 HummockTimerEvent::CompactionHeartBeat => {
@@ -21,6 +21,8 @@ pub use manager::*;
 
 mod level_handler;
 mod metrics_utils;
+mod replication;
+pub use replication::*;
 #[cfg(any(test, feature = "test"))]
 pub mod mock_hummock_meta_client;
 pub mod model;
@@ -123,6 +125,66 @@ where
     (join_handle, shutdown_tx)
 }
 
+/// Starts a task to periodically replicate newly committed SSTs and meta backups to the
+/// secondary object store, for disaster recovery.
+pub fn start_replication_scheduler<S: MetaStore>(
+    replication_manager: ReplicationManagerRef<S>,
+    interval: Duration,
+) -> (JoinHandle<()>, Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_trigger_interval.tick() => {},
+                // Shutdown replication
+                _ = &mut shutdown_rx => {
+                    tracing::info!("DR replication is stopped");
+                    return;
+                }
+            }
+            if let Err(err) = replication_manager.replicate().await {
+                tracing::warn!("DR replication error {:#?}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
+}
+
+/// Starts a task to periodically apply the replicated hummock version checkpoint on a
+/// read-only standby cluster. The task stops once the cluster is promoted to primary.
+pub fn start_standby_apply_scheduler<S: MetaStore>(
+    standby_manager: StandbyManagerRef<S>,
+    interval: Duration,
+) -> (JoinHandle<()>, Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_trigger_interval = tokio::time::interval(interval);
+        min_trigger_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_trigger_interval.tick() => {},
+                // Shutdown standby apply
+                _ = &mut shutdown_rx => {
+                    tracing::info!("DR standby apply is stopped");
+                    return;
+                }
+            }
+            if standby_manager.is_promoted() {
+                tracing::info!("DR standby apply is stopped after promotion");
+                return;
+            }
+            if let Err(err) = standby_manager.apply_checkpoint().await {
+                tracing::warn!("DR standby apply error {:#?}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
+}
+
 pub fn start_checkpoint_loop<S: MetaStore>(
     hummock_manager: HummockManagerRef<S>,
     interval: Duration,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod compact_task_assignment;
+mod compact_task_summary;
 mod compaction_group_config;
 mod compaction_status;
 mod pinned_snapshot;
@@ -38,3 +39,4 @@ const HUMMOCK_COMPACTION_STATUS_CF_NAME: &str = "cf/hummock_4";
 const HUMMOCK_COMPACT_TASK_ASSIGNMENT: &str = "cf/hummock_5";
 const HUMMOCK_COMPACTION_GROUP_CONFIG_CF_NAME: &str = "cf/hummock_6";
 const HUMMOCK_VERSION_STATS_CF_NAME: &str = "cf/hummock_7";
+const HUMMOCK_COMPACT_TASK_HISTORY_CF_NAME: &str = "cf/hummock_8";
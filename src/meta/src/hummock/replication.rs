@@ -0,0 +1,418 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::config::RetryConfig;
+use risingwave_common::util::epoch::Epoch;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::{
+    summarize_group_deltas, HummockVersionExt,
+};
+use risingwave_hummock_sdk::{
+    version_checkpoint_dir, version_checkpoint_path, HummockEpoch, HummockSstableObjectId,
+    OBJECT_SUFFIX,
+};
+use risingwave_object_store::object::{parse_remote_object_store, ObjectStoreRef};
+use risingwave_pb::hummock::GetDrStatusResponse;
+
+use crate::backup_restore::BackupManagerRef;
+use crate::hummock::error::Error;
+use crate::hummock::HummockManagerRef;
+use crate::manager::MetaSrvEnv;
+use crate::rpc::metrics::MetaMetrics;
+use crate::storage::MetaStore;
+use crate::MetaResult;
+
+pub type ReplicationManagerRef<S> = Arc<ReplicationManager<S>>;
+
+#[derive(Default, Clone)]
+struct ReplicationState {
+    /// The id of the last hummock version whose SST objects have been replicated.
+    /// 0 means the initial full replication has not been done yet.
+    last_version_id: u64,
+    /// All SST objects of epochs not greater than this watermark are in the secondary
+    /// object store. This bounds the RPO for regional failure.
+    replicated_epoch: HummockEpoch,
+    /// Total number of SST objects replicated since this meta node started.
+    replicated_object_count: u64,
+    /// The id of the latest meta snapshot that has been replicated.
+    replicated_meta_snapshot_id: u64,
+}
+
+/// Asynchronously mirrors newly committed SST objects and meta backups to a secondary
+/// object store in another bucket/region, for disaster recovery.
+///
+/// The replication is driven by hummock version deltas: on the first run the SST objects
+/// of the current version are copied as a baseline, and afterwards only the objects newly
+/// added by version deltas (barrier commits and compactions) are copied. Meta backups are
+/// mirrored from the backup storage manifest. The watermark of replicated epochs is
+/// exposed via `risectl meta dr-status`.
+pub struct ReplicationManager<S: MetaStore> {
+    hummock_manager: HummockManagerRef<S>,
+    backup_manager: BackupManagerRef<S>,
+    /// The primary object store holding SST data.
+    data_store: ObjectStoreRef,
+    /// The primary object store holding meta backups.
+    backup_store: ObjectStoreRef,
+    /// The secondary object store that SSTs and backups are mirrored to, using the same
+    /// object paths as in the primary stores.
+    secondary_store: ObjectStoreRef,
+    secondary_location: String,
+    data_directory: String,
+    backup_directory: String,
+    version_checkpoint_path: String,
+    state: parking_lot::RwLock<ReplicationState>,
+    metrics: Arc<MetaMetrics>,
+}
+
+impl<S> ReplicationManager<S>
+where
+    S: MetaStore,
+{
+    pub async fn new(
+        env: MetaSrvEnv<S>,
+        hummock_manager: HummockManagerRef<S>,
+        backup_manager: BackupManagerRef<S>,
+        secondary_location: String,
+        metrics: Arc<MetaMetrics>,
+    ) -> MetaResult<ReplicationManagerRef<S>> {
+        let object_store_metric = metrics.object_store_metric.clone();
+        let sys_params = env.system_params_manager().get_params().await;
+        let state_store_url = sys_params.state_store();
+        let data_store = Arc::new(
+            parse_remote_object_store(
+                state_store_url.strip_prefix("hummock+").unwrap_or("memory"),
+                object_store_metric.clone(),
+                "DR Primary",
+                None,
+                RetryConfig::default(),
+            )
+            .await,
+        );
+        let backup_store = Arc::new(
+            parse_remote_object_store(
+                sys_params.backup_storage_url(),
+                object_store_metric.clone(),
+                "DR Backup",
+                None,
+                RetryConfig::default(),
+            )
+            .await,
+        );
+        let secondary_store = Arc::new(
+            parse_remote_object_store(
+                &secondary_location,
+                object_store_metric,
+                "DR Secondary",
+                None,
+                RetryConfig::default(),
+            )
+            .await,
+        );
+        let data_directory = sys_params.data_directory().to_string();
+        Ok(Arc::new(Self {
+            hummock_manager,
+            backup_manager,
+            data_store,
+            backup_store,
+            secondary_store,
+            secondary_location,
+            version_checkpoint_path: version_checkpoint_path(&data_directory),
+            data_directory,
+            backup_directory: sys_params.backup_storage_directory().to_string(),
+            state: parking_lot::RwLock::new(ReplicationState::default()),
+            metrics,
+        }))
+    }
+
+    /// Replicates SST objects, the version checkpoint and meta backups that are not yet in
+    /// the secondary object store, then advances the watermark of replicated epochs.
+    pub async fn replicate(&self) -> MetaResult<()> {
+        let result = self.replicate_inner().await;
+        if result.is_err() {
+            self.metrics.dr_replication_failure_count.inc();
+        }
+        self.refresh_lag_metrics().await;
+        result
+    }
+
+    async fn replicate_inner(&self) -> MetaResult<()> {
+        // Snapshot the version checkpoint before listing version deltas: the snapshot then
+        // only refers to SST objects covered by this round of SST replication, so a standby
+        // cluster reading it from the secondary store never sees dangling objects.
+        let version_checkpoint = self.read_version_checkpoint().await?;
+        self.replicate_ssts().await?;
+        if let Some(data) = version_checkpoint {
+            self.secondary_store
+                .upload(&self.version_checkpoint_path, data)
+                .await
+                .map_err(Error::ObjectStore)?;
+        }
+        self.replicate_backups().await?;
+        Ok(())
+    }
+
+    /// Reads the raw version checkpoint from the primary object store, or `None` if it has
+    /// not been created yet.
+    async fn read_version_checkpoint(&self) -> MetaResult<Option<bytes::Bytes>> {
+        // `list` then `read`, because from `read`'s error we cannot tell whether it's
+        // "object not found" or another kind of error.
+        let metadata = self
+            .data_store
+            .list(&version_checkpoint_dir(&self.version_checkpoint_path))
+            .await
+            .map_err(Error::ObjectStore)?
+            .into_iter()
+            .filter(|o| o.key == self.version_checkpoint_path)
+            .collect_vec();
+        if metadata.is_empty() {
+            return Ok(None);
+        }
+        let data = self
+            .data_store
+            .read(&self.version_checkpoint_path, None)
+            .await
+            .map_err(Error::ObjectStore)?;
+        Ok(Some(data))
+    }
+
+    async fn replicate_ssts(&self) -> MetaResult<()> {
+        let last_version_id = self.state.read().last_version_id;
+        let mut incremental = None;
+        if last_version_id != 0 {
+            // Replicate only the objects newly added by version deltas since the last run.
+            let deltas = self
+                .hummock_manager
+                .list_version_deltas(last_version_id + 1, u32::MAX, HummockEpoch::MAX)
+                .await?
+                .version_deltas;
+            // Vacuum deletes version deltas once they are covered by a version checkpoint, so
+            // the listed range may no longer start at the cursor. The objects added by the
+            // pruned deltas are then unknown; fall back to a full baseline sync instead of
+            // silently skipping them.
+            let pruned = match deltas.first() {
+                Some(first) => first.prev_id != last_version_id,
+                None => self.hummock_manager.get_current_version().await.id != last_version_id,
+            };
+            if pruned {
+                tracing::warn!(
+                    "version deltas after replicated version {} have been pruned, \
+                     falling back to a full baseline sync",
+                    last_version_id
+                );
+            } else {
+                let mut object_ids = HashSet::new();
+                let mut target_version_id = last_version_id;
+                let mut target_epoch = self.state.read().replicated_epoch;
+                for delta in deltas {
+                    for group_deltas in delta.group_deltas.values() {
+                        let summary = summarize_group_deltas(group_deltas);
+                        object_ids.extend(
+                            summary
+                                .insert_table_infos
+                                .iter()
+                                .map(|sst| sst.get_object_id()),
+                        );
+                    }
+                    target_version_id = delta.id;
+                    target_epoch = delta.max_committed_epoch;
+                }
+                incremental = Some((
+                    object_ids.into_iter().collect(),
+                    target_version_id,
+                    target_epoch,
+                ));
+            }
+        }
+        let (object_ids, target_version_id, target_epoch) = match incremental {
+            Some(incremental) => incremental,
+            None => {
+                // First run (or the delta trail is broken): replicate all objects of the
+                // current version as the baseline.
+                let version = self.hummock_manager.get_current_version().await;
+                (
+                    version.get_object_ids(),
+                    version.id,
+                    version.max_committed_epoch,
+                )
+            }
+        };
+
+        let object_count = object_ids.len() as u64;
+        for object_id in object_ids {
+            self.copy_object(&self.data_store, &self.get_sst_data_path(object_id))
+                .await?;
+        }
+
+        // The watermark is advanced only after all objects are in the secondary store.
+        {
+            let mut state = self.state.write();
+            state.last_version_id = target_version_id;
+            state.replicated_epoch = target_epoch;
+            state.replicated_object_count += object_count;
+        }
+        self.metrics.dr_replicated_object_count.inc_by(object_count);
+        Ok(())
+    }
+
+    /// Refreshes the replication lag gauge: the physical time between the latest committed
+    /// epoch and the epoch watermark covered by the secondary object store.
+    async fn refresh_lag_metrics(&self) {
+        let committed_epoch = self
+            .hummock_manager
+            .get_current_version()
+            .await
+            .max_committed_epoch;
+        let replicated_epoch = self.state.read().replicated_epoch;
+        let lag_ms = Epoch::from(committed_epoch)
+            .physical_time()
+            .saturating_sub(Epoch::from(replicated_epoch).physical_time());
+        self.metrics.dr_replication_lag_ms.set(lag_ms as i64);
+    }
+
+    async fn replicate_backups(&self) -> MetaResult<()> {
+        let manifest = self.backup_manager.manifest();
+        let replicated_id = self.state.read().replicated_meta_snapshot_id;
+        let mut new_snapshot_ids = manifest
+            .snapshot_metadata
+            .iter()
+            .map(|snapshot| snapshot.id)
+            .filter(|id| *id > replicated_id)
+            .collect::<Vec<_>>();
+        new_snapshot_ids.sort_unstable();
+        if new_snapshot_ids.is_empty() {
+            return Ok(());
+        }
+        for id in &new_snapshot_ids {
+            self.copy_object(
+                &self.backup_store,
+                &format!("{}/{}.snapshot", self.backup_directory, id),
+            )
+            .await?;
+        }
+        // Mirror the manifest after all the snapshots it mentions, so that the secondary
+        // manifest never refers to a missing snapshot.
+        self.copy_object(
+            &self.backup_store,
+            &format!("{}/manifest.json", self.backup_directory),
+        )
+        .await?;
+        self.state.write().replicated_meta_snapshot_id = *new_snapshot_ids.last().unwrap();
+        Ok(())
+    }
+
+    async fn copy_object(&self, from: &ObjectStoreRef, path: &str) -> MetaResult<()> {
+        let data = from.read(path, None).await.map_err(Error::ObjectStore)?;
+        self.secondary_store
+            .upload(path, data)
+            .await
+            .map_err(Error::ObjectStore)?;
+        Ok(())
+    }
+
+    fn get_sst_data_path(&self, object_id: HummockSstableObjectId) -> String {
+        let obj_prefix = self.data_store.get_object_prefix(object_id);
+        format!(
+            "{}/{}{}.{}",
+            self.data_directory, obj_prefix, object_id, OBJECT_SUFFIX
+        )
+    }
+
+    /// Gets the current disaster recovery status, for `risectl meta dr-status`.
+    pub async fn get_dr_status(&self) -> GetDrStatusResponse {
+        let committed_epoch = self
+            .hummock_manager
+            .get_current_version()
+            .await
+            .max_committed_epoch;
+        let state = self.state.read();
+        GetDrStatusResponse {
+            enabled: true,
+            secondary_location: self.secondary_location.clone(),
+            replicated_epoch: state.replicated_epoch,
+            committed_epoch,
+            replicated_object_count: state.replicated_object_count,
+            replicated_meta_snapshot_id: state.replicated_meta_snapshot_id,
+        }
+    }
+}
+
+pub type StandbyManagerRef<S> = Arc<StandbyManager<S>>;
+
+/// Drives a read-only standby cluster fed from a replicated object store.
+///
+/// A standby cluster points its state store at the secondary location that a primary cluster
+/// replicates to, with `dr_standby` set in the meta config. Its meta node disables epoch
+/// commits, so the cluster serves reads only, and this manager continuously applies the
+/// replicated hummock version checkpoint to follow the primary. On promotion the applied
+/// version is checkpointed and epoch commits are enabled again, turning the cluster into a
+/// primary.
+pub struct StandbyManager<S: MetaStore> {
+    hummock_manager: HummockManagerRef<S>,
+    promoted: AtomicBool,
+}
+
+impl<S> StandbyManager<S>
+where
+    S: MetaStore,
+{
+    pub fn new(hummock_manager: HummockManagerRef<S>) -> StandbyManagerRef<S> {
+        Arc::new(Self {
+            hummock_manager,
+            promoted: AtomicBool::new(false),
+        })
+    }
+
+    /// Applies the latest replicated version checkpoint, if it is newer than the current
+    /// version.
+    pub async fn apply_checkpoint(&self) -> MetaResult<()> {
+        if self.is_promoted() {
+            return Ok(());
+        }
+        let Some(checkpoint) = self.hummock_manager.read_checkpoint().await? else {
+            return Ok(());
+        };
+        let Some(version) = checkpoint.version else {
+            return Ok(());
+        };
+        if let Some(applied) = self.hummock_manager.apply_replicated_version(version).await {
+            tracing::info!(
+                "standby applied replicated hummock version {}, committed epoch {}",
+                applied.id,
+                applied.max_committed_epoch
+            );
+        }
+        Ok(())
+    }
+
+    /// Promotes the standby cluster to primary: stops applying replicated versions,
+    /// checkpoints the version applied so far and enables epoch commits again.
+    /// Returns the id of the hummock version the cluster is promoted at.
+    pub async fn promote(&self) -> MetaResult<u64> {
+        self.promoted.store(true, Ordering::Relaxed);
+        // Persist the applied version so that it survives a meta node restart.
+        self.hummock_manager.create_version_checkpoint(0).await?;
+        self.hummock_manager.enable_commit_epoch().await;
+        let version_id = self.hummock_manager.get_current_version().await.id;
+        tracing::info!("standby promoted to primary at hummock version {version_id}");
+        Ok(version_id)
+    }
+
+    pub fn is_promoted(&self) -> bool {
+        self.promoted.load(Ordering::Relaxed)
+    }
+}
@@ -108,6 +108,16 @@ pub struct MetaNodeOpts {
     #[clap(long, env = "RW_PROMETHEUS_ENDPOINT")]
     prometheus_endpoint: Option<String>,
 
+    /// Bearer token that authorizes all meta admin RPCs (e.g. unregistering workers or
+    /// triggering a full GC via risectl). Authentication of admin RPCs is disabled when neither
+    /// this nor `--readonly-token` is set.
+    #[clap(long, env = "RW_META_ADMIN_TOKEN")]
+    admin_token: Option<String>,
+
+    /// Bearer token that authorizes read-only meta admin RPCs.
+    #[clap(long, env = "RW_META_READONLY_TOKEN")]
+    readonly_token: Option<String>,
+
     /// Endpoint of the connector node, there will be a sidecar connector node
     /// colocated with Meta node in the cloud environment
     #[clap(long, env = "RW_CONNECTOR_RPC_ENDPOINT")]
@@ -247,11 +257,17 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
             config.meta.meta_leader_lease_secs,
             MetaOpts {
                 enable_recovery: !config.meta.disable_recovery,
+                quarantine_failure_threshold: config.meta.quarantine_failure_threshold,
+                auto_rebalance_interval_sec: config.meta.auto_rebalance_interval_sec,
+                auto_rebalance_max_moves: config.meta.auto_rebalance_max_moves,
                 in_flight_barrier_nums,
                 max_idle_ms,
                 compaction_deterministic_test: config.meta.enable_compaction_deterministic,
                 default_parallelism: config.meta.default_parallelism,
                 vacuum_interval_sec: config.meta.vacuum_interval_sec,
+                dr_secondary_object_store: config.meta.dr_secondary_object_store,
+                dr_replication_interval_sec: config.meta.dr_replication_interval_sec,
+                dr_standby: config.meta.dr_standby,
                 hummock_version_checkpoint_interval_sec: config
                     .meta
                     .hummock_version_checkpoint_interval_sec,
@@ -264,8 +280,13 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                     .collect_gc_watermark_spin_interval_sec,
                 enable_committed_sst_sanity_check: config.meta.enable_committed_sst_sanity_check,
                 periodic_compaction_interval_sec: config.meta.periodic_compaction_interval_sec,
+                compaction_task_history_retention_sec: config
+                    .meta
+                    .compaction_task_history_retention_sec,
                 node_num_monitor_interval_sec: config.meta.node_num_monitor_interval_sec,
                 prometheus_endpoint: opts.prometheus_endpoint,
+                admin_token: opts.admin_token,
+                readonly_token: opts.readonly_token,
                 vpc_id: opts.vpc_id,
                 security_group_id: opts.security_group_id,
                 connector_rpc_endpoint: opts.connector_rpc_endpoint,
@@ -280,6 +301,9 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 periodic_split_compact_group_interval_sec: config
                     .meta
                     .periodic_split_compact_group_interval_sec,
+                enable_automatic_split_compact_group: config
+                    .meta
+                    .enable_automatic_split_compact_group,
                 max_compactor_task_multiplier: config.meta.max_compactor_task_multiplier,
                 split_group_size_limit: config.meta.split_group_size_limit,
                 min_table_split_size: config.meta.move_table_size_limit,
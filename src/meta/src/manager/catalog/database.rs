@@ -17,11 +17,14 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableOption;
+use risingwave_common::constants::hummock;
 use risingwave_pb::catalog::{
-    Connection, Database, Function, Index, Schema, Sink, Source, Table, View,
+    Connection, Database, Function, Index, Schema, Secret, Sink, Source, Table, View,
 };
 
-use super::{ConnectionId, DatabaseId, FunctionId, RelationId, SchemaId, SinkId, SourceId, ViewId};
+use super::{
+    ConnectionId, DatabaseId, FunctionId, RelationId, SchemaId, SecretId, SinkId, SourceId, ViewId,
+};
 use crate::manager::{IndexId, MetaSrvEnv, TableId};
 use crate::model::MetadataModel;
 use crate::storage::MetaStore;
@@ -37,6 +40,7 @@ pub type Catalog = (
     Vec<View>,
     Vec<Function>,
     Vec<Connection>,
+    Vec<Secret>,
 );
 
 type DatabaseKey = String;
@@ -64,6 +68,8 @@ pub struct DatabaseManager {
     pub(super) functions: BTreeMap<FunctionId, Function>,
     /// Cached connection information.
     pub(super) connections: BTreeMap<ConnectionId, Connection>,
+    /// Cached secret information.
+    pub(super) secrets: BTreeMap<SecretId, Secret>,
 
     /// Relation reference count mapping.
     // TODO(zehua): avoid key conflicts after distinguishing table's and source's id generator.
@@ -88,6 +94,7 @@ impl DatabaseManager {
         let views = View::list(env.meta_store()).await?;
         let functions = Function::list(env.meta_store()).await?;
         let connections = Connection::list(env.meta_store()).await?;
+        let secrets = Secret::list(env.meta_store()).await?;
 
         let mut relation_ref_count = HashMap::new();
 
@@ -125,6 +132,7 @@ impl DatabaseManager {
         }));
         let functions = BTreeMap::from_iter(functions.into_iter().map(|f| (f.id, f)));
         let connections = BTreeMap::from_iter(connections.into_iter().map(|c| (c.id, c)));
+        let secrets = BTreeMap::from_iter(secrets.into_iter().map(|s| (s.id, s)));
 
         Ok(Self {
             databases,
@@ -136,6 +144,7 @@ impl DatabaseManager {
             indexes,
             functions,
             connections,
+            secrets,
             relation_ref_count,
             in_progress_creation_tracker: HashSet::default(),
             in_progress_creation_streaming_job: HashMap::default(),
@@ -154,6 +163,7 @@ impl DatabaseManager {
             self.views.values().cloned().collect_vec(),
             self.functions.values().cloned().collect_vec(),
             self.connections.values().cloned().collect_vec(),
+            self.secrets.values().cloned().collect_vec(),
         )
     }
 
@@ -218,10 +228,26 @@ impl DatabaseManager {
         }
     }
 
+    pub fn check_secret_name_duplicated(&self, relation_key: &RelationKey) -> MetaResult<()> {
+        if self.secrets.values().any(|secret| {
+            secret.database_id == relation_key.0
+                && secret.schema_id == relation_key.1
+                && secret.name.eq(&relation_key.2)
+        }) {
+            Err(MetaError::catalog_duplicated("secret", &relation_key.2))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn list_databases(&self) -> Vec<Database> {
         self.databases.values().cloned().collect_vec()
     }
 
+    pub fn list_secrets(&self) -> Vec<Secret> {
+        self.secrets.values().cloned().collect_vec()
+    }
+
     pub fn list_creating_tables(&self) -> Vec<Table> {
         self.in_progress_creating_tables
             .values()
@@ -244,6 +270,19 @@ impl DatabaseManager {
             .collect()
     }
 
+    pub fn get_all_table_cold_storage_classes(&self) -> HashMap<TableId, String> {
+        self.tables
+            .iter()
+            .filter_map(|(id, table)| {
+                table
+                    .properties
+                    .get(hummock::PROPERTIES_COLD_STORAGE_CLASS_KEY)
+                    .filter(|class| !class.is_empty())
+                    .map(|class| (*id, class.clone()))
+            })
+            .collect()
+    }
+
     pub fn list_table_ids(&self, schema_id: SchemaId) -> Vec<TableId> {
         self.tables
             .values()
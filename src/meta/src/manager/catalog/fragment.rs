@@ -534,6 +534,34 @@ where
         }
     }
 
+    /// Used in [`crate::barrier::GlobalBarrierManager`], get a mapping from actor id to the
+    /// fragment it belongs to, over all table fragments.
+    pub async fn get_actor_to_fragment_mapping(&self) -> HashMap<ActorId, FragmentId> {
+        let map = &self.core.read().await.table_fragments;
+        let mut mapping = HashMap::new();
+        for fragments in map.values() {
+            for fragment in fragments.fragments() {
+                for actor in &fragment.actors {
+                    mapping.insert(actor.actor_id, fragment.fragment_id);
+                }
+            }
+        }
+        mapping
+    }
+
+    /// Used in [`crate::barrier::GlobalBarrierManager`], get a mapping from actor id to the
+    /// streaming job it belongs to, over all table fragments.
+    pub async fn get_actor_to_table_mapping(&self) -> HashMap<ActorId, TableId> {
+        let map = &self.core.read().await.table_fragments;
+        let mut mapping = HashMap::new();
+        for fragments in map.values() {
+            for actor_id in fragments.actor_ids() {
+                mapping.insert(actor_id, fragments.table_id());
+            }
+        }
+        mapping
+    }
+
     /// Used in [`crate::barrier::GlobalBarrierManager`]
     /// migrate actors and update fragments one by one according to the migration plan.
     pub async fn migrate_fragment_actors(&self, migration_plan: &MigrationPlan) -> MetaResult<()> {
@@ -32,9 +32,10 @@ use risingwave_common::catalog::{
     DEFAULT_SUPER_USER_FOR_PG_ID, DEFAULT_SUPER_USER_ID, SYSTEM_SCHEMAS,
 };
 use risingwave_common::{bail, ensure};
+use risingwave_connector::secret::secret_ref_property;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    Connection, Database, Function, Index, Schema, Sink, Source, Table, View,
+    Connection, Database, Function, Index, Schema, Secret, Sink, Source, Table, View,
 };
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, Object};
@@ -61,6 +62,7 @@ pub type FunctionId = u32;
 
 pub type UserId = u32;
 pub type ConnectionId = u32;
+pub type SecretId = u32;
 
 /// `commit_meta` provides a wrapper for committing metadata changes to both in-memory and
 /// meta store.
@@ -270,6 +272,7 @@ where
         let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
         let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
         let mut connections = BTreeMapTransaction::new(&mut database_core.connections);
+        let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
 
         /// `drop_by_database_id` provides a wrapper for dropping relations by database id, it will
         /// return the relation ids that dropped.
@@ -301,6 +304,7 @@ where
             let views_to_drop = drop_by_database_id!(views, database_id);
             let functions_to_drop = drop_by_database_id!(functions, database_id);
             let connections_to_drop = drop_by_database_id!(connections, database_id);
+            let secrets_to_drop = drop_by_database_id!(secrets, database_id);
             connections_dropped = connections_to_drop.clone();
 
             let objects = std::iter::once(Object::DatabaseId(database_id))
@@ -335,7 +339,8 @@ where
                 views,
                 users,
                 connections,
-                functions
+                functions,
+                secrets
             )?;
 
             std::iter::once(database.owner)
@@ -356,6 +361,7 @@ where
                         .iter()
                         .map(|connection| connection.owner),
                 )
+                .chain(secrets_to_drop.iter().map(|secret| secret.owner))
                 .for_each(|owner_id| user_core.decrease_ref(owner_id));
 
             // Update relation ref count.
@@ -377,6 +383,14 @@ where
                     .await;
             }
 
+            // Compute nodes only subscribe to secrets, so evict the dropped ones explicitly.
+            for secret in secrets_to_drop {
+                self.env
+                    .notification_manager()
+                    .notify_compute(Operation::Delete, Info::Secret(secret))
+                    .await;
+            }
+
             // Frontend will drop cache of schema and table in the database.
             let version = self
                 .notify_frontend(Operation::Delete, Info::Database(database))
@@ -613,6 +627,78 @@ where
         }
     }
 
+    /// Replaces the definition of an existing view, or creates the view if it does not exist
+    /// yet, i.e. `CREATE OR REPLACE VIEW`. A replaced view keeps its original id, so dependent
+    /// relations and granted privileges remain valid.
+    pub async fn replace_view(&self, view: &View) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        database_core.ensure_database_id(view.database_id)?;
+        database_core.ensure_schema_id(view.schema_id)?;
+        for dependent_id in &view.dependent_relations {
+            // TODO(zehua): refactor when using SourceId.
+            database_core.ensure_table_view_or_source_id(dependent_id)?;
+        }
+        #[cfg(not(test))]
+        user_core.ensure_user_id(view.owner)?;
+
+        let old_view = database_core
+            .views
+            .values()
+            .find(|v| {
+                v.database_id == view.database_id
+                    && v.schema_id == view.schema_id
+                    && v.name == view.name
+            })
+            .cloned();
+        let mut view = view.clone();
+        let operation = match &old_view {
+            Some(old_view) => {
+                // Replacing the columns of a view would invalidate the plans of the relations
+                // built on top of it, so it is only allowed for views without dependents.
+                if database_core.relation_ref_count.contains_key(&old_view.id)
+                    && old_view.columns != view.columns
+                {
+                    return Err(MetaError::permission_denied(format!(
+                        "Fail to replace view `{}` because other relation(s) depend on it and \
+                         the new definition changes its columns",
+                        old_view.name
+                    )));
+                }
+                // Keep the original id so that dependent relations and privileges remain valid.
+                view.id = old_view.id;
+                Operation::Update
+            }
+            None => {
+                let key = (view.database_id, view.schema_id, view.name.clone());
+                database_core.check_relation_name_duplicated(&key)?;
+                Operation::Add
+            }
+        };
+
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
+        views.insert(view.id, view.clone());
+        commit_meta!(self, views)?;
+
+        user_core.increase_ref(view.owner);
+        for &dependent_relation_id in &view.dependent_relations {
+            database_core.increase_ref_count(dependent_relation_id);
+        }
+        if let Some(old_view) = old_view {
+            user_core.decrease_ref(old_view.owner);
+            for &dependent_relation_id in &old_view.dependent_relations {
+                database_core.decrease_ref_count(dependent_relation_id);
+            }
+        }
+
+        let version = self
+            .notify_frontend_relation_info(operation, RelationInfo::View(view))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn create_function(&self, function: &Function) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -667,6 +753,193 @@ where
         Ok(version)
     }
 
+    /// Replaces the definition of an existing function with the same name and argument types,
+    /// or creates the function if it does not exist yet, i.e. `CREATE OR REPLACE FUNCTION`. A
+    /// replaced function keeps its original id, so granted privileges remain valid.
+    pub async fn replace_function(&self, function: &Function) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        database_core.ensure_database_id(function.database_id)?;
+        database_core.ensure_schema_id(function.schema_id)?;
+
+        #[cfg(not(test))]
+        user_core.ensure_user_id(function.owner)?;
+
+        let old_function = database_core
+            .functions
+            .values()
+            .find(|f| {
+                f.database_id == function.database_id
+                    && f.schema_id == function.schema_id
+                    && f.name == function.name
+                    && f.arg_types == function.arg_types
+            })
+            .cloned();
+        let mut function = function.clone();
+        let operation = match &old_function {
+            Some(old_function) => {
+                // Replacing the return type would invalidate the plans of the relations that
+                // reference the function.
+                if old_function.return_type != function.return_type
+                    || old_function.kind != function.kind
+                {
+                    return Err(MetaError::permission_denied(format!(
+                        "Fail to replace function `{}` because the new definition changes its \
+                         return type",
+                        old_function.name
+                    )));
+                }
+                // Keep the original id so that references and privileges remain valid.
+                function.id = old_function.id;
+                Operation::Update
+            }
+            None => Operation::Add,
+        };
+
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        functions.insert(function.id, function.clone());
+        commit_meta!(self, functions)?;
+
+        user_core.increase_ref(function.owner);
+        if let Some(old_function) = old_function {
+            user_core.decrease_ref(old_function.owner);
+        }
+
+        let version = self
+            .notify_frontend(operation, Info::Function(function))
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn create_secret(&self, secret: &Secret) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        database_core.ensure_database_id(secret.database_id)?;
+        database_core.ensure_schema_id(secret.schema_id)?;
+        database_core.check_secret_name_duplicated(&(
+            secret.database_id,
+            secret.schema_id,
+            secret.name.clone(),
+        ))?;
+
+        #[cfg(not(test))]
+        user_core.ensure_user_id(secret.owner)?;
+
+        let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
+        secrets.insert(secret.id, secret.clone());
+        commit_meta!(self, secrets)?;
+
+        user_core.increase_ref(secret.owner);
+
+        // Compute nodes resolve secret references when building connectors, so they are notified
+        // in addition to the frontends. Frontends only need the catalog entry for name resolution
+        // and never see the value.
+        self.env
+            .notification_manager()
+            .notify_compute(Operation::Add, Info::Secret(secret.to_owned()))
+            .await;
+        let version = self
+            .notify_frontend(
+                Operation::Add,
+                Info::Secret(Secret {
+                    value: vec![],
+                    ..secret.to_owned()
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Replaces the value (and backend) of an existing secret, so that credentials can be rotated
+    /// without recreating the sources and sinks that reference the secret. Compute nodes receive
+    /// the new value through the notification manager and use it the next time a connector is
+    /// built; the streaming jobs themselves keep running.
+    pub async fn alter_secret(&self, secret: &Secret) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
+
+        let original_secret = secrets
+            .get(&secret.id)
+            .cloned()
+            .ok_or_else(|| anyhow!("secret not found"))?;
+        let secret = Secret {
+            backend: secret.backend.clone(),
+            value: secret.value.clone(),
+            ..original_secret
+        };
+        secrets.insert(secret.id, secret.clone());
+        commit_meta!(self, secrets)?;
+
+        self.env
+            .notification_manager()
+            .notify_compute(Operation::Update, Info::Secret(secret.clone()))
+            .await;
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::Secret(Secret {
+                    value: vec![],
+                    ..secret
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn drop_secret(&self, secret_id: SecretId) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+
+        // A secret must not be dropped while the properties of an existing relation still
+        // reference it, otherwise the connectors of that relation could no longer be built
+        // after a recovery.
+        let secret_ref = secret_ref_property(secret_id);
+        let referencing_relation = (database_core.sources.values())
+            .map(|source| (&source.name, &source.properties))
+            .chain((database_core.sinks.values()).map(|sink| (&sink.name, &sink.properties)))
+            .chain((database_core.tables.values()).map(|table| (&table.name, &table.properties)))
+            .find(|(_, properties)| properties.values().any(|value| value == &secret_ref));
+        if let Some((relation_name, _)) = referencing_relation {
+            return Err(MetaError::permission_denied(format!(
+                "Fail to delete secret because relation `{}` depends on it",
+                relation_name
+            )));
+        }
+
+        let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
+
+        let secret = secrets
+            .remove(secret_id)
+            .ok_or_else(|| anyhow!("secret not found"))?;
+
+        commit_meta!(self, secrets)?;
+
+        user_core.decrease_ref(secret.owner);
+
+        self.env
+            .notification_manager()
+            .notify_compute(Operation::Delete, Info::Secret(secret.clone()))
+            .await;
+        let version = self
+            .notify_frontend(
+                Operation::Delete,
+                Info::Secret(Secret {
+                    value: vec![],
+                    ..secret
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn start_create_stream_job_procedure(
         &self,
         stream_job: &StreamingJob,
@@ -1141,6 +1414,104 @@ where
 
     // TODO: refactor dependency cache in catalog manager for better performance.
     #[allow(clippy::too_many_arguments)]
+    pub async fn alter_table_owner(
+        &self,
+        table_id: TableId,
+        owner_id: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        database_core.ensure_table_id(table_id)?;
+        user_core.ensure_user_id(owner_id)?;
+
+        // 1. transfer the ownership of the table and its associated source, if any.
+        let mut table = database_core.tables.get(&table_id).unwrap().clone();
+        let old_owner = table.owner;
+        table.owner = owner_id;
+
+        let source = table.optional_associated_source_id.as_ref().map(
+            |OptionalAssociatedSourceId::AssociatedSourceId(id)| {
+                let mut source = database_core.sources.get(id).unwrap().clone();
+                source.owner = owner_id;
+                source
+            },
+        );
+
+        // 2. commit meta.
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        tables.insert(table.id, table.clone());
+        if let Some(source) = &source {
+            sources.insert(source.id, source.clone());
+        }
+        commit_meta!(self, tables, sources)?;
+
+        // 3. move the ownership refs from the old owner to the new one.
+        let ref_count = if source.is_some() { 2 } else { 1 };
+        user_core.increase_ref_count(owner_id, ref_count);
+        user_core.decrease_ref_count(old_owner, ref_count);
+
+        // 4. notify frontend.
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::RelationGroup(RelationGroup {
+                    relations: vec![Relation {
+                        relation_info: RelationInfo::Table(table).into(),
+                    }]
+                    .into_iter()
+                    .chain(source.into_iter().map(|source| Relation {
+                        relation_info: RelationInfo::Source(source).into(),
+                    }))
+                    .collect(),
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_view_owner(
+        &self,
+        view_id: ViewId,
+        owner_id: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        database_core.ensure_view_id(view_id)?;
+        user_core.ensure_user_id(owner_id)?;
+
+        // 1. transfer the ownership of the view.
+        let mut view = database_core.views.get(&view_id).unwrap().clone();
+        let old_owner = view.owner;
+        view.owner = owner_id;
+
+        // 2. commit meta.
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
+        views.insert(view.id, view.clone());
+        commit_meta!(self, views)?;
+
+        // 3. move the ownership ref from the old owner to the new one.
+        user_core.increase_ref(owner_id);
+        user_core.decrease_ref(old_owner);
+
+        // 4. notify frontend.
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::RelationGroup(RelationGroup {
+                    relations: vec![Relation {
+                        relation_info: RelationInfo::View(view).into(),
+                    }],
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
     async fn alter_relation_name_refs_inner(
         &self,
         database_mgr: &mut DatabaseManager,
@@ -1333,6 +1704,33 @@ where
         .await
     }
 
+    /// Merge `changed_props` into the properties of the source and notify the frontends. The
+    /// updated source is returned so that the caller can refresh its split discovery worker.
+    pub async fn alter_source_props(
+        &self,
+        source_id: SourceId,
+        changed_props: HashMap<String, String>,
+    ) -> MetaResult<(NotificationVersion, Source)> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_source_id(source_id)?;
+
+        // 1. merge the changed properties.
+        let mut source = database_core.sources.get(&source_id).unwrap().clone();
+        source.properties.extend(changed_props);
+
+        // 2. commit and notify frontend.
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        sources.insert(source_id, source.clone());
+        commit_meta!(self, sources)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Source(source.clone()))
+            .await;
+
+        Ok((version, source))
+    }
+
     pub async fn alter_index_name(
         &self,
         index_id: IndexId,
@@ -2128,12 +2526,14 @@ where
     /// This is used for `ALTER TABLE ADD/DROP COLUMN`.
     pub async fn finish_replace_table_procedure(
         &self,
+        source: Option<&Source>,
         table: &Table,
         table_col_index_mapping: ColIndexMapping,
     ) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
         let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
         let key = (table.database_id, table.schema_id, table.name.clone());
         assert!(
@@ -2142,6 +2542,16 @@ where
             "table must exist and be in altering procedure"
         );
 
+        // For the table with a connector, the associated source is replaced together to keep
+        // the columns in sync.
+        if let Some(source) = source {
+            assert!(
+                sources.contains_key(&source.id),
+                "source must exist and be in altering procedure"
+            );
+            sources.insert(source.id, source.clone());
+        }
+
         let index_ids: Vec<_> = indexes
             .tree_ref()
             .iter()
@@ -2168,7 +2578,7 @@ where
         database_core.in_progress_creation_tracker.remove(&key);
 
         tables.insert(table.id, table.clone());
-        commit_meta!(self, tables, indexes)?;
+        commit_meta!(self, tables, sources, indexes)?;
 
         // Group notification
         let version = self
@@ -2179,6 +2589,9 @@ where
                         relation_info: RelationInfo::Table(table.to_owned()).into(),
                     }]
                     .into_iter()
+                    .chain(source.iter().map(|source| Relation {
+                        relation_info: RelationInfo::Source((*source).to_owned()).into(),
+                    }))
                     .chain(updated_indexes.into_iter().map(|index| Relation {
                         relation_info: RelationInfo::Index(index).into(),
                     }))
@@ -2226,6 +2639,14 @@ where
         self.core.lock().await.database.get_all_table_options()
     }
 
+    pub async fn get_all_table_cold_storage_classes(&self) -> HashMap<TableId, String> {
+        self.core
+            .lock()
+            .await
+            .database
+            .get_all_table_cold_storage_classes()
+    }
+
     pub async fn list_table_ids(&self, schema_id: SchemaId) -> Vec<TableId> {
         self.core.lock().await.database.list_table_ids(schema_id)
     }
@@ -2442,6 +2863,478 @@ where
         Ok(version)
     }
 
+    /// Drop all objects owned by the given users and strip any privileges granted to or by
+    /// them, so that the users themselves can be dropped afterwards. Databases owned by the
+    /// users are kept, following the behavior of `DROP OWNED` in PostgreSQL. Returns the ids
+    /// of streaming jobs among the dropped objects, which need to be dropped by stream
+    /// manager.
+    pub async fn drop_owned(
+        &self,
+        user_ids: Vec<UserId>,
+    ) -> MetaResult<(
+        NotificationVersion,
+        Vec<StreamingJobId>,
+        Vec<SourceId>,
+        Vec<Connection>,
+    )> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        for user_id in &user_ids {
+            user_core.ensure_user_id(*user_id)?;
+        }
+
+        if !database_core.in_progress_creation_tracker.is_empty() {
+            return Err(MetaError::permission_denied(
+                "Some relations are creating, try again later".into(),
+            ));
+        }
+
+        let mut schemas = BTreeMapTransaction::new(&mut database_core.schemas);
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        let mut connections = BTreeMapTransaction::new(&mut database_core.connections);
+
+        /// `drop_by_owner` removes the relations owned by one of `user_ids` and returns them.
+        macro_rules! drop_by_owner {
+            ($val_txn:expr) => {{
+                let ids_to_drop = $val_txn
+                    .tree_ref()
+                    .values()
+                    .filter(|relation| user_ids.contains(&relation.owner))
+                    .map(|relation| relation.id)
+                    .collect_vec();
+                ids_to_drop
+                    .into_iter()
+                    .map(|id| $val_txn.remove(id).unwrap())
+                    .collect_vec()
+            }};
+        }
+
+        // The dropping follows the `RESTRICT` behavior: any object of other users that
+        // depends on the objects to drop blocks the statement, since transitively dropping
+        // other users' objects is not supported yet. All checks are performed against the
+        // original maps before any object is removed.
+        /// `remaining` iterates over the objects that are not owned by `user_ids`.
+        macro_rules! remaining {
+            ($val_txn:expr) => {
+                $val_txn
+                    .tree_ref()
+                    .values()
+                    .filter(|obj| !user_ids.contains(&obj.owner))
+            };
+        }
+
+        let dropped_relation_ids: HashSet<RelationId> = tables
+            .tree_ref()
+            .values()
+            .filter(|table| user_ids.contains(&table.owner))
+            .map(|table| table.id)
+            .chain(
+                sources
+                    .tree_ref()
+                    .values()
+                    .filter(|source| user_ids.contains(&source.owner))
+                    .map(|source| source.id),
+            )
+            .chain(
+                views
+                    .tree_ref()
+                    .values()
+                    .filter(|view| user_ids.contains(&view.owner))
+                    .map(|view| view.id),
+            )
+            .collect();
+        for (name, dependent_relations) in remaining!(tables)
+            .map(|table| (&table.name, &table.dependent_relations))
+            .chain(remaining!(sinks).map(|sink| (&sink.name, &sink.dependent_relations)))
+            .chain(remaining!(views).map(|view| (&view.name, &view.dependent_relations)))
+        {
+            if dependent_relations
+                .iter()
+                .any(|id| dropped_relation_ids.contains(id))
+            {
+                return Err(MetaError::permission_denied(format!(
+                    "Fail to drop owned objects because relation `{}` depends on them",
+                    name
+                )));
+            }
+        }
+        for index in remaining!(indexes) {
+            if dropped_relation_ids.contains(&index.primary_table_id) {
+                return Err(MetaError::permission_denied(format!(
+                    "Fail to drop owned objects because index `{}` of another user depends on them",
+                    index.name
+                )));
+            }
+        }
+        let dropped_connection_ids: HashSet<ConnectionId> = connections
+            .tree_ref()
+            .values()
+            .filter(|conn| user_ids.contains(&conn.owner))
+            .map(|conn| conn.id)
+            .collect();
+        for (name, connection_id) in remaining!(sources)
+            .map(|source| (&source.name, source.connection_id))
+            .chain(remaining!(sinks).map(|sink| (&sink.name, sink.connection_id)))
+        {
+            if connection_id.is_some_and(|id| dropped_connection_ids.contains(&id)) {
+                return Err(MetaError::permission_denied(format!(
+                    "Fail to drop owned objects because relation `{}` uses a dropped connection",
+                    name
+                )));
+            }
+        }
+        // A schema can only be dropped together with everything in it.
+        for schema in schemas
+            .tree_ref()
+            .values()
+            .filter(|schema| user_ids.contains(&schema.owner))
+        {
+            let is_empty = remaining!(tables).all(|t| t.schema_id != schema.id)
+                && remaining!(sources).all(|s| s.schema_id != schema.id)
+                && remaining!(sinks).all(|s| s.schema_id != schema.id)
+                && remaining!(indexes).all(|i| i.schema_id != schema.id)
+                && remaining!(views).all(|v| v.schema_id != schema.id)
+                && remaining!(functions).all(|f| f.schema_id != schema.id)
+                && remaining!(connections).all(|c| c.schema_id != schema.id);
+            if !is_empty {
+                return Err(MetaError::permission_denied(format!(
+                    "Fail to drop owned schema `{}` because it contains objects of other users",
+                    schema.name
+                )));
+            }
+        }
+
+        let schemas_to_drop = drop_by_owner!(schemas);
+        let sources_to_drop = drop_by_owner!(sources);
+        let sinks_to_drop = drop_by_owner!(sinks);
+        let tables_to_drop = drop_by_owner!(tables);
+        let indexes_to_drop = drop_by_owner!(indexes);
+        let views_to_drop = drop_by_owner!(views);
+        let functions_to_drop = drop_by_owner!(functions);
+        let connections_to_drop = drop_by_owner!(connections);
+
+        let objects = schemas_to_drop
+            .iter()
+            .map(|schema| Object::SchemaId(schema.id))
+            .chain(views_to_drop.iter().map(|view| Object::ViewId(view.id)))
+            .chain(tables_to_drop.iter().map(|table| Object::TableId(table.id)))
+            .chain(
+                sources_to_drop
+                    .iter()
+                    .map(|source| Object::SourceId(source.id)),
+            )
+            .chain(
+                functions_to_drop
+                    .iter()
+                    .map(|function| Object::FunctionId(function.id)),
+            )
+            .collect_vec();
+
+        // Revoke privileges on the dropped objects, all privileges granted to the users and
+        // all privileges granted by them, in a single pass.
+        let mut users_need_update = vec![];
+        let user_keys = users.tree_ref().keys().copied().collect_vec();
+        for user_id in user_keys {
+            let mut user = users.get(&user_id).cloned().unwrap();
+            let sz = user.grant_privileges.len();
+            let mut has_change = false;
+            if user_ids.contains(&user_id) {
+                user.grant_privileges.clear();
+            } else {
+                user.grant_privileges.retain_mut(|privilege| {
+                    if objects.contains(privilege.object.as_ref().unwrap()) {
+                        return false;
+                    }
+                    let actions = privilege.action_with_opts.len();
+                    privilege
+                        .action_with_opts
+                        .retain(|ao| !user_ids.contains(&ao.granted_by));
+                    has_change |= actions != privilege.action_with_opts.len();
+                    !privilege.action_with_opts.is_empty()
+                });
+            }
+            if has_change || sz != user.grant_privileges.len() {
+                users.insert(user_id, user.clone());
+                users_need_update.push(user);
+            }
+        }
+
+        commit_meta!(
+            self,
+            schemas,
+            sources,
+            sinks,
+            tables,
+            indexes,
+            views,
+            users,
+            connections,
+            functions
+        )?;
+
+        user_core.build_grant_relation_map();
+
+        schemas_to_drop
+            .iter()
+            .map(|schema| schema.owner)
+            .chain(sources_to_drop.iter().map(|source| source.owner))
+            .chain(sinks_to_drop.iter().map(|sink| sink.owner))
+            .chain(
+                tables_to_drop
+                    .iter()
+                    .filter(|table| valid_table_name(&table.name))
+                    .map(|table| table.owner),
+            )
+            .chain(indexes_to_drop.iter().map(|index| index.owner))
+            .chain(views_to_drop.iter().map(|view| view.owner))
+            .chain(functions_to_drop.iter().map(|function| function.owner))
+            .chain(
+                connections_to_drop
+                    .iter()
+                    .map(|connection| connection.owner),
+            )
+            .for_each(|owner_id| user_core.decrease_ref(owner_id));
+
+        // Update relation ref count. Dropped objects may depend on remaining ones of other
+        // users, e.g. a materialized view on someone else's table.
+        for dependent_relations in tables_to_drop
+            .iter()
+            .map(|table| &table.dependent_relations)
+            .chain(sinks_to_drop.iter().map(|sink| &sink.dependent_relations))
+            .chain(views_to_drop.iter().map(|view| &view.dependent_relations))
+        {
+            for dependent_relation_id in dependent_relations {
+                if !dropped_relation_ids.contains(dependent_relation_id) {
+                    database_core.decrease_ref_count(*dependent_relation_id);
+                }
+            }
+        }
+        for relation_id in &dropped_relation_ids {
+            database_core.relation_ref_count.remove(relation_id);
+        }
+        // TODO(weili): wait for yezizp to refactor ref cnt
+        for connection in &connections_to_drop {
+            database_core.relation_ref_count.remove(&connection.id);
+        }
+
+        for user in users_need_update {
+            self.notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+
+        let mut version = self
+            .notify_frontend(
+                Operation::Delete,
+                Info::RelationGroup(RelationGroup {
+                    relations: indexes_to_drop
+                        .into_iter()
+                        .map(|index| Relation {
+                            relation_info: RelationInfo::Index(index).into(),
+                        })
+                        .chain(tables_to_drop.iter().map(|table| Relation {
+                            relation_info: RelationInfo::Table(table.clone()).into(),
+                        }))
+                        .chain(sources_to_drop.iter().map(|source| Relation {
+                            relation_info: RelationInfo::Source(source.clone()).into(),
+                        }))
+                        .chain(sinks_to_drop.iter().map(|sink| Relation {
+                            relation_info: RelationInfo::Sink(sink.clone()).into(),
+                        }))
+                        .chain(views_to_drop.into_iter().map(|view| Relation {
+                            relation_info: RelationInfo::View(view).into(),
+                        }))
+                        .collect_vec(),
+                }),
+            )
+            .await;
+        for schema in schemas_to_drop {
+            version = self
+                .notify_frontend(Operation::Delete, Info::Schema(schema))
+                .await;
+        }
+        for function in functions_to_drop {
+            version = self
+                .notify_frontend(Operation::Delete, Info::Function(function))
+                .await;
+        }
+        for connection in &connections_to_drop {
+            version = self
+                .notify_frontend(Operation::Delete, Info::Connection(connection.clone()))
+                .await;
+        }
+
+        let catalog_deleted_ids = tables_to_drop
+            .into_iter()
+            .filter(|table| valid_table_name(&table.name))
+            .map(|table| StreamingJobId::new(table.id))
+            .chain(
+                sinks_to_drop
+                    .into_iter()
+                    .map(|sink| StreamingJobId::new(sink.id)),
+            )
+            .collect_vec();
+        let source_deleted_ids = sources_to_drop
+            .into_iter()
+            .map(|source| source.id)
+            .collect_vec();
+
+        Ok((
+            version,
+            catalog_deleted_ids,
+            source_deleted_ids,
+            connections_to_drop,
+        ))
+    }
+
+    /// Reassign ownership of all objects owned by the given users to `new_owner`, following
+    /// the behavior of `REASSIGN OWNED` in PostgreSQL.
+    pub async fn reassign_owned(
+        &self,
+        user_ids: Vec<UserId>,
+        new_owner: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        for user_id in &user_ids {
+            user_core.ensure_user_id(*user_id)?;
+        }
+        user_core.ensure_user_id(new_owner)?;
+
+        let mut databases = BTreeMapTransaction::new(&mut database_core.databases);
+        let mut schemas = BTreeMapTransaction::new(&mut database_core.schemas);
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        let mut connections = BTreeMapTransaction::new(&mut database_core.connections);
+
+        // The number of ownership refs transferred away from each user.
+        let mut reassigned_count: HashMap<UserId, usize> = HashMap::new();
+
+        /// `reassign_by_owner` transfers the ownership of the objects owned by one of
+        /// `user_ids` to `new_owner` and returns the updated objects.
+        macro_rules! reassign_by_owner {
+            ($val_txn:expr) => {{
+                let ids_to_update = $val_txn
+                    .tree_ref()
+                    .values()
+                    .filter(|obj| user_ids.contains(&obj.owner))
+                    .map(|obj| obj.id)
+                    .collect_vec();
+                ids_to_update
+                    .into_iter()
+                    .map(|id| {
+                        let mut obj = $val_txn.get_mut(id).unwrap();
+                        *reassigned_count.entry(obj.owner).or_insert(0) += 1;
+                        obj.owner = new_owner;
+                        obj.clone()
+                    })
+                    .collect_vec()
+            }};
+        }
+
+        let databases_updated = reassign_by_owner!(databases);
+        let schemas_updated = reassign_by_owner!(schemas);
+        let sources_updated = reassign_by_owner!(sources);
+        let sinks_updated = reassign_by_owner!(sinks);
+        let indexes_updated = reassign_by_owner!(indexes);
+        let views_updated = reassign_by_owner!(views);
+        let functions_updated = reassign_by_owner!(functions);
+        let connections_updated = reassign_by_owner!(connections);
+        // Internal tables do not hold an ownership ref, although their owner field is kept
+        // in sync with the owning streaming job.
+        let table_ids_to_update = tables
+            .tree_ref()
+            .values()
+            .filter(|table| user_ids.contains(&table.owner))
+            .map(|table| table.id)
+            .collect_vec();
+        let tables_updated = table_ids_to_update
+            .into_iter()
+            .map(|id| {
+                let mut table = tables.get_mut(id).unwrap();
+                if valid_table_name(&table.name) {
+                    *reassigned_count.entry(table.owner).or_insert(0) += 1;
+                }
+                table.owner = new_owner;
+                table.clone()
+            })
+            .collect_vec();
+
+        commit_meta!(
+            self,
+            databases,
+            schemas,
+            sources,
+            sinks,
+            tables,
+            indexes,
+            views,
+            functions,
+            connections
+        )?;
+
+        for (old_owner, count) in reassigned_count {
+            user_core.increase_ref_count(new_owner, count);
+            user_core.decrease_ref_count(old_owner, count);
+        }
+
+        for database in databases_updated {
+            self.notify_frontend(Operation::Update, Info::Database(database))
+                .await;
+        }
+        for schema in schemas_updated {
+            self.notify_frontend(Operation::Update, Info::Schema(schema))
+                .await;
+        }
+        for function in functions_updated {
+            self.notify_frontend(Operation::Update, Info::Function(function))
+                .await;
+        }
+        for connection in connections_updated {
+            self.notify_frontend(Operation::Update, Info::Connection(connection))
+                .await;
+        }
+
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::RelationGroup(RelationGroup {
+                    relations: indexes_updated
+                        .into_iter()
+                        .map(|index| Relation {
+                            relation_info: RelationInfo::Index(index).into(),
+                        })
+                        .chain(tables_updated.into_iter().map(|table| Relation {
+                            relation_info: RelationInfo::Table(table).into(),
+                        }))
+                        .chain(sources_updated.into_iter().map(|source| Relation {
+                            relation_info: RelationInfo::Source(source).into(),
+                        }))
+                        .chain(sinks_updated.into_iter().map(|sink| Relation {
+                            relation_info: RelationInfo::Sink(sink).into(),
+                        }))
+                        .chain(views_updated.into_iter().map(|view| Relation {
+                            relation_info: RelationInfo::View(view).into(),
+                        }))
+                        .collect_vec(),
+                }),
+            )
+            .await;
+
+        Ok(version)
+    }
+
     // Defines privilege grant for a user.
 
     // Merge new granted privilege.
@@ -2449,29 +3342,31 @@ where
     fn merge_privilege(origin_privilege: &mut GrantPrivilege, new_privilege: &GrantPrivilege) {
         assert_eq!(origin_privilege.object, new_privilege.object);
 
-        let mut action_map = HashMap::<i32, (bool, u32)>::from_iter(
+        let mut action_map = HashMap::<i32, ActionWithGrantOption>::from_iter(
             origin_privilege
                 .action_with_opts
                 .iter()
-                .map(|ao| (ao.action, (ao.with_grant_option, ao.granted_by))),
+                .map(|ao| (ao.action, ao.clone())),
         );
         for nao in &new_privilege.action_with_opts {
             if let Some(o) = action_map.get_mut(&nao.action) {
-                o.0 |= nao.with_grant_option;
+                o.with_grant_option |= nao.with_grant_option;
+                // An empty column list means the whole relation, which supersedes any
+                // column-level grant. Otherwise merge the granted columns.
+                if o.column_names.is_empty() || nao.column_names.is_empty() {
+                    o.column_names.clear();
+                } else {
+                    for column in &nao.column_names {
+                        if !o.column_names.contains(column) {
+                            o.column_names.push(column.clone());
+                        }
+                    }
+                }
             } else {
-                action_map.insert(nao.action, (nao.with_grant_option, nao.granted_by));
+                action_map.insert(nao.action, nao.clone());
             }
         }
-        origin_privilege.action_with_opts = action_map
-            .into_iter()
-            .map(
-                |(action, (with_grant_option, granted_by))| ActionWithGrantOption {
-                    action,
-                    with_grant_option,
-                    granted_by,
-                },
-            )
-            .collect();
+        origin_privilege.action_with_opts = action_map.into_values().collect();
     }
 
     // Check whether new_privilege is a subset of origin_privilege, and check grand_option if
@@ -2484,15 +3379,26 @@ where
     ) -> bool {
         assert_eq!(origin_privilege.object, new_privilege.object);
 
-        let action_map = HashMap::<i32, bool>::from_iter(
+        let action_map = HashMap::<i32, &ActionWithGrantOption>::from_iter(
             origin_privilege
                 .action_with_opts
                 .iter()
-                .map(|ao| (ao.action, ao.with_grant_option)),
+                .map(|ao| (ao.action, ao)),
         );
         for nao in &new_privilege.action_with_opts {
-            if let Some(with_grant_option) = action_map.get(&nao.action) {
-                if !with_grant_option && need_grand_option {
+            if let Some(ao) = action_map.get(&nao.action) {
+                if !ao.with_grant_option && need_grand_option {
+                    return false;
+                }
+                // A column-restricted privilege only covers privileges on a subset of its
+                // columns, never on the whole relation.
+                if !ao.column_names.is_empty()
+                    && (nao.column_names.is_empty()
+                        || nao
+                            .column_names
+                            .iter()
+                            .any(|column| !ao.column_names.contains(column)))
+                {
                     return false;
                 }
             } else {
@@ -2602,15 +3508,30 @@ where
                 }
             })
         } else {
-            let sz = origin_privilege.action_with_opts.len();
             // Revoke all privileges matched with revoke_grant_privilege.
-            origin_privilege.action_with_opts.retain(|ao| {
-                !revoke_grant_privilege
+            origin_privilege.action_with_opts.retain_mut(|ao| {
+                let Some(rao) = revoke_grant_privilege
                     .action_with_opts
                     .iter()
-                    .any(|rao| rao.action == ao.action)
+                    .find(|rao| rao.action == ao.action)
+                else {
+                    return true;
+                };
+                if rao.column_names.is_empty() {
+                    // Revoking on the whole relation removes the action entirely.
+                    has_change = true;
+                    return false;
+                }
+                if ao.column_names.is_empty() {
+                    // A whole-relation grant is not shrunk by a column-level revoke.
+                    return true;
+                }
+                let sz = ao.column_names.len();
+                ao.column_names
+                    .retain(|column| !rao.column_names.contains(column));
+                has_change |= sz != ao.column_names.len();
+                !ao.column_names.is_empty()
             });
-            has_change = sz != origin_privilege.action_with_opts.len();
         }
         has_change
     }
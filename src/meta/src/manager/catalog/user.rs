@@ -71,7 +71,6 @@ impl UserManager {
         self.user_info.values().any(|x| x.name.eq(user))
     }
 
-    #[allow(dead_code)]
     pub fn ensure_user_id(&self, user_id: UserId) -> MetaResult<()> {
         if self.user_info.contains_key(&user_id) {
             Ok(())
@@ -158,6 +157,7 @@ mod tests {
                     action: action as i32,
                     with_grant_option,
                     granted_by: DEFAULT_SUPER_USER_ID,
+                    column_names: vec![],
                 })
                 .collect(),
         }
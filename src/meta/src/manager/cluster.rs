@@ -109,12 +109,10 @@ where
 
         if let Some(worker) = core.get_worker_by_host_mut(host_address.clone()) {
             if let Some(property) = &mut property {
-                property.is_unschedulable = worker
-                    .worker_node
-                    .property
-                    .as_ref()
-                    .unwrap()
-                    .is_unschedulable;
+                // Keep the states only mutable through dedicated RPCs across re-registrations.
+                let recorded = worker.worker_node.property.as_ref().unwrap();
+                property.is_unschedulable = recorded.is_unschedulable;
+                property.resource_group = recorded.resource_group.clone();
             }
 
             worker.update_ttl(self.max_heartbeat_interval);
@@ -265,6 +263,45 @@ where
         Ok(())
     }
 
+    pub async fn update_resource_group(
+        &self,
+        worker_ids: Vec<u32>,
+        resource_group: String,
+    ) -> MetaResult<()> {
+        let worker_ids: HashSet<_> = worker_ids.into_iter().collect();
+
+        let mut core = self.core.write().await;
+        let mut txn = Transaction::default();
+        let mut var_txns = vec![];
+
+        for worker in core.workers.values_mut() {
+            if worker_ids.contains(&worker.worker_node.id) {
+                if let Some(property) = worker.worker_node.property.as_ref() {
+                    if property.resource_group != resource_group {
+                        let mut var_txn = VarTransaction::new(worker);
+                        var_txn
+                            .worker_node
+                            .property
+                            .as_mut()
+                            .unwrap()
+                            .resource_group = resource_group.clone();
+
+                        var_txn.apply_to_txn(&mut txn)?;
+                        var_txns.push(var_txn);
+                    }
+                }
+            }
+        }
+
+        self.env.meta_store().txn(txn).await?;
+
+        for var_txn in var_txns {
+            var_txn.commit();
+        }
+
+        Ok(())
+    }
+
     pub async fn delete_worker_node(&self, host_address: HostAddress) -> MetaResult<WorkerType> {
         let mut core = self.core.write().await;
         let worker = core.get_worker_by_host_checked(host_address.clone())?;
@@ -447,6 +484,7 @@ where
                 is_streaming: worker_property.is_streaming,
                 is_serving: worker_property.is_serving,
                 is_unschedulable: worker_property.is_unschedulable,
+                resource_group: Default::default(),
             })
         } else {
             None
@@ -491,6 +529,40 @@ pub struct StreamingClusterInfo {
     pub unschedulable_parallel_units: HashMap<ParallelUnitId, ParallelUnit>,
 }
 
+impl StreamingClusterInfo {
+    /// Restrict the cluster info to the workers labeled with the given resource group.
+    pub fn filter_by_resource_group(self, resource_group: &str) -> Self {
+        let worker_nodes: HashMap<_, _> = self
+            .worker_nodes
+            .into_iter()
+            .filter(|(_, worker)| {
+                worker
+                    .property
+                    .as_ref()
+                    .map(|property| property.resource_group == resource_group)
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        let parallel_units = self
+            .parallel_units
+            .into_iter()
+            .filter(|(_, parallel_unit)| worker_nodes.contains_key(&parallel_unit.worker_node_id))
+            .collect();
+        let unschedulable_parallel_units = self
+            .unschedulable_parallel_units
+            .into_iter()
+            .filter(|(_, parallel_unit)| worker_nodes.contains_key(&parallel_unit.worker_node_id))
+            .collect();
+
+        Self {
+            worker_nodes,
+            parallel_units,
+            unschedulable_parallel_units,
+        }
+    }
+}
+
 pub struct ClusterManagerCore {
     /// Record for workers in the cluster.
     workers: HashMap<WorkerKey, Worker>,
@@ -71,6 +71,14 @@ pub struct MetaOpts {
     /// Whether to enable the recovery of the cluster. If disabled, the meta service will exit on
     /// abnormal cases.
     pub enable_recovery: bool,
+    /// Number of unexpected actor failures after which the owning streaming job is
+    /// automatically quarantined. Zero disables automatic quarantine.
+    pub quarantine_failure_threshold: u64,
+    /// Interval in seconds at which parallelism imbalance is checked and automatically
+    /// rebalanced. Zero disables automatic rebalancing.
+    pub auto_rebalance_interval_sec: u64,
+    /// Maximum number of fragments to reschedule per automatic rebalancing round.
+    pub auto_rebalance_max_moves: u64,
     /// The maximum number of barriers in-flight in the compute nodes.
     pub in_flight_barrier_nums: usize,
     /// After specified seconds of idle (no mview or flush), the process will be exited.
@@ -98,6 +106,8 @@ pub struct MetaOpts {
     pub enable_committed_sst_sanity_check: bool,
     /// Schedule compaction for all compaction groups with this interval.
     pub periodic_compaction_interval_sec: u64,
+    /// How long summaries of completed compaction tasks are retained.
+    pub compaction_task_history_retention_sec: u64,
     /// Interval of reporting the number of nodes in the cluster.
     pub node_num_monitor_interval_sec: u64,
 
@@ -133,6 +143,10 @@ pub struct MetaOpts {
     /// Schedule split_compaction_group for all compaction groups with this interval.
     pub periodic_split_compact_group_interval_sec: u64,
 
+    /// Whether to automatically move high-write-throughput state tables to dedicated
+    /// compaction groups.
+    pub enable_automatic_split_compact_group: bool,
+
     /// The size limit to split a large compaction group.
     pub split_group_size_limit: u64,
     /// The size limit to move a state-table to other group.
@@ -146,6 +160,21 @@ pub struct MetaOpts {
     pub min_table_split_write_throughput: u64,
 
     pub compaction_task_max_heartbeat_interval_secs: u64,
+
+    /// The secondary object store that newly committed SSTs and meta backups are
+    /// asynchronously mirrored to, for disaster recovery. `None` disables replication.
+    pub dr_secondary_object_store: Option<String>,
+    /// Interval of mirroring newly committed SSTs and meta backups to the secondary
+    /// object store.
+    pub dr_replication_interval_sec: u64,
+    /// Whether this cluster is a read-only disaster recovery standby.
+    pub dr_standby: bool,
+
+    /// Token that authorizes all meta admin RPCs. `None` together with `readonly_token` being
+    /// `None` disables admin RPC authentication.
+    pub admin_token: Option<String>,
+    /// Token that authorizes read-only meta admin RPCs.
+    pub readonly_token: Option<String>,
 }
 
 impl MetaOpts {
@@ -153,6 +182,9 @@ impl MetaOpts {
     pub fn test(enable_recovery: bool) -> Self {
         Self {
             enable_recovery,
+            quarantine_failure_threshold: 0,
+            auto_rebalance_interval_sec: 0,
+            auto_rebalance_max_moves: 8,
             in_flight_barrier_nums: 40,
             max_idle_ms: 0,
             compaction_deterministic_test: false,
@@ -164,6 +196,7 @@ impl MetaOpts {
             collect_gc_watermark_spin_interval_sec: 5,
             enable_committed_sst_sanity_check: false,
             periodic_compaction_interval_sec: 60,
+            compaction_task_history_retention_sec: 2592000,
             node_num_monitor_interval_sec: 10,
             prometheus_endpoint: None,
             vpc_id: None,
@@ -174,6 +207,7 @@ impl MetaOpts {
             telemetry_enabled: false,
             periodic_ttl_reclaim_compaction_interval_sec: 60,
             periodic_split_compact_group_interval_sec: 60,
+            enable_automatic_split_compact_group: true,
             max_compactor_task_multiplier: 2,
             split_group_size_limit: 5 * 1024 * 1024 * 1024,
             min_table_split_size: 2 * 1024 * 1024 * 1024,
@@ -182,6 +216,11 @@ impl MetaOpts {
             do_not_config_object_storage_lifecycle: true,
             partition_vnode_count: 32,
             compaction_task_max_heartbeat_interval_secs: 0,
+            dr_secondary_object_store: None,
+            dr_replication_interval_sec: 10,
+            dr_standby: false,
+            admin_token: None,
+            readonly_token: None,
         }
     }
 }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use risingwave_pb::catalog::{
-    Connection, Database, Function, Index, Schema, Sink, Source, Table, View,
+    Connection, Database, Function, Index, Schema, Secret, Sink, Source, Table, View,
 };
 
 use crate::model::{MetadataModel, MetadataModelResult};
@@ -30,6 +30,8 @@ const CATALOG_SINK_CF_NAME: &str = "cf/catalog_sink";
 const CATALOG_INDEX_CF_NAME: &str = "cf/catalog_index";
 /// Column family name for function catalog.
 const CATALOG_FUNCTION_CF_NAME: &str = "cf/catalog_function";
+/// Column family name for secret catalog.
+const CATALOG_SECRET_CF_NAME: &str = "cf/catalog_secret";
 /// Column family name for table catalog.
 const CATALOG_TABLE_CF_NAME: &str = "cf/catalog_table";
 /// Column family name for schema catalog.
@@ -68,6 +70,7 @@ impl_model_for_catalog!(Source, CATALOG_SOURCE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Sink, CATALOG_SINK_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Index, CATALOG_INDEX_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Function, CATALOG_FUNCTION_CF_NAME, u32, get_id);
+impl_model_for_catalog!(Secret, CATALOG_SECRET_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Table, CATALOG_TABLE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Schema, CATALOG_SCHEMA_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Database, CATALOG_DATABASE_CF_NAME, u32, get_id);
@@ -64,12 +64,16 @@ pub struct TableFragments {
 pub struct StreamEnvironment {
     /// The timezone used to interpret timestamps and dates for conversion
     pub(crate) timezone: Option<String>,
+
+    /// The expression feature gates enabled when the job was created
+    pub(crate) expr_feature_gates: Vec<String>,
 }
 
 impl StreamEnvironment {
     pub fn to_protobuf(&self) -> PbStreamEnvironment {
         PbStreamEnvironment {
             timezone: self.timezone.clone().unwrap_or("".into()),
+            expr_feature_gates: self.expr_feature_gates.clone(),
         }
     }
 
@@ -80,6 +84,7 @@ impl StreamEnvironment {
             } else {
                 Some(prost.get_timezone().clone())
             },
+            expr_feature_gates: prost.expr_feature_gates.clone(),
         }
     }
 }
@@ -185,6 +190,11 @@ impl TableFragments {
         self.env.timezone.clone()
     }
 
+    /// Returns the expression feature gates recorded for the table
+    pub fn expr_feature_gates(&self) -> &[String] {
+        &self.env.expr_feature_gates
+    }
+
     /// Returns whether the table fragments is in `Created` state.
     pub fn is_created(&self) -> bool {
         self.state == State::Created
@@ -238,6 +248,19 @@ impl TableFragments {
             .collect()
     }
 
+    /// Returns the fragment id of each actor associated with this table.
+    pub fn actor_fragment_mapping(&self) -> HashMap<ActorId, FragmentId> {
+        self.fragments
+            .values()
+            .flat_map(|fragment| {
+                fragment
+                    .actors
+                    .iter()
+                    .map(|actor| (actor.actor_id, fragment.fragment_id))
+            })
+            .collect()
+    }
+
     /// Returns actors associated with this table.
     pub fn actors(&self) -> Vec<StreamActor> {
         self.fragments
@@ -0,0 +1,203 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Token-based authentication for meta admin RPCs.
+//!
+//! Admin RPCs are the control-plane operations exposed to operators via risectl, e.g.
+//! unregistering workers or triggering a full GC. When a cluster is deployed with its control
+//! plane reachable from outside, these should not be callable by anyone who can open a
+//! connection.
+//!
+//! Authentication is enabled by configuring at least one token on the meta node (`--admin-token`
+//! / `--readonly-token`). Clients present a token via the `authorization: Bearer <token>` gRPC
+//! metadata, which [`MetaClient`](risingwave_rpc_client::MetaClient) attaches automatically from
+//! the `RW_META_TOKEN` env variable. The admin token authorizes all admin RPCs, the read-only
+//! token only the inspecting ones. RPCs used by cluster nodes themselves (heartbeat, DDL, ...)
+//! are not gated; note that worker nodes unregister themselves on shutdown, so they must also be
+//! given the admin token when authentication is enabled.
+//!
+//! Every gated call is recorded in the audit log, i.e. the tracing target `meta_admin_audit`.
+
+use std::sync::Arc;
+
+use tonic::{Request, Status};
+
+/// The privilege level required by (or granted to) an admin RPC caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AdminRole {
+    /// May call admin RPCs that only inspect state.
+    ReadOnly,
+    /// May call all admin RPCs, including mutating ones.
+    Admin,
+}
+
+pub type MetaAdminAuthRef = Arc<MetaAdminAuth>;
+
+/// Authenticates admin RPC callers against the configured tokens. See the module-level docs.
+pub struct MetaAdminAuth {
+    admin_token: Option<String>,
+    readonly_token: Option<String>,
+}
+
+impl MetaAdminAuth {
+    pub fn new(admin_token: Option<String>, readonly_token: Option<String>) -> Self {
+        Self {
+            admin_token,
+            readonly_token,
+        }
+    }
+
+    /// Whether authentication is enabled, i.e. at least one token is configured.
+    pub fn enabled(&self) -> bool {
+        self.admin_token.is_some() || self.readonly_token.is_some()
+    }
+
+    /// Checks that the caller of an admin RPC holds the `required` role, and records the action
+    /// in the audit log. Allows everything if authentication is not enabled.
+    pub fn check<T>(
+        &self,
+        request: &Request<T>,
+        action: &str,
+        required: AdminRole,
+    ) -> Result<(), Status> {
+        let peer = request
+            .remote_addr()
+            .map_or_else(|| "unknown".to_string(), |addr| addr.to_string());
+        if !self.enabled() {
+            tracing::info!(
+                target: "meta_admin_audit",
+                action, peer, "admin RPC allowed (authentication not enabled)"
+            );
+            return Ok(());
+        }
+
+        let result = match self.authenticate(request) {
+            Some(role) if role >= required => Ok(role),
+            Some(role) => Err((
+                Some(role),
+                Status::permission_denied(format!(
+                    "{action} requires the {required:?} role, but the presented token only \
+                     grants {role:?}"
+                )),
+            )),
+            None => Err((
+                None,
+                Status::unauthenticated(format!(
+                    "{action} requires authentication, set a valid token in the \
+                     `authorization` metadata (risectl reads it from `RW_META_TOKEN`)"
+                )),
+            )),
+        };
+        match result {
+            Ok(role) => {
+                tracing::info!(
+                    target: "meta_admin_audit",
+                    action, peer, role = ?role, "admin RPC authorized"
+                );
+                Ok(())
+            }
+            Err((role, status)) => {
+                tracing::warn!(
+                    target: "meta_admin_audit",
+                    action, peer, role = ?role, "admin RPC rejected"
+                );
+                Err(status)
+            }
+        }
+    }
+
+    /// Resolves the role granted by the token presented in the request, if any.
+    fn authenticate<T>(&self, request: &Request<T>) -> Option<AdminRole> {
+        let token = request
+            .metadata()
+            .get("authorization")?
+            .to_str()
+            .ok()?
+            .strip_prefix("Bearer ")?;
+        if self.admin_token.as_deref() == Some(token) {
+            Some(AdminRole::Admin)
+        } else if self.readonly_token.as_deref() == Some(token) {
+            Some(AdminRole::ReadOnly)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with_token(token: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn test_disabled_allows_all() {
+        let auth = MetaAdminAuth::new(None, None);
+        assert!(auth
+            .check(&request_with_token(None), "test", AdminRole::Admin)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_role_check() {
+        let auth = MetaAdminAuth::new(Some("s3cret".to_string()), Some("peek".to_string()));
+        // admin token grants everything
+        assert!(auth
+            .check(
+                &request_with_token(Some("s3cret")),
+                "test",
+                AdminRole::Admin
+            )
+            .is_ok());
+        // read-only token grants read-only RPCs only
+        assert!(auth
+            .check(
+                &request_with_token(Some("peek")),
+                "test",
+                AdminRole::ReadOnly
+            )
+            .is_ok());
+        assert_eq!(
+            auth.check(&request_with_token(Some("peek")), "test", AdminRole::Admin)
+                .unwrap_err()
+                .code(),
+            tonic::Code::PermissionDenied
+        );
+        // unknown or missing token is rejected
+        assert_eq!(
+            auth.check(
+                &request_with_token(Some("wrong")),
+                "test",
+                AdminRole::ReadOnly
+            )
+            .unwrap_err()
+            .code(),
+            tonic::Code::Unauthenticated
+        );
+        assert_eq!(
+            auth.check(&request_with_token(None), "test", AdminRole::ReadOnly)
+                .unwrap_err()
+                .code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+}
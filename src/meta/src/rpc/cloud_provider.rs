@@ -15,10 +15,11 @@
 use std::collections::HashMap;
 
 use anyhow::anyhow;
-use aws_config::retry::RetryConfig;
+use aws_config::retry::RetryConfig as SdkRetryConfig;
 use aws_sdk_ec2::error::ProvideErrorMetadata;
 use aws_sdk_ec2::types::{Filter, ResourceType, State, Tag, TagSpecification, VpcEndpointType};
 use itertools::Itertools;
+use risingwave_common::config::RetryConfig;
 use risingwave_pb::catalog::connection::private_link_service::PrivateLinkProvider;
 use risingwave_pb::catalog::connection::PrivateLinkService;
 
@@ -34,8 +35,11 @@ pub struct AwsEc2Client {
 
 impl AwsEc2Client {
     pub async fn new(vpc_id: &str, security_group_id: &str) -> Self {
+        // Retrying is left to the AWS SDK here, but the number of attempts follows the shared
+        // retry policy defaults.
+        let max_attempts = RetryConfig::default().max_attempts as u32;
         let sdk_config = aws_config::from_env()
-            .retry_config(RetryConfig::standard().with_max_attempts(4))
+            .retry_config(SdkRetryConfig::standard().with_max_attempts(max_attempts))
             .load()
             .await;
         let client = aws_sdk_ec2::Client::new(&sdk_config);
@@ -12,26 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use itertools::Itertools;
 use risingwave_common::config::DefaultParallelism;
+use risingwave_connector::source::RATE_LIMIT_KEY;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::catalog::connection::private_link_service::PbPrivateLinkProvider;
 use risingwave_pb::catalog::{
-    connection, Connection, Database, Function, Schema, Source, Table, View,
+    connection, Connection, Database, Function, Schema, Secret, Source, Table, View,
 };
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
-use risingwave_pb::ddl_service::DdlProgress;
+use risingwave_pb::ddl_service::alter_relation_owner_request::Relation as OwnerRelation;
+use risingwave_pb::ddl_service::{BackfillProgress, DdlProgress};
 use risingwave_pb::stream_plan::StreamFragmentGraph as StreamFragmentGraphProto;
 use tracing::log::warn;
 
 use crate::barrier::BarrierManagerRef;
 use crate::manager::{
     CatalogManagerRef, ClusterManagerRef, ConnectionId, DatabaseId, FragmentManagerRef, FunctionId,
-    IdCategory, IndexId, MetaSrvEnv, NotificationVersion, SchemaId, SinkId, SourceId,
-    StreamingClusterInfo, StreamingJob, TableId, ViewId,
+    IdCategory, IndexId, MetaSrvEnv, NotificationVersion, SchemaId, SecretId, SinkId, SourceId,
+    StreamingClusterInfo, StreamingJob, TableId, UserId, ViewId,
 };
 use crate::model::{StreamEnvironment, TableFragments};
 use crate::rpc::cloud_provider::AwsEc2Client;
@@ -70,14 +74,23 @@ pub enum DdlCommand {
     DropSource(SourceId),
     CreateFunction(Function),
     DropFunction(FunctionId),
+    ReplaceFunction(Function),
     CreateView(View),
     DropView(ViewId),
+    ReplaceView(View),
     CreateStreamingJob(StreamingJob, StreamFragmentGraphProto),
     DropStreamingJob(StreamingJobId),
     ReplaceTable(StreamingJob, StreamFragmentGraphProto, ColIndexMapping),
     AlterRelationName(Relation, String),
+    AlterRelationOwner(OwnerRelation, u32),
+    AlterSourceProps(SourceId, HashMap<String, String>, Option<i64>),
     CreateConnection(Connection),
     DropConnection(ConnectionId),
+    CreateSecret(Secret),
+    AlterSecret(Secret),
+    DropSecret(SecretId),
+    DropOwned(Vec<UserId>),
+    ReassignOwned(Vec<UserId>, UserId),
 }
 
 #[derive(Clone)]
@@ -148,8 +161,10 @@ where
                 DdlCommand::DropSource(source_id) => ctrl.drop_source(source_id).await,
                 DdlCommand::CreateFunction(function) => ctrl.create_function(function).await,
                 DdlCommand::DropFunction(function_id) => ctrl.drop_function(function_id).await,
+                DdlCommand::ReplaceFunction(function) => ctrl.replace_function(function).await,
                 DdlCommand::CreateView(view) => ctrl.create_view(view).await,
                 DdlCommand::DropView(view_id) => ctrl.drop_view(view_id).await,
+                DdlCommand::ReplaceView(view) => ctrl.replace_view(view).await,
                 DdlCommand::CreateStreamingJob(stream_job, fragment_graph) => {
                     ctrl.create_streaming_job(stream_job, fragment_graph).await
                 }
@@ -161,12 +176,34 @@ where
                 DdlCommand::AlterRelationName(relation, name) => {
                     ctrl.alter_relation_name(relation, &name).await
                 }
+                DdlCommand::AlterRelationOwner(relation, owner_id) => {
+                    ctrl.alter_relation_owner(relation, owner_id).await
+                }
+                DdlCommand::AlterSourceProps(
+                    source_id,
+                    changed_props,
+                    migrate_offsets_timestamp_millis,
+                ) => {
+                    ctrl.alter_source_props(
+                        source_id,
+                        changed_props,
+                        migrate_offsets_timestamp_millis,
+                    )
+                    .await
+                }
                 DdlCommand::CreateConnection(connection) => {
                     ctrl.create_connection(connection).await
                 }
                 DdlCommand::DropConnection(connection_id) => {
                     ctrl.drop_connection(connection_id).await
                 }
+                DdlCommand::CreateSecret(secret) => ctrl.create_secret(secret).await,
+                DdlCommand::AlterSecret(secret) => ctrl.alter_secret(secret).await,
+                DdlCommand::DropSecret(secret_id) => ctrl.drop_secret(secret_id).await,
+                DdlCommand::DropOwned(user_ids) => ctrl.drop_owned(user_ids).await,
+                DdlCommand::ReassignOwned(user_ids, new_owner) => {
+                    ctrl.reassign_owned(user_ids, new_owner).await
+                }
             }
         });
         handler.await.unwrap()
@@ -176,6 +213,10 @@ where
         self.barrier_manager.get_ddl_progress().await
     }
 
+    pub(crate) async fn get_backfill_progress(&self) -> Vec<BackfillProgress> {
+        self.barrier_manager.get_backfill_progress().await
+    }
+
     async fn create_database(&self, database: Database) -> MetaResult<NotificationVersion> {
         self.catalog_manager.create_database(&database).await
     }
@@ -242,6 +283,10 @@ where
         self.catalog_manager.drop_function(function_id).await
     }
 
+    async fn replace_function(&self, function: Function) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.replace_function(&function).await
+    }
+
     async fn create_view(&self, view: View) -> MetaResult<NotificationVersion> {
         self.catalog_manager.create_view(&view).await
     }
@@ -250,10 +295,26 @@ where
         self.catalog_manager.drop_view(view_id).await
     }
 
+    async fn replace_view(&self, view: View) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.replace_view(&view).await
+    }
+
     async fn create_connection(&self, connection: Connection) -> MetaResult<NotificationVersion> {
         self.catalog_manager.create_connection(connection).await
     }
 
+    async fn create_secret(&self, secret: Secret) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.create_secret(&secret).await
+    }
+
+    async fn alter_secret(&self, secret: Secret) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.alter_secret(&secret).await
+    }
+
+    async fn drop_secret(&self, secret_id: SecretId) -> MetaResult<NotificationVersion> {
+        self.catalog_manager.drop_secret(secret_id).await
+    }
+
     async fn drop_connection(
         &self,
         connection_id: ConnectionId,
@@ -263,6 +324,34 @@ where
         Ok(version)
     }
 
+    async fn drop_owned(&self, user_ids: Vec<UserId>) -> MetaResult<NotificationVersion> {
+        // 1. drop all catalogs owned by the users.
+        let (version, streaming_ids, source_ids, connections_dropped) =
+            self.catalog_manager.drop_owned(user_ids).await?;
+        // 2. Unregister source connector worker.
+        self.source_manager.unregister_sources(source_ids).await;
+        // 3. drop streaming jobs.
+        if !streaming_ids.is_empty() {
+            self.stream_manager.drop_streaming_jobs(streaming_ids).await;
+        }
+        // 4. delete cloud resources if any
+        for conn in connections_dropped {
+            self.delete_vpc_endpoint(&conn).await?;
+        }
+
+        Ok(version)
+    }
+
+    async fn reassign_owned(
+        &self,
+        user_ids: Vec<UserId>,
+        new_owner: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        self.catalog_manager
+            .reassign_owned(user_ids, new_owner)
+            .await
+    }
+
     async fn delete_vpc_endpoint(&self, connection: &Connection) -> MetaResult<()> {
         // delete AWS vpc endpoint
         if let Some(connection::Info::PrivateLinkService(svc)) = &connection.info
@@ -429,6 +518,7 @@ where
     ) -> MetaResult<(CreateStreamingJobContext, TableFragments)> {
         let id = stream_job.id();
         let default_parallelism = fragment_graph.default_parallelism();
+        let resource_group = fragment_graph.resource_group();
         let internal_tables = fragment_graph.internal_tables();
 
         // 1. Resolve the upstream fragments, extend the fragment graph to a complete graph that
@@ -451,7 +541,16 @@ where
             CompleteStreamFragmentGraph::with_upstreams(fragment_graph, upstream_mview_fragments)?;
 
         // 2. Build the actor graph.
-        let cluster_info = self.cluster_manager.get_streaming_cluster_info().await;
+        let mut cluster_info = self.cluster_manager.get_streaming_cluster_info().await;
+        if let Some(resource_group) = &resource_group {
+            cluster_info = cluster_info.filter_by_resource_group(resource_group);
+            if cluster_info.parallel_units.is_empty() {
+                return Err(MetaError::unavailable(format!(
+                    "No available workers in resource group \"{}\"",
+                    resource_group
+                )));
+            }
+        }
         let default_parallelism =
             self.resolve_stream_parallelism(default_parallelism, &cluster_info)?;
 
@@ -676,7 +775,11 @@ where
         let fragment_graph =
             StreamFragmentGraph::new(fragment_graph, self.env.id_gen_manager_ref(), stream_job)
                 .await?;
-        assert!(fragment_graph.internal_tables().is_empty());
+        // A table with a connector additionally has an internal state table for the source
+        // executor.
+        if !matches!(stream_job, StreamingJob::Table(Some(_), _)) {
+            assert!(fragment_graph.internal_tables().is_empty());
+        }
         assert!(fragment_graph.dependent_table_ids().is_empty());
 
         // 2. Set the graph-related fields and freeze the `stream_job`.
@@ -703,6 +806,7 @@ where
     ) -> MetaResult<(ReplaceTableContext, TableFragments)> {
         let id = stream_job.id();
         let default_parallelism = fragment_graph.default_parallelism();
+        let resource_group = fragment_graph.resource_group();
 
         // 1. Resolve the edges to the downstream fragments, extend the fragment graph to a complete
         // graph that contains all information needed for building the actor graph.
@@ -730,7 +834,16 @@ where
         )?;
 
         // 2. Build the actor graph.
-        let cluster_info = self.cluster_manager.get_streaming_cluster_info().await;
+        let mut cluster_info = self.cluster_manager.get_streaming_cluster_info().await;
+        if let Some(resource_group) = &resource_group {
+            cluster_info = cluster_info.filter_by_resource_group(resource_group);
+            if cluster_info.parallel_units.is_empty() {
+                return Err(MetaError::unavailable(format!(
+                    "No available workers in resource group \"{}\"",
+                    resource_group
+                )));
+            }
+        }
         let default_parallelism =
             self.resolve_stream_parallelism(default_parallelism, &cluster_info)?;
         let actor_graph_builder =
@@ -789,17 +902,17 @@ where
         stream_job: &StreamingJob,
         table_col_index_mapping: ColIndexMapping,
     ) -> MetaResult<NotificationVersion> {
-        let StreamingJob::Table(None, table) = stream_job else {
+        let StreamingJob::Table(source, table) = stream_job else {
             unreachable!("unexpected job: {stream_job:?}")
         };
 
         self.catalog_manager
-            .finish_replace_table_procedure(table, table_col_index_mapping)
+            .finish_replace_table_procedure(source.as_ref(), table, table_col_index_mapping)
             .await
     }
 
     async fn cancel_replace_table(&self, stream_job: &StreamingJob) -> MetaResult<()> {
-        let StreamingJob::Table(None, table) = stream_job else {
+        let StreamingJob::Table(_, table) = stream_job else {
             unreachable!("unexpected job: {stream_job:?}")
         };
 
@@ -841,4 +954,61 @@ where
             }
         }
     }
+
+    async fn alter_relation_owner(
+        &self,
+        relation: OwnerRelation,
+        owner_id: u32,
+    ) -> MetaResult<NotificationVersion> {
+        match relation {
+            OwnerRelation::TableId(table_id) => {
+                self.catalog_manager
+                    .alter_table_owner(table_id, owner_id)
+                    .await
+            }
+            OwnerRelation::ViewId(view_id) => {
+                self.catalog_manager
+                    .alter_view_owner(view_id, owner_id)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_source_props(
+        &self,
+        source_id: SourceId,
+        changed_props: HashMap<String, String>,
+        migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> MetaResult<NotificationVersion> {
+        let rate_limit = changed_props
+            .get(RATE_LIMIT_KEY)
+            .map(|v| v.parse::<u32>())
+            .transpose()
+            .map_err(|e| anyhow!("invalid {}: {}", RATE_LIMIT_KEY, e))?;
+        let connection_props_changed = changed_props.keys().any(|k| k != RATE_LIMIT_KEY);
+
+        // 1. update the persisted source catalog and notify the frontends.
+        let (version, source) = self
+            .catalog_manager
+            .alter_source_props(source_id, changed_props)
+            .await?;
+
+        // 2. switch the split discovery worker to the new properties and, if requested,
+        // translate the offsets of the assigned splits onto the new cluster. A pure rate limit
+        // change does not affect split discovery, so the worker is kept as is.
+        if connection_props_changed || migrate_offsets_timestamp_millis.is_some() {
+            self.source_manager
+                .migrate_source(&source, migrate_offsets_timestamp_millis)
+                .await?;
+        }
+
+        // 3. broadcast the new rate limit to the running source executors.
+        if let Some(rate_limit) = rate_limit {
+            self.source_manager
+                .apply_rate_limit(source_id, rate_limit)
+                .await?;
+        }
+
+        Ok(version)
+    }
 }
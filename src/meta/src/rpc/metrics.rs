@@ -127,6 +127,8 @@ pub struct MetaMetrics {
     pub compact_skip_frequency: IntCounterVec,
     /// Bytes of lsm tree needed to reach balance
     pub compact_pending_bytes: IntGaugeVec,
+    /// Compactor CPU cores suggested to digest the pending compaction bytes
+    pub compactor_suggested_cores: IntGauge,
     /// Per level compression ratio
     pub compact_level_compression_ratio: GenericGaugeVec<AtomicF64>,
     /// Per level number of running compaction task
@@ -139,6 +141,15 @@ pub struct MetaMetrics {
     pub state_table_count: IntGaugeVec,
     pub branched_sst_count: IntGaugeVec,
 
+    /// ********************************** DR replication ************************************
+    /// Physical time in ms between the latest committed epoch and the latest epoch whose SST
+    /// objects are all in the secondary object store.
+    pub dr_replication_lag_ms: IntGauge,
+    /// Total number of SST objects copied to the secondary object store.
+    pub dr_replicated_object_count: IntCounter,
+    /// Total number of failed replication rounds.
+    pub dr_replication_failure_count: IntCounter,
+
     /// ********************************** Object Store ************************************
     // Object store related metrics (for backup/restore and version checkpoint)
     pub object_store_metric: Arc<ObjectStoreMetrics>,
@@ -434,6 +445,13 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let compactor_suggested_cores = register_int_gauge_with_registry!(
+            "storage_compactor_suggested_cores",
+            "compactor cpu cores suggested to digest the pending compaction bytes",
+            registry
+        )
+        .unwrap();
+
         let compact_level_compression_ratio = register_gauge_vec_with_registry!(
             "storage_compact_level_compression_ratio",
             "compression ratio of each level of the lsm tree",
@@ -544,6 +562,27 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let dr_replication_lag_ms = register_int_gauge_with_registry!(
+            "dr_replication_lag_ms",
+            "Physical time in ms between the committed epoch and the replicated epoch",
+            registry
+        )
+        .unwrap();
+
+        let dr_replicated_object_count = register_int_counter_with_registry!(
+            "dr_replicated_object_count",
+            "Total number of SST objects copied to the secondary object store",
+            registry
+        )
+        .unwrap();
+
+        let dr_replication_failure_count = register_int_counter_with_registry!(
+            "dr_replication_failure_count",
+            "Total number of failed DR replication rounds",
+            registry
+        )
+        .unwrap();
+
         Self {
             registry,
             grpc_latency,
@@ -588,6 +627,7 @@ impl MetaMetrics {
             worker_num,
             meta_type,
             compact_pending_bytes,
+            compactor_suggested_cores,
             compact_level_compression_ratio,
             level_compact_task_cnt,
             object_store_metric,
@@ -601,6 +641,9 @@ impl MetaMetrics {
             move_state_table_count,
             state_table_count,
             branched_sst_count,
+            dr_replication_lag_ms,
+            dr_replicated_object_count,
+            dr_replication_failure_count,
         }
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod auth;
 mod cloud_provider;
 pub mod ddl_controller;
 mod election_client;
@@ -48,13 +48,14 @@ use tokio::sync::watch;
 use tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender};
 use tokio::task::JoinHandle;
 
+use super::auth::MetaAdminAuth;
 use super::intercept::MetricsMiddlewareLayer;
 use super::service::health_service::HealthServiceImpl;
 use super::service::notification_service::NotificationServiceImpl;
 use super::service::scale_service::ScaleServiceImpl;
 use super::service::serving_service::ServingServiceImpl;
 use super::DdlServiceImpl;
-use crate::backup_restore::BackupManager;
+use crate::backup_restore::{self, BackupManager};
 use crate::barrier::{BarrierScheduler, GlobalBarrierManager};
 use crate::hummock::{CompactionScheduler, HummockManager};
 use crate::manager::{
@@ -484,6 +485,30 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         compactor_manager.clone(),
     ));
 
+    let replication_manager = match &env.opts.dr_secondary_object_store {
+        Some(secondary_location) => Some(
+            hummock::ReplicationManager::new(
+                env.clone(),
+                hummock_manager.clone(),
+                backup_manager.clone(),
+                secondary_location.clone(),
+                meta_metrics.clone(),
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    let standby_manager = match env.opts.dr_standby {
+        true => {
+            // A standby cluster serves reads only: reject epoch commits at the storage
+            // layer until it is promoted to primary.
+            hummock_manager.disable_commit_epoch().await;
+            Some(hummock::StandbyManager::new(hummock_manager.clone()))
+        }
+        false => None,
+    };
+
     let mut aws_cli = None;
     if let Some(my_vpc_id) = &env.opts.vpc_id
         && let Some(security_group_id) = &env.opts.security_group_id
@@ -505,6 +530,11 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
 
     let user_srv = UserServiceImpl::<S>::new(env.clone(), catalog_manager.clone());
 
+    let admin_auth = Arc::new(MetaAdminAuth::new(
+        env.opts.admin_token.clone(),
+        env.opts.readonly_token.clone(),
+    ));
+
     let scale_srv = ScaleServiceImpl::<S>::new(
         barrier_scheduler.clone(),
         fragment_manager.clone(),
@@ -512,20 +542,26 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         source_manager,
         catalog_manager.clone(),
         stream_manager.clone(),
+        admin_auth.clone(),
     );
 
-    let cluster_srv = ClusterServiceImpl::<S>::new(cluster_manager.clone());
+    let cluster_srv = ClusterServiceImpl::<S>::new(cluster_manager.clone(), admin_auth.clone());
     let stream_srv = StreamServiceImpl::<S>::new(
         env.clone(),
         barrier_scheduler.clone(),
+        barrier_manager.clone(),
         stream_manager.clone(),
         catalog_manager.clone(),
         fragment_manager.clone(),
+        admin_auth.clone(),
     );
     let hummock_srv = HummockServiceImpl::new(
         hummock_manager.clone(),
         vacuum_manager.clone(),
         fragment_manager.clone(),
+        replication_manager.clone(),
+        standby_manager.clone(),
+        admin_auth.clone(),
     );
     let notification_srv = NotificationServiceImpl::new(
         env.clone(),
@@ -537,7 +573,7 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         serving_vnode_mapping.clone(),
     );
     let health_srv = HealthServiceImpl::new();
-    let backup_srv = BackupServiceImpl::new(backup_manager);
+    let backup_srv = BackupServiceImpl::new(backup_manager.clone(), admin_auth);
     let telemetry_srv = TelemetryInfoServiceImpl::new(meta_store.clone());
     let system_params_srv = SystemParamsServiceImpl::new(system_params_manager.clone());
     let serving_srv =
@@ -563,6 +599,18 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         compaction_scheduler,
         &env.opts,
     );
+    if let Some(replication_manager) = replication_manager {
+        sub_tasks.push(hummock::start_replication_scheduler(
+            replication_manager,
+            Duration::from_secs(env.opts.dr_replication_interval_sec),
+        ));
+    }
+    if let Some(standby_manager) = standby_manager {
+        sub_tasks.push(hummock::start_standby_apply_scheduler(
+            standby_manager,
+            Duration::from_secs(env.opts.dr_replication_interval_sec),
+        ));
+    }
     sub_tasks.push(
         start_worker_info_monitor(
             cluster_manager.clone(),
@@ -581,6 +629,10 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
         .await,
     );
     sub_tasks.push(SystemParamsManager::start_params_notifier(system_params_manager.clone()).await);
+    sub_tasks.push(backup_restore::start_auto_backup_scheduler(
+        backup_manager,
+        Duration::from_secs(60),
+    ));
     sub_tasks.push(HummockManager::hummock_timer_task(hummock_manager).await);
     sub_tasks.push(
         serving::start_serving_vnode_mapping_worker(
@@ -601,6 +653,12 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
             .await,
         );
         sub_tasks.push(GlobalBarrierManager::start(barrier_manager).await);
+
+        if env.opts.auto_rebalance_interval_sec > 0 {
+            sub_tasks.push(
+                GlobalStreamManager::start_auto_rebalance_monitor(stream_manager.clone()).await,
+            );
+        }
     }
     let (idle_send, idle_recv) = tokio::sync::oneshot::channel();
     sub_tasks.push(
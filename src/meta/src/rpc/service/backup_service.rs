@@ -23,6 +23,7 @@ use risingwave_pb::backup_service::{
 use tonic::{Request, Response, Status};
 
 use crate::backup_restore::BackupManagerRef;
+use crate::rpc::auth::{AdminRole, MetaAdminAuthRef};
 use crate::storage::MetaStore;
 
 pub struct BackupServiceImpl<S>
@@ -30,11 +31,15 @@ where
     S: MetaStore,
 {
     backup_manager: BackupManagerRef<S>,
+    admin_auth: MetaAdminAuthRef,
 }
 
 impl<S: MetaStore> BackupServiceImpl<S> {
-    pub fn new(backup_manager: BackupManagerRef<S>) -> Self {
-        Self { backup_manager }
+    pub fn new(backup_manager: BackupManagerRef<S>, admin_auth: MetaAdminAuthRef) -> Self {
+        Self {
+            backup_manager,
+            admin_auth,
+        }
     }
 }
 
@@ -45,8 +50,10 @@ where
 {
     async fn backup_meta(
         &self,
-        _request: Request<BackupMetaRequest>,
+        request: Request<BackupMetaRequest>,
     ) -> Result<Response<BackupMetaResponse>, Status> {
+        self.admin_auth
+            .check(&request, "backup_meta", AdminRole::Admin)?;
         let job_id = self.backup_manager.start_backup_job().await?;
         Ok(Response::new(BackupMetaResponse { job_id }))
     }
@@ -55,6 +62,8 @@ where
         &self,
         request: Request<GetBackupJobStatusRequest>,
     ) -> Result<Response<GetBackupJobStatusResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_backup_job_status", AdminRole::ReadOnly)?;
         let job_id = request.into_inner().job_id;
         let job_status = self.backup_manager.get_backup_job_status(job_id).await? as _;
         Ok(Response::new(GetBackupJobStatusResponse {
@@ -67,6 +76,8 @@ where
         &self,
         request: Request<DeleteMetaSnapshotRequest>,
     ) -> Result<Response<DeleteMetaSnapshotResponse>, Status> {
+        self.admin_auth
+            .check(&request, "delete_meta_snapshot", AdminRole::Admin)?;
         let snapshot_ids = request.into_inner().snapshot_ids;
         self.backup_manager.delete_backups(&snapshot_ids).await?;
         Ok(Response::new(DeleteMetaSnapshotResponse {}))
@@ -74,8 +85,10 @@ where
 
     async fn get_meta_snapshot_manifest(
         &self,
-        _request: Request<GetMetaSnapshotManifestRequest>,
+        request: Request<GetMetaSnapshotManifestRequest>,
     ) -> Result<Response<GetMetaSnapshotManifestResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_meta_snapshot_manifest", AdminRole::ReadOnly)?;
         Ok(Response::new(GetMetaSnapshotManifestResponse {
             manifest: Some(self.backup_manager.manifest().deref().into()),
         }))
@@ -17,26 +17,32 @@ use risingwave_pb::meta::cluster_service_server::ClusterService;
 use risingwave_pb::meta::{
     ActivateWorkerNodeRequest, ActivateWorkerNodeResponse, AddWorkerNodeRequest,
     AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse, ListAllNodesRequest,
-    ListAllNodesResponse, UpdateWorkerNodeSchedulabilityRequest,
+    ListAllNodesResponse, UpdateWorkerNodeResourceGroupRequest,
+    UpdateWorkerNodeResourceGroupResponse, UpdateWorkerNodeSchedulabilityRequest,
     UpdateWorkerNodeSchedulabilityResponse,
 };
 use tonic::{Request, Response, Status};
 
 use crate::manager::ClusterManagerRef;
+use crate::rpc::auth::{AdminRole, MetaAdminAuthRef};
 use crate::storage::MetaStore;
 use crate::MetaError;
 
 #[derive(Clone)]
 pub struct ClusterServiceImpl<S: MetaStore> {
     cluster_manager: ClusterManagerRef<S>,
+    admin_auth: MetaAdminAuthRef,
 }
 
 impl<S> ClusterServiceImpl<S>
 where
     S: MetaStore,
 {
-    pub fn new(cluster_manager: ClusterManagerRef<S>) -> Self {
-        ClusterServiceImpl { cluster_manager }
+    pub fn new(cluster_manager: ClusterManagerRef<S>, admin_auth: MetaAdminAuthRef) -> Self {
+        ClusterServiceImpl {
+            cluster_manager,
+            admin_auth,
+        }
     }
 }
 
@@ -85,6 +91,8 @@ where
         &self,
         req: Request<UpdateWorkerNodeSchedulabilityRequest>,
     ) -> Result<Response<UpdateWorkerNodeSchedulabilityResponse>, Status> {
+        self.admin_auth
+            .check(&req, "update_worker_node_schedulability", AdminRole::Admin)?;
         let req = req.into_inner();
         let schedulability = req.get_schedulability()?;
         let worker_ids = req.worker_ids;
@@ -98,6 +106,25 @@ where
         }))
     }
 
+    /// Update the resource group label of compute nodes. Will not affect actors which are
+    /// already running on them.
+    async fn update_worker_node_resource_group(
+        &self,
+        req: Request<UpdateWorkerNodeResourceGroupRequest>,
+    ) -> Result<Response<UpdateWorkerNodeResourceGroupResponse>, Status> {
+        self.admin_auth
+            .check(&req, "update_worker_node_resource_group", AdminRole::Admin)?;
+        let req = req.into_inner();
+
+        self.cluster_manager
+            .update_resource_group(req.worker_ids, req.resource_group)
+            .await?;
+
+        Ok(Response::new(UpdateWorkerNodeResourceGroupResponse {
+            status: None,
+        }))
+    }
+
     async fn activate_worker_node(
         &self,
         request: Request<ActivateWorkerNodeRequest>,
@@ -112,6 +139,8 @@ where
         &self,
         request: Request<DeleteWorkerNodeRequest>,
     ) -> Result<Response<DeleteWorkerNodeResponse>, Status> {
+        self.admin_auth
+            .check(&request, "delete_worker_node", AdminRole::Admin)?;
         let req = request.into_inner();
         let host = req.get_host()?.clone();
         self.cluster_manager.delete_worker_node(host).await?;
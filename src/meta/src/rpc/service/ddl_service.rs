@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use anyhow::anyhow;
@@ -42,6 +42,60 @@ use crate::storage::MetaStore;
 use crate::stream::{GlobalStreamManagerRef, SourceManagerRef};
 use crate::{MetaError, MetaResult};
 
+/// Maximum number of cached DDL responses. Entries are evicted in insertion
+/// order once the capacity is reached, which is enough to cover the retry
+/// window of a timed-out request.
+const DDL_IDEMPOTENCY_CACHE_CAPACITY: usize = 1024;
+
+/// Caches the responses of completed `create_*` calls keyed by the
+/// client-provided idempotency key, so that a retry of a timed-out request
+/// returns the original result instead of an "already exists" error.
+///
+/// Responses are stored in their encoded form to keep the cache untyped across
+/// the different response messages. An empty key disables caching for that
+/// request.
+#[derive(Default)]
+struct DdlIdempotencyCache {
+    inner: parking_lot::Mutex<DdlIdempotencyCacheInner>,
+}
+
+#[derive(Default)]
+struct DdlIdempotencyCacheInner {
+    responses: HashMap<String, Vec<u8>>,
+    insertion_order: VecDeque<String>,
+}
+
+impl DdlIdempotencyCache {
+    fn get<T: prost::Message + Default>(&self, key: &str) -> Option<T> {
+        if key.is_empty() {
+            return None;
+        }
+        let inner = self.inner.lock();
+        let encoded = inner.responses.get(key)?;
+        // The cache is keyed by a client-generated random token, so a hit
+        // always decodes as the message type of the original request.
+        Some(T::decode(encoded.as_slice()).expect("corrupted cached DDL response"))
+    }
+
+    fn insert<T: prost::Message>(&self, key: &str, response: &T) {
+        if key.is_empty() {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        if inner
+            .responses
+            .insert(key.to_owned(), response.encode_to_vec())
+            .is_none()
+        {
+            inner.insertion_order.push_back(key.to_owned());
+            if inner.insertion_order.len() > DDL_IDEMPOTENCY_CACHE_CAPACITY {
+                let evicted = inner.insertion_order.pop_front().unwrap();
+                inner.responses.remove(&evicted);
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DdlServiceImpl<S: MetaStore> {
     env: MetaSrvEnv<S>,
@@ -49,6 +103,7 @@ pub struct DdlServiceImpl<S: MetaStore> {
     catalog_manager: CatalogManagerRef<S>,
     ddl_controller: DdlController<S>,
     aws_client: Arc<Option<AwsEc2Client>>,
+    idempotency_cache: Arc<DdlIdempotencyCache>,
 }
 
 impl<S> DdlServiceImpl<S>
@@ -82,6 +137,7 @@ where
             catalog_manager,
             ddl_controller,
             aws_client: aws_cli_ref,
+            idempotency_cache: Arc::new(DdlIdempotencyCache::default()),
         }
     }
 }
@@ -96,6 +152,9 @@ where
         request: Request<CreateDatabaseRequest>,
     ) -> Result<Response<CreateDatabaseResponse>, Status> {
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let id = self.gen_unique_id::<{ IdCategory::Database }>().await?;
         let mut database = req.get_db()?.clone();
         database.id = id;
@@ -104,11 +163,13 @@ where
             .run_command(DdlCommand::CreateDatabase(database))
             .await?;
 
-        Ok(Response::new(CreateDatabaseResponse {
+        let resp = CreateDatabaseResponse {
             status: None,
             database_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_database(
@@ -134,6 +195,9 @@ where
         request: Request<CreateSchemaRequest>,
     ) -> Result<Response<CreateSchemaResponse>, Status> {
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let id = self.gen_unique_id::<{ IdCategory::Schema }>().await?;
         let mut schema = req.get_schema()?.clone();
         schema.id = id;
@@ -142,11 +206,13 @@ where
             .run_command(DdlCommand::CreateSchema(schema))
             .await?;
 
-        Ok(Response::new(CreateSchemaResponse {
+        let resp = CreateSchemaResponse {
             status: None,
             schema_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_schema(
@@ -169,7 +235,11 @@ where
         &self,
         request: Request<CreateSourceRequest>,
     ) -> Result<Response<CreateSourceResponse>, Status> {
-        let mut source = request.into_inner().get_source()?.clone();
+        let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
+        let mut source = req.get_source()?.clone();
 
         // validate connection before starting the DDL procedure
         if let Some(connection_id) = source.connection_id {
@@ -183,11 +253,13 @@ where
             .ddl_controller
             .run_command(DdlCommand::CreateSource(source))
             .await?;
-        Ok(Response::new(CreateSourceResponse {
+        let resp = CreateSourceResponse {
             status: None,
             source_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_source(
@@ -213,6 +285,9 @@ where
         self.env.idle_manager().record_activity();
 
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let sink = req.get_sink()?.clone();
         let fragment_graph = req.get_fragment_graph()?.clone();
 
@@ -230,11 +305,13 @@ where
             .run_command(DdlCommand::CreateStreamingJob(stream_job, fragment_graph))
             .await?;
 
-        Ok(Response::new(CreateSinkResponse {
+        let resp = CreateSinkResponse {
             status: None,
             sink_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_sink(
@@ -261,6 +338,9 @@ where
         self.env.idle_manager().record_activity();
 
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let mview = req.get_materialized_view()?.clone();
         let fragment_graph = req.get_fragment_graph()?.clone();
 
@@ -273,11 +353,13 @@ where
             .run_command(DdlCommand::CreateStreamingJob(stream_job, fragment_graph))
             .await?;
 
-        Ok(Response::new(CreateMaterializedViewResponse {
+        let resp = CreateMaterializedViewResponse {
             status: None,
             table_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_materialized_view(
@@ -309,6 +391,9 @@ where
         self.env.idle_manager().record_activity();
 
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let index = req.get_index()?.clone();
         let index_table = req.get_index_table()?.clone();
         let fragment_graph = req.get_fragment_graph()?.clone();
@@ -322,11 +407,13 @@ where
             .run_command(DdlCommand::CreateStreamingJob(stream_job, fragment_graph))
             .await?;
 
-        Ok(Response::new(CreateIndexResponse {
+        let resp = CreateIndexResponse {
             status: None,
             index_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_index(
@@ -354,19 +441,27 @@ where
         request: Request<CreateFunctionRequest>,
     ) -> Result<Response<CreateFunctionResponse>, Status> {
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let id = self.gen_unique_id::<{ IdCategory::Function }>().await?;
         let mut function = req.get_function()?.clone();
         function.id = id;
-        let version = self
-            .ddl_controller
-            .run_command(DdlCommand::CreateFunction(function))
-            .await?;
+        let command = if req.or_replace {
+            // The generated id is only used if no function gets replaced.
+            DdlCommand::ReplaceFunction(function)
+        } else {
+            DdlCommand::CreateFunction(function)
+        };
+        let version = self.ddl_controller.run_command(command).await?;
 
-        Ok(Response::new(CreateFunctionResponse {
+        let resp = CreateFunctionResponse {
             status: None,
             function_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_function(
@@ -386,11 +481,68 @@ where
         }))
     }
 
+    async fn create_secret(
+        &self,
+        request: Request<CreateSecretRequest>,
+    ) -> Result<Response<CreateSecretResponse>, Status> {
+        let req = request.into_inner();
+        let id = self.gen_unique_id::<{ IdCategory::Secret }>().await?;
+        let mut secret = req.get_secret()?.clone();
+        secret.id = id;
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::CreateSecret(secret))
+            .await?;
+
+        Ok(Response::new(CreateSecretResponse {
+            status: None,
+            secret_id: id,
+            version,
+        }))
+    }
+
+    async fn alter_secret(
+        &self,
+        request: Request<AlterSecretRequest>,
+    ) -> Result<Response<AlterSecretResponse>, Status> {
+        let req = request.into_inner();
+        let secret = req.get_secret()?.clone();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterSecret(secret))
+            .await?;
+
+        Ok(Response::new(AlterSecretResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn drop_secret(
+        &self,
+        request: Request<DropSecretRequest>,
+    ) -> Result<Response<DropSecretResponse>, Status> {
+        let request = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::DropSecret(request.secret_id))
+            .await?;
+
+        Ok(Response::new(DropSecretResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn create_table(
         &self,
         request: Request<CreateTableRequest>,
     ) -> Result<Response<CreateTableResponse>, Status> {
         let request = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&request.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let mut source = request.source;
         let mut mview = request.materialized_view.unwrap();
         let mut fragment_graph = request.fragment_graph.unwrap();
@@ -434,11 +586,14 @@ where
             .run_command(DdlCommand::CreateStreamingJob(stream_job, fragment_graph))
             .await?;
 
-        Ok(Response::new(CreateTableResponse {
+        let resp = CreateTableResponse {
             status: None,
             table_id,
             version,
-        }))
+        };
+        self.idempotency_cache
+            .insert(&request.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_table(
@@ -468,20 +623,28 @@ where
         request: Request<CreateViewRequest>,
     ) -> Result<Response<CreateViewResponse>, Status> {
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         let mut view = req.get_view()?.clone();
         let id = self.gen_unique_id::<{ IdCategory::Table }>().await?;
         view.id = id;
 
-        let version = self
-            .ddl_controller
-            .run_command(DdlCommand::CreateView(view))
-            .await?;
+        let command = if req.or_replace {
+            // The generated id is only used if no view gets replaced.
+            DdlCommand::ReplaceView(view)
+        } else {
+            DdlCommand::CreateView(view)
+        };
+        let version = self.ddl_controller.run_command(command).await?;
 
-        Ok(Response::new(CreateViewResponse {
+        let resp = CreateViewResponse {
             status: None,
             view_id: id,
             version,
-        }))
+        };
+        self.idempotency_cache.insert(&req.idempotency_key, &resp);
+        Ok(Response::new(resp))
     }
 
     async fn drop_view(
@@ -514,8 +677,22 @@ where
     ) -> Result<Response<ReplaceTablePlanResponse>, Status> {
         let req = request.into_inner();
 
-        let stream_job = StreamingJob::Table(None, req.table.unwrap());
-        let fragment_graph = req.fragment_graph.unwrap();
+        let source = req.source;
+        let mut fragment_graph = req.fragment_graph.unwrap();
+        // If the table has a connector, the source keeps its original ID. Fill it into the
+        // source node of the new fragment graph, like we do on `create_table`.
+        if let Some(source) = &source {
+            let source_id = source.id;
+            for fragment in fragment_graph.fragments.values_mut() {
+                visit_fragment(fragment, |node_body| {
+                    if let NodeBody::Source(source_node) = node_body {
+                        source_node.source_inner.as_mut().unwrap().source_id = source_id;
+                    }
+                });
+            }
+        }
+
+        let stream_job = StreamingJob::Table(source, req.table.unwrap());
         let table_col_index_mapping =
             ColIndexMapping::from_protobuf(&req.table_col_index_mapping.unwrap());
 
@@ -573,6 +750,44 @@ where
         }))
     }
 
+    async fn alter_relation_owner(
+        &self,
+        request: Request<AlterRelationOwnerRequest>,
+    ) -> Result<Response<AlterRelationOwnerResponse>, Status> {
+        let AlterRelationOwnerRequest { relation, owner_id } = request.into_inner();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterRelationOwner(relation.unwrap(), owner_id))
+            .await?;
+        Ok(Response::new(AlterRelationOwnerResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_source_props(
+        &self,
+        request: Request<AlterSourcePropsRequest>,
+    ) -> Result<Response<AlterSourcePropsResponse>, Status> {
+        let AlterSourcePropsRequest {
+            source_id,
+            changed_props,
+            migrate_offsets_timestamp_millis,
+        } = request.into_inner();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterSourceProps(
+                source_id,
+                changed_props,
+                migrate_offsets_timestamp_millis,
+            ))
+            .await?;
+        Ok(Response::new(AlterSourcePropsResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn get_ddl_progress(
         &self,
         _request: Request<GetDdlProgressRequest>,
@@ -582,11 +797,23 @@ where
         }))
     }
 
+    async fn get_backfill_progress(
+        &self,
+        _request: Request<GetBackfillProgressRequest>,
+    ) -> Result<Response<GetBackfillProgressResponse>, Status> {
+        Ok(Response::new(GetBackfillProgressResponse {
+            backfill_progress: self.ddl_controller.get_backfill_progress().await,
+        }))
+    }
+
     async fn create_connection(
         &self,
         request: Request<CreateConnectionRequest>,
     ) -> Result<Response<CreateConnectionResponse>, Status> {
         let req = request.into_inner();
+        if let Some(resp) = self.idempotency_cache.get(&req.idempotency_key) {
+            return Ok(Response::new(resp));
+        }
         if req.payload.is_none() {
             return Err(Status::invalid_argument("request is empty"));
         }
@@ -647,10 +874,12 @@ where
                     .run_command(DdlCommand::CreateConnection(connection))
                     .await?;
 
-                Ok(Response::new(CreateConnectionResponse {
+                let resp = CreateConnectionResponse {
                     connection_id: id,
                     version,
-                }))
+                };
+                self.idempotency_cache.insert(&req.idempotency_key, &resp);
+                Ok(Response::new(resp))
             }
         }
     }
@@ -682,6 +911,43 @@ where
         }))
     }
 
+    async fn drop_owned(
+        &self,
+        request: Request<DropOwnedRequest>,
+    ) -> Result<Response<DropOwnedResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::DropOwned(req.user_ids))
+            .await?;
+
+        Ok(Response::new(DropOwnedResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn reassign_owned(
+        &self,
+        request: Request<ReassignOwnedRequest>,
+    ) -> Result<Response<ReassignOwnedResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::ReassignOwned(
+                req.old_owner_ids,
+                req.new_owner_id,
+            ))
+            .await?;
+
+        Ok(Response::new(ReassignOwnedResponse {
+            status: None,
+            version,
+        }))
+    }
+
     #[cfg_attr(coverage, no_coverage)]
     async fn get_tables(
         &self,
@@ -23,8 +23,11 @@ use risingwave_pb::hummock::*;
 use tonic::{Request, Response, Status};
 
 use crate::hummock::compaction::ManualCompactionOption;
-use crate::hummock::{HummockManagerRef, VacuumManagerRef};
+use crate::hummock::{
+    HummockManagerRef, ReplicationManagerRef, StandbyManagerRef, VacuumManagerRef,
+};
 use crate::manager::FragmentManagerRef;
+use crate::rpc::auth::{AdminRole, MetaAdminAuthRef};
 use crate::rpc::service::RwReceiverStream;
 use crate::storage::MetaStore;
 
@@ -35,6 +38,11 @@ where
     hummock_manager: HummockManagerRef<S>,
     vacuum_manager: VacuumManagerRef<S>,
     fragment_manager: FragmentManagerRef<S>,
+    /// `None` if cross-region replication is not configured.
+    replication_manager: Option<ReplicationManagerRef<S>>,
+    /// `None` if this cluster is not a DR standby.
+    standby_manager: Option<StandbyManagerRef<S>>,
+    admin_auth: MetaAdminAuthRef,
 }
 
 impl<S> HummockServiceImpl<S>
@@ -45,11 +53,17 @@ where
         hummock_manager: HummockManagerRef<S>,
         vacuum_trigger: VacuumManagerRef<S>,
         fragment_manager: FragmentManagerRef<S>,
+        replication_manager: Option<ReplicationManagerRef<S>>,
+        standby_manager: Option<StandbyManagerRef<S>>,
+        admin_auth: MetaAdminAuthRef,
     ) -> Self {
         HummockServiceImpl {
             hummock_manager,
             vacuum_manager: vacuum_trigger,
             fragment_manager,
+            replication_manager,
+            standby_manager,
+            admin_auth,
         }
     }
 }
@@ -111,8 +125,10 @@ where
 
     async fn disable_commit_epoch(
         &self,
-        _request: Request<DisableCommitEpochRequest>,
+        request: Request<DisableCommitEpochRequest>,
     ) -> Result<Response<DisableCommitEpochResponse>, Status> {
+        self.admin_auth
+            .check(&request, "disable_commit_epoch", AdminRole::Admin)?;
         let version = self.hummock_manager.disable_commit_epoch().await;
         Ok(Response::new(DisableCommitEpochResponse {
             current_version: Some(version),
@@ -374,6 +390,8 @@ where
         &self,
         request: Request<TriggerFullGcRequest>,
     ) -> Result<Response<TriggerFullGcResponse>, Status> {
+        self.admin_auth
+            .check(&request, "trigger_full_gc", AdminRole::Admin)?;
         self.vacuum_manager
             .start_full_gc(Duration::from_secs(
                 request.into_inner().sst_retention_time_sec,
@@ -384,8 +402,13 @@ where
 
     async fn rise_ctl_get_pinned_versions_summary(
         &self,
-        _request: Request<RiseCtlGetPinnedVersionsSummaryRequest>,
+        request: Request<RiseCtlGetPinnedVersionsSummaryRequest>,
     ) -> Result<Response<RiseCtlGetPinnedVersionsSummaryResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_get_pinned_versions_summary",
+            AdminRole::ReadOnly,
+        )?;
         let pinned_versions = self.hummock_manager.list_pinned_version().await;
         let workers = self
             .hummock_manager
@@ -401,8 +424,13 @@ where
 
     async fn rise_ctl_get_pinned_snapshots_summary(
         &self,
-        _request: Request<RiseCtlGetPinnedSnapshotsSummaryRequest>,
+        request: Request<RiseCtlGetPinnedSnapshotsSummaryRequest>,
     ) -> Result<Response<RiseCtlGetPinnedSnapshotsSummaryResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_get_pinned_snapshots_summary",
+            AdminRole::ReadOnly,
+        )?;
         let pinned_snapshots = self.hummock_manager.list_pinned_snapshot().await;
         let workers = self
             .hummock_manager
@@ -428,8 +456,13 @@ where
 
     async fn rise_ctl_list_compaction_group(
         &self,
-        _request: Request<RiseCtlListCompactionGroupRequest>,
+        request: Request<RiseCtlListCompactionGroupRequest>,
     ) -> Result<Response<RiseCtlListCompactionGroupResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_list_compaction_group",
+            AdminRole::ReadOnly,
+        )?;
         let compaction_groups = self.hummock_manager.list_compaction_group().await;
         Ok(Response::new(RiseCtlListCompactionGroupResponse {
             status: None,
@@ -441,6 +474,11 @@ where
         &self,
         request: Request<RiseCtlUpdateCompactionConfigRequest>,
     ) -> Result<Response<RiseCtlUpdateCompactionConfigResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_update_compaction_config",
+            AdminRole::Admin,
+        )?;
         let RiseCtlUpdateCompactionConfigRequest {
             compaction_group_ids,
             configs,
@@ -495,6 +533,8 @@ where
         &self,
         request: Request<SplitCompactionGroupRequest>,
     ) -> Result<Response<SplitCompactionGroupResponse>, Status> {
+        self.admin_auth
+            .check(&request, "split_compaction_group", AdminRole::Admin)?;
         let req = request.into_inner();
         let new_group_id = self
             .hummock_manager
@@ -505,24 +545,39 @@ where
 
     async fn rise_ctl_pause_version_checkpoint(
         &self,
-        _request: Request<RiseCtlPauseVersionCheckpointRequest>,
+        request: Request<RiseCtlPauseVersionCheckpointRequest>,
     ) -> Result<Response<RiseCtlPauseVersionCheckpointResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_pause_version_checkpoint",
+            AdminRole::Admin,
+        )?;
         self.hummock_manager.pause_version_checkpoint();
         Ok(Response::new(RiseCtlPauseVersionCheckpointResponse {}))
     }
 
     async fn rise_ctl_resume_version_checkpoint(
         &self,
-        _request: Request<RiseCtlResumeVersionCheckpointRequest>,
+        request: Request<RiseCtlResumeVersionCheckpointRequest>,
     ) -> Result<Response<RiseCtlResumeVersionCheckpointResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_resume_version_checkpoint",
+            AdminRole::Admin,
+        )?;
         self.hummock_manager.resume_version_checkpoint();
         Ok(Response::new(RiseCtlResumeVersionCheckpointResponse {}))
     }
 
     async fn rise_ctl_get_checkpoint_version(
         &self,
-        _request: Request<RiseCtlGetCheckpointVersionRequest>,
+        request: Request<RiseCtlGetCheckpointVersionRequest>,
     ) -> Result<Response<RiseCtlGetCheckpointVersionResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_get_checkpoint_version",
+            AdminRole::ReadOnly,
+        )?;
         let checkpoint_version = self.hummock_manager.get_checkpoint_version().await;
         Ok(Response::new(RiseCtlGetCheckpointVersionResponse {
             checkpoint_version: Some(checkpoint_version),
@@ -531,8 +586,13 @@ where
 
     async fn rise_ctl_list_compaction_status(
         &self,
-        _request: Request<RiseCtlListCompactionStatusRequest>,
+        request: Request<RiseCtlListCompactionStatusRequest>,
     ) -> Result<Response<RiseCtlListCompactionStatusResponse>, Status> {
+        self.admin_auth.check(
+            &request,
+            "rise_ctl_list_compaction_status",
+            AdminRole::ReadOnly,
+        )?;
         let (compaction_statuses, task_assignment) =
             self.hummock_manager.list_compaction_status().await;
         let task_progress = self.hummock_manager.compactor_manager.get_progress();
@@ -542,4 +602,64 @@ where
             task_progress,
         }))
     }
+
+    async fn list_compact_task_history(
+        &self,
+        _request: Request<ListCompactTaskHistoryRequest>,
+    ) -> Result<Response<ListCompactTaskHistoryResponse>, Status> {
+        let task_summaries = self.hummock_manager.list_compact_task_history().await;
+        Ok(Response::new(ListCompactTaskHistoryResponse {
+            task_summaries,
+        }))
+    }
+
+    async fn get_dr_status(
+        &self,
+        _request: Request<GetDrStatusRequest>,
+    ) -> Result<Response<GetDrStatusResponse>, Status> {
+        let status = match &self.replication_manager {
+            Some(replication_manager) => replication_manager.get_dr_status().await,
+            None => GetDrStatusResponse::default(),
+        };
+        Ok(Response::new(status))
+    }
+
+    async fn promote_dr_standby(
+        &self,
+        request: Request<PromoteDrStandbyRequest>,
+    ) -> Result<Response<PromoteDrStandbyResponse>, Status> {
+        self.admin_auth
+            .check(&request, "promote_dr_standby", AdminRole::Admin)?;
+        let Some(standby_manager) = &self.standby_manager else {
+            return Err(Status::failed_precondition(
+                "this cluster is not a DR standby",
+            ));
+        };
+        let version_id = standby_manager.promote().await?;
+        Ok(Response::new(PromoteDrStandbyResponse { version_id }))
+    }
+
+    async fn get_version_stats(
+        &self,
+        request: Request<GetVersionStatsRequest>,
+    ) -> Result<Response<GetVersionStatsResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_version_stats", AdminRole::ReadOnly)?;
+        let stats = self.hummock_manager.get_version_stats().await;
+        Ok(Response::new(GetVersionStatsResponse {
+            stats: Some(stats),
+        }))
+    }
+
+    async fn get_compactor_scale_info(
+        &self,
+        request: Request<GetCompactorScaleInfoRequest>,
+    ) -> Result<Response<GetCompactorScaleInfoResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_compactor_scale_info", AdminRole::ReadOnly)?;
+        let info = self.hummock_manager.get_compactor_scale_info().await;
+        Ok(Response::new(GetCompactorScaleInfoResponse {
+            info: Some(info),
+        }))
+    }
 }
@@ -14,7 +14,7 @@
 
 use itertools::Itertools;
 use risingwave_pb::backup_service::MetaBackupManifestId;
-use risingwave_pb::catalog::Table;
+use risingwave_pb::catalog::{Secret, Table};
 use risingwave_pb::common::worker_node::State::Running;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::hummock::WriteLimits;
@@ -74,8 +74,18 @@ where
 
     async fn get_catalog_snapshot(&self) -> (Catalog, Vec<UserInfo>, NotificationVersion) {
         let catalog_guard = self.catalog_manager.get_catalog_core_guard().await;
-        let (databases, schemas, tables, sources, sinks, indexes, views, functions, connections) =
-            catalog_guard.database.get_catalog();
+        let (
+            databases,
+            schemas,
+            tables,
+            sources,
+            sinks,
+            indexes,
+            views,
+            functions,
+            connections,
+            secrets,
+        ) = catalog_guard.database.get_catalog();
         let users = catalog_guard.user.list_users();
         let notification_version = self.env.notification_manager().current_version().await;
         (
@@ -89,6 +99,7 @@ where
                 views,
                 functions,
                 connections,
+                secrets,
             ),
             users,
             notification_version,
@@ -145,10 +156,29 @@ where
 
     async fn frontend_subscribe(&self) -> MetaSnapshot {
         let (
-            (databases, schemas, tables, sources, sinks, indexes, views, functions, connections),
+            (
+                databases,
+                schemas,
+                tables,
+                sources,
+                sinks,
+                indexes,
+                views,
+                functions,
+                connections,
+                secrets,
+            ),
             users,
             catalog_version,
         ) = self.get_catalog_snapshot().await;
+        // Frontends only need the secret catalog entries for name resolution, not the values.
+        let secrets = secrets
+            .into_iter()
+            .map(|secret| Secret {
+                value: vec![],
+                ..secret
+            })
+            .collect();
         let (parallel_unit_mappings, parallel_unit_mapping_version) =
             self.get_parallel_unit_mapping_snapshot().await;
         let serving_parallel_unit_mappings = self.get_serving_vnode_mappings();
@@ -166,6 +196,7 @@ where
             views,
             functions,
             connections,
+            secrets,
             users,
             parallel_unit_mappings,
             nodes,
@@ -203,8 +234,21 @@ where
         }
     }
 
-    fn compute_subscribe(&self) -> MetaSnapshot {
-        MetaSnapshot::default()
+    async fn compute_subscribe(&self) -> MetaSnapshot {
+        // Compute nodes resolve secret references when building connectors, so they receive the
+        // secrets with their values.
+        let catalog_guard = self.catalog_manager.get_catalog_core_guard().await;
+        let secrets = catalog_guard.database.list_secrets();
+        let catalog_version = self.env.notification_manager().current_version().await;
+
+        MetaSnapshot {
+            secrets,
+            version: Some(SnapshotVersion {
+                catalog_version,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
     }
 }
 
@@ -246,7 +290,7 @@ where
                     .await?;
                 self.hummock_subscribe().await
             }
-            SubscribeType::Compute => self.compute_subscribe(),
+            SubscribeType::Compute => self.compute_subscribe().await,
             SubscribeType::Unspecified => unreachable!(),
         };
 
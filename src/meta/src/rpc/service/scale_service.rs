@@ -26,6 +26,7 @@ use tonic::{Request, Response, Status};
 use crate::barrier::{BarrierScheduler, Command};
 use crate::manager::{CatalogManagerRef, ClusterManagerRef, FragmentManagerRef};
 use crate::model::MetadataModel;
+use crate::rpc::auth::{AdminRole, MetaAdminAuthRef};
 use crate::storage::MetaStore;
 use crate::stream::{GlobalStreamManagerRef, ParallelUnitReschedule, SourceManagerRef};
 
@@ -36,6 +37,7 @@ pub struct ScaleServiceImpl<S: MetaStore> {
     source_manager: SourceManagerRef<S>,
     catalog_manager: CatalogManagerRef<S>,
     stream_manager: GlobalStreamManagerRef<S>,
+    admin_auth: MetaAdminAuthRef,
 }
 
 impl<S> ScaleServiceImpl<S>
@@ -49,6 +51,7 @@ where
         source_manager: SourceManagerRef<S>,
         catalog_manager: CatalogManagerRef<S>,
         stream_manager: GlobalStreamManagerRef<S>,
+        admin_auth: MetaAdminAuthRef,
     ) -> Self {
         Self {
             barrier_scheduler,
@@ -57,6 +60,7 @@ where
             source_manager,
             catalog_manager,
             stream_manager,
+            admin_auth,
         }
     }
 }
@@ -67,13 +71,22 @@ where
     S: MetaStore,
 {
     #[cfg_attr(coverage, no_coverage)]
-    async fn pause(&self, _: Request<PauseRequest>) -> Result<Response<PauseResponse>, Status> {
+    async fn pause(
+        &self,
+        request: Request<PauseRequest>,
+    ) -> Result<Response<PauseResponse>, Status> {
+        self.admin_auth.check(&request, "pause", AdminRole::Admin)?;
         self.barrier_scheduler.run_command(Command::pause()).await?;
         Ok(Response::new(PauseResponse {}))
     }
 
     #[cfg_attr(coverage, no_coverage)]
-    async fn resume(&self, _: Request<ResumeRequest>) -> Result<Response<ResumeResponse>, Status> {
+    async fn resume(
+        &self,
+        request: Request<ResumeRequest>,
+    ) -> Result<Response<ResumeResponse>, Status> {
+        self.admin_auth
+            .check(&request, "resume", AdminRole::Admin)?;
         self.barrier_scheduler
             .run_command(Command::resume())
             .await?;
@@ -83,8 +96,10 @@ where
     #[cfg_attr(coverage, no_coverage)]
     async fn get_cluster_info(
         &self,
-        _: Request<GetClusterInfoRequest>,
+        request: Request<GetClusterInfoRequest>,
     ) -> Result<Response<GetClusterInfoResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_cluster_info", AdminRole::ReadOnly)?;
         let _reschedule_job_lock = self.stream_manager.reschedule_lock.read().await;
 
         let table_fragments = self
@@ -135,6 +150,8 @@ where
         &self,
         request: Request<RescheduleRequest>,
     ) -> Result<Response<RescheduleResponse>, Status> {
+        self.admin_auth
+            .check(&request, "reschedule", AdminRole::Admin)?;
         let req = request.into_inner();
 
         let _reschedule_job_lock = self.stream_manager.reschedule_lock.write().await;
@@ -191,6 +208,8 @@ where
         &self,
         request: Request<GetReschedulePlanRequest>,
     ) -> Result<Response<GetReschedulePlanResponse>, Status> {
+        self.admin_auth
+            .check(&request, "get_reschedule_plan", AdminRole::ReadOnly)?;
         let req = request.into_inner();
 
         let _reschedule_job_lock = self.stream_manager.reschedule_lock.read().await;
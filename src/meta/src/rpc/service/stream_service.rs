@@ -16,6 +16,10 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
+use risingwave_connector::source::SplitMetaData;
+use risingwave_pb::meta::cancel_creating_jobs_request::Jobs;
+use risingwave_pb::meta::get_source_lag_response::SplitLag;
+use risingwave_pb::meta::list_source_splits_response::SplitAssignment;
 use risingwave_pb::meta::list_table_fragments_response::{
     ActorInfo, FragmentInfo, TableFragmentInfo,
 };
@@ -23,8 +27,9 @@ use risingwave_pb::meta::stream_manager_service_server::StreamManagerService;
 use risingwave_pb::meta::*;
 use tonic::{Request, Response, Status};
 
-use crate::barrier::BarrierScheduler;
+use crate::barrier::{BarrierManagerRef, BarrierScheduler};
 use crate::manager::{CatalogManagerRef, FragmentManagerRef, MetaSrvEnv};
+use crate::rpc::auth::{AdminRole, MetaAdminAuthRef};
 use crate::storage::MetaStore;
 use crate::stream::GlobalStreamManagerRef;
 
@@ -37,9 +42,11 @@ where
 {
     env: MetaSrvEnv<S>,
     barrier_scheduler: BarrierScheduler<S>,
+    barrier_manager: BarrierManagerRef<S>,
     stream_manager: GlobalStreamManagerRef<S>,
     catalog_manager: CatalogManagerRef<S>,
     fragment_manager: FragmentManagerRef<S>,
+    admin_auth: MetaAdminAuthRef,
 }
 
 impl<S> StreamServiceImpl<S>
@@ -49,16 +56,20 @@ where
     pub fn new(
         env: MetaSrvEnv<S>,
         barrier_scheduler: BarrierScheduler<S>,
+        barrier_manager: BarrierManagerRef<S>,
         stream_manager: GlobalStreamManagerRef<S>,
         catalog_manager: CatalogManagerRef<S>,
         fragment_manager: FragmentManagerRef<S>,
+        admin_auth: MetaAdminAuthRef,
     ) -> Self {
         StreamServiceImpl {
             env,
             barrier_scheduler,
+            barrier_manager,
             stream_manager,
             catalog_manager,
             fragment_manager,
+            admin_auth,
         }
     }
 }
@@ -85,10 +96,14 @@ where
         request: Request<CancelCreatingJobsRequest>,
     ) -> TonicResponse<CancelCreatingJobsResponse> {
         let req = request.into_inner();
-        let table_ids = self
-            .catalog_manager
-            .find_creating_streaming_job_ids(req.infos)
-            .await;
+        let table_ids = match req.jobs.unwrap() {
+            Jobs::Infos(infos) => {
+                self.catalog_manager
+                    .find_creating_streaming_job_ids(infos.infos)
+                    .await
+            }
+            Jobs::Ids(ids) => ids.job_ids,
+        };
         if !table_ids.is_empty() {
             self.stream_manager
                 .cancel_streaming_jobs(table_ids.into_iter().map(TableId::from).collect_vec())
@@ -138,4 +153,174 @@ where
             table_fragments: info,
         }))
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_source_splits(
+        &self,
+        request: Request<ListSourceSplitsRequest>,
+    ) -> TonicResponse<ListSourceSplitsResponse> {
+        let req = request.into_inner();
+        let assignments = self
+            .stream_manager
+            .source_manager
+            .list_source_splits(req.source_id)
+            .await?
+            .into_iter()
+            .map(|(fragment_id, actor_id, splits)| SplitAssignment {
+                fragment_id,
+                actor_id,
+                split_ids: splits.iter().map(|split| split.id().to_string()).collect(),
+            })
+            .collect_vec();
+
+        Ok(Response::new(ListSourceSplitsResponse { assignments }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_source_lag(
+        &self,
+        request: Request<GetSourceLagRequest>,
+    ) -> TonicResponse<GetSourceLagResponse> {
+        let req = request.into_inner();
+        let source = self
+            .catalog_manager
+            .list_sources()
+            .await
+            .into_iter()
+            .find(|source| source.id == req.source_id)
+            .ok_or_else(|| Status::not_found(format!("source {} not found", req.source_id)))?;
+
+        let split_lags = self
+            .stream_manager
+            .source_manager
+            .get_source_lag(&source)
+            .await?
+            .into_iter()
+            .map(|(split_id, assigned_offset, latest_offset)| SplitLag {
+                split_id: split_id.to_string(),
+                assigned_offset: assigned_offset.map(|o| o.to_string()).unwrap_or_default(),
+                latest_offset: latest_offset.to_string(),
+            })
+            .collect_vec();
+
+        Ok(Response::new(GetSourceLagResponse { split_lags }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn pause_source(
+        &self,
+        request: Request<PauseSourceRequest>,
+    ) -> TonicResponse<PauseSourceResponse> {
+        self.admin_auth
+            .check(&request, "pause_source", AdminRole::Admin)?;
+        let req = request.into_inner();
+        self.stream_manager
+            .source_manager
+            .apply_source_pause(req.source_id, true)
+            .await?;
+        Ok(Response::new(PauseSourceResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn resume_source(
+        &self,
+        request: Request<ResumeSourceRequest>,
+    ) -> TonicResponse<ResumeSourceResponse> {
+        self.admin_auth
+            .check(&request, "resume_source", AdminRole::Admin)?;
+        let req = request.into_inner();
+        self.stream_manager
+            .source_manager
+            .apply_source_pause(req.source_id, false)
+            .await?;
+        Ok(Response::new(ResumeSourceResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn pause_streaming_job(
+        &self,
+        request: Request<PauseStreamingJobRequest>,
+    ) -> TonicResponse<PauseStreamingJobResponse> {
+        self.admin_auth
+            .check(&request, "pause_streaming_job", AdminRole::Admin)?;
+        let req = request.into_inner();
+        self.stream_manager
+            .pause_streaming_job(TableId::new(req.table_id), true)
+            .await?;
+        Ok(Response::new(PauseStreamingJobResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn resume_streaming_job(
+        &self,
+        request: Request<ResumeStreamingJobRequest>,
+    ) -> TonicResponse<ResumeStreamingJobResponse> {
+        self.admin_auth
+            .check(&request, "resume_streaming_job", AdminRole::Admin)?;
+        let req = request.into_inner();
+        self.stream_manager
+            .pause_streaming_job(TableId::new(req.table_id), false)
+            .await?;
+        Ok(Response::new(ResumeStreamingJobResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_barrier_latency(
+        &self,
+        request: Request<ListBarrierLatencyRequest>,
+    ) -> TonicResponse<ListBarrierLatencyResponse> {
+        let req = request.into_inner();
+        let entries = self
+            .barrier_manager
+            .list_barrier_latency(req.limit as usize);
+        Ok(Response::new(ListBarrierLatencyResponse { entries }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_recovery_events(
+        &self,
+        _request: Request<ListRecoveryEventsRequest>,
+    ) -> TonicResponse<ListRecoveryEventsResponse> {
+        let events = self.barrier_manager.list_recovery_events();
+        Ok(Response::new(ListRecoveryEventsResponse { events }))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn resume_quarantined_job(
+        &self,
+        request: Request<ResumeQuarantinedJobRequest>,
+    ) -> TonicResponse<ResumeQuarantinedJobResponse> {
+        self.admin_auth
+            .check(&request, "resume_quarantined_job", AdminRole::Admin)?;
+        let req = request.into_inner();
+        let table_id = TableId::new(req.table_id);
+        self.barrier_manager.resume_quarantined_job(table_id)?;
+        self.stream_manager
+            .pause_streaming_job(table_id, false)
+            .await?;
+        Ok(Response::new(ResumeQuarantinedJobResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn alter_parallelism(
+        &self,
+        request: Request<AlterParallelismRequest>,
+    ) -> TonicResponse<AlterParallelismResponse> {
+        self.admin_auth
+            .check(&request, "alter_parallelism", AdminRole::Admin)?;
+        let req = request.into_inner();
+        self.stream_manager
+            .alter_job_parallelism(TableId::new(req.table_id), req.parallelism as usize)
+            .await?;
+        Ok(Response::new(AlterParallelismResponse {}))
+    }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_recoveries(
+        &self,
+        _request: Request<ListRecoveriesRequest>,
+    ) -> TonicResponse<ListRecoveriesResponse> {
+        let recoveries = self.barrier_manager.list_recoveries();
+        Ok(Response::new(ListRecoveriesResponse { recoveries }))
+    }
 }
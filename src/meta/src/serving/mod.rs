@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use parking_lot::RwLock;
 use risingwave_common::hash::ParallelUnitMapping;
+use risingwave_common::util::worker_util::prefer_dedicated_serving_nodes;
 use risingwave_common::vnode_mapping::vnode_placement::place_vnode;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
@@ -111,10 +112,9 @@ pub(crate) async fn on_meta_start<S: MetaStore>(
     serving_vnode_mapping: ServingVnodeMappingRef,
 ) {
     let streaming_parallelisms = fragment_manager.running_fragment_parallelisms(None).await;
-    let (mappings, _) = serving_vnode_mapping.upsert(
-        streaming_parallelisms,
-        &cluster_manager.list_active_serving_compute_nodes().await,
-    );
+    let workers =
+        prefer_dedicated_serving_nodes(cluster_manager.list_active_serving_compute_nodes().await);
+    let (mappings, _) = serving_vnode_mapping.upsert(streaming_parallelisms, &workers);
     tracing::debug!(
         "Initialize serving vnode mapping snapshot for fragments {:?}.",
         mappings.keys()
@@ -149,7 +149,7 @@ pub(crate) async fn start_serving_vnode_mapping_worker<S: MetaStore>(
                                     if w.r#type() != WorkerType::ComputeNode || !w.property.as_ref().map_or(false, |p| p.is_serving) {
                                         continue;
                                     }
-                                    let workers = cluster_manager.list_active_serving_compute_nodes().await;
+                                    let workers = prefer_dedicated_serving_nodes(cluster_manager.list_active_serving_compute_nodes().await);
                                     let streaming_parallelisms = fragment_manager.running_fragment_parallelisms(None).await;
                                     let (mappings, _) = serving_vnode_mapping.upsert(streaming_parallelisms, &workers);
                                     tracing::debug!("Update serving vnode mapping snapshot for fragments {:?}.", mappings.keys());
@@ -159,7 +159,7 @@ pub(crate) async fn start_serving_vnode_mapping_worker<S: MetaStore>(
                                     if fragment_ids.is_empty() {
                                         continue;
                                     }
-                                    let workers = cluster_manager.list_active_serving_compute_nodes().await;
+                                    let workers = prefer_dedicated_serving_nodes(cluster_manager.list_active_serving_compute_nodes().await);
                                     let streaming_parallelisms = fragment_manager.running_fragment_parallelisms(Some(fragment_ids.into_iter().collect())).await;
                                     let (upserted, failed) = serving_vnode_mapping.upsert(streaming_parallelisms, &workers);
                                     if !upserted.is_empty() {
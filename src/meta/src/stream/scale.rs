@@ -15,18 +15,21 @@
 use std::cmp::{min, Ordering};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::iter::repeat;
+use std::time::Duration;
 
 use anyhow::anyhow;
+use either::Either;
 use futures::future::BoxFuture;
 use itertools::Itertools;
 use num_integer::Integer;
 use num_traits::abs;
 use risingwave_common::bail;
 use risingwave_common::buffer::{Bitmap, BitmapBuilder};
+use risingwave_common::catalog::TableId;
 use risingwave_common::hash::{ActorMapping, ParallelUnitId, VirtualNode};
 use risingwave_common::util::iter_util::ZipEqDebug;
 use risingwave_pb::common::{ActorInfo, ParallelUnit, WorkerNode};
-use risingwave_pb::meta::get_reschedule_plan_request::{Policy, StableResizePolicy};
+use risingwave_pb::meta::get_reschedule_plan_request::{Policy, StableResizePolicy, WorkerChanges};
 use risingwave_pb::meta::table_fragments::actor_status::ActorState;
 use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
 use risingwave_pb::meta::table_fragments::{self, ActorStatus, Fragment};
@@ -35,13 +38,15 @@ use risingwave_pb::stream_plan::{DispatcherType, FragmentTypeFlag, StreamActor,
 use risingwave_pb::stream_service::{
     BroadcastActorInfoTableRequest, BuildActorsRequest, UpdateActorsRequest,
 };
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::barrier::{Command, Reschedule};
 use crate::manager::{IdCategory, WorkerId};
 use crate::model::{ActorId, DispatcherId, FragmentId, TableFragments};
 use crate::storage::{MetaStore, MetaStoreError, Transaction, DEFAULT_COLUMN_FAMILY};
-use crate::stream::GlobalStreamManager;
+use crate::stream::{GlobalStreamManager, GlobalStreamManagerRef};
 use crate::{MetaError, MetaResult};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -1808,4 +1813,259 @@ where
             Policy::StableResizePolicy(resize) => self.generate_stable_resize_plan(resize).await,
         }
     }
+
+    /// Start a background monitor that periodically checks for parallelism imbalance, e.g. after
+    /// workers joined or left, and automatically applies reschedule plans. Opt-in via the
+    /// `auto_rebalance_interval_sec` config.
+    pub async fn start_auto_rebalance_monitor(
+        stream_manager: GlobalStreamManagerRef<S>,
+    ) -> (JoinHandle<()>, Sender<()>) {
+        let check_interval =
+            Duration::from_secs(stream_manager.env.opts.auto_rebalance_interval_sec);
+        let max_moves = stream_manager.env.opts.auto_rebalance_max_moves as usize;
+
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut min_interval = tokio::time::interval(check_interval);
+            min_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // Fragments that already had a pending reschedule at the previous check. Only an
+            // imbalance that persists across two consecutive checks is acted on, so that
+            // transient states, e.g. a worker that is about to re-register after a restart, do
+            // not trigger moves.
+            let mut pending_fragments = HashSet::new();
+            loop {
+                tokio::select! {
+                    // Wait for interval
+                    _ = min_interval.tick() => {},
+                    // Shutdown
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Auto rebalance monitor is stopped");
+                        return;
+                    }
+                }
+                if let Err(e) = stream_manager
+                    .auto_rebalance_round(&mut pending_fragments, max_moves)
+                    .await
+                {
+                    tracing::warn!("Failed to auto-rebalance: {}", e);
+                }
+            }
+        });
+
+        (join_handle, shutdown_tx)
+    }
+
+    /// One round of automatic rebalancing: generate a stable resize plan over all fragments and
+    /// the current set of schedulable workers, then apply it for at most `max_moves` fragments
+    /// whose imbalance persisted since the previous round.
+    async fn auto_rebalance_round(
+        &self,
+        pending_fragments: &mut HashSet<FragmentId>,
+        max_moves: usize,
+    ) -> MetaResult<()> {
+        let _reschedule_job_lock = self.reschedule_lock.write().await;
+
+        let workers = self
+            .cluster_manager
+            .list_active_streaming_compute_nodes()
+            .await;
+
+        let (unschedulable_worker_ids, schedulable_worker_ids): (Vec<_>, Vec<_>) =
+            workers.iter().partition_map(|worker| {
+                let unschedulable = worker
+                    .property
+                    .as_ref()
+                    .map(|p| p.is_unschedulable)
+                    .unwrap_or(false);
+                if unschedulable {
+                    Either::Left(worker.id)
+                } else {
+                    Either::Right(worker.id)
+                }
+            });
+
+        if schedulable_worker_ids.is_empty() {
+            return Ok(());
+        }
+
+        let worker_changes = WorkerChanges {
+            include_worker_ids: schedulable_worker_ids,
+            exclude_worker_ids: unschedulable_worker_ids,
+        };
+
+        let fragment_worker_changes = self
+            .fragment_manager
+            .list_table_fragments()
+            .await
+            .iter()
+            .flat_map(|table_fragments| table_fragments.fragment_ids())
+            .map(|fragment_id| (fragment_id, worker_changes.clone()))
+            .collect();
+
+        let plan = self
+            .generate_stable_resize_plan(StableResizePolicy {
+                fragment_worker_changes,
+            })
+            .await?;
+
+        if plan.is_empty() {
+            pending_fragments.clear();
+            return Ok(());
+        }
+
+        let actionable: HashMap<_, _> = plan
+            .iter()
+            .filter(|(fragment_id, _)| pending_fragments.contains(*fragment_id))
+            .sorted_by_key(|(fragment_id, _)| **fragment_id)
+            .take(max_moves)
+            .map(|(fragment_id, reschedule)| (*fragment_id, reschedule.clone()))
+            .collect();
+        *pending_fragments = plan.keys().copied().collect();
+
+        if actionable.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            "auto-rebalancing {} of {} imbalanced fragments",
+            actionable.len(),
+            plan.len()
+        );
+        self.reschedule_actors(actionable).await
+    }
+
+    /// Reschedule the hash-distributed fragments of a streaming job to the given parallelism,
+    /// e.g. for `ALTER MATERIALIZED VIEW .. SET PARALLELISM ..`.
+    pub async fn alter_job_parallelism(
+        &self,
+        table_id: TableId,
+        parallelism: usize,
+    ) -> MetaResult<()> {
+        if parallelism == 0 {
+            bail!("parallelism must be positive");
+        }
+
+        let _reschedule_job_lock = self.reschedule_lock.write().await;
+
+        let table_fragments = self
+            .fragment_manager
+            .select_table_fragments_by_table_id(&table_id)
+            .await?;
+
+        // Pick the target parallel units as an even, round-robin share over the schedulable
+        // workers.
+        let workers = self
+            .cluster_manager
+            .list_active_streaming_compute_nodes()
+            .await;
+        let mut worker_parallel_units = workers
+            .iter()
+            .filter(|worker| {
+                !worker
+                    .property
+                    .as_ref()
+                    .map(|p| p.is_unschedulable)
+                    .unwrap_or(false)
+            })
+            .sorted_by_key(|worker| worker.id)
+            .map(|worker| {
+                worker
+                    .parallel_units
+                    .iter()
+                    .map(|parallel_unit| parallel_unit.id)
+                    .collect::<VecDeque<_>>()
+            })
+            .collect_vec();
+
+        let available: usize = worker_parallel_units.iter().map(|units| units.len()).sum();
+        if available < parallelism {
+            bail!(
+                "parallelism {} exceeds the {} parallel units available on schedulable workers",
+                parallelism,
+                available
+            );
+        }
+
+        let mut target_parallel_unit_ids = BTreeSet::new();
+        'round_robin: loop {
+            for units in &mut worker_parallel_units {
+                if let Some(unit) = units.pop_front() {
+                    target_parallel_unit_ids.insert(unit);
+                    if target_parallel_unit_ids.len() == parallelism {
+                        break 'round_robin;
+                    }
+                }
+            }
+        }
+
+        // The downstream half of a NoShuffle pair (e.g. a Chain fragment) is rescheduled along
+        // with its upstream, which may belong to another job, so it is skipped below.
+        let all_table_fragments = self.fragment_manager.list_table_fragments().await;
+        let mut actor_map = HashMap::new();
+        for table_fragments in &all_table_fragments {
+            for fragment in table_fragments.fragments.values() {
+                for actor in &fragment.actors {
+                    actor_map.insert(actor.actor_id as ActorId, actor.clone());
+                }
+            }
+        }
+        let mut no_shuffle_source_fragment_ids = HashSet::new();
+        let mut no_shuffle_target_fragment_ids = HashSet::new();
+        Self::build_no_shuffle_relation_index(
+            &actor_map,
+            &mut no_shuffle_source_fragment_ids,
+            &mut no_shuffle_target_fragment_ids,
+        );
+
+        let mut reschedules = HashMap::new();
+        for (fragment_id, fragment) in &table_fragments.fragments {
+            if fragment.distribution_type() != FragmentDistributionType::Hash {
+                // Singleton fragments always run with parallelism 1.
+                continue;
+            }
+            if no_shuffle_target_fragment_ids.contains(fragment_id) {
+                continue;
+            }
+
+            let current_parallel_unit_ids: BTreeSet<_> = fragment
+                .actors
+                .iter()
+                .map(|actor| {
+                    table_fragments
+                        .actor_status
+                        .get(&actor.actor_id)
+                        .and_then(|status| status.parallel_unit.as_ref())
+                        .expect("actor should have a parallel unit")
+                        .id
+                })
+                .collect();
+
+            let added_parallel_units: BTreeSet<_> = target_parallel_unit_ids
+                .difference(&current_parallel_unit_ids)
+                .copied()
+                .collect();
+            let removed_parallel_units: BTreeSet<_> = current_parallel_unit_ids
+                .difference(&target_parallel_unit_ids)
+                .copied()
+                .collect();
+
+            if added_parallel_units.is_empty() && removed_parallel_units.is_empty() {
+                continue;
+            }
+
+            reschedules.insert(
+                *fragment_id,
+                ParallelUnitReschedule {
+                    added_parallel_units,
+                    removed_parallel_units,
+                },
+            );
+        }
+
+        if reschedules.is_empty() {
+            return Ok(());
+        }
+
+        self.reschedule_actors(reschedules).await
+    }
 }
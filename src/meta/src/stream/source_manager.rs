@@ -29,6 +29,8 @@ use risingwave_pb::catalog::Source;
 use risingwave_pb::connector_service::table_schema::Column;
 use risingwave_pb::connector_service::TableSchema;
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
+use risingwave_pb::stream_plan::barrier::Mutation;
+use risingwave_pb::stream_plan::{SourcePauseMutation, ThrottleMutation};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::sync::{oneshot, Mutex};
 use tokio::task::JoinHandle;
@@ -725,6 +727,230 @@ where
         Ok(())
     }
 
+    /// Switch a source to its altered properties, e.g. a mirrored kafka cluster. The connector
+    /// worker is re-created against the new properties, and if `migrate_offsets_timestamp_millis`
+    /// is given, the start offsets of all splits currently assigned to the source's actors are
+    /// re-resolved on the new cluster from that timestamp, so that consumption continues without
+    /// reprocessing history.
+    pub async fn migrate_source(
+        &self,
+        source: &Source,
+        migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> MetaResult<()> {
+        let mut core = self.core.lock().await;
+        if let Some(handle) = core.managed_sources.remove(&source.id) {
+            handle.handle.abort();
+        }
+        // if the new cluster is unreachable, the worker will refuse to start and the
+        // migration fails here.
+        Self::create_source_worker(
+            &self.connector_rpc_endpoint,
+            source,
+            &mut core.managed_sources,
+            true,
+            self.metrics.clone(),
+        )
+        .await?;
+
+        let Some(timestamp_millis) = migrate_offsets_timestamp_millis else {
+            return Ok(());
+        };
+
+        // translate the offsets of the assigned splits onto the new cluster by timestamp.
+        let properties = ConnectorProperties::extract(source.properties.clone())?;
+        let translated_splits: BTreeMap<SplitId, SplitImpl> =
+            match SplitEnumeratorImpl::create(properties).await? {
+                SplitEnumeratorImpl::Kafka(mut enumerator) => enumerator
+                    .list_splits_start_from_timestamp(timestamp_millis)
+                    .await
+                    .map_err(|e| anyhow!(e))?
+                    .into_iter()
+                    .map(|split| (split.id(), SplitImpl::Kafka(split)))
+                    .collect(),
+                _ => {
+                    return Err(anyhow!(
+                        "offset migration by timestamp is only supported for kafka sources"
+                    )
+                    .into());
+                }
+            };
+
+        let mut split_assignment: SplitAssignment = HashMap::new();
+        if let Some(fragment_ids) = core.source_fragments.get(&source.id) {
+            for fragment_id in fragment_ids {
+                let actor_ids = core
+                    .fragment_manager
+                    .get_running_actors_of_fragment(*fragment_id)
+                    .await?;
+                let mut changed_actor_splits = HashMap::new();
+                for actor_id in actor_ids {
+                    if let Some(splits) = core.actor_splits.get(&actor_id) {
+                        let splits = splits
+                            .iter()
+                            .map(|split| {
+                                translated_splits
+                                    .get(&split.id())
+                                    .cloned()
+                                    .unwrap_or_else(|| split.clone())
+                            })
+                            .collect_vec();
+                        changed_actor_splits.insert(actor_id, splits);
+                    }
+                }
+                if !changed_actor_splits.is_empty() {
+                    split_assignment.insert(*fragment_id, changed_actor_splits);
+                }
+            }
+        }
+        // the barrier scheduler will apply the assignment to the core after the command is
+        // collected, so the core lock must be released first.
+        drop(core);
+
+        if !split_assignment.is_empty() {
+            self.barrier_scheduler
+                .run_command(Command::SourceSplitAssignment(split_assignment))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply a changed `rate_limit` to the running source executors of the source by
+    /// broadcasting a throttle mutation to the actors of its source fragments. `0` means
+    /// unlimited.
+    pub async fn apply_rate_limit(&self, source_id: SourceId, rate_limit: u32) -> MetaResult<()> {
+        let core = self.core.lock().await;
+        let mut actor_rate_limits = HashMap::new();
+        if let Some(fragment_ids) = core.source_fragments.get(&source_id) {
+            for fragment_id in fragment_ids {
+                let actor_ids = core
+                    .fragment_manager
+                    .get_running_actors_of_fragment(*fragment_id)
+                    .await?;
+                actor_rate_limits
+                    .extend(actor_ids.into_iter().map(|actor_id| (actor_id, rate_limit)));
+            }
+        }
+        drop(core);
+
+        if !actor_rate_limits.is_empty() {
+            self.barrier_scheduler
+                .run_command(Command::Plain(Some(Mutation::Throttle(ThrottleMutation {
+                    actor_rate_limits,
+                }))))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Pause or resume the running source executors of the source by broadcasting a pause-source
+    /// mutation to the actors of its source fragments, without pausing the rest of the streaming
+    /// graph.
+    pub async fn apply_source_pause(&self, source_id: SourceId, pause: bool) -> MetaResult<()> {
+        let core = self.core.lock().await;
+        let mut actor_pause = HashMap::new();
+        if let Some(fragment_ids) = core.source_fragments.get(&source_id) {
+            for fragment_id in fragment_ids {
+                let actor_ids = core
+                    .fragment_manager
+                    .get_running_actors_of_fragment(*fragment_id)
+                    .await?;
+                actor_pause.extend(actor_ids.into_iter().map(|actor_id| (actor_id, pause)));
+            }
+        }
+        drop(core);
+
+        if !actor_pause.is_empty() {
+            self.barrier_scheduler
+                .run_command(Command::Plain(Some(Mutation::PauseSource(
+                    SourcePauseMutation { actor_pause },
+                ))))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// List the current split assignment of the source, per fragment and actor.
+    pub async fn list_source_splits(
+        &self,
+        source_id: SourceId,
+    ) -> MetaResult<Vec<(FragmentId, ActorId, Vec<SplitImpl>)>> {
+        let core = self.core.lock().await;
+        let mut assignments = Vec::new();
+        if let Some(fragment_ids) = core.source_fragments.get(&source_id) {
+            for fragment_id in fragment_ids {
+                let actor_ids = core
+                    .fragment_manager
+                    .get_running_actors_of_fragment(*fragment_id)
+                    .await?;
+                for actor_id in actor_ids {
+                    let splits = core
+                        .actor_splits
+                        .get(&actor_id)
+                        .cloned()
+                        .unwrap_or_default();
+                    assignments.push((*fragment_id, actor_id, splits));
+                }
+            }
+        }
+        Ok(assignments)
+    }
+
+    /// Compare the start offsets of the splits assigned to the source's actors with the latest
+    /// offsets available upstream, returning `(split_id, assigned_offset, latest_offset)` per
+    /// split. The assigned offset only tracks split discovery — the actual consumer offsets live
+    /// in the state tables of the compute nodes — so the reported lag is an upper bound. Only
+    /// supported for kafka sources.
+    pub async fn get_source_lag(
+        &self,
+        source: &Source,
+    ) -> MetaResult<Vec<(SplitId, Option<i64>, i64)>> {
+        let assigned_splits: BTreeMap<SplitId, SplitImpl> = {
+            let core = self.core.lock().await;
+            let mut splits = BTreeMap::new();
+            if let Some(fragment_ids) = core.source_fragments.get(&source.id) {
+                for fragment_id in fragment_ids {
+                    let actor_ids = core
+                        .fragment_manager
+                        .get_running_actors_of_fragment(*fragment_id)
+                        .await?;
+                    for actor_id in actor_ids {
+                        for split in core.actor_splits.get(&actor_id).into_iter().flatten() {
+                            splits.insert(split.id(), split.clone());
+                        }
+                    }
+                }
+            }
+            splits
+        };
+
+        let properties = ConnectorProperties::extract(source.properties.clone())?;
+        let enumerator = match SplitEnumeratorImpl::create(properties).await? {
+            SplitEnumeratorImpl::Kafka(enumerator) => enumerator,
+            _ => {
+                return Err(anyhow!("lag reporting is only supported for kafka sources").into());
+            }
+        };
+        let mut latest_offsets = enumerator
+            .fetch_latest_offsets()
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        Ok(assigned_splits
+            .into_iter()
+            .filter_map(|(split_id, split)| {
+                let SplitImpl::Kafka(split) = split else {
+                    return None;
+                };
+                let (_, partition) = split.get_topic_and_partition();
+                let latest = latest_offsets.remove(&partition)?;
+                Some((split_id, split.get_start_offset(), latest))
+            })
+            .collect())
+    }
+
     /// unregister connector worker for source.
     pub async fn unregister_sources(&self, source_ids: Vec<SourceId>) {
         let mut core = self.core.lock().await;
@@ -250,6 +250,10 @@ pub struct StreamFragmentGraph {
     /// The default parallelism of the job, specified by the `STREAMING_PARALLELISM` session
     /// variable. If not specified, all active parallel units will be used.
     default_parallelism: Option<NonZeroUsize>,
+
+    /// The resource group of the job, specified by the `resource_group` option in the `WITH`
+    /// clause. If specified, the job is only scheduled to workers labeled with the same group.
+    resource_group: Option<String>,
 }
 
 impl StreamFragmentGraph {
@@ -318,12 +322,15 @@ impl StreamFragmentGraph {
             None
         };
 
+        let resource_group = Some(proto.resource_group).filter(|group| !group.is_empty());
+
         Ok(Self {
             fragments,
             downstreams,
             upstreams,
             dependent_table_ids,
             default_parallelism,
+            resource_group,
         })
     }
 
@@ -370,6 +377,11 @@ impl StreamFragmentGraph {
         self.default_parallelism
     }
 
+    /// Get the resource group of the job.
+    pub fn resource_group(&self) -> Option<String> {
+        self.resource_group.clone()
+    }
+
     /// Get downstreams of a fragment.
     fn get_downstreams(
         &self,
@@ -19,8 +19,9 @@ use futures::future::{try_join_all, BoxFuture};
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_pb::catalog::Table;
+use risingwave_pb::stream_plan::barrier::Mutation;
 use risingwave_pb::stream_plan::update_mutation::MergeUpdate;
-use risingwave_pb::stream_plan::Dispatcher;
+use risingwave_pb::stream_plan::{Dispatcher, SourcePauseMutation};
 use risingwave_pb::stream_service::{
     BroadcastActorInfoTableRequest, BuildActorsRequest, DropActorsRequest, UpdateActorsRequest,
 };
@@ -462,9 +463,20 @@ where
             merge_updates,
             building_locations,
             existing_locations,
-            table_properties: _,
+            table_properties,
         }: ReplaceTableContext,
     ) -> MetaResult<()> {
+        // Register the new internal tables (i.e. the state table of the source executor for a
+        // table with a connector) to the compaction group beforehand.
+        let hummock_manager_ref = self.hummock_manager.clone();
+        let registered_table_ids = hummock_manager_ref
+            .register_table_fragments(
+                None,
+                table_fragments.internal_table_ids(),
+                &table_properties,
+            )
+            .await?;
+
         self.build_actors(&table_fragments, &building_locations, &existing_locations)
             .await?;
 
@@ -475,15 +487,33 @@ where
 
         let dummy_table_id = table_fragments.table_id();
 
+        // Allocate splits for the source fragments of a table with a connector, so that the new
+        // source executors can start consuming right after the replacement.
+        let init_split_assignment = self
+            .source_manager
+            .pre_allocate_splits(&dummy_table_id)
+            .await?;
+
         if let Err(err) = self
             .barrier_scheduler
             .run_command_with_paused(Command::ReplaceTable {
                 old_table_fragments,
                 new_table_fragments: table_fragments,
                 merge_updates,
+                init_split_assignment,
             })
             .await
         {
+            if let Err(e) = hummock_manager_ref
+                .unregister_table_ids(&registered_table_ids)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to unregister tables {:#?}. They will be cleaned up on node restart. {:#?}",
+                    registered_table_ids,
+                    e
+                );
+            }
             self.fragment_manager
                 .drop_table_fragments_vec(&HashSet::from_iter(std::iter::once(dummy_table_id)))
                 .await?;
@@ -539,6 +569,31 @@ where
         let _reschedule_job_lock = self.reschedule_lock.read().await;
         self.creating_job_info.cancel_jobs(table_ids).await;
     }
+
+    /// Pause or resume a single streaming job by broadcasting a pause-source mutation scoped to
+    /// the barrier-inject actors (source, DML, etc.) of the job, leaving the rest of the
+    /// streaming graph running.
+    pub async fn pause_streaming_job(&self, table_id: TableId, pause: bool) -> MetaResult<()> {
+        let table_fragments = self
+            .fragment_manager
+            .select_table_fragments_by_table_id(&table_id)
+            .await?;
+        let actor_pause: HashMap<_, _> = table_fragments
+            .barrier_inject_actor_ids()
+            .into_iter()
+            .map(|actor_id| (actor_id, pause))
+            .collect();
+
+        if !actor_pause.is_empty() {
+            self.barrier_scheduler
+                .run_command(Command::Plain(Some(Mutation::PauseSource(
+                    SourcePauseMutation { actor_pause },
+                ))))
+                .await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
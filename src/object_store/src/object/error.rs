@@ -20,6 +20,7 @@ use aws_sdk_s3::operation::get_object::GetObjectError;
 use aws_sdk_s3::operation::head_object::HeadObjectError;
 use aws_sdk_s3::primitives::ByteStreamError;
 use risingwave_common::error::BoxedError;
+use risingwave_common::util::retry::RetryError;
 use thiserror::Error;
 use tokio::sync::oneshot::error::RecvError;
 
@@ -115,6 +116,19 @@ where
     }
 }
 
+impl<E> From<RetryError<E>> for ObjectError
+where
+    ObjectError: From<E>,
+    E: std::fmt::Display,
+{
+    fn from(e: RetryError<E>) -> Self {
+        match e {
+            RetryError::Inner(e) => e.into(),
+            e => ObjectErrorInner::Internal(e.to_string()).into(),
+        }
+    }
+}
+
 impl From<opendal::Error> for ObjectError {
     fn from(e: opendal::Error) -> Self {
         ObjectErrorInner::Opendal(e).into()
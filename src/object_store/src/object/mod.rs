@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use prometheus::HistogramTimer;
+use risingwave_common::config::RetryConfig;
 use tokio::io::{AsyncRead, AsyncReadExt};
 
 pub mod mem;
@@ -99,6 +100,26 @@ pub trait ObjectStore: Send + Sync {
 
     async fn streaming_upload(&self, path: &str) -> ObjectResult<BoxedStreamingUploader>;
 
+    /// Uploads the object into the given storage class, e.g. "STANDARD_IA" on S3. Stores without
+    /// a notion of storage classes ignore the class and behave like `upload`.
+    async fn upload_with_storage_class(
+        &self,
+        path: &str,
+        obj: Bytes,
+        _storage_class: &str,
+    ) -> ObjectResult<()> {
+        self.upload(path, obj).await
+    }
+
+    /// Streaming variant of `upload_with_storage_class`.
+    async fn streaming_upload_with_storage_class(
+        &self,
+        path: &str,
+        _storage_class: &str,
+    ) -> ObjectResult<BoxedStreamingUploader> {
+        self.streaming_upload(path).await
+    }
+
     /// If the `block_loc` is None, the whole object will be returned.
     /// If objects are PUT using a multipart upload, it’s a good practice to GET them in the same
     /// part sizes (or at least aligned to part boundaries) for best performance.
@@ -206,6 +227,36 @@ impl ObjectStoreImpl {
         object_store_impl_method_body!(self, streaming_upload, dispatch_async, path)
     }
 
+    pub async fn upload_with_storage_class(
+        &self,
+        path: &str,
+        obj: Bytes,
+        storage_class: &str,
+    ) -> ObjectResult<()> {
+        object_store_impl_method_body!(
+            self,
+            upload_with_storage_class,
+            dispatch_async,
+            path,
+            obj,
+            storage_class
+        )
+    }
+
+    pub async fn streaming_upload_with_storage_class(
+        &self,
+        path: &str,
+        storage_class: &str,
+    ) -> ObjectResult<MonitoredStreamingUploader> {
+        object_store_impl_method_body!(
+            self,
+            streaming_upload_with_storage_class,
+            dispatch_async,
+            path,
+            storage_class
+        )
+    }
+
     pub async fn read(&self, path: &str, block_loc: Option<BlockLocation>) -> ObjectResult<Bytes> {
         object_store_impl_method_body!(self, read, dispatch_async, path, block_loc)
     }
@@ -500,6 +551,62 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         ))
     }
 
+    pub async fn upload_with_storage_class(
+        &self,
+        path: &str,
+        obj: Bytes,
+        storage_class: &str,
+    ) -> ObjectResult<()> {
+        let operation_type = "upload";
+        self.object_store_metrics
+            .write_bytes
+            .inc_by(obj.len() as u64);
+        self.object_store_metrics
+            .operation_size
+            .with_label_values(&[operation_type])
+            .observe(obj.len() as f64);
+        let _timer = self
+            .object_store_metrics
+            .operation_latency
+            .with_label_values(&[self.media_type(), operation_type])
+            .start_timer();
+
+        let ret = self
+            .inner
+            .upload_with_storage_class(path, obj, storage_class)
+            .verbose_instrument_await("object_store_upload")
+            .await;
+
+        try_update_failure_metric(&self.object_store_metrics, &ret, operation_type);
+        ret
+    }
+
+    pub async fn streaming_upload_with_storage_class(
+        &self,
+        path: &str,
+        storage_class: &str,
+    ) -> ObjectResult<MonitoredStreamingUploader> {
+        let operation_type = "streaming_upload_start";
+        let media_type = self.media_type();
+        let _timer = self
+            .object_store_metrics
+            .operation_latency
+            .with_label_values(&[media_type, operation_type])
+            .start_timer();
+
+        let handle_res = self
+            .inner
+            .streaming_upload_with_storage_class(path, storage_class)
+            .await;
+
+        try_update_failure_metric(&self.object_store_metrics, &handle_res, operation_type);
+        Ok(MonitoredStreamingUploader::new(
+            media_type,
+            handle_res?,
+            self.object_store_metrics.clone(),
+        ))
+    }
+
     pub async fn read(&self, path: &str, block_loc: Option<BlockLocation>) -> ObjectResult<Bytes> {
         let operation_type = "read";
         let _timer = self
@@ -664,12 +771,16 @@ pub async fn parse_remote_object_store(
     url: &str,
     metrics: Arc<ObjectStoreMetrics>,
     ident: &str,
+    user_agent_label: Option<String>,
+    retry_config: RetryConfig,
 ) -> ObjectStoreImpl {
     match url {
         s3 if s3.starts_with("s3://") => ObjectStoreImpl::S3(
             S3ObjectStore::new(
                 s3.strip_prefix("s3://").unwrap().to_string(),
                 metrics.clone(),
+                user_agent_label,
+                retry_config,
             )
             .await
             .monitored(metrics),
@@ -740,12 +851,14 @@ pub async fn parse_remote_object_store(
                     .unwrap()
                     .to_string(),
                 metrics.clone(),
+                user_agent_label,
+                retry_config,
             )
             .await
             .monitored(metrics),
         ),
         minio if minio.starts_with("minio://") => ObjectStoreImpl::S3(
-            S3ObjectStore::with_minio(minio, metrics.clone())
+            S3ObjectStore::with_minio(minio, metrics.clone(), retry_config)
                 .await
                 .monitored(metrics),
         ),
@@ -16,7 +16,10 @@ use std::time::Duration;
 
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::monitor_service::monitor_service_client::MonitorServiceClient;
-use risingwave_pb::monitor_service::{StackTraceRequest, StackTraceResponse};
+use risingwave_pb::monitor_service::{
+    ListFailpointsRequest, ListFailpointsResponse, SetFailpointRequest, SetFailpointResponse,
+    StackTraceRequest, StackTraceResponse,
+};
 use tonic::transport::{Channel, Endpoint};
 
 use crate::error::Result;
@@ -45,4 +48,26 @@ impl CompactorClient {
             .await?
             .into_inner())
     }
+
+    pub async fn list_failpoints(&self) -> Result<ListFailpointsResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .list_failpoints(ListFailpointsRequest {})
+            .await?
+            .into_inner())
+    }
+
+    pub async fn set_failpoint(
+        &self,
+        name: String,
+        actions: String,
+    ) -> Result<SetFailpointResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .set_failpoint(SetFailpointRequest { name, actions })
+            .await?
+            .into_inner())
+    }
 }
@@ -26,7 +26,10 @@ use risingwave_pb::compute::config_service_client::ConfigServiceClient;
 use risingwave_pb::compute::{ShowConfigRequest, ShowConfigResponse};
 use risingwave_pb::monitor_service::monitor_service_client::MonitorServiceClient;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    GetStreamStatsRequest, GetStreamStatsResponse, GetTableReadStatsRequest,
+    GetTableReadStatsResponse, HeapProfilingRequest, HeapProfilingResponse, ListFailpointsRequest,
+    ListFailpointsResponse, ProfilingRequest, ProfilingResponse, SetFailpointRequest,
+    SetFailpointResponse, StackTraceRequest, StackTraceResponse,
 };
 use risingwave_pb::task_service::exchange_service_client::ExchangeServiceClient;
 use risingwave_pb::task_service::task_service_client::TaskServiceClient;
@@ -192,6 +195,55 @@ impl ComputeClient {
             .into_inner())
     }
 
+    pub async fn heap_profile(&self, dir: String) -> Result<HeapProfilingResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .heap_profiling(HeapProfilingRequest { dir })
+            .await?
+            .into_inner())
+    }
+
+    pub async fn get_stream_stats(&self) -> Result<GetStreamStatsResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .get_stream_stats(GetStreamStatsRequest {})
+            .await?
+            .into_inner())
+    }
+
+    pub async fn get_table_read_stats(&self) -> Result<GetTableReadStatsResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .get_table_read_stats(GetTableReadStatsRequest {})
+            .await?
+            .into_inner())
+    }
+
+    pub async fn list_failpoints(&self) -> Result<ListFailpointsResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .list_failpoints(ListFailpointsRequest {})
+            .await?
+            .into_inner())
+    }
+
+    pub async fn set_failpoint(
+        &self,
+        name: String,
+        actions: String,
+    ) -> Result<SetFailpointResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .set_failpoint(SetFailpointRequest { name, actions })
+            .await?
+            .into_inner())
+    }
+
     pub async fn show_config(&self) -> Result<ShowConfigResponse> {
         Ok(self
             .config_client
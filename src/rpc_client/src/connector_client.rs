@@ -19,6 +19,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use risingwave_common::config::{MAX_CONNECTION_WINDOW_SIZE, STREAM_WINDOW_SIZE};
 use risingwave_common::util::addr::HostAddr;
+use risingwave_common::util::retry::{RetryError, RetryPolicy};
 use risingwave_pb::catalog::SinkType;
 use risingwave_pb::connector_service::connector_service_client::ConnectorServiceClient;
 use risingwave_pb::connector_service::sink_stream_request::{Request as SinkRequest, StartSink};
@@ -36,7 +37,7 @@ pub struct ConnectorClient(ConnectorServiceClient<Channel>);
 
 impl ConnectorClient {
     pub async fn new(host_addr: HostAddr) -> Result<Self> {
-        let channel = Endpoint::from_shared(format!("http://{}", &host_addr))
+        let endpoint = Endpoint::from_shared(format!("http://{}", &host_addr))
             .map_err(|e| {
                 RpcError::Internal(anyhow!(format!(
                     "invalid connector endpoint `{}`: {:?}",
@@ -46,9 +47,16 @@ impl ConnectorClient {
             .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
             .initial_stream_window_size(STREAM_WINDOW_SIZE)
             .tcp_nodelay(true)
-            .connect_timeout(Duration::from_secs(5))
-            .connect()
-            .await?;
+            .connect_timeout(Duration::from_secs(5));
+        // The connector node may not be up yet when the cluster starts, retry connecting with
+        // backoff instead of failing the first request.
+        let channel = RetryPolicy::default()
+            .retry(|| endpoint.connect(), |_| true)
+            .await
+            .map_err(|e| match e {
+                RetryError::Inner(e) => RpcError::TransportError(e),
+                e => RpcError::Internal(anyhow!(e)),
+            })?;
         Ok(Self(ConnectorServiceClient::new(channel)))
     }
 
@@ -168,13 +168,33 @@ macro_rules! rpc_client_method_impl {
     }
 }
 
+/// Attaches the bearer token from the `RW_META_TOKEN` env variable to a meta request, for
+/// clusters that enable authentication of meta admin RPCs. The request is sent as-is when the
+/// variable is not set.
+pub fn with_auth_token<T>(msg: T) -> tonic::Request<T> {
+    static TOKEN: std::sync::OnceLock<Option<tonic::metadata::AsciiMetadataValue>> =
+        std::sync::OnceLock::new();
+    let token = TOKEN.get_or_init(|| {
+        std::env::var("RW_META_TOKEN")
+            .ok()
+            .and_then(|token| format!("Bearer {token}").parse().ok())
+    });
+    let mut request = tonic::Request::new(msg);
+    if let Some(token) = token {
+        request
+            .metadata_mut()
+            .insert("authorization", token.clone());
+    }
+    request
+}
+
 #[macro_export]
 macro_rules! meta_rpc_client_method_impl {
     ($( { $client:tt, $fn_name:ident, $req:ty, $resp:ty }),*) => {
         $(
             pub async fn $fn_name(&self, request: $req) -> $crate::Result<$resp> {
                 let mut client = self.core.read().await.$client.to_owned();
-                match client.$fn_name(request).await {
+                match client.$fn_name($crate::with_auth_token(request)).await {
                     Ok(resp) => Ok(resp.into_inner()),
                     Err(e) => {
                         self.refresh_client_if_needed(e.code()).await;
@@ -25,6 +25,7 @@ use either::Either;
 use futures::stream::BoxStream;
 use itertools::Itertools;
 use lru::LruCache;
+use rand::Rng;
 use risingwave_common::catalog::{CatalogVersion, FunctionId, IndexId, TableId};
 use risingwave_common::config::{MetaConfig, MAX_CONNECTION_WINDOW_SIZE};
 use risingwave_common::hash::ParallelUnitMapping;
@@ -41,10 +42,12 @@ use risingwave_hummock_sdk::{
 use risingwave_pb::backup_service::backup_service_client::BackupServiceClient;
 use risingwave_pb::backup_service::*;
 use risingwave_pb::catalog::{
-    Connection, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbTable, PbView, Table,
+    Connection, PbDatabase, PbFunction, PbIndex, PbSchema, PbSecret, PbSink, PbSource, PbTable,
+    PbView, Table,
 };
 use risingwave_pb::common::{HostAddress, WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::alter_relation_name_request::Relation;
+use risingwave_pb::ddl_service::alter_relation_owner_request::Relation as OwnerRelation;
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::drop_table_request::SourceId;
 use risingwave_pb::ddl_service::*;
@@ -52,6 +55,7 @@ use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServic
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::mutable_config::MutableConfig;
 use risingwave_pb::hummock::*;
 use risingwave_pb::meta::add_worker_node_request::Property;
+use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
 use risingwave_pb::meta::cluster_service_client::ClusterServiceClient;
 use risingwave_pb::meta::get_reschedule_plan_request::PbPolicy;
 use risingwave_pb::meta::heartbeat_request::{extra_info, ExtraInfo};
@@ -86,6 +90,7 @@ use crate::{meta_rpc_client_method_impl, ExtraInfoSourceRef};
 type ConnectionId = u32;
 type DatabaseId = u32;
 type SchemaId = u32;
+type SecretId = u32;
 
 /// Client to meta server. Cloning the instance is lightweight.
 #[derive(Clone, Debug)]
@@ -149,6 +154,7 @@ impl MetaClient {
             schema_id,
             owner_id,
             payload: Some(req),
+            idempotency_key: Self::gen_idempotency_key(),
         };
         let resp = self.inner.create_connection(request).await?;
         Ok((resp.connection_id, resp.version))
@@ -166,6 +172,25 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn drop_owned(&self, user_ids: Vec<u32>) -> Result<CatalogVersion> {
+        let request = DropOwnedRequest { user_ids };
+        let resp = self.inner.drop_owned(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn reassign_owned(
+        &self,
+        old_owner_ids: Vec<u32>,
+        new_owner_id: u32,
+    ) -> Result<CatalogVersion> {
+        let request = ReassignOwnedRequest {
+            old_owner_ids,
+            new_owner_id,
+        };
+        let resp = self.inner.reassign_owned(request).await?;
+        Ok(resp.version)
+    }
+
     pub(crate) fn parse_meta_addr(meta_addr: &str) -> Result<MetaAddressStrategy> {
         if meta_addr.starts_with(Self::META_ADDRESS_LOAD_BALANCE_MODE_PREFIX) {
             let addr = meta_addr
@@ -302,8 +327,19 @@ impl MetaClient {
         Ok(())
     }
 
+    /// Generates a random idempotency key for a create-DDL request. The meta
+    /// service caches the response under this key, so that a retry of a
+    /// timed-out request returns the original result instead of an "already
+    /// exists" error.
+    fn gen_idempotency_key() -> String {
+        format!("{:032x}", rand::thread_rng().gen::<u128>())
+    }
+
     pub async fn create_database(&self, db: PbDatabase) -> Result<(DatabaseId, CatalogVersion)> {
-        let request = CreateDatabaseRequest { db: Some(db) };
+        let request = CreateDatabaseRequest {
+            db: Some(db),
+            idempotency_key: Self::gen_idempotency_key(),
+        };
         let resp = self.inner.create_database(request).await?;
         // TODO: handle error in `resp.status` here
         Ok((resp.database_id, resp.version))
@@ -312,6 +348,7 @@ impl MetaClient {
     pub async fn create_schema(&self, schema: PbSchema) -> Result<(SchemaId, CatalogVersion)> {
         let request = CreateSchemaRequest {
             schema: Some(schema),
+            idempotency_key: Self::gen_idempotency_key(),
         };
         let resp = self.inner.create_schema(request).await?;
         // TODO: handle error in `resp.status` here
@@ -326,6 +363,7 @@ impl MetaClient {
         let request = CreateMaterializedViewRequest {
             materialized_view: Some(table),
             fragment_graph: Some(graph),
+            idempotency_key: Self::gen_idempotency_key(),
         };
         let resp = self.inner.create_materialized_view(request).await?;
         // TODO: handle error in `resp.status` here
@@ -344,6 +382,7 @@ impl MetaClient {
     pub async fn create_source(&self, source: PbSource) -> Result<(u32, CatalogVersion)> {
         let request = CreateSourceRequest {
             source: Some(source),
+            idempotency_key: Self::gen_idempotency_key(),
         };
 
         let resp = self.inner.create_source(request).await?;
@@ -358,6 +397,7 @@ impl MetaClient {
         let request = CreateSinkRequest {
             sink: Some(sink),
             fragment_graph: Some(graph),
+            idempotency_key: Self::gen_idempotency_key(),
         };
 
         let resp = self.inner.create_sink(request).await?;
@@ -367,14 +407,39 @@ impl MetaClient {
     pub async fn create_function(
         &self,
         function: PbFunction,
+        or_replace: bool,
     ) -> Result<(FunctionId, CatalogVersion)> {
         let request = CreateFunctionRequest {
             function: Some(function),
+            idempotency_key: Self::gen_idempotency_key(),
+            or_replace,
         };
         let resp = self.inner.create_function(request).await?;
         Ok((resp.function_id.into(), resp.version))
     }
 
+    pub async fn create_secret(&self, secret: PbSecret) -> Result<(SecretId, CatalogVersion)> {
+        let request = CreateSecretRequest {
+            secret: Some(secret),
+        };
+        let resp = self.inner.create_secret(request).await?;
+        Ok((resp.secret_id, resp.version))
+    }
+
+    pub async fn alter_secret(&self, secret: PbSecret) -> Result<CatalogVersion> {
+        let request = AlterSecretRequest {
+            secret: Some(secret),
+        };
+        let resp = self.inner.alter_secret(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn drop_secret(&self, secret_id: SecretId) -> Result<CatalogVersion> {
+        let request = DropSecretRequest { secret_id };
+        let resp = self.inner.drop_secret(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn create_table(
         &self,
         source: Option<PbSource>,
@@ -385,6 +450,7 @@ impl MetaClient {
             materialized_view: Some(table),
             fragment_graph: Some(graph),
             source,
+            idempotency_key: Self::gen_idempotency_key(),
         };
         let resp = self.inner.create_table(request).await?;
         // TODO: handle error in `resp.status` here
@@ -404,13 +470,43 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn alter_relation_owner(
+        &self,
+        relation: OwnerRelation,
+        owner_id: u32,
+    ) -> Result<CatalogVersion> {
+        let request = AlterRelationOwnerRequest {
+            relation: Some(relation),
+            owner_id,
+        };
+        let resp = self.inner.alter_relation_owner(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_source_props(
+        &self,
+        source_id: u32,
+        changed_props: HashMap<String, String>,
+        migrate_offsets_timestamp_millis: Option<i64>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterSourcePropsRequest {
+            source_id,
+            changed_props,
+            migrate_offsets_timestamp_millis,
+        };
+        let resp = self.inner.alter_source_props(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn replace_table(
         &self,
+        source: Option<PbSource>,
         table: PbTable,
         graph: StreamFragmentGraph,
         table_col_index_mapping: ColIndexMapping,
     ) -> Result<CatalogVersion> {
         let request = ReplaceTablePlanRequest {
+            source,
             table: Some(table),
             fragment_graph: Some(graph),
             table_col_index_mapping: Some(table_col_index_mapping.to_protobuf()),
@@ -420,8 +516,16 @@ impl MetaClient {
         Ok(resp.version)
     }
 
-    pub async fn create_view(&self, view: PbView) -> Result<(u32, CatalogVersion)> {
-        let request = CreateViewRequest { view: Some(view) };
+    pub async fn create_view(
+        &self,
+        view: PbView,
+        or_replace: bool,
+    ) -> Result<(u32, CatalogVersion)> {
+        let request = CreateViewRequest {
+            view: Some(view),
+            idempotency_key: Self::gen_idempotency_key(),
+            or_replace,
+        };
         let resp = self.inner.create_view(request).await?;
         // TODO: handle error in `resp.status` here
         Ok((resp.view_id, resp.version))
@@ -437,6 +541,7 @@ impl MetaClient {
             index: Some(index),
             index_table: Some(table),
             fragment_graph: Some(graph),
+            idempotency_key: Self::gen_idempotency_key(),
         };
         let resp = self.inner.create_index(request).await?;
         // TODO: handle error in `resp.status` here
@@ -596,6 +701,22 @@ impl MetaClient {
         Ok(resp)
     }
 
+    pub async fn update_resource_group(
+        &self,
+        worker_ids: &[u32],
+        resource_group: String,
+    ) -> Result<UpdateWorkerNodeResourceGroupResponse> {
+        let request = UpdateWorkerNodeResourceGroupRequest {
+            worker_ids: worker_ids.to_vec(),
+            resource_group,
+        };
+        let resp = self
+            .inner
+            .update_worker_node_resource_group(request)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn list_worker_nodes(&self, worker_type: WorkerType) -> Result<Vec<WorkerNode>> {
         let request = ListAllNodesRequest {
             worker_type: worker_type as _,
@@ -668,8 +789,8 @@ impl MetaClient {
         Ok(resp.snapshot.unwrap())
     }
 
-    pub async fn cancel_creating_jobs(&self, infos: Vec<CreatingJobInfo>) -> Result<()> {
-        let request = CancelCreatingJobsRequest { infos };
+    pub async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<()> {
+        let request = CancelCreatingJobsRequest { jobs: Some(jobs) };
         let _ = self.inner.cancel_creating_jobs(request).await?;
         Ok(())
     }
@@ -685,6 +806,81 @@ impl MetaClient {
         Ok(resp.table_fragments)
     }
 
+    pub async fn list_source_splits(
+        &self,
+        source_id: u32,
+    ) -> Result<Vec<list_source_splits_response::SplitAssignment>> {
+        let request = ListSourceSplitsRequest { source_id };
+        let resp = self.inner.list_source_splits(request).await?;
+        Ok(resp.assignments)
+    }
+
+    pub async fn get_source_lag(
+        &self,
+        source_id: u32,
+    ) -> Result<Vec<get_source_lag_response::SplitLag>> {
+        let request = GetSourceLagRequest { source_id };
+        let resp = self.inner.get_source_lag(request).await?;
+        Ok(resp.split_lags)
+    }
+
+    pub async fn pause_source(&self, source_id: u32) -> Result<()> {
+        let request = PauseSourceRequest { source_id };
+        let _resp = self.inner.pause_source(request).await?;
+        Ok(())
+    }
+
+    pub async fn resume_source(&self, source_id: u32) -> Result<()> {
+        let request = ResumeSourceRequest { source_id };
+        let _resp = self.inner.resume_source(request).await?;
+        Ok(())
+    }
+
+    pub async fn pause_streaming_job(&self, table_id: u32) -> Result<()> {
+        let request = PauseStreamingJobRequest { table_id };
+        let _resp = self.inner.pause_streaming_job(request).await?;
+        Ok(())
+    }
+
+    pub async fn resume_streaming_job(&self, table_id: u32) -> Result<()> {
+        let request = ResumeStreamingJobRequest { table_id };
+        let _resp = self.inner.resume_streaming_job(request).await?;
+        Ok(())
+    }
+
+    pub async fn list_barrier_latency(&self, limit: u32) -> Result<Vec<BarrierLatencyEntry>> {
+        let request = ListBarrierLatencyRequest { limit };
+        let resp = self.inner.list_barrier_latency(request).await?;
+        Ok(resp.entries)
+    }
+
+    pub async fn list_recovery_events(&self) -> Result<Vec<RecoveryEvent>> {
+        let request = ListRecoveryEventsRequest {};
+        let resp = self.inner.list_recovery_events(request).await?;
+        Ok(resp.events)
+    }
+
+    pub async fn resume_quarantined_job(&self, table_id: u32) -> Result<()> {
+        let request = ResumeQuarantinedJobRequest { table_id };
+        let _resp = self.inner.resume_quarantined_job(request).await?;
+        Ok(())
+    }
+
+    pub async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()> {
+        let request = AlterParallelismRequest {
+            table_id,
+            parallelism,
+        };
+        let _resp = self.inner.alter_parallelism(request).await?;
+        Ok(())
+    }
+
+    pub async fn list_recoveries(&self) -> Result<Vec<RecoveryRecord>> {
+        let request = ListRecoveriesRequest {};
+        let resp = self.inner.list_recoveries(request).await?;
+        Ok(resp.recoveries)
+    }
+
     pub async fn pause(&self) -> Result<()> {
         let request = PauseRequest {};
         let _resp = self.inner.pause(request).await?;
@@ -925,6 +1121,12 @@ impl MetaClient {
         Ok(resp.ddl_progress)
     }
 
+    pub async fn get_backfill_progress(&self) -> Result<Vec<BackfillProgress>> {
+        let req = GetBackfillProgressRequest {};
+        let resp = self.inner.get_backfill_progress(req).await?;
+        Ok(resp.backfill_progress)
+    }
+
     pub async fn split_compaction_group(
         &self,
         group_id: CompactionGroupId,
@@ -986,6 +1188,36 @@ impl MetaClient {
         ))
     }
 
+    pub async fn list_compact_task_history(&self) -> Result<Vec<CompactTaskSummary>> {
+        let req = ListCompactTaskHistoryRequest {};
+        let resp = self.inner.list_compact_task_history(req).await?;
+        Ok(resp.task_summaries)
+    }
+
+    pub async fn get_hummock_version_stats(&self) -> Result<HummockVersionStats> {
+        let req = GetVersionStatsRequest {};
+        let resp = self.inner.get_version_stats(req).await?;
+        Ok(resp.stats.unwrap_or_default())
+    }
+
+    pub async fn get_compactor_scale_info(&self) -> Result<CompactorScaleInfo> {
+        let req = GetCompactorScaleInfoRequest {};
+        let resp = self.inner.get_compactor_scale_info(req).await?;
+        Ok(resp.info.unwrap_or_default())
+    }
+
+    pub async fn get_dr_status(&self) -> Result<GetDrStatusResponse> {
+        let req = GetDrStatusRequest {};
+        let resp = self.inner.get_dr_status(req).await?;
+        Ok(resp)
+    }
+
+    pub async fn promote_dr_standby(&self) -> Result<PromoteDrStandbyResponse> {
+        let req = PromoteDrStandbyRequest {};
+        let resp = self.inner.promote_dr_standby(req).await?;
+        Ok(resp)
+    }
+
     pub async fn delete_worker_node(&self, worker: HostAddress) -> Result<()> {
         let _resp = self
             .inner
@@ -1553,14 +1785,28 @@ macro_rules! for_all_meta_rpc {
             ,{ cluster_client, activate_worker_node, ActivateWorkerNodeRequest, ActivateWorkerNodeResponse }
             ,{ cluster_client, delete_worker_node, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse }
             ,{ cluster_client, update_worker_node_schedulability, UpdateWorkerNodeSchedulabilityRequest, UpdateWorkerNodeSchedulabilityResponse }
+            ,{ cluster_client, update_worker_node_resource_group, UpdateWorkerNodeResourceGroupRequest, UpdateWorkerNodeResourceGroupResponse }
             //(not used) ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
             ,{ stream_client, cancel_creating_jobs, CancelCreatingJobsRequest, CancelCreatingJobsResponse }
             ,{ stream_client, list_table_fragments, ListTableFragmentsRequest, ListTableFragmentsResponse }
+            ,{ stream_client, list_source_splits, ListSourceSplitsRequest, ListSourceSplitsResponse }
+            ,{ stream_client, get_source_lag, GetSourceLagRequest, GetSourceLagResponse }
+            ,{ stream_client, pause_source, PauseSourceRequest, PauseSourceResponse }
+            ,{ stream_client, resume_source, ResumeSourceRequest, ResumeSourceResponse }
+            ,{ stream_client, pause_streaming_job, PauseStreamingJobRequest, PauseStreamingJobResponse }
+            ,{ stream_client, resume_streaming_job, ResumeStreamingJobRequest, ResumeStreamingJobResponse }
+            ,{ stream_client, list_barrier_latency, ListBarrierLatencyRequest, ListBarrierLatencyResponse }
+            ,{ stream_client, list_recovery_events, ListRecoveryEventsRequest, ListRecoveryEventsResponse }
+            ,{ stream_client, resume_quarantined_job, ResumeQuarantinedJobRequest, ResumeQuarantinedJobResponse }
+            ,{ stream_client, list_recoveries, ListRecoveriesRequest, ListRecoveriesResponse }
+            ,{ stream_client, alter_parallelism, AlterParallelismRequest, AlterParallelismResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
             ,{ ddl_client, alter_relation_name, AlterRelationNameRequest, AlterRelationNameResponse }
+            ,{ ddl_client, alter_relation_owner, AlterRelationOwnerRequest, AlterRelationOwnerResponse }
+            ,{ ddl_client, alter_source_props, AlterSourcePropsRequest, AlterSourcePropsResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
             ,{ ddl_client, create_source, CreateSourceRequest, CreateSourceResponse }
@@ -1578,12 +1824,18 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
             ,{ ddl_client, drop_index, DropIndexRequest, DropIndexResponse }
             ,{ ddl_client, drop_function, DropFunctionRequest, DropFunctionResponse }
+            ,{ ddl_client, create_secret, CreateSecretRequest, CreateSecretResponse }
+            ,{ ddl_client, alter_secret, AlterSecretRequest, AlterSecretResponse }
+            ,{ ddl_client, drop_secret, DropSecretRequest, DropSecretResponse }
             ,{ ddl_client, replace_table_plan, ReplaceTablePlanRequest, ReplaceTablePlanResponse }
             ,{ ddl_client, risectl_list_state_tables, RisectlListStateTablesRequest, RisectlListStateTablesResponse }
             ,{ ddl_client, get_ddl_progress, GetDdlProgressRequest, GetDdlProgressResponse }
+            ,{ ddl_client, get_backfill_progress, GetBackfillProgressRequest, GetBackfillProgressResponse }
             ,{ ddl_client, create_connection, CreateConnectionRequest, CreateConnectionResponse }
             ,{ ddl_client, list_connections, ListConnectionsRequest, ListConnectionsResponse }
             ,{ ddl_client, drop_connection, DropConnectionRequest, DropConnectionResponse }
+            ,{ ddl_client, drop_owned, DropOwnedRequest, DropOwnedResponse }
+            ,{ ddl_client, reassign_owned, ReassignOwnedRequest, ReassignOwnedResponse }
             ,{ ddl_client, get_tables, GetTablesRequest, GetTablesResponse }
             ,{ hummock_client, unpin_version_before, UnpinVersionBeforeRequest, UnpinVersionBeforeResponse }
             ,{ hummock_client, get_current_version, GetCurrentVersionRequest, GetCurrentVersionResponse }
@@ -1615,6 +1867,11 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, init_metadata_for_replay, InitMetadataForReplayRequest, InitMetadataForReplayResponse }
             ,{ hummock_client, split_compaction_group, SplitCompactionGroupRequest, SplitCompactionGroupResponse }
             ,{ hummock_client, rise_ctl_list_compaction_status, RiseCtlListCompactionStatusRequest, RiseCtlListCompactionStatusResponse }
+            ,{ hummock_client, list_compact_task_history, ListCompactTaskHistoryRequest, ListCompactTaskHistoryResponse }
+            ,{ hummock_client, get_dr_status, GetDrStatusRequest, GetDrStatusResponse }
+            ,{ hummock_client, promote_dr_standby, PromoteDrStandbyRequest, PromoteDrStandbyResponse }
+            ,{ hummock_client, get_version_stats, GetVersionStatsRequest, GetVersionStatsResponse }
+            ,{ hummock_client, get_compactor_scale_info, GetCompactorScaleInfoRequest, GetCompactorScaleInfoResponse }
             ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
             ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
             ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
@@ -13,19 +13,22 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::future::try_join_all;
 use futures::StreamExt;
+use futures_async_stream::try_stream;
 use itertools::Itertools;
 use risingwave_common::catalog::ColumnId;
 use risingwave_common::error::ErrorCode::ConnectorError;
-use risingwave_common::error::{internal_error, Result};
+use risingwave_common::error::{internal_error, Result, RwError};
 use risingwave_common::util::select_all;
 use risingwave_connector::parser::{CommonParserConfig, ParserConfig, SpecificParserConfig};
 use risingwave_connector::source::{
-    BoxSourceWithStateStream, Column, ConnectorProperties, ConnectorState, SourceColumnDesc,
-    SourceContext, SplitReaderImpl,
+    rate_limit_from_props, BoxSourceWithStateStream, Column, ConnectorProperties, ConnectorState,
+    ParseErrorPolicy, SourceColumnDesc, SourceContext, SplitReaderImpl, StreamChunkWithState,
 };
 
 #[derive(Clone, Debug)]
@@ -34,6 +37,14 @@ pub struct ConnectorSource {
     pub columns: Vec<SourceColumnDesc>,
     pub parser_config: SpecificParserConfig,
     pub connector_message_buffer_size: usize,
+    pub parse_error_policy: ParseErrorPolicy,
+    /// The maximum number of rows per second this source may emit per actor, from the
+    /// `rate_limit` option. `0` means unlimited. Shared with the running reader streams so that
+    /// a throttle barrier mutation takes effect without rebuilding them.
+    pub rate_limit: Arc<AtomicU32>,
+    /// Whether the source is paused by a pause-source barrier mutation. Shared with the running
+    /// reader streams, which stop emitting data while it is set.
+    pub paused: Arc<AtomicBool>,
 }
 
 impl ConnectorSource {
@@ -44,6 +55,10 @@ impl ConnectorSource {
         connector_message_buffer_size: usize,
         parser_config: SpecificParserConfig,
     ) -> Result<Self> {
+        let parse_error_policy =
+            ParseErrorPolicy::from_props(&properties).map_err(|e| ConnectorError(e.into()))?;
+        let rate_limit =
+            rate_limit_from_props(&properties).map_err(|e| ConnectorError(e.into()))?;
         let mut config =
             ConnectorProperties::extract(properties).map_err(|e| ConnectorError(e.into()))?;
         if let Some(addr) = connector_node_addr {
@@ -56,9 +71,22 @@ impl ConnectorSource {
             columns,
             parser_config,
             connector_message_buffer_size,
+            parse_error_policy,
+            rate_limit: Arc::new(AtomicU32::new(rate_limit)),
+            paused: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// Change the rate limit of the running reader streams. `0` means unlimited.
+    pub fn set_rate_limit(&self, rate_limit: u32) {
+        self.rate_limit.store(rate_limit, Ordering::Relaxed);
+    }
+
+    /// Pause or resume the running reader streams.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
     fn get_target_columns(&self, column_ids: Vec<ColumnId>) -> Result<Vec<SourceColumnDesc>> {
         column_ids
             .iter()
@@ -125,6 +153,66 @@ impl ConnectorSource {
         }))
         .await?;
 
-        Ok(select_all(readers.into_iter().map(|r| r.into_stream())).boxed())
+        let stream = select_all(readers.into_iter().map(|r| r.into_stream())).boxed();
+        Ok(apply_rate_limit(
+            stream,
+            self.rate_limit.clone(),
+            self.paused.clone(),
+            source_ctx,
+        ))
+    }
+}
+
+/// How often a paused stream re-checks the shared `paused` flag.
+const PAUSE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Wrap a reader stream so that it emits at most `rate_limit` rows per second, sleeping until
+/// the next one-second window once the budget of the current one is used up. The limit is
+/// re-read from the shared atomic for every chunk, so an update from a throttle barrier
+/// mutation takes effect without rebuilding the stream; `0` means unlimited. Time spent
+/// sleeping is reported via the `source_throttled_duration_ms` metric.
+///
+/// While `paused` is set, the stream stops emitting data entirely, which also stops pulling
+/// from the reader through backpressure.
+#[try_stream(boxed, ok = StreamChunkWithState, error = RwError)]
+pub async fn apply_rate_limit(
+    stream: BoxSourceWithStateStream,
+    rate_limit: Arc<AtomicU32>,
+    paused: Arc<AtomicBool>,
+    source_ctx: Arc<SourceContext>,
+) {
+    let actor_id = source_ctx.source_info.actor_id.to_string();
+    let source_id = source_ctx.source_info.source_id.to_string();
+    let mut window_start = tokio::time::Instant::now();
+    let mut emitted_rows: u64 = 0;
+
+    #[for_await]
+    for chunk in stream {
+        let chunk: StreamChunkWithState = chunk?;
+        while paused.load(Ordering::Relaxed) {
+            tokio::time::sleep(PAUSE_CHECK_INTERVAL).await;
+        }
+        let limit = rate_limit.load(Ordering::Relaxed) as u64;
+        if limit != 0 {
+            if window_start.elapsed() >= Duration::from_secs(1) {
+                window_start = tokio::time::Instant::now();
+                emitted_rows = 0;
+            }
+            if emitted_rows >= limit {
+                let wait = Duration::from_secs(1).saturating_sub(window_start.elapsed());
+                if !wait.is_zero() {
+                    tokio::time::sleep(wait).await;
+                    source_ctx
+                        .metrics
+                        .source_throttled_duration_ms
+                        .with_label_values(&[&actor_id, &source_id])
+                        .inc_by(wait.as_millis() as u64);
+                }
+                window_start = tokio::time::Instant::now();
+                emitted_rows = 0;
+            }
+            emitted_rows += chunk.chunk.cardinality() as u64;
+        }
+        yield chunk;
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 
 use risingwave_common::catalog::ColumnId;
@@ -20,7 +21,8 @@ use risingwave_common::error::ErrorCode::ConnectorError;
 use risingwave_common::error::{internal_error, Result, RwError};
 use risingwave_connector::parser::{CommonParserConfig, ParserConfig, SpecificParserConfig};
 use risingwave_connector::source::{
-    ConnectorProperties, ConnectorState, SourceColumnDesc, SourceContext, SplitReaderImpl,
+    rate_limit_from_props, ConnectorProperties, ConnectorState, ParseErrorPolicy, SourceColumnDesc,
+    SourceContext, SplitReaderImpl,
 };
 
 #[derive(Clone, Debug)]
@@ -29,6 +31,17 @@ pub struct FsConnectorSource {
     pub columns: Vec<SourceColumnDesc>,
     pub properties: HashMap<String, String>,
     pub parser_config: SpecificParserConfig,
+    pub parse_error_policy: ParseErrorPolicy,
+    /// The maximum number of rows per second this source may emit per actor, from the
+    /// `rate_limit` option. `0` means unlimited. See [`ConnectorSource`] for details.
+    ///
+    /// [`ConnectorSource`]: crate::connector_source::ConnectorSource
+    pub rate_limit: Arc<AtomicU32>,
+    /// Whether the source is paused by a pause-source barrier mutation. See [`ConnectorSource`]
+    /// for details.
+    ///
+    /// [`ConnectorSource`]: crate::connector_source::ConnectorSource
+    pub paused: Arc<AtomicBool>,
 }
 
 impl FsConnectorSource {
@@ -46,15 +59,32 @@ impl FsConnectorSource {
             .map(|addr| source_props.insert("connector_node_addr".to_string(), addr));
         let config =
             ConnectorProperties::extract(source_props).map_err(|e| ConnectorError(e.into()))?;
+        let parse_error_policy =
+            ParseErrorPolicy::from_props(&properties).map_err(|e| ConnectorError(e.into()))?;
+        let rate_limit =
+            rate_limit_from_props(&properties).map_err(|e| ConnectorError(e.into()))?;
 
         Ok(Self {
             config,
             columns,
             properties,
             parser_config,
+            parse_error_policy,
+            rate_limit: Arc::new(AtomicU32::new(rate_limit)),
+            paused: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// Change the rate limit of the running reader streams. `0` means unlimited.
+    pub fn set_rate_limit(&self, rate_limit: u32) {
+        self.rate_limit.store(rate_limit, Ordering::Relaxed);
+    }
+
+    /// Pause or resume the running reader streams.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
     fn get_target_columns(&self, column_ids: Vec<ColumnId>) -> Result<Vec<SourceColumnDesc>> {
         column_ids
             .iter()
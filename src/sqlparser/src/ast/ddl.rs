@@ -20,7 +20,9 @@ use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::ast::{display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName};
+use crate::ast::{
+    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, SqlOption,
+};
 use crate::tokenizer::Token;
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
@@ -75,6 +77,10 @@ pub enum AlterTableOperation {
     ChangeOwner {
         new_owner_name: Ident,
     },
+    /// `SET PARALLELISM <parallelism>`
+    SetParallelism {
+        parallelism: u64,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -88,14 +94,33 @@ pub enum AlterIndexOperation {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterViewOperation {
-    RenameView { view_name: ObjectName },
+    RenameView {
+        view_name: ObjectName,
+    },
+    ChangeOwner {
+        new_owner_name: Ident,
+    },
+    /// `SUSPEND`, only for materialized views.
+    Suspend,
+    /// `RESUME`, only for materialized views.
+    Resume,
+    /// `SET PARALLELISM <parallelism>`, only for materialized views.
+    SetParallelism {
+        parallelism: u64,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterSinkOperation {
-    RenameSink { sink_name: ObjectName },
+    RenameSink {
+        sink_name: ObjectName,
+    },
+    /// `SET PARALLELISM <parallelism>`
+    SetParallelism {
+        parallelism: u64,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -103,6 +128,7 @@ pub enum AlterSinkOperation {
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterSourceOperation {
     RenameSource { source_name: ObjectName },
+    SetSourceProps { changed_props: Vec<SqlOption> },
 }
 
 impl fmt::Display for AlterTableOperation {
@@ -157,6 +183,9 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::ChangeOwner { new_owner_name } => {
                 write!(f, "OWNER TO {}", new_owner_name)
             }
+            AlterTableOperation::SetParallelism { parallelism } => {
+                write!(f, "SET PARALLELISM {}", parallelism)
+            }
         }
     }
 }
@@ -177,6 +206,18 @@ impl fmt::Display for AlterViewOperation {
             AlterViewOperation::RenameView { view_name } => {
                 write!(f, "RENAME TO {view_name}")
             }
+            AlterViewOperation::ChangeOwner { new_owner_name } => {
+                write!(f, "OWNER TO {}", new_owner_name)
+            }
+            AlterViewOperation::Suspend => {
+                write!(f, "SUSPEND")
+            }
+            AlterViewOperation::Resume => {
+                write!(f, "RESUME")
+            }
+            AlterViewOperation::SetParallelism { parallelism } => {
+                write!(f, "SET PARALLELISM {}", parallelism)
+            }
         }
     }
 }
@@ -187,6 +228,9 @@ impl fmt::Display for AlterSinkOperation {
             AlterSinkOperation::RenameSink { sink_name } => {
                 write!(f, "RENAME TO {sink_name}")
             }
+            AlterSinkOperation::SetParallelism { parallelism } => {
+                write!(f, "SET PARALLELISM {}", parallelism)
+            }
         }
     }
 }
@@ -197,6 +241,9 @@ impl fmt::Display for AlterSourceOperation {
             AlterSourceOperation::RenameSource { source_name } => {
                 write!(f, "RENAME TO {source_name}")
             }
+            AlterSourceOperation::SetSourceProps { changed_props } => {
+                write!(f, "SET ({})", display_comma_separated(changed_props))
+            }
         }
     }
 }
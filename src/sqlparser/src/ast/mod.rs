@@ -798,6 +798,8 @@ pub enum ShowObject {
     Connection { schema: Option<Ident> },
     Function { schema: Option<Ident> },
     Indexes { table: ObjectName },
+    ProcessList,
+    Jobs,
 }
 
 impl fmt::Display for ShowObject {
@@ -831,6 +833,8 @@ impl fmt::Display for ShowObject {
             ShowObject::Connection { schema } => write!(f, "CONNECTIONS{}", fmt_schema(schema)),
             ShowObject::Function { schema } => write!(f, "FUNCTIONS{}", fmt_schema(schema)),
             ShowObject::Indexes { table } => write!(f, "INDEXES FROM {}", table),
+            ShowObject::ProcessList => f.write_str("PROCESSLIST"),
+            ShowObject::Jobs => f.write_str("JOBS"),
         }
     }
 }
@@ -935,6 +939,11 @@ impl fmt::Display for ExplainOptions {
     }
 }
 
+/// Ids of the creating streaming jobs to cancel, as reported by `SHOW JOBS`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JobIdents(pub Vec<u32>);
+
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1034,6 +1043,10 @@ pub enum Statement {
     CreateSink { stmt: CreateSinkStatement },
     /// CREATE CONNECTION
     CreateConnection { stmt: CreateConnectionStatement },
+    /// CREATE SECRET
+    CreateSecret { stmt: CreateSecretStatement },
+    /// ALTER SECRET
+    AlterSecret { stmt: AlterSecretStatement },
     /// CREATE FUNCTION
     ///
     /// Postgres: https://www.postgresql.org/docs/15/sql-createfunction.html
@@ -1101,6 +1114,20 @@ pub enum Statement {
         /// `CASCADE` or `RESTRICT`
         option: Option<ReferentialAction>,
     },
+    /// DROP OWNED BY
+    DropOwned {
+        /// The user(s) whose objects should be dropped
+        owned_by: Vec<Ident>,
+        /// `CASCADE` or `RESTRICT`
+        option: Option<ReferentialAction>,
+    },
+    /// REASSIGN OWNED BY ... TO ...
+    ReassignOwned {
+        /// The user(s) whose objects should be reassigned
+        owned_by: Vec<Ident>,
+        /// The user that should become the new owner
+        new_owner: Ident,
+    },
     /// SET <variable>
     ///
     /// Note: this is not a standard SQL statement, but it is supported by at
@@ -1209,6 +1236,17 @@ pub enum Statement {
     ///
     /// Note: RisingWave specific statement.
     Flush,
+    /// KILL process_id
+    ///
+    /// Cancel the batch query currently being run by the session with the given process id.
+    Kill(i32),
+    /// CANCEL JOBS
+    ///
+    /// Cancel the creating streaming jobs (e.g. materialized views being created) with the given
+    /// job ids. The job ids can be looked up with `SHOW JOBS`.
+    ///
+    /// Note: RisingWave specific statement.
+    CancelJobs(JobIdents),
 }
 
 impl fmt::Display for Statement {
@@ -1472,6 +1510,8 @@ impl fmt::Display for Statement {
             ),
             Statement::CreateSink { stmt } => write!(f, "CREATE SINK {}", stmt,),
             Statement::CreateConnection { stmt } => write!(f, "CREATE CONNECTION {}", stmt,),
+            Statement::CreateSecret { stmt } => write!(f, "CREATE SECRET {}", stmt,),
+            Statement::AlterSecret { stmt } => write!(f, "ALTER SECRET {}", stmt,),
             Statement::AlterTable { name, operation } => {
                 write!(f, "ALTER TABLE {} {}", name, operation)
             }
@@ -1504,6 +1544,25 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::DropOwned { owned_by, option } => {
+                write!(f, "DROP OWNED BY {}", display_comma_separated(owned_by))?;
+                if let Some(op) = option {
+                    write!(f, " {}", op)?;
+                }
+                Ok(())
+            }
+            Statement::ReassignOwned {
+                owned_by,
+                new_owner,
+            } => {
+                write!(
+                    f,
+                    "REASSIGN OWNED BY {} TO {}",
+                    display_comma_separated(owned_by),
+                    new_owner
+                )?;
+                Ok(())
+            }
             Statement::SetVariable {
                 local,
                 variable,
@@ -1675,6 +1734,12 @@ impl fmt::Display for Statement {
             Statement::Flush => {
                 write!(f, "FLUSH")
             }
+            Statement::Kill(process_id) => {
+                write!(f, "KILL {}", process_id)
+            }
+            Statement::CancelJobs(jobs) => {
+                write!(f, "CANCEL JOBS {}", display_comma_separated(&jobs.0))
+            }
             Statement::BEGIN { modes } => {
                 write!(f, "BEGIN")?;
                 if !modes.is_empty() {
@@ -2045,6 +2110,7 @@ pub enum ObjectType {
     Database,
     User,
     Connection,
+    Secret,
 }
 
 impl fmt::Display for ObjectType {
@@ -2060,6 +2126,7 @@ impl fmt::Display for ObjectType {
             ObjectType::Database => "DATABASE",
             ObjectType::User => "USER",
             ObjectType::Connection => "CONNECTION",
+            ObjectType::Secret => "SECRET",
         })
     }
 }
@@ -2086,9 +2153,12 @@ impl ParseTo for ObjectType {
             ObjectType::User
         } else if parser.parse_keyword(Keyword::CONNECTION) {
             ObjectType::Connection
+        } else if parser.parse_keyword(Keyword::SECRET) {
+            ObjectType::Secret
         } else {
             return parser.expected(
-                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, SINK, SCHEMA, DATABASE, USER or CONNECTION after DROP",
+                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, SINK, SCHEMA, DATABASE, USER, \
+                 CONNECTION or SECRET after DROP",
                 parser.peek_token(),
             );
         };
@@ -235,20 +235,40 @@ impl SourceSchemaV2 {
                 RowFormat::Maxwell => SourceSchema::Maxwell,
                 RowFormat::CanalJson => SourceSchema::CanalJson,
                 RowFormat::Csv => {
-                    let mut chars = consume_string_from_options(&options, "delimiter")?.0;
-                    if chars.len() != 1 {
-                        return Err(ParserError::ParserError(format!(
-                            "The delimiter should be a char, but got {:?}",
-                            chars
-                        )));
+                    let delimiter = consume_string_from_options(&options, "delimiter")?.0;
+                    if delimiter.is_empty() {
+                        return Err(ParserError::ParserError(
+                            "The delimiter should not be empty".to_string(),
+                        ));
                     }
-                    let delimiter = chars.remove(0) as u8;
+                    let consume_char_from_options =
+                        |key: &str| -> Result<Option<char>, ParserError> {
+                            try_consume_string_from_options(&options, key)
+                                .map(|s| {
+                                    let mut chars = s.0.chars();
+                                    match (chars.next(), chars.next()) {
+                                        (Some(c), None) => Ok(c),
+                                        _ => Err(ParserError::ParserError(format!(
+                                            "The {} should be a char, but got {:?}",
+                                            key, s.0
+                                        ))),
+                                    }
+                                })
+                                .transpose()
+                        };
+                    let quote = consume_char_from_options("quote")?;
+                    let escape = consume_char_from_options("escape")?;
+                    let null_literal =
+                        try_consume_string_from_options(&options, "null").map(|s| s.0);
                     let has_header = try_consume_string_from_options(&options, "without_header")
                         .map(|s| s.0 == "false")
                         .unwrap_or(true);
                     SourceSchema::Csv(CsvInfo {
                         delimiter,
                         has_header,
+                        quote,
+                        escape,
+                        null_literal,
                     })
                 }
                 RowFormat::Native => todo!(),
@@ -366,8 +386,17 @@ impl fmt::Display for DebeziumAvroSchema {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvInfo {
-    pub delimiter: u8,
+    /// The field delimiter, which may be longer than one character.
+    pub delimiter: String,
     pub has_header: bool,
+    /// The quote character. `None` means the default `"`.
+    pub quote: Option<char>,
+    /// The character used to escape a quote inside a quoted field. `None`
+    /// means RFC-4180 style doubled quotes.
+    pub escape: Option<char>,
+    /// The string that is decoded as `NULL`. Empty fields are always decoded
+    /// as `NULL`.
+    pub null_literal: Option<String>,
 }
 
 impl fmt::Display for CsvInfo {
@@ -380,6 +409,15 @@ impl fmt::Display for CsvInfo {
             ));
         }
         impl_fmt_display!(delimiter, v, self);
+        if let Some(quote) = self.quote {
+            v.push(format!("QUOTE {}", quote));
+        }
+        if let Some(escape) = self.escape {
+            v.push(format!("ESCAPE {}", escape));
+        }
+        if let Some(null_literal) = &self.null_literal {
+            v.push(format!("NULL {}", null_literal));
+        }
         v.iter().join(" ").fmt(f)
     }
 }
@@ -609,6 +647,96 @@ impl fmt::Display for CreateConnectionStatement {
     }
 }
 
+// sql_grammar!(CreateSecretStatement {
+//     if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS],
+//     secret_name: Ident,
+//     with_properties: AstOption<WithProperties>,
+//     [Keyword::AS],
+//     credential: Value,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateSecretStatement {
+    pub if_not_exists: bool,
+    pub secret_name: ObjectName,
+    pub with_properties: WithProperties,
+    pub credential: Value,
+}
+
+impl ParseTo for CreateSecretStatement {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], p);
+        impl_parse_to!(secret_name: ObjectName, p);
+        impl_parse_to!(with_properties: WithProperties, p);
+        if with_properties.0.is_empty() {
+            return Err(ParserError::ParserError(
+                "secret properties not provided".to_string(),
+            ));
+        }
+        p.expect_keyword(Keyword::AS)?;
+        let credential = p.parse_value()?;
+
+        Ok(Self {
+            if_not_exists,
+            secret_name,
+            with_properties,
+            credential,
+        })
+    }
+}
+
+impl fmt::Display for CreateSecretStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], v, self);
+        impl_fmt_display!(secret_name, v, self);
+        impl_fmt_display!(with_properties, v, self);
+        impl_fmt_display!([Keyword::AS], v);
+        impl_fmt_display!(credential, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
+// sql_grammar!(AlterSecretStatement {
+//     secret_name: Ident,
+//     with_properties: AstOption<WithProperties>,
+//     [Keyword::AS],
+//     credential: Value,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AlterSecretStatement {
+    pub secret_name: ObjectName,
+    pub with_properties: WithProperties,
+    pub credential: Value,
+}
+
+impl ParseTo for AlterSecretStatement {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(secret_name: ObjectName, p);
+        impl_parse_to!(with_properties: WithProperties, p);
+        p.expect_keyword(Keyword::AS)?;
+        let credential = p.parse_value()?;
+
+        Ok(Self {
+            secret_name,
+            with_properties,
+            credential,
+        })
+    }
+}
+
+impl fmt::Display for AlterSecretStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(secret_name, v, self);
+        impl_fmt_display!(with_properties, v, self);
+        impl_fmt_display!([Keyword::AS], v);
+        impl_fmt_display!(credential, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AstVec<T>(pub Vec<T>);
@@ -57,6 +57,9 @@ pub enum Value {
     },
     /// `NULL` value
     Null,
+    /// `SECRET <secret_name>` reference to a secret created with `CREATE SECRET`, resolved to the
+    /// actual value on compute nodes. Only valid as the value of a WITH option.
+    SecretRef(String),
 }
 
 impl fmt::Display for Value {
@@ -111,6 +114,7 @@ impl fmt::Display for Value {
                 Ok(())
             }
             Value::Null => write!(f, "NULL"),
+            Value::SecretRef(v) => write!(f, "SECRET {}", v),
         }
     }
 }
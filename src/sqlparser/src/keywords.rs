@@ -14,10 +14,9 @@
 //! 1) a list of constants for every keyword that
 //! can appear in [crate::tokenizer::Word::keyword]:
 //!    pub const KEYWORD = "KEYWORD"
-//! 2) an `ALL_KEYWORDS` array with every keyword in it
-//!     This is not a list of *reserved* keywords: some of these can be
-//!     parsed as identifiers if the parser decides so. This means that
-//!     new keywords can be added here without affecting the parse result.
+//! 2) an `ALL_KEYWORDS` array with every keyword in it This is not a list of *reserved* keywords:
+//!    some of these can be parsed as identifiers if the parser decides so. This means that new
+//!    keywords can be added here without affecting the parse result.
 //!
 //!     As a matter of fact, most of these keywords are not used at all
 //!     and could be removed.
@@ -107,6 +106,7 @@ define_keywords!(
     CACHE,
     CALL,
     CALLED,
+    CANCEL,
     CARDINALITY,
     CASCADE,
     CASCADED,
@@ -274,8 +274,11 @@ define_keywords!(
     IS,
     ISNULL,
     ISOLATION,
+    JOB,
+    JOBS,
     JOIN,
     KEY,
+    KILL,
     LANGUAGE,
     LARGE,
     LAST,
@@ -350,7 +353,9 @@ define_keywords!(
     OVER,
     OVERLAPS,
     OVERLAY,
+    OWNED,
     OWNER,
+    PARALLELISM,
     PARAMETER,
     PARQUET,
     PARTITION,
@@ -375,6 +380,7 @@ define_keywords!(
     PRIMARY,
     PRIVILEGES,
     PROCEDURE,
+    PROCESSLIST,
     PURGE,
     RANGE,
     RANK,
@@ -382,6 +388,7 @@ define_keywords!(
     READ,
     READS,
     REAL,
+    REASSIGN,
     RECURSIVE,
     REF,
     REFERENCES,
@@ -404,6 +411,7 @@ define_keywords!(
     REPLACE,
     RESTRICT,
     RESULT,
+    RESUME,
     RETURN,
     RETURNING,
     RETURNS,
@@ -422,6 +430,7 @@ define_keywords!(
     SCROLL,
     SEARCH,
     SECOND,
+    SECRET,
     SELECT,
     SENSITIVE,
     SEQUENCE,
@@ -466,6 +475,7 @@ define_keywords!(
     SUCCEEDS,
     SUM,
     SUPERUSER,
+    SUSPEND,
     SYMMETRIC,
     SYNC,
     SYSTEM,
@@ -234,6 +234,7 @@ impl Parser {
                 }),
                 Keyword::GRANT => Ok(self.parse_grant()?),
                 Keyword::REVOKE => Ok(self.parse_revoke()?),
+                Keyword::REASSIGN => Ok(self.parse_reassign_owned()?),
                 Keyword::START => Ok(self.parse_start_transaction()?),
                 Keyword::ABORT => Ok(Statement::Abort),
                 // `BEGIN` is a nonstandard but common alias for the
@@ -249,6 +250,8 @@ impl Parser {
                 Keyword::PREPARE => Ok(self.parse_prepare()?),
                 Keyword::COMMENT => Ok(self.parse_comment()?),
                 Keyword::FLUSH => Ok(Statement::Flush),
+                Keyword::KILL => Ok(self.parse_kill()?),
+                Keyword::CANCEL => Ok(self.parse_cancel_job()?),
                 _ => self.expected(
                     "an SQL statement",
                     Token::Word(w).with_location(token.location),
@@ -270,6 +273,30 @@ impl Parser {
         Ok(Statement::Truncate { table_name })
     }
 
+    pub fn parse_kill(&mut self) -> Result<Statement, ParserError> {
+        let process_id = self.parse_literal_uint()?;
+        let process_id = i32::try_from(process_id)
+            .map_err(|_| ParserError::ParserError(format!("invalid process id: {}", process_id)))?;
+        Ok(Statement::Kill(process_id))
+    }
+
+    pub fn parse_cancel_job(&mut self) -> Result<Statement, ParserError> {
+        if !self.parse_keyword(Keyword::JOBS) && !self.parse_keyword(Keyword::JOB) {
+            return self.expected("JOBS or JOB after CANCEL", self.peek_token());
+        }
+        let mut job_ids = vec![];
+        loop {
+            let job_id = self.parse_literal_uint()?;
+            let job_id = u32::try_from(job_id)
+                .map_err(|_| ParserError::ParserError(format!("invalid job id: {}", job_id)))?;
+            job_ids.push(job_id);
+            if !self.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(Statement::CancelJobs(JobIdents(job_ids)))
+    }
+
     pub fn parse_analyze(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
 
@@ -1273,6 +1300,9 @@ impl Parser {
             Token::LongArrow => Some(BinaryOperator::LongArrow),
             Token::HashArrow => Some(BinaryOperator::HashArrow),
             Token::HashLongArrow => Some(BinaryOperator::HashLongArrow),
+            Token::AtArrow => Some(BinaryOperator::Contains),
+            Token::ArrowAt => Some(BinaryOperator::Contained),
+            Token::QuestionMark => Some(BinaryOperator::Exists),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
@@ -1566,7 +1596,10 @@ impl Parser {
             | Token::Arrow
             | Token::LongArrow
             | Token::HashArrow
-            | Token::HashLongArrow => Ok(P::Other),
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::ArrowAt
+            | Token::QuestionMark => Ok(P::Other),
             Token::Word(w) if w.keyword == Keyword::AT => {
                 match (self.peek_nth_token(1).token, self.peek_nth_token(2).token) {
                     (Token::Word(w), Token::Word(w2))
@@ -1861,6 +1894,8 @@ impl Parser {
             self.parse_create_sink(or_replace)
         } else if self.parse_keyword(Keyword::CONNECTION) {
             self.parse_create_connection()
+        } else if self.parse_keyword(Keyword::SECRET) {
+            self.parse_create_secret()
         } else if self.parse_keyword(Keyword::FUNCTION) {
             self.parse_create_function(or_replace, temporary)
         } else if or_replace {
@@ -1970,6 +2005,18 @@ impl Parser {
         })
     }
 
+    // CREATE
+    // SECRET
+    // [IF NOT EXISTS]?
+    // <secret_name: Ident>
+    // WITH (properties)
+    // AS <credential>
+    pub fn parse_create_secret(&mut self) -> Result<Statement, ParserError> {
+        Ok(Statement::CreateSecret {
+            stmt: CreateSecretStatement::parse_to(self)?,
+        })
+    }
+
     pub fn parse_create_function(
         &mut self,
         or_replace: bool,
@@ -2136,6 +2183,9 @@ impl Parser {
         if self.parse_keyword(Keyword::FUNCTION) {
             return self.parse_drop_function();
         }
+        if self.parse_keyword(Keyword::OWNED) {
+            return self.parse_drop_owned();
+        }
         Ok(Statement::Drop(DropStatement::parse_to(self)?))
     }
 
@@ -2158,6 +2208,35 @@ impl Parser {
         })
     }
 
+    /// ```sql
+    /// DROP OWNED BY name [, ...] [ CASCADE | RESTRICT ]
+    /// ```
+    fn parse_drop_owned(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::BY)?;
+        let owned_by = self.parse_comma_separated(Parser::parse_identifier)?;
+        let option = match self.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]) {
+            Some(Keyword::CASCADE) => Some(ReferentialAction::Cascade),
+            Some(Keyword::RESTRICT) => Some(ReferentialAction::Restrict),
+            _ => None,
+        };
+        Ok(Statement::DropOwned { owned_by, option })
+    }
+
+    /// ```sql
+    /// REASSIGN OWNED BY old_role [, ...] TO new_role
+    /// ```
+    fn parse_reassign_owned(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::OWNED)?;
+        self.expect_keyword(Keyword::BY)?;
+        let owned_by = self.parse_comma_separated(Parser::parse_identifier)?;
+        self.expect_keyword(Keyword::TO)?;
+        let new_owner = self.parse_identifier()?;
+        Ok(Statement::ReassignOwned {
+            owned_by,
+            new_owner,
+        })
+    }
+
     fn parse_drop_function_desc(&mut self) -> Result<DropFunctionDesc, ParserError> {
         let name = self.parse_object_name()?;
 
@@ -2570,7 +2649,11 @@ impl Parser {
     pub fn parse_sql_option(&mut self) -> Result<SqlOption, ParserError> {
         let name = self.parse_object_name()?;
         self.expect_token(&Token::Eq)?;
-        let value = self.parse_value()?;
+        let value = if self.parse_keyword(Keyword::SECRET) {
+            Value::SecretRef(self.parse_identifier()?.real_value())
+        } else {
+            self.parse_value()?
+        };
         Ok(SqlOption { name, value })
     }
 
@@ -2606,18 +2689,31 @@ impl Parser {
             self.parse_alter_sink()
         } else if self.parse_keyword(Keyword::SOURCE) {
             self.parse_alter_source()
+        } else if self.parse_keyword(Keyword::SECRET) {
+            self.parse_alter_secret()
         } else if self.parse_keyword(Keyword::USER) {
             self.parse_alter_user()
         } else if self.parse_keyword(Keyword::SYSTEM) {
             self.parse_alter_system()
         } else {
             self.expected(
-                "TABLE, INDEX, MATERIALIZED, VIEW, SINK, SOURCE, USER or SYSTEM after ALTER",
+                "TABLE, INDEX, MATERIALIZED, VIEW, SINK, SOURCE, SECRET, USER or SYSTEM after ALTER",
                 self.peek_token(),
             )
         }
     }
 
+    // ALTER
+    // SECRET
+    // <secret_name: Ident>
+    // WITH (properties)
+    // AS <credential>
+    pub fn parse_alter_secret(&mut self) -> Result<Statement, ParserError> {
+        Ok(Statement::AlterSecret {
+            stmt: AlterSecretStatement::parse_to(self)?,
+        })
+    }
+
     pub fn parse_alter_user(&mut self) -> Result<Statement, ParserError> {
         Ok(Statement::AlterUser(AlterUserStatement::parse_to(self)?))
     }
@@ -2700,8 +2796,14 @@ impl Parser {
                 );
             };
             AlterTableOperation::AlterColumn { column_name, op }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::PARALLELISM]) {
+            let parallelism = self.parse_set_parallelism_value()?;
+            AlterTableOperation::SetParallelism { parallelism }
         } else {
-            return self.expected("ADD, RENAME or DROP after ALTER TABLE", self.peek_token());
+            return self.expected(
+                "ADD, RENAME, DROP or SET PARALLELISM after ALTER TABLE",
+                self.peek_token(),
+            );
         };
         Ok(Statement::AlterTable {
             name: table_name,
@@ -2737,10 +2839,27 @@ impl Parser {
             } else {
                 return self.expected("TO after RENAME", self.peek_token());
             }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            let owner_name: Ident = self.parse_identifier()?;
+            AlterViewOperation::ChangeOwner {
+                new_owner_name: owner_name,
+            }
+        } else if materialized && self.parse_keyword(Keyword::SUSPEND) {
+            AlterViewOperation::Suspend
+        } else if materialized && self.parse_keyword(Keyword::RESUME) {
+            AlterViewOperation::Resume
+        } else if materialized && self.parse_keywords(&[Keyword::SET, Keyword::PARALLELISM]) {
+            let parallelism = self.parse_set_parallelism_value()?;
+            AlterViewOperation::SetParallelism { parallelism }
         } else {
             return self.expected(
                 &format!(
-                    "RENAME after ALTER {}VIEW",
+                    "RENAME or OWNER TO{} after ALTER {}VIEW",
+                    if materialized {
+                        " or SUSPEND or RESUME or SET PARALLELISM"
+                    } else {
+                        ""
+                    },
                     if materialized { "MATERIALIZED " } else { "" }
                 ),
                 self.peek_token(),
@@ -2763,8 +2882,14 @@ impl Parser {
             } else {
                 return self.expected("TO after RENAME", self.peek_token());
             }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::PARALLELISM]) {
+            let parallelism = self.parse_set_parallelism_value()?;
+            AlterSinkOperation::SetParallelism { parallelism }
         } else {
-            return self.expected("RENAME after ALTER SINK", self.peek_token());
+            return self.expected(
+                "RENAME or SET PARALLELISM after ALTER SINK",
+                self.peek_token(),
+            );
         };
 
         Ok(Statement::AlterSink {
@@ -2782,8 +2907,14 @@ impl Parser {
             } else {
                 return self.expected("TO after RENAME", self.peek_token());
             }
+        } else if self.parse_keyword(Keyword::SET) {
+            let changed_props = self.parse_options()?;
+            if changed_props.is_empty() {
+                return self.expected("(...) after SET", self.peek_token());
+            }
+            AlterSourceOperation::SetSourceProps { changed_props }
         } else {
-            return self.expected("RENAME after ALTER SOURCE", self.peek_token());
+            return self.expected("RENAME or SET after ALTER SOURCE", self.peek_token());
         };
 
         Ok(Statement::AlterSource {
@@ -2792,6 +2923,12 @@ impl Parser {
         })
     }
 
+    /// Parse the value of a `SET PARALLELISM` clause, with an optional `TO` or `=` in front.
+    fn parse_set_parallelism_value(&mut self) -> Result<u64, ParserError> {
+        let _ = self.parse_keyword(Keyword::TO) || self.consume_token(&Token::Eq);
+        self.parse_literal_uint()
+    }
+
     pub fn parse_alter_system(&mut self) -> Result<Statement, ParserError> {
         self.expect_keyword(Keyword::SET)?;
         let param = self.parse_identifier()?;
@@ -3774,6 +3911,12 @@ impl Parser {
                         return self.expected("from after indexes", self.peek_token());
                     }
                 }
+                Keyword::PROCESSLIST => {
+                    return Ok(Statement::ShowObjects(ShowObject::ProcessList));
+                }
+                Keyword::JOBS => {
+                    return Ok(Statement::ShowObjects(ShowObject::Jobs));
+                }
                 _ => {}
             }
         }
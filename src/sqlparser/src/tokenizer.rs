@@ -156,6 +156,12 @@ pub enum Token {
     HashArrow,
     /// `#>>`, extract JSON sub-object at the specified path as text in PostgreSQL
     HashLongArrow,
+    /// `@>`, does the left JSON value contain the right JSON value in PostgreSQL
+    AtArrow,
+    /// `<@`, is the left JSON value contained in the right JSON value in PostgreSQL
+    ArrowAt,
+    /// `?`, does the string exist as a top-level key within the JSON value in PostgreSQL
+    QuestionMark,
 }
 
 impl fmt::Display for Token {
@@ -219,6 +225,9 @@ impl fmt::Display for Token {
             Token::LongArrow => f.write_str("->>"),
             Token::HashArrow => f.write_str("#>"),
             Token::HashLongArrow => f.write_str("#>>"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::ArrowAt => f.write_str("<@"),
+            Token::QuestionMark => f.write_str("?"),
         }
     }
 }
@@ -671,6 +680,7 @@ impl<'a> Tokenizer<'a> {
                         }
                         Some('>') => self.consume_and_return(chars, Token::Neq),
                         Some('<') => self.consume_and_return(chars, Token::ShiftLeft),
+                        Some('@') => self.consume_and_return(chars, Token::ArrowAt),
                         _ => Ok(Some(Token::Lt)),
                     }
                 }
@@ -728,7 +738,15 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Sharp)),
                     }
                 }
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '@' => {
+                    chars.next(); // consume the '@'
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::AtArrow),
+                        // a regular '@' operator
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
+                '?' => self.consume_and_return(chars, Token::QuestionMark),
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
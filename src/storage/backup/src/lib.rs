@@ -35,6 +35,7 @@ pub mod storage;
 
 use std::collections::HashSet;
 use std::hash::Hasher;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use itertools::Itertools;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
@@ -56,10 +57,21 @@ pub struct MetaSnapshotMetadata {
     pub ssts: Vec<HummockSstableObjectId>,
     pub max_committed_epoch: u64,
     pub safe_epoch: u64,
+    /// Size of the encoded snapshot in bytes.
+    #[serde(default)]
+    pub size: u64,
+    /// UNIX timestamp in seconds when the snapshot was taken.
+    /// `None` for snapshots taken before this field was introduced.
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+    /// Id of the snapshot this one is based on. `None` for a full snapshot, `Some` for an
+    /// incremental one that must be reconstructed from its delta chain.
+    #[serde(default)]
+    pub base_id: Option<MetaSnapshotId>,
 }
 
 impl MetaSnapshotMetadata {
-    pub fn new(id: MetaSnapshotId, v: &HummockVersion) -> Self {
+    pub fn new(id: MetaSnapshotId, v: &HummockVersion, size: u64) -> Self {
         Self {
             id,
             hummock_version_id: v.id,
@@ -68,10 +80,41 @@ impl MetaSnapshotMetadata {
                 .collect_vec(),
             max_committed_epoch: v.max_committed_epoch,
             safe_epoch: v.safe_epoch,
+            size,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|d| d.as_secs()),
+            base_id: None,
         }
     }
 }
 
+/// Returns the ids of the snapshots that `id` depends on for reconstruction: the delta chain
+/// from `id` (exclusive) back to and including its base full snapshot. Returns an empty vector
+/// for a full snapshot.
+pub fn snapshot_base_chain(
+    manifest: &MetaSnapshotManifest,
+    id: MetaSnapshotId,
+) -> Vec<MetaSnapshotId> {
+    let mut chain = vec![];
+    let mut current_id = id;
+    while let Some(base_id) = manifest
+        .snapshot_metadata
+        .iter()
+        .find(|m| m.id == current_id)
+        .and_then(|m| m.base_id)
+    {
+        // A base is always older than its dependents. Guard against a corrupt manifest.
+        if base_id >= current_id {
+            break;
+        }
+        chain.push(base_id);
+        current_id = base_id;
+    }
+    chain
+}
+
 /// `MetaSnapshotManifest` is the source of truth for valid `MetaSnapshot`.
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MetaSnapshotManifest {
@@ -104,6 +147,9 @@ impl From<&MetaSnapshotMetadata> for PbMetaSnapshotMetadata {
             hummock_version_id: m.hummock_version_id,
             max_committed_epoch: m.max_committed_epoch,
             safe_epoch: m.safe_epoch,
+            size: m.size,
+            timestamp: m.timestamp,
+            base_id: m.base_id,
         }
     }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 
 use bytes::{Buf, BufMut};
@@ -237,11 +237,262 @@ impl ClusterMetadata {
     }
 }
 
+/// An incremental snapshot that stores only the difference from a base snapshot. It's
+/// reconstructed into a full [`MetaSnapshot`] by applying the delta chain onto its base full
+/// snapshot at read time.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MetaSnapshotDelta {
+    pub format_version: u32,
+    pub id: MetaSnapshotId,
+    /// Id of the snapshot this delta is based on, which may be a delta itself.
+    pub base_id: MetaSnapshotId,
+    pub metadata_delta: ClusterMetadataDelta,
+}
+
+impl MetaSnapshotDelta {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        buf.put_u32_le(self.format_version);
+        buf.put_u64_le(self.id);
+        buf.put_u64_le(self.base_id);
+        self.metadata_delta.encode_to(&mut buf);
+        let checksum = xxhash64_checksum(&buf);
+        buf.put_u64_le(checksum);
+        buf
+    }
+
+    pub fn decode(mut buf: &[u8]) -> BackupResult<Self> {
+        let checksum = (&buf[buf.len() - 8..]).get_u64_le();
+        xxhash64_verify(&buf[..buf.len() - 8], checksum)?;
+        let format_version = buf.get_u32_le();
+        let id = buf.get_u64_le();
+        let base_id = buf.get_u64_le();
+        let metadata_delta = ClusterMetadataDelta::decode(buf)?;
+        Ok(Self {
+            format_version,
+            id,
+            base_id,
+            metadata_delta,
+        })
+    }
+}
+
+/// Difference of a collection of metadata items, identified by their encoded representation. An
+/// updated item shows up as one removal plus one upsert.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CollectionDelta<T> {
+    pub upserts: Vec<T>,
+    pub removals: Vec<T>,
+}
+
+impl<T: prost::Message + Default + Clone> CollectionDelta<T> {
+    fn diff(base: &[T], current: &[T]) -> Self {
+        let base_set: HashSet<Vec<u8>> = base.iter().map(|m| m.encode_to_vec()).collect();
+        let current_set: HashSet<Vec<u8>> = current.iter().map(|m| m.encode_to_vec()).collect();
+        let upserts = current
+            .iter()
+            .filter(|m| !base_set.contains(&m.encode_to_vec()))
+            .cloned()
+            .collect();
+        let removals = base
+            .iter()
+            .filter(|m| !current_set.contains(&m.encode_to_vec()))
+            .cloned()
+            .collect();
+        Self { upserts, removals }
+    }
+
+    fn apply_to(&self, target: &mut Vec<T>) {
+        let removals: HashSet<Vec<u8>> = self.removals.iter().map(|m| m.encode_to_vec()).collect();
+        target.retain(|m| !removals.contains(&m.encode_to_vec()));
+        target.extend(self.upserts.iter().cloned());
+    }
+
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        ClusterMetadata::encode_prost_message_list(&self.upserts.iter().collect_vec(), buf);
+        ClusterMetadata::encode_prost_message_list(&self.removals.iter().collect_vec(), buf);
+    }
+
+    fn decode(buf: &mut &[u8]) -> BackupResult<Self> {
+        let upserts = ClusterMetadata::decode_prost_message_list(buf)?;
+        let removals = ClusterMetadata::decode_prost_message_list(buf)?;
+        Ok(Self { upserts, removals })
+    }
+}
+
+/// Difference between two [`ClusterMetadata`]. Singleton fields are always stored in full since
+/// they are replaced wholesale anyway; list fields store only changed items.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClusterMetadataDelta {
+    pub default_cf_upserts: HashMap<Vec<u8>, Vec<u8>>,
+    pub default_cf_removals: Vec<Vec<u8>>,
+    pub hummock_version: HummockVersion,
+    pub version_stats: HummockVersionStats,
+    pub compaction_groups: CollectionDelta<CompactionGroup>,
+    pub table_fragments: CollectionDelta<TableFragments>,
+    pub user_info: CollectionDelta<UserInfo>,
+    pub database: CollectionDelta<Database>,
+    pub schema: CollectionDelta<Schema>,
+    pub table: CollectionDelta<Table>,
+    pub index: CollectionDelta<Index>,
+    pub sink: CollectionDelta<Sink>,
+    pub source: CollectionDelta<Source>,
+    pub view: CollectionDelta<View>,
+    pub function: CollectionDelta<Function>,
+    pub connection: CollectionDelta<Connection>,
+    pub system_param: SystemParams,
+    pub cluster_id: String,
+}
+
+impl ClusterMetadataDelta {
+    pub fn diff(base: &ClusterMetadata, current: &ClusterMetadata) -> Self {
+        let default_cf_upserts = current
+            .default_cf
+            .iter()
+            .filter(|(k, v)| base.default_cf.get(*k) != Some(v))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let default_cf_removals = base
+            .default_cf
+            .keys()
+            .filter(|k| !current.default_cf.contains_key(*k))
+            .cloned()
+            .collect();
+        Self {
+            default_cf_upserts,
+            default_cf_removals,
+            hummock_version: current.hummock_version.clone(),
+            version_stats: current.version_stats.clone(),
+            compaction_groups: CollectionDelta::diff(
+                &base.compaction_groups,
+                &current.compaction_groups,
+            ),
+            table_fragments: CollectionDelta::diff(&base.table_fragments, &current.table_fragments),
+            user_info: CollectionDelta::diff(&base.user_info, &current.user_info),
+            database: CollectionDelta::diff(&base.database, &current.database),
+            schema: CollectionDelta::diff(&base.schema, &current.schema),
+            table: CollectionDelta::diff(&base.table, &current.table),
+            index: CollectionDelta::diff(&base.index, &current.index),
+            sink: CollectionDelta::diff(&base.sink, &current.sink),
+            source: CollectionDelta::diff(&base.source, &current.source),
+            view: CollectionDelta::diff(&base.view, &current.view),
+            function: CollectionDelta::diff(&base.function, &current.function),
+            connection: CollectionDelta::diff(&base.connection, &current.connection),
+            system_param: current.system_param.clone(),
+            cluster_id: current.cluster_id.clone(),
+        }
+    }
+
+    pub fn apply_to(&self, target: &mut ClusterMetadata) {
+        for key in &self.default_cf_removals {
+            target.default_cf.remove(key);
+        }
+        for (key, value) in &self.default_cf_upserts {
+            target.default_cf.insert(key.clone(), value.clone());
+        }
+        target.hummock_version = self.hummock_version.clone();
+        target.version_stats = self.version_stats.clone();
+        self.compaction_groups
+            .apply_to(&mut target.compaction_groups);
+        self.table_fragments.apply_to(&mut target.table_fragments);
+        self.user_info.apply_to(&mut target.user_info);
+        self.database.apply_to(&mut target.database);
+        self.schema.apply_to(&mut target.schema);
+        self.table.apply_to(&mut target.table);
+        self.index.apply_to(&mut target.index);
+        self.sink.apply_to(&mut target.sink);
+        self.source.apply_to(&mut target.source);
+        self.view.apply_to(&mut target.view);
+        self.function.apply_to(&mut target.function);
+        self.connection.apply_to(&mut target.connection);
+        target.system_param = self.system_param.clone();
+        target.cluster_id = self.cluster_id.clone();
+    }
+
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        let upsert_keys = self.default_cf_upserts.keys().collect_vec();
+        let upsert_values = self.default_cf_upserts.values().collect_vec();
+        ClusterMetadata::encode_prost_message_list(&upsert_keys, buf);
+        ClusterMetadata::encode_prost_message_list(&upsert_values, buf);
+        ClusterMetadata::encode_prost_message_list(
+            &self.default_cf_removals.iter().collect_vec(),
+            buf,
+        );
+        ClusterMetadata::encode_prost_message(&self.hummock_version, buf);
+        ClusterMetadata::encode_prost_message(&self.version_stats, buf);
+        self.compaction_groups.encode_to(buf);
+        self.table_fragments.encode_to(buf);
+        self.user_info.encode_to(buf);
+        self.database.encode_to(buf);
+        self.schema.encode_to(buf);
+        self.table.encode_to(buf);
+        self.index.encode_to(buf);
+        self.sink.encode_to(buf);
+        self.source.encode_to(buf);
+        self.view.encode_to(buf);
+        self.function.encode_to(buf);
+        self.connection.encode_to(buf);
+        ClusterMetadata::encode_prost_message(&self.system_param, buf);
+        ClusterMetadata::encode_prost_message(&self.cluster_id, buf);
+    }
+
+    pub fn decode(mut buf: &[u8]) -> BackupResult<Self> {
+        let upsert_keys: Vec<Vec<u8>> = ClusterMetadata::decode_prost_message_list(&mut buf)?;
+        let upsert_values: Vec<Vec<u8>> = ClusterMetadata::decode_prost_message_list(&mut buf)?;
+        let default_cf_upserts = upsert_keys
+            .into_iter()
+            .zip_eq_fast(upsert_values.into_iter())
+            .collect();
+        let default_cf_removals: Vec<Vec<u8>> =
+            ClusterMetadata::decode_prost_message_list(&mut buf)?;
+        let hummock_version = ClusterMetadata::decode_prost_message(&mut buf)?;
+        let version_stats = ClusterMetadata::decode_prost_message(&mut buf)?;
+        let compaction_groups = CollectionDelta::decode(&mut buf)?;
+        let table_fragments = CollectionDelta::decode(&mut buf)?;
+        let user_info = CollectionDelta::decode(&mut buf)?;
+        let database = CollectionDelta::decode(&mut buf)?;
+        let schema = CollectionDelta::decode(&mut buf)?;
+        let table = CollectionDelta::decode(&mut buf)?;
+        let index = CollectionDelta::decode(&mut buf)?;
+        let sink = CollectionDelta::decode(&mut buf)?;
+        let source = CollectionDelta::decode(&mut buf)?;
+        let view = CollectionDelta::decode(&mut buf)?;
+        let function = CollectionDelta::decode(&mut buf)?;
+        let connection = CollectionDelta::decode(&mut buf)?;
+        let system_param = ClusterMetadata::decode_prost_message(&mut buf)?;
+        let cluster_id = ClusterMetadata::decode_prost_message(&mut buf)?;
+
+        Ok(Self {
+            default_cf_upserts,
+            default_cf_removals,
+            hummock_version,
+            version_stats,
+            compaction_groups,
+            table_fragments,
+            user_info,
+            database,
+            schema,
+            table,
+            index,
+            sink,
+            source,
+            view,
+            function,
+            connection,
+            system_param,
+            cluster_id,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use risingwave_pb::catalog::Table;
     use risingwave_pb::hummock::{CompactionGroup, TableStats};
 
-    use crate::meta_snapshot::{ClusterMetadata, MetaSnapshot};
+    use crate::meta_snapshot::{
+        ClusterMetadata, ClusterMetadataDelta, MetaSnapshot, MetaSnapshotDelta,
+    };
 
     #[test]
     fn test_snapshot_encoding_decoding() {
@@ -279,4 +530,69 @@ mod tests {
         let decoded = ClusterMetadata::decode(buf.as_slice()).unwrap();
         assert_eq!(raw, decoded);
     }
+
+    #[test]
+    fn test_delta_diff_apply() {
+        let mut base = ClusterMetadata::default();
+        base.default_cf.insert(vec![0], vec![1]);
+        base.default_cf.insert(vec![2], vec![3]);
+        base.table.push(Table {
+            id: 1,
+            ..Default::default()
+        });
+        base.table.push(Table {
+            id: 2,
+            ..Default::default()
+        });
+
+        let mut current = base.clone();
+        // One removed kv, one updated kv, one new kv.
+        current.default_cf.remove(&vec![0]);
+        current.default_cf.insert(vec![2], vec![4]);
+        current.default_cf.insert(vec![5], vec![6]);
+        // One removed table, one updated table, one new table.
+        current.table.retain(|t| t.id != 1);
+        current
+            .table
+            .iter_mut()
+            .for_each(|t| t.name = "t".to_string());
+        current.table.push(Table {
+            id: 3,
+            ..Default::default()
+        });
+        current.hummock_version.id = 100;
+
+        let delta = ClusterMetadataDelta::diff(&base, &current);
+        assert_eq!(delta.table.upserts.len(), 2);
+        assert_eq!(delta.table.removals.len(), 2);
+
+        let mut reconstructed = base;
+        delta.apply_to(&mut reconstructed);
+        reconstructed.table.sort_by_key(|t| t.id);
+        assert_eq!(reconstructed, current);
+    }
+
+    #[test]
+    fn test_delta_encoding_decoding() {
+        let mut base = ClusterMetadata::default();
+        base.table.push(Table {
+            id: 1,
+            ..Default::default()
+        });
+        let mut current = base.clone();
+        current.table.push(Table {
+            id: 2,
+            ..Default::default()
+        });
+        current.default_cf.insert(vec![0], vec![1]);
+        let raw = MetaSnapshotDelta {
+            format_version: 1,
+            id: 2,
+            base_id: 1,
+            metadata_delta: ClusterMetadataDelta::diff(&base, &current),
+        };
+        let encoded = raw.encode();
+        let decoded = MetaSnapshotDelta::decode(&encoded).unwrap();
+        assert_eq!(raw, decoded);
+    }
 }
@@ -18,7 +18,7 @@ use std::sync::Arc;
 use itertools::Itertools;
 use risingwave_object_store::object::{ObjectError, ObjectStoreRef};
 
-use crate::meta_snapshot::MetaSnapshot;
+use crate::meta_snapshot::{MetaSnapshot, MetaSnapshotDelta};
 use crate::{
     BackupError, BackupResult, MetaSnapshotId, MetaSnapshotManifest, MetaSnapshotMetadata,
 };
@@ -28,10 +28,13 @@ pub type BoxedMetaSnapshotStorage = Box<dyn MetaSnapshotStorage>;
 
 #[async_trait::async_trait]
 pub trait MetaSnapshotStorage: 'static + Sync + Send {
-    /// Creates a snapshot.
+    /// Creates a full snapshot.
     async fn create(&self, snapshot: &MetaSnapshot) -> BackupResult<()>;
 
-    /// Gets a snapshot by id.
+    /// Creates an incremental snapshot. Its base must already exist in the storage.
+    async fn create_delta(&self, delta: &MetaSnapshotDelta) -> BackupResult<()>;
+
+    /// Gets a snapshot by id, reconstructing it from its delta chain if it's incremental.
     async fn get(&self, id: MetaSnapshotId) -> BackupResult<MetaSnapshot>;
 
     /// Gets local snapshot manifest.
@@ -101,6 +104,11 @@ impl ObjectStoreMetaSnapshotStorage {
         format!("{}/{}.snapshot", self.path, id)
     }
 
+    async fn read_snapshot_object(&self, id: MetaSnapshotId) -> BackupResult<bytes::Bytes> {
+        let path = self.get_snapshot_path(id);
+        Ok(self.store.read(&path, None).await?)
+    }
+
     #[allow(dead_code)]
     fn get_snapshot_id_from_path(path: &str) -> MetaSnapshotId {
         let split = path.split(&['/', '.']).collect_vec();
@@ -116,7 +124,9 @@ impl ObjectStoreMetaSnapshotStorage {
 impl MetaSnapshotStorage for ObjectStoreMetaSnapshotStorage {
     async fn create(&self, snapshot: &MetaSnapshot) -> BackupResult<()> {
         let path = self.get_snapshot_path(snapshot.id);
-        self.store.upload(&path, snapshot.encode().into()).await?;
+        let data = snapshot.encode();
+        let size = data.len() as u64;
+        self.store.upload(&path, data.into()).await?;
 
         // update manifest last
         let mut new_manifest = (**self.manifest.read()).clone();
@@ -126,15 +136,67 @@ impl MetaSnapshotStorage for ObjectStoreMetaSnapshotStorage {
             .push(MetaSnapshotMetadata::new(
                 snapshot.id,
                 &snapshot.metadata.hummock_version,
+                size,
             ));
         self.update_manifest(new_manifest).await?;
         Ok(())
     }
 
+    async fn create_delta(&self, delta: &MetaSnapshotDelta) -> BackupResult<()> {
+        let path = self.get_snapshot_path(delta.id);
+        let data = delta.encode();
+        let size = data.len() as u64;
+        self.store.upload(&path, data.into()).await?;
+
+        // update manifest last
+        let mut new_manifest = (**self.manifest.read()).clone();
+        new_manifest.manifest_id += 1;
+        new_manifest.snapshot_metadata.push(MetaSnapshotMetadata {
+            base_id: Some(delta.base_id),
+            ..MetaSnapshotMetadata::new(delta.id, &delta.metadata_delta.hummock_version, size)
+        });
+        self.update_manifest(new_manifest).await?;
+        Ok(())
+    }
+
     async fn get(&self, id: MetaSnapshotId) -> BackupResult<MetaSnapshot> {
-        let path = self.get_snapshot_path(id);
-        let data = self.store.read(&path, None).await?;
-        MetaSnapshot::decode(&data)
+        // Collect the delta chain from `id` back to its base full snapshot.
+        let manifest = self.manifest();
+        let mut delta_ids = vec![];
+        let mut current_id = id;
+        loop {
+            let metadata = manifest
+                .snapshot_metadata
+                .iter()
+                .find(|m| m.id == current_id)
+                .ok_or_else(|| {
+                    BackupError::Other(anyhow::anyhow!(format!(
+                        "snapshot {} not found in manifest",
+                        current_id
+                    )))
+                })?;
+            match metadata.base_id {
+                Some(base_id) => {
+                    delta_ids.push(current_id);
+                    current_id = base_id;
+                }
+                None => break,
+            }
+        }
+        let mut snapshot = MetaSnapshot::decode(&self.read_snapshot_object(current_id).await?)?;
+        for delta_id in delta_ids.into_iter().rev() {
+            let delta = MetaSnapshotDelta::decode(&self.read_snapshot_object(delta_id).await?)?;
+            if delta.base_id != snapshot.id {
+                return Err(BackupError::Other(anyhow::anyhow!(format!(
+                    "inconsistent delta chain: expected base {}, actual {}",
+                    snapshot.id, delta.base_id
+                ))));
+            }
+            delta.metadata_delta.apply_to(&mut snapshot.metadata);
+            snapshot.format_version = delta.format_version;
+            snapshot.id = delta.id;
+        }
+        Ok(snapshot)
     }
 
     fn manifest(&self) -> Arc<MetaSnapshotManifest> {
@@ -187,6 +249,10 @@ impl MetaSnapshotStorage for DummyMetaSnapshotStorage {
         panic!("should not create from DummyBackupStorage")
     }
 
+    async fn create_delta(&self, _delta: &MetaSnapshotDelta) -> BackupResult<()> {
+        panic!("should not create from DummyBackupStorage")
+    }
+
     async fn get(&self, _id: MetaSnapshotId) -> BackupResult<MetaSnapshot> {
         panic!("should not get from DummyBackupStorage")
     }
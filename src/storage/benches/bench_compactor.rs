@@ -62,6 +62,7 @@ pub fn default_writer_opts() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Fill(CachePriority::High),
+        storage_class: None,
     }
 }
 
@@ -91,6 +92,7 @@ async fn build_table(
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        data_key: None,
     };
     let writer = sstable_store.create_sst_writer(
         sstable_object_id,
@@ -98,6 +100,7 @@ async fn build_table(
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Fill(CachePriority::High),
+            storage_class: None,
         },
     );
     let mut builder =
@@ -180,6 +183,7 @@ async fn compact<I: HummockIterator<Direction = Forward>>(iter: I, sstable_store
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        data_key: None,
     };
     let mut builder =
         CapacitySplitTableBuilder::for_test(LocalTableBuilderFactory::new(32, sstable_store, opt));
@@ -194,6 +198,7 @@ async fn compact<I: HummockIterator<Direction = Forward>>(iter: I, sstable_store
         is_target_l0_or_lbase: false,
         split_by_table: false,
         split_weight_by_vnode: 0,
+        storage_class: None,
     };
     Compactor::compact_and_build_sst(
         &mut builder,
@@ -23,6 +23,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use futures::future::try_join_all;
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
+use risingwave_common::config::RetryConfig;
 use risingwave_hummock_sdk::key::{FullKey, UserKey};
 use risingwave_object_store::object::{ObjectStore, ObjectStoreImpl, S3ObjectStore};
 use risingwave_storage::hummock::multi_builder::{CapacitySplitTableBuilder, TableBuilderFactory};
@@ -71,6 +72,7 @@ impl<F: SstableWriterFactory> TableBuilderFactory for LocalTableBuilderFactory<F
             capacity_hint: Some(self.options.capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            storage_class: None,
         };
         let writer = self
             .writer_factory
@@ -90,6 +92,7 @@ fn get_builder_options(capacity_mb: usize) -> SstableBuilderOptions {
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        data_key: None,
     }
 }
 
@@ -131,9 +134,14 @@ fn bench_builder(
 
     let metrics = Arc::new(ObjectStoreMetrics::unused());
     let object_store = runtime.block_on(async {
-        S3ObjectStore::new(bucket.to_string(), metrics.clone())
-            .await
-            .monitored(metrics)
+        S3ObjectStore::new(
+            bucket.to_string(),
+            metrics.clone(),
+            None,
+            RetryConfig::default(),
+        )
+        .await
+        .monitored(metrics)
     });
     let object_store = Arc::new(ObjectStoreImpl::S3(object_store));
     let sstable_store = Arc::new(SstableStore::new(
@@ -20,7 +20,10 @@ use risingwave_pb::compactor::compactor_service_server::CompactorService;
 use risingwave_pb::compactor::{EchoRequest, EchoResponse};
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    GetStreamStatsRequest, GetStreamStatsResponse, GetTableReadStatsRequest,
+    GetTableReadStatsResponse, HeapProfilingRequest, HeapProfilingResponse, ListFailpointsRequest,
+    ListFailpointsResponse, ProfilingRequest, ProfilingResponse, SetFailpointRequest,
+    SetFailpointResponse, StackTraceRequest, StackTraceResponse,
 };
 use tonic::{Request, Response, Status};
 
@@ -73,4 +76,53 @@ impl MonitorService for MonitorServiceImpl {
             "profiling unimplemented in compactor",
         ))
     }
+
+    async fn heap_profiling(
+        &self,
+        _request: Request<HeapProfilingRequest>,
+    ) -> Result<Response<HeapProfilingResponse>, Status> {
+        Err(Status::unimplemented(
+            "heap_profiling unimplemented in compactor",
+        ))
+    }
+
+    async fn get_stream_stats(
+        &self,
+        _request: Request<GetStreamStatsRequest>,
+    ) -> Result<Response<GetStreamStatsResponse>, Status> {
+        Err(Status::unimplemented(
+            "get_stream_stats unimplemented in compactor",
+        ))
+    }
+
+    async fn get_table_read_stats(
+        &self,
+        _request: Request<GetTableReadStatsRequest>,
+    ) -> Result<Response<GetTableReadStatsResponse>, Status> {
+        Err(Status::unimplemented(
+            "get_table_read_stats unimplemented in compactor",
+        ))
+    }
+
+    async fn list_failpoints(
+        &self,
+        _request: Request<ListFailpointsRequest>,
+    ) -> Result<Response<ListFailpointsResponse>, Status> {
+        Ok(Response::new(ListFailpointsResponse {
+            failpoints: fail::list().into_iter().collect(),
+        }))
+    }
+
+    async fn set_failpoint(
+        &self,
+        request: Request<SetFailpointRequest>,
+    ) -> Result<Response<SetFailpointResponse>, Status> {
+        let req = request.into_inner();
+        if req.actions.is_empty() {
+            fail::remove(&req.name);
+        } else {
+            fail::cfg(req.name, &req.actions).map_err(Status::invalid_argument)?;
+        }
+        Ok(Response::new(SetFailpointResponse::default()))
+    }
 }
@@ -38,7 +38,7 @@ use risingwave_storage::filter_key_extractor::{FilterKeyExtractorManager, Remote
 use risingwave_storage::hummock::compactor::{CompactionExecutor, CompactorContext};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
-    HummockMemoryCollector, MemoryLimiter, SstableObjectIdManager, SstableStore,
+    build_key_provider, HummockMemoryCollector, MemoryLimiter, SstableObjectIdManager, SstableStore,
 };
 use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, HummockMetrics, ObjectStoreMetrics,
@@ -137,15 +137,24 @@ pub async fn compactor_serve(
                 .expect("object store must be hummock for compactor server"),
             object_metrics,
             "Hummock",
+            (!storage_opts.resource_label.is_empty()).then(|| storage_opts.resource_label.clone()),
+            storage_opts.object_store_retry.clone(),
         )
         .await,
     );
-    let sstable_store = Arc::new(SstableStore::for_compactor(
+    let mut sstable_store = SstableStore::for_compactor(
         object_store,
         storage_opts.data_directory.to_string(),
         1 << 20, // set 1MB memory to avoid panic.
         meta_cache_capacity_bytes,
-    ));
+    );
+    if let Some(data_key_provider) = build_key_provider(&storage_opts.data_key_provider)
+        .await
+        .expect("failed to build the data key provider")
+    {
+        sstable_store.set_data_key_provider(data_key_provider);
+    }
+    let sstable_store = Arc::new(sstable_store);
 
     let telemetry_enabled = system_params_reader.telemetry_enabled();
 
@@ -28,7 +28,7 @@ use std::sync::Arc;
 use clap::Parser;
 use replay_impl::{get_replay_notification_client, GlobalReplayImpl};
 use risingwave_common::config::{
-    extract_storage_memory_config, load_config, StorageConfig, NO_OVERRIDE,
+    extract_storage_memory_config, load_config, RetryConfig, StorageConfig, NO_OVERRIDE,
 };
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_hummock_trace::{
@@ -99,8 +99,14 @@ async fn create_replay_hummock(r: Record, args: &Args) -> Result<impl GlobalRepl
 
     let compactor_metrics = Arc::new(CompactorMetrics::unused());
 
-    let object_store =
-        parse_remote_object_store(&args.object_storage, object_store_stats, "Hummock").await;
+    let object_store = parse_remote_object_store(
+        &args.object_storage,
+        object_store_stats,
+        "Hummock",
+        None,
+        RetryConfig::default(),
+    )
+    .await;
 
     let sstable_store = {
         let tiered_cache = TieredCache::none();
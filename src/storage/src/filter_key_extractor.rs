@@ -19,6 +19,7 @@ use std::sync::Arc;
 use itertools::Itertools;
 use parking_lot::RwLock;
 use risingwave_common::catalog::ColumnDesc;
+use risingwave_common::constants::hummock::PROPERTIES_BLOOM_FILTER_MODE_KEY;
 use risingwave_common::hash::VirtualNode;
 use risingwave_common::util::row_serde::OrderedRowSerde;
 use risingwave_common::util::sort_util::OrderType;
@@ -45,6 +46,17 @@ pub enum FilterKeyExtractorImpl {
 
 impl FilterKeyExtractorImpl {
     pub fn from_table(table_catalog: &Table) -> Self {
+        if table_catalog
+            .properties
+            .get(PROPERTIES_BLOOM_FILTER_MODE_KEY)
+            .map(|mode| mode == "full_key")
+            .unwrap_or(false)
+        {
+            // The table opted out of prefix filters, so build and check filters on full keys
+            // regardless of the prefix length hint.
+            return FilterKeyExtractorImpl::FullKey(FullKeyFilterKeyExtractor);
+        }
+
         let read_prefix_len = table_catalog.get_read_prefix_len_hint() as usize;
 
         if read_prefix_len == 0 || read_prefix_len > table_catalog.get_pk().len() {
@@ -27,6 +27,7 @@ use risingwave_backup::storage::{
     BoxedMetaSnapshotStorage, DummyMetaSnapshotStorage, ObjectStoreMetaSnapshotStorage,
 };
 use risingwave_backup::MetaSnapshotId;
+use risingwave_common::config::RetryConfig;
 use risingwave_common::system_param::local_manager::SystemParamsReaderRef;
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
 use risingwave_object_store::object::parse_remote_object_store;
@@ -48,6 +49,8 @@ async fn create_snapshot_store(config: &StoreConfig) -> StorageResult<BoxedMetaS
             &config.0,
             Arc::new(ObjectStoreMetrics::unused()),
             "Meta Backup",
+            None,
+            RetryConfig::default(),
         )
         .await,
     );
@@ -227,6 +227,17 @@ impl BlockCache {
         }
     }
 
+    /// Iterates over the keys of all cached blocks. See [`LruCache::for_all`] for the safety
+    /// requirements.
+    ///
+    /// [`LruCache::for_all`]: risingwave_common::cache::LruCache::for_all
+    pub fn for_all<F>(&self, mut f: F)
+    where
+        F: FnMut(&(HummockSstableObjectId, u64)),
+    {
+        self.inner.for_all(|key, _| f(key));
+    }
+
     fn hash(object_id: HummockSstableObjectId, block_idx: u64) -> u64 {
         let mut hasher = DefaultHasher::default();
         object_id.hash(&mut hasher);
@@ -0,0 +1,301 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fast restart support for compute nodes.
+//!
+//! On graceful shutdown the compute node dumps an index of its meta cache and block cache to a
+//! local file. On the next start the index is loaded back and the referenced meta and data blocks
+//! are refetched from the object store in the background, so a restarted node with a large
+//! working set serves from a warm cache instead of paying a full cold start.
+//!
+//! Only the cache *index* is persisted, never the block payloads themselves: blocks are
+//! immutable and can always be refetched by `(object_id, block_idx)`, which also means a stale
+//! index is harmless — refetching a vacuumed object simply fails and is skipped.
+
+use std::path::{Path, PathBuf};
+
+use bytes::{Buf, BufMut};
+use risingwave_common::cache::CachePriority;
+use risingwave_hummock_sdk::HummockSstableObjectId;
+use risingwave_pb::hummock::SstableInfo;
+
+use super::sstable::utils::{xxhash64_checksum, xxhash64_verify};
+use super::{CachePolicy, HummockError, HummockResult, SstableStoreRef};
+use crate::monitor::StoreLocalStatistic;
+
+const MAGIC: u32 = 0x6361_6368;
+const VERSION: u32 = 1;
+
+/// File name of the persisted cache index under `cache_recovery_dir`.
+const CACHE_RECOVERY_INDEX_FILE: &str = "cache-recovery.idx";
+
+pub fn cache_recovery_index_path(dir: &str) -> PathBuf {
+    Path::new(dir).join(CACHE_RECOVERY_INDEX_FILE)
+}
+
+/// Which meta and data blocks were cached when the node last shut down.
+#[derive(Debug, Default, PartialEq)]
+pub struct CacheRecoveryIndex {
+    entries: Vec<CacheRecoveryEntry>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CacheRecoveryEntry {
+    object_id: HummockSstableObjectId,
+    /// Copied from the cached [`SstableMeta`](super::SstableMeta), enough to refetch the meta
+    /// block without consulting the meta service.
+    meta_offset: u64,
+    file_size: u64,
+    /// Indexes of the data blocks that were cached.
+    block_indexes: Vec<u64>,
+}
+
+impl CacheRecoveryIndex {
+    /// Takes a snapshot of the current cache contents of `sstable_store`.
+    ///
+    /// Data blocks whose sstable meta is no longer cached are dropped: without the meta we can
+    /// neither locate the block in the object nor decode it, and the meta cache is large enough
+    /// that this loses little in practice.
+    pub fn snapshot(sstable_store: &SstableStoreRef) -> Self {
+        let mut entries = Vec::new();
+        sstable_store.get_meta_cache().for_all(|object_id, sst| {
+            entries.push(CacheRecoveryEntry {
+                object_id: *object_id,
+                meta_offset: sst.meta.meta_offset,
+                file_size: sst.meta.estimated_size as u64,
+                block_indexes: Vec::new(),
+            });
+        });
+        entries.sort_by_key(|entry| entry.object_id);
+        sstable_store
+            .get_block_cache()
+            .for_all(|(object_id, block_idx)| {
+                if let Ok(pos) = entries.binary_search_by_key(object_id, |entry| entry.object_id) {
+                    entries[pos].block_indexes.push(*block_idx);
+                }
+            });
+        for entry in &mut entries {
+            entry.block_indexes.sort_unstable();
+        }
+        Self { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of data blocks recorded in the index.
+    pub fn block_count(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| entry.block_indexes.len())
+            .sum()
+    }
+
+    /// Refills the caches of `sstable_store` from the object store.
+    ///
+    /// Objects that fail to load (e.g. vacuumed while the node was down) are skipped with a
+    /// warning. Blocks are inserted with low priority so that they never evict blocks fetched by
+    /// live queries after the restart.
+    pub async fn rehydrate(self, sstable_store: &SstableStoreRef) -> HummockResult<()> {
+        for entry in self.entries {
+            let sst_info = SstableInfo {
+                object_id: entry.object_id,
+                sst_id: entry.object_id,
+                meta_offset: entry.meta_offset,
+                file_size: entry.file_size,
+                ..Default::default()
+            };
+            let mut stats = StoreLocalStatistic::default();
+            stats.ignore();
+            let sst = match sstable_store.sstable(&sst_info, &mut stats).await {
+                Ok(sst) => sst,
+                Err(e) => {
+                    tracing::warn!(
+                        "skip rehydrating object {} as its meta cannot be loaded: {}",
+                        entry.object_id,
+                        e
+                    );
+                    continue;
+                }
+            };
+            for block_idx in entry.block_indexes {
+                if block_idx as usize >= sst.value().block_count() {
+                    // The index file does not match the object, e.g. it survived a data
+                    // directory switch. Refuse to read out of bounds.
+                    break;
+                }
+                if let Err(e) = sstable_store
+                    .get(
+                        sst.value(),
+                        block_idx as usize,
+                        CachePolicy::Fill(CachePriority::Low),
+                        &mut stats,
+                    )
+                    .await
+                {
+                    tracing::warn!(
+                        "skip rehydrating block {} of object {}: {}",
+                        block_idx,
+                        entry.object_id,
+                        e
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Format:
+    ///
+    /// ```plain
+    /// | magic (4B) | version (4B) | N (4B) |
+    /// | entry 0 | ... | entry N-1 |
+    /// | checksum (8B) |
+    /// ```
+    ///
+    /// Entry format:
+    ///
+    /// ```plain
+    /// | object id (8B) | meta offset (8B) | file size (8B) |
+    /// | M (4B) | block idx 0 (8B) | ... | block idx M-1 (8B) |
+    /// ```
+    pub fn encode_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.put_u32_le(MAGIC);
+        buf.put_u32_le(VERSION);
+        buf.put_u32_le(self.entries.len() as u32);
+        for entry in &self.entries {
+            buf.put_u64_le(entry.object_id);
+            buf.put_u64_le(entry.meta_offset);
+            buf.put_u64_le(entry.file_size);
+            buf.put_u32_le(entry.block_indexes.len() as u32);
+            for block_idx in &entry.block_indexes {
+                buf.put_u64_le(*block_idx);
+            }
+        }
+        let checksum = xxhash64_checksum(&buf);
+        buf.put_u64_le(checksum);
+        buf
+    }
+
+    pub fn decode(buf: &[u8]) -> HummockResult<Self> {
+        if buf.len() < 20 {
+            return Err(HummockError::decode_error("cache recovery index too short"));
+        }
+        let cursor = buf.len() - 8;
+        let checksum = (&buf[cursor..]).get_u64_le();
+        let buf = &mut &buf[..cursor];
+        xxhash64_verify(buf, checksum)?;
+
+        let magic = buf.get_u32_le();
+        if magic != MAGIC {
+            return Err(HummockError::magic_mismatch(MAGIC, magic));
+        }
+        let version = buf.get_u32_le();
+        if version != VERSION {
+            return Err(HummockError::invalid_format_version(version));
+        }
+
+        let entry_count = buf.get_u32_le() as usize;
+        let mut entries = Vec::with_capacity(entry_count);
+        for _ in 0..entry_count {
+            let object_id = buf.get_u64_le();
+            let meta_offset = buf.get_u64_le();
+            let file_size = buf.get_u64_le();
+            let block_idx_count = buf.get_u32_le() as usize;
+            let mut block_indexes = Vec::with_capacity(block_idx_count);
+            for _ in 0..block_idx_count {
+                block_indexes.push(buf.get_u64_le());
+            }
+            entries.push(CacheRecoveryEntry {
+                object_id,
+                meta_offset,
+                file_size,
+                block_indexes,
+            });
+        }
+        Ok(Self { entries })
+    }
+
+    /// Persists the index to `dir`, replacing any previous one atomically.
+    pub async fn dump(&self, dir: &str) -> HummockResult<()> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(HummockError::other)?;
+        let path = cache_recovery_index_path(dir);
+        let tmp_path = path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, self.encode_to_bytes())
+            .await
+            .map_err(HummockError::other)?;
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .map_err(HummockError::other)?;
+        Ok(())
+    }
+
+    /// Loads and removes the persisted index from `dir`, if any.
+    ///
+    /// The index is removed even if it fails to decode: it describes the cache of exactly one
+    /// past incarnation of the node, and must not be replayed after a later unclean restart.
+    pub async fn load(dir: &str) -> HummockResult<Option<Self>> {
+        let path = cache_recovery_index_path(dir);
+        let buf = match tokio::fs::read(&path).await {
+            Ok(buf) => buf,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(HummockError::other(e)),
+        };
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(HummockError::other)?;
+        Self::decode(&buf).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enc_dec() {
+        let index = CacheRecoveryIndex {
+            entries: vec![
+                CacheRecoveryEntry {
+                    object_id: 1,
+                    meta_offset: 1024,
+                    file_size: 2048,
+                    block_indexes: vec![0, 2, 5],
+                },
+                CacheRecoveryEntry {
+                    object_id: 42,
+                    meta_offset: 4096,
+                    file_size: 8192,
+                    block_indexes: vec![1],
+                },
+            ],
+        };
+        let buf = index.encode_to_bytes();
+        let decoded = CacheRecoveryIndex::decode(&buf).unwrap();
+        assert_eq!(index, decoded);
+    }
+
+    #[test]
+    fn test_decode_corrupted() {
+        let index = CacheRecoveryIndex::default();
+        let mut buf = index.encode_to_bytes();
+        *buf.first_mut().unwrap() ^= 1;
+        assert!(CacheRecoveryIndex::decode(&buf).is_err());
+        assert!(CacheRecoveryIndex::decode(&buf[..8]).is_err());
+    }
+}
@@ -35,7 +35,7 @@ use crate::hummock::compactor::{
 use crate::hummock::multi_builder::TableBuilderFactory;
 use crate::hummock::sstable::DEFAULT_ENTRY_SIZE;
 use crate::hummock::{
-    CachePolicy, FilterBuilder, HummockResult, MemoryLimiter, SstableBuilder,
+    CachePolicy, FilterAlgorithm, FilterBuilder, HummockResult, MemoryLimiter, SstableBuilder,
     SstableBuilderOptions, SstableObjectIdManagerRef, SstableWriterFactory, SstableWriterOptions,
 };
 use crate::monitor::StoreLocalStatistic;
@@ -48,6 +48,7 @@ pub struct RemoteBuilderFactory<W: SstableWriterFactory, F: FilterBuilder> {
     pub remote_rpc_cost: Arc<AtomicU64>,
     pub filter_key_extractor: Arc<FilterKeyExtractorImpl>,
     pub sstable_writer_factory: W,
+    pub storage_class: Option<String>,
     pub _phantom: PhantomData<F>,
 }
 
@@ -73,6 +74,7 @@ impl<W: SstableWriterFactory, F: FilterBuilder> TableBuilderFactory for RemoteBu
             capacity_hint: Some(self.options.capacity + self.options.block_capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            storage_class: self.storage_class.clone(),
         };
         let writer = self
             .sstable_writer_factory
@@ -128,6 +130,10 @@ pub struct TaskConfig {
     pub is_target_l0_or_lbase: bool,
     pub split_by_table: bool,
     pub split_weight_by_vnode: u32,
+    /// Storage class for output SSTs, e.g. "STANDARD_IA" on S3. `None` means the store default.
+    pub storage_class: Option<String>,
+    /// Filter implementation for output SSTs, see `CompactionConfig::filter_algorithm`.
+    pub filter_algorithm: FilterAlgorithm,
 }
 
 pub fn build_multi_compaction_filter(compact_task: &CompactTask) -> MultiCompactionFilter {
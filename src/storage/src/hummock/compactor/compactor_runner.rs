@@ -31,8 +31,8 @@ use crate::hummock::compactor::{CompactOutput, CompactionFilter, Compactor, Comp
 use crate::hummock::iterator::{Forward, HummockIterator, UnorderedMergeIteratorInner};
 use crate::hummock::sstable::CompactionDeleteRangesBuilder;
 use crate::hummock::{
-    CachePolicy, CompactionDeleteRanges, CompressionAlgorithm, HummockResult,
-    SstableBuilderOptions, SstableStoreRef,
+    CachePolicy, CompactionDeleteRanges, CompressionAlgorithm, DataKey, FilterAlgorithm,
+    HummockResult, SstableBuilderOptions, SstableStoreRef,
 };
 use crate::monitor::StoreLocalStatistic;
 
@@ -45,14 +45,28 @@ pub struct CompactorRunner {
 }
 
 impl CompactorRunner {
-    pub fn new(split_index: usize, context: Arc<CompactorContext>, task: CompactTask) -> Self {
+    pub fn new(
+        split_index: usize,
+        context: Arc<CompactorContext>,
+        task: CompactTask,
+        data_key: Option<DataKey>,
+    ) -> Self {
         let mut options: SstableBuilderOptions = context.storage_opts.as_ref().into();
         options.compression_algorithm = match task.compression_algorithm {
             0 => CompressionAlgorithm::None,
             1 => CompressionAlgorithm::Lz4,
+            3 => CompressionAlgorithm::ZstdDict,
             _ => CompressionAlgorithm::Zstd,
         };
         options.capacity = estimate_task_memory_capacity(context.clone(), &task);
+        options.data_key = data_key;
+        if task.filter_bits_per_key > 0 {
+            // Filter builders size themselves through the false positive rate, which
+            // `bloom_bits_per_key` derives from. Convert the explicit bits-per-key budget into
+            // the equivalent false positive rate.
+            options.bloom_false_positive =
+                (-(task.filter_bits_per_key as f64) * std::f64::consts::LN_2.powi(2)).exp();
+        }
 
         let key_range = KeyRange {
             left: Bytes::copy_from_slice(task.splits[split_index].get_left()),
@@ -74,6 +88,13 @@ impl CompactorRunner {
                     || task.target_level == task.base_level,
                 split_by_table: task.split_by_state_table,
                 split_weight_by_vnode: task.split_weight_by_vnode,
+                storage_class: (!task.cold_storage_class.is_empty())
+                    .then(|| task.cold_storage_class.clone()),
+                filter_algorithm: match task.filter_algorithm.as_str() {
+                    "Bloom" => FilterAlgorithm::Bloom,
+                    "Ribbon" => FilterAlgorithm::Ribbon,
+                    _ => FilterAlgorithm::Xor,
+                },
             },
         );
 
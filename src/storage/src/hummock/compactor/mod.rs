@@ -58,7 +58,10 @@ pub use self::compaction_utils::{CompactionStatistics, RemoteBuilderFactory, Tas
 pub use self::task_progress::TaskProgress;
 use super::multi_builder::CapacitySplitTableBuilder;
 use super::value::HummockValue;
-use super::{CompactionDeleteRanges, HummockResult, SstableBuilderOptions, Xor16FilterBuilder};
+use super::{
+    BloomFilterBuilder, CompactionDeleteRanges, HummockResult, RibbonFilterBuilder,
+    SstableBuilderOptions, Xor16FilterBuilder,
+};
 use crate::filter_key_extractor::FilterKeyExtractorImpl;
 use crate::hummock::compactor::compaction_utils::{
     build_multi_compaction_filter, estimate_task_memory_capacity, generate_splits,
@@ -69,8 +72,8 @@ use crate::hummock::iterator::{Forward, HummockIterator};
 use crate::hummock::multi_builder::{SplitTableOutput, TableBuilderFactory};
 use crate::hummock::vacuum::Vacuum;
 use crate::hummock::{
-    validate_ssts, BatchSstableWriterFactory, FilterBuilder, HummockError, SstableWriterFactory,
-    StreamingSstableWriterFactory, Xor8FilterBuilder,
+    validate_ssts, BatchSstableWriterFactory, FilterAlgorithm, FilterBuilder, HummockError,
+    SstableWriterFactory, StreamingSstableWriterFactory, Xor8FilterBuilder,
 };
 use crate::monitor::{CompactorMetrics, StoreLocalStatistic};
 
@@ -308,12 +311,47 @@ impl Compactor {
         }
 
         drop(memory_detector);
+
+        // Generate a fresh data key for the output SSTs if encryption at rest is enabled for the
+        // compaction group. Every task gets its own data key wrapped with the currently
+        // configured KMS key, so rotating the KMS key and re-compacting re-encrypts the data.
+        let data_key = match compact_task
+            .encryption_key_id
+            .as_deref()
+            .filter(|key_id| !key_id.is_empty())
+        {
+            Some(key_id) => {
+                let result = match context.sstable_store.data_key_provider() {
+                    Some(data_key_provider) => data_key_provider.generate_data_key(key_id).await,
+                    None => Err(HummockError::encryption_error(
+                        "the compaction group requires encryption at rest but no data key \
+                         provider is configured",
+                    )),
+                };
+                match result {
+                    Ok(data_key) => Some(data_key),
+                    Err(err) => {
+                        tracing::warn!("Failed to generate a data key {:#?}", err);
+                        task_status = TaskStatus::ExecuteFailed;
+                        Self::compact_done(&mut compact_task, context.clone(), vec![], task_status)
+                            .await;
+                        return task_status;
+                    }
+                }
+            }
+            None => None,
+        };
+
         context.compactor_metrics.compact_task_pending_num.inc();
         for (split_index, _) in compact_task.splits.iter().enumerate() {
             let filter = multi_filter.clone();
             let multi_filter_key_extractor = multi_filter_key_extractor.clone();
-            let compactor_runner =
-                CompactorRunner::new(split_index, compactor_context.clone(), compact_task.clone());
+            let compactor_runner = CompactorRunner::new(
+                split_index,
+                compactor_context.clone(),
+                compact_task.clone(),
+                data_key.clone(),
+            );
             let del_agg = delete_range_agg.clone();
             let task_progress = task_progress_guard.progress.clone();
             let runner = async move {
@@ -871,53 +909,107 @@ impl Compactor {
             > self.context.storage_opts.min_sst_size_for_streaming_upload
         {
             let factory = StreamingSstableWriterFactory::new(self.context.sstable_store.clone());
-            if self.task_config.is_target_l0_or_lbase {
-                self.compact_key_range_impl::<_, Xor16FilterBuilder>(
-                    factory,
-                    iter,
-                    compaction_filter,
-                    del_agg,
-                    filter_key_extractor,
-                    task_progress.clone(),
-                )
-                .verbose_instrument_await("compact")
-                .await?
-            } else {
-                self.compact_key_range_impl::<_, Xor8FilterBuilder>(
-                    factory,
-                    iter,
-                    compaction_filter,
-                    del_agg,
-                    filter_key_extractor,
-                    task_progress.clone(),
-                )
-                .verbose_instrument_await("compact")
-                .await?
+            match self.task_config.filter_algorithm {
+                FilterAlgorithm::Bloom => {
+                    self.compact_key_range_impl::<_, BloomFilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Ribbon => {
+                    self.compact_key_range_impl::<_, RibbonFilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Xor if self.task_config.is_target_l0_or_lbase => {
+                    self.compact_key_range_impl::<_, Xor16FilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Xor => {
+                    self.compact_key_range_impl::<_, Xor8FilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
             }
         } else {
             let factory = BatchSstableWriterFactory::new(self.context.sstable_store.clone());
-            if self.task_config.is_target_l0_or_lbase {
-                self.compact_key_range_impl::<_, Xor16FilterBuilder>(
-                    factory,
-                    iter,
-                    compaction_filter,
-                    del_agg,
-                    filter_key_extractor,
-                    task_progress.clone(),
-                )
-                .verbose_instrument_await("compact")
-                .await?
-            } else {
-                self.compact_key_range_impl::<_, Xor8FilterBuilder>(
-                    factory,
-                    iter,
-                    compaction_filter,
-                    del_agg,
-                    filter_key_extractor,
-                    task_progress.clone(),
-                )
-                .verbose_instrument_await("compact")
-                .await?
+            match self.task_config.filter_algorithm {
+                FilterAlgorithm::Bloom => {
+                    self.compact_key_range_impl::<_, BloomFilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Ribbon => {
+                    self.compact_key_range_impl::<_, RibbonFilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Xor if self.task_config.is_target_l0_or_lbase => {
+                    self.compact_key_range_impl::<_, Xor16FilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
+                FilterAlgorithm::Xor => {
+                    self.compact_key_range_impl::<_, Xor8FilterBuilder>(
+                        factory,
+                        iter,
+                        compaction_filter,
+                        del_agg,
+                        filter_key_extractor,
+                        task_progress.clone(),
+                    )
+                    .verbose_instrument_await("compact")
+                    .await?
+                }
             }
         };
 
@@ -1004,6 +1096,7 @@ impl Compactor {
             remote_rpc_cost: self.get_id_time.clone(),
             filter_key_extractor,
             sstable_writer_factory: writer_factory,
+            storage_class: self.task_config.storage_class.clone(),
             _phantom: PhantomData,
         };
 
@@ -46,7 +46,7 @@ use crate::hummock::utils::MemoryTracker;
 use crate::hummock::value::HummockValue;
 use crate::hummock::{
     create_monotonic_events_from_compaction_delete_events, CachePolicy, CompactionDeleteRanges,
-    HummockError, HummockResult, SstableBuilderOptions,
+    FilterAlgorithm, HummockError, HummockResult, SstableBuilderOptions,
 };
 
 const GC_DELETE_KEYS_FOR_FLUSH: bool = false;
@@ -458,6 +458,8 @@ impl SharedBufferCompactRunner {
                 is_target_l0_or_lbase: true,
                 split_by_table: false,
                 split_weight_by_vnode,
+                storage_class: None,
+                filter_algorithm: FilterAlgorithm::default(),
             },
         );
         Self {
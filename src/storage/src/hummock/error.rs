@@ -61,6 +61,8 @@ enum HummockErrorInner {
     SstableUploadError(String),
     #[error("Read backup error {0}.")]
     ReadBackupError(String),
+    #[error("Encryption error {0}.")]
+    EncryptionError(String),
     #[error("Other error {0}.")]
     Other(String),
 }
@@ -158,6 +160,10 @@ impl HummockError {
         HummockErrorInner::ReadBackupError(error.to_string()).into()
     }
 
+    pub fn encryption_error(error: impl ToString) -> HummockError {
+        HummockErrorInner::EncryptionError(error.to_string()).into()
+    }
+
     pub fn other(error: impl ToString) -> HummockError {
         HummockErrorInner::Other(error.to_string()).into()
     }
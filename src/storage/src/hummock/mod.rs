@@ -57,6 +57,7 @@ pub mod test_utils;
 pub mod utils;
 pub use utils::MemoryLimiter;
 pub mod backup_reader;
+pub mod cache_recovery;
 pub mod event_handler;
 pub mod local_version;
 pub mod observer_manager;
@@ -31,6 +31,11 @@ pub const DEFAULT_BLOCK_SIZE: usize = 4 * 1024;
 pub const DEFAULT_RESTART_INTERVAL: usize = 16;
 pub const DEFAULT_ENTRY_SIZE: usize = 24; // table_id(u64) + primary_key(u64) + epoch(u64)
 
+/// Total bytes of uncompressed block payloads to collect before training a zstd dictionary.
+pub const ZSTD_DICT_SAMPLE_SIZE_LIMIT: usize = 1 << 20;
+/// Maximum size of a trained zstd dictionary.
+pub const ZSTD_DICT_MAX_SIZE: usize = 16 * 1024;
+
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum LenType {
@@ -153,6 +158,18 @@ pub struct Block {
 
 impl Block {
     pub fn decode(buf: Bytes, uncompressed_capacity: usize) -> HummockResult<Self> {
+        Self::decode_with_dict(buf, uncompressed_capacity, None)
+    }
+
+    /// Decodes a block, decompressing [`CompressionAlgorithm::ZstdDict`] blocks with the
+    /// per-SST dictionary stored in [`SstableMeta`].
+    ///
+    /// [`SstableMeta`]: crate::hummock::SstableMeta
+    pub fn decode_with_dict(
+        buf: Bytes,
+        uncompressed_capacity: usize,
+        zstd_dict: Option<&[u8]>,
+    ) -> HummockResult<Self> {
         // Verify checksum.
 
         let xxhash64_checksum = (&buf[buf.len() - 8..]).get_u64_le();
@@ -184,6 +201,16 @@ impl Block {
                 debug_assert_eq!(decoded.capacity(), uncompressed_capacity);
                 Bytes::from(decoded)
             }
+            CompressionAlgorithm::ZstdDict => {
+                let dict = zstd_dict.ok_or_else(|| {
+                    HummockError::decode_error("zstd dictionary is required to decode this block")
+                })?;
+                let decoded = zstd::bulk::Decompressor::with_dictionary(dict)
+                    .map_err(HummockError::decode_error)?
+                    .decompress(compressed_data, uncompressed_capacity)
+                    .map_err(HummockError::decode_error)?;
+                Bytes::from(decoded)
+            }
         };
 
         Ok(Self::decode_from_raw(buf))
@@ -404,6 +431,15 @@ pub struct BlockBuilder {
     // restart_points_type_index stores only the restart_point corresponding to each type change,
     // as an index, in order to reduce space usage
     restart_points_type_index: Vec<RestartPoint>,
+
+    /// Dictionary trained from the first blocks of the SST. `None` until enough samples have
+    /// been collected. Only used with [`CompressionAlgorithm::ZstdDict`].
+    zstd_dict: Option<Vec<u8>>,
+    /// Uncompressed block payloads collected as training samples while the dictionary does not
+    /// exist yet.
+    zstd_dict_samples: Vec<Vec<u8>>,
+    /// Total bytes in `zstd_dict_samples`.
+    zstd_dict_sample_size: usize,
 }
 
 impl BlockBuilder {
@@ -420,6 +456,9 @@ impl BlockBuilder {
             compression_algorithm: options.compression_algorithm,
             table_id: None,
             restart_points_type_index: Vec::default(),
+            zstd_dict: None,
+            zstd_dict_samples: vec![],
+            zstd_dict_sample_size: 0,
         }
     }
 
@@ -574,7 +613,20 @@ impl BlockBuilder {
             .put_u32_le(self.restart_points_type_index.len() as u32);
 
         self.buf.put_u32_le(self.table_id.unwrap());
-        match self.compression_algorithm {
+        if self.compression_algorithm == CompressionAlgorithm::ZstdDict && self.zstd_dict.is_none()
+        {
+            self.collect_zstd_dict_sample();
+        }
+        let compression_algorithm = if self.compression_algorithm == CompressionAlgorithm::ZstdDict
+            && self.zstd_dict.is_none()
+        {
+            // The dictionary has not been trained yet. Compress this block without it so that
+            // it stays readable with the dictionary-less decoder.
+            CompressionAlgorithm::Zstd
+        } else {
+            self.compression_algorithm
+        };
+        match compression_algorithm {
             CompressionAlgorithm::None => (),
             CompressionAlgorithm::Lz4 => {
                 let mut encoder = lz4::EncoderBuilder::new()
@@ -605,15 +657,53 @@ impl BlockBuilder {
                     .unwrap();
                 self.buf = writer.into_inner();
             }
+            CompressionAlgorithm::ZstdDict => {
+                let compressed =
+                    zstd::bulk::Compressor::with_dictionary(4, self.zstd_dict.as_ref().unwrap())
+                        .and_then(|mut compressor| compressor.compress(&self.buf[..]))
+                        .map_err(HummockError::encode_error)
+                        .unwrap();
+                self.buf.clear();
+                self.buf.extend_from_slice(&compressed);
+            }
         };
 
-        self.compression_algorithm.encode(&mut self.buf);
+        compression_algorithm.encode(&mut self.buf);
         let checksum = xxhash64_checksum(&self.buf);
         self.buf.put_u64_le(checksum);
 
         self.buf.as_ref()
     }
 
+    /// Collects the uncompressed payload of the current block as a dictionary training sample,
+    /// and trains the dictionary once enough samples have been accumulated.
+    fn collect_zstd_dict_sample(&mut self) {
+        self.zstd_dict_sample_size += self.buf.len();
+        self.zstd_dict_samples.push(self.buf.to_vec());
+        if self.zstd_dict_sample_size < ZSTD_DICT_SAMPLE_SIZE_LIMIT {
+            return;
+        }
+        let samples = std::mem::take(&mut self.zstd_dict_samples);
+        self.zstd_dict_sample_size = 0;
+        match zstd::dict::from_samples(&samples, ZSTD_DICT_MAX_SIZE) {
+            Ok(dict) => self.zstd_dict = Some(dict),
+            Err(e) => {
+                tracing::warn!(
+                    "failed to train zstd dictionary, compress with plain zstd instead: {}",
+                    e
+                );
+                // Give up on this SST instead of retrying on every block.
+                self.compression_algorithm = CompressionAlgorithm::Zstd;
+            }
+        }
+    }
+
+    /// Takes the dictionary trained for the current SST, if any. It must be stored in the SST's
+    /// meta to decode the blocks compressed with [`CompressionAlgorithm::ZstdDict`].
+    pub fn take_zstd_dict(&mut self) -> Vec<u8> {
+        self.zstd_dict.take().unwrap_or_default()
+    }
+
     /// Approximate block len (uncompressed).
     pub fn approximate_len(&self) -> usize {
         // block + restart_points + restart_points.len + restart_points_type_indices +
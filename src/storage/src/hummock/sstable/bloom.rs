@@ -55,7 +55,6 @@ impl<T: AsMut<[u8]>> BitSliceMut for T {
 }
 
 /// Bloom implements Bloom filter functionalities over a bit-slice of data.
-#[allow(dead_code)]
 #[derive(Clone)]
 pub struct BloomFilterReader {
     /// data of filter in bits
@@ -66,7 +65,6 @@ pub struct BloomFilterReader {
 
 impl BloomFilterReader {
     /// Creates a Bloom filter from a byte slice
-    #[allow(dead_code)]
     pub fn new(mut buf: Vec<u8>) -> Self {
         if buf.len() <= 1 {
             return Self { data: vec![], k: 0 };
@@ -76,12 +74,10 @@ impl BloomFilterReader {
         Self { data: buf, k }
     }
 
-    #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
 
-    #[allow(dead_code)]
     pub fn get_raw_data(&self) -> &[u8] {
         &self.data
     }
@@ -93,16 +89,15 @@ impl BloomFilterReader {
     ///     the hash;
     ///   - if the return value is true, then the table may or may not have the user key that has
     ///     the hash actually, a.k.a. we don't know the answer.
-    #[allow(dead_code)]
-    pub fn may_match(&self, mut h: u32) -> bool {
+    pub fn may_match(&self, mut h: u64) -> bool {
         if self.k > 30 || self.k == 00 {
             // potential new encoding for short Bloom filters
             true
         } else {
             let nbits = self.data.bit_len();
-            let delta = (h >> 17) | (h << 15);
+            let delta = (h >> 49) | (h << 15);
             for _ in 0..self.k {
-                let bit_pos = h % (nbits as u32);
+                let bit_pos = h % (nbits as u64);
                 if !self.data.get_bit(bit_pos as usize) {
                     return false;
                 }
@@ -111,10 +106,14 @@ impl BloomFilterReader {
             true
         }
     }
+
+    pub fn estimate_size(&self) -> usize {
+        self.data.len()
+    }
 }
 
 pub struct BloomFilterBuilder {
-    key_hash_entries: Vec<u32>,
+    key_hash_entries: Vec<u64>,
     bits_per_key: usize,
 }
 
@@ -143,11 +142,11 @@ pub fn bloom_bits_per_key(entries: usize, false_positive_rate: f64) -> usize {
 impl FilterBuilder for BloomFilterBuilder {
     fn add_key(&mut self, key: &[u8], table_id: u32) {
         self.key_hash_entries
-            .push(Sstable::hash_for_bloom_filter_u32(key, table_id));
+            .push(Sstable::hash_for_bloom_filter(key, table_id));
     }
 
     fn approximate_len(&self) -> usize {
-        self.key_hash_entries.len() * 4
+        self.key_hash_entries.len() * 8
     }
 
     fn finish(&mut self) -> Vec<u8> {
@@ -164,9 +163,9 @@ impl FilterBuilder for BloomFilterBuilder {
         filter.resize(nbytes, 0);
         for h in &self.key_hash_entries {
             let mut h = *h;
-            let delta = (h >> 17) | (h << 15);
+            let delta = (h >> 49) | (h << 15);
             for _ in 0..k {
-                let bit_pos = (h as usize) % nbits;
+                let bit_pos = (h % (nbits as u64)) as usize;
                 filter.set_bit(bit_pos, true);
                 h = h.wrapping_add(delta);
             }
@@ -183,10 +182,7 @@ impl FilterBuilder for BloomFilterBuilder {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::BitXor;
-
     use bytes::Bytes;
-    use xxhash_rust::xxh32;
 
     use super::*;
 
@@ -197,14 +193,14 @@ mod tests {
         builder.add_key(b"world", 0);
         let buf = builder.finish();
 
-        let check_hash: Vec<u32> = vec![
+        let check_hash: Vec<u64> = vec![
             b"hello".to_vec(),
             b"world".to_vec(),
             b"x".to_vec(),
             b"fool".to_vec(),
         ]
         .into_iter()
-        .map(|x| xxh32::xxh32(&x, 0).bitxor(0))
+        .map(|x| Sstable::hash_for_bloom_filter(&x, 0))
         .collect();
 
         let f = BloomFilterReader::new(buf);
@@ -233,7 +229,7 @@ mod tests {
         let mut true_count = 0;
         for i in preset_key_count..preset_key_count + test_key_count {
             let k = Bytes::from(format!("{:032}", i));
-            let h = xxh32::xxh32(&k, 0);
+            let h = Sstable::hash_for_bloom_filter(&k, 0);
             if !filter.may_match(h) {
                 true_count += 1;
             }
@@ -25,8 +25,9 @@ use risingwave_pb::hummock::SstableInfo;
 
 use super::utils::CompressionAlgorithm;
 use super::{
-    BlockBuilder, BlockBuilderOptions, BlockMeta, MonotonicDeleteEvent, SstableMeta, SstableWriter,
-    DEFAULT_BLOCK_SIZE, DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
+    encrypt_block_data, BlockBuilder, BlockBuilderOptions, BlockMeta, DataKey,
+    MonotonicDeleteEvent, SstableMeta, SstableWriter, DEFAULT_BLOCK_SIZE, DEFAULT_ENTRY_SIZE,
+    DEFAULT_RESTART_INTERVAL, VERSION,
 };
 use crate::filter_key_extractor::{FilterKeyExtractorImpl, FullKeyFilterKeyExtractor};
 use crate::hummock::sstable::FilterBuilder;
@@ -48,6 +49,10 @@ pub struct SstableBuilderOptions {
     pub bloom_false_positive: f64,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Data key used to encrypt blocks after compression. `None` disables encryption at rest.
+    /// Note that blocks of an encrypted SST must be written with `CachePolicy::NotFill`, because
+    /// the writers refill the block cache by re-decoding the written bytes.
+    pub data_key: Option<DataKey>,
 }
 
 impl From<&StorageOpts> for SstableBuilderOptions {
@@ -59,6 +64,7 @@ impl From<&StorageOpts> for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: options.bloom_false_positive,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         }
     }
 }
@@ -71,6 +77,7 @@ impl Default for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: DEFAULT_BLOOM_FALSE_POSITIVE,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         }
     }
 }
@@ -385,6 +392,13 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             version: VERSION,
             meta_offset,
             monotonic_tombstone_events: self.monotonic_deletes,
+            encrypted_data_key: self
+                .options
+                .data_key
+                .as_ref()
+                .map(|data_key| data_key.encrypted.clone())
+                .unwrap_or_default(),
+            zstd_dict: self.block_builder.take_zstd_dict(),
         };
         meta.estimated_size = meta.encoded_size() as u32 + meta_offset as u32;
 
@@ -501,7 +515,15 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         let block_meta = self.block_metas.last_mut().unwrap();
         block_meta.uncompressed_size = self.block_builder.uncompressed_block_size() as u32;
         let block = self.block_builder.build();
-        self.writer.write_block(block, block_meta).await?;
+        match &self.options.data_key {
+            Some(data_key) => {
+                let encrypted = encrypt_block_data(&data_key.plaintext, block)?;
+                self.writer.write_block(&encrypted, block_meta).await?;
+            }
+            None => {
+                self.writer.write_block(block, block_meta).await?;
+            }
+        }
         block_meta.len = self.writer.data_len() as u32 - block_meta.offset;
         self.block_builder.clear();
         Ok(())
@@ -553,6 +575,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.001,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
 
         let b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -568,6 +591,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
         let table_id = TableId::default();
         let mut b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -629,6 +653,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: if with_blooms { 0.01 } else { 0.0 },
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
 
         // build remote table
@@ -0,0 +1,208 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encryption at rest for SST data.
+//!
+//! Each encrypted SST is protected by its own 256-bit data key. Blocks are encrypted with
+//! AES-GCM after compression, and the data key itself is stored in the SST meta wrapped
+//! (encrypted) by a key management service, so neither the plaintext key nor plaintext data
+//! ever reach the object store. Which KMS key wraps the data keys is configured per compaction
+//! group via `CompactionConfig::sst_encryption_key_id`; a per-table key is achieved by moving
+//! the table into its own compaction group. Since every newly built SST gets a fresh data key
+//! wrapped with the currently configured KMS key, key rotation is performed by re-compacting
+//! existing data.
+//!
+//! SSTs flushed from the shared buffer are written before any compaction group config is
+//! consulted and stay unencrypted until their first compaction.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, AeadCore, OsRng};
+use aes_gcm::{Aes256Gcm, Key, KeyInit, Nonce};
+use aws_sdk_kms::model::DataKeySpec;
+use aws_sdk_kms::types::Blob;
+
+use crate::hummock::{HummockError, HummockResult};
+
+/// Length of the nonce prepended to each encrypted block.
+const NONCE_LENGTH: usize = 12;
+
+/// Length of a plaintext data key (AES-256).
+const DATA_KEY_LENGTH: usize = 32;
+
+fn new_cipher(key: &[u8]) -> HummockResult<Aes256Gcm> {
+    if key.len() != DATA_KEY_LENGTH {
+        return Err(HummockError::encryption_error(format!(
+            "invalid data key length: expected {}, found {}",
+            DATA_KEY_LENGTH,
+            key.len()
+        )));
+    }
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)))
+}
+
+/// A per-SST data key, generated by a [`KeyProvider`]. The plaintext form only lives in memory;
+/// the encrypted (wrapped) form is what gets persisted in the SST meta.
+#[derive(Clone)]
+pub struct DataKey {
+    pub plaintext: Vec<u8>,
+    pub encrypted: Vec<u8>,
+}
+
+impl Debug for DataKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Never print the plaintext key.
+        f.debug_struct("DataKey")
+            .field("encrypted_len", &self.encrypted.len())
+            .finish()
+    }
+}
+
+/// Generates and unwraps data keys through a key management service.
+#[async_trait::async_trait]
+pub trait KeyProvider: Send + Sync + 'static {
+    /// Generates a fresh data key wrapped with the KMS key identified by `key_id`.
+    async fn generate_data_key(&self, key_id: &str) -> HummockResult<DataKey>;
+
+    /// Decrypts a wrapped data key read from an SST meta. The KMS key used for wrapping is
+    /// inferred from the ciphertext, so no key id is needed.
+    async fn decrypt_data_key(&self, encrypted_key: &[u8]) -> HummockResult<Vec<u8>>;
+}
+
+pub type KeyProviderRef = Arc<dyn KeyProvider>;
+
+/// A [`KeyProvider`] backed by AWS KMS. Credentials and region are resolved from the
+/// environment, like the S3 object store.
+pub struct AwsKmsKeyProvider {
+    client: aws_sdk_kms::Client,
+}
+
+impl AwsKmsKeyProvider {
+    pub async fn new() -> Self {
+        let config = aws_config::load_from_env().await;
+        Self {
+            client: aws_sdk_kms::Client::new(&config),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyProvider for AwsKmsKeyProvider {
+    async fn generate_data_key(&self, key_id: &str) -> HummockResult<DataKey> {
+        let output = self
+            .client
+            .generate_data_key()
+            .key_id(key_id)
+            .key_spec(DataKeySpec::Aes256)
+            .send()
+            .await
+            .map_err(HummockError::encryption_error)?;
+        let plaintext = output
+            .plaintext()
+            .ok_or_else(|| HummockError::encryption_error("KMS returned no plaintext key"))?
+            .as_ref()
+            .to_vec();
+        let encrypted = output
+            .ciphertext_blob()
+            .ok_or_else(|| HummockError::encryption_error("KMS returned no ciphertext blob"))?
+            .as_ref()
+            .to_vec();
+        Ok(DataKey {
+            plaintext,
+            encrypted,
+        })
+    }
+
+    async fn decrypt_data_key(&self, encrypted_key: &[u8]) -> HummockResult<Vec<u8>> {
+        let output = self
+            .client
+            .decrypt()
+            .ciphertext_blob(Blob::new(encrypted_key))
+            .send()
+            .await
+            .map_err(HummockError::encryption_error)?;
+        Ok(output
+            .plaintext()
+            .ok_or_else(|| HummockError::encryption_error("KMS returned no plaintext key"))?
+            .as_ref()
+            .to_vec())
+    }
+}
+
+/// Creates a key provider from the `storage.data_key_provider` option. Returns `None` if the
+/// option is empty, i.e. encryption at rest is disabled for the node.
+pub async fn build_key_provider(provider: &str) -> HummockResult<Option<KeyProviderRef>> {
+    match provider {
+        "" => Ok(None),
+        "aws-kms" => Ok(Some(Arc::new(AwsKmsKeyProvider::new().await) as _)),
+        other => Err(HummockError::encryption_error(format!(
+            "unsupported data key provider: {}",
+            other
+        ))),
+    }
+}
+
+/// Encrypts a (compressed) block payload with AES-GCM. The output is the random nonce followed
+/// by the ciphertext.
+pub fn encrypt_block_data(key: &[u8], payload: &[u8]) -> HummockResult<Vec<u8>> {
+    let cipher = new_cipher(key)?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, payload)
+        .map_err(HummockError::encryption_error)?;
+    let mut data = Vec::with_capacity(NONCE_LENGTH + ciphertext.len());
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+    Ok(data)
+}
+
+/// Decrypts a block payload produced by [`encrypt_block_data`].
+pub fn decrypt_block_data(key: &[u8], data: &[u8]) -> HummockResult<Vec<u8>> {
+    if data.len() < NONCE_LENGTH {
+        return Err(HummockError::encryption_error(
+            "encrypted block is too short",
+        ));
+    }
+    let cipher = new_cipher(key)?;
+    let (nonce, ciphertext) = data.split_at(NONCE_LENGTH);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(HummockError::encryption_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_data_enc_dec() {
+        let key = [7u8; 32];
+        let payload = b"the quick brown fox jumps over the lazy dog".to_vec();
+        let encrypted = encrypt_block_data(&key, &payload).unwrap();
+        assert_ne!(encrypted, payload);
+        let decrypted = decrypt_block_data(&key, &encrypted).unwrap();
+        assert_eq!(decrypted, payload);
+
+        // Each encryption uses a fresh nonce.
+        let encrypted2 = encrypt_block_data(&key, &payload).unwrap();
+        assert_ne!(encrypted, encrypted2);
+
+        // Tampered data must not decrypt.
+        let mut tampered = encrypted;
+        let last = tampered.last_mut().unwrap();
+        *last = last.wrapping_add(1);
+        assert!(decrypt_block_data(&key, &tampered).is_err());
+    }
+}
@@ -14,6 +14,10 @@
 
 // Copyright 2021 TiKV Project Authors. Licensed under Apache-2.0.
 
+use super::bloom::BloomFilterReader;
+use super::ribbon_filter::{RibbonFilterReader, FOOTER_RIBBON};
+use super::xor_filter::{XorFilterReader, FOOTER_XOR16, FOOTER_XOR8};
+
 pub trait FilterBuilder: Send {
     /// add key which need to be filter for construct filter data.
     fn add_key(&mut self, dist_key: &[u8], table_id: u32);
@@ -24,3 +28,62 @@ pub trait FilterBuilder: Send {
 
     fn create(fpr: f64, capacity: usize) -> Self;
 }
+
+/// Filter implementation for an SST, chosen per compaction group through
+/// `CompactionConfig::filter_algorithm`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FilterAlgorithm {
+    /// The default: a 16-bit xor filter for L0/Lbase output and an 8-bit one below.
+    #[default]
+    Xor,
+    Bloom,
+    Ribbon,
+}
+
+/// Dispatches to the filter implementation indicated by the footer byte of the encoded filter
+/// data, see the `FilterBuilder::finish` impls.
+#[derive(Clone)]
+pub enum FilterReader {
+    Xor(XorFilterReader),
+    Bloom(BloomFilterReader),
+    Ribbon(RibbonFilterReader),
+}
+
+impl FilterReader {
+    pub fn new(buf: Vec<u8>) -> Self {
+        if buf.len() <= 1 {
+            return Self::Xor(XorFilterReader::new(buf));
+        }
+        match *buf.last().unwrap() {
+            FOOTER_XOR8 | FOOTER_XOR16 => Self::Xor(XorFilterReader::new(buf)),
+            FOOTER_RIBBON => Self::Ribbon(RibbonFilterReader::new(buf)),
+            _ => Self::Bloom(BloomFilterReader::new(buf)),
+        }
+    }
+
+    pub fn estimate_size(&self) -> usize {
+        match self {
+            Self::Xor(reader) => reader.estimate_size(),
+            Self::Bloom(reader) => reader.estimate_size(),
+            Self::Ribbon(reader) => reader.estimate_size(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Xor(reader) => reader.is_empty(),
+            Self::Bloom(reader) => reader.is_empty(),
+            Self::Ribbon(reader) => reader.is_empty(),
+        }
+    }
+
+    /// Judges whether the hash value is in the table with the filter's false positive rate, see
+    /// `XorFilterReader::may_match`.
+    pub fn may_match(&self, h: u64) -> bool {
+        match self {
+            Self::Xor(reader) => reader.may_match(h),
+            Self::Bloom(reader) => reader.may_match(h),
+            Self::Ribbon(reader) => reader.may_match(h),
+        }
+    }
+}
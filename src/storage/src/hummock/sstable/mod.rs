@@ -25,9 +25,10 @@ pub use block::*;
 mod block_iterator;
 pub use block_iterator::*;
 mod bloom;
+mod ribbon_filter;
 mod xor_filter;
 pub use bloom::BloomFilterBuilder;
-use xor_filter::XorFilterReader;
+pub use ribbon_filter::RibbonFilterBuilder;
 pub use xor_filter::{Xor16FilterBuilder, Xor8FilterBuilder};
 pub mod builder;
 pub use builder::*;
@@ -47,15 +48,20 @@ use risingwave_hummock_sdk::{HummockEpoch, HummockSstableObjectId};
 use risingwave_pb::hummock::{KeyRange, SstableInfo};
 
 mod delete_range_aggregator;
+pub mod encryption;
 mod filter;
 mod sstable_object_id_manager;
-mod utils;
+pub mod utils;
 
 pub use delete_range_aggregator::{
     get_min_delete_range_epoch_from_sstable, CompactionDeleteRanges, CompactionDeleteRangesBuilder,
     SstableDeleteRangeIterator,
 };
-pub use filter::FilterBuilder;
+pub use encryption::{
+    build_key_provider, decrypt_block_data, encrypt_block_data, AwsKmsKeyProvider, DataKey,
+    KeyProvider, KeyProviderRef,
+};
+pub use filter::{FilterAlgorithm, FilterBuilder, FilterReader};
 pub use sstable_object_id_manager::*;
 pub use utils::CompressionAlgorithm;
 use utils::{get_length_prefixed_slice, put_length_prefixed_slice};
@@ -69,7 +75,11 @@ use crate::store::ReadOptions;
 
 const DEFAULT_META_BUFFER_CAPACITY: usize = 4096;
 const MAGIC: u32 = 0x5785ab73;
-const VERSION: u32 = 1;
+/// Format version before the encrypted data key was added to the meta.
+const OLD_VERSION: u32 = 1;
+/// Format version that added the encrypted data key.
+const ENCRYPTION_VERSION: u32 = 2;
+const VERSION: u32 = 3;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 // delete keys located in [start_user_key, end_user_key)
@@ -229,7 +239,10 @@ pub(crate) fn create_monotonic_events(
 pub struct Sstable {
     pub id: HummockSstableObjectId,
     pub meta: SstableMeta,
-    pub filter_reader: XorFilterReader,
+    pub filter_reader: FilterReader,
+    /// Plaintext data key, unwrapped from `meta.encrypted_data_key` through the key provider
+    /// when the meta is loaded. `None` if the SST is not encrypted.
+    pub data_key: Option<Vec<u8>>,
 }
 
 impl Debug for Sstable {
@@ -244,12 +257,13 @@ impl Debug for Sstable {
 impl Sstable {
     pub fn new(id: HummockSstableObjectId, mut meta: SstableMeta) -> Self {
         let filter_data = std::mem::take(&mut meta.bloom_filter);
-        let filter_reader = XorFilterReader::new(filter_data);
+        let filter_reader = FilterReader::new(filter_data);
 
         Self {
             id,
             meta,
             filter_reader,
+            data_key: None,
         }
     }
 
@@ -382,6 +396,12 @@ pub struct SstableMeta {
     /// If there is no range deletes between current event key and next event key, `new_epoch` will
     /// be `HummockEpoch::MAX`.
     pub monotonic_tombstone_events: Vec<MonotonicDeleteEvent>,
+    /// The SST's data key wrapped by a KMS, see the [`encryption`] module. Empty if the SST is
+    /// not encrypted at rest.
+    pub encrypted_data_key: Vec<u8>,
+    /// The zstd dictionary used to compress the SST's `ZstdDict` blocks. Empty if the SST does
+    /// not use dictionary compression.
+    pub zstd_dict: Vec<u8>,
     /// Format version, for further compatibility.
     pub version: u32,
 }
@@ -399,6 +419,8 @@ impl SstableMeta {
     /// | K (4B) |
     /// | tombstone-event 0 | ... | tombstone-event K-1 |
     /// | file offset of this meta block (8B) |
+    /// | encrypted data key len (4B) | encrypted data key |
+    /// | zstd dict len (4B) | zstd dict |
     /// | checksum (8B) | version (4B) | magic (4B) |
     /// ```
     pub fn encode_to_bytes(&self) -> Vec<u8> {
@@ -423,6 +445,8 @@ impl SstableMeta {
             monotonic_tombstone_event.encode(buf);
         }
         buf.put_u64_le(self.meta_offset);
+        put_length_prefixed_slice(buf, &self.encrypted_data_key);
+        put_length_prefixed_slice(buf, &self.zstd_dict);
         let checksum = xxhash64_checksum(&buf[start_offset..]);
         buf.put_u64_le(checksum);
         buf.put_u32_le(VERSION);
@@ -430,6 +454,16 @@ impl SstableMeta {
     }
 
     pub fn decode(buf: &mut &[u8]) -> HummockResult<Self> {
+        Self::decode_inner(buf, true)
+    }
+
+    /// Decodes the meta without verifying its checksum. Only for tooling that inspects or
+    /// repairs SSTs whose meta block is corrupt; use `decode` everywhere else.
+    pub fn decode_unchecked(buf: &mut &[u8]) -> HummockResult<Self> {
+        Self::decode_inner(buf, false)
+    }
+
+    fn decode_inner(buf: &mut &[u8], verify_checksum: bool) -> HummockResult<Self> {
         let mut cursor = buf.len();
 
         cursor -= 4;
@@ -440,14 +474,16 @@ impl SstableMeta {
 
         cursor -= 4;
         let version = (&buf[cursor..cursor + 4]).get_u32_le();
-        if version != VERSION {
+        if !(OLD_VERSION..=VERSION).contains(&version) {
             return Err(HummockError::invalid_format_version(version));
         }
 
         cursor -= 8;
         let checksum = (&buf[cursor..cursor + 8]).get_u64_le();
         let buf = &mut &buf[..cursor];
-        xxhash64_verify(buf, checksum)?;
+        if verify_checksum {
+            xxhash64_verify(buf, checksum)?;
+        }
 
         let block_meta_count = buf.get_u32_le() as usize;
         let mut block_metas = Vec::with_capacity(block_meta_count);
@@ -466,6 +502,16 @@ impl SstableMeta {
             monotonic_tombstone_events.push(monotonic_tombstone_event);
         }
         let meta_offset = buf.get_u64_le();
+        let encrypted_data_key = if version >= ENCRYPTION_VERSION {
+            get_length_prefixed_slice(buf)
+        } else {
+            vec![]
+        };
+        let zstd_dict = if version >= VERSION {
+            get_length_prefixed_slice(buf)
+        } else {
+            vec![]
+        };
 
         Ok(Self {
             block_metas,
@@ -476,6 +522,8 @@ impl SstableMeta {
             largest_key,
             meta_offset,
             monotonic_tombstone_events,
+            encrypted_data_key,
+            zstd_dict,
             version,
         })
     }
@@ -502,6 +550,10 @@ impl SstableMeta {
             + self.smallest_key.len()
             + 4 // key len
             + self.largest_key.len()
+            + 4 // encrypted data key len
+            + self.encrypted_data_key.len()
+            + 4 // zstd dict len
+            + self.zstd_dict.len()
             + 8 // footer
             + 8 // checksum
             + 4 // version
@@ -552,6 +604,8 @@ mod tests {
             largest_key: b"9-largest-key".to_vec(),
             meta_offset: 123,
             monotonic_tombstone_events: vec![],
+            encrypted_data_key: vec![],
+            zstd_dict: vec![],
             version: VERSION,
         };
         let sz = meta.encoded_size();
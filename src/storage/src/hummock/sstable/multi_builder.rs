@@ -376,6 +376,7 @@ impl TableBuilderFactory for LocalTableBuilderFactory {
             capacity_hint: Some(self.options.capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            storage_class: None,
         };
         let writer = self
             .sstable_store
@@ -411,6 +412,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
         let builder_factory = LocalTableBuilderFactory::new(1001, mock_sstable_store(), opts);
         let builder = CapacitySplitTableBuilder::for_test(builder_factory);
@@ -428,6 +430,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
         let builder_factory = LocalTableBuilderFactory::new(1001, mock_sstable_store(), opts);
         let mut builder = CapacitySplitTableBuilder::for_test(builder_factory);
@@ -600,6 +603,7 @@ mod tests {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            data_key: None,
         };
         let table_id = TableId::new(1);
         let mut builder = CompactionDeleteRangesBuilder::default();
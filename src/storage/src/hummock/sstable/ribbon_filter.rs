@@ -0,0 +1,318 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::{Buf, BufMut};
+
+use super::{FilterBuilder, Sstable};
+
+pub(crate) const FOOTER_RIBBON: u8 = 253;
+
+/// Width of the coefficient band in bits. Each key occupies a random 64-bit window of the
+/// solution matrix.
+const RIBBON_BAND_WIDTH: usize = 64;
+/// Space overhead over the number of keys. Banded gaussian elimination succeeds with high
+/// probability at 5% over-provisioning; on failure the filter is rebuilt with more slots.
+const RIBBON_OVERHEAD_PERCENT: usize = 5;
+
+/// A ribbon filter: keys are inserted as rows of a banded linear system over GF(2), which is
+/// solved by gaussian elimination and back substitution. A query recomputes the row of the key
+/// and checks that its dot product with the solution equals the key's fingerprint.
+///
+/// Compared to a Bloom filter with the same false positive rate, a ribbon filter needs roughly
+/// 30% less space, at the cost of a more expensive build. Like the xor filters it must be built
+/// from the complete, deduplicated key set.
+pub struct RibbonFilterBuilder {
+    key_hash_entries: Vec<u64>,
+    fingerprint_bits: u8,
+}
+
+/// Derives the per-key row of the linear system from the key hash: the start column, the 64
+/// coefficient bits (lowest bit always set so the row is anchored at the start column) and the
+/// fingerprint.
+fn ribbon_hash(h: u64, num_starts: usize, fingerprint_mask: u16) -> (usize, u64, u16) {
+    let start = ((h as u128 * num_starts as u128) >> 64) as usize;
+    let coeff = mix(h) | 1;
+    let fingerprint = (mix(!h) & fingerprint_mask as u64) as u16;
+    (start, coeff, fingerprint)
+}
+
+/// A 64-bit finalizer (as in murmur3) to decorrelate the coefficient bits and the fingerprint
+/// from the start column, which uses the raw hash.
+fn fingerprint_mask(fingerprint_bits: u8) -> u16 {
+    (((1_u32 << fingerprint_bits) - 1) & u16::MAX as u32) as u16
+}
+
+fn mix(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    x ^= x >> 33;
+    x
+}
+
+impl RibbonFilterBuilder {
+    pub fn new(bloom_false_positive: f64, capacity: usize) -> Self {
+        let key_hash_entries = if capacity > 0 {
+            Vec::with_capacity(capacity)
+        } else {
+            vec![]
+        };
+        // One fingerprint bit halves the false positive rate.
+        let fingerprint_bits = (-bloom_false_positive.log2()).ceil().clamp(1.0, 16.0) as u8;
+        Self {
+            key_hash_entries,
+            fingerprint_bits,
+        }
+    }
+
+    /// Inserts one row into the banded system with incremental gaussian elimination. Returns
+    /// false if the row is inconsistent with the rows inserted so far.
+    fn insert_row(
+        coeff: &mut [u64],
+        result: &mut [u16],
+        mut start: usize,
+        mut c: u64,
+        mut fingerprint: u16,
+    ) -> bool {
+        loop {
+            debug_assert_eq!(c & 1, 1);
+            if coeff[start] == 0 {
+                coeff[start] = c;
+                result[start] = fingerprint;
+                return true;
+            }
+            c ^= coeff[start];
+            fingerprint ^= result[start];
+            if c == 0 {
+                // The row is a linear combination of earlier rows.
+                return fingerprint == 0;
+            }
+            let shift = c.trailing_zeros() as usize;
+            start += shift;
+            c >>= shift;
+        }
+    }
+
+    /// Solves the banded system by back substitution, yielding one fingerprint-sized slot per
+    /// column.
+    fn back_substitute(coeff: &[u64], result: &[u16]) -> Vec<u16> {
+        let mut slots = vec![0_u16; coeff.len()];
+        for i in (0..coeff.len()).rev() {
+            let mut z = result[i];
+            // Bit 0 of the coefficients is the column itself.
+            let mut bits = coeff[i] >> 1;
+            let mut j = i + 1;
+            while bits != 0 {
+                if bits & 1 == 1 {
+                    z ^= slots[j];
+                }
+                bits >>= 1;
+                j += 1;
+            }
+            slots[i] = z;
+        }
+        slots
+    }
+}
+
+impl FilterBuilder for RibbonFilterBuilder {
+    fn add_key(&mut self, key: &[u8], table_id: u32) {
+        self.key_hash_entries
+            .push(Sstable::hash_for_bloom_filter(key, table_id));
+    }
+
+    fn approximate_len(&self) -> usize {
+        self.key_hash_entries.len() * 8
+    }
+
+    fn finish(&mut self) -> Vec<u8> {
+        self.key_hash_entries.sort();
+        self.key_hash_entries.dedup();
+        if self.key_hash_entries.is_empty() {
+            // An empty filter must match everything, like the other filter implementations.
+            let mut buf = Vec::with_capacity(4 + 1 + 1);
+            buf.put_u32_le(0);
+            buf.put_u8(self.fingerprint_bits);
+            buf.put_u8(FOOTER_RIBBON);
+            return buf;
+        }
+        let fingerprint_mask = fingerprint_mask(self.fingerprint_bits);
+
+        let mut num_starts = self
+            .key_hash_entries
+            .len()
+            .max(RIBBON_BAND_WIDTH)
+            .saturating_mul(100 + RIBBON_OVERHEAD_PERCENT)
+            / 100;
+        let (coeff, result) = loop {
+            // The band may stick out by up to `RIBBON_BAND_WIDTH - 1` columns.
+            let num_slots = num_starts + RIBBON_BAND_WIDTH - 1;
+            let mut coeff = vec![0_u64; num_slots];
+            let mut result = vec![0_u16; num_slots];
+            if self.key_hash_entries.iter().all(|h| {
+                let (start, c, fingerprint) = ribbon_hash(*h, num_starts, fingerprint_mask);
+                Self::insert_row(&mut coeff, &mut result, start, c, fingerprint)
+            }) {
+                break (coeff, result);
+            }
+            // Elimination failed: retry with more slots, which re-randomizes the start columns.
+            num_starts += num_starts / 2;
+        };
+        let slots = Self::back_substitute(&coeff, &result);
+
+        let mut buf =
+            Vec::with_capacity(4 + 1 + slots.len() * self.fingerprint_bytes_per_slot() + 1);
+        buf.put_u32_le(num_starts as u32);
+        buf.put_u8(self.fingerprint_bits);
+        for slot in &slots {
+            if self.fingerprint_bits > 8 {
+                buf.put_u16_le(*slot);
+            } else {
+                buf.put_u8(*slot as u8);
+            }
+        }
+        // Footer to distinguish the filter kind, see `xor_filter.rs`.
+        buf.put_u8(FOOTER_RIBBON);
+        buf
+    }
+
+    fn create(fpr: f64, capacity: usize) -> Self {
+        RibbonFilterBuilder::new(fpr, capacity)
+    }
+}
+
+impl RibbonFilterBuilder {
+    fn fingerprint_bytes_per_slot(&self) -> usize {
+        if self.fingerprint_bits > 8 {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RibbonFilterReader {
+    num_starts: usize,
+    fingerprint_mask: u16,
+    slots: Vec<u16>,
+}
+
+impl RibbonFilterReader {
+    /// Creates a ribbon filter from a byte slice, see `RibbonFilterBuilder::finish` for the
+    /// format.
+    pub fn new(buf: Vec<u8>) -> Self {
+        let buf = &mut &buf[..];
+        let num_starts = buf.get_u32_le() as usize;
+        let fingerprint_bits = buf.get_u8();
+        let fingerprint_mask = fingerprint_mask(fingerprint_bits);
+        let num_slots = if num_starts == 0 {
+            0
+        } else {
+            num_starts + RIBBON_BAND_WIDTH - 1
+        };
+        let slots = (0..num_slots)
+            .map(|_| {
+                if fingerprint_bits > 8 {
+                    buf.get_u16_le()
+                } else {
+                    buf.get_u8() as u16
+                }
+            })
+            .collect();
+        Self {
+            num_starts,
+            fingerprint_mask,
+            slots,
+        }
+    }
+
+    pub fn estimate_size(&self) -> usize {
+        self.slots.len() * std::mem::size_of::<u16>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_starts == 0
+    }
+
+    /// Judges whether the hash value is in the table with the given false positive rate.
+    ///
+    /// Note:
+    ///   - if the return value is false, then the table surely does not have the user key that has
+    ///     the hash;
+    ///   - if the return value is true, then the table may or may not have the user key that has
+    ///     the hash actually, a.k.a. we don't know the answer.
+    pub fn may_match(&self, h: u64) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let (start, coeff, fingerprint) = ribbon_hash(h, self.num_starts, self.fingerprint_mask);
+        let mut z = 0;
+        let mut bits = coeff;
+        let mut j = start;
+        while bits != 0 {
+            if bits & 1 == 1 {
+                z ^= self.slots[j];
+            }
+            bits >>= 1;
+            j += 1;
+        }
+        z == fingerprint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_ribbon_filter() {
+        let mut builder = RibbonFilterBuilder::new(0.01, 2);
+        builder.add_key(b"hello", 0);
+        builder.add_key(b"world", 0);
+        let buf = builder.finish();
+        assert_eq!(*buf.last().unwrap(), FOOTER_RIBBON);
+
+        let reader = RibbonFilterReader::new(buf);
+        assert!(reader.may_match(Sstable::hash_for_bloom_filter(b"hello", 0)));
+        assert!(reader.may_match(Sstable::hash_for_bloom_filter(b"world", 0)));
+    }
+
+    #[test]
+    fn test_ribbon_filter_false_positive_rate() {
+        const KEY_COUNT: usize = 10000;
+        const TEST_KEY_COUNT: usize = 10000;
+        const EXPECTED_FPR: f64 = 0.01;
+
+        let mut builder = RibbonFilterBuilder::new(EXPECTED_FPR, KEY_COUNT);
+        for i in 0..KEY_COUNT {
+            let k = Bytes::from(format!("{:032}", i));
+            builder.add_key(&k, 0);
+        }
+        let reader = RibbonFilterReader::new(builder.finish());
+
+        let mut false_positive_count = 0;
+        for i in KEY_COUNT..KEY_COUNT + TEST_KEY_COUNT {
+            let k = Bytes::from(format!("{:032}", i));
+            if reader.may_match(Sstable::hash_for_bloom_filter(&k, 0)) {
+                false_positive_count += 1;
+            }
+        }
+        let false_positive_rate = false_positive_count as f64 / TEST_KEY_COUNT as f64;
+        assert!(false_positive_rate < 3_f64 * EXPECTED_FPR);
+    }
+}
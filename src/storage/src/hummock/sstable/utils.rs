@@ -88,6 +88,12 @@ pub enum CompressionAlgorithm {
     None,
     Lz4,
     Zstd,
+    /// Zstd with a dictionary trained per SST, stored in the SST's meta block. Blocks written
+    /// before enough samples were collected for training are compressed with plain `Zstd`.
+    /// Like encrypted SSTs, SSTs using this algorithm must be written with
+    /// `CachePolicy::NotFill`, because the writers refill the block cache by re-decoding the
+    /// written bytes without the dictionary.
+    ZstdDict,
 }
 
 impl CompressionAlgorithm {
@@ -96,6 +102,7 @@ impl CompressionAlgorithm {
             Self::None => 0,
             Self::Lz4 => 1,
             Self::Zstd => 2,
+            Self::ZstdDict => 3,
         };
         buf.put_u8(v);
     }
@@ -105,6 +112,7 @@ impl CompressionAlgorithm {
             0 => Ok(Self::None),
             1 => Ok(Self::Lz4),
             2 => Ok(Self::Zstd),
+            3 => Ok(Self::ZstdDict),
             _ => Err(HummockError::decode_error(
                 "not valid compression algorithm",
             )),
@@ -118,6 +126,7 @@ impl From<CompressionAlgorithm> for u8 {
             CompressionAlgorithm::None => 0,
             CompressionAlgorithm::Lz4 => 1,
             CompressionAlgorithm::Zstd => 2,
+            CompressionAlgorithm::ZstdDict => 3,
         }
     }
 }
@@ -128,6 +137,7 @@ impl From<CompressionAlgorithm> for u64 {
             CompressionAlgorithm::None => 0,
             CompressionAlgorithm::Lz4 => 1,
             CompressionAlgorithm::Zstd => 2,
+            CompressionAlgorithm::ZstdDict => 3,
         }
     }
 }
@@ -140,6 +150,7 @@ impl TryFrom<u8> for CompressionAlgorithm {
             0 => Ok(Self::None),
             1 => Ok(Self::Lz4),
             2 => Ok(Self::Zstd),
+            3 => Ok(Self::ZstdDict),
             _ => Err(HummockError::decode_error(
                 "not valid compression algorithm",
             )),
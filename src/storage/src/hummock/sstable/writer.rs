@@ -107,6 +107,8 @@ mod tests {
             largest_key: Vec::new(),
             meta_offset: data.len() as u64,
             monotonic_tombstone_events: vec![],
+            encrypted_data_key: vec![],
+            zstd_dict: vec![],
             version: VERSION,
         };
 
@@ -18,8 +18,8 @@ use xorf::{Filter, Xor16, Xor8};
 
 use super::{FilterBuilder, Sstable};
 
-const FOOTER_XOR8: u8 = 254;
-const FOOTER_XOR16: u8 = 255;
+pub(crate) const FOOTER_XOR8: u8 = 254;
+pub(crate) const FOOTER_XOR16: u8 = 255;
 
 pub struct Xor16FilterBuilder {
     key_hash_entries: Vec<u64>,
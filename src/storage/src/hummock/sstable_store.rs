@@ -34,8 +34,8 @@ use zstd::zstd_safe::WriteBuf;
 
 use super::utils::MemoryTracker;
 use super::{
-    Block, BlockCache, BlockMeta, BlockResponse, Sstable, SstableMeta, SstableWriter, TieredCache,
-    TieredCacheKey, TieredCacheValue,
+    decrypt_block_data, Block, BlockCache, BlockMeta, BlockResponse, KeyProviderRef, Sstable,
+    SstableMeta, SstableWriter, TieredCache, TieredCacheKey, TieredCacheValue,
 };
 use crate::hummock::multi_builder::UploadJoinHandle;
 use crate::hummock::{
@@ -145,6 +145,9 @@ pub struct SstableStore {
     block_cache: BlockCache,
     meta_cache: Arc<LruCache<HummockSstableObjectId, Box<Sstable>>>,
     tiered_cache: TieredCache<(HummockSstableObjectId, u64), Box<Block>>,
+    /// Used to unwrap the data keys of encrypted SSTs on read, and by the compactor to generate
+    /// data keys on write. `None` if encryption at rest is not configured for this node.
+    data_key_provider: Option<KeyProviderRef>,
 }
 
 impl SstableStore {
@@ -178,6 +181,7 @@ impl SstableStore {
             ),
             meta_cache,
             tiered_cache,
+            data_key_provider: None,
         }
     }
 
@@ -197,9 +201,20 @@ impl SstableStore {
             block_cache: BlockCache::new(block_cache_capacity, 0, 0),
             meta_cache,
             tiered_cache,
+            data_key_provider: None,
         }
     }
 
+    /// Sets the key provider used for encryption at rest. Must be called before the store is
+    /// shared, i.e. right after construction.
+    pub fn set_data_key_provider(&mut self, data_key_provider: KeyProviderRef) {
+        self.data_key_provider = Some(data_key_provider);
+    }
+
+    pub fn data_key_provider(&self) -> Option<&KeyProviderRef> {
+        self.data_key_provider.as_ref()
+    }
+
     pub async fn delete(&self, object_id: HummockSstableObjectId) -> HummockResult<()> {
         // Data
         self.store
@@ -238,12 +253,18 @@ impl SstableStore {
         &self,
         object_id: HummockSstableObjectId,
         data: Bytes,
+        storage_class: Option<&str>,
     ) -> HummockResult<()> {
         let data_path = self.get_sst_data_path(object_id);
-        self.store
-            .upload(&data_path, data)
-            .await
-            .map_err(HummockError::object_io_error)
+        match storage_class {
+            Some(class) => {
+                self.store
+                    .upload_with_storage_class(&data_path, data, class)
+                    .await
+            }
+            None => self.store.upload(&data_path, data).await,
+        }
+        .map_err(HummockError::object_io_error)
     }
 
     pub async fn get_block_response(
@@ -263,6 +284,8 @@ impl SstableStore {
             let data_path = self.get_sst_data_path(object_id);
             let store = self.store.clone();
             let use_tiered_cache = !matches!(policy, CachePolicy::Disable);
+            let data_key = sst.data_key.clone();
+            let zstd_dict = (!sst.meta.zstd_dict.is_empty()).then(|| sst.meta.zstd_dict.clone());
 
             async move {
                 if use_tiered_cache
@@ -276,7 +299,18 @@ impl SstableStore {
                 }
 
                 let block_data = store.read(&data_path, Some(block_loc)).await?;
-                let block = Block::decode(block_data, uncompressed_capacity)?;
+                let block = match &data_key {
+                    Some(data_key) => Block::decode_with_dict(
+                        decrypt_block_data(data_key, &block_data)?.into(),
+                        uncompressed_capacity,
+                        zstd_dict.as_deref(),
+                    )?,
+                    None => Block::decode_with_dict(
+                        block_data,
+                        uncompressed_capacity,
+                        zstd_dict.as_deref(),
+                    )?,
+                };
                 Ok(Box::new(block))
             }
         };
@@ -403,6 +437,7 @@ impl SstableStore {
                         offset: sst.meta_offset as usize,
                         size: (sst.file_size - sst.meta_offset) as usize,
                     };
+                    let data_key_provider = self.data_key_provider.clone();
                     async move {
                         let now = Instant::now();
                         let buf = store
@@ -410,7 +445,25 @@ impl SstableStore {
                             .await
                             .map_err(HummockError::object_io_error)?;
                         let meta = SstableMeta::decode(&mut &buf[..])?;
-                        let sst = Sstable::new(object_id, meta);
+                        // Unwrap the data key once on load, so that block reads don't have to
+                        // consult the KMS.
+                        let data_key = if meta.encrypted_data_key.is_empty() {
+                            None
+                        } else {
+                            let data_key_provider = data_key_provider.ok_or_else(|| {
+                                HummockError::encryption_error(format!(
+                                    "SST {} is encrypted but no data key provider is configured",
+                                    object_id
+                                ))
+                            })?;
+                            Some(
+                                data_key_provider
+                                    .decrypt_data_key(&meta.encrypted_data_key)
+                                    .await?,
+                            )
+                        };
+                        let mut sst = Sstable::new(object_id, meta);
+                        sst.data_key = data_key;
                         let charge = sst.estimate_size();
                         let add = (now.elapsed().as_secs_f64() * 1000.0).ceil();
                         stats_ptr.fetch_add(add as u64, Ordering::Relaxed);
@@ -467,6 +520,11 @@ impl SstableStore {
     }
 
     pub fn insert_meta_cache(&self, object_id: HummockSstableObjectId, meta: SstableMeta) {
+        if !meta.encrypted_data_key.is_empty() {
+            // The plaintext data key is only unwrapped when the meta is loaded from the object
+            // store. Caching a handle without it would make later block reads fail.
+            return;
+        }
         let sst = Sstable::new(object_id, meta);
         let charge = sst.estimate_size();
         self.meta_cache.insert(
@@ -520,6 +578,7 @@ impl SstableStore {
                 .map_err(HummockError::object_io_error)?,
             block_index.unwrap_or(0),
             &sst.meta,
+            sst.data_key.clone(),
         ))
     }
 }
@@ -580,6 +639,10 @@ pub struct SstableWriterOptions {
     pub capacity_hint: Option<usize>,
     pub tracker: Option<MemoryTracker>,
     pub policy: CachePolicy,
+    /// Object-store storage class for the SST, e.g. "STANDARD_IA" on S3. `None` means the store
+    /// default. Set by compaction for bottommost-level output of tables with a cold storage
+    /// class configured.
+    pub storage_class: Option<String>,
 }
 
 impl Default for SstableWriterOptions {
@@ -588,6 +651,7 @@ impl Default for SstableWriterOptions {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::NotFill,
+            storage_class: None,
         }
     }
 }
@@ -638,6 +702,7 @@ pub struct BatchUploadWriter {
     buf: Vec<u8>,
     block_info: Vec<Block>,
     tracker: Option<MemoryTracker>,
+    storage_class: Option<String>,
 }
 
 impl BatchUploadWriter {
@@ -653,6 +718,7 @@ impl BatchUploadWriter {
             buf: Vec::with_capacity(options.capacity_hint.unwrap_or(0)),
             block_info: Vec::new(),
             tracker: options.tracker,
+            storage_class: options.storage_class,
         }
     }
 }
@@ -688,7 +754,7 @@ impl SstableWriter for BatchUploadWriter {
             // Upload data to object store.
             self.sstable_store
                 .clone()
-                .put_sst_data(self.object_id, data)
+                .put_sst_data(self.object_id, data, self.storage_class.as_deref())
                 .await?;
             self.sstable_store.insert_meta_cache(self.object_id, meta);
 
@@ -829,7 +895,15 @@ impl SstableWriterFactory for StreamingSstableWriterFactory {
         options: SstableWriterOptions,
     ) -> HummockResult<Self::Writer> {
         let path = self.sstable_store.get_sst_data_path(object_id);
-        let uploader = self.sstable_store.store.streaming_upload(&path).await?;
+        let uploader = match &options.storage_class {
+            Some(class) => {
+                self.sstable_store
+                    .store
+                    .streaming_upload_with_storage_class(&path, class)
+                    .await?
+            }
+            None => self.sstable_store.store.streaming_upload(&path).await?,
+        };
         Ok(StreamingUploadWriter::new(
             object_id,
             self.sstable_store.clone(),
@@ -856,6 +930,14 @@ pub struct BlockStream {
     /// streaming starts at block 2 of a given SST, then the list does not contain information
     /// about block 0 and block 1.
     block_size_vec: Vec<(usize, usize)>,
+
+    /// Plaintext data key of the streamed SST, used to decrypt blocks before decoding. `None` if
+    /// the SST is not encrypted.
+    data_key: Option<Vec<u8>>,
+
+    /// The zstd dictionary of the streamed SST, used to decode its `ZstdDict` blocks. `None` if
+    /// the SST does not use dictionary compression.
+    zstd_dict: Option<Vec<u8>>,
 }
 
 impl BlockStream {
@@ -873,6 +955,9 @@ impl BlockStream {
 
         // Meta data of the SST that is streamed.
         sst_meta: &SstableMeta,
+
+        // Plaintext data key of the SST, if it is encrypted.
+        data_key: Option<Vec<u8>>,
     ) -> Self {
         let metas = &sst_meta.block_metas;
 
@@ -890,6 +975,8 @@ impl BlockStream {
             byte_stream,
             block_idx: 0,
             block_size_vec: block_len_vec,
+            data_key,
+            zstd_dict: (!sst_meta.zstd_dict.is_empty()).then(|| sst_meta.zstd_dict.clone()),
         }
     }
 
@@ -919,7 +1006,15 @@ impl BlockStream {
             )));
         }
 
-        let boxed_block = Box::new(Block::decode(Bytes::from(buffer), block_full_size)?);
+        let buffer = match &self.data_key {
+            Some(data_key) => decrypt_block_data(data_key, &buffer)?,
+            None => buffer,
+        };
+        let boxed_block = Box::new(Block::decode_with_dict(
+            Bytes::from(buffer),
+            block_full_size,
+            self.zstd_dict.as_deref(),
+        )?);
         self.block_idx += 1;
 
         Ok(Some(boxed_block))
@@ -993,6 +1088,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            storage_class: None,
         };
         let info = put_sst(
             SST_ID,
@@ -1023,6 +1119,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            storage_class: None,
         };
         let info = put_sst(
             SST_ID,
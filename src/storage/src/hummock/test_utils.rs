@@ -128,6 +128,7 @@ pub fn default_builder_opt_for_test() -> SstableBuilderOptions {
         restart_interval: DEFAULT_RESTART_INTERVAL,
         bloom_false_positive: 0.1,
         compression_algorithm: CompressionAlgorithm::None,
+        data_key: None,
     }
 }
 
@@ -136,6 +137,7 @@ pub fn default_writer_opt_for_test() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Disable,
+        storage_class: None,
     }
 }
 
@@ -209,6 +211,7 @@ pub async fn gen_test_sstable_impl<B: AsRef<[u8]> + Clone + Default + Eq, F: Fil
         capacity_hint: None,
         tracker: None,
         policy,
+        storage_class: None,
     };
     let writer = sstable_store
         .clone()
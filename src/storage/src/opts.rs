@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::config::{extract_storage_memory_config, RwConfig, StorageMemoryConfig};
+use risingwave_common::config::{
+    extract_storage_memory_config, RetryConfig, RwConfig, StorageMemoryConfig,
+};
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_common::system_param::system_params_for_test;
 
@@ -73,6 +75,16 @@ pub struct StorageOpts {
     pub backup_storage_directory: String,
     /// max time which wait for preload. 0 represent do not do any preload.
     pub max_preload_wait_time_mill: u64,
+    /// Local directory for persisting the block cache index on graceful shutdown. Empty string
+    /// disables the feature.
+    pub cache_recovery_dir: String,
+    /// Label attached to object store requests for cost attribution. Empty string disables it.
+    pub resource_label: String,
+    /// Key provider used for SST encryption at rest, e.g. "aws-kms". Empty string disables the
+    /// feature.
+    pub data_key_provider: String,
+    /// Retry behavior of object store requests.
+    pub object_store_retry: RetryConfig,
 }
 
 impl Default for StorageOpts {
@@ -118,6 +130,10 @@ impl From<(&RwConfig, &SystemParamsReader, &StorageMemoryConfig)> for StorageOpt
             max_preload_wait_time_mill: c.storage.max_preload_wait_time_mill,
             backup_storage_url: p.backup_storage_url().to_string(),
             backup_storage_directory: p.backup_storage_directory().to_string(),
+            cache_recovery_dir: c.storage.cache_recovery_dir.clone(),
+            resource_label: p.resource_label().to_string(),
+            data_key_provider: c.storage.data_key_provider.clone(),
+            object_store_retry: c.storage.object_store_retry.clone(),
         }
     }
 }
@@ -27,8 +27,8 @@ use crate::hummock::backup_reader::BackupReaderRef;
 use crate::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::{
-    HummockStorage, MemoryLimiter, SstableObjectIdManagerRef, SstableStore, TieredCache,
-    TieredCacheMetricsBuilder,
+    build_key_provider, HummockStorage, MemoryLimiter, SstableObjectIdManagerRef, SstableStore,
+    TieredCache, TieredCacheMetricsBuilder,
 };
 use crate::memory::sled::SledStateStore;
 use crate::memory::MemoryStateStore;
@@ -580,17 +580,25 @@ impl StateStoreImpl {
                     hummock.strip_prefix("hummock+").unwrap(),
                     object_store_metrics.clone(),
                     "Hummock",
+                    (!opts.resource_label.is_empty()).then(|| opts.resource_label.clone()),
+                    opts.object_store_retry.clone(),
                 )
                 .await;
 
-                let sstable_store = Arc::new(SstableStore::new(
+                let mut sstable_store = SstableStore::new(
                     Arc::new(object_store),
                     opts.data_directory.to_string(),
                     opts.block_cache_capacity_mb * (1 << 20),
                     opts.meta_cache_capacity_mb * (1 << 20),
                     opts.high_priority_ratio,
                     tiered_cache,
-                ));
+                );
+                if let Some(data_key_provider) =
+                    build_key_provider(&opts.data_key_provider).await?
+                {
+                    sstable_store.set_data_key_provider(data_key_provider);
+                }
+                let sstable_store = Arc::new(sstable_store);
                 let notification_client =
                     RpcNotificationClient::new(hummock_meta_client.get_inner().clone());
                 let key_filter_manager = Arc::new(FilterKeyExtractorManager::new(Box::new(
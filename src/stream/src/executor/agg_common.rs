@@ -35,6 +35,7 @@ pub struct AggExecutorArgs<S: StateStore, E: AggExecutorExtraArgs> {
 
     // system configs
     pub extreme_cache_size: usize,
+    pub approx_count_distinct_index_bits: u8,
 
     // agg common things
     pub agg_calls: Vec<AggCall>,
@@ -202,6 +202,7 @@ impl<S: StateStore, Strtg: Strategy> AggGroup<S, Strtg> {
         pk_indices: &PkIndices,
         row_count_index: usize,
         extreme_cache_size: usize,
+        approx_count_distinct_index_bits: u8,
         input_schema: &Schema,
     ) -> StreamExecutorResult<AggGroup<S, Strtg>> {
         let prev_outputs: Option<OwnedRow> = result_table
@@ -220,6 +221,7 @@ impl<S: StateStore, Strtg: Strategy> AggGroup<S, Strtg> {
                     pk_indices,
                     group_key.as_ref(),
                     extreme_cache_size,
+                    approx_count_distinct_index_bits,
                     input_schema,
                 )
             }))
@@ -24,7 +24,7 @@ use risingwave_common::{bail, row};
 use risingwave_storage::StateStore;
 
 use super::approx_distinct_utils::{
-    deserialize_buckets_from_list, serialize_buckets, RegisterBucket, StreamingApproxCountDistinct,
+    deserialize_state, serialize_state, RegisterBucket, StreamingApproxCountDistinct,
 };
 use crate::common::table::state_table::StateTable;
 use crate::executor::aggregation::table::TableStateImpl;
@@ -134,7 +134,12 @@ impl<S: StateStore> TableStateImpl<S> for AppendOnlyStreamingApproxCountDistinct
                 .as_ref()
                 .unwrap()
             {
-                let state = deserialize_buckets_from_list(list.values());
+                let state = deserialize_state(list.values())?;
+                // The persisted sketch takes precedence over the configured accuracy, so that
+                // changing the config does not corrupt the state of existing jobs.
+                if state.len() != self.registers().len() {
+                    self.reset_buckets(state.len() as u32);
+                }
                 for (idx, bucket) in self.registers_mut().iter_mut().enumerate() {
                     if state[idx] != 0 {
                         bucket.update_bucket(state[idx] as usize, true)?;
@@ -152,18 +157,13 @@ impl<S: StateStore> TableStateImpl<S> for AppendOnlyStreamingApproxCountDistinct
         state_table: &mut StateTable<S>,
         group_key: Option<&GroupKey>,
     ) -> StreamExecutorResult<()> {
-        let list = Some(ScalarImpl::List(ListValue::new(
-            serialize_buckets(
-                &self
-                    .registers()
-                    .iter()
-                    .map(|register| register.get_max())
-                    .collect_vec(),
-            )
-            .into_iter()
-            .map(|x| Some(ScalarImpl::Int64(x as i64)))
-            .collect_vec(),
-        )));
+        let list = Some(ScalarImpl::List(ListValue::new(serialize_state(
+            &self
+                .registers()
+                .iter()
+                .map(|register| register.get_max())
+                .collect_vec(),
+        ))));
         let current_row = group_key.map(GroupKey::table_row).chain(row::once(list));
 
         let state_row = {
@@ -194,4 +194,10 @@ impl AppendOnlyStreamingApproxCountDistinct {
     pub fn new() -> Self {
         Self::with_no_initial()
     }
+
+    /// Create a sketch with `2^index_bits` registers. Larger `index_bits` gives a more accurate
+    /// estimate at the cost of a larger state.
+    pub fn with_index_bits(index_bits: u8) -> Self {
+        Self::with_i64(1 << index_bits, 0)
+    }
 }
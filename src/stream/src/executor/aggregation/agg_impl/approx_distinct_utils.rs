@@ -20,21 +20,29 @@ use risingwave_common::array::stream_chunk::Ops;
 use risingwave_common::array::*;
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::estimate_size::EstimateSize;
-use risingwave_common::must_match;
 use risingwave_common::types::{Datum, DatumRef, Scalar, ScalarImpl};
 use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_common::{bail, must_match};
 
 use crate::executor::aggregation::agg_impl::StreamingAggImpl;
 use crate::executor::StreamExecutorResult;
 
-const INDEX_BITS: u8 = 16; // number of bits used for finding the index of each 64-bit hash
-const NUM_OF_REGISTERS: u32 = 1 << INDEX_BITS; // number of registers available
-const COUNT_BITS: u8 = 64 - INDEX_BITS; // number of non-index bits in each 64-bit hash
+/// Default number of bits used for finding the index of each 64-bit hash. Larger values give a
+/// more accurate estimate at the cost of a larger sketch.
+const DEFAULT_INDEX_BITS: u8 = 16;
+const NUM_OF_REGISTERS: u32 = 1 << DEFAULT_INDEX_BITS; // default number of registers
 const LOG_COUNT_BITS: u8 = 6;
 
-// Approximation for bias correction for 16384 registers. See "HyperLogLog: the analysis of a
-// near-optimal cardinality estimation algorithm" by Philippe Flajolet et al.
-const BIAS_CORRECTION: f64 = 0.7213 / (1. + (1.079 / NUM_OF_REGISTERS as f64));
+/// Version of the on-disk encoding produced by [`serialize_state`]. Bump this whenever the layout
+/// of the serialized sketch changes, and keep [`deserialize_state`] able to tell old versions
+/// apart so that an upgraded cluster fails loudly instead of misreading the state.
+const STATE_ENCODING_VERSION: i64 = 1;
+
+// Approximation for bias correction. See "HyperLogLog: the analysis of a near-optimal
+// cardinality estimation algorithm" by Philippe Flajolet et al.
+fn bias_correction(registers_num: f64) -> f64 {
+    0.7213 / (1. + (1.079 / registers_num))
+}
 
 fn pos_in_serialized(bucket_idx: usize) -> (usize, usize, u32) {
     // rust compiler will optimize for us
@@ -44,8 +52,65 @@ fn pos_in_serialized(bucket_idx: usize) -> (usize, usize, u32) {
     (start_idx, begin_bit, post_end_bit)
 }
 
-pub(super) fn deserialize_buckets_from_list(list: &[Datum]) -> Vec<u8> {
-    let bucket_num = list.len() * i64::BITS as usize / LOG_COUNT_BITS as usize;
+/// Serializes the register buckets into a list of `i64`, prefixed with a header carrying the
+/// encoding version and the number of index bits, so that the state stays self-describing.
+pub(super) fn serialize_state(buckets: &[u8]) -> Vec<Datum> {
+    let index_bits = buckets.len().trailing_zeros() as i64;
+    let mut result = Vec::with_capacity(2 + buckets.len() * LOG_COUNT_BITS as usize / 64 + 1);
+    result.push(Some(ScalarImpl::Int64(STATE_ENCODING_VERSION)));
+    result.push(Some(ScalarImpl::Int64(index_bits)));
+    result.extend(
+        serialize_buckets(buckets)
+            .into_iter()
+            .map(|x| Some(ScalarImpl::Int64(x as i64))),
+    );
+    result
+}
+
+/// Deserializes the register buckets written by [`serialize_state`], or by a release predating
+/// the version header. Returns an error if neither layout is recognized.
+pub(super) fn deserialize_state(list: &[Datum]) -> StreamExecutorResult<Vec<u8>> {
+    let version = must_match!(list[0], Some(ScalarImpl::Int64(v)) => v);
+    if version != STATE_ENCODING_VERSION {
+        // States written before the version header was introduced are a bare list of bucket
+        // words for the fixed default register count, so the first element is an arbitrary
+        // word rather than the version tag. Fall back to that layout instead of failing the
+        // upgrade.
+        return deserialize_legacy_state(list);
+    }
+    let index_bits = must_match!(list[1], Some(ScalarImpl::Int64(v)) => v);
+    if !(4..=20).contains(&index_bits) {
+        bail!(
+            "invalid index bits {} in approx_count_distinct state",
+            index_bits
+        );
+    }
+    Ok(deserialize_buckets_from_list(
+        &list[2..],
+        1usize << index_bits,
+    ))
+}
+
+/// Deserializes the unversioned legacy layout: the bucket words of [`NUM_OF_REGISTERS`] registers
+/// without any header.
+fn deserialize_legacy_state(list: &[Datum]) -> StreamExecutorResult<Vec<u8>> {
+    let expected_len =
+        (NUM_OF_REGISTERS as usize * LOG_COUNT_BITS as usize - 1) / i64::BITS as usize + 1;
+    if list.len() != expected_len {
+        bail!(
+            "unrecognized state encoding for approx_count_distinct: neither version {} nor the \
+             legacy layout of {} bucket words",
+            STATE_ENCODING_VERSION,
+            expected_len
+        );
+    }
+    Ok(deserialize_buckets_from_list(
+        list,
+        NUM_OF_REGISTERS as usize,
+    ))
+}
+
+fn deserialize_buckets_from_list(list: &[Datum], bucket_num: usize) -> Vec<u8> {
     let mut buckets = Vec::with_capacity(bucket_num);
     for i in 0..bucket_num {
         buckets.push({
@@ -65,7 +130,7 @@ pub(super) fn deserialize_buckets_from_list(list: &[Datum]) -> Vec<u8> {
     buckets
 }
 
-pub(super) fn serialize_buckets(buckets: &[u8]) -> Vec<u64> {
+fn serialize_buckets(buckets: &[u8]) -> Vec<u64> {
     let bucket_num = buckets.len();
     let result_len = (bucket_num * LOG_COUNT_BITS as usize - 1) / (i64::BITS as usize) + 1;
     let mut result = vec![];
@@ -98,9 +163,11 @@ pub(super) trait RegisterBucket {
 ///
 /// `StreamingApproxCountDistinct` can count up to a total of 2^64 unduplicated rows.
 ///
-/// The estimation error for `HyperLogLog` is 1.04/sqrt(num of registers). With 2^16 registers this
-/// is ~1/256, or about 0.4%. The memory usage for the default choice of parameters is about
-/// (1024 + 24) bits * 2^16 buckets, which is about 8.58 MB.
+/// The estimation error for `HyperLogLog` is 1.04/sqrt(num of registers). With the default of
+/// 2^16 registers this is ~1/256, or about 0.4%. The number of registers is configurable via the
+/// `approx_count_distinct_index_bits` streaming developer config, trading accuracy for state
+/// size. The memory usage for the default choice of parameters is about (1024 + 24) bits * 2^16
+/// buckets, which is about 8.58 MB.
 pub(super) trait StreamingApproxCountDistinct: Sized {
     type Bucket: RegisterBucket;
 
@@ -144,10 +211,11 @@ pub(super) trait StreamingApproxCountDistinct: Sized {
         let scalar_impl = datum_ref.unwrap().into_scalar_impl();
         let hash = self.get_hash(scalar_impl);
 
-        let index = (hash as u32) & (NUM_OF_REGISTERS - 1); // Index is based on last few bits
+        let registers_num = self.registers().len() as u64;
+        let index = (hash & (registers_num - 1)) as usize; // Index is based on last few bits
         let count = self.count_hash(hash) as usize;
 
-        self.registers_mut()[index as usize].update_bucket(count, is_insert)?;
+        self.registers_mut()[index].update_bucket(count, is_insert)?;
 
         Ok(())
     }
@@ -161,8 +229,9 @@ pub(super) trait StreamingApproxCountDistinct: Sized {
 
     /// Counts the number of trailing zeroes plus 1 in the non-index bits of the hash.
     fn count_hash(&self, mut hash: u64) -> u8 {
-        hash >>= INDEX_BITS; // Ignore bits used as index for the hash
-        hash |= 1 << COUNT_BITS; // To allow hash to terminate if it is all 0s
+        let index_bits = self.registers().len().trailing_zeros();
+        hash >>= index_bits; // Ignore bits used as index for the hash
+        hash |= 1 << (64 - index_bits); // To allow hash to terminate if it is all 0s
 
         (hash.trailing_zeros() + 1) as u8
     }
@@ -201,7 +270,7 @@ pub(super) trait StreamingApproxCountDistinct: Sized {
     }
 
     fn get_output_inner(&self) -> StreamExecutorResult<Datum> {
-        let m = NUM_OF_REGISTERS as f64;
+        let m = self.registers().len() as f64;
         let mut mean = 0.0;
 
         // Get harmonic mean of all the counts in results
@@ -210,7 +279,7 @@ pub(super) trait StreamingApproxCountDistinct: Sized {
             mean += 1.0 / ((1 << count) as f64);
         }
 
-        let raw_estimate = BIAS_CORRECTION * m * m / mean;
+        let raw_estimate = bias_correction(m) * m * m / mean;
 
         // If raw_estimate is not much bigger than m and some registers have value 0, set answer to
         // m * log(m/V) where V is the number of registers with value 0
@@ -266,6 +335,48 @@ where
     }
 
     fn reset(&mut self) {
-        self.reset_buckets(NUM_OF_REGISTERS);
+        self.reset_buckets(self.registers().len() as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        for index_bits in [4u8, 8, 16] {
+            let buckets = (0..(1usize << index_bits))
+                .map(|i| (i % 61) as u8)
+                .collect::<Vec<_>>();
+            let serialized = serialize_state(&buckets);
+            assert_eq!(
+                serialized[0],
+                Some(ScalarImpl::Int64(STATE_ENCODING_VERSION))
+            );
+            assert_eq!(serialized[1], Some(ScalarImpl::Int64(index_bits as i64)));
+            assert_eq!(deserialize_state(&serialized).unwrap(), buckets);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_encoding_version() {
+        let mut serialized = serialize_state(&[0; 16]);
+        serialized[0] = Some(ScalarImpl::Int64(STATE_ENCODING_VERSION + 1));
+        assert!(deserialize_state(&serialized).is_err());
+    }
+
+    #[test]
+    fn test_legacy_unversioned_state() {
+        let buckets = (0..NUM_OF_REGISTERS as usize)
+            .map(|i| (i % 61) as u8)
+            .collect::<Vec<_>>();
+        let legacy = serialize_buckets(&buckets)
+            .into_iter()
+            .map(|x| Some(ScalarImpl::Int64(x as i64)))
+            .collect::<Vec<_>>();
+        // Make sure the first bucket word is not mistaken for the current version tag.
+        assert_ne!(legacy[0], Some(ScalarImpl::Int64(STATE_ENCODING_VERSION)));
+        assert_eq!(deserialize_state(&legacy).unwrap(), buckets);
     }
 }
@@ -0,0 +1,377 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module implements the streaming `approx_percentile` aggregator, backed by the t-digest
+//! sketch from the expression crate.
+//!
+//! [`AppendOnlyStreamingApproxPercentile`] is used on append-only streams and persists its
+//! digest in a state table, so it survives failover and scale-out.
+//! [`UpdatableStreamingApproxPercentile`] additionally supports retraction by approximately
+//! removing values from the digest, but keeps the digest only in memory.
+
+use futures::{pin_mut, StreamExt};
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayBuilderImpl, ArrayImpl, F64ArrayBuilder, ListValue};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::estimate_size::EstimateSize;
+use risingwave_common::row::RowExt;
+use risingwave_common::types::{Datum, DatumRef, ScalarImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_common::{bail, must_match, row};
+use risingwave_expr::agg::{Centroid, TDigest};
+use risingwave_storage::StateStore;
+
+use super::StreamingAggImpl;
+use crate::common::table::state_table::StateTable;
+use crate::executor::aggregation::table::TableStateImpl;
+use crate::executor::aggregation::GroupKey;
+use crate::executor::StreamExecutorResult;
+
+/// Version of the state encoding, in case the layout needs to be changed later.
+const STATE_ENCODING_VERSION: i64 = 1;
+
+/// Serialize a digest into a `List<Float64>` datum list. The layout is self-describing:
+/// the encoding version and compression factor, followed by the `(weight, mean)` pair of each
+/// centroid in mean order.
+fn serialize_state(digest: &TDigest) -> Vec<Datum> {
+    let mut values = Vec::with_capacity(2 + digest.centroids().len() * 2);
+    values.push(Some(ScalarImpl::Float64(
+        (STATE_ENCODING_VERSION as f64).into(),
+    )));
+    values.push(Some(ScalarImpl::Float64(digest.compression().into())));
+    for centroid in digest.centroids() {
+        values.push(Some(ScalarImpl::Float64(centroid.weight.into())));
+        values.push(Some(ScalarImpl::Float64(centroid.mean.into())));
+    }
+    values
+}
+
+/// Deserialize a digest from the datum list of the `List<Float64>` state produced by
+/// [`serialize_state`].
+fn deserialize_state(list: &[Datum]) -> StreamExecutorResult<TDigest> {
+    if list.len() < 2 || list.len() % 2 != 0 {
+        bail!("corrupted state of streaming approx_percentile");
+    }
+    let values = list
+        .iter()
+        .map(|x| {
+            let f =
+                must_match!(x.as_ref().expect("should not be NULL"), ScalarImpl::Float64(f) => *f);
+            f.into()
+        })
+        .collect::<Vec<f64>>();
+    let version = values[0] as i64;
+    if version != STATE_ENCODING_VERSION {
+        bail!(
+            "unsupported state encoding version of streaming approx_percentile: {}",
+            version
+        );
+    }
+    let compression = values[1];
+    let centroids = values[2..]
+        .chunks_exact(2)
+        .map(|pair| Centroid {
+            weight: pair[0],
+            mean: pair[1],
+        })
+        .collect();
+    Ok(TDigest::from_sorted_centroids(compression, centroids))
+}
+
+/// Streaming `approx_percentile` on append-only streams, persisting its t-digest in a state
+/// table as a `List<Float64>` column.
+#[derive(Clone, Debug, Default, EstimateSize)]
+pub struct AppendOnlyStreamingApproxPercentile {
+    fraction: Option<f64>,
+    digest: TDigest,
+}
+
+impl AppendOnlyStreamingApproxPercentile {
+    pub fn new(fraction: Option<f64>) -> Self {
+        Self {
+            fraction,
+            digest: TDigest::default(),
+        }
+    }
+
+    fn apply_batch_inner(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        match visibility {
+            None => {
+                for (op, datum) in ops.iter().zip_eq_fast(data[0].iter()) {
+                    self.apply_row(*op, datum)?;
+                }
+            }
+            Some(visibility) => {
+                for ((visible, op), datum) in visibility
+                    .iter()
+                    .zip_eq_fast(ops.iter())
+                    .zip_eq_fast(data[0].iter())
+                {
+                    if visible {
+                        self.apply_row(*op, datum)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_row(&mut self, op: Op, datum: DatumRef<'_>) -> StreamExecutorResult<()> {
+        match op {
+            Op::Insert | Op::UpdateInsert => {
+                if let Some(scalar) = datum {
+                    self.digest.insert(scalar.into_float64().into());
+                }
+            }
+            Op::Delete | Op::UpdateDelete => {
+                bail!("deletion in append-only approx_percentile")
+            }
+        }
+        Ok(())
+    }
+
+    fn get_output_inner(&self) -> StreamExecutorResult<Datum> {
+        Ok(self
+            .fraction
+            .and_then(|fraction| self.digest.quantile(fraction))
+            .map(|x| ScalarImpl::Float64(x.into())))
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: StateStore> TableStateImpl<S> for AppendOnlyStreamingApproxPercentile {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        self.apply_batch_inner(ops, visibility, data)
+    }
+
+    fn get_output(&mut self) -> StreamExecutorResult<Datum> {
+        self.get_output_inner()
+    }
+
+    async fn update_from_state_table(
+        &mut self,
+        state_table: &StateTable<S>,
+        group_key: Option<&GroupKey>,
+    ) -> StreamExecutorResult<()> {
+        let state_row = {
+            let data_iter = state_table
+                .iter_with_pk_prefix(group_key.map(GroupKey::table_pk), Default::default())
+                .await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        if let Some(state_row) = state_row {
+            if let ScalarImpl::List(list) = state_row[group_key.map(GroupKey::len).unwrap_or(0)]
+                .as_ref()
+                .unwrap()
+            {
+                // The persisted digest takes precedence over the configured compression, so that
+                // changing the config does not corrupt the state of existing jobs.
+                self.digest = deserialize_state(list.values())?;
+            } else {
+                panic!("The state of append-only ApproxPercentile must be List.");
+            }
+        }
+        Ok(())
+    }
+
+    async fn flush_state_if_needed(
+        &self,
+        state_table: &mut StateTable<S>,
+        group_key: Option<&GroupKey>,
+    ) -> StreamExecutorResult<()> {
+        let list = Some(ScalarImpl::List(ListValue::new(serialize_state(
+            &self.digest,
+        ))));
+        let current_row = group_key.map(GroupKey::table_row).chain(row::once(list));
+
+        let state_row = {
+            let data_iter = state_table
+                .iter_with_pk_prefix(group_key.map(GroupKey::table_pk), Default::default())
+                .await?;
+            pin_mut!(data_iter);
+            if let Some(state_row) = data_iter.next().await {
+                Some(state_row?)
+            } else {
+                None
+            }
+        };
+        match state_row {
+            Some(state_row) => {
+                state_table.update(state_row, current_row);
+            }
+            None => {
+                state_table.insert(current_row);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Streaming `approx_percentile` supporting retraction, by approximately removing values from
+/// the digest.
+///
+/// FIXME: like non-append-only `approx_count_distinct`, the digest is kept only in memory and
+/// merely the output value is checkpointed, so the estimate cannot handle failover or
+/// scale-out correctly.
+#[derive(Clone, Debug, Default, EstimateSize)]
+pub struct UpdatableStreamingApproxPercentile {
+    fraction: Option<f64>,
+    digest: TDigest,
+
+    /// Output restored from the previous checkpoint, returned until new input arrives.
+    initial_output: Datum,
+}
+
+impl UpdatableStreamingApproxPercentile {
+    pub fn new(fraction: Option<f64>, initial_output: Datum) -> Self {
+        Self {
+            fraction,
+            digest: TDigest::default(),
+            initial_output,
+        }
+    }
+
+    fn apply_row(&mut self, op: Op, datum: DatumRef<'_>) {
+        if let Some(scalar) = datum {
+            let value = scalar.into_float64().into();
+            match op {
+                Op::Insert | Op::UpdateInsert => self.digest.insert(value),
+                Op::Delete | Op::UpdateDelete => self.digest.delete(value),
+            }
+        }
+    }
+}
+
+impl StreamingAggImpl for UpdatableStreamingApproxPercentile {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> StreamExecutorResult<()> {
+        match visibility {
+            None => {
+                for (op, datum) in ops.iter().zip_eq_fast(data[0].iter()) {
+                    self.apply_row(*op, datum);
+                }
+            }
+            Some(visibility) => {
+                for ((visible, op), datum) in visibility
+                    .iter()
+                    .zip_eq_fast(ops.iter())
+                    .zip_eq_fast(data[0].iter())
+                {
+                    if visible {
+                        self.apply_row(*op, datum);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_output(&self) -> StreamExecutorResult<Datum> {
+        if self.digest.centroids().is_empty() {
+            return Ok(self.initial_output.clone());
+        }
+        Ok(self
+            .fraction
+            .and_then(|fraction| self.digest.quantile(fraction))
+            .map(|x| ScalarImpl::Float64(x.into())))
+    }
+
+    fn new_builder(&self) -> ArrayBuilderImpl {
+        ArrayBuilderImpl::Float64(F64ArrayBuilder::new(0))
+    }
+
+    fn reset(&mut self) {
+        self.digest = TDigest::default();
+        self.initial_output = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        let mut digest = TDigest::default();
+        for v in 1..=100 {
+            digest.insert(v as f64);
+        }
+        let state = serialize_state(&digest);
+        assert_eq!(state[0], Some(ScalarImpl::Float64(1.0.into())));
+        let restored = deserialize_state(&state).unwrap();
+        assert_eq!(restored.centroids(), digest.centroids());
+        assert_eq!(restored.quantile(0.99), digest.quantile(0.99));
+    }
+
+    #[test]
+    fn test_unsupported_encoding_version() {
+        let state = vec![
+            Some(ScalarImpl::Float64(2.0.into())),
+            Some(ScalarImpl::Float64(100.0.into())),
+        ];
+        assert!(deserialize_state(&state).is_err());
+    }
+
+    #[test]
+    fn test_updatable_output() {
+        let mut agg = UpdatableStreamingApproxPercentile::new(
+            Some(0.5),
+            Some(ScalarImpl::Float64(42.0.into())),
+        );
+        // Before any input arrives, the restored output is returned.
+        assert_eq!(
+            agg.get_output().unwrap(),
+            Some(ScalarImpl::Float64(42.0.into()))
+        );
+        agg.apply_batch(
+            &[Op::Insert, Op::Insert, Op::Insert, Op::Delete],
+            None,
+            &[&ArrayImpl::Float64(
+                [
+                    Some(1.0.into()),
+                    Some(2.0.into()),
+                    Some(3.0.into()),
+                    Some(3.0.into()),
+                ]
+                .into_iter()
+                .collect(),
+            )],
+        )
+        .unwrap();
+        assert_eq!(
+            agg.get_output().unwrap(),
+            Some(ScalarImpl::Float64(1.5.into()))
+        );
+    }
+}
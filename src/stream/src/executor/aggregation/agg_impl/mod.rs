@@ -20,6 +20,9 @@ use std::any::Any;
 pub use approx_count_distinct::*;
 pub use approx_distinct_append::AppendOnlyStreamingApproxCountDistinct;
 use approx_distinct_utils::StreamingApproxCountDistinct;
+pub use approx_percentile::{
+    AppendOnlyStreamingApproxPercentile, UpdatableStreamingApproxPercentile,
+};
 use dyn_clone::DynClone;
 pub use foldable::*;
 use risingwave_common::array::stream_chunk::Ops;
@@ -32,6 +35,7 @@ use risingwave_common::buffer::Bitmap;
 use risingwave_common::estimate_size::EstimateSize;
 use risingwave_common::types::{DataType, Datum};
 use risingwave_expr::agg::AggKind;
+use risingwave_expr::expr::LiteralExpression;
 use risingwave_expr::*;
 pub use row_count::*;
 
@@ -40,6 +44,7 @@ use crate::executor::{StreamExecutorError, StreamExecutorResult};
 mod approx_count_distinct;
 mod approx_distinct_append;
 mod approx_distinct_utils;
+mod approx_percentile;
 mod foldable;
 mod row_count;
 
@@ -120,6 +125,7 @@ pub fn create_streaming_agg_impl(
     agg_type: &AggKind,
     return_type: &DataType,
     datum: Option<Datum>,
+    direct_args: &[LiteralExpression],
 ) -> StreamExecutorResult<Box<dyn StreamingAggImpl>> {
     macro_rules! gen_unary_agg_state_match {
         ($agg_type_expr:expr, $input_type_expr:expr, $return_type_expr:expr, $datum: expr,
@@ -144,6 +150,15 @@ pub fn create_streaming_agg_impl(
                 (AggKind::ApproxCountDistinct, _, DataType::Int64, None) => {
                     Box::new(UpdatableStreamingApproxCountDistinct::<{approx_count_distinct::DENSE_BITS_DEFAULT}>::with_no_initial())
                 }
+                (AggKind::ApproxPercentile, _, DataType::Float64, prev_output) => {
+                    let fraction = direct_args[0]
+                        .literal()
+                        .map(|x| (*x.as_float64()).into());
+                    Box::new(UpdatableStreamingApproxPercentile::new(
+                        fraction,
+                        prev_output.flatten(),
+                    ))
+                }
                 (other_agg, other_input, other_return, _) => panic!(
                     "streaming agg state not implemented: {:?} {:?} {:?}",
                     other_agg, other_input, other_return
@@ -84,15 +84,17 @@ impl<S: StateStore> AggState<S> {
         pk_indices: &PkIndices,
         group_key: Option<&GroupKey>,
         extreme_cache_size: usize,
+        approx_count_distinct_index_bits: u8,
         input_schema: &Schema,
     ) -> StreamExecutorResult<Self> {
         Ok(match storage {
             AggStateStorage::ResultValue => {
                 Self::Value(ValueState::new(agg_call, prev_output.cloned())?)
             }
-            AggStateStorage::Table { table } => {
-                Self::Table(TableState::new(agg_call, table, group_key).await?)
-            }
+            AggStateStorage::Table { table } => Self::Table(
+                TableState::new(agg_call, table, group_key, approx_count_distinct_index_bits)
+                    .await?,
+            ),
             AggStateStorage::MaterializedInput { mapping, .. } => {
                 Self::MaterializedInput(MaterializedInputState::new(
                     agg_call,
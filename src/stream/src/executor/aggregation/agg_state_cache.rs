@@ -23,6 +23,7 @@ use smallvec::SmallVec;
 
 use super::minput_agg_impl::MInputAggregator;
 use crate::common::cache::{StateCache, StateCacheFiller};
+use crate::executor::StreamExecutorResult;
 
 /// Cache key type.
 type CacheKey = MemcmpEncoded;
@@ -104,7 +105,7 @@ pub trait AggStateCache: EstimateSize {
     fn begin_syncing(&mut self) -> Box<dyn AggStateCacheFiller + Send + Sync + '_>;
 
     /// Get the aggregation output.
-    fn get_output(&self) -> Datum;
+    fn get_output(&self) -> StreamExecutorResult<Datum>;
 }
 
 /// Trait that defines agg state cache syncing interface.
@@ -169,7 +170,7 @@ where
         })
     }
 
-    fn get_output(&self) -> Datum {
+    fn get_output(&self) -> StreamExecutorResult<Datum> {
         self.aggregator.aggregate(self.state_cache.values())
     }
 }
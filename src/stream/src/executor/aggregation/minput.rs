@@ -33,6 +33,7 @@ use risingwave_storage::StateStore;
 use super::agg_state_cache::{AggStateCache, GenericAggStateCache, StateCacheInputBatch};
 use super::minput_agg_impl::array_agg::ArrayAgg;
 use super::minput_agg_impl::extreme::ExtremeAgg;
+use super::minput_agg_impl::jsonb_agg::{JsonbAgg, JsonbObjectAgg};
 use super::minput_agg_impl::string_agg::StringAgg;
 use super::GroupKey;
 use crate::common::cache::{OrderedStateCache, TopNStateCache};
@@ -139,6 +140,14 @@ impl<S: StateStore> MaterializedInputState<S> {
                 OrderedStateCache::new(),
                 ArrayAgg,
             )),
+            AggKind::JsonbAgg => Box::new(GenericAggStateCache::new(
+                OrderedStateCache::new(),
+                JsonbAgg,
+            )),
+            AggKind::JsonbObjectAgg => Box::new(GenericAggStateCache::new(
+                OrderedStateCache::new(),
+                JsonbObjectAgg,
+            )),
             _ => panic!(
                 "Agg kind `{}` is not expected to have materialized input state",
                 agg_call.kind
@@ -216,7 +225,7 @@ impl<S: StateStore> MaterializedInputState<S> {
             cache_filler.finish();
         }
         assert!(self.cache.is_synced());
-        Ok(self.cache.get_output())
+        self.cache.get_output()
     }
 }
 
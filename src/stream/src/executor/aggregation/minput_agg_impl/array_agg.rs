@@ -17,6 +17,7 @@ use risingwave_common::types::{Datum, DatumRef, ToOwnedDatum};
 use smallvec::SmallVec;
 
 use super::MInputAggregator;
+use crate::executor::StreamExecutorResult;
 
 pub struct ArrayAgg;
 
@@ -27,12 +28,15 @@ impl MInputAggregator for ArrayAgg {
         value[0].to_owned_datum()
     }
 
-    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+    fn aggregate<'a>(
+        &'a self,
+        values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum> {
         let res_values: Vec<Datum> = values.cloned().collect();
         if res_values.is_empty() {
-            return None;
+            return Ok(None);
         }
-        Some(ListValue::new(res_values).into())
+        Ok(Some(ListValue::new(res_values).into()))
     }
 }
 
@@ -46,12 +50,12 @@ mod tests {
         let agg = ArrayAgg;
 
         let mut cache = TopNCache::new(10);
-        assert_eq!(agg.aggregate(cache.values()), None);
+        assert_eq!(agg.aggregate(cache.values()).unwrap(), None);
 
         cache.insert(vec![1, 2, 3], Some("hello".to_string().into()));
         cache.insert(vec![1, 2, 4], Some("world".to_string().into()));
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some(
                 ListValue::new(vec![
                     Some("hello".to_string().into()),
@@ -63,7 +67,7 @@ mod tests {
 
         cache.insert(vec![0, 1, 2], Some("emmm".to_string().into()));
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some(
                 ListValue::new(vec![
                     Some("emmm".to_string().into()),
@@ -76,7 +80,7 @@ mod tests {
 
         cache.insert(vec![6, 6, 6], None);
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some(
                 ListValue::new(vec![
                     Some("emmm".to_string().into()),
@@ -16,6 +16,7 @@ use risingwave_common::types::{Datum, DatumRef, ToOwnedDatum};
 use smallvec::SmallVec;
 
 use super::MInputAggregator;
+use crate::executor::StreamExecutorResult;
 
 /// Common aggregator for `min`/`max`. The behavior is simply to choose the
 /// first value as aggregation result, so the value order in the given cache
@@ -30,8 +31,11 @@ impl MInputAggregator for ExtremeAgg {
         value[0].to_owned_datum()
     }
 
-    fn aggregate<'a>(&'a self, mut values: impl Iterator<Item = &'a Self::Value>) -> Datum {
-        values.next().cloned().flatten()
+    fn aggregate<'a>(
+        &'a self,
+        mut values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum> {
+        Ok(values.next().cloned().flatten())
     }
 }
 
@@ -45,18 +49,18 @@ mod tests {
         let agg = ExtremeAgg;
 
         let mut cache = TopNCache::new(10);
-        assert_eq!(agg.aggregate(cache.values()), None);
+        assert_eq!(agg.aggregate(cache.values()).unwrap(), None);
 
         cache.insert(vec![1, 2, 3], Some("hello".to_string().into()));
         cache.insert(vec![1, 3, 4], Some("world".to_string().into()));
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some("hello".to_string().into())
         );
 
         cache.insert(vec![0, 1, 2], Some("emmm".to_string().into()));
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some("emmm".to_string().into())
         );
     }
@@ -0,0 +1,203 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::estimate_size::EstimateSize;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::{Datum, DatumRef, JsonbVal, ScalarImpl, ToOwnedDatum};
+use risingwave_expr::ExprError;
+use serde_json::{Map, Number, Value};
+use smallvec::SmallVec;
+
+use super::MInputAggregator;
+use crate::executor::StreamExecutorResult;
+
+/// Converts a cached scalar into a json value. The set of types is restricted by the
+/// `jsonb_agg`/`jsonb_object_agg` signatures registered in the expression crate, so any other
+/// variant here is a planner bug.
+fn scalar_to_json(scalar: &ScalarImpl) -> Value {
+    match scalar {
+        ScalarImpl::Bool(v) => Value::Bool(*v),
+        ScalarImpl::Int16(v) => Value::from(*v),
+        ScalarImpl::Int32(v) => Value::from(*v),
+        ScalarImpl::Int64(v) => Value::from(*v),
+        ScalarImpl::Float32(v) => float_to_json(v.0 as f64),
+        ScalarImpl::Float64(v) => float_to_json(v.0),
+        ScalarImpl::Utf8(v) => Value::String(v.to_string()),
+        ScalarImpl::Jsonb(v) => v.clone().take(),
+        _ => panic!(
+            "unexpected type of jsonb aggregation argument: {:?}",
+            scalar
+        ),
+    }
+}
+
+fn float_to_json(v: f64) -> Value {
+    match Number::from_f64(v) {
+        Some(number) => Value::Number(number),
+        // `NaN` and `Infinity` are not valid json numbers, so they become strings like in
+        // PostgreSQL.
+        None => Value::String(ScalarImpl::Float64(v.into()).as_scalar_ref_impl().to_text()),
+    }
+}
+
+pub struct JsonbAgg;
+
+impl MInputAggregator for JsonbAgg {
+    type Value = Datum;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        value[0].to_owned_datum()
+    }
+
+    fn aggregate<'a>(
+        &'a self,
+        values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum> {
+        let array: Vec<_> = values
+            .map(|v| v.as_ref().map_or(Value::Null, scalar_to_json))
+            .collect();
+        if array.is_empty() {
+            return Ok(None); // return NULL if no rows to aggregate
+        }
+        Ok(Some(JsonbVal::from(Value::Array(array)).into()))
+    }
+}
+
+#[derive(EstimateSize)]
+pub struct JsonbObjectAggData {
+    key: Datum,
+    value: Datum,
+}
+
+pub struct JsonbObjectAgg;
+
+impl MInputAggregator for JsonbObjectAgg {
+    type Value = JsonbObjectAggData;
+
+    fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value {
+        JsonbObjectAggData {
+            key: value[0].to_owned_datum(),
+            value: value[1].to_owned_datum(),
+        }
+    }
+
+    fn aggregate<'a>(
+        &'a self,
+        values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum> {
+        let mut map = Map::new();
+        let mut any = false;
+        for JsonbObjectAggData { key, value } in values {
+            any = true;
+            // A null key is an error, like in the batch implementation.
+            let Some(ScalarImpl::Utf8(key)) = key else {
+                return Err(ExprError::FieldNameNull.into());
+            };
+            map.insert(
+                key.to_string(),
+                value.as_ref().map_or(Value::Null, scalar_to_json),
+            );
+        }
+        if !any {
+            return Ok(None); // return NULL if no rows to aggregate
+        }
+        Ok(Some(JsonbVal::from(Value::Object(map)).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::cache::TopNCache;
+
+    fn jsonb(s: &str) -> Datum {
+        Some(ScalarImpl::Jsonb(s.parse().unwrap()))
+    }
+
+    #[test]
+    fn test_jsonb_agg_aggregate() {
+        let agg = JsonbAgg;
+
+        let mut cache = TopNCache::new(10);
+        assert_eq!(agg.aggregate(cache.values()).unwrap(), None);
+
+        cache.insert(vec![1, 2, 3], Some(1i32.into()));
+        cache.insert(vec![1, 2, 4], jsonb(r#"{"a": 1}"#));
+        cache.insert(vec![2, 2, 4], None);
+        assert_eq!(
+            agg.aggregate(cache.values()).unwrap(),
+            jsonb(r#"[1, {"a": 1}, null]"#)
+        );
+    }
+
+    #[test]
+    fn test_jsonb_object_agg_aggregate() {
+        let agg = JsonbObjectAgg;
+
+        let mut cache = TopNCache::new(10);
+        assert_eq!(agg.aggregate(cache.values()).unwrap(), None);
+
+        cache.insert(
+            vec![1, 2, 3],
+            JsonbObjectAggData {
+                key: Some("a".to_string().into()),
+                value: Some(true.into()),
+            },
+        );
+        cache.insert(
+            vec![1, 2, 4],
+            JsonbObjectAggData {
+                key: Some("b".to_string().into()),
+                value: None,
+            },
+        );
+        // A duplicate key observed later in order overrides the earlier value.
+        cache.insert(
+            vec![2, 2, 4],
+            JsonbObjectAggData {
+                key: Some("a".to_string().into()),
+                value: Some("x".to_string().into()),
+            },
+        );
+        assert_eq!(
+            agg.aggregate(cache.values()).unwrap(),
+            jsonb(r#"{"a": "x", "b": null}"#)
+        );
+    }
+
+    #[test]
+    fn test_jsonb_object_agg_null_key() {
+        let agg = JsonbObjectAgg;
+
+        let mut cache = TopNCache::new(10);
+        cache.insert(
+            vec![1, 2, 3],
+            JsonbObjectAggData {
+                key: None,
+                value: Some(true.into()),
+            },
+        );
+        assert!(agg.aggregate(cache.values()).is_err());
+    }
+
+    #[test]
+    fn test_jsonb_agg_convert() {
+        let agg = JsonbAgg;
+        let args = SmallVec::from_vec(vec![Some("hello".into())]);
+        assert_eq!(
+            agg.convert_cache_value(args),
+            Some("hello".to_string().into())
+        );
+    }
+}
@@ -15,8 +15,11 @@
 use risingwave_common::types::{Datum, DatumRef};
 use smallvec::SmallVec;
 
+use crate::executor::StreamExecutorResult;
+
 pub mod array_agg;
 pub mod extreme;
+pub mod jsonb_agg;
 pub mod string_agg;
 
 /// Trait that defines aggregators that aggregate over an iterator of cached values.
@@ -27,6 +30,10 @@ pub trait MInputAggregator {
     /// Convert cache value into compact representation.
     fn convert_cache_value(&self, value: SmallVec<[DatumRef<'_>; 2]>) -> Self::Value;
 
-    /// Aggregate cached values.
-    fn aggregate<'a>(&'a self, values: impl Iterator<Item = &'a Self::Value>) -> Datum;
+    /// Aggregate cached values. Returns an error if a cached value is not valid for the
+    /// aggregation, e.g. a `NULL` key for `jsonb_object_agg`.
+    fn aggregate<'a>(
+        &'a self,
+        values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum>;
 }
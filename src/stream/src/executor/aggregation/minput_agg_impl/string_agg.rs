@@ -17,6 +17,7 @@ use risingwave_common::types::{Datum, DatumRef, ScalarRefImpl};
 use smallvec::SmallVec;
 
 use super::MInputAggregator;
+use crate::executor::StreamExecutorResult;
 
 #[derive(EstimateSize)]
 pub struct StringAggData {
@@ -42,16 +43,19 @@ impl MInputAggregator for StringAgg {
         }
     }
 
-    fn aggregate<'a>(&'a self, mut values: impl Iterator<Item = &'a Self::Value>) -> Datum {
+    fn aggregate<'a>(
+        &'a self,
+        mut values: impl Iterator<Item = &'a Self::Value>,
+    ) -> StreamExecutorResult<Datum> {
         let mut result = match values.next() {
             Some(data) => data.value.clone(),
-            None => return None, // return NULL if no rows to aggregate
+            None => return Ok(None), // return NULL if no rows to aggregate
         };
         for StringAggData { value, delim } in values {
             result.push_str(delim);
             result.push_str(value);
         }
-        Some(result.into())
+        Ok(Some(result.into()))
     }
 }
 
@@ -65,7 +69,7 @@ mod tests {
         let agg = StringAgg;
 
         let mut cache = TopNCache::new(10);
-        assert_eq!(agg.aggregate(cache.values()), None);
+        assert_eq!(agg.aggregate(cache.values()).unwrap(), None);
 
         cache.insert(
             vec![1, 2, 3],
@@ -82,7 +86,7 @@ mod tests {
             },
         );
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some("hello,world".to_string().into())
         );
 
@@ -94,7 +98,7 @@ mod tests {
             },
         );
         assert_eq!(
-            agg.aggregate(cache.values()),
+            agg.aggregate(cache.values()).unwrap(),
             Some("emmm_hello,world".to_string().into())
         );
     }
@@ -21,7 +21,9 @@ use risingwave_common::types::Datum;
 use risingwave_expr::agg::{AggCall, AggKind};
 use risingwave_storage::StateStore;
 
-use super::agg_impl::AppendOnlyStreamingApproxCountDistinct;
+use super::agg_impl::{
+    AppendOnlyStreamingApproxCountDistinct, AppendOnlyStreamingApproxPercentile,
+};
 use super::GroupKey;
 use crate::common::table::state_table::StateTable;
 use crate::executor::StreamExecutorResult;
@@ -54,9 +56,9 @@ pub trait TableStateImpl<S: StateStore>: EstimateSize + Send + Sync + 'static {
 /// Aggregation state as a single state table whose schema is deduced by frontend and backend with
 /// implicit consensus.
 ///
-/// For example, in `single_phase_append_only_approx_count_distinct_agg`, 65536 buckets are stored
-/// according to hash value, and the aggregation result is calculated from buckets when need to get
-/// output.
+/// For example, in `single_phase_append_only_approx_count_distinct_agg`, the configured number of
+/// buckets are stored according to hash value, and the aggregation result is calculated from
+/// buckets when need to get output.
 #[derive(EstimateSize)]
 pub struct TableState<S: StateStore> {
     /// Upstream column indices of agg arguments.
@@ -72,12 +74,19 @@ impl<S: StateStore> TableState<S> {
         agg_call: &AggCall,
         state_table: &StateTable<S>,
         group_key: Option<&GroupKey>,
+        index_bits: u8,
     ) -> StreamExecutorResult<Self> {
         let mut this = Self {
             arg_indices: agg_call.args.val_indices().to_vec(),
             inner: match agg_call.kind {
-                AggKind::ApproxCountDistinct => {
-                    Box::new(AppendOnlyStreamingApproxCountDistinct::new())
+                AggKind::ApproxCountDistinct => Box::new(
+                    AppendOnlyStreamingApproxCountDistinct::with_index_bits(index_bits),
+                ),
+                AggKind::ApproxPercentile => {
+                    let fraction = agg_call.direct_args[0]
+                        .literal()
+                        .map(|x| (*x.as_float64()).into());
+                    Box::new(AppendOnlyStreamingApproxPercentile::new(fraction))
                 }
                 _ => panic!(
                     "Agg kind `{}` is not expected to have table state",
@@ -45,6 +45,7 @@ impl ValueState {
                 &agg_call.kind,
                 &agg_call.return_type,
                 prev_output,
+                &agg_call.direct_args,
             )?,
         })
     }
@@ -54,8 +54,10 @@ use crate::task::{ActorId, CreateMviewProgress};
 /// It uses the latest epoch to read the snapshot of the upstream mv during two barriers and all the
 /// `StreamChunk` of the snapshot read will forward to the downstream.
 ///
-/// It uses `current_pos` to record the progress of the backfill (the pk of the upstream mv) and
-/// `current_pos` is initiated as an empty `Row`.
+/// It uses `current_pos` to record the progress of the backfill (the pk of the upstream mv).
+/// `current_pos` is persisted to the state table on barrier, and restored from it on recovery, so
+/// that an interrupted backfill resumes from the last checkpointed position instead of starting
+/// over.
 ///
 /// All upstream messages during the two barriers interval will be buffered and decide to forward or
 /// ignore based on the `current_pos` at the end of the later barrier. Once `current_pos` reaches
@@ -139,18 +141,26 @@ where
             state_table.init_epoch(first_barrier.epoch);
         }
 
-        let is_finished = if let Some(state_table) = self.state_table.as_mut() {
-            let is_finished = Self::check_all_vnode_finished(state_table, state_len).await?;
+        let (is_finished, recovered_pos) = if let Some(state_table) = self.state_table.as_mut() {
+            let (is_finished, recovered_pos) =
+                Self::recover_backfill_state(state_table, pk_in_output_indices.len(), pk_order)
+                    .await?;
             if is_finished {
                 assert!(!first_barrier.is_newly_added(self.actor_id));
             }
-            is_finished
+            (is_finished, recovered_pos)
         } else {
             // Maintain backwards compatibility with no state table
-            !first_barrier.is_newly_added(self.actor_id)
+            (!first_barrier.is_newly_added(self.actor_id), None)
         };
 
-        // If the snapshot is empty, we don't need to backfill.
+        // Current position of the upstream_table storage primary key.
+        // `None` means it starts from the beginning. After a recovery it is seeded with the
+        // persisted position, so the backfill resumes from where it was interrupted instead of
+        // scanning the snapshot from scratch.
+        let mut current_pos: Option<OwnedRow> = recovered_pos;
+
+        // If the (remaining) snapshot is empty, we don't need to backfill.
         // We cannot complete progress now, as we want to persist
         // finished state to state store first.
         // As such we will wait for next barrier.
@@ -159,7 +169,12 @@ where
                 // It is finished, so just assign a value to avoid accessing storage table again.
                 false
             } else {
-                let snapshot = Self::snapshot_read(&self.upstream_table, init_epoch, None, false);
+                let snapshot = Self::snapshot_read(
+                    &self.upstream_table,
+                    init_epoch,
+                    current_pos.clone(),
+                    false,
+                );
                 pin_mut!(snapshot);
                 snapshot.try_next().await?.unwrap().is_none()
             }
@@ -171,10 +186,6 @@ where
         // | f                    | f              | t                |
         let to_backfill = !is_finished && !is_snapshot_empty;
 
-        // Current position of the upstream_table storage primary key.
-        // `None` means it starts from the beginning.
-        let mut current_pos: Option<OwnedRow> = None;
-
         // Use these to persist state.
         // They contain the backfill position,
         // as well as the progress.
@@ -183,6 +194,13 @@ where
         let mut current_state: Vec<Datum> = vec![None; state_len];
         let mut old_state: Option<Vec<Datum>> = None;
 
+        // If we recovered a position from the state table, seed the old state with it, so that
+        // later flushes update the existing rows instead of inserting new ones.
+        if let Some(recovered_pos) = &current_pos {
+            Self::build_temporary_state(&mut current_state, is_finished, recovered_pos);
+            old_state = Some(current_state.clone());
+        }
+
         // The first barrier message should be propagated.
         yield Message::Barrier(first_barrier);
 
@@ -384,7 +402,7 @@ where
                     // This is because we can't update state table in first epoch,
                     // since it expects to have been initialized in previous epoch
                     // (there's no epoch before the first epoch).
-                    if is_snapshot_empty {
+                    if is_snapshot_empty && current_pos.is_none() {
                         current_pos =
                             Self::construct_initial_finished_state(pk_in_output_indices.len())
                     }
@@ -625,37 +643,48 @@ where
         Some(OwnedRow::new(vec![None; pos_len]))
     }
 
-    /// All vnodes should be persisted with status finished.
-    /// TODO: In the future we will support partial backfill recovery.
-    /// When that is done, this logic may need to be rewritten to handle
-    /// partially complete states per vnode.
-    async fn check_all_vnode_finished(
+    /// Recover the backfill state persisted in the state table.
+    ///
+    /// Returns whether the backfill has finished, and the position to resume the snapshot read
+    /// from. If any vnode has no state persisted yet, e.g. because it was just assigned to this
+    /// actor, we conservatively restart the backfill from the beginning.
+    async fn recover_backfill_state(
         state_table: &StateTable<S>,
-        state_len: usize,
-    ) -> StreamExecutorResult<bool> {
+        pk_len: usize,
+        pk_order: &[OrderType],
+    ) -> StreamExecutorResult<(bool, Option<OwnedRow>)> {
         debug_assert!(!state_table.vnode_bitmap().is_empty());
         let vnodes = state_table.vnodes().iter_vnodes_scalar();
         let mut is_finished = true;
+        let mut resume_pos: Option<OwnedRow> = None;
         for vnode in vnodes {
             let key: &[Datum] = &[Some(vnode.into())];
-            let row = state_table.get_row(key).await?;
-
-            // original_backfill_datum_pos = (state_len - 1)
-            // value indices are set, so we can -1 for the pk (a single vnode).
-            let backfill_datum_pos = state_len - 2;
-            let vnode_is_finished = if let Some(row) = row
-                && let Some(vnode_is_finished) = row.datum_at(backfill_datum_pos)
-            {
-                vnode_is_finished.into_bool()
-            } else {
-                false
+            let Some(row) = state_table.get_row(key).await? else {
+                return Ok((false, None));
             };
-            if !vnode_is_finished {
-                is_finished = false;
-                break;
+
+            // Value indices are set, so the `backfill_finished` flag directly follows the pk.
+            let vnode_is_finished = row.datum_at(pk_len).map_or(false, |datum| datum.into_bool());
+            is_finished &= vnode_is_finished;
+
+            // We write the same state to all vnodes, but in case they ever diverge, resume from
+            // the minimum position across vnodes so that no row is skipped.
+            let pos = OwnedRow::new(row.as_inner()[..pk_len].to_vec());
+            let pos_is_less = resume_pos.as_ref().map_or(true, |resume_pos| {
+                pos.iter()
+                    .zip_eq_fast(pk_order.iter().copied())
+                    .cmp_by(resume_pos.iter(), |(x, order), y| cmp_datum(x, y, order))
+                    == Ordering::Less
+            });
+            if pos_is_less {
+                resume_pos = Some(pos);
             }
         }
-        Ok(is_finished)
+        if is_finished {
+            Ok((true, None))
+        } else {
+            Ok((false, resume_pos))
+        }
     }
 }
 
@@ -0,0 +1,608 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::ops::Bound::Included;
+use std::sync::Arc;
+
+use futures::{pin_mut, StreamExt};
+use futures_async_stream::try_stream;
+use risingwave_common::array::Op;
+use risingwave_common::bail;
+use risingwave_common::catalog::Schema;
+use risingwave_common::hash::VnodeBitmapExt;
+use risingwave_common::row::{once, OwnedRow, Row, RowExt};
+use risingwave_common::types::{DefaultOrd, DefaultOrdered, ScalarImpl, ToOwnedDatum};
+use risingwave_common::util::iter_util::ZipEqDebug;
+use risingwave_expr::expr::BoxedExpression;
+use risingwave_storage::store::PrefetchOptions;
+use risingwave_storage::StateStore;
+
+use super::barrier_align::*;
+use super::error::StreamExecutorError;
+use super::monitor::StreamingMetrics;
+use super::{
+    ActorContextRef, BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef,
+};
+use crate::common::table::state_table::StateTable;
+use crate::common::StreamChunkBuilder;
+use crate::executor::expect_first_barrier_from_aligned_stream;
+
+/// The intervals of the band side, indexed by their lower bound so that probing a left value
+/// only visits intervals whose lower bound does not exceed it. Intervals with a `NULL` lower
+/// bound are never stored since they cannot contain any value.
+#[derive(Default)]
+struct BandIndex {
+    intervals: BTreeMap<DefaultOrdered<ScalarImpl>, Vec<OwnedRow>>,
+}
+
+impl BandIndex {
+    fn insert(&mut self, lower: ScalarImpl, row: OwnedRow) {
+        self.intervals
+            .entry(DefaultOrdered(lower))
+            .or_default()
+            .push(row);
+    }
+
+    fn remove(&mut self, lower: ScalarImpl, row: &OwnedRow) -> bool {
+        let key = DefaultOrdered(lower);
+        let Some(rows) = self.intervals.get_mut(&key) else {
+            return false;
+        };
+        let Some(pos) = rows.iter().position(|r| r == row) else {
+            return false;
+        };
+        rows.swap_remove(pos);
+        if rows.is_empty() {
+            self.intervals.remove(&key);
+        }
+        true
+    }
+
+    /// All rows whose lower bound does not exceed `probe`. The upper bound and the strictness
+    /// of the lower bound still have to be checked by the caller.
+    fn candidates(&self, probe: &ScalarImpl) -> impl Iterator<Item = &OwnedRow> {
+        self.intervals
+            .range(..=DefaultOrdered(probe.clone()))
+            .flat_map(|(_, rows)| rows)
+    }
+}
+
+/// `BandJoinExecutor` supports streaming inner joins whose join condition consists of range
+/// predicates only: a column of the left (probe) side must fall into the band defined by two
+/// columns of the right (band) side, i.e. `l.x >= r.lo AND l.x <= r.hi` with possibly strict
+/// bounds.
+///
+/// The right side is broadcast, so every parallel unit holds a full copy of the band side in
+/// an in-memory [`BandIndex`] (recovered from the right state table on startup), while the
+/// left side is persisted in a state table keyed by the band column so that an update of the
+/// band side can find the affected left rows with a range scan.
+pub struct BandJoinExecutor<S: StateStore> {
+    ctx: ActorContextRef,
+    source_l: Option<BoxedExecutor>,
+    source_r: Option<BoxedExecutor>,
+    left_key: usize,
+    right_lower_key: usize,
+    right_upper_key: usize,
+    lower_inclusive: bool,
+    upper_inclusive: bool,
+    /// The remaining part of the join condition, evaluated on the concatenated row.
+    condition: Option<BoxedExpression>,
+    output_indices: Vec<usize>,
+    pk_indices: PkIndices,
+    identity: String,
+    schema: Schema,
+    left_table: StateTable<S>,
+    right_table: StateTable<S>,
+    metrics: Arc<StreamingMetrics>,
+    /// The maximum size of the chunk produced by executor at a time.
+    chunk_size: usize,
+}
+
+impl<S: StateStore> BandJoinExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ctx: ActorContextRef,
+        source_l: BoxedExecutor,
+        source_r: BoxedExecutor,
+        left_key: usize,
+        right_lower_key: usize,
+        right_upper_key: usize,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+        condition: Option<BoxedExpression>,
+        output_indices: Vec<usize>,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        state_table_l: StateTable<S>,
+        state_table_r: StateTable<S>,
+        metrics: Arc<StreamingMetrics>,
+        chunk_size: usize,
+    ) -> Self {
+        let concat_fields = source_l
+            .schema()
+            .fields()
+            .iter()
+            .chain(source_r.schema().fields())
+            .cloned()
+            .collect::<Vec<_>>();
+        let schema = Schema::new(
+            output_indices
+                .iter()
+                .map(|&idx| concat_fields[idx].clone())
+                .collect(),
+        );
+        Self {
+            ctx,
+            source_l: Some(source_l),
+            source_r: Some(source_r),
+            left_key,
+            right_lower_key,
+            right_upper_key,
+            lower_inclusive,
+            upper_inclusive,
+            condition,
+            output_indices,
+            pk_indices,
+            identity: format!("BandJoinExecutor {:X}", executor_id),
+            schema,
+            left_table: state_table_l,
+            right_table: state_table_r,
+            metrics,
+            chunk_size,
+        }
+    }
+
+    /// Whether `probe` falls into the band of `right_row`, respecting the strictness of the
+    /// bounds. The lower bound is guaranteed not to exceed `probe` by [`BandIndex`] pruning.
+    fn band_contains(&self, probe: &ScalarImpl, right_row: &impl Row) -> bool {
+        let probe = probe.as_scalar_ref_impl();
+        let lower_ok = match right_row.datum_at(self.right_lower_key) {
+            Some(lower) => match lower.default_cmp(&probe) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => self.lower_inclusive,
+                std::cmp::Ordering::Greater => false,
+            },
+            None => false,
+        };
+        if !lower_ok {
+            return false;
+        }
+        match right_row.datum_at(self.right_upper_key) {
+            Some(upper) => match probe.default_cmp(&upper) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => self.upper_inclusive,
+                std::cmp::Ordering::Greater => false,
+            },
+            None => false,
+        }
+    }
+
+    async fn check_condition(
+        condition: &Option<BoxedExpression>,
+        ctx: &ActorContextRef,
+        identity: &str,
+        row_l: impl Row,
+        row_r: impl Row,
+    ) -> bool {
+        let Some(cond) = condition else {
+            return true;
+        };
+        let concat_row = row_l.chain(row_r).into_owned_row();
+        cond.eval_row_infallible(&concat_row, |err| ctx.on_compute_error(err, identity))
+            .await
+            .map(|s| *s.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Whether this actor is in charge of writing the replicated right state table.
+    fn is_right_table_writer(&self) -> bool {
+        self.left_table.vnode_bitmap().is_set(0)
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn into_stream(mut self) {
+        let input_l = self.source_l.take().unwrap();
+        let input_r = self.source_r.take().unwrap();
+        let left_len = input_l.schema().len();
+        let right_len = input_r.schema().len();
+
+        let aligned_stream = barrier_align(
+            input_l.execute(),
+            input_r.execute(),
+            self.ctx.id,
+            self.metrics.clone(),
+        );
+        pin_mut!(aligned_stream);
+
+        let barrier = expect_first_barrier_from_aligned_stream(&mut aligned_stream).await?;
+        self.left_table.init_epoch(barrier.epoch);
+        self.right_table.init_epoch(barrier.epoch);
+
+        // Recover the band index from the replicated right state table.
+        let mut band_index = BandIndex::default();
+        {
+            let row_stream = self.right_table.iter(Default::default()).await?;
+            pin_mut!(row_stream);
+            while let Some(res) = row_stream.next().await {
+                let row: OwnedRow = res?;
+                let lower = row[self.right_lower_key]
+                    .clone()
+                    .expect("lower bound of a stored band row must not be NULL");
+                band_index.insert(lower, row);
+            }
+        }
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+
+        let (left_to_output, right_to_output) = StreamChunkBuilder::get_i2o_mapping(
+            self.output_indices.iter().copied(),
+            left_len,
+            right_len,
+        );
+        let mut left_builder = StreamChunkBuilder::new(
+            self.chunk_size,
+            &self.schema.data_types(),
+            left_to_output.clone(),
+            right_to_output.clone(),
+        );
+        let mut right_builder = StreamChunkBuilder::new(
+            self.chunk_size,
+            &self.schema.data_types(),
+            right_to_output,
+            left_to_output,
+        );
+
+        #[for_await]
+        for msg in aligned_stream {
+            match msg? {
+                AlignedMessage::Left(chunk) => {
+                    let chunk = chunk.compact();
+                    let (data_chunk, ops) = chunk.into_parts();
+                    for (row, op) in data_chunk.rows().zip_eq_debug(ops.iter()) {
+                        // Rows with a NULL band column can never match, so they are neither
+                        // stored nor joined.
+                        let Some(probe) = row.datum_at(self.left_key).to_owned_datum() else {
+                            continue;
+                        };
+                        let out_op = match *op {
+                            Op::Insert | Op::UpdateInsert => {
+                                self.left_table.insert(row);
+                                Op::Insert
+                            }
+                            Op::Delete | Op::UpdateDelete => {
+                                self.left_table.delete(row);
+                                Op::Delete
+                            }
+                        };
+                        for right_row in band_index.candidates(&probe) {
+                            if !self.band_contains(&probe, right_row) {
+                                continue;
+                            }
+                            if !Self::check_condition(
+                                &self.condition,
+                                &self.ctx,
+                                &self.identity,
+                                row,
+                                right_row,
+                            )
+                            .await
+                            {
+                                continue;
+                            }
+                            if let Some(chunk) = left_builder.append_row(out_op, row, right_row) {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    }
+                    if let Some(chunk) = left_builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::Right(chunk) => {
+                    let chunk = chunk.compact();
+                    let (data_chunk, ops) = chunk.into_parts();
+                    for (row, op) in data_chunk.rows().zip_eq_debug(ops.iter()) {
+                        // Intervals with a NULL lower bound can never contain any value.
+                        let Some(lower) = row.datum_at(self.right_lower_key).to_owned_datum()
+                        else {
+                            continue;
+                        };
+                        // The right side is broadcast, so only one actor writes the replicated
+                        // state table while all actors maintain their in-memory index.
+                        let out_op = match *op {
+                            Op::Insert | Op::UpdateInsert => {
+                                if self.is_right_table_writer() {
+                                    self.right_table.insert(row);
+                                }
+                                band_index.insert(lower, row.into_owned_row());
+                                Op::Insert
+                            }
+                            Op::Delete | Op::UpdateDelete => {
+                                if self.is_right_table_writer() {
+                                    self.right_table.delete(row);
+                                }
+                                if !band_index.remove(lower, &row.into_owned_row()) {
+                                    bail!("deleting a band row that does not exist: {:?}", row);
+                                }
+                                Op::Delete
+                            }
+                        };
+                        // Find the affected left rows with a range scan on the band column. The
+                        // bounds are conservatively inclusive here; strict bounds are checked
+                        // per row by `band_contains`.
+                        let Some(upper) = row.datum_at(self.right_upper_key).to_owned_datum()
+                        else {
+                            continue;
+                        };
+                        let range = (
+                            Included(once(row.datum_at(self.right_lower_key).to_owned_datum())),
+                            Included(once(Some(upper))),
+                        );
+                        for vnode in self.left_table.vnodes().iter_vnodes() {
+                            let row_stream = self
+                                .left_table
+                                .iter_with_pk_range(
+                                    &range,
+                                    vnode,
+                                    PrefetchOptions::new_for_exhaust_iter(),
+                                )
+                                .await?;
+                            pin_mut!(row_stream);
+                            while let Some(res) = row_stream.next().await {
+                                let left_row: OwnedRow = res?;
+                                let probe = left_row[self.left_key]
+                                    .clone()
+                                    .expect("band column of a stored left row must not be NULL");
+                                if !self.band_contains(&probe, &row) {
+                                    continue;
+                                }
+                                if !Self::check_condition(
+                                    &self.condition,
+                                    &self.ctx,
+                                    &self.identity,
+                                    &left_row,
+                                    row,
+                                )
+                                .await
+                                {
+                                    continue;
+                                }
+                                if let Some(chunk) =
+                                    right_builder.append_row(out_op, row, &left_row)
+                                {
+                                    yield Message::Chunk(chunk);
+                                }
+                            }
+                        }
+                    }
+                    if let Some(chunk) = right_builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::WatermarkLeft(_) | AlignedMessage::WatermarkRight(_) => {
+                    // TODO: state cleaning by watermark is not supported yet.
+                }
+                AlignedMessage::Barrier(barrier) => {
+                    self.left_table.commit(barrier.epoch).await?;
+                    if self.is_right_table_writer() {
+                        self.right_table.commit(barrier.epoch).await?;
+                    } else {
+                        self.right_table.commit_no_data_expected(barrier.epoch);
+                    }
+
+                    // Update the vnode bitmap for the left state table if asked.
+                    if let Some(vnode_bitmap) = barrier.as_update_vnode_bitmap(self.ctx.id) {
+                        let (_previous_vnode_bitmap, _cache_may_stale) =
+                            self.left_table.update_vnode_bitmap(vnode_bitmap);
+                    }
+
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> Executor for BandJoinExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.into_stream().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::array::StreamChunk;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
+    use crate::executor::{ActorContext, StreamExecutorResult};
+
+    async fn create_in_memory_state_table(
+        mem_state: MemoryStateStore,
+    ) -> (StateTable<MemoryStateStore>, StateTable<MemoryStateStore>) {
+        let column_descs = |n| ColumnDesc::unnamed(ColumnId::new(n), DataType::Int64);
+        let state_table_l = StateTable::new_without_distribution_inconsistent_op(
+            mem_state.clone(),
+            TableId::new(0),
+            vec![column_descs(0)],
+            vec![OrderType::ascending()],
+            vec![0],
+        )
+        .await;
+        let state_table_r = StateTable::new_without_distribution_inconsistent_op(
+            mem_state,
+            TableId::new(1),
+            vec![column_descs(0), column_descs(1)],
+            vec![OrderType::ascending(), OrderType::ascending()],
+            vec![0, 1],
+        )
+        .await;
+        (state_table_l, state_table_r)
+    }
+
+    async fn create_executor(
+        mem_state: MemoryStateStore,
+    ) -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let (state_table_l, state_table_r) = create_in_memory_state_table(mem_state).await;
+        let schema_l = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let schema_r = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (tx_l, source_l) = MockSource::channel(schema_l, vec![0]);
+        let (tx_r, source_r) = MockSource::channel(schema_r, vec![0, 1]);
+
+        let executor = BandJoinExecutor::<MemoryStateStore>::new(
+            ActorContext::create(123),
+            Box::new(source_l),
+            Box::new(source_r),
+            0,
+            0,
+            1,
+            true,
+            true,
+            None,
+            vec![0, 1, 2],
+            vec![0],
+            1,
+            state_table_l,
+            state_table_r,
+            Arc::new(StreamingMetrics::unused()),
+            1024,
+        );
+        (tx_l, tx_r, Box::new(executor).execute())
+    }
+
+    #[tokio::test]
+    async fn test_band_join_inner() -> StreamExecutorResult<()> {
+        let mem_state = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut band_join) = create_executor(mem_state.clone()).await;
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        band_join.next_unwrap_ready_barrier()?;
+
+        // insert a band on the right side
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 2 4",
+        ));
+        // no left rows yet, no output
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 1
+             + 2
+             + 3
+             + 5",
+        ));
+        let chunk = band_join.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I I
+                 + 2 2 4
+                 + 3 2 4"
+            )
+        );
+
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        band_join.next_unwrap_ready_barrier()?;
+
+        // replacing the band retracts the old matches and emits the new ones
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 2 4
+             + 3 5",
+        ));
+        let chunk = band_join.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I I
+                 - 2 2 4
+                 - 3 2 4
+                 + 3 3 5
+                 + 5 3 5"
+            )
+        );
+
+        // deleting a left row inside the band retracts its match
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             - 3",
+        ));
+        let chunk = band_join.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I I
+                 - 3 3 5"
+            )
+        );
+
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        band_join.next_unwrap_ready_barrier()?;
+
+        // recover the executor and check that the band index is rebuilt from the state table
+        drop(tx_l);
+        drop(tx_r);
+        drop(band_join);
+        let (mut tx_l, mut tx_r, mut band_join) = create_executor(mem_state).await;
+
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        band_join.next_unwrap_ready_barrier()?;
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 4",
+        ));
+        let chunk = band_join.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I I
+                 + 4 3 5"
+            )
+        );
+
+        Ok(())
+    }
+}
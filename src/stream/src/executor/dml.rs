@@ -25,14 +25,16 @@ use risingwave_source::dml_manager::DmlManagerRef;
 
 use super::error::StreamExecutorError;
 use super::{
-    expect_first_barrier, BoxedExecutor, BoxedMessageStream, Executor, Message, Mutation,
-    PkIndices, PkIndicesRef,
+    expect_first_barrier, ActorContextRef, BoxedExecutor, BoxedMessageStream, Executor, Message,
+    Mutation, PkIndices, PkIndicesRef,
 };
 use crate::executor::stream_reader::StreamReaderWithPause;
 
 /// [`DmlExecutor`] accepts both stream data and batch data for data manipulation on a specific
 /// table. The two streams will be merged into one and then sent to downstream.
 pub struct DmlExecutor {
+    ctx: ActorContextRef,
+
     upstream: BoxedExecutor,
 
     schema: Schema,
@@ -73,6 +75,7 @@ struct TxnBuffer {
 impl DmlExecutor {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
+        ctx: ActorContextRef,
         upstream: BoxedExecutor,
         schema: Schema,
         pk_indices: PkIndices,
@@ -83,6 +86,7 @@ impl DmlExecutor {
         column_descs: Vec<ColumnDesc>,
     ) -> Self {
         Self {
+            ctx,
             upstream,
             schema,
             pk_indices,
@@ -141,6 +145,15 @@ impl DmlExecutor {
                             match mutation {
                                 Mutation::Pause => stream.pause_stream(),
                                 Mutation::Resume => stream.resume_stream(),
+                                Mutation::PauseSource(actor_pause) => {
+                                    // Also covers job-scoped pause, which stops the DML input of
+                                    // the paused table.
+                                    match actor_pause.get(&self.ctx.id).copied() {
+                                        Some(true) => stream.pause_stream(),
+                                        Some(false) => stream.resume_stream(),
+                                        None => {}
+                                    }
+                                }
                                 _ => {}
                             }
                         }
@@ -231,6 +244,7 @@ mod tests {
 
     use super::*;
     use crate::executor::test_utils::MockSource;
+    use crate::executor::ActorContext;
 
     const TEST_TRANSACTION_ID: TxnId = 0;
 
@@ -251,6 +265,7 @@ mod tests {
         let (mut tx, source) = MockSource::channel(schema.clone(), pk_indices.clone());
 
         let dml_executor = Box::new(DmlExecutor::new(
+            ActorContext::create(0),
             Box::new(source),
             schema,
             pk_indices,
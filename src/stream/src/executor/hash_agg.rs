@@ -113,6 +113,9 @@ struct ExecutorInner<K: HashKey, S: StateStore> {
     /// State cache size for extreme agg.
     extreme_cache_size: usize,
 
+    /// Number of index bits of the sketch for approx count distinct agg.
+    approx_count_distinct_index_bits: u8,
+
     /// The maximum size of the chunk produced by executor at a time.
     chunk_size: usize,
 
@@ -233,6 +236,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 distinct_dedup_tables: args.distinct_dedup_tables,
                 watermark_epoch: args.watermark_epoch,
                 extreme_cache_size: args.extreme_cache_size,
+                approx_count_distinct_index_bits: args.approx_count_distinct_index_bits,
                 chunk_size: args.extra.chunk_size,
                 emit_on_window_close: args.extra.emit_on_window_close,
                 metrics: args.metrics,
@@ -294,6 +298,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                             &this.input_pk_indices,
                             this.row_count_index,
                             this.extreme_cache_size,
+                            this.approx_count_distinct_index_bits,
                             &this.input_schema,
                         )
                         .await?;
@@ -526,6 +531,10 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 // Update watermark of state tables, for state cleaning.
                 this.all_state_tables_mut()
                     .for_each(|table| table.update_watermark(watermark.clone(), false));
+                this.metrics
+                    .agg_state_cleanup_count
+                    .with_label_values(&[&table_id_str, &actor_id_str])
+                    .inc();
             }
             // Commit all state tables.
             futures::future::try_join_all(
@@ -41,7 +41,8 @@ use risingwave_pb::stream_plan::stream_message::StreamMessage;
 use risingwave_pb::stream_plan::update_mutation::{DispatcherUpdate, MergeUpdate};
 use risingwave_pb::stream_plan::{
     AddMutation, PauseMutation, PbBarrier, PbDispatcher, PbStreamMessage, PbWatermark,
-    ResumeMutation, SourceChangeSplitMutation, StopMutation, UpdateMutation,
+    ResumeMutation, SourceChangeSplitMutation, SourcePauseMutation, StopMutation, ThrottleMutation,
+    UpdateMutation,
 };
 use smallvec::SmallVec;
 
@@ -55,6 +56,7 @@ pub mod monitor;
 
 pub mod agg_common;
 pub mod aggregation;
+mod band_join;
 mod barrier_recv;
 mod batch_query;
 mod chain;
@@ -81,6 +83,7 @@ mod project_set;
 mod rearranged_chain;
 mod receiver;
 pub mod row_id_gen;
+mod session_window;
 mod simple_agg;
 mod sink;
 mod sort;
@@ -105,6 +108,7 @@ pub mod test_utils;
 pub use actor::{Actor, ActorContext, ActorContextRef};
 use anyhow::Context;
 pub use backfill::*;
+pub use band_join::BandJoinExecutor;
 pub use barrier_recv::BarrierRecvExecutor;
 pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
@@ -129,6 +133,7 @@ pub use project_set::*;
 pub use rearranged_chain::RearrangedChainExecutor;
 pub use receiver::ReceiverExecutor;
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
+pub use session_window::*;
 pub use simple_agg::SimpleAggExecutor;
 pub use sink::SinkExecutor;
 pub use sort::*;
@@ -235,6 +240,8 @@ pub enum Mutation {
     SourceChangeSplit(HashMap<ActorId, Vec<SplitImpl>>),
     Pause,
     Resume,
+    Throttle(HashMap<ActorId, u32>),
+    PauseSource(HashMap<ActorId, bool>),
 }
 
 #[derive(Debug, Clone)]
@@ -456,6 +463,12 @@ impl Mutation {
             }),
             Mutation::Pause => PbMutation::Pause(PauseMutation {}),
             Mutation::Resume => PbMutation::Resume(ResumeMutation {}),
+            Mutation::Throttle(changes) => PbMutation::Throttle(ThrottleMutation {
+                actor_rate_limits: changes.clone(),
+            }),
+            Mutation::PauseSource(changes) => PbMutation::PauseSource(SourcePauseMutation {
+                actor_pause: changes.clone(),
+            }),
         }
     }
 
@@ -540,6 +553,8 @@ impl Mutation {
             }
             PbMutation::Pause(_) => Mutation::Pause,
             PbMutation::Resume(_) => Mutation::Resume,
+            PbMutation::Throttle(t) => Mutation::Throttle(t.actor_rate_limits.clone()),
+            PbMutation::PauseSource(p) => Mutation::PauseSource(p.actor_pause.clone()),
         };
         Ok(mutation)
     }
@@ -68,6 +68,7 @@ pub struct StreamingMetrics {
     pub agg_distinct_cache_miss_count: GenericCounterVec<AtomicU64>,
     pub agg_distinct_total_cache_count: GenericCounterVec<AtomicU64>,
     pub agg_distinct_cached_entry_count: GenericGaugeVec<AtomicI64>,
+    pub agg_state_cleanup_count: GenericCounterVec<AtomicU64>,
 
     // Streaming TopN
     pub group_top_n_cache_miss_count: GenericCounterVec<AtomicU64>,
@@ -533,6 +534,14 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let agg_state_cleanup_count = register_int_counter_vec_with_registry!(
+            "stream_agg_state_cleanup_count",
+            "Number of watermark-driven state cleanups in streaming aggregation operators",
+            &["table_id", "actor_id"],
+            registry
+        )
+        .unwrap();
+
         let backfill_snapshot_read_row_count = register_int_counter_vec_with_registry!(
             "stream_backfill_snapshot_read_row_count",
             "Total number of rows that have been read from the backfill snapshot",
@@ -693,6 +702,7 @@ impl StreamingMetrics {
             agg_distinct_cache_miss_count,
             agg_distinct_total_cache_count,
             agg_distinct_cached_entry_count,
+            agg_state_cleanup_count,
             group_top_n_cache_miss_count,
             group_top_n_total_query_cache_count,
             group_top_n_cached_entry_count,
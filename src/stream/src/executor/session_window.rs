@@ -0,0 +1,488 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{pin_mut, StreamExt};
+use futures_async_stream::try_stream;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::bail;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::row::{OwnedRow, Row, RowExt};
+use risingwave_common::types::{DefaultOrd, ScalarImpl, ToOwnedDatum};
+use risingwave_expr::expr::BoxedExpression;
+use risingwave_storage::store::PrefetchOptions;
+use risingwave_storage::StateStore;
+
+use super::aggregation::ChunkBuilder;
+use super::{
+    expect_first_barrier, ActorContextRef, BoxedExecutor, BoxedMessageStream, Executor,
+    ExecutorInfo, Message, PkIndices, PkIndicesRef, StreamExecutorError, StreamExecutorResult,
+    Watermark,
+};
+use crate::common::table::state_table::StateTable;
+
+/// [`SessionWindowExecutor`] assigns rows of an append-only stream to session windows:
+/// consecutive rows whose time values are within a gap of each other belong to the same window.
+/// The input must be sorted by the time column, which is guaranteed by the `SortExecutor`
+/// always placed below this executor.
+///
+/// The rows of the currently open session are buffered in a state table and emitted with the
+/// `window_start` and `window_end` columns appended once the session is closed, i.e. when a row
+/// or a watermark beyond the window end is received. Since buffered rows are emitted after the
+/// watermark that closes their session, no watermark is propagated downstream.
+pub struct SessionWindowExecutor<S: StateStore> {
+    input: BoxedExecutor,
+    inner: ExecutorInner<S>,
+}
+
+pub struct SessionWindowExecutorArgs<S: StateStore> {
+    pub input: BoxedExecutor,
+
+    pub actor_ctx: ActorContextRef,
+    pub pk_indices: PkIndices,
+    pub executor_id: u64,
+
+    pub buffer_table: StateTable<S>,
+    pub chunk_size: usize,
+    pub time_col_index: usize,
+    pub window_end_expr: BoxedExpression,
+}
+
+struct ExecutorInner<S: StateStore> {
+    actor_ctx: ActorContextRef,
+    info: ExecutorInfo,
+
+    buffer_table: StateTable<S>,
+    chunk_size: usize,
+    time_col_index: usize,
+    window_end_expr: BoxedExpression,
+}
+
+/// The session window that the latest input rows belong to.
+struct OpenSession {
+    /// Buffered rows of the session, in time order.
+    rows: Vec<OwnedRow>,
+    /// Time value of the first row.
+    window_start: ScalarImpl,
+    /// Maximum window end candidate (`time_col + gap`) of the buffered rows.
+    window_end: ScalarImpl,
+}
+
+struct ExecutionVars {
+    session: Option<OpenSession>,
+    buffer_changed: bool,
+}
+
+impl<S: StateStore> Executor for SessionWindowExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.executor_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.inner.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
+        &self.inner.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.inner.info.identity
+    }
+}
+
+impl<S: StateStore> SessionWindowExecutor<S> {
+    pub fn new(args: SessionWindowExecutorArgs<S>) -> Self {
+        let mut schema = args.input.schema().clone();
+        let window_type = schema.fields[args.time_col_index].data_type();
+        schema
+            .fields
+            .push(Field::with_name(window_type, "window_start"));
+        schema
+            .fields
+            .push(Field::with_name(args.window_end_expr.return_type(), "window_end"));
+        Self {
+            input: args.input,
+            inner: ExecutorInner {
+                actor_ctx: args.actor_ctx,
+                info: ExecutorInfo {
+                    identity: format!("SessionWindowExecutor {:X}", args.executor_id),
+                    schema,
+                    pk_indices: args.pk_indices,
+                },
+                buffer_table: args.buffer_table,
+                chunk_size: args.chunk_size,
+                time_col_index: args.time_col_index,
+                window_end_expr: args.window_end_expr,
+            },
+        }
+    }
+
+    /// Close the session: remove its rows from the buffer table and build the output chunks
+    /// with the window columns appended.
+    fn flush_session(
+        this: &mut ExecutorInner<S>,
+        session: OpenSession,
+    ) -> StreamExecutorResult<Vec<StreamChunk>> {
+        let OpenSession {
+            rows,
+            window_start,
+            window_end,
+        } = session;
+        let window_cols = OwnedRow::new(vec![Some(window_start), Some(window_end)]);
+
+        let mut chunks = Vec::new();
+        let mut chunk_builder = ChunkBuilder::new(this.chunk_size, &this.info.schema.data_types());
+        for row in rows {
+            this.buffer_table.delete(&row);
+            if let Some(chunk) = chunk_builder.append_row(Op::Insert, (&row).chain(&window_cols)) {
+                chunks.push(chunk);
+            }
+        }
+        if let Some(chunk) = chunk_builder.take() {
+            chunks.push(chunk);
+        }
+        Ok(chunks)
+    }
+
+    /// Rebuild the open session from the buffer table, used on initialization and when the
+    /// vnode bitmap changes.
+    async fn recover_session(
+        this: &ExecutorInner<S>,
+    ) -> StreamExecutorResult<Option<OpenSession>> {
+        let mut rows = Vec::new();
+        {
+            let data_iter = this
+                .buffer_table
+                .iter(PrefetchOptions::new_for_exhaust_iter())
+                .await?;
+            pin_mut!(data_iter);
+            while let Some(row) = data_iter.next().await {
+                rows.push(row?);
+            }
+        }
+
+        let mut session: Option<OpenSession> = None;
+        for row in rows {
+            let Some(ts) = row.datum_at(this.time_col_index).to_owned_datum() else {
+                bail!("time column of buffered session row should not be NULL");
+            };
+            let Some(end_candidate) = this.window_end_expr.eval_row(&row).await? else {
+                bail!("window end candidate should not be NULL");
+            };
+            match &mut session {
+                Some(session) => {
+                    if end_candidate.default_cmp(&session.window_end).is_gt() {
+                        session.window_end = end_candidate;
+                    }
+                    session.rows.push(row);
+                }
+                None => {
+                    session = Some(OpenSession {
+                        rows: vec![row],
+                        window_start: ts,
+                        window_end: end_candidate,
+                    });
+                }
+            }
+        }
+        Ok(session)
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn executor_inner(self) {
+        let Self {
+            input,
+            inner: mut this,
+        } = self;
+
+        let mut input = input.execute();
+
+        let barrier = expect_first_barrier(&mut input).await?;
+        this.buffer_table.init_epoch(barrier.epoch);
+        yield Message::Barrier(barrier);
+
+        let mut vars = ExecutionVars {
+            session: Self::recover_session(&this).await?,
+            buffer_changed: false,
+        };
+
+        #[for_await]
+        for msg in input {
+            match msg? {
+                Message::Watermark(Watermark { col_idx, val, .. })
+                    if col_idx == this.time_col_index =>
+                {
+                    // The input is sorted, so all rows after this watermark have a greater time
+                    // value and cannot belong to a session ending at or before it.
+                    let session_closed = vars
+                        .session
+                        .as_ref()
+                        .map_or(false, |s| s.window_end.default_cmp(&val).is_le());
+                    if session_closed {
+                        let session = vars.session.take().unwrap();
+                        for chunk in Self::flush_session(&mut this, session)? {
+                            yield Message::Chunk(chunk);
+                        }
+                        vars.buffer_changed = true;
+                    }
+                    // The open session may emit rows with `window_start` less than any received
+                    // watermark, so watermarks are consumed here instead of being propagated.
+                }
+                Message::Watermark(_) => {
+                    // ignore watermarks on other columns
+                    continue;
+                }
+                Message::Chunk(chunk) => {
+                    for (op, row) in chunk.rows() {
+                        if !matches!(op, Op::Insert) {
+                            bail!("append-only stream is required by SessionWindowExecutor");
+                        }
+                        let row = row.into_owned_row();
+                        let Some(ts) = row.datum_at(this.time_col_index).to_owned_datum() else {
+                            // Rows with NULL time value are filtered out by the frontend.
+                            continue;
+                        };
+                        let Some(end_candidate) = this.window_end_expr.eval_row(&row).await? else {
+                            bail!("window end candidate should not be NULL");
+                        };
+
+                        let session_closed = vars
+                            .session
+                            .as_ref()
+                            .map_or(false, |s| ts.default_cmp(&s.window_end).is_gt());
+                        if session_closed {
+                            let session = vars.session.take().unwrap();
+                            for chunk in Self::flush_session(&mut this, session)? {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+
+                        match &mut vars.session {
+                            Some(session) => {
+                                if end_candidate.default_cmp(&session.window_end).is_gt() {
+                                    session.window_end = end_candidate;
+                                }
+                                session.rows.push(row.clone());
+                            }
+                            None => {
+                                vars.session = Some(OpenSession {
+                                    rows: vec![row.clone()],
+                                    window_start: ts,
+                                    window_end: end_candidate,
+                                });
+                            }
+                        }
+                        this.buffer_table.insert(row);
+                        vars.buffer_changed = true;
+                    }
+                }
+                Message::Barrier(barrier) => {
+                    if vars.buffer_changed {
+                        this.buffer_table.commit(barrier.epoch).await?;
+                    } else {
+                        this.buffer_table.commit_no_data_expected(barrier.epoch);
+                    }
+                    vars.buffer_changed = false;
+
+                    // Update the vnode bitmap of the buffer table if asked.
+                    if let Some(vnode_bitmap) = barrier.as_update_vnode_bitmap(this.actor_ctx.id) {
+                        let (_, cache_may_stale) =
+                            this.buffer_table.update_vnode_bitmap(vnode_bitmap);
+
+                        if cache_may_stale {
+                            vars.session = Self::recover_session(&this).await?;
+                        }
+                    }
+
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, TableId};
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_expr::expr::build_from_pretty;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
+    use crate::executor::ActorContext;
+
+    async fn create_executor<S: StateStore>(
+        time_col_index: usize,
+        store: S,
+    ) -> (MessageSender, BoxedMessageStream) {
+        let input_schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64), // pk
+            Field::unnamed(DataType::Int64), // time
+        ]);
+        let input_pk_indices = vec![0];
+
+        // buffer table schema = input schema
+        let table_columns = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+
+        // note that the time column is the first table pk column to ensure ordering
+        let table_pk_indices = vec![time_col_index, 0];
+        let table_order_types = vec![OrderType::ascending(), OrderType::ascending()];
+        let buffer_table = StateTable::new_without_distribution(
+            store,
+            TableId::new(1),
+            table_columns,
+            table_order_types,
+            table_pk_indices,
+        )
+        .await;
+
+        let (tx, source) = MockSource::channel(input_schema, input_pk_indices.clone());
+        let session_window_executor = SessionWindowExecutor::new(SessionWindowExecutorArgs {
+            input: source.boxed(),
+            actor_ctx: ActorContext::create(123),
+            pk_indices: input_pk_indices,
+            executor_id: 1,
+            buffer_table,
+            chunk_size: 1024,
+            time_col_index,
+            // gap of 10
+            window_end_expr: build_from_pretty("(add:int8 $1:int8 10:int8)"),
+        });
+        (tx, session_window_executor.boxed().execute())
+    }
+
+    #[tokio::test]
+    async fn test_session_window_executor() {
+        let time_col_index = 1;
+
+        let store = MemoryStateStore::new();
+        let (mut tx, mut session_window_executor) =
+            create_executor(time_col_index, store).await;
+
+        // Init barrier
+        tx.push_barrier(1, false);
+
+        // Consume the barrier
+        session_window_executor.expect_barrier().await;
+
+        // Push data chunk, all rows within gap of each other
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2
+            + 3 6",
+        ));
+
+        // Push a row beyond the window end (6 + 10), closing the session
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I  I
+            + 4 20",
+        ));
+
+        // Consume the closed session
+        let chunk = session_window_executor.expect_chunk().await;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I  I
+                + 1 1 1 16
+                + 2 2 1 16
+                + 3 6 1 16"
+            )
+        );
+
+        // Push watermark on an irrelevant column, expected to be ignored
+        tx.push_int64_watermark(0, 100_i64);
+
+        // Push watermark on the time column beyond the window end (20 + 10)
+        tx.push_int64_watermark(time_col_index, 35_i64);
+
+        // Consume the closed session; note that no watermark is propagated
+        let chunk = session_window_executor.expect_chunk().await;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I  I  I  I
+                + 4 20 20 30"
+            )
+        );
+
+        // Push barrier
+        tx.push_barrier(2, false);
+
+        // Consume the barrier
+        session_window_executor.expect_barrier().await;
+    }
+
+    #[tokio::test]
+    async fn test_session_window_executor_fail_over() {
+        let time_col_index = 1;
+
+        let store = MemoryStateStore::new();
+        let (mut tx, mut session_window_executor) =
+            create_executor(time_col_index, store.clone()).await;
+
+        // Init barrier
+        tx.push_barrier(1, false);
+
+        // Consume the barrier
+        session_window_executor.expect_barrier().await;
+
+        // Push data chunk
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2",
+        ));
+
+        // Push barrier
+        tx.push_barrier(2, false);
+
+        // Consume the barrier
+        session_window_executor.expect_barrier().await;
+
+        // Mock fail over
+        let (mut recovered_tx, mut recovered_executor) =
+            create_executor(time_col_index, store).await;
+
+        // Push barrier
+        recovered_tx.push_barrier(3, false);
+
+        // Consume the barrier
+        recovered_executor.expect_barrier().await;
+
+        // Push a row beyond the window end (2 + 10) of the recovered session
+        recovered_tx.push_chunk(StreamChunk::from_pretty(
+            " I  I
+            + 3 30",
+        ));
+
+        // Consume the recovered session
+        let chunk = recovered_executor.expect_chunk().await;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I  I
+                + 1 1 1 12
+                + 2 2 1 12"
+            )
+        );
+    }
+}
@@ -85,6 +85,9 @@ struct ExecutorInner<S: StateStore> {
     /// Extreme state cache size
     extreme_cache_size: usize,
 
+    /// Number of index bits of the sketch for approx count distinct agg.
+    approx_count_distinct_index_bits: u8,
+
     metrics: Arc<StreamingMetrics>,
 }
 
@@ -151,6 +154,7 @@ impl<S: StateStore> SimpleAggExecutor<S> {
                 distinct_dedup_tables: args.distinct_dedup_tables,
                 watermark_epoch: args.watermark_epoch,
                 extreme_cache_size: args.extreme_cache_size,
+                approx_count_distinct_index_bits: args.approx_count_distinct_index_bits,
                 metrics: args.metrics,
             },
         })
@@ -299,6 +303,7 @@ impl<S: StateStore> SimpleAggExecutor<S> {
                 &this.input_pk_indices,
                 this.row_count_index,
                 this.extreme_cache_size,
+                this.approx_count_distinct_index_bits,
                 &this.input_schema,
             )
             .await?,
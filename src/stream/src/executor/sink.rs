@@ -26,7 +26,7 @@ use risingwave_common::row::Row;
 use risingwave_common::types::DataType;
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_connector::sink::catalog::SinkType;
-use risingwave_connector::sink::{Sink, SinkConfig, SinkImpl};
+use risingwave_connector::sink::{Sink, SinkConfig, SinkError, SinkImpl};
 use risingwave_connector::{dispatch_sink, ConnectorParams};
 
 use super::error::{StreamExecutorError, StreamExecutorResult};
@@ -40,6 +40,7 @@ pub struct SinkExecutor<F: LogStoreFactory> {
     metrics: Arc<StreamingMetrics>,
     sink: SinkImpl,
     config: SinkConfig,
+    sink_id: u64,
     identity: String,
     columns: Vec<ColumnCatalog>,
     schema: Schema,
@@ -127,6 +128,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             metrics,
             sink,
             config,
+            sink_id,
             identity: format!("SinkExecutor {:X?}", executor_id),
             columns,
             schema,
@@ -157,8 +159,12 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             self.actor_context,
         );
 
+        let connector = self.config.get_connector().to_owned();
+        let sink_id = self.sink_id;
+
         dispatch_sink!(self.sink, sink, {
-            let consume_log_stream = Self::execute_consume_log(sink, self.log_reader, sink_metrics);
+            let consume_log_stream =
+                Self::execute_consume_log(sink, self.log_reader, sink_metrics, connector, sink_id);
             select(consume_log_stream.into_stream(), write_log_stream).boxed()
         })
     }
@@ -236,7 +242,13 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         mut sink: S,
         mut log_reader: R,
         sink_metrics: SinkMetrics,
+        connector: String,
+        sink_id: u64,
     ) -> StreamExecutorResult<Message> {
+        // Tag every error from the sink with the connector name and sink id, so that failures
+        // in clusters with many sinks can be attributed without digging through actor ids.
+        let err_ctx = |e: SinkError| e.with_sink_context(&connector, sink_id);
+
         log_reader.init().await?;
 
         enum LogConsumerState {
@@ -259,7 +271,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                 LogStoreReadItem::StreamChunk(chunk) => {
                     state = match state {
                         LogConsumerState::Uninitialized => {
-                            sink.begin_epoch(epoch).await?;
+                            sink.begin_epoch(epoch).await.map_err(err_ctx)?;
                             LogConsumerState::Writing { curr_epoch: epoch }
                         }
                         LogConsumerState::Writing { curr_epoch } => {
@@ -278,14 +290,14 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                                 epoch,
                                 prev_epoch
                             );
-                            sink.begin_epoch(epoch).await?;
+                            sink.begin_epoch(epoch).await.map_err(err_ctx)?;
                             LogConsumerState::Writing { curr_epoch: epoch }
                         }
                     };
 
                     if let Err(e) = sink.write_batch(chunk.clone()).await {
-                        sink.abort().await?;
-                        return Err(e.into());
+                        sink.abort().await.map_err(err_ctx)?;
+                        return Err(err_ctx(e).into());
                     }
                 }
                 LogStoreReadItem::Barrier { is_checkpoint } => {
@@ -302,7 +314,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                             );
                             if is_checkpoint {
                                 let start_time = Instant::now();
-                                sink.commit().await?;
+                                sink.commit().await.map_err(err_ctx)?;
                                 sink_metrics
                                     .sink_commit_duration_metrics
                                     .observe(start_time.elapsed().as_millis() as f64);
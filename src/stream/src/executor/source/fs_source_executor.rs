@@ -24,6 +24,7 @@ use risingwave_connector::source::{
     BoxSourceWithStateStream, ConnectorState, SourceContext, SourceCtrlOpts, SplitId, SplitImpl,
     SplitMetaData, StreamChunkWithState,
 };
+use risingwave_source::connector_source::apply_rate_limit;
 use risingwave_source::source_desc::{FsSourceDesc, SourceDescBuilder};
 use risingwave_storage::StateStore;
 use tokio::sync::mpsc::UnboundedReceiver;
@@ -105,12 +106,19 @@ impl<S: StateStore> FsSourceExecutor<S> {
             self.source_ctrl_opts.clone(),
         );
         source_ctx.add_suppressor(self.ctx.error_suppressor.clone());
+        source_ctx.set_parse_error_policy(source_desc.source.parse_error_policy);
+        let source_ctx = Arc::new(source_ctx);
         let stream_reader = source_desc
             .source
-            .stream_reader(state, column_ids, Arc::new(source_ctx))
+            .stream_reader(state, column_ids, source_ctx.clone())
             .await
             .map_err(StreamExecutorError::connector_error)?;
-        Ok(stream_reader.into_stream())
+        Ok(apply_rate_limit(
+            stream_reader.into_stream(),
+            source_desc.source.rate_limit.clone(),
+            source_desc.source.paused.clone(),
+            source_ctx,
+        ))
     }
 
     async fn apply_split_change<const BIASED: bool>(
@@ -382,6 +390,16 @@ impl<S: StateStore> FsSourceExecutor<S> {
                                     )
                                     .await?;
                                 }
+                                Mutation::Throttle(actor_rate_limits) => {
+                                    if let Some(rate_limit) = actor_rate_limits.get(&self.ctx.id) {
+                                        source_desc.source.set_rate_limit(*rate_limit);
+                                    }
+                                }
+                                Mutation::PauseSource(actor_pause) => {
+                                    if let Some(pause) = actor_pause.get(&self.ctx.id) {
+                                        source_desc.source.set_paused(*pause);
+                                    }
+                                }
                                 _ => {}
                             }
                         }
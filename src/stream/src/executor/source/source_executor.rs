@@ -105,6 +105,7 @@ impl<S: StateStore> SourceExecutor<S> {
             self.source_ctrl_opts.clone(),
         );
         source_ctx.add_suppressor(self.ctx.error_suppressor.clone());
+        source_ctx.set_parse_error_policy(source_desc.source.parse_error_policy);
         source_desc
             .source
             .stream_reader(state, column_ids, Arc::new(source_ctx))
@@ -455,6 +456,16 @@ impl<S: StateStore> SourceExecutor<S> {
                                         .apply_split_change(&source_desc, &mut stream, actor_splits)
                                         .await?;
                                 }
+                                Mutation::Throttle(actor_rate_limits) => {
+                                    if let Some(rate_limit) = actor_rate_limits.get(&self.ctx.id) {
+                                        source_desc.source.set_rate_limit(*rate_limit);
+                                    }
+                                }
+                                Mutation::PauseSource(actor_pause) => {
+                                    if let Some(pause) = actor_pause.get(&self.ctx.id) {
+                                        source_desc.source.set_paused(*pause);
+                                    }
+                                }
                                 _ => {}
                             }
                         }
@@ -108,6 +108,7 @@ impl StatelessSimpleAggExecutor {
                     &agg_call.kind,
                     &agg_call.return_type,
                     None,
+                    &agg_call.direct_args,
                 )
             })
             .try_collect()?;
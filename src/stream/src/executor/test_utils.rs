@@ -442,6 +442,7 @@ pub mod agg_executor {
             executor_id,
 
             extreme_cache_size,
+            approx_count_distinct_index_bits: 16,
 
             agg_calls,
             row_count_index,
@@ -504,6 +505,7 @@ pub mod agg_executor {
             executor_id,
 
             extreme_cache_size: 1024,
+            approx_count_distinct_index_bits: 16,
 
             agg_calls,
             row_count_index,
@@ -0,0 +1,84 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use risingwave_common::bail;
+use risingwave_expr::expr::build_from_prost;
+use risingwave_pb::plan_common::JoinType;
+use risingwave_pb::stream_plan::BandJoinNode;
+
+use super::*;
+use crate::common::table::state_table::StateTable;
+use crate::executor::BandJoinExecutor;
+
+pub struct BandJoinExecutorBuilder;
+
+#[async_trait::async_trait]
+impl ExecutorBuilder for BandJoinExecutorBuilder {
+    type Node = BandJoinNode;
+
+    async fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &Self::Node,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> StreamResult<BoxedExecutor> {
+        let [source_l, source_r]: [_; 2] = params.input.try_into().unwrap();
+
+        if node.get_join_type()? != JoinType::Inner {
+            bail!("`BandJoinExecutor` only supports inner joins");
+        }
+
+        let condition = node.condition.as_ref().map(build_from_prost).transpose()?;
+        let output_indices = node
+            .get_output_indices()
+            .iter()
+            .map(|&x| x as usize)
+            .collect();
+
+        let vnodes = Arc::new(params.vnode_bitmap.expect("vnodes not set for band join"));
+
+        let state_table_l = StateTable::from_table_catalog_inconsistent_op(
+            node.get_left_table()?,
+            store.clone(),
+            Some(vnodes),
+        )
+        .await;
+
+        // The right side is broadcast and its state table is replicated, so it is not
+        // distributed by vnode.
+        let state_table_r =
+            StateTable::from_table_catalog_inconsistent_op(node.get_right_table()?, store, None)
+                .await;
+
+        Ok(Box::new(BandJoinExecutor::new(
+            params.actor_context,
+            source_l,
+            source_r,
+            node.get_left_key() as usize,
+            node.get_right_lower_key() as usize,
+            node.get_right_upper_key() as usize,
+            node.get_lower_inclusive(),
+            node.get_upper_inclusive(),
+            condition,
+            output_indices,
+            params.pk_indices,
+            params.executor_id,
+            state_table_l,
+            state_table_r,
+            params.executor_stats,
+            params.env.config().developer.chunk_size,
+        )))
+    }
+}
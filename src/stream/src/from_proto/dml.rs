@@ -41,6 +41,7 @@ impl ExecutorBuilder for DmlExecutorBuilder {
         let fields = column_descs.iter().map(Into::into).collect_vec();
         let schema = Schema::new(fields);
         Ok(Box::new(DmlExecutor::new(
+            params.actor_context,
             upstream,
             schema,
             params.pk_indices,
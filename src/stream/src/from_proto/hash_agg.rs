@@ -102,6 +102,10 @@ impl ExecutorBuilder for HashAggExecutorBuilder {
                 executor_id: params.executor_id,
 
                 extreme_cache_size: stream.config.developer.unsafe_extreme_cache_size,
+                approx_count_distinct_index_bits: stream
+                    .config
+                    .developer
+                    .approx_count_distinct_index_bits,
 
                 agg_calls,
                 row_count_index: node.get_row_count_index() as usize,
@@ -16,6 +16,7 @@
 
 mod agg_common;
 mod append_only_dedup;
+mod band_join;
 mod barrier_recv;
 mod batch_query;
 mod chain;
@@ -37,6 +38,7 @@ mod now;
 mod project;
 mod project_set;
 mod row_id_gen;
+mod session_window;
 mod simple_agg;
 mod sink;
 mod sort;
@@ -55,6 +57,7 @@ use risingwave_pb::stream_plan::{StreamNode, TemporalJoinNode};
 use risingwave_storage::StateStore;
 
 use self::append_only_dedup::*;
+use self::band_join::*;
 use self::barrier_recv::*;
 use self::batch_query::*;
 use self::chain::*;
@@ -76,6 +79,7 @@ use self::now::NowExecutorBuilder;
 use self::project::*;
 use self::project_set::*;
 use self::row_id_gen::RowIdGenExecutorBuilder;
+use self::session_window::*;
 use self::simple_agg::*;
 use self::sink::*;
 use self::sort::*;
@@ -163,5 +167,7 @@ pub async fn create_executor(
         NodeBody::AppendOnlyDedup => AppendOnlyDedupExecutorBuilder,
         NodeBody::NoOp => NoOpExecutorBuilder,
         NodeBody::EowcOverWindow => EowcOverWindowExecutorBuilder,
+        NodeBody::SessionWindow => SessionWindowExecutorBuilder,
+        NodeBody::BandJoin => BandJoinExecutorBuilder,
     }
 }
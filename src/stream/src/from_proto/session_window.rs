@@ -0,0 +1,58 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_expr::expr::build_from_prost;
+use risingwave_pb::stream_plan::SessionWindowNode;
+
+use super::*;
+use crate::common::table::state_table::StateTable;
+use crate::executor::{SessionWindowExecutor, SessionWindowExecutorArgs};
+
+pub struct SessionWindowExecutorBuilder;
+
+#[async_trait::async_trait]
+impl ExecutorBuilder for SessionWindowExecutorBuilder {
+    type Node = SessionWindowNode;
+
+    async fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &Self::Node,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> StreamResult<BoxedExecutor> {
+        let [input]: [_; 1] = params.input.try_into().unwrap();
+        let vnodes = Arc::new(
+            params
+                .vnode_bitmap
+                .expect("vnodes not set for session window"),
+        );
+        let state_table =
+            StateTable::from_table_catalog(node.get_state_table()?, store, Some(vnodes)).await;
+        let window_end_expr = build_from_prost(node.get_window_end_expr()?)?;
+        Ok(Box::new(SessionWindowExecutor::new(
+            SessionWindowExecutorArgs {
+                input,
+                actor_ctx: params.actor_context,
+                pk_indices: params.pk_indices,
+                executor_id: params.executor_id,
+                buffer_table: state_table,
+                chunk_size: params.env.config().developer.chunk_size,
+                time_col_index: node.time_col as _,
+                window_end_expr,
+            },
+        )))
+    }
+}
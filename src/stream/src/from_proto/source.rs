@@ -77,6 +77,16 @@ impl ExecutorBuilder for SourceExecutorBuilder {
 
             let source_ctrl_opts = SourceCtrlOpts {
                 chunk_size: params.env.config().developer.chunk_size,
+                resource_label: {
+                    let label = params
+                        .env
+                        .system_params_manager_ref()
+                        .get_params()
+                        .load()
+                        .resource_label()
+                        .to_string();
+                    (!label.is_empty()).then_some(label)
+                },
             };
 
             let column_ids: Vec<_> = source
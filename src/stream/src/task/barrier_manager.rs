@@ -16,7 +16,9 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::anyhow;
 use prometheus::HistogramTimer;
-use risingwave_pb::stream_service::barrier_complete_response::PbCreateMviewProgress;
+use risingwave_pb::stream_service::barrier_complete_response::{
+    PbActorCollectLatency, PbCreateMviewProgress, PbFailedActor,
+};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::Receiver;
@@ -43,6 +45,9 @@ pub const ENABLE_BARRIER_AGGREGATION: bool = false;
 #[derive(Debug)]
 pub struct CollectResult {
     pub create_mview_progress: Vec<PbCreateMviewProgress>,
+
+    /// The duration from issuing the barrier to collecting it, for each actor on this node.
+    pub actor_collect_latency: Vec<PbActorCollectLatency>,
 }
 
 enum BarrierState {
@@ -243,6 +248,16 @@ impl LocalBarrierManager {
             }
         }
     }
+
+    /// List the actors that have reported an unexpected exit so far.
+    pub fn failed_actors(&self) -> Vec<PbFailedActor> {
+        match &self.state {
+            #[cfg(test)]
+            BarrierState::Local => vec![],
+
+            BarrierState::Managed(managed_state) => managed_state.failed_actors(),
+        }
+    }
 }
 
 #[cfg(test)]
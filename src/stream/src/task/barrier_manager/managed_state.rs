@@ -14,10 +14,13 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter::once;
+use std::time::{Duration, Instant};
 
 use anyhow::anyhow;
 use risingwave_common::bail;
-use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
+use risingwave_pb::stream_service::barrier_complete_response::{
+    ActorCollectLatency, CreateMviewProgress, FailedActor,
+};
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use tokio::sync::oneshot;
 
@@ -42,6 +45,12 @@ enum ManagedBarrierStateInner {
         /// Actor ids remaining to be collected.
         remaining_actors: HashSet<ActorId>,
 
+        /// When the barrier was issued, for measuring per-actor collect latency.
+        issued_at: Instant,
+
+        /// Time from issuing the barrier to collecting it, for each collected actor.
+        collect_latency: HashMap<ActorId, Duration>,
+
         /// Notify that the collection is finished.
         collect_notifier: Option<oneshot::Sender<StreamResult<CollectResult>>>,
     },
@@ -139,11 +148,21 @@ impl ManagedBarrierState {
 
                 match barrier_state.inner {
                     ManagedBarrierStateInner::Issued {
-                        collect_notifier, ..
+                        collect_notifier,
+                        collect_latency,
+                        ..
                     } => {
+                        let actor_collect_latency = collect_latency
+                            .into_iter()
+                            .map(|(actor_id, latency)| ActorCollectLatency {
+                                actor_id,
+                                collect_latency_ms: latency.as_millis() as u64,
+                            })
+                            .collect();
                         // Notify about barrier finishing.
                         let result = CollectResult {
                             create_mview_progress,
+                            actor_collect_latency,
                         };
                         if collect_notifier.unwrap().send(Ok(result)).is_err() {
                             warn!("failed to notify barrier collection with epoch {}", epoch)
@@ -191,6 +210,18 @@ impl ManagedBarrierState {
         self.failure_actors.insert(actor_id, err);
     }
 
+    /// List the actors that have reported an unexpected exit so far, for structured reporting
+    /// back to the meta service.
+    pub(crate) fn failed_actors(&self) -> Vec<FailedActor> {
+        self.failure_actors
+            .iter()
+            .map(|(&actor_id, err)| FailedActor {
+                actor_id,
+                message: err.to_string(),
+            })
+            .collect()
+    }
+
     /// Collect a `barrier` from the actor with `actor_id`.
     pub(super) fn collect(&mut self, actor_id: ActorId, barrier: &Barrier) {
         tracing::trace!(
@@ -219,6 +250,8 @@ impl ManagedBarrierState {
                 inner:
                     ManagedBarrierStateInner::Issued {
                         ref mut remaining_actors,
+                        issued_at,
+                        ref mut collect_latency,
                         ..
                     },
                 ..
@@ -230,6 +263,7 @@ impl ManagedBarrierState {
                     actor_id, barrier.epoch.curr
                 );
                 assert_eq!(prev_epoch, barrier.epoch.prev);
+                collect_latency.insert(actor_id, issued_at.elapsed());
                 self.may_notify(barrier.epoch.curr);
             }
             None => {
@@ -263,9 +297,19 @@ impl ManagedBarrierState {
                     },
                 ..
             }) => {
+                // Actors collected before the barrier was issued have effectively zero latency
+                // from our perspective.
+                let mut collect_latency = HashMap::new();
                 let remaining_actors: HashSet<ActorId> = actor_ids_to_collect
                     .into_iter()
-                    .filter(|a| !collected_actors.remove(a))
+                    .filter(|a| {
+                        if collected_actors.remove(a) {
+                            collect_latency.insert(*a, Duration::ZERO);
+                            false
+                        } else {
+                            true
+                        }
+                    })
                     .collect();
                 for (actor_id, err) in &self.failure_actors {
                     if remaining_actors.contains(actor_id) {
@@ -275,6 +319,8 @@ impl ManagedBarrierState {
                 assert!(collected_actors.is_empty());
                 ManagedBarrierStateInner::Issued {
                     remaining_actors,
+                    issued_at: Instant::now(),
+                    collect_latency,
                     collect_notifier: Some(collect_notifier),
                 }
             }
@@ -291,6 +337,8 @@ impl ManagedBarrierState {
                 let remaining_actors = actor_ids_to_collect.into_iter().collect();
                 ManagedBarrierStateInner::Issued {
                     remaining_actors,
+                    issued_at: Instant::now(),
+                    collect_latency: HashMap::new(),
                     collect_notifier: Some(collect_notifier),
                 }
             }
@@ -35,6 +35,7 @@ use risingwave_pb::common::ActorInfo;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::StreamNode;
+use risingwave_pb::stream_service::barrier_complete_response::PbFailedActor;
 use risingwave_storage::monitor::HummockTraceFutureExt;
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use tokio::sync::Mutex;
@@ -266,6 +267,11 @@ impl LocalStreamManager {
         Ok((result, complete_receiver.checkpoint))
     }
 
+    /// List the actors that have reported an unexpected exit so far on this worker.
+    pub fn failed_actors(&self) -> Vec<PbFailedActor> {
+        self.context.lock_barrier_manager().failed_actors()
+    }
+
     pub async fn sync_epoch(&self, epoch: u64) -> StreamResult<Vec<LocalSstableInfo>> {
         let timer = self
             .core
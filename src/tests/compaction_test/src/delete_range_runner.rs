@@ -180,6 +180,8 @@ async fn compaction_test(
         state_store_type.strip_prefix("hummock+").unwrap(),
         object_store_metrics.clone(),
         "Hummock",
+        None,
+        config.storage.object_store_retry.clone(),
     )
     .await;
     let sstable_store = Arc::new(SstableStore::new(
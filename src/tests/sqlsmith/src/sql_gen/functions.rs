@@ -175,6 +175,9 @@ fn make_general_expr(func: ExprType, exprs: Vec<Expr>) -> Option<Expr> {
         E::Sha256 => Some(Expr::Function(make_simple_func("sha256", &exprs))),
         E::Sha384 => Some(Expr::Function(make_simple_func("sha384", &exprs))),
         E::Sha512 => Some(Expr::Function(make_simple_func("sha512", &exprs))),
+        E::MaskEmail => Some(Expr::Function(make_simple_func("mask_email", &exprs))),
+        E::MaskSsn => Some(Expr::Function(make_simple_func("mask_ssn", &exprs))),
+        E::HashPii => Some(Expr::Function(make_simple_func("hash_pii", &exprs))),
         // TODO: Tracking issue: https://github.com/risingwavelabs/risingwave/issues/112
         // E::Translate => Some(Expr::Function(make_simple_func("translate", &exprs))),
         E::Overlay => Some(make_overlay(exprs)),
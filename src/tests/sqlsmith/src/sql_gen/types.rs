@@ -222,6 +222,8 @@ pub(crate) static AGG_FUNC_TABLE: LazyLock<HashMap<DataType, Vec<AggFuncSig>>> =
                         AggKind::PercentileCont,
                         AggKind::PercentileDisc,
                         AggKind::Mode,
+                        // `approx_percentile` requires a constant fraction argument.
+                        AggKind::ApproxPercentile,
                     ]
                     .contains(&func.func)
             })
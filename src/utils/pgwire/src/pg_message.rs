@@ -223,10 +223,11 @@ impl FeParseMessage {
 }
 
 impl FePasswordMessage {
-    pub fn parse(mut buf: Bytes) -> Result<FeMessage> {
-        let password = read_null_terminated(&mut buf)?;
-
-        Ok(FeMessage::Password(FePasswordMessage { password }))
+    pub fn parse(buf: Bytes) -> Result<FeMessage> {
+        // Keep the raw body: the same tag carries the null-terminated `PasswordMessage` as
+        // well as the binary `SASLInitialResponse` and `SASLResponse` messages, which can
+        // only be told apart by the authentication state.
+        Ok(FeMessage::Password(FePasswordMessage { password: buf }))
     }
 }
 
@@ -348,6 +349,12 @@ pub enum BeMessage<'a> {
     AuthenticationOk,
     AuthenticationCleartextPassword,
     AuthenticationMd5Password(&'a [u8; 4]),
+    // SASL authentication: the supported mechanism names.
+    AuthenticationSasl(&'a [&'a str]),
+    // SASL challenge data.
+    AuthenticationSaslContinue(&'a [u8]),
+    // SASL outcome data.
+    AuthenticationSaslFinal(&'a [u8]),
     CommandComplete(BeCommandCompleteMessage),
     NoticeResponse(&'a str),
     // Single byte - used in response to SSLRequest/GSSENCRequest.
@@ -423,6 +430,43 @@ impl<'a> BeMessage<'a> {
                 buf.put_slice(&salt[..]);
             }
 
+            // AuthenticationSASL
+            // +-----+-----------+-----------+--------------------------+------+
+            // | 'R' | int32 len | int32(10) | str mechanism... | '\0'  | '\0' |
+            // +-----+-----------+-----------+--------------------------+------+
+            BeMessage::AuthenticationSasl(mechanisms) => {
+                buf.put_u8(b'R');
+                let len: usize = 8 + mechanisms.iter().map(|m| m.len() + 1).sum::<usize>() + 1;
+                buf.put_i32(len as i32);
+                buf.put_i32(10);
+                for mechanism in *mechanisms {
+                    write_cstr(buf, mechanism.as_bytes())?;
+                }
+                buf.put_u8(0);
+            }
+
+            // AuthenticationSASLContinue
+            // +-----+-----------+-----------+----------------+
+            // | 'R' | int32 len | int32(11) | byten(data)    |
+            // +-----+-----------+-----------+----------------+
+            BeMessage::AuthenticationSaslContinue(data) => {
+                buf.put_u8(b'R');
+                buf.put_i32((8 + data.len()) as i32);
+                buf.put_i32(11);
+                buf.put_slice(data);
+            }
+
+            // AuthenticationSASLFinal
+            // +-----+-----------+-----------+----------------+
+            // | 'R' | int32 len | int32(12) | byten(data)    |
+            // +-----+-----------+-----------+----------------+
+            BeMessage::AuthenticationSaslFinal(data) => {
+                buf.put_u8(b'R');
+                buf.put_i32((8 + data.len()) as i32);
+                buf.put_i32(12);
+                buf.put_slice(data);
+            }
+
             // ParameterStatus
             // +-----+-----------+----------+------+-----------+------+
             // | 'S' | int32 len | str name | '\0' | str value | '\0' |
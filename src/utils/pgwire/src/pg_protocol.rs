@@ -39,6 +39,7 @@ use crate::pg_message::{
     FePasswordMessage, FeStartupMessage,
 };
 use crate::pg_server::{Session, SessionManager, UserAuthenticator};
+use crate::scram::{self, ScramSecret, ScramServer};
 use crate::types::Format;
 
 /// The state machine for each psql connection.
@@ -69,6 +70,9 @@ where
     // Used for ssl connection.
     // If None, not expected to build ssl connection (panic).
     tls_context: Option<SslContext>,
+
+    // The in-flight SCRAM-SHA-256 exchange, if the user authenticates via SASL.
+    sasl: Option<ScramServer>,
 }
 
 const PGWIRE_QUERY_LOG: &str = "pgwire_query_log";
@@ -152,6 +156,7 @@ where
             unnamed_portal: Default::default(),
             portal_store: Default::default(),
             statement_portal_dependency: Default::default(),
+            sasl: None,
         }
     }
 
@@ -303,6 +308,17 @@ where
                 self.stream
                     .write_no_flush(&BeMessage::AuthenticationMd5Password(salt))?;
             }
+            UserAuthenticator::ScramSha256(stored_secret) => {
+                let secret = ScramSecret::parse(stored_secret).ok_or_else(|| {
+                    PsqlError::PasswordError(IoError::new(
+                        ErrorKind::InvalidInput,
+                        "malformed SCRAM-SHA-256 secret",
+                    ))
+                })?;
+                self.sasl = Some(ScramServer::new(secret));
+                self.stream
+                    .write_no_flush(&BeMessage::AuthenticationSasl(&[scram::SCRAM_SHA_256]))?;
+            }
         }
 
         self.session = Some(session);
@@ -311,12 +327,42 @@ where
     }
 
     fn process_password_msg(&mut self, msg: FePasswordMessage) -> PsqlResult<()> {
-        let authenticator = self.session.as_ref().unwrap().user_authenticator();
-        if !authenticator.authenticate(&msg.password) {
-            return Err(PsqlError::PasswordError(IoError::new(
-                ErrorKind::InvalidInput,
-                "Invalid password",
-            )));
+        if let Some(sasl) = &mut self.sasl {
+            if sasl.awaiting_initial() {
+                let (mechanism, data) = scram::parse_sasl_initial_response(&msg.password)
+                    .map_err(PsqlError::PasswordError)?;
+                if mechanism != scram::SCRAM_SHA_256 {
+                    return Err(PsqlError::PasswordError(IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!("unsupported SASL mechanism: {}", mechanism),
+                    )));
+                }
+                let server_first = sasl
+                    .handle_client_first(&data)
+                    .map_err(PsqlError::PasswordError)?;
+                self.stream
+                    .write_no_flush(&BeMessage::AuthenticationSaslContinue(&server_first))?;
+                return Ok(());
+            }
+            let server_final = sasl
+                .handle_client_final(&msg.password)
+                .map_err(PsqlError::PasswordError)?;
+            self.sasl = None;
+            self.stream
+                .write_no_flush(&BeMessage::AuthenticationSaslFinal(&server_final))?;
+        } else {
+            // A `PasswordMessage` is a null-terminated string.
+            let password = msg
+                .password
+                .strip_suffix(b"\0")
+                .unwrap_or(&msg.password[..]);
+            let authenticator = self.session.as_ref().unwrap().user_authenticator();
+            if !authenticator.authenticate(password) {
+                return Err(PsqlError::PasswordError(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "Invalid password",
+                )));
+            }
         }
         self.stream.write_no_flush(&BeMessage::AuthenticationOk)?;
         self.stream
@@ -53,6 +53,7 @@ pub enum StatementType {
     CREATE_INDEX,
     CREATE_FUNCTION,
     CREATE_CONNECTION,
+    CREATE_SECRET,
     DESCRIBE,
     GRANT_PRIVILEGE,
     DROP_TABLE,
@@ -66,12 +67,16 @@ pub enum StatementType {
     DROP_DATABASE,
     DROP_USER,
     DROP_CONNECTION,
+    DROP_SECRET,
+    DROP_OWNED,
+    REASSIGN_OWNED,
     ALTER_INDEX,
     ALTER_VIEW,
     ALTER_TABLE,
     ALTER_MATERIALIZED_VIEW,
     ALTER_SINK,
     ALTER_SOURCE,
+    ALTER_SECRET,
     ALTER_SYSTEM,
     REVOKE_PRIVILEGE,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
@@ -84,6 +89,8 @@ pub enum StatementType {
     UPDATE_USER,
     ABORT,
     FLUSH,
+    KILL,
+    CANCEL_COMMAND,
     OTHER,
     // EMPTY is used when query statement is empty (e.g. ";").
     EMPTY,
@@ -255,6 +262,8 @@ impl StatementType {
             Statement::Rollback { .. } => Ok(StatementType::ROLLBACK),
             Statement::Grant { .. } => Ok(StatementType::GRANT_PRIVILEGE),
             Statement::Revoke { .. } => Ok(StatementType::REVOKE_PRIVILEGE),
+            Statement::DropOwned { .. } => Ok(StatementType::DROP_OWNED),
+            Statement::ReassignOwned { .. } => Ok(StatementType::REASSIGN_OWNED),
             Statement::Describe { .. } => Ok(StatementType::DESCRIBE),
             Statement::ShowCreateObject { .. } | Statement::ShowObjects(_) => {
                 Ok(StatementType::SHOW_COMMAND)
@@ -274,9 +283,12 @@ impl StatementType {
                 risingwave_sqlparser::ast::ObjectType::Connection => {
                     Ok(StatementType::DROP_CONNECTION)
                 }
+                risingwave_sqlparser::ast::ObjectType::Secret => Ok(StatementType::DROP_SECRET),
             },
             Statement::Explain { .. } => Ok(StatementType::EXPLAIN),
             Statement::Flush => Ok(StatementType::FLUSH),
+            Statement::Kill(_) => Ok(StatementType::KILL),
+            Statement::CancelJobs(_) => Ok(StatementType::CANCEL_COMMAND),
             _ => Err("unsupported statement type".to_string()),
         }
     }
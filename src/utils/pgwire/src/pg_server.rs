@@ -113,6 +113,8 @@ pub enum UserAuthenticator {
         encrypted_password: Vec<u8>,
         salt: [u8; 4],
     },
+    // stored SCRAM-SHA-256 secret, verified through a SASL exchange.
+    ScramSha256(String),
 }
 
 impl UserAuthenticator {
@@ -123,6 +125,8 @@ impl UserAuthenticator {
             UserAuthenticator::Md5WithSalt {
                 encrypted_password, ..
             } => encrypted_password == password,
+            // The SCRAM exchange is driven by the protocol handler, which never calls this.
+            UserAuthenticator::ScramSha256(_) => false,
         }
     }
 }